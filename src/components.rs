@@ -1,18 +1,103 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// MVP: Position component for entities in 2D space
-#[derive(Component, Clone, Default)]
+#[derive(Component, Clone, Default, Serialize, Deserialize)]
 pub struct Position {
     pub x: f32,
     pub y: f32,
 }
 
 /// MVP: Soil cell environmental properties (minimal)
-#[derive(Component, Clone)]
+#[derive(Component, Clone, Serialize, Deserialize)]
 pub struct SoilCell {
     pub moisture: f32,
     pub temperature: f32,
     pub nutrition: f32,
+    pub compaction: f32, // 0.0 (loose) to 1.0 (hard-packed)
+}
+
+impl SoilCell {
+    /// Natural compaction of undisturbed soil, which packs harder with depth
+    pub fn compaction_at_depth(y: f32) -> f32 {
+        (0.2 - y / 100.0).clamp(0.0, 1.0)
+    }
+}
+
+/// Soil horizon of a cell, which sets its undisturbed base color
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DepthLayer {
+    Topsoil, // Dark, humus-rich surface layer
+    Subsoil, // Lighter mineral soil
+    Clay,    // Dense reddish clay at the bottom of the nest
+}
+
+impl DepthLayer {
+    /// Layer of a cell at world height `y` (the surface is at 0)
+    pub fn from_y(y: f32) -> Self {
+        if y > -20.0 {
+            DepthLayer::Topsoil
+        } else if y > -52.0 {
+            DepthLayer::Subsoil
+        } else {
+            DepthLayer::Clay
+        }
+    }
+
+    pub fn base_color(self) -> Color {
+        match self {
+            DepthLayer::Topsoil => Color::srgb(0.45, 0.3, 0.15),
+            DepthLayer::Subsoil => Color::srgb(0.6, 0.4, 0.2),
+            DepthLayer::Clay => Color::srgb(0.62, 0.36, 0.24),
+        }
+    }
+
+    /// How hard the layer is to dig, from 0.0 (crumbly) to 1.0 (rock hard)
+    pub fn hardness(self) -> f32 {
+        match self {
+            DepthLayer::Topsoil => 0.2,
+            DepthLayer::Subsoil => 0.5,
+            DepthLayer::Clay => 0.8,
+        }
+    }
+}
+
+/// Soil variable drawn by the heatmap override
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SoilHeatmap {
+    Moisture,
+    Temperature,
+    Nutrition,
+}
+
+/// How soil cells are colored
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SoilColorMode {
+    #[default]
+    Realistic, // Base color subtly tinted by moisture and nutrition
+    Heatmap(SoilHeatmap), // One variable on a debug color ramp
+}
+
+/// Settings for soil coloring, recomputed at a throttled cadence
+#[derive(Resource, Clone, Debug)]
+pub struct SoilColorConfig {
+    pub mode: SoilColorMode,
+    pub update_interval: f32,   // Seconds between recolors
+    pub update_timer: f32,      // Seconds since the last recolor
+    pub wet_darkening: f32,     // Share of brightness lost in saturated soil
+    pub nutrient_greening: f32, // Green shift in the richest soil
+}
+
+impl Default for SoilColorConfig {
+    fn default() -> Self {
+        Self {
+            mode: SoilColorMode::Realistic,
+            update_interval: 0.5,
+            update_timer: 0.0,
+            wet_darkening: 0.35,
+            nutrient_greening: 0.08,
+        }
+    }
 }
 
 /// MVP: Marker component for ant entities
@@ -25,8 +110,89 @@ pub struct Queen {
     pub founding_state: FoundingState,
 }
 
+/// A further queen of a polygynous colony, who settles in her founder's royal chamber
+///
+/// She keeps to the founder while the nest is being founded; should the founder die
+/// first, she drops this marker and founds the nest herself.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct CoFoundingQueen {
+    pub founder: Entity,
+}
+
+/// Fat reserves a founding queen lives on until her first workers emerge
+///
+/// Removed once the colony has workers; a queen who exhausts it first dies.
+#[derive(Component, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct FoundingReserve {
+    pub spent: f32, // Reserve used so far, in seconds at ideal conditions
+}
+
+/// How hard it is for a lone queen to raise her first brood
+///
+/// A queen of vigor 1.0 in ideal soil can wait `reserve_seconds` for her first workers.
+/// Harsh soil burns the reserve faster and raises the risk of dying on the way;
+/// vigor stretches the reserve and environmental adaptation softens the soil's toll.
+#[derive(Resource, Clone, Debug)]
+pub struct FoundingSuccessConfig {
+    pub reserve_seconds: f32,       // Reserve of an average queen in ideal soil
+    pub hazard_rate: f32,           // Chance per second of dying anyway, in ideal soil
+    pub ideal_temperature: f32,     // Soil temperature that costs the queen nothing extra
+    pub ideal_moisture: f32,        // Soil moisture that costs the queen nothing extra
+    pub temperature_tolerance: f32, // °C off ideal that doubles the toll
+    pub moisture_tolerance: f32,    // Moisture off ideal that doubles the toll
+    pub sense_radius: f32,          // Soil within this distance of the queen counts
+}
+
+impl Default for FoundingSuccessConfig {
+    fn default() -> Self {
+        Self {
+            reserve_seconds: 120.0,
+            hazard_rate: 0.001,
+            ideal_temperature: 20.0,
+            ideal_moisture: 0.55,
+            temperature_tolerance: 8.0,
+            moisture_tolerance: 0.3,
+            sense_radius: 12.0,
+        }
+    }
+}
+
+/// When a thriving colony sends a daughter queen off on her nuptial flight
+///
+/// Once the colony has `min_workers`, an established queen raises a daughter every
+/// `interval` seconds. The daughter lands within `dispersal_distance` of her mother and
+/// founds a colony of her own, inheriting her mother's [`ColonyTraits`].
+#[derive(Resource, Clone, Debug)]
+pub struct NuptialFlightConfig {
+    pub min_workers: usize,      // Colony size before it raises daughter queens
+    pub interval: f32,           // Seconds between flights
+    pub dispersal_distance: f32, // Farthest a daughter lands from her mother
+}
+
+impl Default for NuptialFlightConfig {
+    fn default() -> Self {
+        Self {
+            min_workers: 150,
+            interval: 600.0,
+            dispersal_distance: 60.0,
+        }
+    }
+}
+
+impl FoundingSuccessConfig {
+    /// How fast founding drains a queen in soil of the given temperature and moisture
+    ///
+    /// 1.0 in ideal soil, rising with the square of each deviation, divided by the
+    /// colony's environmental adaptation.
+    pub fn harshness(&self, temperature: f32, moisture: f32, adaptation: f32) -> f32 {
+        let heat = (temperature - self.ideal_temperature) / self.temperature_tolerance;
+        let damp = (moisture - self.ideal_moisture) / self.moisture_tolerance;
+        (1.0 + heat * heat + damp * damp) / adaptation.max(f32::EPSILON)
+    }
+}
+
 /// State machine for queen ant founding behavior
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
 pub enum FoundingState {
     Seeking,     // Looking for suitable founding location
     Digging,     // Creating the founding chamber
@@ -36,3 +202,4381 @@ pub enum FoundingState {
 /// MVP: Marker component for soil entities
 #[derive(Component)]
 pub struct Soil;
+
+/// Performance metrics tracking resource
+#[derive(Resource)]
+pub struct PerformanceMetrics {
+    pub fps: f32,
+    pub frame_time_ms: f32,
+    pub frame_times: std::collections::VecDeque<f32>,
+    pub frame_time_window: usize, // Frames averaged; larger is smoother, smaller more responsive
+    pub ant_count: usize,
+    pub soil_count: usize,
+    pub total_entity_count: usize,
+    pub update_timer: Timer,
+}
+
+impl Default for PerformanceMetrics {
+    fn default() -> Self {
+        Self::with_window(60)
+    }
+}
+
+impl PerformanceMetrics {
+    /// Metrics averaging frame times over the last `frame_time_window` frames
+    pub fn with_window(frame_time_window: usize) -> Self {
+        let frame_time_window = frame_time_window.max(1);
+        Self {
+            fps: 0.0,
+            frame_time_ms: 0.0,
+            frame_times: std::collections::VecDeque::with_capacity(frame_time_window),
+            frame_time_window,
+            ant_count: 0,
+            soil_count: 0,
+            total_entity_count: 0,
+            update_timer: Timer::from_seconds(0.1, TimerMode::Repeating),
+        }
+    }
+
+    /// Record a frame time (in seconds) and refresh the averaged FPS readout
+    pub fn add_frame_time(&mut self, delta_seconds: f32) {
+        self.frame_times.push_back(delta_seconds);
+        while self.frame_times.len() > self.frame_time_window.max(1) {
+            self.frame_times.pop_front();
+        }
+
+        let average = self.frame_times.iter().sum::<f32>() / self.frame_times.len() as f32;
+        if average > 0.0 {
+            self.fps = 1.0 / average;
+            self.frame_time_ms = average * 1000.0;
+        }
+    }
+
+    /// Update entity counts shown by the performance displays
+    pub fn update_entity_counts(
+        &mut self,
+        ant_count: usize,
+        soil_count: usize,
+        total_entity_count: usize,
+    ) {
+        self.ant_count = ant_count;
+        self.soil_count = soil_count;
+        self.total_entity_count = total_entity_count;
+    }
+}
+
+/// Per-section frame timings for the profiling overlay; records nothing while disabled
+#[derive(Resource, Clone, Debug)]
+pub struct SystemProfiler {
+    pub enabled: bool,
+    pub smoothing: f32, // Weight of the newest frame in each running average
+    pub averages: Vec<(&'static str, f32)>, // Average milliseconds per section, first seen first
+    pub started: std::collections::HashMap<&'static str, bevy::utils::Instant>,
+}
+
+impl Default for SystemProfiler {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            smoothing: 0.1,
+            averages: Vec::new(),
+            started: std::collections::HashMap::new(),
+        }
+    }
+}
+
+impl SystemProfiler {
+    /// Mark the start of a timed section
+    pub fn start(&mut self, section: &'static str) {
+        self.started.insert(section, bevy::utils::Instant::now());
+    }
+
+    /// Close a timed section and fold its duration into the running average
+    pub fn stop(&mut self, section: &'static str) {
+        let Some(started) = self.started.remove(section) else {
+            return;
+        };
+        let elapsed_ms = started.elapsed().as_secs_f32() * 1000.0;
+        match self.averages.iter_mut().find(|(name, _)| *name == section) {
+            Some((_, average)) => *average += (elapsed_ms - *average) * self.smoothing,
+            None => self.averages.push((section, elapsed_ms)),
+        }
+    }
+
+    pub fn average_ms(&self, section: &str) -> Option<f32> {
+        self.averages
+            .iter()
+            .find(|(name, _)| *name == section)
+            .map(|(_, average)| *average)
+    }
+
+    /// Sections from the most to the least expensive
+    pub fn sorted(&self) -> Vec<(&'static str, f32)> {
+        let mut sorted = self.averages.clone();
+        sorted.sort_by(|a, b| b.1.total_cmp(&a.1));
+        sorted
+    }
+}
+
+/// Ants picked out with drag selection for group inspection
+#[derive(Resource, Clone, Debug, Default)]
+pub struct Selection(pub Vec<Entity>);
+
+/// Ant the camera keeps centered on, picked from the selection with F
+#[derive(Resource, Clone, Debug, Default)]
+pub struct CameraFollow {
+    pub target: Option<Entity>,
+}
+
+/// Stable number of a worker, unique within the colony and kept across save/load
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AntId(pub u64);
+
+/// Name of a worker, shown in its tooltip and in the selection panel
+#[derive(Component, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AntName(pub String);
+
+/// Hands out ant ids in order
+#[derive(Resource, Clone, Debug)]
+pub struct AntRegistry {
+    pub next_id: u64,
+}
+
+impl Default for AntRegistry {
+    fn default() -> Self {
+        Self { next_id: 1 }
+    }
+}
+
+/// Rubber band being dragged out for a selection, in world coordinates
+#[derive(Resource, Clone, Debug, Default)]
+pub struct SelectionDrag {
+    pub start: Option<Vec2>, // Where the drag began; None while not dragging
+    pub end: Vec2,           // Latest cursor position of the drag
+}
+
+/// Marker for the panel summarising the selected ants
+#[derive(Component)]
+pub struct SelectionPanel;
+
+/// Marker for the figures inside the selection panel
+#[derive(Component)]
+pub struct SelectionText;
+
+/// Button in the selection panel that starts renaming the one selected ant
+#[derive(Component)]
+pub struct RenameButton;
+
+/// Label of the [`RenameButton`], showing the name as it is typed
+#[derive(Component)]
+pub struct RenameButtonText;
+
+/// Name being typed for an ant from the selection panel
+///
+/// While `ant` is set, typed keys go into `text` instead of triggering shortcuts.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct AntRename {
+    pub ant: Option<Entity>,
+    pub text: String,
+}
+
+/// Everything the keyboard can do, looked up through [`KeyBindings`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InputAction {
+    TogglePause,
+    SetSpeed(u8), // Speed preset 1x-9x
+    MaxSpeed,
+    ToggleTurbo,
+    PanLeft,
+    PanRight,
+    PanUp,
+    PanDown,
+    ZoomIn,
+    ZoomOut,
+    ResetCamera,
+    ToggleAutoDisasters,
+    TriggerDisaster(DisasterType),
+    ToggleDisasterControls,
+    ToggleHelp,
+    ToggleMiniHud,
+    TogglePerformancePanel,
+    ToggleStatistics,
+    ToggleSaveSlots,
+    TogglePheromoneOverlay,
+    ToggleProfiler,
+    ToggleTerritoryOverlay,
+    ToggleColonyComparison,
+    CycleComparisonSort,
+    ToggleSettings,
+    ToggleVisualEffects,
+    ToggleTunnelTool,
+    CycleChamberType,
+    ClearSelection,
+    FollowSelection,
+    NewGame,
+    StressSpawnAnts,
+    StressSpawnFood,
+}
+
+impl InputAction {
+    /// Every action, grouped by category in the order the help overlay lists them
+    pub const ALL: [InputAction; 45] = [
+        InputAction::TogglePause,
+        InputAction::SetSpeed(1),
+        InputAction::SetSpeed(2),
+        InputAction::SetSpeed(3),
+        InputAction::SetSpeed(4),
+        InputAction::SetSpeed(5),
+        InputAction::SetSpeed(6),
+        InputAction::SetSpeed(7),
+        InputAction::SetSpeed(8),
+        InputAction::SetSpeed(9),
+        InputAction::MaxSpeed,
+        InputAction::ToggleTurbo,
+        InputAction::PanLeft,
+        InputAction::PanRight,
+        InputAction::PanUp,
+        InputAction::PanDown,
+        InputAction::ZoomIn,
+        InputAction::ZoomOut,
+        InputAction::ResetCamera,
+        InputAction::ToggleAutoDisasters,
+        InputAction::TriggerDisaster(DisasterType::Rain),
+        InputAction::TriggerDisaster(DisasterType::Drought),
+        InputAction::TriggerDisaster(DisasterType::ColdSnap),
+        InputAction::TriggerDisaster(DisasterType::Heatwave),
+        InputAction::TriggerDisaster(DisasterType::InvasiveSpecies),
+        InputAction::ToggleDisasterControls,
+        InputAction::ToggleHelp,
+        InputAction::ToggleMiniHud,
+        InputAction::TogglePerformancePanel,
+        InputAction::ToggleStatistics,
+        InputAction::ToggleSaveSlots,
+        InputAction::TogglePheromoneOverlay,
+        InputAction::ToggleProfiler,
+        InputAction::ToggleTerritoryOverlay,
+        InputAction::ToggleColonyComparison,
+        InputAction::CycleComparisonSort,
+        InputAction::ToggleSettings,
+        InputAction::ToggleVisualEffects,
+        InputAction::ToggleTunnelTool,
+        InputAction::CycleChamberType,
+        InputAction::ClearSelection,
+        InputAction::FollowSelection,
+        InputAction::NewGame,
+        InputAction::StressSpawnAnts,
+        InputAction::StressSpawnFood,
+    ];
+
+    /// Key the action is bound to out of the box
+    pub fn default_key(&self) -> KeyCode {
+        match self {
+            InputAction::TogglePause => KeyCode::Space,
+            InputAction::SetSpeed(speed) => match speed {
+                1 => KeyCode::Digit1,
+                2 => KeyCode::Digit2,
+                3 => KeyCode::Digit3,
+                4 => KeyCode::Digit4,
+                5 => KeyCode::Digit5,
+                6 => KeyCode::Digit6,
+                7 => KeyCode::Digit7,
+                8 => KeyCode::Digit8,
+                _ => KeyCode::Digit9,
+            },
+            InputAction::MaxSpeed => KeyCode::Digit0,
+            InputAction::ToggleTurbo => KeyCode::KeyT,
+            InputAction::PanLeft => KeyCode::ArrowLeft,
+            InputAction::PanRight => KeyCode::ArrowRight,
+            InputAction::PanUp => KeyCode::ArrowUp,
+            InputAction::PanDown => KeyCode::ArrowDown,
+            InputAction::ZoomIn => KeyCode::Equal,
+            InputAction::ZoomOut => KeyCode::Minus,
+            InputAction::ResetCamera => KeyCode::Home,
+            InputAction::ToggleAutoDisasters => KeyCode::KeyW,
+            InputAction::TriggerDisaster(disaster_type) => match disaster_type {
+                DisasterType::Rain => KeyCode::KeyR,
+                DisasterType::Drought => KeyCode::KeyD,
+                DisasterType::ColdSnap => KeyCode::KeyC,
+                DisasterType::Heatwave => KeyCode::KeyH,
+                DisasterType::InvasiveSpecies => KeyCode::KeyI,
+            },
+            InputAction::ToggleDisasterControls => KeyCode::KeyX,
+            InputAction::ToggleHelp => KeyCode::F1,
+            InputAction::ToggleMiniHud => KeyCode::F2,
+            InputAction::TogglePerformancePanel => KeyCode::F3,
+            InputAction::ToggleStatistics => KeyCode::F4,
+            InputAction::ToggleSaveSlots => KeyCode::F5,
+            InputAction::TogglePheromoneOverlay => KeyCode::F6,
+            InputAction::ToggleProfiler => KeyCode::F7,
+            InputAction::ToggleTerritoryOverlay => KeyCode::F8,
+            InputAction::ToggleColonyComparison => KeyCode::F11,
+            InputAction::CycleComparisonSort => KeyCode::F12,
+            InputAction::ToggleSettings => KeyCode::KeyS,
+            InputAction::ToggleVisualEffects => KeyCode::KeyV,
+            InputAction::ToggleTunnelTool => KeyCode::KeyB,
+            InputAction::CycleChamberType => KeyCode::Tab,
+            InputAction::ClearSelection => KeyCode::Escape,
+            InputAction::FollowSelection => KeyCode::KeyF,
+            InputAction::NewGame => KeyCode::KeyN,
+            InputAction::StressSpawnAnts => KeyCode::F9,
+            InputAction::StressSpawnFood => KeyCode::F10,
+        }
+    }
+
+    /// Localization key of the heading the action is listed under in the help overlay
+    pub fn category_key(&self) -> &'static str {
+        match self {
+            InputAction::TogglePause
+            | InputAction::SetSpeed(_)
+            | InputAction::MaxSpeed
+            | InputAction::ToggleTurbo => "help.category.time",
+            InputAction::PanLeft
+            | InputAction::PanRight
+            | InputAction::PanUp
+            | InputAction::PanDown
+            | InputAction::ZoomIn
+            | InputAction::ZoomOut
+            | InputAction::ResetCamera => "help.category.camera",
+            InputAction::ToggleAutoDisasters
+            | InputAction::TriggerDisaster(_)
+            | InputAction::ToggleDisasterControls => "help.category.disasters",
+            InputAction::ToggleHelp
+            | InputAction::ToggleMiniHud
+            | InputAction::TogglePerformancePanel
+            | InputAction::ToggleStatistics
+            | InputAction::ToggleSaveSlots
+            | InputAction::TogglePheromoneOverlay
+            | InputAction::ToggleProfiler
+            | InputAction::ToggleTerritoryOverlay
+            | InputAction::ToggleColonyComparison
+            | InputAction::CycleComparisonSort
+            | InputAction::ToggleSettings
+            | InputAction::ToggleVisualEffects => "help.category.panels",
+            InputAction::ToggleTunnelTool
+            | InputAction::CycleChamberType
+            | InputAction::ClearSelection
+            | InputAction::FollowSelection
+            | InputAction::NewGame
+            | InputAction::StressSpawnAnts
+            | InputAction::StressSpawnFood => "help.category.tools",
+        }
+    }
+
+    /// Localization key of what the action does, as shown in the help overlay
+    ///
+    /// A speed key's label is followed by its speed, e.g. `Speed 3x`.
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            InputAction::TogglePause => "action.toggle_pause",
+            InputAction::SetSpeed(_) => "action.set_speed",
+            InputAction::MaxSpeed => "action.max_speed",
+            InputAction::ToggleTurbo => "action.toggle_turbo",
+            InputAction::PanLeft => "action.pan_left",
+            InputAction::PanRight => "action.pan_right",
+            InputAction::PanUp => "action.pan_up",
+            InputAction::PanDown => "action.pan_down",
+            InputAction::ZoomIn => "action.zoom_in",
+            InputAction::ZoomOut => "action.zoom_out",
+            InputAction::ResetCamera => "action.reset_camera",
+            InputAction::ToggleAutoDisasters => "action.toggle_auto_disasters",
+            InputAction::TriggerDisaster(disaster_type) => disaster_type.label_key(),
+            InputAction::ToggleDisasterControls => "action.toggle_disaster_controls",
+            InputAction::ToggleHelp => "action.toggle_help",
+            InputAction::ToggleMiniHud => "action.toggle_mini_hud",
+            InputAction::TogglePerformancePanel => "action.toggle_performance_panel",
+            InputAction::ToggleStatistics => "action.toggle_statistics",
+            InputAction::ToggleSaveSlots => "action.toggle_save_slots",
+            InputAction::TogglePheromoneOverlay => "action.toggle_pheromone_overlay",
+            InputAction::ToggleProfiler => "action.toggle_profiler",
+            InputAction::ToggleTerritoryOverlay => "action.toggle_territory_overlay",
+            InputAction::ToggleColonyComparison => "action.toggle_colony_comparison",
+            InputAction::CycleComparisonSort => "action.cycle_comparison_sort",
+            InputAction::ToggleSettings => "action.toggle_settings",
+            InputAction::ToggleVisualEffects => "action.toggle_visual_effects",
+            InputAction::ToggleTunnelTool => "action.toggle_tunnel_tool",
+            InputAction::CycleChamberType => "action.cycle_chamber_type",
+            InputAction::ClearSelection => "action.clear_selection",
+            InputAction::FollowSelection => "action.follow_selection",
+            InputAction::NewGame => "action.new_game",
+            InputAction::StressSpawnAnts => "action.stress_spawn_ants",
+            InputAction::StressSpawnFood => "action.stress_spawn_food",
+        }
+    }
+}
+
+/// Key assigned to each [`InputAction`]; remap an action by replacing its entry
+#[derive(Resource, Clone, Debug)]
+pub struct KeyBindings {
+    pub keys: std::collections::HashMap<InputAction, KeyCode>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            keys: InputAction::ALL
+                .iter()
+                .map(|action| (*action, action.default_key()))
+                .collect(),
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Key currently bound to the action, falling back to its default
+    pub fn key(&self, action: InputAction) -> KeyCode {
+        self.keys
+            .get(&action)
+            .copied()
+            .unwrap_or_else(|| action.default_key())
+    }
+
+    pub fn just_pressed(&self, input: &ButtonInput<KeyCode>, action: InputAction) -> bool {
+        input.just_pressed(self.key(action))
+    }
+
+    pub fn pressed(&self, input: &ButtonInput<KeyCode>, action: InputAction) -> bool {
+        input.pressed(self.key(action))
+    }
+}
+
+/// Marker for the full-screen keyboard shortcut overlay
+#[derive(Component)]
+pub struct HelpOverlay;
+
+/// Marker for the shortcut list inside the help overlay
+#[derive(Component)]
+pub struct HelpOverlayText;
+
+/// When the colony's food reserves count as critically low
+#[derive(Resource, Clone, Debug)]
+pub struct FoodReserveConfig {
+    pub critical_threshold: f32, // Stored carbohydrates below which the warning banner shows
+}
+
+impl Default for FoodReserveConfig {
+    fn default() -> Self {
+        Self {
+            critical_threshold: 20.0,
+        }
+    }
+}
+
+/// State of the low food reserve warning
+#[derive(Resource, Clone, Debug, Default)]
+pub struct FoodReserveWarning {
+    pub dismissed: bool, // Banner closed by the observer; reset once reserves recover
+}
+
+/// Marker for the low food reserve warning banner
+#[derive(Component)]
+pub struct FoodReserveBanner;
+
+/// Marker for the button closing the low food reserve warning banner
+#[derive(Component)]
+pub struct FoodReserveDismissButton;
+
+/// Marker for the per-section profiling overlay
+#[derive(Component)]
+pub struct ProfilerOverlay;
+
+/// Marker for the bar list inside the profiling overlay
+#[derive(Component)]
+pub struct ProfilerText;
+
+/// Marker for the colony comparison panel
+#[derive(Component)]
+pub struct ColonyComparisonPanel;
+
+/// Marker for the row list inside the colony comparison panel
+#[derive(Component)]
+pub struct ColonyComparisonText;
+
+/// Marker component for the detailed performance monitoring panel
+#[derive(Component)]
+pub struct PerformancePanel;
+
+/// Marker component for the FPS text in the performance panel
+#[derive(Component)]
+pub struct FpsText;
+
+/// Marker component for the frame time text in the performance panel
+#[derive(Component)]
+pub struct FrameTimeText;
+
+/// Marker component for the entity count text in the performance panel
+#[derive(Component)]
+pub struct EntityCountText;
+
+/// Marker component for the compact FPS/entity-count HUD
+#[derive(Component)]
+pub struct MiniHud;
+
+/// Marker component for the FPS text in the compact HUD
+#[derive(Component)]
+pub struct MiniHudFpsText;
+
+/// Marker component for the entity count text in the compact HUD
+#[derive(Component)]
+pub struct MiniHudEntityText;
+
+/// Marker component for the simulation speed text in the compact HUD
+#[derive(Component)]
+pub struct MiniHudSpeedText;
+
+/// Last string written into a UI text's first section
+///
+/// Writing through [`DisplayedText::set`] leaves the `Text` untouched, and so skips
+/// re-layout, while the value is the same as last frame.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct DisplayedText(pub String);
+
+impl DisplayedText {
+    /// Write `value` into `text` if it differs from the one shown, returning whether it did
+    pub fn set(&mut self, text: &mut Mut<Text>, value: String) -> bool {
+        if self.0 == value || text.sections.is_empty() {
+            return false;
+        }
+        text.sections[0].value.clone_from(&value);
+        self.0 = value;
+        true
+    }
+}
+
+/// Screen edge a responsive UI panel is anchored to
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PanelAnchor {
+    TopLeft,
+    TopCenter,
+    TopRight,
+    BottomLeft,
+    BottomCenter,
+    BottomRight,
+}
+
+/// UI panel that is re-anchored to the window edges whenever the window is resized
+#[derive(Component, Clone)]
+pub struct ResponsivePanel {
+    pub anchor: PanelAnchor,
+    pub width: f32,  // Preferred width in logical pixels
+    pub margin: f32, // Gap kept between the panel and the window edges
+}
+
+/// UI panel the user can move by dragging its [`PanelTitleBar`]
+///
+/// A dragged panel's position is kept in [`UserSettings`] under its id.
+#[derive(Component, Clone, Debug)]
+pub struct DraggablePanel {
+    pub id: &'static str,
+}
+
+/// Title bar that drags the panel it belongs to
+#[derive(Component, Clone, Debug)]
+pub struct PanelTitleBar {
+    pub panel: Entity,
+}
+
+/// Panel currently being dragged by its title bar
+#[derive(Resource, Clone, Debug, Default)]
+pub struct PanelDrag {
+    pub panel: Option<Entity>, // None while nothing is being dragged
+    pub grab_offset: Vec2,     // Cursor position relative to the panel's top-left corner
+}
+
+/// Top-left corner a panel was dragged to, in logical pixels from the window's top-left
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PanelPosition {
+    pub panel: String,
+    pub left: f32,
+    pub top: f32,
+}
+
+/// Ant aging and hunger state
+#[derive(Component, Clone, Serialize, Deserialize)]
+pub struct Lifecycle {
+    pub age: f32,     // Age in seconds
+    pub max_age: f32, // Natural lifespan in seconds
+    pub energy: f32,  // Hunger reserve; the ant starves at 0
+    pub max_energy: f32,
+}
+
+/// Physical condition of an ant, kept apart from hunger
+///
+/// Fights and other injuries take HP while starvation only drains `Lifecycle.energy`;
+/// the ant dies when either runs out.
+#[derive(Component, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Health {
+    pub hp: f32,
+    pub max_hp: f32,
+}
+
+impl Health {
+    pub fn new(max_hp: f32) -> Self {
+        Self { hp: max_hp, max_hp }
+    }
+
+    pub fn damage(&mut self, amount: f32) {
+        self.hp = (self.hp - amount).max(0.0);
+    }
+
+    pub fn heal(&mut self, amount: f32) {
+        self.hp = (self.hp + amount).min(self.max_hp);
+    }
+
+    pub fn is_dead(&self) -> bool {
+        self.hp <= 0.0
+    }
+}
+
+/// Short-term endurance of a worker, spent while walking and regained at rest
+///
+/// Unlike `Lifecycle.energy` it is not food: a well-fed ant still has to stop and
+/// recover after a long trip.
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct Stamina {
+    pub current: f32,
+    pub max: f32,
+    pub exhausted: bool, // Forced to rest until mostly recovered
+}
+
+impl Stamina {
+    pub fn new(max: f32) -> Self {
+        Self {
+            current: max,
+            max,
+            exhausted: false,
+        }
+    }
+
+    pub fn spend(&mut self, amount: f32) {
+        self.current = (self.current - amount).max(0.0);
+    }
+
+    pub fn recover(&mut self, amount: f32) {
+        self.current = (self.current + amount).min(self.max);
+    }
+
+    /// Remaining stamina as a share of the maximum (0.0 - 1.0)
+    pub fn fraction(&self) -> f32 {
+        if self.max > 0.0 {
+            self.current / self.max
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Age groups used for age-based division of labor
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AntAgeGroup {
+    Young,  // Callow workers that stay in the nest
+    Adult,  // General workers
+    Senior, // Experienced workers that forage outside
+}
+
+impl AntAgeGroup {
+    /// Classify an ant by how far through its lifespan it is (0.0 - 1.0)
+    pub fn from_age_ratio(age_ratio: f32) -> Self {
+        if age_ratio < 0.3 {
+            AntAgeGroup::Young
+        } else if age_ratio < 0.7 {
+            AntAgeGroup::Adult
+        } else {
+            AntAgeGroup::Senior
+        }
+    }
+}
+
+/// Behavior state that depends on the colony's development phase and the ant's age
+#[derive(Component, Clone)]
+pub struct PhaseSpecificBehavior {
+    pub age_group: AntAgeGroup,
+}
+
+/// Unmodified sprite color of an ant; visual modifiers are blended on top of it
+#[derive(Component, Clone, Copy)]
+pub struct BaseColor(pub Color);
+
+/// Dot drawn above an ant showing the kind of food it carries, as a child of the ant
+#[derive(Component)]
+pub struct CarryIndicator;
+
+/// Toggles for optional visual effects (accessibility)
+#[derive(Resource, Clone)]
+pub struct VisualEffectsSettings {
+    pub age_indicators_enabled: bool,
+    pub particles_enabled: bool,
+    pub overlays_enabled: bool,
+    pub reduced_motion: bool, // Cues fade in place instead of expanding
+    pub carry_indicators_enabled: bool, // Tint and dot showing the food kind an ant carries
+}
+
+impl Default for VisualEffectsSettings {
+    fn default() -> Self {
+        Self {
+            age_indicators_enabled: true,
+            particles_enabled: true,
+            overlays_enabled: true,
+            reduced_motion: false,
+            carry_indicators_enabled: true,
+        }
+    }
+}
+
+impl VisualEffectsSettings {
+    /// Toggle every visual effect on or off together
+    pub fn toggle_all(&mut self) {
+        let enabled = !self.age_indicators_enabled;
+        self.age_indicators_enabled = enabled;
+        self.particles_enabled = enabled;
+        self.overlays_enabled = enabled;
+        self.carry_indicators_enabled = enabled;
+    }
+}
+
+/// Heritable colony characteristics carried by a founding queen
+///
+/// Every trait is a multiplier within `ColonyTraits::MIN..=ColonyTraits::MAX`,
+/// where 1.0 is an average colony.
+#[derive(Component, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ColonyTraits {
+    pub queen_vigor: f32,              // Egg-laying rate and founding resilience
+    pub worker_efficiency: f32,        // Worker speed and foraging success
+    pub architectural_skill: f32,      // Digging speed and nest quality
+    pub environmental_adaptation: f32, // Tolerance to harsh soil conditions
+}
+
+impl ColonyTraits {
+    pub const MIN: f32 = 0.5;
+    pub const MAX: f32 = 1.5;
+
+    /// Roll a brand-new set of traits for a colony with no lineage
+    pub fn generate_random(rng: &mut impl rand::Rng) -> Self {
+        Self {
+            queen_vigor: rng.gen_range(0.7..1.3),
+            worker_efficiency: rng.gen_range(0.7..1.3),
+            architectural_skill: rng.gen_range(0.7..1.3),
+            environmental_adaptation: rng.gen_range(0.7..1.3),
+        }
+    }
+}
+
+/// Food the colony has stockpiled in the nest
+#[derive(Resource, Clone)]
+pub struct ColonyFoodStore {
+    pub food: f32,    // Carbohydrates, burned by adult workers for energy
+    pub protein: f32, // Reserved for brood development
+}
+
+impl Default for ColonyFoodStore {
+    fn default() -> Self {
+        Self {
+            food: 100.0, // Queen's founding reserves
+            protein: 20.0,
+        }
+    }
+}
+
+impl ColonyFoodStore {
+    /// The nutrient the colony is shorter on, which foragers favour
+    pub fn scarce_kind(&self) -> FoodKind {
+        if self.protein < self.food {
+            FoodKind::Protein
+        } else {
+            FoodKind::Carbohydrate
+        }
+    }
+
+    pub fn add(&mut self, kind: FoodKind, amount: f32) {
+        match kind {
+            FoodKind::Protein => self.protein += amount,
+            FoodKind::Carbohydrate => self.food += amount,
+        }
+    }
+}
+
+/// Living space the colony has excavated
+#[derive(Resource, Default, Clone)]
+pub struct NestSpace {
+    pub excavated_cells: usize,
+}
+
+/// Population limit derived from food and nest space, with its starvation response
+#[derive(Resource, Clone)]
+pub struct CarryingCapacity {
+    pub food_per_ant: f32,            // Stored food needed to sustain one ant
+    pub cells_per_ant: f32,           // Excavated soil cells needed to house one ant
+    pub base_space: f32,              // Ants that can shelter without any excavation
+    pub overshoot_drain_factor: f32,  // Extra energy drain per unit of relative overshoot
+    pub overshoot_death_rate: f32,    // Extra death chance per second per unit of overshoot
+    pub capacity: f32,                // Current carrying capacity (derived)
+    pub energy_drain_multiplier: f32, // Current colony-wide drain multiplier (derived)
+}
+
+impl Default for CarryingCapacity {
+    fn default() -> Self {
+        Self {
+            food_per_ant: 5.0,
+            cells_per_ant: 0.5,
+            base_space: 10.0,
+            overshoot_drain_factor: 2.0,
+            overshoot_death_rate: 0.05,
+            capacity: 0.0,
+            energy_drain_multiplier: 1.0,
+        }
+    }
+}
+
+impl CarryingCapacity {
+    /// Population the colony can sustain with the given food and excavated space
+    pub fn compute(&self, food: f32, excavated_cells: usize) -> f32 {
+        let food_limit = food / self.food_per_ant;
+        let space_limit = self.base_space + excavated_cells as f32 / self.cells_per_ant;
+        food_limit.min(space_limit).max(0.0)
+    }
+}
+
+/// Fonts the UI panels are built with, and whether they are ready
+///
+/// Panels are only built once `ready` is set, so a custom font that is still loading
+/// never shows up as blank text. A font that fails to load falls back to
+/// `fallback_font_path`, then to the built-in font.
+#[derive(Resource, Clone, Default)]
+pub struct UiAssets {
+    pub font_path: Option<String>, // Custom UI font; None uses the built-in font
+    pub fallback_font_path: Option<String>, // Tried when the custom font fails to load
+    pub font: Handle<Font>,        // Font applied to UI text once ready
+    pub ready: bool,               // Font loaded, or settled on a fallback
+    pub panels_built: bool,        // Deferred panel setup has run
+}
+
+/// Shared visual design system for all UI panels
+///
+/// Themes can be shared as JSON through `export_theme` and `import_theme`.
+#[derive(Resource, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UITheme {
+    pub colors: ColorPalette,
+    pub typography: Typography,
+    pub spacing: Spacing,
+    pub borders: Borders,
+}
+
+/// UI color palette
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ColorPalette {
+    pub surface_primary: Color,
+    pub surface_secondary: Color,
+    pub surface_elevated: Color,
+    pub text_primary: Color,
+    pub text_secondary: Color,
+    pub text_muted: Color,
+    pub border_primary: Color,
+    pub border_secondary: Color,
+    pub border_focus: Color,
+    pub action_primary: Color,
+    pub action_success: Color,
+    pub action_danger: Color,
+    pub accent_green: Color,
+}
+
+/// UI font sizes
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Typography {
+    pub heading_medium: f32,
+    pub heading_small: f32,
+    pub body_large: f32,
+    pub body_medium: f32,
+    pub body_small: f32,
+}
+
+/// UI spacing scale in pixels
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Spacing {
+    pub xs: f32,
+    pub sm: f32,
+    pub md: f32,
+    pub lg: f32,
+}
+
+/// UI border widths and corner radii in pixels
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Borders {
+    pub width_thin: f32,
+    pub width_medium: f32,
+    pub radius_small: f32,
+    pub radius_medium: f32,
+}
+
+impl Default for UITheme {
+    fn default() -> Self {
+        Self {
+            colors: ColorPalette {
+                surface_primary: Color::srgba(0.08, 0.08, 0.1, 0.9),
+                surface_secondary: Color::srgba(0.14, 0.14, 0.17, 0.9),
+                surface_elevated: Color::srgba(0.2, 0.2, 0.24, 0.95),
+                text_primary: Color::srgb(0.95, 0.95, 0.95),
+                text_secondary: Color::srgb(0.75, 0.75, 0.78),
+                text_muted: Color::srgb(0.5, 0.5, 0.55),
+                border_primary: Color::srgb(0.3, 0.3, 0.35),
+                border_secondary: Color::srgb(0.22, 0.22, 0.26),
+                border_focus: Color::srgb(0.4, 0.6, 1.0),
+                action_primary: Color::srgb(0.25, 0.45, 0.85),
+                action_success: Color::srgb(0.2, 0.65, 0.3),
+                action_danger: Color::srgb(0.8, 0.25, 0.25),
+                accent_green: Color::srgb(0.4, 0.8, 0.4),
+            },
+            typography: Typography {
+                heading_medium: 20.0,
+                heading_small: 16.0,
+                body_large: 15.0,
+                body_medium: 14.0,
+                body_small: 12.0,
+            },
+            spacing: Spacing {
+                xs: 2.0,
+                sm: 4.0,
+                md: 8.0,
+                lg: 16.0,
+            },
+            borders: Borders {
+                width_thin: 1.0,
+                width_medium: 2.0,
+                radius_small: 3.0,
+                radius_medium: 6.0,
+            },
+        }
+    }
+}
+
+/// Where a tooltip appears relative to its target
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TooltipPosition {
+    Above,
+    Below,
+    Left,
+    Right,
+}
+
+/// Tooltip content attached to a UI node or a world entity
+#[derive(Component, Clone)]
+pub struct Tooltip {
+    pub text: String,
+    pub shortcut: Option<String>,
+    pub position: TooltipPosition,
+}
+
+/// Hover tracking for an element that can show a tooltip
+#[derive(Component, Clone)]
+pub struct TooltipTrigger {
+    pub is_hovered: bool,
+    pub hover_timer: f32,
+    pub show_delay: f32, // Seconds of hovering before the tooltip appears
+}
+
+impl Default for TooltipTrigger {
+    fn default() -> Self {
+        Self {
+            is_hovered: false,
+            hover_timer: 0.0,
+            show_delay: 0.5,
+        }
+    }
+}
+
+/// Marker component for a currently displayed tooltip node
+#[derive(Component)]
+pub struct TooltipDisplay;
+
+/// Kinds of excavated nest chambers
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChamberType {
+    Queen,
+    Nursery,
+    FoodStorage,
+    Worker,
+    Waste,
+}
+
+impl ChamberType {
+    pub const ALL: [ChamberType; 5] = [
+        ChamberType::Queen,
+        ChamberType::Nursery,
+        ChamberType::FoodStorage,
+        ChamberType::Worker,
+        ChamberType::Waste,
+    ];
+
+    /// Chamber types a player can place with the tunnel drawing tool
+    pub const DRAWABLE: [ChamberType; 4] = [
+        ChamberType::Nursery,
+        ChamberType::FoodStorage,
+        ChamberType::Worker,
+        ChamberType::Waste,
+    ];
+
+    /// Next drawable chamber type, wrapping around
+    pub fn next_drawable(self) -> ChamberType {
+        let index = Self::DRAWABLE
+            .iter()
+            .position(|t| *t == self)
+            .map_or(0, |i| i + 1);
+        Self::DRAWABLE[index % Self::DRAWABLE.len()]
+    }
+
+    /// Color identifying the chamber type, also worn by the ants tied to it
+    pub fn color(self) -> Color {
+        match self {
+            ChamberType::Queen => Color::srgb(0.55, 0.2, 0.6), // Royal purple
+            ChamberType::Nursery => Color::srgb(0.9, 0.85, 0.6),
+            ChamberType::FoodStorage => Color::srgb(0.4, 0.7, 0.25),
+            ChamberType::Worker => Color::srgb(0.5, 0.35, 0.2),
+            ChamberType::Waste => Color::srgb(0.35, 0.3, 0.25),
+        }
+    }
+}
+
+/// The founding chamber an established queen is confined to
+#[derive(Component, Clone, Serialize, Deserialize)]
+pub struct RoyalChamber {
+    pub center: Position,
+    pub radius: f32,
+}
+
+/// An excavated chamber inside the nest
+#[derive(Component, Clone)]
+pub struct Chamber {
+    pub chamber_type: ChamberType,
+    pub radius: f32,
+    pub capacity: usize,  // Ants the chamber comfortably holds
+    pub occupants: usize, // Ants currently inside
+    pub temperature: f32, // Internal microclimate in °C
+}
+
+impl Chamber {
+    /// Human-readable chamber name for UI display
+    pub fn display_name(&self) -> &'static str {
+        match self.chamber_type {
+            ChamberType::Queen => "Royal Chamber",
+            ChamberType::Nursery => "Nursery",
+            ChamberType::FoodStorage => "Food Storage",
+            ChamberType::Worker => "Worker Quarters",
+            ChamberType::Waste => "Waste Dump",
+        }
+    }
+
+    /// Fraction of the chamber's capacity currently in use
+    pub fn capacity_usage(&self) -> f32 {
+        if self.capacity == 0 {
+            return 1.0;
+        }
+        self.occupants as f32 / self.capacity as f32
+    }
+}
+
+/// A designated site where workers are excavating a new chamber
+#[derive(Component, Clone, Debug)]
+pub struct ChamberConstruction {
+    pub chamber_type: ChamberType,
+    pub radius: f32,
+    pub capacity: usize,
+    pub progress: f32, // 0.0 (just marked) to 1.0 (ready to use)
+}
+
+/// Assignment of a nest maintainer to dig out a chamber site
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ExcavationTask {
+    pub site: Entity,
+}
+
+/// When and how the colony plans new chambers
+#[derive(Resource, Clone, Debug)]
+pub struct NestPlanningConfig {
+    pub saturation_threshold: f32, // Chamber usage at which a type counts as full
+    pub min_workers: usize,        // Workers needed before the colony expands
+    pub min_food_reserve: f32,     // Stored food needed to afford the effort
+    pub chamber_radius: f32,       // Radius of a new chamber at average skill
+    pub chamber_capacity: usize,   // Capacity of a new chamber at average skill
+    pub site_spacing: f32,         // Gap left between a new chamber and its neighbors
+    pub diggers_per_site: usize,   // Maintainers assigned to each site
+    pub dig_rate: f32,             // Progress per second each digger adds at average skill
+    pub max_branches: usize,       // Tunnels meeting at one chamber at average skill
+    pub min_branch_angle: f32,     // Radians between two tunnels leaving a chamber at average skill
+    pub shallow_fork_chance: f32,  // Chance a chamber near the surface forks into a new branch
+    pub deep_fork_chance: f32,     // Chance a chamber at `branch_depth` or deeper forks
+    pub branch_depth: f32,         // Depth over which the fork chance falls from shallow to deep
+}
+
+impl Default for NestPlanningConfig {
+    fn default() -> Self {
+        Self {
+            saturation_threshold: 1.0,
+            min_workers: 10,
+            min_food_reserve: 50.0,
+            chamber_radius: 6.0,
+            chamber_capacity: 8,
+            site_spacing: 4.0,
+            diggers_per_site: 4,
+            dig_rate: 0.02,
+            max_branches: 3,
+            min_branch_angle: 0.7,
+            shallow_fork_chance: 0.8,
+            deep_fork_chance: 0.2,
+            branch_depth: 100.0,
+        }
+    }
+}
+
+impl NestPlanningConfig {
+    /// Most tunnels a chamber may have; skilled architects manage busier junctions
+    pub fn max_branches_for(&self, skill: f32) -> usize {
+        (self.max_branches as f32 * skill).round().max(1.0) as usize
+    }
+
+    /// Smallest angle between two tunnels leaving a chamber; skilled architects spread them wider
+    pub fn min_branch_angle_for(&self, skill: f32) -> f32 {
+        self.min_branch_angle * skill
+    }
+
+    /// Chance a chamber at `depth` below the surface forks, so shallow tunnels spread and
+    /// deep ones narrow
+    pub fn fork_chance(&self, depth: f32, skill: f32) -> f32 {
+        let t = (depth / self.branch_depth).clamp(0.0, 1.0);
+        let chance =
+            self.shallow_fork_chance + (self.deep_fork_chance - self.shallow_fork_chance) * t;
+        (chance * skill).clamp(0.0, 1.0)
+    }
+}
+
+/// A junction in the tunnel graph, linked to the nodes a tunnel runs to
+#[derive(Component, Clone, Debug, Default)]
+pub struct TunnelNode {
+    pub neighbors: Vec<Entity>,
+    pub node_type: TunnelNodeType,
+}
+
+/// What a tunnel node sits at
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TunnelNodeType {
+    #[default]
+    Junction, // Plain bend or fork along a tunnel
+    ChamberEntrance, // Node where a tunnel opens into a chamber
+}
+
+impl TunnelNode {
+    /// Record a link to another node, ignoring duplicates
+    pub fn link(&mut self, other: Entity) {
+        if !self.neighbors.contains(&other) {
+            self.neighbors.push(other);
+        }
+    }
+}
+
+/// A straight tunnel segment between two linked nodes
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Tunnel {
+    pub from: Entity,
+    pub to: Entity,
+    pub width: f32, // Drawn thickness of the segment
+}
+
+/// Chamber radius a nest shape was last drawn with, so it is only rebuilt on change
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct NestShape {
+    pub radius: f32,
+}
+
+/// Marks a tunnel segment drawn between its nodes with no soil left at either end
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct DugTunnel;
+
+/// The interactive tunnel drawing tool and the drag in progress
+#[derive(Resource, Clone, Debug)]
+pub struct TunnelDrawTool {
+    pub enabled: bool,
+    pub chamber_type: ChamberType, // Type placed by a double-click
+    pub node_spacing: f32,         // Distance between nodes laid along a drag
+    pub tunnel_width: f32,         // Soil within half this width of a node is dug out
+    pub double_click_window: f32,  // Seconds between clicks that count as a double-click
+    pub last_node: Option<Entity>, // Node the current drag continues from
+    pub last_point: Option<Vec2>,  // Where that node was laid
+    pub last_click_time: f32,      // Elapsed seconds at the previous click
+}
+
+impl Default for TunnelDrawTool {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            chamber_type: ChamberType::Nursery,
+            node_spacing: 4.0, // One soil cell
+            tunnel_width: 4.0,
+            double_click_window: 0.3,
+            last_node: None,
+            last_point: None,
+            last_click_time: f32::NEG_INFINITY,
+        }
+    }
+}
+
+/// High-level activity of a worker ant
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AntState {
+    Foraging,  // Searching for food on the surface
+    Idle,      // Satiated, milling about near home while the colony is well fed
+    Returning, // Carrying food back to the nest
+    Resting,   // Staying put inside the nest
+    Defending, // Answering an alarm by closing in on a threat
+}
+
+/// Foraging behavior state of a worker ant
+#[derive(Component, Clone, Serialize, Deserialize)]
+pub struct AntBehavior {
+    pub state: AntState,
+    pub speed: f32, // World units per second
+    pub target_position: Option<Position>,
+    pub home_position: Position,
+    pub carried_food: f32,
+    pub carried_kind: FoodKind,
+    pub task_dwell: f32, // Seconds left before the ant may switch between foraging and idling
+    pub heading: f32,    // Direction of travel in radians, kept between smooth-wander targets
+}
+
+impl AntBehavior {
+    /// Behavior of a fresh forager setting off from `home_position` along `heading`
+    pub fn new(home_position: Position, heading: f32) -> Self {
+        Self {
+            state: AntState::Foraging,
+            speed: 10.0,
+            target_position: None,
+            home_position,
+            carried_food: 0.0,
+            carried_kind: FoodKind::Carbohydrate,
+            task_dwell: 0.0,
+            heading,
+        }
+    }
+}
+
+/// Marker for foragers that explore widely instead of exploiting known trails
+#[derive(Component)]
+pub struct Scout;
+
+/// Forager pulled from the colony toward a rich food find
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Recruited {
+    pub source: Entity,
+}
+
+/// A recruitment burst toward one rich food source
+#[derive(Clone)]
+pub struct RecruitmentWave {
+    pub source: Entity,
+    pub location: Position, // Where the food was found
+    pub home: Position,     // Nest the recruits are drawn from
+}
+
+/// Rich food finds the colony is currently mass-recruiting to
+#[derive(Resource, Clone, Default)]
+pub struct RecruitmentWaves {
+    pub waves: Vec<RecruitmentWave>,
+}
+
+impl RecruitmentWaves {
+    /// Start a wave toward a source unless one is already running; returns whether it started
+    pub fn announce(&mut self, source: Entity, location: Position, home: Position) -> bool {
+        if self.is_recruiting(source) {
+            return false;
+        }
+        self.waves.push(RecruitmentWave {
+            source,
+            location,
+            home,
+        });
+        true
+    }
+
+    pub fn is_recruiting(&self, source: Entity) -> bool {
+        self.waves.iter().any(|wave| wave.source == source)
+    }
+}
+
+/// Nutrient a food source provides
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FoodKind {
+    Protein,      // Insect prey, fed to the brood
+    Carbohydrate, // Honeydew and nectar, fuel for workers
+}
+
+impl FoodKind {
+    /// Color marking an ant that carries this kind of food
+    pub fn carry_color(self) -> Color {
+        match self {
+            FoodKind::Protein => Color::srgb(0.85, 0.25, 0.2), // Reddish prey
+            FoodKind::Carbohydrate => Color::srgb(0.95, 0.8, 0.25), // Yellowish honeydew
+        }
+    }
+}
+
+/// Harvestable food on the surface
+#[derive(Component, Clone)]
+pub struct FoodSource {
+    pub nutrition_value: f32, // Food remaining
+    pub max_nutrition: f32,
+    pub kind: FoodKind,
+}
+
+/// Batch sizes of the stress test spawn shortcuts, and where bulk food lands
+#[derive(Resource, Clone, Debug)]
+pub struct StressSpawnConfig {
+    pub ant_batch: usize,           // Ants added per F9 press
+    pub food_batch: usize,          // Food sources added per F10 press
+    pub food_half_width: f32,       // Food lands within ± this of the nest entrance
+    pub food_nutrition: (f32, f32), // Nutrition range of each bulk food source
+    pub protein_share: f32,         // Chance a bulk food source is insect prey
+}
+
+impl Default for StressSpawnConfig {
+    fn default() -> Self {
+        Self {
+            ant_batch: 1000,
+            food_batch: 100,
+            food_half_width: 90.0,
+            food_nutrition: (30.0, 80.0),
+            protein_share: 1.0 / 3.0, // As with the startup food sources
+        }
+    }
+}
+
+/// Settings for food springing up from fertile soil and withering on depleted soil
+#[derive(Resource, Clone, Debug)]
+pub struct FoodSpawningConfig {
+    pub check_interval: f32,     // Seconds between soil surveys
+    pub check_timer: f32,        // Seconds since the last survey
+    pub samples: usize,          // Topsoil cells sampled per survey
+    pub spawn_chance: f32,       // Chance a perfectly fertile sample sprouts food
+    pub min_nutrition: f32,      // Soil nutrition needed for food to sprout
+    pub min_moisture: f32,       // Soil moisture needed for food to sprout
+    pub depleted_nutrition: f32, // Below this nutrition food above the soil withers
+    pub wither_rate: f32,        // Food lost per second on depleted soil
+    pub column_radius: f32,      // Horizontal reach of a sampled cell under a food source
+    pub max_food_sources: usize, // No new food beyond this many sources
+}
+
+impl Default for FoodSpawningConfig {
+    fn default() -> Self {
+        Self {
+            check_interval: 5.0,
+            check_timer: 0.0,
+            samples: 20,
+            spawn_chance: 0.05,
+            min_nutrition: 0.5,
+            min_moisture: 0.3,
+            depleted_nutrition: 0.15,
+            wither_rate: 1.0,
+            column_radius: 4.0,
+            max_food_sources: 20,
+        }
+    }
+}
+
+/// Tunable parameters for worker ant decision making
+#[derive(Resource, Clone)]
+pub struct AntAiConfig {
+    pub scout_fraction: f32,     // Share of foragers acting as scouts (0.0 - 1.0)
+    pub forage_range: f32,       // Random target range for trail-exploiting foragers
+    pub scout_range: f32,        // Random target range for scouts
+    pub wander_mode: WanderMode, // How foragers with nothing better to do pick where to walk
+    pub wander_turn_rate: f32, // Largest heading change per second while wandering smoothly (radians)
+    pub wander_step: f32,      // Distance ahead of the ant a smooth-wander target is placed
+    pub pickup_radius: f32,    // Distance at which an ant can take food
+    pub detection_radius: f32, // Distance at which a forager spots food and heads for it
+    pub carry_capacity: f32,   // Food carried per trip
+    pub trail_deposit: f32,    // Pheromone laid per second by returning foragers
+    pub scout_trail_deposit: f32, // Stronger recruitment trail laid by successful scouts
+    pub trail_follow_threshold: f32, // Minimum pheromone an exploiter will follow
+    pub antennation_radius: f32, // Contact distance for antennal information exchange
+    pub antennation_cooldown: f32, // Seconds before an ant exchanges information again
+    pub alarm_detection_radius: f32, // Distance at which an ant spots an invader
+    pub alarm_emission_rate: f32, // Alarm pheromone released per second by an ant facing a threat
+    pub alarm_threshold: f32,  // Alarm concentration an ant reacts to
+    pub preferred_moisture_min: f32, // Driest soil an ant is comfortable in
+    pub preferred_moisture_max: f32, // Wettest soil an ant is comfortable in
+    pub humidity_sense_radius: f32, // Distance at which an ant compares soil moisture
+    pub humidity_drift_speed: f32, // Drift speed per unit of moisture outside the band
+    pub recruitment_threshold: f32, // Food left in a source that makes it worth a recruitment wave
+    pub recruits_per_threshold: f32, // Foragers recruited per threshold's worth of food left
+    pub max_recruits: usize,   // Upper limit on foragers recruited to one source
+    pub recruitment_deposit: f32, // Pheromone per second along the column at threshold richness
+    pub max_stamina: f32,      // Stamina of a fully rested worker
+    pub stamina_drain_rate: f32, // Stamina spent per second of walking
+    pub stamina_recovery_rate: f32, // Stamina regained per second of rest
+    pub fatigue_threshold: f32, // Stamina fraction below which an ant starts to slow down
+    pub exhausted_speed_factor: f32, // Speed multiplier as stamina runs out
+    pub stamina_resume_fraction: f32, // Stamina fraction an exhausted ant waits for before moving on
+    pub idle_food_reserve: f32, // Colony carbohydrate store at which satiated ants stop ranging out
+    pub idle_energy_fraction: f32, // Share of max energy at which a forager counts as satiated
+    pub forage_energy_fraction: f32, // Share of max energy below which an idle ant forages again
+    pub task_min_dwell: f32,    // Seconds an ant keeps to foraging or idling before switching
+    pub idle_radius: f32,       // How far from home idle ants wander
+    pub idle_speed_factor: f32, // Speed multiplier while milling about
+    pub breadcrumb_spacing: f32, // Distance walked out between recorded waypoints
+    pub breadcrumb_capacity: usize, // Waypoints kept per trip; the oldest are dropped first
+    pub breadcrumb_home_radius: f32, // Distance from home within which returners head straight in
+    pub max_foraging_radius: f32, // Farthest from home a mature colony's foragers range
+}
+
+/// Random walk used by foragers that have no food, memory or trail to go for
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WanderMode {
+    FarTarget, // Head for a random point anywhere within the forage range
+    #[default]
+    Smooth, // Correlated random walk: keep a heading that slowly turns
+}
+
+impl AntAiConfig {
+    /// Speed multiplier for an ant with the given stamina fraction
+    ///
+    /// Full speed above the fatigue threshold, easing down to `exhausted_speed_factor`
+    /// as stamina reaches zero.
+    pub fn stamina_speed_factor(&self, stamina_fraction: f32) -> f32 {
+        if stamina_fraction >= self.fatigue_threshold {
+            return 1.0;
+        }
+        let t = (stamina_fraction / self.fatigue_threshold.max(f32::EPSILON)).clamp(0.0, 1.0);
+        self.exhausted_speed_factor + (1.0 - self.exhausted_speed_factor) * t
+    }
+
+    /// Farthest from home foragers may range while the colony is in `phase`
+    pub fn foraging_radius(&self, phase: DevelopmentPhase) -> f32 {
+        self.max_foraging_radius * phase.foraging_range_scale()
+    }
+
+    /// How far a moisture level lies outside the preferred band (0.0 inside it)
+    pub fn moisture_discomfort(&self, moisture: f32) -> f32 {
+        if moisture < self.preferred_moisture_min {
+            self.preferred_moisture_min - moisture
+        } else if moisture > self.preferred_moisture_max {
+            moisture - self.preferred_moisture_max
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Default for AntAiConfig {
+    fn default() -> Self {
+        Self {
+            scout_fraction: 0.2,
+            forage_range: 30.0,
+            scout_range: 90.0,
+            wander_mode: WanderMode::Smooth,
+            wander_turn_rate: 1.5,
+            wander_step: 4.0,
+            pickup_radius: 3.0,
+            detection_radius: 10.0,
+            carry_capacity: 5.0,
+            trail_deposit: 1.0,
+            scout_trail_deposit: 4.0,
+            trail_follow_threshold: 0.1,
+            antennation_radius: 1.5,
+            antennation_cooldown: 2.0,
+            alarm_detection_radius: 10.0,
+            alarm_emission_rate: 5.0,
+            alarm_threshold: 0.02,
+            preferred_moisture_min: 0.3,
+            preferred_moisture_max: 0.7,
+            humidity_sense_radius: 12.0,
+            humidity_drift_speed: 20.0,
+            recruitment_threshold: 50.0,
+            recruits_per_threshold: 5.0,
+            max_recruits: 20,
+            recruitment_deposit: 3.0,
+            max_stamina: 100.0,
+            stamina_drain_rate: 4.0,
+            stamina_recovery_rate: 10.0,
+            fatigue_threshold: 0.3,
+            exhausted_speed_factor: 0.4,
+            stamina_resume_fraction: 0.9,
+            idle_food_reserve: 150.0,
+            idle_energy_fraction: 0.7,
+            forage_energy_fraction: 0.4,
+            task_min_dwell: 2.0,
+            idle_radius: 6.0,
+            idle_speed_factor: 0.3,
+            breadcrumb_spacing: 6.0,
+            breadcrumb_capacity: 32,
+            breadcrumb_home_radius: 8.0,
+            max_foraging_radius: 150.0,
+        }
+    }
+}
+
+/// Debug overlay drawing trail pheromone strength per grid cell
+#[derive(Resource, Clone, Debug)]
+pub struct PheromoneOverlay {
+    pub enabled: bool,
+    pub saturation: f32,                 // Strength drawn at full opacity
+    pub max_alpha: f32,                  // Opacity of a saturated cell
+    pub update_interval: f32,            // Seconds between rebuilds of the drawn cells
+    pub update_timer: f32,               // Seconds since the last rebuild
+    pub visible_cells: Vec<(Vec2, f32)>, // Centers and intensities of the cells being drawn
+}
+
+impl Default for PheromoneOverlay {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            saturation: 5.0,
+            max_alpha: 0.6,
+            update_interval: 0.25,
+            update_timer: 0.0,
+            visible_cells: Vec::new(),
+        }
+    }
+}
+
+/// Grid of trail pheromone concentrations laid by returning foragers
+#[derive(Resource, Clone)]
+pub struct PheromoneGrid {
+    pub cell_size: f32,
+    pub cells: std::collections::HashMap<(i32, i32), f32>,
+    pub decay_rate: f32, // Fraction evaporating per second
+}
+
+impl Default for PheromoneGrid {
+    fn default() -> Self {
+        Self {
+            cell_size: 4.0,
+            cells: std::collections::HashMap::new(),
+            decay_rate: 0.05,
+        }
+    }
+}
+
+impl PheromoneGrid {
+    pub fn cell_of(&self, position: &Position) -> (i32, i32) {
+        (
+            (position.x / self.cell_size).floor() as i32,
+            (position.y / self.cell_size).floor() as i32,
+        )
+    }
+
+    pub fn cell_center(&self, cell: (i32, i32)) -> Position {
+        Position {
+            x: (cell.0 as f32 + 0.5) * self.cell_size,
+            y: (cell.1 as f32 + 0.5) * self.cell_size,
+        }
+    }
+
+    pub fn deposit(&mut self, position: &Position, amount: f32) {
+        *self.cells.entry(self.cell_of(position)).or_insert(0.0) += amount;
+    }
+
+    pub fn concentration(&self, position: &Position) -> f32 {
+        self.cells
+            .get(&self.cell_of(position))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// Pheromone in the eight cells surrounding a position
+    pub fn neighbors(&self, position: &Position) -> Vec<((i32, i32), f32)> {
+        let (cx, cy) = self.cell_of(position);
+        let mut neighbors = Vec::with_capacity(8);
+
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                if dx == 0 && dy == 0 {
+                    continue;
+                }
+                let cell = (cx + dx, cy + dy);
+                if let Some(&strength) = self.cells.get(&cell) {
+                    neighbors.push((cell, strength));
+                }
+            }
+        }
+
+        neighbors
+    }
+
+    /// Share a fraction of each cell's pheromone evenly with its eight neighbors
+    pub fn diffuse(&mut self, rate: f32, delta_time: f32) {
+        let share = (rate * delta_time).clamp(0.0, 0.5);
+        let mut next = self.cells.clone();
+
+        for (&(x, y), &strength) in self.cells.iter() {
+            let outflow = strength * share;
+            *next.entry((x, y)).or_insert(0.0) -= outflow;
+            for dx in -1..=1 {
+                for dy in -1..=1 {
+                    if dx != 0 || dy != 0 {
+                        *next.entry((x + dx, y + dy)).or_insert(0.0) += outflow / 8.0;
+                    }
+                }
+            }
+        }
+
+        self.cells = next;
+    }
+
+    /// Evaporate pheromone and forget cells that have faded out
+    pub fn decay(&mut self, delta_time: f32) {
+        let retain = (1.0 - self.decay_rate * delta_time).max(0.0);
+        self.cells.retain(|_, strength| {
+            *strength *= retain;
+            *strength > 0.001
+        });
+    }
+}
+
+/// Fast-spreading, fast-fading alarm pheromone released when a threat is detected
+#[derive(Resource, Clone)]
+pub struct AlarmPheromoneGrid {
+    pub grid: PheromoneGrid,
+    pub diffusion_rate: f32, // Fraction of each cell shared with its neighbors per second
+}
+
+impl Default for AlarmPheromoneGrid {
+    fn default() -> Self {
+        Self {
+            grid: PheromoneGrid {
+                cell_size: 8.0,
+                decay_rate: 1.5, // Gone within seconds once nobody renews it
+                ..default()
+            },
+            diffusion_rate: 0.5,
+        }
+    }
+}
+
+/// Tuning for spatial diffusion of soil moisture and temperature
+#[derive(Resource, Clone, Debug)]
+pub struct SoilDiffusionConfig {
+    /// Fraction of the difference to each neighbor exchanged per second
+    pub moisture_rate: f32,
+    /// Fraction of the difference to each neighbor exchanged per second
+    pub temperature_rate: f32,
+    /// Spacing between soil cells in world units
+    pub cell_spacing: f32,
+}
+
+impl Default for SoilDiffusionConfig {
+    fn default() -> Self {
+        Self {
+            moisture_rate: 0.2,
+            temperature_rate: 0.1,
+            cell_spacing: 4.0,
+        }
+    }
+}
+
+/// Random drift of soil moisture and temperature
+///
+/// Each follows an Ornstein-Uhlenbeck process: it wanders randomly but is pulled back
+/// toward its mean, so cells neither freeze in place nor drift off for good.
+#[derive(Resource, Clone, Debug)]
+pub struct EnvironmentNoise {
+    pub reversion: f32,              // Share of the gap to the mean closed per second
+    pub moisture_mean: f32,          // Moisture the drift settles around
+    pub moisture_volatility: f32,    // Moisture spread gained per square-root second
+    pub temperature_mean: f32,       // Temperature the drift settles around
+    pub temperature_volatility: f32, // Temperature spread gained per square-root second
+}
+
+impl Default for EnvironmentNoise {
+    fn default() -> Self {
+        Self {
+            reversion: 0.02,
+            moisture_mean: 0.55,
+            moisture_volatility: 0.03,
+            temperature_mean: 20.0,
+            temperature_volatility: 0.12,
+        }
+    }
+}
+
+/// How fast soil nutrition grows back, and what speeds it up or holds it back
+///
+/// Regeneration peaks in moderately moist soil, is boosted close to decaying corpses
+/// and waste dumps, and nearly stops everywhere during a drought.
+#[derive(Resource, Clone, Debug)]
+pub struct NutritionModel {
+    pub base_rate: f32,           // Nutrition regained per second in ideal conditions
+    pub ideal_moisture: f32,      // Moisture at which soil regenerates fastest
+    pub moisture_tolerance: f32,  // Moisture difference at which the rate bottoms out
+    pub min_moisture_factor: f32, // Share of the rate kept in bone-dry or waterlogged soil
+    pub waste_radius: f32,        // Reach of a corpse or waste dump's fertilizing effect
+    pub waste_boost: f32,         // Extra rate multiple right next to waste, fading with distance
+    pub drought_factor: f32,      // Share of the rate kept while a drought is active
+}
+
+impl Default for NutritionModel {
+    fn default() -> Self {
+        Self {
+            base_rate: 0.01,
+            ideal_moisture: 0.5,
+            moisture_tolerance: 0.4,
+            min_moisture_factor: 0.25,
+            waste_radius: 12.0,
+            waste_boost: 3.0,
+            drought_factor: 0.1,
+        }
+    }
+}
+
+impl NutritionModel {
+    /// Nutrition regained per second by soil with the given moisture
+    ///
+    /// `waste_distance` is the distance to the nearest decaying waste, if any.
+    pub fn regeneration_rate(
+        &self,
+        moisture: f32,
+        waste_distance: Option<f32>,
+        drought: bool,
+    ) -> f32 {
+        let deviation = (moisture - self.ideal_moisture).abs() / self.moisture_tolerance.max(0.01);
+        let moisture_factor = (1.0 - deviation).max(self.min_moisture_factor);
+        let waste_factor = waste_distance
+            .filter(|distance| *distance < self.waste_radius)
+            .map_or(1.0, |distance| {
+                1.0 + self.waste_boost * (1.0 - distance / self.waste_radius)
+            });
+        let drought_factor = if drought { self.drought_factor } else { 1.0 };
+        self.base_rate * moisture_factor * waste_factor * drought_factor
+    }
+}
+
+/// Last known food location of an ant, learned by finding food or by antennation
+#[derive(Component, Clone, Default)]
+pub struct FoodMemory {
+    pub location: Option<Position>,
+}
+
+/// Sparse waypoints of a forager's outbound trip, retraced in reverse on the way home
+#[derive(Component, Clone, Default)]
+pub struct Breadcrumbs {
+    pub points: std::collections::VecDeque<Position>, // Oldest first
+}
+
+impl Breadcrumbs {
+    /// Record `position` if it is at least `spacing` from the last waypoint
+    pub fn record(&mut self, position: &Position, spacing: f32, capacity: usize) {
+        let far_enough = self.points.back().is_none_or(|last| {
+            let (dx, dy) = (position.x - last.x, position.y - last.y);
+            dx * dx + dy * dy >= spacing * spacing
+        });
+        if !far_enough {
+            return;
+        }
+        self.points.push_back(position.clone());
+        while self.points.len() > capacity {
+            self.points.pop_front();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+}
+
+/// Time until an ant may exchange information by antennation again
+#[derive(Component, Clone, Default)]
+pub struct AntennationCooldown {
+    pub remaining: f32,
+}
+
+/// Extent of the simulated world
+#[derive(Resource, Clone, Debug)]
+pub struct WorldConfig {
+    pub min_x: f32,             // West edge
+    pub max_x: f32,             // East edge
+    pub min_y: f32,             // Deepest point ants can reach
+    pub max_y: f32,             // Highest point above the surface
+    pub spatial_cell_size: f32, // Bucket size of the shared ant grid
+}
+
+impl Default for WorldConfig {
+    fn default() -> Self {
+        Self {
+            min_x: -100.0,
+            max_x: 100.0,
+            min_y: -100.0,
+            max_y: 20.0,
+            spatial_cell_size: 8.0,
+        }
+    }
+}
+
+impl WorldConfig {
+    pub fn width(&self) -> f32 {
+        self.max_x - self.min_x
+    }
+
+    /// Side-view area of the world, in square world units
+    pub fn area(&self) -> f32 {
+        self.width() * (self.max_y - self.min_y)
+    }
+}
+
+/// Camera zoom limits, pan bounds and default view
+///
+/// Zoom values are orthographic projection scales: smaller is closer.
+#[derive(Resource, Clone, Debug)]
+pub struct CameraSettings {
+    pub default_zoom: f32,    // Scale on startup and after a camera reset
+    pub min_zoom: f32,        // Closest allowed zoom
+    pub max_zoom: f32,        // Farthest allowed zoom
+    pub pan_speed: f32,       // World units per second at zoom 1.0
+    pub zoom_speed: f32,      // Scale change per second
+    pub pan_margin: f32,      // How far beyond the world edges the camera center may go
+    pub default_center: Vec2, // View center on startup, and on reset when there is no queen
+}
+
+impl Default for CameraSettings {
+    fn default() -> Self {
+        Self {
+            default_zoom: 0.6,
+            min_zoom: 0.2,
+            max_zoom: 2.0,
+            pan_speed: 120.0,
+            zoom_speed: 1.0,
+            pan_margin: 20.0,
+            default_center: Vec2::new(0.0, -20.0),
+        }
+    }
+}
+
+impl CameraSettings {
+    /// Region the camera center may occupy: the world bounds grown by the pan margin
+    pub fn pan_bounds(&self, world: &WorldConfig) -> Rect {
+        Rect::new(
+            world.min_x - self.pan_margin,
+            world.min_y - self.pan_margin,
+            world.max_x + self.pan_margin,
+            world.max_y + self.pan_margin,
+        )
+    }
+
+    /// Keep a camera center inside the pan bounds
+    pub fn clamp_center(&self, world: &WorldConfig, center: Vec2) -> Vec2 {
+        let bounds = self.pan_bounds(world);
+        center.clamp(bounds.min, bounds.max)
+    }
+
+    pub fn clamp_zoom(&self, zoom: f32) -> f32 {
+        zoom.clamp(self.min_zoom, self.max_zoom)
+    }
+}
+
+/// What happens to an ant when it dies
+///
+/// Defaults to [`DeathBehavior::Despawn`], the lightweight choice. Insert
+/// `DeathBehavior::Corpse` to leave bodies that decay back into the soil, at the cost of
+/// an extra entity per death.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DeathBehavior {
+    #[default]
+    Despawn, // Remove the ant and leave nothing behind
+    Corpse, // Leave a corpse that returns nutrients to the soil as it decays
+}
+
+/// Remains of a dead ant, slowly decaying into the soil beneath it
+#[derive(Component, Clone, Debug)]
+pub struct Corpse {
+    pub decay_remaining: f32, // Seconds until the corpse is gone
+    pub nutrients: f32,       // Nutrition returned to the nearest soil cell on decay
+}
+
+/// What happens to an ant reaching the west or east edge of the world
+///
+/// The bottom and top of the world are always walls: wrapping from the deepest
+/// tunnel to the sky would make no sense.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BoundaryMode {
+    #[default]
+    Clamp, // Stop at the edge
+    Wrap,   // Reappear at the opposite edge
+    Bounce, // Turn back from the edge
+}
+
+impl BoundaryMode {
+    /// Bring a position back inside the world, returning whether it crossed a side edge
+    ///
+    /// Under [`BoundaryMode::Bounce`] the overshoot is mirrored back inside, and so is
+    /// `target` when it lies beyond the same edge, so an ant heading out turns around.
+    pub fn apply(
+        self,
+        world: &WorldConfig,
+        position: &mut Position,
+        target: Option<&mut Position>,
+    ) -> bool {
+        position.y = position.y.clamp(world.min_y, world.max_y);
+        let (edge, outward) = if position.x > world.max_x {
+            (world.max_x, 1.0)
+        } else if position.x < world.min_x {
+            (world.min_x, -1.0)
+        } else {
+            return false;
+        };
+
+        match self {
+            BoundaryMode::Clamp => position.x = edge,
+            BoundaryMode::Wrap => {
+                position.x = (position.x - world.min_x).rem_euclid(world.width()) + world.min_x;
+            }
+            BoundaryMode::Bounce => {
+                position.x = (2.0 * edge - position.x).clamp(world.min_x, world.max_x);
+                if let Some(target) = target.filter(|target| (target.x - edge) * outward > 0.0) {
+                    target.x = 2.0 * edge - target.x;
+                }
+            }
+        }
+        true
+    }
+}
+
+/// Marker for an adult ant carrying a crowding disease
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Infected;
+
+/// How adult disease breaks out in crowded spots and spreads between cellmates
+#[derive(Resource, Clone, Debug)]
+pub struct DiseaseConfig {
+    pub crowding_threshold: usize, // Ants sharing a spatial grid cell that count as a crowd
+    pub crowding_time: f32,        // Seconds a cell must stay crowded before disease breaks out
+    pub outbreak_rate: f32,        // Chance per second an ant in a long-crowded cell falls ill
+    pub spread_rate: f32,          // Chance per second per infected cellmate of catching it
+    pub hp_loss_rate: f32,         // HP an infected ant loses per second
+    pub grooming_clear_rate: f32,  // Chance per second an infected ant in a chamber recovers
+}
+
+impl Default for DiseaseConfig {
+    fn default() -> Self {
+        Self {
+            crowding_threshold: 8,
+            crowding_time: 10.0,
+            outbreak_rate: 0.02,
+            spread_rate: 0.05,
+            hp_loss_rate: 0.5,
+            grooming_clear_rate: 0.1,
+        }
+    }
+}
+
+/// How well-fed ants share energy with hungry nestmates through trophallaxis
+#[derive(Resource, Clone, Debug)]
+pub struct TrophallaxisConfig {
+    pub radius: f32,                        // Contact distance for sharing food
+    pub donor_min_energy_fraction: f32,     // Share of max energy a donor must hold
+    pub recipient_max_energy_fraction: f32, // Share of max energy below which an ant begs
+    pub share_fraction: f32,                // Portion of the energy gap passed per exchange
+    pub cooldown: f32,                      // Seconds both ants wait before sharing again
+}
+
+impl Default for TrophallaxisConfig {
+    fn default() -> Self {
+        Self {
+            radius: 3.0,
+            donor_min_energy_fraction: 0.7,
+            recipient_max_energy_fraction: 0.3,
+            share_fraction: 0.25,
+            cooldown: 5.0,
+        }
+    }
+}
+
+/// How returning foragers hand their load to storage workers at chamber entrances
+#[derive(Resource, Clone, Debug)]
+pub struct FoodHandoffConfig {
+    pub radius: f32, // Distance from the entrance within which both ants must stand
+}
+
+impl Default for FoodHandoffConfig {
+    fn default() -> Self {
+        Self { radius: 8.0 }
+    }
+}
+
+/// Colony an ant belongs to; ants without one belong to the home colony, id 0
+#[derive(
+    Component,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+)]
+pub struct ColonyId(pub u32);
+
+impl ColonyId {
+    /// Color identifying the colony on the territory overlay
+    pub fn color(&self) -> Color {
+        // Golden-angle hue steps keep neighbouring ids far apart on the color wheel
+        Color::hsl((self.0 as f32 * 137.5 + 30.0) % 360.0, 0.8, 0.55)
+    }
+}
+
+/// Colony holding a spatial grid cell
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TerritoryCell {
+    pub owner: ColonyId,
+    pub share: f32, // Owner's share of the ants in the cell; low values mark contested ground
+}
+
+/// Territory of each colony, by [`SpatialGrid`] cell, with its optional overlay
+#[derive(Resource, Clone, Debug)]
+pub struct TerritoryMap {
+    pub cells: std::collections::HashMap<(i32, i32), TerritoryCell>,
+    pub cell_size: f32,       // Spatial grid cell size the map was built with
+    pub update_interval: f32, // Seconds between rebuilds
+    pub update_timer: f32,    // Seconds since the last rebuild
+    pub overlay_enabled: bool,
+    pub max_alpha: f32, // Opacity of a cell held outright by one colony
+}
+
+impl Default for TerritoryMap {
+    fn default() -> Self {
+        Self {
+            cells: std::collections::HashMap::new(),
+            cell_size: 8.0,
+            update_interval: 1.0,
+            update_timer: 0.0,
+            overlay_enabled: false,
+            max_alpha: 0.35,
+        }
+    }
+}
+
+impl TerritoryMap {
+    /// Colony holding the cell around `position`, if any ants are there
+    pub fn owner_at(&self, position: &Position) -> Option<ColonyId> {
+        let cell = (
+            (position.x / self.cell_size).floor() as i32,
+            (position.y / self.cell_size).floor() as i32,
+        );
+        self.cells.get(&cell).map(|cell| cell.owner)
+    }
+}
+
+/// Figure the colony comparison panel orders its rows by, largest first
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ComparisonMetric {
+    #[default]
+    Population,
+    Phase,
+    Food,
+    Age,
+}
+
+impl ComparisonMetric {
+    pub const ALL: [ComparisonMetric; 4] = [
+        ComparisonMetric::Population,
+        ComparisonMetric::Phase,
+        ComparisonMetric::Food,
+        ComparisonMetric::Age,
+    ];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ComparisonMetric::Population => "Population",
+            ComparisonMetric::Phase => "Phase",
+            ComparisonMetric::Food => "Food",
+            ComparisonMetric::Age => "Age",
+        }
+    }
+
+    /// Localization key of the metric's name
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            ComparisonMetric::Population => "comparison.metric.population",
+            ComparisonMetric::Phase => "comparison.metric.phase",
+            ComparisonMetric::Food => "comparison.metric.food",
+            ComparisonMetric::Age => "comparison.metric.age",
+        }
+    }
+
+    /// The metric after this one, wrapping around
+    pub fn next(&self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|metric| metric == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// One colony's line in the comparison panel
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColonyComparisonRow {
+    pub colony: ColonyId,
+    pub population: usize,
+    pub phase: DevelopmentPhase,
+    pub food: f32,       // Stored plus carried food
+    pub founded_at: f32, // Simulated seconds when the colony was first seen
+    pub age_days: f32,
+    pub extinct: bool, // Seen before but without any ants left
+}
+
+/// Side-by-side figures for every colony seen this run, in `sort_by` order
+#[derive(Resource, Clone, Debug, Default)]
+pub struct ColonyComparison {
+    pub rows: Vec<ColonyComparisonRow>,
+    pub sort_by: ComparisonMetric,
+    pub visible: bool,
+}
+
+impl ColonyComparison {
+    pub fn row(&self, colony: ColonyId) -> Option<&ColonyComparisonRow> {
+        self.rows.iter().find(|row| row.colony == colony)
+    }
+
+    /// Order rows by `sort_by`, largest first; extinct colonies sink to the bottom and
+    /// ties go to the lowest colony id
+    pub fn sort(&mut self) {
+        let metric = self.sort_by;
+        self.rows.sort_by(|a, b| {
+            let by_metric = match metric {
+                ComparisonMetric::Population => b.population.cmp(&a.population),
+                ComparisonMetric::Phase => b.phase.cmp(&a.phase),
+                ComparisonMetric::Food => b.food.total_cmp(&a.food),
+                ComparisonMetric::Age => b.age_days.total_cmp(&a.age_days),
+            };
+            a.extinct
+                .cmp(&b.extinct)
+                .then(by_metric)
+                .then(a.colony.cmp(&b.colony))
+        });
+    }
+}
+
+/// Food store and development phase of a colony other than the home colony
+#[derive(Clone, Debug, PartialEq)]
+pub struct DaughterColony {
+    pub food: f32, // Food its foragers have brought home, of either kind
+    pub phase: DevelopmentPhase,
+}
+
+impl Default for DaughterColony {
+    fn default() -> Self {
+        Self {
+            food: 0.0,
+            phase: DevelopmentPhase::QueenFounding,
+        }
+    }
+}
+
+/// Per-colony figures for the daughter colonies
+///
+/// The home colony keeps its figures in [`ColonyFoodStore`] and
+/// [`ColonyDevelopmentPhase`]; every other [`ColonyId`] is tracked here.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct DaughterColonies {
+    pub colonies: std::collections::HashMap<ColonyId, DaughterColony>,
+    pub last_id: u32, // Highest colony id handed out; ids are never reused
+}
+
+impl DaughterColonies {
+    /// Hand out a fresh colony id above both `last_id` and `in_use`
+    ///
+    /// `in_use` is the highest id still carried by an entity, which covers colonies
+    /// restored from a save.
+    pub fn allocate_id(&mut self, in_use: u32) -> ColonyId {
+        self.last_id = self.last_id.max(in_use) + 1;
+        ColonyId(self.last_id)
+    }
+
+    /// Put food brought home by one of `colony`'s ants into its store
+    pub fn deposit(&mut self, colony: ColonyId, amount: f32) {
+        self.colonies.entry(colony).or_default().food += amount;
+    }
+}
+
+/// Uniform bucket grid for cheap proximity lookups
+#[derive(Resource, Clone)]
+pub struct SpatialGrid {
+    pub cell_size: f32,
+    pub grid: std::collections::HashMap<(i32, i32), Vec<Entity>>,
+    pub positions: std::collections::HashMap<Entity, Position>, // Where each entity was inserted
+}
+
+impl Default for SpatialGrid {
+    fn default() -> Self {
+        Self::new(8.0)
+    }
+}
+
+impl SpatialGrid {
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size,
+            grid: std::collections::HashMap::new(),
+            positions: std::collections::HashMap::new(),
+        }
+    }
+
+    /// Cell size suited to queries of `avg_query_radius` over `density` entities per
+    /// square world unit
+    ///
+    /// Sparse grids use cells as wide as a query so one touches at most four cells;
+    /// crowded grids shrink cells towards the radius to keep buckets small.
+    pub fn recommended_cell_size(avg_query_radius: f32, density: f32) -> f32 {
+        const TARGET_PER_CELL: f32 = 4.0;
+        let min_size = avg_query_radius.max(1.0);
+        let max_size = (avg_query_radius * 2.0).max(min_size);
+        if density <= 0.0 {
+            return max_size;
+        }
+        (TARGET_PER_CELL / density).sqrt().clamp(min_size, max_size)
+    }
+
+    /// Re-bucket every entity under a new cell size, keeping the grid's contents
+    pub fn rebucket(&mut self, cell_size: f32) {
+        self.cell_size = cell_size;
+        self.grid.clear();
+        let positions = std::mem::take(&mut self.positions);
+        for (entity, position) in positions {
+            self.insert_entity(entity, &position);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.grid.clear();
+        self.positions.clear();
+    }
+
+    fn cell_of(&self, position: &Position) -> (i32, i32) {
+        (
+            (position.x / self.cell_size).floor() as i32,
+            (position.y / self.cell_size).floor() as i32,
+        )
+    }
+
+    pub fn insert_entity(&mut self, entity: Entity, position: &Position) {
+        let cell = self.cell_of(position);
+        self.grid.entry(cell).or_default().push(entity);
+        self.positions.insert(entity, position.clone());
+    }
+
+    pub fn remove_entity(&mut self, entity: Entity, position: &Position) {
+        self.positions.remove(&entity);
+        let cell = self.cell_of(position);
+        if let Some(entities) = self.grid.get_mut(&cell) {
+            entities.retain(|other| *other != entity);
+            if entities.is_empty() {
+                self.grid.remove(&cell);
+            }
+        }
+    }
+
+    /// Entities in every cell overlapping the square of `radius` around `position`
+    ///
+    /// Candidates still need an exact distance check.
+    pub fn get_nearby_entities(&self, position: &Position, radius: f32) -> Vec<Entity> {
+        let (min_x, min_y) = self.cell_of(&Position {
+            x: position.x - radius,
+            y: position.y - radius,
+        });
+        let (max_x, max_y) = self.cell_of(&Position {
+            x: position.x + radius,
+            y: position.y + radius,
+        });
+
+        let mut nearby = Vec::new();
+        for x in min_x..=max_x {
+            for y in min_y..=max_y {
+                if let Some(entities) = self.grid.get(&(x, y)) {
+                    nearby.extend_from_slice(entities);
+                }
+            }
+        }
+        nearby
+    }
+
+    /// Entities whose stored position lies inside the rectangle spanned by `min` and `max`
+    pub fn entities_in_rect(&self, min: Vec2, max: Vec2) -> Vec<Entity> {
+        let (min_x, min_y) = self.cell_of(&Position { x: min.x, y: min.y });
+        let (max_x, max_y) = self.cell_of(&Position { x: max.x, y: max.y });
+
+        let mut inside = Vec::new();
+        for x in min_x..=max_x {
+            for y in min_y..=max_y {
+                let Some(entities) = self.grid.get(&(x, y)) else {
+                    continue;
+                };
+                inside.extend(entities.iter().copied().filter(|entity| {
+                    self.positions.get(entity).is_some_and(|position| {
+                        (min.x..=max.x).contains(&position.x)
+                            && (min.y..=max.y).contains(&position.y)
+                    })
+                }));
+            }
+        }
+        inside
+    }
+
+    pub fn cell_center(&self, cell: (i32, i32)) -> Position {
+        Position {
+            x: (cell.0 as f32 + 0.5) * self.cell_size,
+            y: (cell.1 as f32 + 0.5) * self.cell_size,
+        }
+    }
+}
+
+/// When ants are drawn individually or as density blobs
+#[derive(Resource, Clone, Debug)]
+pub struct AntLodConfig {
+    pub population_threshold: usize, // Above this many ants, draw density blobs
+    pub zoom_threshold: f32,         // Above this camera scale, draw density blobs
+    pub blob_base_size: f32,         // Blob size for a single ant
+    pub blob_size_per_ant: f32,      // Extra size per additional ant in the cell
+    pub aggregated: bool,            // Whether blobs are currently drawn (derived)
+}
+
+impl Default for AntLodConfig {
+    fn default() -> Self {
+        Self {
+            population_threshold: 1000,
+            zoom_threshold: 1.5, // Below CameraSettings::max_zoom, so zooming out can reach it
+            blob_base_size: 2.0,
+            blob_size_per_ant: 0.5,
+            aggregated: false,
+        }
+    }
+}
+
+impl AntLodConfig {
+    /// Whether a population seen at a camera scale should be drawn as blobs
+    pub fn should_aggregate(&self, population: usize, camera_scale: f32) -> bool {
+        population > self.population_threshold || camera_scale > self.zoom_threshold
+    }
+
+    /// Blob size for a spatial grid cell holding `count` ants, capped at the cell size
+    pub fn blob_size(&self, count: usize, cell_size: f32) -> f32 {
+        let extra = count.saturating_sub(1) as f32 * self.blob_size_per_ant;
+        (self.blob_base_size + extra).min(cell_size)
+    }
+}
+
+/// Aggregated sprite standing in for every ant in one spatial grid cell
+#[derive(Component, Clone, Copy, Debug)]
+pub struct DensityBlob {
+    pub cell: (i32, i32),
+}
+
+/// Highest speed offered by the speed shortcuts
+pub const UI_MAX_SPEED: f32 = 100.0;
+
+/// Requested simulation speed, pause state and the optional uncapped turbo mode
+///
+/// The requested speed scales virtual time and is capped at `max_speed_multiplier`.
+/// Turbo additionally runs extra fixed-size simulation steps each frame, as many as
+/// `turbo_max_steps` and the `turbo_frame_budget` allow, so the achieved speed can go
+/// well past the cap. `achieved_multiplier` reports what was actually reached.
+#[derive(Resource, Clone, Debug)]
+pub struct TimeControl {
+    pub speed_multiplier: f32,     // Requested speed (1.0 = real time)
+    pub max_speed_multiplier: f32, // Cap on the requested speed
+    pub is_paused: bool,
+    pub turbo: bool,              // Run extra simulation steps each frame
+    pub turbo_step: f32,          // Simulated seconds per turbo step
+    pub turbo_max_steps: usize,   // Turbo steps allowed in one frame
+    pub turbo_frame_budget: f32,  // Wall-clock seconds a frame may spend on turbo steps
+    pub achieved_multiplier: f32, // Simulated seconds per real second over the last frame
+    pub throttle: f32,            // Share of the requested speed allowed by the auto-throttle
+}
+
+impl Default for TimeControl {
+    fn default() -> Self {
+        Self {
+            speed_multiplier: 1.0,
+            max_speed_multiplier: UI_MAX_SPEED,
+            is_paused: false,
+            turbo: false,
+            turbo_step: 0.1,
+            turbo_max_steps: 50,
+            turbo_frame_budget: 0.012,
+            achieved_multiplier: 1.0,
+            throttle: 1.0,
+        }
+    }
+}
+
+impl TimeControl {
+    /// Request a speed, capped at `max_speed_multiplier`
+    pub fn set_speed(&mut self, speed_multiplier: f32) {
+        self.speed_multiplier = speed_multiplier.clamp(0.0, self.max_speed_multiplier);
+    }
+
+    /// Speed actually run: the requested speed scaled by the throttle, never below real time
+    pub fn effective_speed(&self) -> f32 {
+        (self.speed_multiplier * self.throttle).max(self.speed_multiplier.min(1.0))
+    }
+
+    /// Turbo steps allowed per frame after throttling, at least one
+    pub fn effective_turbo_steps(&self) -> usize {
+        ((self.turbo_max_steps as f32 * self.throttle).ceil() as usize).max(1)
+    }
+
+    /// Whether the auto-throttle is currently holding the speed back
+    pub fn is_throttled(&self) -> bool {
+        self.throttle < 1.0
+    }
+}
+
+/// When the simulation slows itself down to keep the frame rate smooth
+#[derive(Resource, Clone, Debug)]
+pub struct AutoThrottleConfig {
+    pub enabled: bool,
+    pub target_fps: f32,     // Throttle while FPS is below this
+    pub recovery_fps: f32,   // Ease off once FPS is back above this
+    pub reduce_factor: f32,  // Throttle scale applied per adjustment while slow
+    pub restore_factor: f32, // Throttle scale applied per adjustment once recovered
+    pub min_throttle: f32,   // Lowest share of the requested speed allowed
+    pub adjust_timer: Timer, // Real time between adjustments
+}
+
+impl Default for AutoThrottleConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            target_fps: 30.0,
+            recovery_fps: 45.0,
+            reduce_factor: 0.75,
+            restore_factor: 1.25,
+            min_throttle: 0.05,
+            adjust_timer: Timer::from_seconds(0.5, TimerMode::Repeating),
+        }
+    }
+}
+
+/// Simulated clock driving the day cycle
+#[derive(Resource, Clone, Debug)]
+pub struct SimulationTime {
+    pub elapsed_seconds: f32,    // Simulated seconds since the colony started
+    pub day_length_seconds: f32, // Simulated seconds in one full day
+    pub current_day: u32,        // 1-based; the colony starts on day 1
+}
+
+impl Default for SimulationTime {
+    fn default() -> Self {
+        let day_length_seconds = 240.0;
+        Self {
+            elapsed_seconds: day_length_seconds * 0.25, // Start at 06:00
+            day_length_seconds,
+            current_day: 1,
+        }
+    }
+}
+
+impl SimulationTime {
+    /// Position within the current day, from 0.0 (midnight) to 1.0
+    pub fn get_time_of_day_fraction(&self) -> f32 {
+        (self.elapsed_seconds / self.day_length_seconds).fract()
+    }
+
+    /// Simulated days since the colony started, including the fraction of the current day
+    pub fn days_elapsed(&self) -> f32 {
+        self.elapsed_seconds / self.day_length_seconds
+    }
+
+    /// Move the clock forward by `seconds` simulated seconds, updating the day counter
+    pub fn advance(&mut self, seconds: f32) {
+        self.elapsed_seconds += seconds;
+        self.current_day = self.days_elapsed() as u32 + 1;
+    }
+
+    /// Jump to a given hour of the current day
+    pub fn set_hour(&mut self, hour: f32) {
+        let day_start = self.current_day.saturating_sub(1) as f32 * self.day_length_seconds;
+        self.elapsed_seconds = day_start + (hour.rem_euclid(24.0) / 24.0) * self.day_length_seconds;
+    }
+}
+
+/// What a simulated day is measured in
+///
+/// In tick mode every frame that time moves forward counts as one tick, however long
+/// it took, so headless runs advance days predictably regardless of the wall clock.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DayLengthMode {
+    #[default]
+    Seconds, // A day lasts `SimulationTime::day_length_seconds` of game time
+    Ticks(u32), // A day lasts this many frames
+}
+
+impl DayLengthMode {
+    /// Simulated seconds a frame of `delta_seconds` moves the clock forward
+    pub fn step_seconds(self, delta_seconds: f32, day_length_seconds: f32) -> f32 {
+        match self {
+            DayLengthMode::Seconds => delta_seconds,
+            DayLengthMode::Ticks(_) if delta_seconds <= 0.0 => 0.0, // Paused
+            DayLengthMode::Ticks(ticks_per_day) => day_length_seconds / ticks_per_day.max(1) as f32,
+        }
+    }
+}
+
+/// Conditions that stop a run automatically; none are set by default
+#[derive(Resource, Clone, Debug, Default)]
+pub struct EndConditions {
+    pub after_days: Option<f32>, // Stop once this many simulated days have passed
+    pub on_extinction: bool,     // Stop when no ants are left
+    pub on_phase: Option<DevelopmentPhase>, // Stop once the colony reaches this phase
+}
+
+/// Why a run was stopped by its [`EndConditions`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EndReason {
+    DaysElapsed(f32),
+    ColonyExtinct,
+    PhaseReached(DevelopmentPhase),
+}
+
+impl EndReason {
+    pub fn description(&self) -> String {
+        match self {
+            EndReason::DaysElapsed(days) => format!("{} days have passed", days),
+            EndReason::ColonyExtinct => "The colony has died out".to_string(),
+            EndReason::PhaseReached(phase) => {
+                format!("The colony reached {}", phase.display_name())
+            }
+        }
+    }
+}
+
+/// Outcome of the run once an end condition is met; stays set until a new game
+#[derive(Resource, Clone, Debug, Default)]
+pub struct SimulationEnded {
+    pub reason: Option<EndReason>,
+    pub day: u32,          // Day the run ended on
+    pub population: usize, // Ants alive when it ended, queens included
+}
+
+impl SimulationEnded {
+    pub fn is_ended(&self) -> bool {
+        self.reason.is_some()
+    }
+}
+
+/// Marker for the summary panel shown once the run has ended
+#[derive(Component)]
+pub struct EndSummaryPanel;
+
+/// Activity multiplier applied between two hours of the day
+#[derive(Clone, Debug)]
+pub struct ActivityPeriod {
+    pub start_hour: f32, // Inclusive
+    pub end_hour: f32,   // Exclusive; may be below start_hour to wrap past midnight
+    pub multiplier: f32, // Share of idle workers willing to forage (0.0 - 1.0)
+}
+
+impl ActivityPeriod {
+    pub fn contains(&self, hour: f32) -> bool {
+        if self.start_hour <= self.end_hour {
+            hour >= self.start_hour && hour < self.end_hour
+        } else {
+            hour >= self.start_hour || hour < self.end_hour
+        }
+    }
+}
+
+/// Circadian schedule scaling how many workers forage at each hour
+#[derive(Resource, Clone, Debug)]
+pub struct ActivitySchedule {
+    pub periods: Vec<ActivityPeriod>, // First matching period wins
+    pub default_multiplier: f32,      // Used for hours not covered by any period
+    pub transition_rate: f32,         // Chance per second that an ant reconsiders resting
+}
+
+impl Default for ActivitySchedule {
+    fn default() -> Self {
+        Self {
+            periods: vec![
+                ActivityPeriod {
+                    start_hour: 21.0,
+                    end_hour: 5.0,
+                    multiplier: 0.15, // Night
+                },
+                ActivityPeriod {
+                    start_hour: 8.0,
+                    end_hour: 12.0,
+                    multiplier: 1.0, // Mid-morning peak
+                },
+                ActivityPeriod {
+                    start_hour: 12.0,
+                    end_hour: 16.0,
+                    multiplier: 0.8, // Afternoon
+                },
+            ],
+            default_multiplier: 0.5, // Dawn and dusk
+            transition_rate: 0.5,
+        }
+    }
+}
+
+impl ActivitySchedule {
+    /// Activity multiplier at the given hour of day
+    pub fn multiplier_at(&self, hour: f32) -> f32 {
+        self.periods
+            .iter()
+            .find(|period| period.contains(hour))
+            .map_or(self.default_multiplier, |period| period.multiplier)
+            .clamp(0.0, 1.0)
+    }
+
+    /// Replace the multiplier for an hour range, adding the range if it is new
+    pub fn set_period(&mut self, start_hour: f32, end_hour: f32, multiplier: f32) {
+        if let Some(period) = self
+            .periods
+            .iter_mut()
+            .find(|period| period.start_hour == start_hour && period.end_hour == end_hour)
+        {
+            period.multiplier = multiplier;
+        } else {
+            // Newly added ranges take precedence over older overlapping ones
+            self.periods.insert(
+                0,
+                ActivityPeriod {
+                    start_hour,
+                    end_hour,
+                    multiplier,
+                },
+            );
+        }
+    }
+}
+
+/// When workers withdraw into the nest for the night and come back out at dawn
+///
+/// Works alongside the [`ActivitySchedule`]: the schedule sets how many ants forage,
+/// this sets where the colony spends the night.
+#[derive(Resource, Clone, Debug)]
+pub struct NightShelterConfig {
+    pub dusk_hour: f32,     // Hour ants start heading into the nest
+    pub dawn_hour: f32,     // Hour they start coming back out
+    pub shelter_share: f32, // Share of surface workers that spend the night inside (0.0 - 1.0)
+    pub retreat_rate: f32,  // Chance per second that an unladen ant heads in after dusk
+    pub emerge_rate: f32,   // Chance per second that a sheltering ant comes out after dawn
+}
+
+impl Default for NightShelterConfig {
+    fn default() -> Self {
+        Self {
+            dusk_hour: 20.0,
+            dawn_hour: 6.0,
+            shelter_share: 0.9,
+            retreat_rate: 0.5,
+            emerge_rate: 0.5,
+        }
+    }
+}
+
+impl NightShelterConfig {
+    /// Whether the given hour falls between dusk and dawn
+    pub fn is_night(&self, hour: f32) -> bool {
+        ActivityPeriod {
+            start_hour: self.dusk_hour,
+            end_hour: self.dawn_hour,
+            multiplier: 0.0,
+        }
+        .contains(hour)
+    }
+}
+
+/// Worker spending the night in the worker quarters until dawn
+#[derive(Component)]
+pub struct Sheltering;
+
+/// Environmental disasters the player can trigger
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DisasterType {
+    Rain,
+    Drought,
+    ColdSnap,
+    Heatwave,
+    InvasiveSpecies,
+}
+
+impl DisasterType {
+    pub const ALL: [DisasterType; 5] = [
+        DisasterType::Rain,
+        DisasterType::Drought,
+        DisasterType::ColdSnap,
+        DisasterType::Heatwave,
+        DisasterType::InvasiveSpecies,
+    ];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            DisasterType::Rain => "Rain",
+            DisasterType::Drought => "Drought",
+            DisasterType::ColdSnap => "Cold Snap",
+            DisasterType::Heatwave => "Heatwave",
+            DisasterType::InvasiveSpecies => "Invasive Species",
+        }
+    }
+
+    /// Localization key of the disaster's name
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            DisasterType::Rain => "disaster.rain",
+            DisasterType::Drought => "disaster.drought",
+            DisasterType::ColdSnap => "disaster.cold_snap",
+            DisasterType::Heatwave => "disaster.heatwave",
+            DisasterType::InvasiveSpecies => "disaster.invasive_species",
+        }
+    }
+
+    pub fn shortcut_key(&self) -> &'static str {
+        match self {
+            DisasterType::Rain => "R",
+            DisasterType::Drought => "D",
+            DisasterType::ColdSnap => "C",
+            DisasterType::Heatwave => "H",
+            DisasterType::InvasiveSpecies => "I",
+        }
+    }
+
+    /// Solid color of the disaster's icon in the compact disasters panel
+    pub fn icon_color(&self) -> Color {
+        match self {
+            DisasterType::Rain => Color::srgb(0.3, 0.5, 0.95),
+            DisasterType::Drought => Color::srgb(0.85, 0.65, 0.3),
+            DisasterType::ColdSnap => Color::srgb(0.75, 0.9, 1.0),
+            DisasterType::Heatwave => Color::srgb(1.0, 0.35, 0.1),
+            DisasterType::InvasiveSpecies => Color::srgb(0.6, 0.2, 0.65),
+        }
+    }
+
+    /// How long the disaster lasts once triggered, in seconds
+    pub fn default_duration(&self) -> f32 {
+        match self {
+            DisasterType::Rain => 30.0,
+            DisasterType::Drought => 45.0,
+            DisasterType::ColdSnap => 25.0,
+            DisasterType::Heatwave => 35.0,
+            DisasterType::InvasiveSpecies => 40.0,
+        }
+    }
+}
+
+/// Currently active disasters and their cooldowns
+#[derive(Resource, Clone, Debug)]
+pub struct DisasterState {
+    pub active_disasters: std::collections::HashMap<DisasterType, f32>, // Remaining seconds
+    pub cooldown_timers: std::collections::HashMap<DisasterType, f32>,  // Remaining seconds
+    pub cooldown_duration: f32, // Seconds before an ended disaster can be triggered again
+    pub visual_intensity: f32,  // Scales overlay tints and particle rates; 1.0 as designed
+    pub effect_intensity: f32,  // Scales the soil and food effects; 1.0 as designed
+}
+
+impl Default for DisasterState {
+    fn default() -> Self {
+        Self {
+            active_disasters: std::collections::HashMap::new(),
+            cooldown_timers: std::collections::HashMap::new(),
+            cooldown_duration: 15.0,
+            visual_intensity: 1.0,
+            effect_intensity: 1.0,
+        }
+    }
+}
+
+impl DisasterState {
+    pub fn is_active(&self, disaster_type: DisasterType) -> bool {
+        self.active_disasters.contains_key(&disaster_type)
+    }
+
+    pub fn is_on_cooldown(&self, disaster_type: DisasterType) -> bool {
+        self.cooldown_timers.contains_key(&disaster_type)
+    }
+
+    /// Set the visual and effect intensity together, keeping them linked
+    pub fn set_intensity(&mut self, intensity: f32) {
+        self.visual_intensity = intensity;
+        self.effect_intensity = intensity;
+    }
+
+    pub fn start_disaster(&mut self, disaster_type: DisasterType, duration: f32) {
+        self.active_disasters.insert(disaster_type, duration);
+    }
+
+    pub fn get_remaining_time(&self, disaster_type: DisasterType) -> Option<f32> {
+        self.active_disasters.get(&disaster_type).copied()
+    }
+
+    /// Count down active disasters and cooldowns; returns disasters that just ended
+    pub fn update(&mut self, delta_time: f32) -> Vec<DisasterType> {
+        self.cooldown_timers.retain(|_, remaining| {
+            *remaining -= delta_time;
+            *remaining > 0.0
+        });
+
+        let mut ended = Vec::new();
+        self.active_disasters.retain(|disaster_type, remaining| {
+            *remaining -= delta_time;
+            if *remaining <= 0.0 {
+                ended.push(*disaster_type);
+                false
+            } else {
+                true
+            }
+        });
+
+        for disaster_type in &ended {
+            self.cooldown_timers
+                .insert(*disaster_type, self.cooldown_duration);
+        }
+        ended
+    }
+}
+
+/// Automatic disaster scheduling and how early the next one is forecast
+#[derive(Resource, Clone, Debug)]
+pub struct AutoDisasterConfig {
+    pub enabled: bool,
+    pub min_interval: f32, // Fewest simulated seconds between automatic disasters
+    pub max_interval: f32, // Most simulated seconds between automatic disasters
+    pub forecast_lead: f32, // Simulated seconds of warning before a disaster strikes
+}
+
+impl Default for AutoDisasterConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_interval: 480.0,
+            max_interval: 960.0,
+            forecast_lead: 480.0,
+        }
+    }
+}
+
+/// A disaster the scheduler has already rolled, and when it will strike
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ForecastedDisaster {
+    pub disaster_type: DisasterType,
+    pub strikes_at: f32, // Simulated elapsed seconds at which it starts
+}
+
+/// The next automatic disaster, pre-rolled so it can be announced ahead of time
+#[derive(Resource, Clone, Debug, Default)]
+pub struct Forecast {
+    pub scheduled_at: Option<f32>, // When the next disaster strikes; its type is rolled later
+    pub upcoming: Option<ForecastedDisaster>, // Set once the strike is within the forecast lead
+}
+
+/// What a screen tint stands for
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OverlaySource {
+    Disaster(DisasterType),
+    DayNight,
+}
+
+/// One tint contributing to the composed screen overlay
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OverlayDescriptor {
+    pub source: OverlaySource,
+    pub color: Color,
+}
+
+/// Marker for the single fullscreen overlay sprite
+#[derive(Component)]
+pub struct ColorOverlay;
+
+/// Overlay tints and the single color they are composed into each frame
+#[derive(Resource, Clone, Debug)]
+pub struct ColorOverlayConfig {
+    pub disaster_colors: std::collections::HashMap<DisasterType, Color>,
+    pub night_color: Color, // Tint at midnight, fading out toward noon
+    pub max_alpha: f32,     // Cap on the composed overlay's opacity
+    pub active_overlays: Vec<OverlayDescriptor>, // Tints in effect this frame
+    pub composed_color: Option<Color>, // Final tint drawn on screen (derived)
+}
+
+impl Default for ColorOverlayConfig {
+    fn default() -> Self {
+        Self {
+            disaster_colors: Self::default_disaster_colors(),
+            night_color: Color::srgba(0.05, 0.05, 0.2, 0.3),
+            max_alpha: 0.4,
+            active_overlays: Vec::new(),
+            composed_color: None,
+        }
+    }
+}
+
+impl ColorOverlayConfig {
+    /// Built-in tint of every disaster
+    pub fn default_disaster_colors() -> std::collections::HashMap<DisasterType, Color> {
+        [
+            (DisasterType::Rain, Color::srgba(0.2, 0.3, 0.8, 0.15)),
+            (DisasterType::Drought, Color::srgba(0.8, 0.6, 0.2, 0.15)),
+            (DisasterType::ColdSnap, Color::srgba(0.7, 0.9, 1.0, 0.2)),
+            (DisasterType::Heatwave, Color::srgba(1.0, 0.3, 0.1, 0.15)),
+            (
+                DisasterType::InvasiveSpecies,
+                Color::srgba(0.5, 0.1, 0.5, 0.12),
+            ),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    /// Tint (including opacity) drawn while a disaster is active; applies from the next frame
+    pub fn set_disaster_color(&mut self, disaster_type: DisasterType, color: Color) {
+        self.disaster_colors.insert(disaster_type, color);
+    }
+
+    /// Reset disaster tints to the built-in ones, then apply the user's overrides
+    pub fn apply_user_colors(&mut self, user_settings: &UserSettings) {
+        self.disaster_colors = Self::default_disaster_colors();
+        for entry in &user_settings.overlay_colors {
+            let [r, g, b, a] = entry.rgba;
+            self.set_disaster_color(entry.disaster_type, Color::srgba(r, g, b, a));
+        }
+    }
+
+    /// Night tint for a time of day (0.0 and 1.0 are midnight), if it is dark enough to show
+    pub fn night_overlay(&self, time_of_day: f32) -> Option<OverlayDescriptor> {
+        let darkness = night_darkness(time_of_day);
+        let alpha = self.night_color.alpha() * darkness;
+        (alpha >= 0.01).then(|| OverlayDescriptor {
+            source: OverlaySource::DayNight,
+            color: self.night_color.with_alpha(alpha),
+        })
+    }
+
+    /// Blend the active overlays into one color
+    ///
+    /// Color channels are averaged weighted by each overlay's alpha. Opacity stacks like
+    /// layered glass (`1 - Π(1 - a)`) but never exceeds `max_alpha`.
+    pub fn compose(&self) -> Option<Color> {
+        let mut weighted = [0.0; 3];
+        let mut total_alpha = 0.0;
+        let mut transparency = 1.0;
+
+        for overlay in &self.active_overlays {
+            let [r, g, b, a] = overlay.color.to_srgba().to_f32_array();
+            weighted[0] += r * a;
+            weighted[1] += g * a;
+            weighted[2] += b * a;
+            total_alpha += a;
+            transparency *= 1.0 - a;
+        }
+        if total_alpha <= 0.0 {
+            return None;
+        }
+
+        let alpha = (1.0 - transparency).min(self.max_alpha);
+        Some(Color::srgba(
+            weighted[0] / total_alpha,
+            weighted[1] / total_alpha,
+            weighted[2] / total_alpha,
+            alpha,
+        ))
+    }
+}
+
+/// Marker for the panel with a trigger button per disaster
+#[derive(Component)]
+pub struct DisasterControlPanel;
+
+/// Button starting a disaster from the disaster control panel
+#[derive(Component, Clone, Copy)]
+pub struct DisasterControlButton {
+    pub disaster_type: DisasterType,
+}
+
+/// Dot on a disaster control button showing whether it is available, active or cooling down
+#[derive(Component, Clone, Copy)]
+pub struct DisasterStatusIndicator {
+    pub disaster_type: DisasterType,
+}
+
+/// Marker for the panel listing active disasters
+#[derive(Component)]
+pub struct ActiveDisastersPanel;
+
+/// Marker for the text inside the active disasters panel
+#[derive(Component)]
+pub struct ActiveDisastersText;
+
+/// Marker for the row of compact disaster icons inside the active disasters panel
+#[derive(Component)]
+pub struct ActiveDisastersIcons;
+
+/// Icon of one active disaster in compact mode; its outline ring fades as time runs out
+#[derive(Component, Clone, Copy)]
+pub struct DisasterIcon {
+    pub disaster_type: DisasterType,
+}
+
+/// Marker for the forecast line inside the active disasters panel
+#[derive(Component)]
+pub struct ForecastText;
+
+/// Marker for the settings panel
+#[derive(Component)]
+pub struct SettingsPanel;
+
+/// Settings panel toggle button and whether the panel is open
+#[derive(Component)]
+pub struct SettingsToggle {
+    pub is_visible: bool,
+}
+
+/// Buttons along the bottom and header of the settings panel
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SettingsAction {
+    ClosePanel,
+    ResetToDefaults,
+    SaveSettings,
+    ApplySettings,
+    LoadSettings,
+    LoadTheme, // Load the shared theme file and recolor the panels
+}
+
+/// A settings panel button and the action it performs
+#[derive(Component)]
+pub struct SettingsButton {
+    pub action: SettingsAction,
+}
+
+/// Tabs of the settings panel
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SettingsCategoryType {
+    Visual,
+    Accessibility,
+    Application,
+}
+
+/// A settings tab button
+#[derive(Component)]
+pub struct SettingsCategory {
+    pub category: SettingsCategoryType,
+    pub is_active: bool,
+}
+
+/// Individual settings offered in the panel
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SettingType {
+    VisualEffectsToggle,
+    UIScale,
+    ColorTheme,
+    WorldTheme,
+    PerformanceMode,
+    DisasterPanelMode,
+}
+
+/// A settings row or control and the setting it edits
+#[derive(Component)]
+pub struct SettingItem {
+    pub setting_type: SettingType,
+}
+
+/// Player preferences edited in the settings panel
+#[derive(Resource, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UserSettings {
+    pub visual_effects_enabled: bool,
+    pub ui_scale: f32,
+    pub performance_mode: bool,
+    pub expanded_stat_sections: Vec<StatSection>, // Statistics panel sections left open
+    #[serde(default)]
+    pub overlay_colors: Vec<OverlayColorSetting>, // Disaster tints replacing the built-in ones
+    #[serde(default)]
+    pub world_theme: WorldTheme, // Background behind the world view
+    #[serde(default)]
+    pub panel_positions: Vec<PanelPosition>, // Panels moved away from their default anchor
+    #[serde(default)]
+    pub disaster_panel_mode: DisasterPanelMode, // Labeled list or compact icons
+}
+
+/// How the active disasters panel shows each disaster
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DisasterPanelMode {
+    #[default]
+    Detailed, // Name and seconds left per disaster, plus combined effects
+    Compact, // One colored icon per disaster with a thin ring for the time left
+}
+
+impl DisasterPanelMode {
+    pub const ALL: [DisasterPanelMode; 2] =
+        [DisasterPanelMode::Detailed, DisasterPanelMode::Compact];
+
+    /// Localization key of the mode's name
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            DisasterPanelMode::Detailed => "settings.disaster_panel.detailed",
+            DisasterPanelMode::Compact => "settings.disaster_panel.compact",
+        }
+    }
+
+    /// The mode after this one, wrapping around
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|mode| mode == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Background preset drawn behind the world view (Bevy's `ClearColor`)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WorldTheme {
+    #[default]
+    Night,
+    Daylight,
+    Sepia,
+}
+
+impl WorldTheme {
+    pub const ALL: [WorldTheme; 3] = [WorldTheme::Night, WorldTheme::Daylight, WorldTheme::Sepia];
+
+    /// How much of the background is lost at midnight
+    pub const NIGHT_DIMMING: f32 = 0.6;
+
+    /// Background color at full daylight
+    pub fn background(&self) -> Color {
+        match self {
+            WorldTheme::Night => Color::srgb(0.0, 0.0, 0.0),
+            WorldTheme::Daylight => Color::srgb(0.62, 0.78, 0.9),
+            WorldTheme::Sepia => Color::srgb(0.44, 0.33, 0.2),
+        }
+    }
+
+    /// Background for a time of day, dimmed at night like the day/night overlay
+    pub fn background_at(&self, time_of_day: f32) -> Color {
+        let brightness = 1.0 - night_darkness(time_of_day) * Self::NIGHT_DIMMING;
+        let [r, g, b, _] = self.background().to_srgba().to_f32_array();
+        Color::srgb(r * brightness, g * brightness, b * brightness)
+    }
+
+    /// Localization key of the preset's name
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            WorldTheme::Night => "settings.world_theme.night",
+            WorldTheme::Daylight => "settings.world_theme.daylight",
+            WorldTheme::Sepia => "settings.world_theme.sepia",
+        }
+    }
+
+    /// The preset after this one, wrapping around
+    pub fn next(&self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|theme| theme == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// How dark it is at a time of day (0.0 and 1.0 are midnight): 1.0 at midnight, 0.0 at noon
+pub fn night_darkness(time_of_day: f32) -> f32 {
+    ((time_of_day * std::f32::consts::TAU).cos() + 1.0) / 2.0
+}
+
+/// A user's replacement tint for one disaster overlay
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OverlayColorSetting {
+    pub disaster_type: DisasterType,
+    pub rgba: [f32; 4], // sRGB color and opacity
+}
+
+impl Default for UserSettings {
+    fn default() -> Self {
+        Self {
+            visual_effects_enabled: true,
+            ui_scale: 1.0,
+            performance_mode: false,
+            expanded_stat_sections: StatSection::ALL.to_vec(),
+            overlay_colors: Vec::new(),
+            world_theme: WorldTheme::default(),
+            panel_positions: Vec::new(),
+            disaster_panel_mode: DisasterPanelMode::default(),
+        }
+    }
+}
+
+impl UserSettings {
+    /// Where the panel was last dragged to, if it has been moved
+    pub fn panel_position(&self, panel: &str) -> Option<Vec2> {
+        self.panel_positions
+            .iter()
+            .find(|position| position.panel == panel)
+            .map(|position| Vec2::new(position.left, position.top))
+    }
+
+    pub fn set_panel_position(&mut self, panel: &str, position: Vec2) {
+        match self
+            .panel_positions
+            .iter_mut()
+            .find(|saved| saved.panel == panel)
+        {
+            Some(saved) => {
+                saved.left = position.x;
+                saved.top = position.y;
+            }
+            None => self.panel_positions.push(PanelPosition {
+                panel: panel.to_string(),
+                left: position.x,
+                top: position.y,
+            }),
+        }
+    }
+
+    pub fn is_section_expanded(&self, section: StatSection) -> bool {
+        self.expanded_stat_sections.contains(&section)
+    }
+
+    /// Collapse an expanded statistics section or expand a collapsed one
+    pub fn toggle_section(&mut self, section: StatSection) {
+        if self.is_section_expanded(section) {
+            self.expanded_stat_sections.retain(|open| *open != section);
+        } else {
+            self.expanded_stat_sections.push(section);
+        }
+    }
+}
+
+/// Marker for the colony statistics panel
+#[derive(Component)]
+pub struct StatisticsPanel;
+
+/// Whether the statistics panel is open
+#[derive(Component, Default)]
+pub struct StatisticsToggle {
+    pub is_visible: bool,
+}
+
+/// Collapsible group of figures in the statistics panel
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StatSection {
+    Population,
+    Resources,
+    Environment,
+    Nest,
+    Behavior,
+    Roles,
+}
+
+impl StatSection {
+    pub const ALL: [StatSection; 6] = [
+        StatSection::Population,
+        StatSection::Resources,
+        StatSection::Environment,
+        StatSection::Nest,
+        StatSection::Behavior,
+        StatSection::Roles,
+    ];
+
+    /// Localization key of the section's title
+    pub fn title_key(self) -> &'static str {
+        match self {
+            StatSection::Population => "stats.section.population",
+            StatSection::Resources => "stats.section.resources",
+            StatSection::Environment => "stats.section.environment",
+            StatSection::Nest => "stats.section.nest",
+            StatSection::Behavior => "stats.section.behavior",
+            StatSection::Roles => "stats.section.roles",
+        }
+    }
+}
+
+/// Header button that collapses or expands a statistics section
+#[derive(Component, Clone, Copy)]
+pub struct StatisticsSectionHeader {
+    pub section: StatSection,
+}
+
+/// Label text of a section header
+#[derive(Component, Clone, Copy)]
+pub struct StatisticsSectionLabel {
+    pub section: StatSection,
+}
+
+/// Container holding one section's figures
+#[derive(Component, Clone, Copy)]
+pub struct StatisticsSectionContent {
+    pub section: StatSection,
+}
+
+/// The four development phases of a Camponotus japonicus colony, ordered from youngest
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum DevelopmentPhase {
+    QueenFounding,
+    FirstWorkers,
+    ColonyExpansion,
+    MatureColony,
+}
+
+impl DevelopmentPhase {
+    pub fn next_phase(&self) -> Option<DevelopmentPhase> {
+        match self {
+            DevelopmentPhase::QueenFounding => Some(DevelopmentPhase::FirstWorkers),
+            DevelopmentPhase::FirstWorkers => Some(DevelopmentPhase::ColonyExpansion),
+            DevelopmentPhase::ColonyExpansion => Some(DevelopmentPhase::MatureColony),
+            DevelopmentPhase::MatureColony => None,
+        }
+    }
+
+    /// Share of the maximum foraging radius open to the colony's foragers
+    ///
+    /// A young colony forages close to the nest and ranges farther as it matures.
+    pub fn foraging_range_scale(&self) -> f32 {
+        match self {
+            DevelopmentPhase::QueenFounding => 0.2,
+            DevelopmentPhase::FirstWorkers => 0.35,
+            DevelopmentPhase::ColonyExpansion => 0.6,
+            DevelopmentPhase::MatureColony => 1.0,
+        }
+    }
+
+    /// Latest phase whose worker target `workers` has passed, ignoring the other conditions
+    ///
+    /// Stands in for the phase of colonies without their own development tracking.
+    pub fn for_worker_count(workers: usize) -> Self {
+        let mut phase = DevelopmentPhase::QueenFounding;
+        while let Some(next) = phase.next_phase() {
+            if workers < PhaseConditions::for_phase(phase).target_worker_count {
+                break;
+            }
+            phase = next;
+        }
+        phase
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            DevelopmentPhase::QueenFounding => "Queen's Independent Founding",
+            DevelopmentPhase::FirstWorkers => "First Workers",
+            DevelopmentPhase::ColonyExpansion => "Colony Expansion",
+            DevelopmentPhase::MatureColony => "Mature Colony",
+        }
+    }
+
+    /// Localization key of the phase's name
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            DevelopmentPhase::QueenFounding => "phase.queen_founding",
+            DevelopmentPhase::FirstWorkers => "phase.first_workers",
+            DevelopmentPhase::ColonyExpansion => "phase.colony_expansion",
+            DevelopmentPhase::MatureColony => "phase.mature_colony",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            DevelopmentPhase::QueenFounding => "The queen raises the first brood alone",
+            DevelopmentPhase::FirstWorkers => "The first workers take over foraging and digging",
+            DevelopmentPhase::ColonyExpansion => "The colony grows quickly and the nest expands",
+            DevelopmentPhase::MatureColony => "A stable colony maintaining its population",
+        }
+    }
+}
+
+/// Criteria that must all be met before a phase can advance
+#[derive(Clone, Debug)]
+pub struct PhaseConditions {
+    pub min_days_in_phase: f32,
+    pub target_worker_count: usize,
+    pub required_nest_complexity: usize, // Chambers plus excavated cells
+    pub stability_threshold: f32,
+}
+
+impl PhaseConditions {
+    pub fn for_phase(phase: DevelopmentPhase) -> Self {
+        match phase {
+            DevelopmentPhase::QueenFounding => Self {
+                min_days_in_phase: 1.0,
+                target_worker_count: 5,
+                required_nest_complexity: 1,
+                stability_threshold: 0.5,
+            },
+            DevelopmentPhase::FirstWorkers => Self {
+                min_days_in_phase: 3.0,
+                target_worker_count: 20,
+                required_nest_complexity: 10,
+                stability_threshold: 0.7,
+            },
+            DevelopmentPhase::ColonyExpansion => Self {
+                min_days_in_phase: 7.0,
+                target_worker_count: 50,
+                required_nest_complexity: 30,
+                stability_threshold: 0.8,
+            },
+            DevelopmentPhase::MatureColony => Self {
+                min_days_in_phase: f32::INFINITY,
+                target_worker_count: usize::MAX,
+                required_nest_complexity: usize::MAX,
+                stability_threshold: 0.8,
+            },
+        }
+    }
+}
+
+/// Current development phase of the colony and progress toward the next one
+#[derive(Resource, Clone, Debug)]
+pub struct ColonyDevelopmentPhase {
+    pub current_phase: DevelopmentPhase,
+    pub phase_started_at: f32, // Days elapsed when the current phase began
+    pub time_in_phase: f32,    // Days spent in the current phase
+    pub phase_progress: f32,   // 0.0 - 1.0, bottleneck of all criteria
+    pub phase_conditions: PhaseConditions,
+}
+
+impl Default for ColonyDevelopmentPhase {
+    fn default() -> Self {
+        Self {
+            current_phase: DevelopmentPhase::QueenFounding,
+            phase_started_at: 0.0,
+            time_in_phase: 0.0,
+            phase_progress: 0.0,
+            phase_conditions: PhaseConditions::for_phase(DevelopmentPhase::QueenFounding),
+        }
+    }
+}
+
+/// Recent notable colony happenings, newest last
+#[derive(Resource, Clone, Debug)]
+pub struct EventLog {
+    pub entries: std::collections::VecDeque<String>,
+    pub capacity: usize, // Oldest entries are dropped beyond this
+}
+
+impl Default for EventLog {
+    fn default() -> Self {
+        Self {
+            entries: std::collections::VecDeque::new(),
+            capacity: 100,
+        }
+    }
+}
+
+impl EventLog {
+    pub fn push(&mut self, entry: impl Into<String>) {
+        self.entries.push_back(entry.into());
+        while self.entries.len() > self.capacity {
+            self.entries.pop_front();
+        }
+    }
+}
+
+/// Brief pulse drawn on an ant that just switched roles
+#[derive(Component, Clone, Debug)]
+pub struct RoleFlash {
+    pub remaining: f32, // Seconds left
+}
+
+/// Short-lived pulse drawn at the queen when she lays an egg
+#[derive(Component, Clone, Debug)]
+pub struct EggLayingPulse {
+    pub remaining: f32, // Seconds left
+}
+
+/// Specialized task an individual worker performs
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SpecializedRole {
+    GeneralWorker,
+    Forager,
+    NurseryWorker,
+    NestMaintainer,
+    StorageWorker,
+}
+
+impl SpecializedRole {
+    /// Pick a role suited to the ant's age (young ants work inside, older ants forage)
+    pub fn random_for_age(age_group: AntAgeGroup, rng: &mut impl rand::Rng) -> Self {
+        let roll: f32 = rng.gen();
+        match age_group {
+            AntAgeGroup::Young if roll < 0.4 => SpecializedRole::NurseryWorker,
+            AntAgeGroup::Young if roll < 0.7 => SpecializedRole::NestMaintainer,
+            AntAgeGroup::Adult if roll < 0.5 => SpecializedRole::Forager,
+            AntAgeGroup::Adult if roll < 0.7 => SpecializedRole::NestMaintainer,
+            AntAgeGroup::Adult if roll < 0.8 => SpecializedRole::StorageWorker,
+            AntAgeGroup::Senior if roll < 0.8 => SpecializedRole::Forager,
+            AntAgeGroup::Senior => SpecializedRole::NestMaintainer,
+            _ => SpecializedRole::GeneralWorker,
+        }
+    }
+
+    /// Whether the role keeps the ant inside the nest instead of foraging
+    pub fn works_inside_nest(&self) -> bool {
+        matches!(
+            self,
+            SpecializedRole::NurseryWorker
+                | SpecializedRole::NestMaintainer
+                | SpecializedRole::StorageWorker
+        )
+    }
+}
+
+/// Tuning for chamber microclimates and their regulation by workers
+#[derive(Resource, Clone, Debug)]
+pub struct ChamberClimateConfig {
+    pub optimal_min: f32,           // Lower bound of the brood-friendly band (°C)
+    pub optimal_max: f32,           // Upper bound of the brood-friendly band (°C)
+    pub drift_rate: f32,            // Fraction of the gap to the soil closed per second
+    pub soil_sample_margin: f32,    // Soil within radius + margin counts as surroundings
+    pub buffer_per_maintainer: f32, // Drift reduction contributed by each maintainer
+    pub max_buffer: f32,            // Upper limit on the combined drift reduction
+    pub regulation_rate: f32,       // °C per second each maintainer pushes toward the band
+}
+
+impl Default for ChamberClimateConfig {
+    fn default() -> Self {
+        Self {
+            optimal_min: 22.0,
+            optimal_max: 28.0,
+            drift_rate: 0.05,
+            soil_sample_margin: 6.0,
+            buffer_per_maintainer: 0.2,
+            max_buffer: 0.8,
+            regulation_rate: 0.02,
+        }
+    }
+}
+
+impl ChamberClimateConfig {
+    /// Incubation speed multiplier for brood kept at the given temperature
+    ///
+    /// Full speed inside the optimal band, falling off linearly to a crawl 15°C outside it.
+    pub fn incubation_speed(&self, temperature: f32) -> f32 {
+        let distance = if temperature < self.optimal_min {
+            self.optimal_min - temperature
+        } else if temperature > self.optimal_max {
+            temperature - self.optimal_max
+        } else {
+            0.0
+        };
+        (1.0 - distance / 15.0).max(0.1)
+    }
+}
+
+/// An egg incubating until it hatches into a worker
+#[derive(Component, Clone)]
+pub struct Egg {
+    pub incubation_time: f32, // Seconds left at full incubation speed
+}
+
+/// Queen an egg came from, kept by the worker that hatches from it
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct LaidBy {
+    pub queen: Entity,
+}
+
+/// Egg laying state of a queen
+#[derive(Component, Clone, Serialize, Deserialize)]
+pub struct ReproductionState {
+    pub time_since_last_egg: f32,
+    pub egg_laying_interval: f32,   // Seconds between eggs
+    pub reproductive_capacity: f32, // 0.0 - 1.0, reduced by poor nutrition and crowding
+}
+
+impl Default for ReproductionState {
+    fn default() -> Self {
+        Self {
+            time_since_last_egg: 0.0,
+            egg_laying_interval: 10.0,
+            reproductive_capacity: 1.0,
+        }
+    }
+}
+
+/// How active disasters speed up or slow down egg laying and hatching
+///
+/// Each factor multiplies the queen's laying pace or the brood's development while
+/// that disaster is active; 1.0 leaves it unchanged.
+#[derive(Resource, Clone, Debug)]
+pub struct DisasterReproductionConfig {
+    pub cold_snap_laying: f32,   // The queen stops laying in the cold
+    pub cold_snap_hatching: f32, // Chilled brood develops slowly
+    pub drought_laying: f32,     // Food scarcity holds back laying
+    pub rain_laying: f32,        // Moderate rain brings a flush of food and laying
+}
+
+impl Default for DisasterReproductionConfig {
+    fn default() -> Self {
+        Self {
+            cold_snap_laying: 0.0,
+            cold_snap_hatching: 0.5,
+            drought_laying: 0.5,
+            rain_laying: 1.1,
+        }
+    }
+}
+
+impl DisasterReproductionConfig {
+    /// Multiplier on the queen's laying pace under the active disasters
+    pub fn laying_factor(&self, disaster_state: &DisasterState) -> f32 {
+        [
+            (DisasterType::ColdSnap, self.cold_snap_laying),
+            (DisasterType::Drought, self.drought_laying),
+            (DisasterType::Rain, self.rain_laying),
+        ]
+        .into_iter()
+        .filter(|(disaster_type, _)| disaster_state.is_active(*disaster_type))
+        .map(|(_, factor)| factor.max(0.0))
+        .product()
+    }
+
+    /// Multiplier on brood development under the active disasters
+    pub fn hatching_factor(&self, disaster_state: &DisasterState) -> f32 {
+        if disaster_state.is_active(DisasterType::ColdSnap) {
+            self.cold_snap_hatching.max(0.0)
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Protein the brood draws from the colony store
+#[derive(Resource, Clone)]
+pub struct BroodNutritionConfig {
+    pub egg_protein_cost: f32,   // Protein the queen needs to lay one egg
+    pub larva_protein_rate: f32, // Protein per second of development for each egg
+}
+
+impl Default for BroodNutritionConfig {
+    fn default() -> Self {
+        Self {
+            egg_protein_cost: 2.0,
+            larva_protein_rate: 0.2,
+        }
+    }
+}
+
+/// A group the colony feeds from its carbohydrate store
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeedingGroup {
+    Queen,
+    Brood,
+    Workers,
+}
+
+/// How the colony shares out its carbohydrates when there is not enough for everyone
+///
+/// Each tick the groups are fed in `order`, so the first ones keep eating while the
+/// store runs dry for the rest.
+#[derive(Resource, Clone, Debug)]
+pub struct FeedingPriorityConfig {
+    pub order: Vec<FeedingGroup>, // Fed first to last
+    pub queen_upkeep: f32,        // Food per second that keeps a queen nourished
+    pub brood_upkeep: f32,        // Food per second each egg needs
+    pub reserve_seconds: f32,     // Seconds it takes a fully nourished queen or egg to starve
+    pub worker_hunger: f32,       // Energy fraction below which a worker asks to be fed
+}
+
+impl Default for FeedingPriorityConfig {
+    fn default() -> Self {
+        Self {
+            order: vec![
+                FeedingGroup::Queen,
+                FeedingGroup::Brood,
+                FeedingGroup::Workers,
+            ],
+            queen_upkeep: 0.5,
+            brood_upkeep: 0.05,
+            reserve_seconds: 10.0,
+            worker_hunger: 0.5,
+        }
+    }
+}
+
+/// How well fed a queen or an egg is, from 0.0 (starving) to 1.0
+///
+/// A starving queen stops laying and a starving egg stops developing. Only the
+/// feeding priority system adds it; without it they count as well fed.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Nourishment(pub f32);
+
+impl Default for Nourishment {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+impl Nourishment {
+    /// Level under which the queen or egg is too hungry to lay or develop
+    pub const STARVING: f32 = 0.25;
+
+    pub fn is_starving(&self) -> bool {
+        self.0 < Self::STARVING
+    }
+}
+
+/// Brood care the colony must provide: a nursery with room and enough nurses in it
+#[derive(Resource, Clone, Debug)]
+pub struct BroodCareConfig {
+    pub min_nurses: usize,             // Nursery workers needed to tend the brood
+    pub unattended_laying_factor: f32, // Egg-laying speed while the brood is unattended
+    pub unattended_loss_rate: f32,     // Chance per second an unattended egg dies
+}
+
+impl Default for BroodCareConfig {
+    fn default() -> Self {
+        Self {
+            min_nurses: 1,
+            unattended_laying_factor: 0.25,
+            unattended_loss_rate: 0.02,
+        }
+    }
+}
+
+/// Occasional brood batches, where a well-resourced queen lays several eggs at once
+#[derive(Resource, Clone)]
+pub struct BroodBatchConfig {
+    pub batch_chance: f32,         // Chance a laying event becomes a batch
+    pub min_vigor: f32,            // Queen vigor needed before batches can happen
+    pub min_protein_reserve: f32,  // Protein the store must hold beyond the batch's cost
+    pub full_protein_reserve: f32, // Reserve at which batches reach full size
+    pub max_batch_size: u32,       // Eggs in a batch from a peak-vigor, fully-fed queen
+}
+
+impl Default for BroodBatchConfig {
+    fn default() -> Self {
+        Self {
+            batch_chance: 0.15,
+            min_vigor: 1.1,
+            min_protein_reserve: 10.0,
+            full_protein_reserve: 40.0,
+            max_batch_size: 6,
+        }
+    }
+}
+
+impl BroodBatchConfig {
+    /// Eggs a queen lays in a batch, scaled by her vigor and the protein store; 1 means no batch
+    pub fn batch_size(&self, queen_vigor: f32, protein: f32) -> u32 {
+        if self.max_batch_size <= 1
+            || queen_vigor < self.min_vigor
+            || protein < self.min_protein_reserve
+        {
+            return 1;
+        }
+        let vigor_factor =
+            ((queen_vigor - self.min_vigor) / (ColonyTraits::MAX - self.min_vigor)).clamp(0.0, 1.0);
+        let food_factor = ((protein - self.min_protein_reserve)
+            / (self.full_protein_reserve - self.min_protein_reserve).max(f32::EPSILON))
+        .clamp(0.0, 1.0);
+        let extra = (self.max_batch_size.saturating_sub(2)) as f32 * vigor_factor * food_factor;
+        2 + extra.round() as u32
+    }
+}
+
+/// Worker size class of a size-polymorphic Camponotus colony
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CasteClass {
+    Minor,
+    Media,
+    Major,
+}
+
+impl CasteClass {
+    /// Sprite edge length in pixels
+    pub fn sprite_size(&self) -> f32 {
+        match self {
+            CasteClass::Minor => 1.5,
+            CasteClass::Media => 2.0,
+            CasteClass::Major => 3.0,
+        }
+    }
+
+    /// Multiplier on the food carried per trip
+    pub fn carry_multiplier(&self) -> f32 {
+        match self {
+            CasteClass::Minor => 0.7,
+            CasteClass::Media => 1.0,
+            CasteClass::Major => 1.6,
+        }
+    }
+
+    /// Multiplier on walking speed; majors are slow foragers
+    pub fn speed_multiplier(&self) -> f32 {
+        match self {
+            CasteClass::Minor => 1.1,
+            CasteClass::Media => 1.0,
+            CasteClass::Major => 0.7,
+        }
+    }
+
+    /// Fighting strength against invaders
+    pub fn combat_strength(&self) -> f32 {
+        match self {
+            CasteClass::Minor => 1.0,
+            CasteClass::Media => 1.5,
+            CasteClass::Major => 3.0,
+        }
+    }
+
+    /// Hit points of a healthy worker
+    pub fn max_hp(&self) -> f32 {
+        match self {
+            CasteClass::Minor => 60.0,
+            CasteClass::Media => 100.0,
+            CasteClass::Major => 160.0,
+        }
+    }
+}
+
+/// Size class of a worker, fixed at hatching
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Caste {
+    pub size_class: CasteClass,
+}
+
+/// What a freshly spawned worker of the colony's species looks like
+///
+/// Bundles where workers appear, which castes they roll and how long they live, so bulk
+/// spawns produce the same ants as the founding spawn.
+#[derive(Clone, Default)]
+pub struct SpeciesConfig {
+    pub placement: InitialSpawnConfig,
+    pub castes: CasteConfig,
+    pub lifespan: LifespanModel,
+}
+
+/// Share of each caste among newly hatched workers
+#[derive(Resource, Clone, Debug)]
+pub struct CasteConfig {
+    pub minor_weight: f32,
+    pub media_weight: f32,
+    pub major_weight: f32,
+}
+
+impl Default for CasteConfig {
+    fn default() -> Self {
+        Self {
+            minor_weight: 0.6,
+            media_weight: 0.3,
+            major_weight: 0.1,
+        }
+    }
+}
+
+impl CasteConfig {
+    /// Roll the caste of a hatching worker according to the configured proportions
+    pub fn roll(&self, rng: &mut impl rand::Rng) -> CasteClass {
+        let total = self.minor_weight + self.media_weight + self.major_weight;
+        if total <= 0.0 {
+            return CasteClass::Media;
+        }
+
+        let roll = rng.gen::<f32>() * total;
+        if roll < self.minor_weight {
+            CasteClass::Minor
+        } else if roll < self.minor_weight + self.media_weight {
+            CasteClass::Media
+        } else {
+            CasteClass::Major
+        }
+    }
+
+    /// Caste shares for a colony with the given traits
+    ///
+    /// A vigorous queen raises better-fed larvae, so media and especially major workers
+    /// become more common; a weak one hatches mostly minors.
+    pub fn for_traits(&self, traits: &ColonyTraits, influence: f32) -> CasteConfig {
+        let scale = (1.0 + (traits.queen_vigor - 1.0) * influence).max(0.0);
+        CasteConfig {
+            minor_weight: self.minor_weight,
+            media_weight: self.media_weight * scale,
+            major_weight: self.major_weight * scale * scale,
+        }
+    }
+}
+
+/// How strongly a colony's traits shape the workers it hatches
+///
+/// Each influence scales how far a trait's distance from an average colony (1.0)
+/// carries over to the hatchling; 0.0 hatches generic workers.
+#[derive(Resource, Clone, Debug)]
+pub struct HatchTraitConfig {
+    pub speed_influence: f32,      // Worker efficiency → walking speed
+    pub efficiency_influence: f32, // Worker efficiency → food carried per trip
+    pub caste_influence: f32,      // Queen vigor → share of larger castes
+}
+
+impl Default for HatchTraitConfig {
+    fn default() -> Self {
+        Self {
+            speed_influence: 0.5,
+            efficiency_influence: 1.0,
+            caste_influence: 1.0,
+        }
+    }
+}
+
+/// Per-worker multipliers set from the colony's traits when the worker hatches
+#[derive(Component, Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BehaviorModifiers {
+    pub speed: f32,               // Walking speed multiplier
+    pub foraging_efficiency: f32, // Multiplier on food carried per trip
+}
+
+impl Default for BehaviorModifiers {
+    fn default() -> Self {
+        Self {
+            speed: 1.0,
+            foraging_efficiency: 1.0,
+        }
+    }
+}
+
+impl BehaviorModifiers {
+    /// Modifiers for a worker hatched into a colony with the given traits
+    pub fn from_traits(traits: &ColonyTraits, config: &HatchTraitConfig) -> Self {
+        let scaled = |influence: f32| (1.0 + (traits.worker_efficiency - 1.0) * influence).max(0.1);
+        Self {
+            speed: scaled(config.speed_influence),
+            foraging_efficiency: scaled(config.efficiency_influence),
+        }
+    }
+}
+
+/// Natural lifespan of workers, sampled when they spawn or hatch
+///
+/// Lifespans are spread evenly around the mean, then scaled by the worker's caste,
+/// so short-lived and long-lived strains are one config away.
+#[derive(Resource, Clone, Debug)]
+pub struct LifespanModel {
+    pub mean: f32,             // Average lifespan in seconds
+    pub spread: f32,           // Lifespans fall within mean ± spread
+    pub minor_multiplier: f32, // Lifespan scale for minor workers
+    pub media_multiplier: f32, // Lifespan scale for media workers
+    pub major_multiplier: f32, // Lifespan scale for major workers
+}
+
+impl Default for LifespanModel {
+    fn default() -> Self {
+        Self {
+            mean: 900.0, // 10-20 minutes of real time
+            spread: 300.0,
+            minor_multiplier: 1.0,
+            media_multiplier: 1.0,
+            major_multiplier: 1.0,
+        }
+    }
+}
+
+impl LifespanModel {
+    /// Roll the natural lifespan of a worker of the given caste
+    pub fn sample(&self, caste: CasteClass, rng: &mut impl rand::Rng) -> f32 {
+        let multiplier = match caste {
+            CasteClass::Minor => self.minor_multiplier,
+            CasteClass::Media => self.media_multiplier,
+            CasteClass::Major => self.major_multiplier,
+        };
+        let lifespan = self.mean + rng.gen_range(-1.0..=1.0) * self.spread;
+        (lifespan * multiplier).max(1.0)
+    }
+}
+
+/// How the founding workers are spread out when a colony starts
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpawnDistribution {
+    Cluster,   // A tight group around the colony home
+    Scattered, // Spread widely around the colony home
+}
+
+/// Where and how many founding workers and queens spawn at startup
+#[derive(Resource, Clone)]
+pub struct InitialSpawnConfig {
+    pub home: Position, // Colony home the workers gather around
+    pub ant_count: usize,
+    pub queens_per_colony: usize, // Above 1 the colony is polygynous, its queens sharing a chamber
+    pub distribution: SpawnDistribution,
+    pub cluster_radius: f32, // Spawn radius of a tight cluster
+    pub scatter_radius: f32, // Spawn radius of a scattered start
+}
+
+impl Default for InitialSpawnConfig {
+    fn default() -> Self {
+        Self {
+            home: Position { x: 0.0, y: 0.0 }, // Nest entrance
+            ant_count: 5,
+            queens_per_colony: 1,
+            distribution: SpawnDistribution::Cluster,
+            cluster_radius: 16.0,
+            scatter_radius: 60.0,
+        }
+    }
+}
+
+impl InitialSpawnConfig {
+    /// Spawn radius of the configured distribution
+    pub fn radius(&self) -> f32 {
+        match self.distribution {
+            SpawnDistribution::Cluster => self.cluster_radius,
+            SpawnDistribution::Scattered => self.scatter_radius,
+        }
+    }
+
+    /// Roll a spawn position within the radius of home, at or below the surface
+    ///
+    /// A cluster packs evenly into a half-disc under home; a scattered start spreads
+    /// across the full width so workers end up far apart.
+    pub fn sample_position(&self, rng: &mut impl rand::Rng) -> Position {
+        let radius = self.radius();
+        let (dx, depth) = match self.distribution {
+            SpawnDistribution::Cluster => {
+                let distance = radius * rng.gen::<f32>().sqrt();
+                let angle = rng.gen_range(0.0..std::f32::consts::PI);
+                (distance * angle.cos(), distance * angle.sin())
+            }
+            SpawnDistribution::Scattered => (
+                rng.gen_range(-radius..=radius),
+                rng.gen_range(0.0..=radius * 0.5),
+            ),
+        };
+        Position {
+            x: self.home.x + dx,
+            y: self.home.y - depth,
+        }
+    }
+}
+
+/// A competing insect introduced by the invasive species disaster
+#[derive(Component, Clone, Debug)]
+pub struct InvasiveSpecies {
+    pub lifetime: f32,              // Seconds before it leaves
+    pub food_consumption_rate: f32, // Nutrition eaten per second from nearby food
+    pub strength: f32,              // Fighting strength against ants
+}
+
+/// Invader belonging to an invasive nest, which it forages for
+#[derive(Component, Clone, Copy, Debug)]
+pub struct NestInvader {
+    pub nest: Entity,
+}
+
+/// A competing colony founded by the invasive species disaster
+///
+/// It releases invaders at a quickening pace until the disaster ends or the native
+/// colony wears its health down.
+#[derive(Component, Clone, Debug)]
+pub struct InvasiveNest {
+    pub spawn_timer: f32,    // Seconds since the last invader left the nest
+    pub spawn_interval: f32, // Seconds until the next one does
+    pub health: f32,
+}
+
+/// How an invasive species disaster arrives
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InvasionMode {
+    #[default]
+    Scattered, // Lone invaders wander in anywhere on the surface
+    Nest, // Invaders cluster around a nest that keeps producing more
+}
+
+/// Tunable parameters of the invasive species disaster
+#[derive(Resource, Clone, Debug)]
+pub struct InvasionConfig {
+    pub mode: InvasionMode,
+    pub nest_spawn_interval: f32, // Seconds between invaders from a newly founded nest
+    pub nest_spawn_acceleration: f32, // Interval multiplier after each invader leaves
+    pub min_nest_spawn_interval: f32, // Fastest a nest can produce invaders
+    pub nest_health: f32,         // Damage the native colony must deal to destroy a nest
+    pub nest_radius: f32,         // Distance at which ants attack the nest
+    pub nest_damage_rate: f32,    // Nest damage per second per unit of ant strength
+}
+
+impl Default for InvasionConfig {
+    fn default() -> Self {
+        Self {
+            mode: InvasionMode::Scattered,
+            nest_spawn_interval: 3.0,
+            nest_spawn_acceleration: 0.85,
+            min_nest_spawn_interval: 0.75,
+            nest_health: 100.0,
+            nest_radius: 4.0,
+            nest_damage_rate: 2.0,
+        }
+    }
+}
+
+/// Stats of individual invaders, setting how hard an invasion is to weather
+#[derive(Resource, Clone, Debug)]
+pub struct InvasiveSpeciesConfig {
+    pub spawn_count: usize,            // Upper limit on invaders alive at once
+    pub lifetime_range: (f32, f32),    // Seconds an invader stays, rolled per invader
+    pub consumption_range: (f32, f32), // Nutrition eaten per second, rolled per invader
+    pub movement_speed: f32,           // Wandering speed in pixels per second
+    pub aggression: f32,               // Fights per second with an ant in contact
+}
+
+impl Default for InvasiveSpeciesConfig {
+    fn default() -> Self {
+        Self {
+            spawn_count: 15,
+            lifetime_range: (15.0, 25.0),
+            consumption_range: (2.0, 4.0),
+            movement_speed: 20.0,
+            aggression: 1.0,
+        }
+    }
+}
+
+/// Seeded random source for the world spawners, set when a run should be reproducible
+#[derive(Resource, Clone, Debug)]
+pub struct SimulationRng(pub rand::rngs::StdRng);
+
+impl SimulationRng {
+    pub fn from_seed(seed: u64) -> Self {
+        Self(rand::SeedableRng::seed_from_u64(seed))
+    }
+
+    /// Derive an independent generator for one spawner
+    pub fn fork(&mut self) -> rand::rngs::StdRng {
+        rand::SeedableRng::seed_from_u64(rand::Rng::gen(&mut self.0))
+    }
+}
+
+/// Colony-wide figures recalculated every frame once movement has settled
+#[derive(Resource, Clone, Debug, Default)]
+pub struct ColonyStatistics {
+    pub total_ant_count: usize, // Workers, excluding queens
+    pub queen_count: usize,
+    pub egg_count: usize,
+    pub average_ant_energy: f32,
+    pub average_ant_hp: f32,
+    pub ants_foraging: usize,
+    pub ants_idle: usize,
+    pub ants_returning: usize,
+    pub ants_resting: usize,
+    pub ants_defending: usize,
+    pub minor_count: usize,
+    pub media_count: usize,
+    pub major_count: usize,
+    pub ants_carrying_food: usize,
+    pub ants_on_surface: usize,
+    pub ants_underground: usize,
+    pub average_ant_depth: f32, // Mean of -y over all ants, queens included
+    pub colony_food: f32,
+    pub colony_protein: f32,
+    pub max_nest_depth: f32, // -y of the deepest tunnel end, 0 before any digging
+    pub deepest_layer: Option<DepthLayer>,
+    pub total_tunnel_length: f32,
+    pub chambers_by_type: std::collections::HashMap<ChamberType, usize>,
+}
+
+impl ColonyStatistics {
+    /// Number of chambers of the given type
+    pub fn chamber_count(&self, chamber_type: ChamberType) -> usize {
+        self.chambers_by_type
+            .get(&chamber_type)
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+/// Marker for weather and disaster particles
+#[derive(Component)]
+pub struct Particle;
+
+/// Kind of disaster particle
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParticleType {
+    RainDrop,
+    DustMote,
+    Snowflake,
+    EnvironmentalDisturbance,
+}
+
+/// Motion and appearance of a single particle
+#[derive(Component, Clone, Debug)]
+pub struct ParticleData {
+    pub particle_type: ParticleType,
+    pub lifetime: f32, // Seconds left
+    pub max_lifetime: f32,
+    pub velocity: Vec2, // Pixels per second
+    pub size: Vec2,
+    pub color: Color, // Color at full life; fades in and out following a ParticleFade
+}
+
+/// Shape of an easing curve mapping progress (0.0 - 1.0) to eased progress
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EasingFunction {
+    #[default]
+    Linear,
+    EaseIn,    // Starts slow, finishes fast
+    EaseOut,   // Starts fast, finishes slow
+    EaseInOut, // Slow at both ends
+}
+
+impl EasingFunction {
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            EasingFunction::Linear => t,
+            EasingFunction::EaseIn => t * t,
+            EasingFunction::EaseOut => t * (2.0 - t),
+            EasingFunction::EaseInOut => t * t * (3.0 - 2.0 * t),
+        }
+    }
+}
+
+/// How particles fade in after spawning and out before expiring
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParticleFade {
+    pub fade_in_fraction: f32,  // Share of the lifetime spent fading in
+    pub fade_out_fraction: f32, // Share of the lifetime spent fading out
+    pub easing: EasingFunction, // Curve applied to both fades
+}
+
+impl Default for ParticleFade {
+    fn default() -> Self {
+        Self {
+            fade_in_fraction: 0.0,
+            fade_out_fraction: 1.0, // Fade steadily over the whole life
+            easing: EasingFunction::Linear,
+        }
+    }
+}
+
+impl ParticleFade {
+    /// Opacity multiplier (0.0 - 1.0) for a particle with `life_fraction` of its life left
+    pub fn alpha(&self, life_fraction: f32) -> f32 {
+        let remaining = life_fraction.clamp(0.0, 1.0);
+        let age = 1.0 - remaining;
+        let fade_in = if self.fade_in_fraction > 0.0 {
+            self.easing.apply(age / self.fade_in_fraction)
+        } else {
+            1.0
+        };
+        let fade_out = if self.fade_out_fraction > 0.0 {
+            self.easing.apply(remaining / self.fade_out_fraction)
+        } else {
+            1.0
+        };
+        fade_in.min(fade_out)
+    }
+}
+
+impl ParticleData {
+    fn new(
+        particle_type: ParticleType,
+        lifetime: f32,
+        velocity: Vec2,
+        size: Vec2,
+        color: Color,
+    ) -> Self {
+        Self {
+            particle_type,
+            lifetime,
+            max_lifetime: lifetime,
+            velocity,
+            size,
+            color,
+        }
+    }
+
+    pub fn new_rain_drop(lifetime: f32, velocity: Vec2) -> Self {
+        Self::new(
+            ParticleType::RainDrop,
+            lifetime,
+            velocity,
+            Vec2::new(1.0, 4.0),
+            Color::srgba(0.5, 0.7, 1.0, 0.8),
+        )
+    }
+
+    pub fn new_dust_mote(lifetime: f32, velocity: Vec2) -> Self {
+        Self::new(
+            ParticleType::DustMote,
+            lifetime,
+            velocity,
+            Vec2::new(2.0, 2.0),
+            Color::srgba(0.8, 0.7, 0.5, 0.6),
+        )
+    }
+
+    pub fn new_snowflake(lifetime: f32, velocity: Vec2) -> Self {
+        Self::new(
+            ParticleType::Snowflake,
+            lifetime,
+            velocity,
+            Vec2::new(2.5, 2.5),
+            Color::srgba(1.0, 1.0, 1.0, 0.9),
+        )
+    }
+
+    pub fn new_environmental_disturbance(lifetime: f32, velocity: Vec2) -> Self {
+        Self::new(
+            ParticleType::EnvironmentalDisturbance,
+            lifetime,
+            velocity,
+            Vec2::new(1.5, 1.5),
+            Color::srgba(0.9, 0.3, 0.3, 0.7),
+        )
+    }
+
+    /// Opacity for the remaining lifetime under the given fade curve
+    pub fn get_current_alpha(&self, fade: &ParticleFade) -> f32 {
+        let life_fraction = (self.lifetime / self.max_lifetime).clamp(0.0, 1.0);
+        self.color.alpha() * fade.alpha(life_fraction)
+    }
+
+    /// Base color with the faded alpha for the remaining lifetime
+    pub fn get_current_color(&self, fade: &ParticleFade) -> Color {
+        self.color.with_alpha(self.get_current_alpha(fade))
+    }
+}
+
+/// Marks an entity queued in [`PendingDespawn`], so systems can skip it until it is gone
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct Dying;
+
+/// Dead entities waiting to be despawned a bounded number per frame
+#[derive(Resource, Clone, Debug)]
+pub struct PendingDespawn {
+    pub queue: std::collections::VecDeque<Entity>,
+    pub queued: std::collections::HashSet<Entity>,
+    pub per_frame_budget: usize,
+}
+
+impl Default for PendingDespawn {
+    fn default() -> Self {
+        Self::with_budget(25)
+    }
+}
+
+impl PendingDespawn {
+    pub fn with_budget(per_frame_budget: usize) -> Self {
+        Self {
+            queue: std::collections::VecDeque::new(),
+            queued: std::collections::HashSet::new(),
+            per_frame_budget,
+        }
+    }
+
+    /// Queue an entity for removal; returns false if it was already queued
+    pub fn enqueue(&mut self, entity: Entity) -> bool {
+        if !self.queued.insert(entity) {
+            return false;
+        }
+        self.queue.push_back(entity);
+        true
+    }
+
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.queued.contains(&entity)
+    }
+
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Take up to the per-frame budget of entities off the front of the queue
+    pub fn next_batch(&mut self) -> Vec<Entity> {
+        let count = self.per_frame_budget.min(self.queue.len());
+        let batch: Vec<Entity> = self.queue.drain(..count).collect();
+        for entity in batch.iter() {
+            self.queued.remove(entity);
+        }
+        batch
+    }
+}
@@ -0,0 +1,65 @@
+//! Caller-driven simulation for notebooks, servers and tests
+//!
+//! [`headless_app`] builds the simulation on top of `MinimalPlugins` with
+//! [`AntNestSimulationPlugin`](crate::AntNestSimulationPlugin), so no window, input or UI
+//! systems are registered: panels, tooltips, particles, keyboard shortcuts and auto-save
+//! are all skipped. [`step`] then advances it by exactly the delta you pass, regardless of
+//! how much wall-clock time has passed. With [`EndConditions`](crate::components::EndConditions)
+//! set, [`run_until_ended`] steps until one of them is met. Insert
+//! [`DayLengthMode::Ticks`](crate::components::DayLengthMode::Ticks) to measure days in
+//! steps rather than simulated seconds.
+//!
+//! ```rust,no_run
+//! let mut app = ant_nest_simulator::headless_app();
+//! for _ in 0..600 {
+//!     ant_nest_simulator::step(&mut app, 0.1);
+//! }
+//! ```
+
+use crate::components::{EndReason, SimulationEnded};
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+/// Build an app running only the core simulation, ready to be advanced with [`step`]
+pub fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(crate::AntNestSimulationPlugin);
+    app
+}
+
+/// Advance the simulation by exactly one frame of `dt` seconds
+///
+/// Time is fed manually rather than read from the clock, and the usual cap on a single
+/// frame's delta is lifted so large steps are not shortened. The very first call also
+/// runs the startup systems in a zero-length frame before the requested step.
+pub fn step(app: &mut App, dt: f32) {
+    let duration = Duration::from_secs_f32(dt.max(0.0));
+    let world = app.world_mut();
+
+    let mut virtual_time = world.resource_mut::<Time<Virtual>>();
+    if virtual_time.max_delta() < duration {
+        virtual_time.set_max_delta(duration);
+    }
+    world.insert_resource(TimeUpdateStrategy::ManualDuration(duration));
+
+    // Real time only starts counting from its first update, which therefore has no delta
+    if world.resource::<Time<Real>>().last_update().is_none() {
+        app.update();
+    }
+    app.update();
+}
+
+/// Step by `dt` until an end condition is met or `max_steps` steps have run
+///
+/// Returns why the run ended, or `None` if it was still going after `max_steps`.
+pub fn run_until_ended(app: &mut App, dt: f32, max_steps: usize) -> Option<EndReason> {
+    for _ in 0..max_steps {
+        step(app, dt);
+        if let Some(reason) = app.world().resource::<SimulationEnded>().reason {
+            return Some(reason);
+        }
+    }
+    None
+}
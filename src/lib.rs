@@ -29,39 +29,480 @@
 #![allow(clippy::too_many_arguments)]
 
 pub mod components;
+pub mod embedding;
+pub mod localization;
+pub mod schedule;
+pub mod snapshot;
+pub mod strategy;
 pub mod systems;
 
 /// Convenient prelude for common imports
 pub mod prelude {
     pub use crate::components::*;
+    pub use crate::localization::*;
+    pub use crate::schedule::*;
+    pub use crate::snapshot::*;
+    pub use crate::strategy::*;
     pub use crate::systems::*;
 }
 
+pub use embedding::{headless_app, run_until_ended, step};
+
 use bevy::prelude::*;
+use schedule::{SimSchedulePlugin, SimSet, SimStep};
 
 /// Main plugin for the Ant Nest Simulator
+///
+/// Runs the full simulation together with its UI, input shortcuts, particles and
+/// auto-save. Embedders that only need the simulation should use
+/// [`AntNestSimulationPlugin`] instead.
 pub struct AntNestPlugin;
 
 impl Plugin for AntNestPlugin {
     fn build(&self, app: &mut App) {
-        app
-            // MVP: Only basic setup systems
+        app.add_plugins(AntNestSimulationPlugin)
+            .init_resource::<components::PerformanceMetrics>()
+            .init_resource::<components::AutoThrottleConfig>()
+            .init_resource::<components::UITheme>()
+            .init_resource::<components::UiAssets>()
+            .init_resource::<components::AntLodConfig>()
+            .init_resource::<components::ColorOverlayConfig>()
+            .init_resource::<components::SoilColorConfig>()
+            .init_resource::<components::CameraSettings>()
+            .init_resource::<components::PheromoneOverlay>()
+            .init_resource::<components::TunnelDrawTool>()
+            .init_resource::<components::UserSettings>()
+            .init_resource::<components::KeyBindings>()
+            .init_resource::<ClearColor>()
+            .init_resource::<localization::Localization>()
+            .init_resource::<systems::ParticleConfig>()
+            .insert_resource(systems::PersistenceState::new())
+            .insert_resource(systems::SettingsFile::new())
+            .init_resource::<systems::SaveSlots>()
+            .add_event::<systems::SaveSlotRequest>()
+            .add_event::<systems::TunnelDrawCommand>()
+            .init_resource::<components::Selection>()
+            .init_resource::<components::SelectionDrag>()
+            .init_resource::<components::AntRename>()
+            .init_resource::<components::CameraFollow>()
+            .init_resource::<components::PanelDrag>()
+            .add_event::<systems::SelectionCommand>()
+            .init_resource::<components::FoodReserveConfig>()
+            .init_resource::<components::FoodReserveWarning>()
             .add_systems(
                 Startup,
                 (
                     systems::setup_world,
+                    systems::refresh_save_slots_system,
+                    systems::load_settings_system,
+                ),
+            )
+            // A name being typed takes the keyboard before any shortcut sees it
+            .add_systems(
+                PreUpdate,
+                systems::rename_text_input_system.after(bevy::input::InputSystem),
+            )
+            // Text-heavy panels wait for the UI font, then are built exactly once
+            .add_systems(
+                PreUpdate,
+                (
+                    systems::ui_assets_loading_system,
+                    (
+                        systems::setup_performance_monitoring_ui,
+                        systems::setup_mini_hud,
+                        systems::setup_active_disasters_panel,
+                        systems::setup_disaster_control_panel,
+                        systems::setup_settings_panel,
+                        systems::setup_settings_toggle_button,
+                        systems::setup_statistics_panel,
+                        systems::setup_save_slot_panel,
+                        systems::setup_profiler_overlay,
+                        systems::setup_colony_comparison_panel,
+                        systems::setup_selection_panel,
+                        systems::setup_help_overlay,
+                    )
+                        .run_if(systems::ui_panels_pending),
+                    systems::mark_ui_panels_built_system.run_if(systems::ui_panels_pending),
+                )
+                    .chain(),
+            )
+            // Input: shortcuts, save slot requests and resets apply before the simulation step
+            .add_systems(
+                Update,
+                (
+                    systems::toggle_performance_monitoring_system,
+                    systems::toggle_profiler_system,
+                    systems::toggle_mini_hud_system,
+                    systems::help_overlay_toggle_system,
+                    systems::visual_effects_toggle_system,
+                    (
+                        systems::disaster_keyboard_input_system,
+                        systems::toggle_disaster_control_panel_system,
+                        systems::handle_disaster_control_interactions,
+                    ),
+                    systems::toggle_save_slot_panel_system,
+                    systems::camera_control_system,
+                    (
+                        systems::pheromone_debug_toggle_system,
+                        systems::territory_overlay_toggle_system,
+                        systems::colony_comparison_input_system,
+                        systems::stress_spawn_input_system,
+                    ),
+                    (
+                        systems::tunnel_draw_input_system,
+                        systems::tunnel_draw_system,
+                    )
+                        .chain(),
+                    (
+                        systems::selection_input_system,
+                        systems::selection_system,
+                        systems::follow_selection_input_system,
+                        systems::rename_button_system,
+                    )
+                        .chain(),
+                    systems::statistics_toggle_input_system,
+                    systems::statistics_section_header_system,
+                    systems::time_control_input_system.before(systems::apply_time_control_system),
+                    systems::auto_throttle_system.before(systems::apply_time_control_system),
+                    systems::settings_toggle_input_system,
+                    (
+                        systems::handle_settings_interactions_system,
+                        systems::world_theme_dropdown_system,
+                        systems::disaster_panel_mode_dropdown_system,
+                        systems::theme_recolor_system
+                            .after(systems::handle_settings_interactions_system),
+                    ),
+                    (
+                        systems::save_slot_button_system,
+                        systems::save_slot_request_system,
+                    )
+                        .chain(),
+                    systems::reset_keyboard_input_system.before(systems::reset_simulation_system),
+                )
+                    .in_set(SimSet::Input),
+            )
+            .add_systems(
+                Update,
+                systems::collect_performance_metrics.in_set(SimSet::Stats),
+            )
+            // Presentation: panels, alerts, tooltips (track hover, then draw) and auto-save
+            .add_systems(
+                Update,
+                (
+                    systems::update_performance_monitoring_ui,
+                    (
+                        systems::update_profiler_overlay,
+                        systems::update_colony_comparison_panel,
+                    ),
+                    (
+                        systems::update_mini_hud,
+                        systems::update_help_overlay_system,
+                    ),
+                    (
+                        systems::pheromone_debug_system,
+                        systems::territory_overlay_system,
+                    ),
+                    (
+                        systems::update_selection_panel_system,
+                        systems::update_rename_button_system,
+                        systems::selection_rubber_band_system,
+                    ),
+                    (
+                        systems::apply_statistics_sections_system,
+                        systems::update_statistics_display,
+                    )
+                        .chain(),
+                    (
+                        systems::responsive_layout_system,
+                        systems::panel_drag_system,
+                    )
+                        .chain(),
+                    (
+                        systems::tooltip_trigger_system,
+                        systems::world_tooltip_hover_system,
+                        systems::tooltip_display_system,
+                        systems::tooltip_cleanup_system,
+                    )
+                        .chain(),
+                    (
+                        systems::update_save_slot_panel_system,
+                        systems::end_summary_panel_system,
+                    ),
+                    (
+                        systems::update_active_disasters_panel,
+                        systems::update_disaster_status_indicators,
+                        (
+                            systems::food_reserve_dismiss_system,
+                            systems::food_reserve_monitor_system,
+                        )
+                            .chain(),
+                    ),
+                    systems::ant_lod_system,
+                    systems::nest_render_system,
+                    systems::realistic_soil_color_system,
+                    (
+                        systems::role_flash_system,
+                        systems::egg_laying_pulse_system,
+                        systems::age_visual_system,
+                        systems::carry_indicator_system,
+                    ),
+                    systems::apply_ui_font_system,
+                    systems::world_background_system,
+                    (
+                        systems::overlay_color_settings_system,
+                        systems::overlay_compositor_system,
+                        systems::color_overlay_system,
+                    )
+                        .chain(),
+                    (
+                        systems::update_particle_config_system,
+                        systems::particle_spawner_system,
+                        systems::particle_update_system,
+                    )
+                        .chain(),
+                    systems::save_game_system,
+                )
+                    .in_set(SimSet::Presentation),
+            );
+    }
+}
+
+/// Core simulation plugin without any UI, input or rendering-only systems
+///
+/// Needs nothing beyond `MinimalPlugins`, so it can be driven headless with [`step`].
+pub struct AntNestSimulationPlugin;
+
+impl Plugin for AntNestSimulationPlugin {
+    fn build(&self, app: &mut App) {
+        // The shared ant grid takes its bucket size from the world config, if one was supplied
+        app.init_resource::<components::WorldConfig>();
+        if !app.world().contains_resource::<components::SpatialGrid>() {
+            let cell_size = app
+                .world()
+                .resource::<components::WorldConfig>()
+                .spatial_cell_size;
+            app.insert_resource(components::SpatialGrid::new(cell_size));
+        }
+
+        app.add_plugins(SimSchedulePlugin)
+            .init_resource::<components::VisualEffectsSettings>()
+            .init_resource::<components::ColonyFoodStore>()
+            .init_resource::<components::NestSpace>()
+            .init_resource::<components::CarryingCapacity>()
+            .init_resource::<components::AntAiConfig>()
+            .init_resource::<components::PheromoneGrid>()
+            .init_resource::<components::AlarmPheromoneGrid>()
+            .init_resource::<strategy::BoxedStrategy>()
+            .init_resource::<components::SoilDiffusionConfig>()
+            .init_resource::<components::NutritionModel>()
+            .init_resource::<components::EnvironmentNoise>()
+            .init_resource::<components::FeedingPriorityConfig>()
+            .init_resource::<components::FoodSpawningConfig>()
+            .init_resource::<components::SimulationTime>()
+            .init_resource::<components::DayLengthMode>()
+            .init_resource::<components::SystemProfiler>()
+            .init_resource::<components::TimeControl>()
+            .init_resource::<components::ActivitySchedule>()
+            .init_resource::<components::NightShelterConfig>()
+            .init_resource::<components::DisasterState>()
+            .init_resource::<components::AutoDisasterConfig>()
+            .init_resource::<components::Forecast>()
+            .init_resource::<components::InvasionConfig>()
+            .init_resource::<components::InvasiveSpeciesConfig>()
+            .init_resource::<components::ColonyDevelopmentPhase>()
+            .init_resource::<components::FoundingSuccessConfig>()
+            .init_resource::<components::NuptialFlightConfig>()
+            .init_resource::<components::ChamberClimateConfig>()
+            .init_resource::<components::CasteConfig>()
+            .init_resource::<components::HatchTraitConfig>()
+            .init_resource::<components::LifespanModel>()
+            .init_resource::<components::InitialSpawnConfig>()
+            .init_resource::<components::BroodNutritionConfig>()
+            .init_resource::<components::BroodBatchConfig>()
+            .init_resource::<components::BroodCareConfig>()
+            .init_resource::<components::DisasterReproductionConfig>()
+            .init_resource::<components::StressSpawnConfig>()
+            .init_resource::<components::DiseaseConfig>()
+            .init_resource::<components::TrophallaxisConfig>()
+            .init_resource::<components::FoodHandoffConfig>()
+            .init_resource::<components::BoundaryMode>()
+            .init_resource::<components::DeathBehavior>()
+            .init_resource::<components::PendingDespawn>()
+            .init_resource::<components::ColonyStatistics>()
+            .init_resource::<components::NestPlanningConfig>()
+            .init_resource::<components::RecruitmentWaves>()
+            .init_resource::<components::EventLog>()
+            .init_resource::<components::TerritoryMap>()
+            .init_resource::<components::ColonyComparison>()
+            .init_resource::<components::DaughterColonies>()
+            .init_resource::<components::EndConditions>()
+            .init_resource::<components::AntRegistry>()
+            .init_resource::<components::SimulationEnded>()
+            .init_resource::<systems::PhaseProgressTracking>()
+            .add_event::<systems::ResetSimulationRequest>()
+            .add_event::<systems::RoleTransitionEvent>()
+            .add_event::<systems::EggLaidEvent>()
+            .add_event::<systems::RenameAntRequest>()
+            .add_event::<systems::AutoDisasterEvent>()
+            .add_systems(
+                Startup,
+                (
                     systems::spawn_soil_grid,
                     systems::spawn_initial_ants,
                     systems::spawn_queen,
+                    systems::spawn_food_sources,
                 ),
             )
-            // MVP: Only core simulation systems
             .add_systems(
                 Update,
+                (
+                    systems::reset_simulation_system,
+                    systems::apply_time_control_system,
+                )
+                    .in_set(SimSet::Input),
+            )
+            .add_systems(Last, systems::turbo_substep_system)
+            .add_systems(
+                SimStep,
+                (
+                    (
+                        systems::auto_disaster_system,
+                        systems::disaster_update_system,
+                        systems::environmental_update_system,
+                        systems::disaster_effect_system,
+                        systems::soil_diffusion_system,
+                        systems::food_spawning_system,
+                    )
+                        .chain(),
+                    systems::simulation_time_system,
+                    systems::chamber_climate_system,
+                )
+                    .in_set(SimSet::Environment),
+            )
+            // MVP: Only core simulation systems
+            .add_systems(
+                SimStep,
                 (
                     systems::ant_movement_system,
-                    systems::queen_founding_system,
-                    systems::environmental_update_system,
+                    systems::humidity_comfort_system,
+                    (
+                        systems::queen_founding_system,
+                        systems::co_founding_queen_system,
+                        systems::queen_movement_system,
+                        systems::founding_success_system,
+                        systems::nuptial_flight_system,
+                    )
+                        .chain(),
+                    (
+                        systems::invasive_species_spawning_system,
+                        systems::invasive_nest_system,
+                        systems::invasive_species_behavior_system,
+                        systems::alarm_emission_system,
+                        systems::alarm_spread_system,
+                        systems::alarm_response_system,
+                        systems::invader_combat_system,
+                        systems::invasive_species_cleanup_system,
+                    )
+                        .chain(),
+                    (
+                        systems::activity_schedule_system,
+                        systems::night_shelter_system,
+                        systems::scout_allocation_system,
+                        systems::foraging_behavior_system,
+                        systems::food_handoff_system,
+                        systems::recruitment_system,
+                        systems::antennation_system,
+                        systems::pheromone_decay_system,
+                        systems::depleted_food_cleanup_system,
+                    )
+                        .chain(),
+                    (
+                        systems::nest_planning_system,
+                        systems::chamber_excavation_system,
+                    )
+                        .chain(),
+                    (
+                        systems::nest_worker_positioning_system,
+                        systems::queen_reproduction_system,
+                        systems::egg_hatching_system,
+                    )
+                        .chain(),
+                    (
+                        systems::profile_section_start("Lifecycle"),
+                        systems::feeding_priority_system,
+                        systems::trophallaxis_system,
+                        systems::starvation_system,
+                        systems::adult_disease_system,
+                        systems::ant_lifecycle_system,
+                        systems::queen_health_system,
+                        systems::corpse_decay_system,
+                        systems::update_ant_age_groups_system,
+                        systems::profile_section_stop("Lifecycle"),
+                    )
+                        .chain(),
+                )
+                    .in_set(SimSet::Movement),
+            )
+            .add_systems(
+                SimStep,
+                (
+                    systems::batched_cleanup_system,
+                    systems::spatial_grid_cell_size_system,
+                    systems::spatial_grid_update_system,
+                )
+                    .chain()
+                    .in_set(SimSet::Spatial),
+            )
+            .add_systems(
+                SimStep,
+                (
+                    systems::colony_statistics_calculation_system,
+                    systems::colony_development_management_system,
+                    systems::chamber_occupancy_system,
+                    (
+                        systems::role_transition_system,
+                        systems::role_transition_log_system,
+                    )
+                        .chain(),
+                    systems::egg_laid_log_system,
+                    (
+                        systems::territory_system,
+                        (
+                            systems::daughter_colony_system,
+                            systems::colony_comparison_system,
+                        )
+                            .chain(),
+                    ),
+                    (systems::ant_naming_system, systems::rename_ant_system).chain(),
+                    systems::end_condition_system
+                        .after(systems::colony_development_management_system),
+                )
+                    .in_set(SimSet::Stats),
+            )
+            // Profiling: timestamps between the stages, idle unless the profiler is enabled
+            .add_systems(
+                SimStep,
+                (
+                    systems::profile_section_start("Environment").before(SimSet::Environment),
+                    systems::profile_section_stop("Environment")
+                        .after(SimSet::Environment)
+                        .before(SimSet::Movement),
+                    systems::profile_section_start("Movement")
+                        .after(SimSet::Environment)
+                        .before(SimSet::Movement),
+                    systems::profile_section_stop("Movement")
+                        .after(SimSet::Movement)
+                        .before(SimSet::Spatial),
+                    systems::profile_section_start("Spatial grid")
+                        .after(SimSet::Movement)
+                        .before(SimSet::Spatial),
+                    systems::profile_section_stop("Spatial grid")
+                        .after(SimSet::Spatial)
+                        .before(SimSet::Stats),
+                    systems::profile_section_start("Stats")
+                        .after(SimSet::Spatial)
+                        .before(SimSet::Stats),
+                    systems::profile_section_stop("Stats").after(SimSet::Stats),
                 ),
             );
     }
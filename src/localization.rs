@@ -0,0 +1,230 @@
+//! Translated UI labels
+//!
+//! Panels look their labels up by key through [`Localization::t`] instead of hardcoding
+//! English. A locale is a plain text file with one `key = value` pair per line; blank
+//! lines and lines starting with `#` are ignored. English is built in and used by
+//! default. A key the active locale does not define is shown as the key itself, so a
+//! gap in a translation is visible on screen instead of crashing the game.
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Built-in English strings, in the same format as locale files
+const ENGLISH: &str = "
+# Active disasters panel
+disasters.title = Active Disasters
+disasters.time_left = s left
+disasters.combined_effect = Combined effect
+disasters.incoming_in = incoming in ~
+disasters.day = day
+disasters.days = days
+disaster.rain = Rain
+disaster.drought = Drought
+disaster.cold_snap = Cold Snap
+disaster.heatwave = Heatwave
+disaster.invasive_species = Invasive Species
+
+# Disaster control panel
+disasters.controls.title = Disaster Control
+disasters.controls.description = Trigger environmental disasters to observe colony adaptation
+
+# Time control
+time.paused = Paused
+time.speed = Speed
+time.turbo = Turbo
+time.throttled = throttled
+
+# Help overlay
+help.title = Keyboard shortcuts
+help.category.time = Time
+help.category.camera = Camera
+help.category.disasters = Disasters
+help.category.panels = Panels
+help.category.tools = Tools
+action.toggle_pause = Pause / resume
+action.set_speed = Speed
+action.max_speed = Maximum speed
+action.toggle_turbo = Turbo mode
+action.pan_left = Pan left
+action.pan_right = Pan right
+action.pan_up = Pan up
+action.pan_down = Pan down
+action.zoom_in = Zoom in
+action.zoom_out = Zoom out
+action.reset_camera = Reset camera
+action.toggle_auto_disasters = Automatic disasters
+action.toggle_disaster_controls = Disaster control panel
+action.toggle_help = This help
+action.toggle_mini_hud = Compact HUD
+action.toggle_performance_panel = Performance panel
+action.toggle_statistics = Colony statistics
+action.toggle_save_slots = Save slots
+action.toggle_pheromone_overlay = Pheromone overlay
+action.toggle_profiler = Profiler
+action.toggle_territory_overlay = Territory overlay
+action.toggle_colony_comparison = Colony comparison
+action.cycle_comparison_sort = Sort colony comparison
+action.toggle_settings = Settings
+action.toggle_visual_effects = Visual effects
+action.toggle_tunnel_tool = Tunnel drawing
+action.cycle_chamber_type = Next chamber type (while drawing)
+action.clear_selection = Clear selection
+action.follow_selection = Follow selected ant with the camera
+action.new_game = New game
+action.stress_spawn_ants = Stress test: add ants
+action.stress_spawn_food = Stress test: add food
+
+# Statistics panel
+stats.title = Colony Statistics
+stats.toggle_hint = Press F4 to toggle
+stats.section.population = Population
+stats.section.resources = Resources
+stats.section.environment = Environment
+stats.section.nest = Nest
+stats.section.behavior = Behavior
+stats.section.roles = Roles
+stats.population_total = Total Ants
+stats.population_queen = Queen
+stats.population_castes = Castes
+stats.population_eggs = Eggs
+stats.population_phase = Phase
+stats.resource_food = Food Store
+stats.resource_energy = Avg Energy
+stats.resource_carrying = Carrying Food
+stats.environment_depth = Avg Ant Depth
+stats.environment_location = Surface / Underground
+stats.environment_disasters = Active Disasters
+stats.nest_depth = Max Depth
+stats.nest_tunnels = Tunnel Length
+stats.nest_chambers = Chambers
+stats.behavior_activity = Activity
+stats.role_general_workers = General Workers
+stats.role_foragers = Foragers
+stats.role_nursery_workers = Nursery Workers
+stats.role_nest_maintainers = Nest Maintainers
+stats.role_storage_workers = Storage Workers
+stats.carbs = carbs
+stats.protein = protein
+stats.hp = HP
+caste.minor = Minor
+caste.media = Media
+caste.major = Major
+state.foraging = Foraging
+state.idle = Idle
+state.returning = Returning
+state.resting = Resting
+state.defending = Defending
+
+# Colony comparison panel
+comparison.title = Colonies
+comparison.sorted_by = sorted by
+comparison.colony = Colony
+comparison.population = Pop
+comparison.food = Food
+comparison.day = Day
+comparison.extinct = extinct
+comparison.metric.population = Population
+comparison.metric.phase = Phase
+comparison.metric.food = Food
+comparison.metric.age = Age
+phase.queen_founding = Queen's Independent Founding
+phase.first_workers = First Workers
+phase.colony_expansion = Colony Expansion
+phase.mature_colony = Mature Colony
+
+# Save slot panel
+slots.title = Save Slots
+slots.slot = Slot
+slots.empty = empty
+slots.day = Day
+slots.ants = ants
+slots.save = Save
+slots.load = Load
+slots.delete = Delete
+slots.saved_to = Saved to slot
+slots.loaded = Loaded slot
+slots.deleted = Deleted slot
+slots.error = Error
+
+# Settings panel
+settings.title = Settings
+settings.tab.visual = Visual
+settings.tab.accessibility = Accessibility
+settings.tab.application = Application
+settings.reset_defaults = Reset to Defaults
+settings.save = Save Settings
+settings.apply = Apply
+settings.load_theme = Load Theme
+settings.on = ON
+settings.visual_effects = Visual Effects
+settings.visual_effects.description = Enable particle effects and visual overlays
+settings.ui_scale = UI Scale
+settings.ui_scale.description = Adjust the size of interface elements
+settings.color_theme = Color Theme
+settings.color_theme.description = Choose interface color scheme
+settings.color_theme.default = Default Theme
+settings.world_theme = World Background
+settings.world_theme.description = Backdrop behind the nest; dims with the day/night cycle
+settings.world_theme.night = Night
+settings.world_theme.daylight = Daylight
+settings.world_theme.sepia = Sepia
+settings.performance_mode = Performance Mode
+settings.performance_mode.description = Reduce visual effects for better performance
+settings.disaster_panel = Disaster Panel
+settings.disaster_panel.description = Labeled list, or compact icons when the screen is crowded
+settings.disaster_panel.detailed = Detailed
+settings.disaster_panel.compact = Compact
+";
+
+/// Active UI language and its label strings
+#[derive(Resource, Clone, Debug)]
+pub struct Localization {
+    pub locale: String,
+    strings: HashMap<String, String>,
+}
+
+impl Default for Localization {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+impl Localization {
+    /// The built-in English strings
+    pub fn english() -> Self {
+        Self::from_key_values("en", ENGLISH)
+    }
+
+    /// Parse `key = value` lines into a locale
+    pub fn from_key_values(locale: &str, text: &str) -> Self {
+        let strings = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| line.split_once('='))
+            .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+            .collect();
+
+        Self {
+            locale: locale.to_string(),
+            strings,
+        }
+    }
+
+    /// Load a locale file; the locale is named after the file, e.g. `de.txt` is `de`
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)?;
+        let locale = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("custom");
+        Ok(Self::from_key_values(locale, &text))
+    }
+
+    /// Translated label for a key, or the key itself when the locale lacks it
+    pub fn t<'a>(&'a self, key: &'a str) -> &'a str {
+        self.strings.get(key).map_or(key, String::as_str)
+    }
+}
@@ -7,7 +7,7 @@ fn main() {
             primary_window: Some(Window {
                 title: "Ant Nest Simulator".into(),
                 resolution: (800.0, 600.0).into(),
-                resizable: false,
+                resizable: true,
                 ..default()
             }),
             ..default()
@@ -0,0 +1,79 @@
+//! Frame ordering of the simulation
+//!
+//! Every `Update` system belongs to one [`SimSet`], and the sets run in this order each
+//! frame:
+//!
+//! 1. [`SimSet::Input`]: keyboard shortcuts and resets, so requests apply this frame
+//! 2. [`SimSet::Environment`]: clock, disasters, soil and climate
+//! 3. [`SimSet::Movement`]: everything that moves, feeds, breeds or kills entities
+//! 4. [`SimSet::Spatial`]: lookup grids rebuilt from the post-movement positions
+//! 5. [`SimSet::Stats`]: aggregates read from the settled world
+//! 6. [`SimSet::Presentation`]: UI panels, tooltips and saving
+//!
+//! The simulation sets 2-5 live in the [`SimStep`] schedule, which `Update` runs once
+//! between input and presentation. Turbo mode reruns only [`SimStep`], so input and
+//! panels still run once per frame.
+//!
+//! Anything read in a later set reflects the current frame's changes from earlier sets,
+//! never the previous frame's. Order inside a set is only defined where systems are
+//! chained explicitly.
+
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::prelude::*;
+
+/// Ordered stages of one simulation frame
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SimSet {
+    Input,
+    Environment,
+    Movement,
+    Spatial,
+    Stats,
+    Presentation,
+}
+
+/// Schedule holding one simulation step, from [`SimSet::Environment`] to [`SimSet::Stats`]
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SimStep;
+
+/// Plugin chaining the [`SimSet`]s in `Update` and running [`SimStep`] between them
+pub struct SimSchedulePlugin;
+
+impl Plugin for SimSchedulePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_schedule(SimStep)
+            .configure_sets(
+                SimStep,
+                (
+                    SimSet::Environment,
+                    SimSet::Movement,
+                    SimSet::Spatial,
+                    SimSet::Stats,
+                )
+                    .chain(),
+            )
+            .configure_sets(
+                Update,
+                (
+                    SimSet::Input,
+                    SimSet::Environment,
+                    SimSet::Movement,
+                    SimSet::Spatial,
+                    SimSet::Stats,
+                    SimSet::Presentation,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                run_simulation_step
+                    .after(SimSet::Input)
+                    .before(SimSet::Environment),
+            );
+    }
+}
+
+/// Exclusive system running the frame's regular [`SimStep`]
+pub fn run_simulation_step(world: &mut World) {
+    world.run_schedule(SimStep);
+}
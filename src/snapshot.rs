@@ -0,0 +1,116 @@
+//! Read-only colony metrics for external tools
+//!
+//! [`colony_snapshot`] aggregates the colony's state into one plain, serializable struct
+//! without touching the UI or mutating the world, so dashboards can poll it freely.
+
+use crate::components::{
+    Ant, AntBehavior, AntState, Caste, CasteClass, ColonyDevelopmentPhase, ColonyFoodStore,
+    DevelopmentPhase, DisasterState, DisasterType, Dying, FoodSource, Lifecycle, Queen, Scout,
+    SimulationTime,
+};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Point-in-time summary of the colony
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ColonySnapshot {
+    pub population: usize, // Workers plus queens
+    pub worker_count: usize,
+    pub queen_count: usize,
+    pub scout_count: usize,
+    pub foraging_count: usize,
+    pub idle_count: usize,
+    pub returning_count: usize,
+    pub resting_count: usize,
+    pub defending_count: usize,
+    pub minor_count: usize,
+    pub media_count: usize,
+    pub major_count: usize,
+    pub average_energy: f32, // Across workers with a lifecycle, 0.0 when there are none
+    pub colony_food: f32,    // Carbohydrates in the colony store
+    pub colony_protein: f32, // Protein in the colony store
+    pub food_source_count: usize,
+    pub food_source_nutrition: f32, // Nutrition left in all food sources
+    pub active_disasters: Vec<String>,
+    pub current_phase: Option<DevelopmentPhase>,
+    pub simulated_day: u32,
+    pub simulated_seconds: f32,
+}
+
+/// Aggregate the current colony state without mutating the world
+pub fn colony_snapshot(world: &World) -> ColonySnapshot {
+    let mut snapshot = ColonySnapshot::default();
+    let mut total_energy = 0.0;
+    let mut energy_samples = 0;
+
+    for entity in world.iter_entities() {
+        if let Some(food) = entity.get::<FoodSource>() {
+            snapshot.food_source_count += 1;
+            snapshot.food_source_nutrition += food.nutrition_value;
+        }
+
+        // Ants waiting to be despawned are already dead
+        if !entity.contains::<Ant>() || entity.contains::<Dying>() {
+            continue;
+        }
+        snapshot.population += 1;
+
+        if entity.contains::<Queen>() {
+            snapshot.queen_count += 1;
+            continue;
+        }
+        snapshot.worker_count += 1;
+
+        if entity.contains::<Scout>() {
+            snapshot.scout_count += 1;
+        }
+        if let Some(behavior) = entity.get::<AntBehavior>() {
+            match behavior.state {
+                AntState::Foraging => snapshot.foraging_count += 1,
+                AntState::Idle => snapshot.idle_count += 1,
+                AntState::Returning => snapshot.returning_count += 1,
+                AntState::Resting => snapshot.resting_count += 1,
+                AntState::Defending => snapshot.defending_count += 1,
+            }
+        }
+        if let Some(caste) = entity.get::<Caste>() {
+            match caste.size_class {
+                CasteClass::Minor => snapshot.minor_count += 1,
+                CasteClass::Media => snapshot.media_count += 1,
+                CasteClass::Major => snapshot.major_count += 1,
+            }
+        }
+        if let Some(lifecycle) = entity.get::<Lifecycle>() {
+            total_energy += lifecycle.energy;
+            energy_samples += 1;
+        }
+    }
+
+    if energy_samples > 0 {
+        snapshot.average_energy = total_energy / energy_samples as f32;
+    }
+
+    if let Some(food_store) = world.get_resource::<ColonyFoodStore>() {
+        snapshot.colony_food = food_store.food;
+        snapshot.colony_protein = food_store.protein;
+    }
+
+    if let Some(disaster_state) = world.get_resource::<DisasterState>() {
+        snapshot.active_disasters = DisasterType::ALL
+            .iter()
+            .filter(|disaster_type| disaster_state.is_active(**disaster_type))
+            .map(|disaster_type| disaster_type.display_name().to_string())
+            .collect();
+    }
+
+    snapshot.current_phase = world
+        .get_resource::<ColonyDevelopmentPhase>()
+        .map(|colony_phase| colony_phase.current_phase);
+
+    if let Some(simulation_time) = world.get_resource::<SimulationTime>() {
+        snapshot.simulated_day = simulation_time.current_day;
+        snapshot.simulated_seconds = simulation_time.elapsed_seconds;
+    }
+
+    snapshot
+}
@@ -0,0 +1,162 @@
+//! Pluggable ant decision making
+//!
+//! Foraging ants ask the [`BoxedStrategy`] resource where to go next. Insert a different
+//! boxed strategy to experiment with other foraging algorithms:
+//!
+//! ```rust,no_run
+//! use ant_nest_simulator::components::Position;
+//! use ant_nest_simulator::strategy::{AntContext, AntStrategy, BoxedStrategy};
+//! use bevy::prelude::*;
+//!
+//! struct GoHome;
+//!
+//! impl AntStrategy for GoHome {
+//!     fn choose_target(&self, ctx: &AntContext) -> Option<Position> {
+//!         Some(ctx.home.clone())
+//!     }
+//! }
+//!
+//! App::new().insert_resource(BoxedStrategy::new(GoHome));
+//! ```
+
+use crate::components::{AntAiConfig, Position, WanderMode, WorldConfig};
+use bevy::prelude::*;
+use rand::prelude::*;
+
+/// Everything an ant knows when picking its next target
+pub struct AntContext<'a> {
+    pub position: &'a Position,
+    pub home: &'a Position,
+    pub energy: Option<f32>, // None for ants without a lifecycle
+    pub is_scout: bool,
+    pub heading: f32, // Current direction of travel in radians
+    pub remembered_food: Option<&'a Position>,
+    pub nearby_food: &'a [Position], // Food sources within sensing range
+    pub nearby_pheromone: &'a [(Position, f32)], // Neighboring trail cells and their strength
+    pub config: &'a AntAiConfig,
+    pub world: &'a WorldConfig, // Wander targets stay within its east and west edges
+}
+
+/// Decision making for a foraging ant
+pub trait AntStrategy: Send + Sync + 'static {
+    /// Next position to walk toward, or `None` to stay put
+    fn choose_target(&self, ctx: &AntContext) -> Option<Position>;
+}
+
+/// Strategy used by the foraging system
+#[derive(Resource)]
+pub struct BoxedStrategy(pub Box<dyn AntStrategy>);
+
+impl BoxedStrategy {
+    pub fn new(strategy: impl AntStrategy) -> Self {
+        Self(Box::new(strategy))
+    }
+}
+
+impl Default for BoxedStrategy {
+    fn default() -> Self {
+        Self::new(PheromoneStrategy)
+    }
+}
+
+/// Random walk along the surface, following the configured [`WanderMode`]
+///
+/// Smooth wandering walks a short way along the ant's heading and turns back at the edge
+/// of its range; far-target wandering picks a random point within the range. Scouts roam
+/// further than regular foragers either way, and an ant with no range stays put.
+pub struct DefaultStrategy;
+
+impl AntStrategy for DefaultStrategy {
+    fn choose_target(&self, ctx: &AntContext) -> Option<Position> {
+        let range = if ctx.is_scout {
+            ctx.config.scout_range
+        } else {
+            ctx.config.forage_range
+        };
+        // No range to wander in; the ant stays where it is
+        if range <= 0.0 {
+            return Some(ctx.position.clone());
+        }
+        if ctx.config.wander_mode == WanderMode::Smooth {
+            return Some(smooth_wander_target(ctx));
+        }
+
+        let mut rng = thread_rng();
+        Some(Position {
+            x: (ctx.home.x + rng.gen_range(-range..range)).clamp(ctx.world.min_x, ctx.world.max_x),
+            y: rng.gen_range(0.0..4.0), // Forage along the surface
+        })
+    }
+}
+
+/// Exploiters go for known or sensed food, then follow outbound trails, and fall back to a
+/// random walk. Scouts ignore trails so they keep discovering new sources.
+pub struct PheromoneStrategy;
+
+impl AntStrategy for PheromoneStrategy {
+    fn choose_target(&self, ctx: &AntContext) -> Option<Position> {
+        if !ctx.is_scout {
+            if let Some(remembered) = ctx.remembered_food {
+                return Some(remembered.clone());
+            }
+
+            let nearest_food = ctx
+                .nearby_food
+                .iter()
+                .min_by(|a, b| distance(a, ctx.position).total_cmp(&distance(b, ctx.position)));
+            if let Some(food) = nearest_food {
+                return Some(food.clone());
+            }
+
+            let home_distance = distance(ctx.position, ctx.home);
+            let outbound_trail = ctx
+                .nearby_pheromone
+                .iter()
+                .filter(|(_, strength)| *strength >= ctx.config.trail_follow_threshold)
+                .filter(|(center, _)| distance(center, ctx.home) > home_distance)
+                .max_by(|a, b| a.1.total_cmp(&b.1));
+            if let Some((center, _)) = outbound_trail {
+                return Some(center.clone());
+            }
+        }
+
+        DefaultStrategy.choose_target(ctx)
+    }
+}
+
+/// Point `wander_step` ahead along the ant's heading, turned back at the edges of its range
+fn smooth_wander_target(ctx: &AntContext) -> Position {
+    let range = if ctx.is_scout {
+        ctx.config.scout_range
+    } else {
+        ctx.config.forage_range
+    };
+    let min_x = (ctx.home.x - range).max(ctx.world.min_x);
+    let max_x = (ctx.home.x + range).min(ctx.world.max_x);
+    let step = ctx.config.wander_step;
+
+    // Mirror off the side edges; run along the surface instead of leaving it
+    let mut heading = ctx.heading;
+    let next_x = ctx.position.x + heading.cos() * step;
+    if (next_x < min_x && heading.cos() < 0.0) || (next_x > max_x && heading.cos() > 0.0) {
+        heading = std::f32::consts::PI - heading;
+    }
+    let next_y = ctx.position.y + heading.sin() * step;
+    if (next_y < 0.0 && heading.sin() < 0.0) || (next_y > 4.0 && heading.sin() > 0.0) {
+        heading = if heading.cos() >= 0.0 {
+            0.0
+        } else {
+            std::f32::consts::PI
+        };
+    }
+    Position {
+        x: (ctx.position.x + heading.cos() * step).clamp(min_x, max_x),
+        y: (ctx.position.y + heading.sin() * step).clamp(0.0, 4.0), // Forage along the surface
+    }
+}
+
+fn distance(a: &Position, b: &Position) -> f32 {
+    let dx = a.x - b.x;
+    let dy = a.y - b.y;
+    (dx * dx + dy * dy).sqrt()
+}
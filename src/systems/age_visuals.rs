@@ -0,0 +1,59 @@
+use crate::components::{
+    AntAgeGroup, AntBehavior, BaseColor, PhaseSpecificBehavior, Queen, VisualEffectsSettings,
+};
+use crate::systems::carry_visuals::{carried_kind, carry_tinted_color};
+use bevy::prelude::*;
+
+/// Blend an ant's base color toward its age tint
+///
+/// Young (callow) workers are paler, seniors fade slightly toward grey; adults keep
+/// the base color. The blend is applied on top of whatever base color the ant has,
+/// so other tints (colony, food carrying) survive it.
+pub fn age_tinted_color(base: Color, age_group: AntAgeGroup) -> Color {
+    let base = base.to_srgba();
+    let (target, amount) = match age_group {
+        AntAgeGroup::Young => (Srgba::rgb(0.55, 0.45, 0.35), 0.35), // Pale callow cuticle
+        AntAgeGroup::Adult => return base.into(),
+        AntAgeGroup::Senior => (Srgba::rgb(0.35, 0.35, 0.35), 0.2), // Faded, greyish
+    };
+
+    Color::srgba(
+        base.red + (target.red - base.red) * amount,
+        base.green + (target.green - base.green) * amount,
+        base.blue + (target.blue - base.blue) * amount,
+        base.alpha,
+    )
+}
+
+/// System to subtly recolor worker ants by age group
+///
+/// The tint of the food an ant carries, see [`carry_tinted_color`], is layered on top.
+/// Carried loads change from frame to frame, so every worker's color is recomputed each
+/// frame and its sprite is only written when the color differs.
+pub fn age_visual_system(
+    visual_effects_settings: Res<VisualEffectsSettings>,
+    mut ant_query: Query<
+        (
+            &PhaseSpecificBehavior,
+            Option<&AntBehavior>,
+            &BaseColor,
+            &mut Sprite,
+        ),
+        Without<Queen>, // The queen keeps her distinct color
+    >,
+) {
+    for (behavior, ant_behavior, base_color, mut sprite) in ant_query.iter_mut() {
+        let color = if visual_effects_settings.age_indicators_enabled {
+            age_tinted_color(base_color.0, behavior.age_group)
+        } else {
+            base_color.0
+        };
+        let carried = ant_behavior
+            .and_then(carried_kind)
+            .filter(|_| visual_effects_settings.carry_indicators_enabled);
+        let color = carry_tinted_color(color, carried);
+        if sprite.color != color {
+            sprite.color = color;
+        }
+    }
+}
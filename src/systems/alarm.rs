@@ -0,0 +1,104 @@
+use crate::components::{
+    AlarmPheromoneGrid, Ant, AntAiConfig, AntBehavior, AntState, Caste, CasteClass, Dying,
+    InvasiveSpecies, Position,
+};
+use crate::systems::foraging::{distance, step_toward};
+use bevy::prelude::*;
+
+/// System for ants releasing alarm pheromone while an invader is in sight
+pub fn alarm_emission_system(
+    time: Res<Time>,
+    ai_config: Res<AntAiConfig>,
+    mut alarm: ResMut<AlarmPheromoneGrid>,
+    ant_query: Query<&Position, (With<Ant>, Without<Dying>)>,
+    invasive_query: Query<&Position, With<InvasiveSpecies>>,
+) {
+    let emission = ai_config.alarm_emission_rate * time.delta_seconds();
+
+    for ant_position in ant_query.iter() {
+        let sees_threat = invasive_query.iter().any(|invader_position| {
+            distance(ant_position, invader_position) <= ai_config.alarm_detection_radius
+        });
+        if sees_threat {
+            alarm.grid.deposit(ant_position, emission);
+        }
+    }
+}
+
+/// System spreading alarm pheromone to neighboring cells and letting it fade
+pub fn alarm_spread_system(time: Res<Time>, mut alarm: ResMut<AlarmPheromoneGrid>) {
+    let delta_time = time.delta_seconds();
+    let diffusion_rate = alarm.diffusion_rate;
+
+    alarm.grid.diffuse(diffusion_rate, delta_time);
+    alarm.grid.decay(delta_time);
+}
+
+/// System turning alarmed ants into defenders or sending them home
+///
+/// Majors always answer the alarm, medias do unless they are carrying food, and minors
+/// retreat to the nest. Defenders head straight for an invader in sight and otherwise
+/// climb the alarm gradient toward its source. Once the alarm fades they resume foraging.
+pub fn alarm_response_system(
+    time: Res<Time>,
+    ai_config: Res<AntAiConfig>,
+    alarm: Res<AlarmPheromoneGrid>,
+    mut ant_query: Query<
+        (&mut Position, &mut AntBehavior, Option<&Caste>),
+        (With<Ant>, Without<Dying>),
+    >,
+    invasive_query: Query<&Position, (With<InvasiveSpecies>, Without<Ant>)>,
+) {
+    let delta_time = time.delta_seconds();
+    let grid = &alarm.grid;
+
+    for (mut position, mut behavior, caste) in ant_query.iter_mut() {
+        let here = grid.concentration(&position);
+        let strongest_neighbor = grid
+            .neighbors(&position)
+            .into_iter()
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        let alarmed = here.max(strongest_neighbor.map_or(0.0, |(_, strength)| strength))
+            >= ai_config.alarm_threshold;
+
+        if !alarmed {
+            if behavior.state == AntState::Defending {
+                behavior.state = AntState::Foraging;
+                behavior.target_position = None;
+            }
+            continue;
+        }
+
+        let defends = match caste.map_or(CasteClass::Media, |caste| caste.size_class) {
+            CasteClass::Major => true,
+            CasteClass::Media => behavior.carried_food == 0.0,
+            CasteClass::Minor => false,
+        };
+        if !defends {
+            if matches!(
+                behavior.state,
+                AntState::Foraging | AntState::Idle | AntState::Defending
+            ) {
+                behavior.state = AntState::Returning;
+                behavior.target_position = Some(behavior.home_position.clone());
+            }
+            continue;
+        }
+
+        behavior.state = AntState::Defending;
+        let nearest_invader = invasive_query
+            .iter()
+            .filter(|invader| distance(invader, &position) <= ai_config.alarm_detection_radius)
+            .min_by(|a, b| distance(a, &position).total_cmp(&distance(b, &position)));
+        let target = match (nearest_invader, strongest_neighbor) {
+            (Some(invader), _) => Some(invader.clone()),
+            (None, Some((cell, strength))) if strength > here => Some(grid.cell_center(cell)),
+            _ => None,
+        };
+
+        if let Some(target) = target {
+            step_toward(&mut position, &target, behavior.speed * delta_time);
+            behavior.target_position = Some(target);
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::components::{
+    Ant, CameraFollow, CameraSettings, InputAction, KeyBindings, Position, Queen, WorldConfig,
+};
+use bevy::prelude::*;
+
+/// System panning and zooming the camera from the keyboard
+///
+/// Arrow keys pan (faster when zoomed out), `=`/`-` zoom in and out, and Home recenters
+/// on the queen at the default zoom. While [`CameraFollow`] has a target the camera
+/// stays centered on that ant until it dies or the camera is panned away. The camera is
+/// always kept within [`CameraSettings::pan_bounds`] and the zoom limits, whatever moved it.
+#[allow(clippy::too_many_arguments)]
+pub fn camera_control_system(
+    time: Res<Time>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    camera_settings: Res<CameraSettings>,
+    world_config: Option<Res<WorldConfig>>,
+    follow: Option<ResMut<CameraFollow>>,
+    queen_query: Query<&Position, With<Queen>>,
+    ant_query: Query<&Position, With<Ant>>,
+    mut camera_query: Query<(&mut Transform, &mut OrthographicProjection), With<Camera>>,
+) {
+    let world_config = world_config
+        .map(|config| config.clone())
+        .unwrap_or_default();
+    let delta_time = time.delta_seconds();
+    let mut follow = follow;
+
+    for (mut transform, mut projection) in camera_query.iter_mut() {
+        let mut center = transform.translation.truncate();
+        let mut zoom = projection.scale;
+
+        if key_bindings.just_pressed(&keyboard_input, InputAction::ResetCamera) {
+            center = queen_query
+                .iter()
+                .next()
+                .map_or(camera_settings.default_center, |queen| {
+                    Vec2::new(queen.x, queen.y)
+                });
+            zoom = camera_settings.default_zoom;
+        }
+
+        let mut direction = Vec2::ZERO;
+        for (action, step) in [
+            (InputAction::PanLeft, Vec2::NEG_X),
+            (InputAction::PanRight, Vec2::X),
+            (InputAction::PanDown, Vec2::NEG_Y),
+            (InputAction::PanUp, Vec2::Y),
+        ] {
+            if key_bindings.pressed(&keyboard_input, action) {
+                direction += step;
+            }
+        }
+        center += direction * camera_settings.pan_speed * zoom * delta_time;
+
+        if let Some(follow) = follow.as_mut() {
+            if let Some(target) = follow.target {
+                match ant_query.get(target) {
+                    Ok(ant) if direction == Vec2::ZERO => center = Vec2::new(ant.x, ant.y),
+                    _ => follow.target = None,
+                }
+            }
+        }
+
+        if key_bindings.pressed(&keyboard_input, InputAction::ZoomIn) {
+            zoom -= camera_settings.zoom_speed * delta_time;
+        }
+        if key_bindings.pressed(&keyboard_input, InputAction::ZoomOut) {
+            zoom += camera_settings.zoom_speed * delta_time;
+        }
+
+        let center = camera_settings.clamp_center(&world_config, center);
+        if transform.translation.truncate() != center {
+            transform.translation.x = center.x;
+            transform.translation.y = center.y;
+        }
+        let zoom = camera_settings.clamp_zoom(zoom);
+        if projection.scale != zoom {
+            projection.scale = zoom;
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::components::{AntBehavior, CarryIndicator, FoodKind, Queen, VisualEffectsSettings};
+use bevy::prelude::*;
+
+/// Share of an ant's color replaced by the color of the food it carries
+const CARRY_TINT_AMOUNT: f32 = 0.5;
+
+/// Size of the dot drawn above a carrying ant
+const CARRY_DOT_SIZE: f32 = 1.5;
+
+/// Height of the dot above the ant's center
+const CARRY_DOT_HEIGHT: f32 = 2.5;
+
+/// Kind of food an ant is carrying, if any
+pub fn carried_kind(behavior: &AntBehavior) -> Option<FoodKind> {
+    (behavior.carried_food > 0.0).then_some(behavior.carried_kind)
+}
+
+/// Blend an ant's color toward the color of the food it carries
+///
+/// Ants with empty mandibles keep their color unchanged.
+pub fn carry_tinted_color(color: Color, carried: Option<FoodKind>) -> Color {
+    let Some(kind) = carried else {
+        return color;
+    };
+    let color = color.to_srgba();
+    let target = kind.carry_color().to_srgba();
+
+    Color::srgba(
+        color.red + (target.red - color.red) * CARRY_TINT_AMOUNT,
+        color.green + (target.green - color.green) * CARRY_TINT_AMOUNT,
+        color.blue + (target.blue - color.blue) * CARRY_TINT_AMOUNT,
+        color.alpha,
+    )
+}
+
+/// System keeping a [`CarryIndicator`] dot above every worker that carries food
+///
+/// The dot wears the food kind's color and is removed once the load is dropped or the
+/// carry indicators are switched off.
+pub fn carry_indicator_system(
+    mut commands: Commands,
+    visual_effects_settings: Res<VisualEffectsSettings>,
+    ant_query: Query<(Entity, &AntBehavior, Option<&Children>), Without<Queen>>,
+    mut indicator_query: Query<&mut Sprite, With<CarryIndicator>>,
+) {
+    for (ant, behavior, children) in ant_query.iter() {
+        let carried =
+            carried_kind(behavior).filter(|_| visual_effects_settings.carry_indicators_enabled);
+        let indicator = children
+            .into_iter()
+            .flatten()
+            .copied()
+            .find(|child| indicator_query.contains(*child));
+
+        match (carried, indicator) {
+            (Some(kind), Some(indicator)) => {
+                let mut sprite = indicator_query.get_mut(indicator).unwrap();
+                if sprite.color != kind.carry_color() {
+                    sprite.color = kind.carry_color();
+                }
+            }
+            (Some(kind), None) => {
+                let dot = commands
+                    .spawn((
+                        CarryIndicator,
+                        SpriteBundle {
+                            sprite: Sprite {
+                                color: kind.carry_color(),
+                                custom_size: Some(Vec2::splat(CARRY_DOT_SIZE)),
+                                ..default()
+                            },
+                            // Just in front of the ant it rides on
+                            transform: Transform::from_xyz(0.0, CARRY_DOT_HEIGHT, 0.1),
+                            ..default()
+                        },
+                    ))
+                    .id();
+                commands.entity(ant).add_child(dot);
+            }
+            (None, Some(indicator)) => {
+                commands.entity(indicator).despawn_recursive();
+            }
+            (None, None) => {}
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use crate::components::{
+    Ant, AntBehavior, AntState, Chamber, ChamberClimateConfig, ChamberType, ExcavationTask,
+    Position, SoilCell, SpecializedRole, Tooltip, TooltipPosition, TooltipTrigger,
+};
+use crate::systems::rendering::{z_for, RenderLayer};
+use bevy::prelude::*;
+
+/// Spawn a nest chamber entity that shows its status on hover
+///
+/// Its filled circle is added by [`nest_render_system`](crate::systems::nest_render_system).
+pub fn spawn_chamber(
+    commands: &mut Commands,
+    position: Position,
+    chamber_type: ChamberType,
+    radius: f32,
+    capacity: usize,
+) -> Entity {
+    let chamber = Chamber {
+        chamber_type,
+        radius,
+        capacity,
+        occupants: 0,
+        temperature: 25.0, // Freshly dug chambers start comfortable
+    };
+
+    let translation = Vec3::new(position.x, position.y, z_for(RenderLayer::Chamber));
+
+    commands
+        .spawn((
+            position,
+            Tooltip {
+                text: chamber_tooltip_text(&chamber),
+                shortcut: None,
+                position: TooltipPosition::Right,
+            },
+            TooltipTrigger::default(),
+            chamber,
+            SpatialBundle::from_transform(Transform::from_translation(translation)),
+        ))
+        .id()
+}
+
+/// Draw a thin outline around a chamber in its type's color
+pub fn spawn_chamber_outline(
+    commands: &mut Commands,
+    chamber: Entity,
+    chamber_type: ChamberType,
+    radius: f32,
+) {
+    let thickness = 0.5;
+    let length = radius * 2.0 + thickness;
+    let edges = [
+        (Vec2::new(0.0, radius), Vec2::new(length, thickness)),
+        (Vec2::new(0.0, -radius), Vec2::new(length, thickness)),
+        (Vec2::new(-radius, 0.0), Vec2::new(thickness, length)),
+        (Vec2::new(radius, 0.0), Vec2::new(thickness, length)),
+    ];
+
+    commands.entity(chamber).with_children(|parent| {
+        for (offset, size) in edges {
+            parent.spawn(SpriteBundle {
+                sprite: Sprite {
+                    color: chamber_type.color(),
+                    custom_size: Some(size),
+                    ..default()
+                },
+                transform: Transform::from_translation(offset.extend(0.01)),
+                ..default()
+            });
+        }
+    });
+}
+
+/// Tooltip text summarizing a chamber
+pub fn chamber_tooltip_text(chamber: &Chamber) -> String {
+    format!(
+        "{}\n{}/{} ants ({:.0}% full)",
+        chamber.display_name(),
+        chamber.occupants,
+        chamber.capacity,
+        chamber.capacity_usage() * 100.0
+    )
+}
+
+/// System to count the ants inside each chamber and refresh its tooltip
+pub fn chamber_occupancy_system(
+    mut chamber_query: Query<(&Position, &mut Chamber, &mut Tooltip)>,
+    ant_query: Query<&Position, With<Ant>>,
+) {
+    for (chamber_position, mut chamber, mut tooltip) in chamber_query.iter_mut() {
+        let occupants = ant_query
+            .iter()
+            .filter(|ant_position| {
+                let dx = ant_position.x - chamber_position.x;
+                let dy = ant_position.y - chamber_position.y;
+                dx * dx + dy * dy <= chamber.radius * chamber.radius
+            })
+            .count();
+
+        if chamber.occupants != occupants {
+            chamber.occupants = occupants;
+            tooltip.text = chamber_tooltip_text(&chamber);
+        }
+    }
+}
+
+/// System letting each chamber's microclimate follow the surrounding soil, buffered by
+/// nest maintainers inside it
+///
+/// Maintainers slow the drift while the surroundings are outside the optimal band
+/// (huddling against cold, ventilating against heat) and nudge it back toward the band.
+pub fn chamber_climate_system(
+    time: Res<Time>,
+    config: Res<ChamberClimateConfig>,
+    mut chamber_query: Query<(&Position, &mut Chamber)>,
+    soil_query: Query<(&Position, &SoilCell)>,
+    ant_query: Query<(&Position, &SpecializedRole), With<Ant>>,
+) {
+    let delta_time = time.delta_seconds();
+
+    for (chamber_position, mut chamber) in chamber_query.iter_mut() {
+        let sample_radius = chamber.radius + config.soil_sample_margin;
+        let (total, count) = soil_query
+            .iter()
+            .filter(|(position, _)| distance(position, chamber_position) <= sample_radius)
+            .fold((0.0, 0), |(total, count), (_, soil)| {
+                (total + soil.temperature, count + 1)
+            });
+        if count == 0 {
+            continue;
+        }
+        let ambient = total / count as f32;
+
+        let maintainers = ant_query
+            .iter()
+            .filter(|(position, role)| {
+                **role == SpecializedRole::NestMaintainer
+                    && distance(position, chamber_position) <= chamber.radius
+            })
+            .count() as f32;
+
+        // Only drift toward unfavorable surroundings is buffered
+        let mut drift = (ambient - chamber.temperature) * config.drift_rate * delta_time;
+        if ambient < config.optimal_min || ambient > config.optimal_max {
+            let buffer = (maintainers * config.buffer_per_maintainer).min(config.max_buffer);
+            drift *= 1.0 - buffer;
+        }
+        chamber.temperature += drift;
+
+        // Active regulation back toward the optimal band
+        let target = chamber
+            .temperature
+            .clamp(config.optimal_min, config.optimal_max);
+        let correction = maintainers * config.regulation_rate * delta_time;
+        chamber.temperature += (target - chamber.temperature).clamp(-correction, correction);
+    }
+}
+
+/// Chamber type an ant heads for given its role and what it is doing, if any
+///
+/// Nursery workers tend the brood, storage workers keep to the food stores, ants bringing
+/// food back deliver it to storage and resting ants retire to the worker quarters.
+pub fn destination_chamber_type(
+    role: Option<&SpecializedRole>,
+    state: AntState,
+) -> Option<ChamberType> {
+    match (role, state) {
+        (Some(SpecializedRole::NurseryWorker), _) => Some(ChamberType::Nursery),
+        (Some(SpecializedRole::StorageWorker), _) => Some(ChamberType::FoodStorage),
+        (_, AntState::Returning) => Some(ChamberType::FoodStorage),
+        (_, AntState::Resting) => Some(ChamberType::Worker),
+        _ => None,
+    }
+}
+
+/// Nearest chamber of the given type, with its position
+pub fn nearest_chamber_of_type<'a>(
+    chamber_type: ChamberType,
+    position: &Position,
+    chambers: impl IntoIterator<Item = (&'a Position, &'a Chamber)>,
+) -> Option<(&'a Position, &'a Chamber)> {
+    chambers
+        .into_iter()
+        .filter(|(_, chamber)| chamber.chamber_type == chamber_type)
+        .min_by(|a, b| distance(a.0, position).total_cmp(&distance(b.0, position)))
+}
+
+/// Where an ant should head: the nearest chamber its role and state call for, or home
+/// while the colony has no such chamber yet
+pub fn ant_destination<'a>(
+    role: Option<&SpecializedRole>,
+    state: AntState,
+    position: &Position,
+    home: &Position,
+    chambers: impl IntoIterator<Item = (&'a Position, &'a Chamber)>,
+) -> Position {
+    destination_chamber_type(role, state)
+        .and_then(|chamber_type| nearest_chamber_of_type(chamber_type, position, chambers))
+        .map_or_else(
+            || home.clone(),
+            |(chamber_position, _)| chamber_position.clone(),
+        )
+}
+
+/// System sending nursery workers and nest maintainers to the nursery (or any chamber)
+///
+/// The chosen chamber becomes the ant's target. Maintainers busy excavating a new chamber
+/// are left to their task.
+pub fn nest_worker_positioning_system(
+    time: Res<Time>,
+    chamber_query: Query<(&Position, &Chamber), Without<Ant>>,
+    mut ant_query: Query<
+        (&mut Position, &mut AntBehavior, &SpecializedRole),
+        (With<Ant>, Without<ExcavationTask>),
+    >,
+) {
+    let delta_time = time.delta_seconds();
+
+    for (mut position, mut behavior, role) in ant_query.iter_mut() {
+        if !role.works_inside_nest() {
+            continue;
+        }
+
+        let preferred =
+            destination_chamber_type(Some(role), behavior.state).unwrap_or(ChamberType::Nursery);
+        let Some((chamber_position, chamber)) =
+            nearest_chamber_of_type(preferred, &position, chamber_query.iter()).or_else(|| {
+                chamber_query
+                    .iter()
+                    .min_by(|a, b| distance(a.0, &position).total_cmp(&distance(b.0, &position)))
+            })
+        else {
+            continue;
+        };
+        behavior.target_position = Some(chamber_position.clone());
+
+        // Stay put once inside the chamber
+        let gap = distance(chamber_position, &position);
+        if gap <= chamber.radius * 0.5 {
+            continue;
+        }
+        let step = (behavior.speed * delta_time).min(gap);
+        position.x += (chamber_position.x - position.x) / gap * step;
+        position.y += (chamber_position.y - position.y) / gap * step;
+    }
+}
+
+/// Temperature of the chamber containing a position, if any
+pub fn chamber_temperature_at<'a>(
+    position: &Position,
+    chambers: impl IntoIterator<Item = (&'a Position, &'a Chamber)>,
+) -> Option<f32> {
+    chambers
+        .into_iter()
+        .find(|(chamber_position, chamber)| distance(chamber_position, position) <= chamber.radius)
+        .map(|(_, chamber)| chamber.temperature)
+}
+
+fn distance(a: &Position, b: &Position) -> f32 {
+    let dx = a.x - b.x;
+    let dy = a.y - b.y;
+    (dx * dx + dy * dy).sqrt()
+}
@@ -0,0 +1,33 @@
+use crate::components::{PendingDespawn, Position, SpatialGrid};
+use bevy::prelude::*;
+
+/// System despawning at most the per-frame budget of queued dead entities
+///
+/// Spreading mass deaths over several frames keeps a harsh disaster from spiking the
+/// frame time. Each removed entity also leaves the shared spatial grid.
+pub fn batched_cleanup_system(
+    mut commands: Commands,
+    mut pending_despawn: ResMut<PendingDespawn>,
+    mut spatial_grid: Option<ResMut<SpatialGrid>>,
+    position_query: Query<&Position>,
+) {
+    if pending_despawn.is_empty() {
+        return;
+    }
+
+    for entity in pending_despawn.next_batch() {
+        let Some(entity_commands) = commands.get_entity(entity) else {
+            continue; // Already gone
+        };
+        if let (Some(spatial_grid), Ok(position)) =
+            (spatial_grid.as_mut(), position_query.get(entity))
+        {
+            spatial_grid.remove_entity(entity, position);
+        }
+        entity_commands.despawn_recursive();
+    }
+
+    if !pending_despawn.is_empty() {
+        debug!("{} dead entities left to clean up", pending_despawn.len());
+    }
+}
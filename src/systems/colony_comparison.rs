@@ -0,0 +1,295 @@
+use crate::components::{
+    Ant, AntBehavior, ColonyComparison, ColonyComparisonPanel, ColonyComparisonRow,
+    ColonyComparisonText, ColonyDevelopmentPhase, ColonyFoodStore, ColonyId, ComparisonMetric,
+    DaughterColonies, DevelopmentPhase, DisplayedText, Dying, InputAction, KeyBindings,
+    PanelAnchor, Queen, ResponsivePanel, SimulationTime,
+};
+use crate::localization::Localization;
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Color of the rows of colonies that died out
+const EXTINCT_COLOR: Color = Color::srgb(0.45, 0.45, 0.45);
+
+/// Header line of the comparison panel
+pub fn comparison_title(metric: ComparisonMetric, localization: &Localization) -> String {
+    format!(
+        "{} ({} {})",
+        localization.t("comparison.title"),
+        localization.t("comparison.sorted_by"),
+        localization.t(metric.label_key())
+    )
+}
+
+/// One line of the comparison panel
+pub fn comparison_row_label(row: &ColonyComparisonRow, localization: &Localization) -> String {
+    let extinct = if row.extinct {
+        format!(" ({})", localization.t("comparison.extinct"))
+    } else {
+        String::new()
+    };
+    format!(
+        "{} {:<3} {} {:<5} {:<28} {} {:<6.0} {} {:.1}{}",
+        localization.t("comparison.colony"),
+        row.colony.0,
+        localization.t("comparison.population"),
+        row.population,
+        localization.t(row.phase.label_key()),
+        localization.t("comparison.food"),
+        row.food,
+        localization.t("comparison.day"),
+        row.age_days,
+        extinct
+    )
+}
+
+/// System advancing the development phase of every colony in [`DaughterColonies`]
+///
+/// Daughter colonies have no phase criteria of their own, so the phase follows their
+/// worker count; like the home colony's, it never falls back once reached.
+pub fn daughter_colony_system(
+    ant_query: Query<&ColonyId, (With<Ant>, Without<Queen>, Without<Dying>)>,
+    mut daughters: ResMut<DaughterColonies>,
+) {
+    let mut workers: HashMap<ColonyId, usize> = HashMap::new();
+    for colony in ant_query.iter() {
+        if *colony != ColonyId::default() {
+            *workers.entry(*colony).or_default() += 1;
+        }
+    }
+
+    for (colony, workers) in workers {
+        let phase = DevelopmentPhase::for_worker_count(workers);
+        let daughter = daughters.colonies.entry(colony).or_default();
+        if phase > daughter.phase {
+            daughter.phase = phase;
+        }
+    }
+}
+
+/// System rebuilding the per-colony comparison rows from the ants of each [`ColonyId`]
+///
+/// The home colony reports its tracked development phase and its food store, daughter
+/// colonies the ones kept in [`DaughterColonies`]; food carried by foragers counts for
+/// their colony too. A colony whose last ant is gone keeps its row, frozen at its last
+/// figures and marked extinct.
+pub fn colony_comparison_system(
+    simulation_time: Option<Res<SimulationTime>>,
+    colony_phase: Option<Res<ColonyDevelopmentPhase>>,
+    food_store: Option<Res<ColonyFoodStore>>,
+    daughters: Option<Res<DaughterColonies>>,
+    ant_query: Query<
+        (Option<&ColonyId>, Option<&AntBehavior>, Has<Queen>),
+        (With<Ant>, Without<Dying>),
+    >,
+    mut comparison: ResMut<ColonyComparison>,
+) {
+    let simulation_time = simulation_time.as_deref().cloned().unwrap_or_default();
+    let day_length = simulation_time.day_length_seconds.max(f32::EPSILON);
+
+    // Population, workers and carried food per colony
+    let mut tallies: HashMap<ColonyId, (usize, usize, f32)> = HashMap::new();
+    for (colony, behavior, is_queen) in ant_query.iter() {
+        let tally = tallies
+            .entry(colony.copied().unwrap_or_default())
+            .or_default();
+        tally.0 += 1;
+        if !is_queen {
+            tally.1 += 1;
+        }
+        tally.2 += behavior.map_or(0.0, |behavior| behavior.carried_food);
+    }
+
+    let mut rows = comparison.rows.clone();
+    for row in rows.iter_mut() {
+        if !tallies.contains_key(&row.colony) {
+            row.extinct = true;
+        }
+    }
+    for (colony, (population, workers, carried)) in tallies {
+        let (phase, stored) = if colony == ColonyId::default() {
+            (
+                colony_phase.as_ref().map_or_else(
+                    || DevelopmentPhase::for_worker_count(workers),
+                    |phase| phase.current_phase,
+                ),
+                food_store
+                    .as_ref()
+                    .map_or(0.0, |store| store.food + store.protein),
+            )
+        } else {
+            daughters
+                .as_ref()
+                .and_then(|daughters| daughters.colonies.get(&colony))
+                .map_or(
+                    (DevelopmentPhase::for_worker_count(workers), 0.0),
+                    |daughter| (daughter.phase, daughter.food),
+                )
+        };
+
+        let founded_at = rows
+            .iter()
+            .find(|row| row.colony == colony)
+            .map_or(simulation_time.elapsed_seconds, |row| row.founded_at);
+        let row = ColonyComparisonRow {
+            colony,
+            population,
+            phase,
+            food: stored + carried,
+            founded_at,
+            age_days: (simulation_time.elapsed_seconds - founded_at).max(0.0) / day_length,
+            extinct: false,
+        };
+        match rows.iter_mut().find(|existing| existing.colony == colony) {
+            Some(existing) => *existing = row,
+            None => rows.push(row),
+        }
+    }
+
+    let mut updated = ColonyComparison {
+        rows,
+        ..comparison.clone()
+    };
+    updated.sort();
+    if updated.rows != comparison.rows {
+        comparison.rows = updated.rows;
+    }
+}
+
+/// System to set up the colony comparison panel (hidden until toggled)
+///
+/// It sits at the bottom centre so it never covers the statistics panel in the
+/// bottom-left corner.
+pub fn setup_colony_comparison_panel(mut commands: Commands) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(170.0),
+                bottom: Val::Px(10.0),
+                width: Val::Px(460.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            background_color: Color::srgba(0.0, 0.0, 0.0, 0.8).into(),
+            border_radius: BorderRadius::all(Val::Px(5.0)),
+            visibility: Visibility::Hidden,
+            ..default()
+        })
+        .insert(ColonyComparisonPanel)
+        .insert(ResponsivePanel {
+            anchor: PanelAnchor::BottomCenter,
+            width: 460.0,
+            margin: 10.0,
+        })
+        .with_children(|parent| {
+            parent
+                .spawn(TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 13.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                ))
+                .insert((ColonyComparisonText, DisplayedText::default()));
+        });
+}
+
+/// System showing the comparison panel and cycling its sort metric, on the keys bound to
+/// [`InputAction::ToggleColonyComparison`] and [`InputAction::CycleComparisonSort`]
+pub fn colony_comparison_input_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    mut comparison: ResMut<ColonyComparison>,
+) {
+    if key_bindings.just_pressed(&keyboard_input, InputAction::ToggleColonyComparison) {
+        comparison.visible = !comparison.visible;
+        info!(
+            "Colony comparison {}",
+            if comparison.visible {
+                "shown"
+            } else {
+                "hidden"
+            }
+        );
+    }
+    if key_bindings.just_pressed(&keyboard_input, InputAction::CycleComparisonSort) {
+        comparison.sort_by = comparison.sort_by.next();
+        comparison.sort();
+        info!(
+            "Colony comparison sorted by {}",
+            comparison.sort_by.display_name()
+        );
+    }
+}
+
+/// System refreshing the comparison panel when its rows, order, visibility or language change
+///
+/// Ages move on every frame, so the text is only rebuilt when one of the shown labels
+/// differs from what is on screen. Each colony gets its own text section so extinct
+/// colonies can be greyed out.
+pub fn update_colony_comparison_panel(
+    comparison: Res<ColonyComparison>,
+    localization: Res<Localization>,
+    mut panel_query: Query<&mut Visibility, With<ColonyComparisonPanel>>,
+    mut text_query: Query<(&mut Text, &mut DisplayedText), With<ColonyComparisonText>>,
+) {
+    if !comparison.is_changed() && !localization.is_changed() {
+        return;
+    }
+    let visibility = if comparison.visible {
+        Visibility::Visible
+    } else {
+        Visibility::Hidden
+    };
+    for mut panel_visibility in panel_query.iter_mut() {
+        panel_visibility.set_if_neq(visibility);
+    }
+    if !comparison.visible {
+        return;
+    }
+
+    let title = comparison_title(comparison.sort_by, &localization);
+    let labels: Vec<String> = comparison
+        .rows
+        .iter()
+        .map(|row| comparison_row_label(row, &localization))
+        .collect();
+    let shown = std::iter::once(title.clone())
+        .chain(labels.iter().cloned())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    for (mut text, mut displayed) in text_query.iter_mut() {
+        if displayed.0 == shown {
+            continue;
+        }
+        let Some(style) = text.sections.first().map(|section| section.style.clone()) else {
+            continue;
+        };
+        let mut sections = vec![TextSection::new(
+            title.clone(),
+            TextStyle {
+                color: Color::WHITE,
+                ..style.clone()
+            },
+        )];
+        sections.extend(comparison.rows.iter().zip(&labels).map(|(row, label)| {
+            TextSection::new(
+                format!("\n{}", label),
+                TextStyle {
+                    color: if row.extinct {
+                        EXTINCT_COLOR
+                    } else {
+                        row.colony.color()
+                    },
+                    ..style.clone()
+                },
+            )
+        }));
+        text.sections = sections;
+        displayed.0.clone_from(&shown);
+    }
+}
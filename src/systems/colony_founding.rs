@@ -0,0 +1,324 @@
+use crate::components::{
+    Ant, ChamberType, CoFoundingQueen, ColonyId, ColonyTraits, DaughterColonies, Dying, EventLog,
+    FoundingReserve, FoundingState, FoundingSuccessConfig, Health, LaidBy, NuptialFlightConfig,
+    PendingDespawn, Position, Queen, ReproductionState, RoyalChamber, SimulationRng, SoilCell,
+};
+use crate::systems::rendering::{z_for, RenderLayer};
+use bevy::prelude::*;
+use rand::{thread_rng, Rng, RngCore};
+use std::collections::{HashMap, HashSet};
+
+/// Maximum per-trait drift between a mother colony and a daughter colony
+pub const DAUGHTER_TRAIT_MUTATION: f32 = 0.05;
+
+/// Hit points of a healthy queen
+pub const QUEEN_MAX_HP: f32 = 200.0;
+
+/// Derive a daughter colony's traits from its mother colony
+///
+/// Each trait is jittered by at most `mutation` in either direction and clamped to
+/// the valid `ColonyTraits` range, so lineages drift slowly under selection.
+pub fn inherit_traits(parent: &ColonyTraits, mutation: f32, rng: &mut impl Rng) -> ColonyTraits {
+    let mut jitter = |value: f32| {
+        let delta = if mutation > 0.0 {
+            rng.gen_range(-mutation..=mutation)
+        } else {
+            0.0
+        };
+        (value + delta).clamp(ColonyTraits::MIN, ColonyTraits::MAX)
+    };
+
+    ColonyTraits {
+        queen_vigor: jitter(parent.queen_vigor),
+        worker_efficiency: jitter(parent.worker_efficiency),
+        architectural_skill: jitter(parent.architectural_skill),
+        environmental_adaptation: jitter(parent.environmental_adaptation),
+    }
+}
+
+/// Spawn a queen that starts seeking a founding site with the given traits
+///
+/// She carries a full [`FoundingReserve`] to live on until her first workers emerge.
+pub fn spawn_founding_queen(
+    commands: &mut Commands,
+    position: Position,
+    traits: ColonyTraits,
+) -> Entity {
+    let translation = Vec3::new(position.x, position.y, z_for(RenderLayer::Queen));
+
+    commands
+        .spawn((
+            position,
+            Queen {
+                founding_state: FoundingState::Seeking,
+            },
+            traits,
+            ReproductionState::default(),
+            FoundingReserve::default(),
+            Health::new(QUEEN_MAX_HP),
+            Ant, // Queens are also ants, so they can use existing movement systems
+            SpriteBundle {
+                sprite: Sprite {
+                    color: ChamberType::Queen.color(), // Royal purple, matching her chamber
+                    custom_size: Some(Vec2::new(4.0, 4.0)), // 4x4 pixels (twice the 2x2 workers)
+                    ..default()
+                },
+                transform: Transform::from_translation(translation),
+                ..default()
+            },
+        ))
+        .id()
+}
+
+/// Spawn a queen founding a new colony from an existing one, keeping its lineage
+///
+/// The daughter heads colony `colony`, which her eggs and workers inherit.
+pub fn found_daughter_colony(
+    commands: &mut Commands,
+    parent_traits: &ColonyTraits,
+    position: Position,
+    colony: ColonyId,
+    rng: &mut impl Rng,
+) -> Entity {
+    let traits = inherit_traits(parent_traits, DAUGHTER_TRAIT_MUTATION, rng);
+    let queen = spawn_founding_queen(commands, position, traits);
+    commands.entity(queen).insert(colony);
+    queen
+}
+
+/// System sending daughter queens off on nuptial flights from a thriving colony
+///
+/// Once the colony is as large as [`NuptialFlightConfig::min_workers`], an established
+/// queen raises a daughter every `interval` seconds. The daughter lands on the surface
+/// near her mother and founds a colony through [`found_daughter_colony`], under a
+/// fresh [`ColonyId`] from [`DaughterColonies`], one no colony has had before.
+/// Rolls come from [`SimulationRng`] when the run is seeded.
+pub fn nuptial_flight_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Option<Res<NuptialFlightConfig>>,
+    mut simulation_rng: Option<ResMut<SimulationRng>>,
+    mut event_log: Option<ResMut<EventLog>>,
+    mut daughter_colonies: Option<ResMut<DaughterColonies>>,
+    mut since_last_flight: Local<f32>,
+    queen_query: Query<(&Queen, &Position, &ColonyTraits)>,
+    worker_query: Query<(), (With<Ant>, Without<Queen>)>,
+    colony_query: Query<&ColonyId>,
+) {
+    let config = config.map(|config| config.clone()).unwrap_or_default();
+    if worker_query.iter().count() < config.min_workers {
+        *since_last_flight = 0.0;
+        return;
+    }
+    let Some((_, mother_position, mother_traits)) = queen_query
+        .iter()
+        .find(|(queen, ..)| queen.founding_state == FoundingState::Established)
+    else {
+        return;
+    };
+
+    *since_last_flight += time.delta_seconds();
+    if *since_last_flight < config.interval {
+        return;
+    }
+    *since_last_flight = 0.0;
+
+    let mut fallback_rng = thread_rng();
+    let mut rng: &mut dyn RngCore = match simulation_rng.as_mut() {
+        Some(simulation_rng) => &mut simulation_rng.0,
+        None => &mut fallback_rng,
+    };
+    let offset = if config.dispersal_distance > 0.0 {
+        rng.gen_range(-config.dispersal_distance..=config.dispersal_distance)
+    } else {
+        0.0
+    };
+    let landing = Position {
+        x: mother_position.x + offset,
+        y: 0.0, // Surface level, where founding queens start
+    };
+    // Ants without an id belong to the home colony, 0. Ids of colonies that died out
+    // are not handed out again, so a new colony never inherits their figures
+    let in_use = colony_query
+        .iter()
+        .map(|colony| colony.0)
+        .max()
+        .unwrap_or(0);
+    let colony = match daughter_colonies.as_mut() {
+        Some(daughter_colonies) => daughter_colonies.allocate_id(in_use),
+        None => ColonyId(in_use + 1),
+    };
+    found_daughter_colony(
+        &mut commands,
+        mother_traits,
+        landing.clone(),
+        colony,
+        &mut rng,
+    );
+
+    info!(
+        "A daughter queen left on her nuptial flight and landed at ({:.1}, {:.1})",
+        landing.x, landing.y
+    );
+    if let Some(event_log) = event_log.as_mut() {
+        event_log.push("A daughter queen flew off to found a colony of her own");
+    }
+}
+
+/// System keeping co-founding queens with their founder until she has a royal chamber
+///
+/// A co-founder follows her founder while she seeks and digs, then moves into the
+/// founder's royal chamber and starts laying there. If the founder dies before settling,
+/// the co-founder carries on seeking a site of her own.
+pub fn co_founding_queen_system(
+    mut commands: Commands,
+    mut queen_query: Query<(
+        Entity,
+        &mut Queen,
+        &mut Position,
+        &mut Transform,
+        Option<&RoyalChamber>,
+        Option<&CoFoundingQueen>,
+    )>,
+) {
+    let founders: HashMap<Entity, (Position, Option<RoyalChamber>)> = queen_query
+        .iter()
+        .filter(|(_, _, _, _, _, co_founder)| co_founder.is_none())
+        .map(|(entity, _, position, _, chamber, _)| (entity, (position.clone(), chamber.cloned())))
+        .collect();
+
+    for (entity, mut queen, mut position, mut transform, _, co_founder) in queen_query.iter_mut() {
+        let Some(co_founder) = co_founder else {
+            continue;
+        };
+        match founders.get(&co_founder.founder) {
+            None => {
+                info!("A co-founding queen lost her founder and seeks a site of her own");
+                commands.entity(entity).remove::<CoFoundingQueen>();
+            }
+            Some((_, Some(chamber))) => {
+                queen.founding_state = FoundingState::Established;
+                *position = chamber.center.clone();
+                commands
+                    .entity(entity)
+                    .insert(chamber.clone())
+                    .remove::<CoFoundingQueen>();
+                info!("A co-founding queen joined the royal chamber");
+            }
+            Some((founder_position, None)) => {
+                position.x = founder_position.x;
+                position.y = founder_position.y;
+            }
+        }
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+    }
+}
+
+/// System deciding whether a lone queen lives to see her first workers
+///
+/// Once settled in her chamber, a queen with a [`FoundingReserve`] burns it at a rate set
+/// by the soil around her (see [`FoundingSuccessConfig::harshness`]) and runs a small
+/// risk of dying every second, both eased by her vigor. The first worker hatched from her
+/// own brood ends her founding; workers she did not raise do not count. A queen whose
+/// reserve runs out before then dies.
+/// Rolls come from [`SimulationRng`] when the run is seeded.
+pub fn founding_success_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Option<Res<FoundingSuccessConfig>>,
+    mut simulation_rng: Option<ResMut<SimulationRng>>,
+    mut pending_despawn: Option<ResMut<PendingDespawn>>,
+    mut event_log: Option<ResMut<EventLog>>,
+    mut queen_query: Query<(
+        Entity,
+        &Queen,
+        &Position,
+        Option<&ColonyTraits>,
+        &mut FoundingReserve,
+    )>,
+    worker_query: Query<&LaidBy, (With<Ant>, Without<Queen>)>,
+    soil_query: Query<(&Position, &SoilCell), Without<Queen>>,
+) {
+    let mothers: HashSet<Entity> = worker_query.iter().map(|laid_by| laid_by.queen).collect();
+
+    let config = config.map(|config| config.clone()).unwrap_or_default();
+    let delta_time = time.delta_seconds();
+    let mut fallback_rng = thread_rng();
+    let rng: &mut dyn RngCore = match simulation_rng.as_mut() {
+        Some(simulation_rng) => &mut simulation_rng.0,
+        None => &mut fallback_rng,
+    };
+
+    for (queen, state, position, traits, mut reserve) in queen_query.iter_mut() {
+        if mothers.contains(&queen) {
+            commands.entity(queen).remove::<FoundingReserve>();
+            continue;
+        }
+        if state.founding_state != FoundingState::Established {
+            continue;
+        }
+        if pending_despawn
+            .as_ref()
+            .is_some_and(|pending| pending.contains(queen))
+        {
+            continue;
+        }
+
+        let (vigor, adaptation) = traits.map_or((1.0, 1.0), |traits| {
+            (traits.queen_vigor, traits.environmental_adaptation)
+        });
+        let (temperature, moisture) = surrounding_soil(position, &soil_query, &config);
+        let harshness = config.harshness(temperature, moisture, adaptation);
+        let vigor = vigor.max(f32::EPSILON);
+
+        reserve.spent += harshness * delta_time;
+        let exhausted = reserve.spent >= config.reserve_seconds * vigor;
+        let hazard = (config.hazard_rate * harshness / vigor * delta_time).clamp(0.0, 1.0);
+        if !exhausted && !rng.gen_bool(hazard as f64) {
+            continue;
+        }
+
+        match pending_despawn.as_mut() {
+            Some(pending) => {
+                pending.enqueue(queen);
+                commands.entity(queen).insert(Dying);
+            }
+            None => commands.entity(queen).despawn_recursive(),
+        }
+        info!(
+            "A founding queen died after {:.0}s without raising workers",
+            reserve.spent
+        );
+        if let Some(event_log) = event_log.as_mut() {
+            event_log.push("A founding queen died before her first workers emerged");
+        }
+    }
+}
+
+/// Mean temperature and moisture of the soil around a queen; ideal when there is none
+fn surrounding_soil(
+    position: &Position,
+    soil_query: &Query<(&Position, &SoilCell), Without<Queen>>,
+    config: &FoundingSuccessConfig,
+) -> (f32, f32) {
+    let mut total_temperature = 0.0;
+    let mut total_moisture = 0.0;
+    let mut samples = 0;
+    for (soil_position, soil) in soil_query.iter() {
+        let dx = soil_position.x - position.x;
+        let dy = soil_position.y - position.y;
+        if dx * dx + dy * dy <= config.sense_radius * config.sense_radius {
+            total_temperature += soil.temperature;
+            total_moisture += soil.moisture;
+            samples += 1;
+        }
+    }
+    if samples == 0 {
+        return (config.ideal_temperature, config.ideal_moisture);
+    }
+    (
+        total_temperature / samples as f32,
+        total_moisture / samples as f32,
+    )
+}
@@ -1,130 +1,120 @@
 use crate::components::{
-    ColorOverlay, ColorOverlayConfig, DisasterState, DisasterType, VisualEffectsSettings,
+    ColorOverlay, ColorOverlayConfig, DisasterState, DisasterType, OverlayDescriptor,
+    OverlaySource, SimulationTime, UserSettings, VisualEffectsSettings,
 };
+use crate::systems::rendering::{z_for, RenderLayer};
 use bevy::prelude::*;
 
-/// System for managing color overlay entities based on active disasters
-pub fn color_overlay_system(
-    mut commands: Commands,
-    disaster_state: Res<DisasterState>,
+/// System loading the user's disaster tints into the overlay config whenever they change
+///
+/// The compositor reads the config every frame, so new tints show up on an overlay that
+/// is already visible.
+pub fn overlay_color_settings_system(
+    user_settings: Res<UserSettings>,
     mut overlay_config: ResMut<ColorOverlayConfig>,
-    overlay_query: Query<Entity, With<ColorOverlay>>,
-    windows: Query<&Window>,
-    visual_effects_settings: Res<VisualEffectsSettings>,
 ) {
-    // Get window size for fullscreen overlay
-    let window = windows.single();
-    let window_width = window.width();
-    let window_height = window.height();
-
-    // Remove existing overlay entities
-    for entity in overlay_query.iter() {
-        commands.entity(entity).despawn();
-    }
-    overlay_config.overlay_entity = None;
-
-    // Skip overlay creation if overlays are disabled for accessibility
-    if !visual_effects_settings.overlays_enabled {
-        return;
-    }
-
-    // Calculate blended color for active disasters
-    let blended_color = calculate_blended_overlay_color(&disaster_state, &overlay_config);
-
-    // Spawn new overlay entity if any disasters are active
-    if let Some(color) = blended_color {
-        let overlay_entity = commands
-            .spawn((
-                ColorOverlay {
-                    color,
-                    disaster_type: DisasterType::Rain, // Primary disaster type (for reference)
-                },
-                SpriteBundle {
-                    sprite: Sprite {
-                        color,
-                        custom_size: Some(Vec2::new(window_width, window_height)),
-                        ..default()
-                    },
-                    transform: Transform::from_translation(Vec3::new(0.0, 0.0, 1000.0)), // High Z to render on top
-                    ..default()
-                },
-            ))
-            .id();
-
-        overlay_config.overlay_entity = Some(overlay_entity);
+    if user_settings.is_changed() {
+        overlay_config.apply_user_colors(&user_settings);
     }
 }
 
-/// Calculate the blended color for multiple active disasters
-fn calculate_blended_overlay_color(
-    disaster_state: &DisasterState,
-    overlay_config: &ColorOverlayConfig,
-) -> Option<Color> {
-    let active_disasters: Vec<DisasterType> =
-        disaster_state.active_disasters.keys().copied().collect();
-
-    if active_disasters.is_empty() {
-        return None;
-    }
-
-    // If only one disaster is active, return its color directly
-    if active_disasters.len() == 1 {
-        return overlay_config
-            .disaster_colors
-            .get(&active_disasters[0])
-            .copied();
-    }
-
-    // For multiple disasters, blend the colors using additive blending with alpha management
-    let mut total_red = 0.0;
-    let mut total_green = 0.0;
-    let mut total_blue = 0.0;
-    let mut total_alpha = 0.0;
-
-    for disaster_type in &active_disasters {
-        if let Some(color) = overlay_config.disaster_colors.get(disaster_type) {
-            let [r, g, b, a] = color.to_srgba().to_f32_array();
-            total_red += r * a; // Weight by alpha for proper blending
-            total_green += g * a;
-            total_blue += b * a;
-            total_alpha += a;
-        }
+/// System collecting this frame's overlay tints and composing them into one color
+///
+/// Every active disaster contributes its configured tint, its alpha scaled by the
+/// disasters' `visual_intensity`, and the day/night cycle adds a night tint that
+/// deepens toward midnight.
+pub fn overlay_compositor_system(
+    disaster_state: Res<DisasterState>,
+    simulation_time: Option<Res<SimulationTime>>,
+    mut overlay_config: ResMut<ColorOverlayConfig>,
+) {
+    let mut overlays: Vec<OverlayDescriptor> = DisasterType::ALL
+        .iter()
+        .filter(|disaster_type| disaster_state.is_active(**disaster_type))
+        .filter_map(|disaster_type| {
+            overlay_config
+                .disaster_colors
+                .get(disaster_type)
+                .map(|color| OverlayDescriptor {
+                    source: OverlaySource::Disaster(*disaster_type),
+                    color: color.with_alpha(
+                        (color.alpha() * disaster_state.visual_intensity).clamp(0.0, 1.0),
+                    ),
+                })
+        })
+        .collect();
+    if let Some(simulation_time) = simulation_time {
+        overlays.extend(overlay_config.night_overlay(simulation_time.get_time_of_day_fraction()));
     }
 
-    if total_alpha > 0.0 {
-        // Normalize by total alpha and cap alpha at reasonable level
-        let blended_alpha = (total_alpha * 0.7).min(0.4); // Cap combined alpha to prevent too strong overlay
-        let normalized_red = total_red / total_alpha;
-        let normalized_green = total_green / total_alpha;
-        let normalized_blue = total_blue / total_alpha;
+    overlay_config.active_overlays = overlays;
+    overlay_config.composed_color = overlay_config.compose();
+}
 
-        Some(Color::srgba(
-            normalized_red,
-            normalized_green,
-            normalized_blue,
-            blended_alpha,
-        ))
-    } else {
-        None
+/// System painting the user's world theme into Bevy's `ClearColor`
+///
+/// The background dims toward midnight on the same curve as the night tint, so the
+/// theme and the day/night cycle agree instead of fighting over the background.
+pub fn world_background_system(
+    user_settings: Res<UserSettings>,
+    simulation_time: Option<Res<SimulationTime>>,
+    mut clear_color: ResMut<ClearColor>,
+) {
+    let theme = user_settings.world_theme;
+    let background = simulation_time.map_or(theme.background(), |simulation_time| {
+        theme.background_at(simulation_time.get_time_of_day_fraction())
+    });
+    if clear_color.0 != background {
+        clear_color.0 = background;
     }
 }
 
-/// System for updating overlay size when window is resized
-pub fn update_overlay_size_system(
-    mut overlay_query: Query<(&mut Transform, &mut Sprite), With<ColorOverlay>>,
+/// System drawing the composed tint as a single fullscreen overlay sprite
+///
+/// The sprite follows the camera and covers the whole window at any zoom. It is hidden
+/// while no overlay is active or overlays are disabled for accessibility.
+pub fn color_overlay_system(
+    mut commands: Commands,
+    overlay_config: Res<ColorOverlayConfig>,
+    visual_effects_settings: Res<VisualEffectsSettings>,
     windows: Query<&Window>,
-    mut resize_events: EventReader<bevy::window::WindowResized>,
+    camera_query: Query<
+        (&Transform, &OrthographicProjection),
+        (With<Camera>, Without<ColorOverlay>),
+    >,
+    mut overlay_query: Query<(&mut Sprite, &mut Transform, &mut Visibility), With<ColorOverlay>>,
 ) {
-    for _event in resize_events.read() {
-        let window = windows.single();
-        let window_width = window.width();
-        let window_height = window.height();
+    let Ok((mut sprite, mut transform, mut visibility)) = overlay_query.get_single_mut() else {
+        commands.spawn((
+            ColorOverlay,
+            SpriteBundle {
+                transform: Transform::from_xyz(0.0, 0.0, z_for(RenderLayer::Overlay)),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+        ));
+        return;
+    };
+
+    let color = overlay_config
+        .composed_color
+        .filter(|_| visual_effects_settings.overlays_enabled);
+    let Some(color) = color else {
+        visibility.set_if_neq(Visibility::Hidden);
+        return;
+    };
 
-        for (mut transform, mut sprite) in overlay_query.iter_mut() {
-            sprite.custom_size = Some(Vec2::new(window_width, window_height));
-            // Keep overlay centered
-            transform.translation.x = 0.0;
-            transform.translation.y = 0.0;
-        }
+    let (camera_position, camera_scale) =
+        camera_query
+            .get_single()
+            .map_or((Vec2::ZERO, 1.0), |(camera_transform, projection)| {
+                (camera_transform.translation.truncate(), projection.scale)
+            });
+    if let Ok(window) = windows.get_single() {
+        sprite.custom_size = Some(window.size() * camera_scale);
     }
+    sprite.color = color;
+    transform.translation.x = camera_position.x;
+    transform.translation.y = camera_position.y;
+    visibility.set_if_neq(Visibility::Inherited);
 }
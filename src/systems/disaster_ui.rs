@@ -1,12 +1,24 @@
 use crate::components::{
-    DisasterControlButton, DisasterControlPanel, DisasterState, DisasterType, Tooltip,
-    TooltipPosition, TooltipTrigger, UITheme,
+    ActiveDisastersIcons, ActiveDisastersPanel, ActiveDisastersText, AutoDisasterConfig,
+    DisasterControlButton, DisasterControlPanel, DisasterIcon, DisasterPanelMode, DisasterState,
+    DisasterStatusIndicator, DisasterType, DisplayedText, Forecast, ForecastText, InputAction,
+    KeyBindings, PanelAnchor, ResponsivePanel, SimulationTime, Tooltip, TooltipPosition,
+    TooltipTrigger, UITheme, UserSettings,
 };
+use crate::localization::Localization;
+use crate::systems::disasters::active_interactions;
+use crate::systems::help_overlay::key_label;
 use bevy::prelude::*;
 
-/// Setup the disaster control panel UI
-pub fn setup_disaster_control_panel(mut commands: Commands, ui_theme: Res<UITheme>) {
-    // Main disaster control panel container
+/// Setup the disaster control panel with a trigger button per disaster
+///
+/// Starts hidden for a pure observation experience; its shortcut shows it.
+pub fn setup_disaster_control_panel(
+    mut commands: Commands,
+    ui_theme: Res<UITheme>,
+    localization: Res<Localization>,
+    key_bindings: Res<KeyBindings>,
+) {
     commands
         .spawn(NodeBundle {
             style: Style {
@@ -24,24 +36,26 @@ pub fn setup_disaster_control_panel(mut commands: Commands, ui_theme: Res<UIThem
             background_color: ui_theme.colors.surface_primary.into(),
             border_color: ui_theme.colors.border_primary.into(),
             border_radius: BorderRadius::all(Val::Px(ui_theme.borders.radius_medium)),
-            visibility: Visibility::Hidden, // Start hidden for pure observation experience
+            visibility: Visibility::Hidden,
             ..default()
         })
         .insert(DisasterControlPanel)
+        .insert(ResponsivePanel {
+            anchor: PanelAnchor::TopLeft,
+            width: 280.0,
+            margin: 20.0,
+        })
         .with_children(|parent| {
-            // Panel Title
             parent.spawn(TextBundle::from_section(
-                "Disaster Control",
+                localization.t("disasters.controls.title"),
                 TextStyle {
                     font_size: ui_theme.typography.heading_small,
                     color: ui_theme.colors.text_primary,
                     ..default()
                 },
             ));
-
-            // Description text
             parent.spawn(TextBundle::from_section(
-                "Trigger environmental disasters to observe colony adaptation",
+                localization.t("disasters.controls.description"),
                 TextStyle {
                     font_size: ui_theme.typography.body_small,
                     color: ui_theme.colors.text_secondary,
@@ -49,7 +63,6 @@ pub fn setup_disaster_control_panel(mut commands: Commands, ui_theme: Res<UIThem
                 },
             ));
 
-            // Disaster control buttons container
             parent
                 .spawn(NodeBundle {
                     style: Style {
@@ -61,36 +74,40 @@ pub fn setup_disaster_control_panel(mut commands: Commands, ui_theme: Res<UIThem
                     ..default()
                 })
                 .with_children(|buttons_parent| {
-                    // Create buttons for each disaster type
-                    create_disaster_button(buttons_parent, DisasterType::Rain, &ui_theme);
-                    create_disaster_button(buttons_parent, DisasterType::Drought, &ui_theme);
-                    create_disaster_button(buttons_parent, DisasterType::ColdSnap, &ui_theme);
-                    create_disaster_button(
-                        buttons_parent,
-                        DisasterType::InvasiveSpecies,
-                        &ui_theme,
-                    );
+                    for disaster_type in DisasterType::ALL {
+                        let key = key_label(
+                            key_bindings.key(InputAction::TriggerDisaster(disaster_type)),
+                        );
+                        create_disaster_button(
+                            buttons_parent,
+                            disaster_type,
+                            &key,
+                            &ui_theme,
+                            &localization,
+                        );
+                    }
                 });
         });
 }
 
-/// Create a disaster control button with icon and status indicator
+/// Create a disaster control button with its icon, shortcut and status indicator
 fn create_disaster_button(
     parent: &mut ChildBuilder,
     disaster_type: DisasterType,
+    key: &str,
     ui_theme: &UITheme,
+    localization: &Localization,
 ) {
     parent
         .spawn(ButtonBundle {
             style: Style {
                 width: Val::Percent(100.0),
-                height: Val::Px(60.0),
+                height: Val::Px(48.0),
                 flex_direction: FlexDirection::Row,
                 justify_content: JustifyContent::SpaceBetween,
                 align_items: AlignItems::Center,
                 padding: UiRect::all(Val::Px(ui_theme.spacing.md)),
                 border: UiRect::all(Val::Px(ui_theme.borders.width_thin)),
-                margin: UiRect::bottom(Val::Px(ui_theme.spacing.xs)),
                 ..default()
             },
             background_color: ui_theme.colors.surface_elevated.into(),
@@ -102,15 +119,15 @@ fn create_disaster_button(
         .insert(Tooltip {
             text: format!(
                 "{}: {}",
-                disaster_type.display_name(),
+                localization.t(disaster_type.label_key()),
                 get_disaster_description(disaster_type)
             ),
-            shortcut: Some(format!("Press {}", disaster_type.shortcut_key())),
+            shortcut: Some(format!("Press {}", key)),
             position: TooltipPosition::Right,
         })
         .insert(TooltipTrigger::default())
         .with_children(|button_parent| {
-            // Left side: Icon and label
+            // Left side: icon, name and shortcut
             button_parent
                 .spawn(NodeBundle {
                     style: Style {
@@ -122,46 +139,18 @@ fn create_disaster_button(
                     ..default()
                 })
                 .with_children(|left_parent| {
-                    // Disaster icon
+                    spawn_disaster_icon(left_parent, disaster_type);
                     left_parent.spawn(TextBundle::from_section(
-                        disaster_type.get_icon(),
+                        format!("{} ({})", localization.t(disaster_type.label_key()), key),
                         TextStyle {
-                            font_size: ui_theme.typography.heading_medium,
+                            font_size: ui_theme.typography.body_medium,
                             color: ui_theme.colors.text_primary,
                             ..default()
                         },
                     ));
-
-                    // Disaster name and shortcut
-                    left_parent
-                        .spawn(NodeBundle {
-                            style: Style {
-                                flex_direction: FlexDirection::Column,
-                                ..default()
-                            },
-                            ..default()
-                        })
-                        .with_children(|text_parent| {
-                            text_parent.spawn(TextBundle::from_section(
-                                disaster_type.display_name(),
-                                TextStyle {
-                                    font_size: ui_theme.typography.body_medium,
-                                    color: ui_theme.colors.text_primary,
-                                    ..default()
-                                },
-                            ));
-                            text_parent.spawn(TextBundle::from_section(
-                                format!("({})", disaster_type.shortcut_key()),
-                                TextStyle {
-                                    font_size: ui_theme.typography.body_small,
-                                    color: ui_theme.colors.text_muted,
-                                    ..default()
-                                },
-                            ));
-                        });
                 });
 
-            // Right side: Status indicator
+            // Right side: status indicator
             button_parent.spawn((
                 NodeBundle {
                     style: Style {
@@ -178,6 +167,21 @@ fn create_disaster_button(
         });
 }
 
+/// Color of a disaster's status indicator: available, active or cooling down
+pub fn disaster_status_color(
+    disaster_type: DisasterType,
+    disaster_state: &DisasterState,
+    ui_theme: &UITheme,
+) -> Color {
+    if disaster_state.is_active(disaster_type) {
+        ui_theme.colors.action_danger
+    } else if disaster_state.is_on_cooldown(disaster_type) {
+        ui_theme.colors.text_muted
+    } else {
+        ui_theme.colors.accent_green
+    }
+}
+
 /// Handle disaster control button interactions
 pub fn handle_disaster_control_interactions(
     mut interaction_query: Query<
@@ -188,80 +192,86 @@ pub fn handle_disaster_control_interactions(
     ui_theme: Res<UITheme>,
 ) {
     for (interaction, mut color, button) in &mut interaction_query {
-        match *interaction {
+        *color = match *interaction {
             Interaction::Pressed => {
-                // Trigger disaster if not on cooldown and not already active
-                if !disaster_state.is_active(button.disaster_type)
-                    && !disaster_state.is_on_cooldown(button.disaster_type)
-                {
-                    // Start disaster with appropriate duration
-                    let duration = match button.disaster_type {
-                        DisasterType::Rain => 30.0,
-                        DisasterType::Drought => 45.0,
-                        DisasterType::ColdSnap => 25.0,
-                        DisasterType::InvasiveSpecies => 40.0,
-                    };
-                    disaster_state.start_disaster(button.disaster_type, duration);
-                }
-                *color = ui_theme
-                    .get_active_color(ui_theme.colors.surface_elevated)
-                    .into();
-            }
-            Interaction::Hovered => {
-                *color = ui_theme
-                    .get_hover_color(ui_theme.colors.surface_elevated)
-                    .into();
-            }
-            Interaction::None => {
-                *color = ui_theme.colors.surface_elevated.into();
+                trigger_disaster_if_available(&mut disaster_state, button.disaster_type);
+                ui_theme.colors.action_primary
             }
+            Interaction::Hovered => ui_theme.colors.surface_secondary,
+            Interaction::None => ui_theme.colors.surface_elevated,
         }
+        .into();
     }
 }
 
-/// Update disaster status indicators based on current disaster state
+/// Update disaster status indicators when the disaster state changes
 pub fn update_disaster_status_indicators(
     mut status_query: Query<(&mut BackgroundColor, &DisasterStatusIndicator)>,
     disaster_state: Res<DisasterState>,
-    _ui_theme: Res<UITheme>,
+    ui_theme: Res<UITheme>,
 ) {
+    if !disaster_state.is_changed() && !ui_theme.is_changed() {
+        return;
+    }
     for (mut color, indicator) in &mut status_query {
-        *color = indicator
-            .disaster_type
-            .get_status_color(&disaster_state)
-            .into();
+        let status = disaster_status_color(indicator.disaster_type, &disaster_state, &ui_theme);
+        if color.0 != status {
+            color.0 = status;
+        }
+    }
+}
+
+/// System showing and hiding the disaster control panel with its shortcut
+pub fn toggle_disaster_control_panel_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    mut panel_query: Query<&mut Visibility, With<DisasterControlPanel>>,
+) {
+    if !key_bindings.just_pressed(&keyboard_input, InputAction::ToggleDisasterControls) {
+        return;
+    }
+    for mut visibility in panel_query.iter_mut() {
+        *visibility = match *visibility {
+            Visibility::Hidden => Visibility::Visible,
+            _ => Visibility::Hidden,
+        };
     }
 }
 
 /// Handle keyboard shortcuts for disaster control
 pub fn disaster_keyboard_input_system(
     keyboard_input: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
     mut disaster_state: ResMut<DisasterState>,
+    auto_disaster_config: Option<ResMut<AutoDisasterConfig>>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::KeyR) {
-        trigger_disaster_if_available(&mut disaster_state, DisasterType::Rain);
-    }
-    if keyboard_input.just_pressed(KeyCode::KeyD) {
-        trigger_disaster_if_available(&mut disaster_state, DisasterType::Drought);
-    }
-    if keyboard_input.just_pressed(KeyCode::KeyC) {
-        trigger_disaster_if_available(&mut disaster_state, DisasterType::ColdSnap);
+    if key_bindings.just_pressed(&keyboard_input, InputAction::ToggleAutoDisasters) {
+        if let Some(mut config) = auto_disaster_config {
+            config.enabled = !config.enabled;
+            info!(
+                "Automatic disasters {}",
+                if config.enabled { "on" } else { "off" }
+            );
+        }
     }
-    if keyboard_input.just_pressed(KeyCode::KeyI) {
-        trigger_disaster_if_available(&mut disaster_state, DisasterType::InvasiveSpecies);
+    for action in InputAction::ALL {
+        if let InputAction::TriggerDisaster(disaster_type) = action {
+            if key_bindings.just_pressed(&keyboard_input, action) {
+                trigger_disaster_if_available(&mut disaster_state, disaster_type);
+            }
+        }
     }
 }
 
 /// Helper function to trigger disaster if available
 fn trigger_disaster_if_available(disaster_state: &mut DisasterState, disaster_type: DisasterType) {
     if !disaster_state.is_active(disaster_type) && !disaster_state.is_on_cooldown(disaster_type) {
-        let duration = match disaster_type {
-            DisasterType::Rain => 30.0,
-            DisasterType::Drought => 45.0,
-            DisasterType::ColdSnap => 25.0,
-            DisasterType::InvasiveSpecies => 40.0,
-        };
-        disaster_state.start_disaster(disaster_type, duration);
+        disaster_state.start_disaster(disaster_type, disaster_type.default_duration());
+        info!(
+            "{} triggered ({})",
+            disaster_type.display_name(),
+            get_disaster_description(disaster_type)
+        );
     }
 }
 
@@ -271,8 +281,250 @@ fn get_disaster_description(disaster_type: DisasterType) -> &'static str {
         DisasterType::Rain => "Increases soil moisture, affects ant movement",
         DisasterType::Drought => "Reduces soil moisture, stresses food sources",
         DisasterType::ColdSnap => "Lowers temperature, slows ant activity",
+        DisasterType::Heatwave => "Raises temperature, dries soil and spoils food",
         DisasterType::InvasiveSpecies => "Introduces competing species, reduces food",
     }
 }
 
-use crate::components::DisasterStatusIndicator;
+/// Setup the panel listing active disasters (shown only while any are active)
+pub fn setup_active_disasters_panel(mut commands: Commands, ui_theme: Res<UITheme>) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(10.0),
+                bottom: Val::Px(10.0),
+                width: Val::Px(260.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(ui_theme.spacing.md)),
+                border: UiRect::all(Val::Px(ui_theme.borders.width_thin)),
+                ..default()
+            },
+            background_color: ui_theme.colors.surface_primary.into(),
+            border_color: ui_theme.colors.border_primary.into(),
+            border_radius: BorderRadius::all(Val::Px(ui_theme.borders.radius_small)),
+            visibility: Visibility::Hidden,
+            ..default()
+        })
+        .insert(ActiveDisastersPanel)
+        .insert(ResponsivePanel {
+            anchor: PanelAnchor::BottomLeft,
+            width: 260.0,
+            margin: 10.0,
+        })
+        .with_children(|parent| {
+            parent
+                .spawn(TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: ui_theme.typography.body_small,
+                        color: ui_theme.colors.text_primary,
+                        ..default()
+                    },
+                ))
+                .insert((ActiveDisastersText, DisplayedText::default()));
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        display: Display::None, // Shown in compact mode
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(ui_theme.spacing.sm),
+                        padding: UiRect::vertical(Val::Px(ui_theme.spacing.xs)),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .insert(ActiveDisastersIcons);
+            parent
+                .spawn(TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: ui_theme.typography.body_small,
+                        color: ui_theme.colors.text_secondary,
+                        ..default()
+                    },
+                ))
+                .insert((ForecastText, DisplayedText::default()));
+        });
+}
+
+/// Text listing active disasters and any combined effects between them
+pub fn active_disasters_text(
+    disaster_state: &DisasterState,
+    localization: &Localization,
+) -> String {
+    let mut lines = vec![localization.t("disasters.title").to_string()];
+
+    // Stable order regardless of hash map iteration
+    for disaster_type in DisasterType::ALL {
+        if let Some(remaining) = disaster_state.get_remaining_time(disaster_type) {
+            lines.push(format!(
+                "{} ({:.0}{})",
+                localization.t(disaster_type.label_key()),
+                remaining,
+                localization.t("disasters.time_left")
+            ));
+        }
+    }
+
+    for interaction in active_interactions(disaster_state) {
+        lines.push(format!(
+            "{}: {}",
+            localization.t("disasters.combined_effect"),
+            interaction.note
+        ));
+    }
+
+    lines.join("\n")
+}
+
+/// Forecast line for an announced disaster, e.g. "Rain incoming in ~2 days"
+///
+/// Days are rounded up, so a disaster due later today reads as one day away.
+pub fn forecast_text(
+    forecast: &Forecast,
+    simulation_time: &SimulationTime,
+    localization: &Localization,
+) -> Option<String> {
+    let upcoming = forecast.upcoming?;
+    let remaining = (upcoming.strikes_at - simulation_time.elapsed_seconds).max(0.0);
+    let days = (remaining / simulation_time.day_length_seconds)
+        .ceil()
+        .max(1.0) as u32;
+    let unit = if days == 1 {
+        localization.t("disasters.day")
+    } else {
+        localization.t("disasters.days")
+    };
+    Some(format!(
+        "{} {}{} {}",
+        localization.t(upcoming.disaster_type.label_key()),
+        localization.t("disasters.incoming_in"),
+        days,
+        unit
+    ))
+}
+
+/// Compact icon for one disaster: a colored dot ringed by a thin outline
+fn spawn_disaster_icon(parent: &mut ChildBuilder, disaster_type: DisasterType) {
+    parent.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Px(14.0),
+                height: Val::Px(14.0),
+                ..default()
+            },
+            background_color: disaster_type.icon_color().into(),
+            border_radius: BorderRadius::MAX,
+            ..default()
+        },
+        Outline::new(Val::Px(1.5), Val::Px(1.5), Color::WHITE),
+        DisasterIcon { disaster_type },
+    ));
+}
+
+/// Update the active disasters panel and hide it when nothing is active or forecast
+///
+/// In compact mode the labeled list gives way to one icon per active disaster, whose
+/// ring fades out as the disaster runs its course.
+#[allow(clippy::too_many_arguments)]
+pub fn update_active_disasters_panel(
+    mut commands: Commands,
+    disaster_state: Res<DisasterState>,
+    localization: Res<Localization>,
+    forecast: Option<Res<Forecast>>,
+    simulation_time: Option<Res<SimulationTime>>,
+    user_settings: Option<Res<UserSettings>>,
+    mut panel_query: Query<&mut Visibility, With<ActiveDisastersPanel>>,
+    mut text_query: Query<(&mut Text, &mut Style, &mut DisplayedText), With<ActiveDisastersText>>,
+    mut forecast_query: Query<
+        (&mut Text, &mut DisplayedText),
+        (With<ForecastText>, Without<ActiveDisastersText>),
+    >,
+    mut icon_row_query: Query<(Entity, &mut Style), (With<ActiveDisastersIcons>, Without<Text>)>,
+    mut icon_query: Query<(Entity, &DisasterIcon, &mut Outline)>,
+) {
+    let forecast_line = match (forecast.as_deref(), simulation_time.as_deref()) {
+        (Some(forecast), Some(simulation_time)) => {
+            forecast_text(forecast, simulation_time, &localization)
+        }
+        _ => None,
+    };
+    for (mut text, mut displayed) in forecast_query.iter_mut() {
+        displayed.set(&mut text, forecast_line.clone().unwrap_or_default());
+    }
+
+    let forecast_changed = forecast
+        .as_ref()
+        .is_some_and(|forecast| forecast.is_changed());
+    let settings_changed = user_settings
+        .as_ref()
+        .is_some_and(|settings| settings.is_changed());
+    if !disaster_state.is_changed()
+        && !localization.is_changed()
+        && !forecast_changed
+        && !settings_changed
+    {
+        return;
+    }
+    let compact = user_settings
+        .as_ref()
+        .is_some_and(|settings| settings.disaster_panel_mode == DisasterPanelMode::Compact);
+
+    let any_active = !disaster_state.active_disasters.is_empty() || forecast_line.is_some();
+    for mut visibility in panel_query.iter_mut() {
+        *visibility = if any_active {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+
+    for (mut text, mut style, mut displayed) in text_query.iter_mut() {
+        let display = if compact {
+            Display::None
+        } else {
+            Display::Flex
+        };
+        if style.display != display {
+            style.display = display;
+        }
+        let value = if compact {
+            String::new()
+        } else {
+            active_disasters_text(&disaster_state, &localization)
+        };
+        displayed.set(&mut text, value);
+    }
+
+    // Icons come and go with their disasters; the rest have their ring refreshed
+    let mut shown = Vec::new();
+    for (entity, icon, mut outline) in icon_query.iter_mut() {
+        let remaining = disaster_state.get_remaining_time(icon.disaster_type);
+        match remaining {
+            Some(remaining) if compact => {
+                let fraction = (remaining / icon.disaster_type.default_duration()).clamp(0.0, 1.0);
+                outline.color = Color::WHITE.with_alpha(0.2 + 0.8 * fraction);
+                shown.push(icon.disaster_type);
+            }
+            _ => commands.entity(entity).despawn_recursive(),
+        }
+    }
+    for (row, mut style) in icon_row_query.iter_mut() {
+        style.display = if compact {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if !compact {
+            continue;
+        }
+        commands.entity(row).with_children(|parent| {
+            for disaster_type in DisasterType::ALL {
+                if disaster_state.is_active(disaster_type) && !shown.contains(&disaster_type) {
+                    spawn_disaster_icon(parent, disaster_type);
+                }
+            }
+        });
+    }
+}
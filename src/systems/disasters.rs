@@ -0,0 +1,231 @@
+use crate::components::{
+    AutoDisasterConfig, DisasterState, DisasterType, FoodSource, Forecast, ForecastedDisaster,
+    SimulationTime, SoilCell,
+};
+use bevy::prelude::*;
+use rand::prelude::*;
+
+/// Lowest soil temperature a disaster can drive a cell to
+pub const MIN_SOIL_TEMPERATURE: f32 = -10.0;
+/// Highest soil temperature a disaster can drive a cell to
+pub const MAX_SOIL_TEMPERATURE: f32 = 45.0;
+
+/// Per-second environmental change caused by one or more disasters
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DisasterEffect {
+    pub moisture: f32,    // Change in soil moisture per second
+    pub temperature: f32, // Change in soil temperature per second
+    pub food_decay: f32,  // Nutrition lost by each food source per second
+}
+
+/// Special rule applied when two disasters are active at the same time
+#[derive(Clone, Copy, Debug)]
+pub struct DisasterInteraction {
+    pub disasters: (DisasterType, DisasterType),
+    pub moisture_multiplier: f32,
+    pub temperature_multiplier: f32,
+    pub food_decay_multiplier: f32,
+    pub note: &'static str,
+}
+
+/// Combination rules consulted when two or more disasters are active
+pub const DISASTER_INTERACTIONS: [DisasterInteraction; 3] = [
+    DisasterInteraction {
+        disasters: (DisasterType::Rain, DisasterType::ColdSnap),
+        moisture_multiplier: 1.0,
+        temperature_multiplier: 2.0,
+        food_decay_multiplier: 1.0,
+        note: "Freezing rain: soil ices over faster",
+    },
+    DisasterInteraction {
+        disasters: (DisasterType::Rain, DisasterType::Drought),
+        moisture_multiplier: 0.5,
+        temperature_multiplier: 1.0,
+        food_decay_multiplier: 1.0,
+        note: "Rain and drought partly cancel out",
+    },
+    DisasterInteraction {
+        disasters: (DisasterType::Heatwave, DisasterType::Drought),
+        moisture_multiplier: 1.0,
+        temperature_multiplier: 1.0,
+        food_decay_multiplier: 2.5,
+        note: "Scorching drought: food dies off rapidly",
+    },
+];
+
+/// Effect of a single disaster on its own
+pub fn base_disaster_effect(disaster_type: DisasterType) -> DisasterEffect {
+    match disaster_type {
+        DisasterType::Rain => DisasterEffect {
+            moisture: 0.1,
+            temperature: -0.1,
+            food_decay: 0.0,
+        },
+        DisasterType::Drought => DisasterEffect {
+            moisture: -0.08,
+            temperature: 0.05,
+            food_decay: 0.2,
+        },
+        DisasterType::ColdSnap => DisasterEffect {
+            moisture: 0.0,
+            temperature: -0.5,
+            food_decay: 0.0,
+        },
+        DisasterType::Heatwave => DisasterEffect {
+            moisture: -0.03,
+            temperature: 0.5,
+            food_decay: 0.2,
+        },
+        DisasterType::InvasiveSpecies => DisasterEffect {
+            moisture: 0.0,
+            temperature: 0.0,
+            food_decay: 0.3,
+        },
+    }
+}
+
+/// Combination rules whose disasters are all currently active
+pub fn active_interactions(disaster_state: &DisasterState) -> Vec<&'static DisasterInteraction> {
+    DISASTER_INTERACTIONS
+        .iter()
+        .filter(|interaction| {
+            disaster_state.is_active(interaction.disasters.0)
+                && disaster_state.is_active(interaction.disasters.1)
+        })
+        .collect()
+}
+
+/// Net per-second effect of all active disasters, including combination rules
+///
+/// Each rule scales only the effects of its own two disasters; other active disasters
+/// add their effects unchanged.
+pub fn combined_disaster_effect(disaster_state: &DisasterState) -> DisasterEffect {
+    let mut effect = DisasterEffect::default();
+    for disaster_type in disaster_state.active_disasters.keys() {
+        let base = base_disaster_effect(*disaster_type);
+        effect.moisture += base.moisture;
+        effect.temperature += base.temperature;
+        effect.food_decay += base.food_decay;
+    }
+
+    for interaction in active_interactions(disaster_state) {
+        let first = base_disaster_effect(interaction.disasters.0);
+        let second = base_disaster_effect(interaction.disasters.1);
+        effect.moisture +=
+            (first.moisture + second.moisture) * (interaction.moisture_multiplier - 1.0);
+        effect.temperature +=
+            (first.temperature + second.temperature) * (interaction.temperature_multiplier - 1.0);
+        effect.food_decay +=
+            (first.food_decay + second.food_decay) * (interaction.food_decay_multiplier - 1.0);
+    }
+
+    effect
+}
+
+/// A disaster started by the automatic scheduler
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub struct AutoDisasterEvent {
+    pub disaster_type: DisasterType,
+    pub strikes_at: f32, // Scheduled simulated time, as forecast
+    pub fired_at: f32,   // Simulated time it actually started
+}
+
+/// System starting disasters on its own while auto-disasters are enabled
+///
+/// The next strike time is rolled as soon as the previous disaster fires, and its type
+/// is rolled `forecast_lead` seconds ahead into the [`Forecast`]. The disaster that
+/// strikes is always the forecast one, even if it is still active or on cooldown.
+pub fn auto_disaster_system(
+    config: Option<Res<AutoDisasterConfig>>,
+    simulation_time: Res<SimulationTime>,
+    mut forecast: ResMut<Forecast>,
+    mut disaster_state: ResMut<DisasterState>,
+    mut auto_disasters: EventWriter<AutoDisasterEvent>,
+) {
+    let config = config.map(|c| c.clone()).unwrap_or_default();
+    if !config.enabled {
+        if forecast.scheduled_at.is_some() || forecast.upcoming.is_some() {
+            *forecast = Forecast::default();
+        }
+        return;
+    }
+
+    let now = simulation_time.elapsed_seconds;
+    let mut rng = thread_rng();
+    let strikes_at = match forecast.scheduled_at {
+        Some(strikes_at) => strikes_at,
+        None => {
+            let interval =
+                rng.gen_range(config.min_interval..=config.max_interval.max(config.min_interval));
+            forecast.scheduled_at = Some(now + interval);
+            now + interval
+        }
+    };
+
+    if forecast.upcoming.is_none() && now >= strikes_at - config.forecast_lead {
+        let disaster_type = *DisasterType::ALL.choose(&mut rng).unwrap();
+        forecast.upcoming = Some(ForecastedDisaster {
+            disaster_type,
+            strikes_at,
+        });
+        info!(
+            "Forecast: {} in {:.0}s",
+            disaster_type.display_name(),
+            strikes_at - now
+        );
+    }
+
+    let Some(upcoming) = forecast.upcoming else {
+        return;
+    };
+    if now < upcoming.strikes_at {
+        return;
+    }
+    let disaster_type = upcoming.disaster_type;
+    disaster_state.cooldown_timers.remove(&disaster_type);
+    disaster_state.start_disaster(disaster_type, disaster_type.default_duration());
+    auto_disasters.send(AutoDisasterEvent {
+        disaster_type,
+        strikes_at: upcoming.strikes_at,
+        fired_at: now,
+    });
+    *forecast = Forecast::default();
+    info!("{} struck as forecast", disaster_type.display_name());
+}
+
+/// System counting down active disasters and their cooldowns
+pub fn disaster_update_system(time: Res<Time>, mut disaster_state: ResMut<DisasterState>) {
+    for disaster_type in disaster_state.update(time.delta_seconds()) {
+        info!("{} has ended", disaster_type.display_name());
+    }
+}
+
+/// System applying active disasters to soil and food sources
+///
+/// The combined effect is scaled by the disasters' `effect_intensity`, however
+/// dramatic their visuals are set to be.
+pub fn disaster_effect_system(
+    time: Res<Time>,
+    disaster_state: Res<DisasterState>,
+    mut soil_query: Query<&mut SoilCell>,
+    mut food_query: Query<&mut FoodSource>,
+) {
+    if disaster_state.active_disasters.is_empty() {
+        return;
+    }
+
+    let delta_time = time.delta_seconds() * disaster_state.effect_intensity.max(0.0);
+    let effect = combined_disaster_effect(&disaster_state);
+
+    for mut soil in soil_query.iter_mut() {
+        soil.moisture = (soil.moisture + effect.moisture * delta_time).clamp(0.0, 1.0);
+        soil.temperature = (soil.temperature + effect.temperature * delta_time)
+            .clamp(MIN_SOIL_TEMPERATURE, MAX_SOIL_TEMPERATURE);
+    }
+
+    if effect.food_decay > 0.0 {
+        for mut food in food_query.iter_mut() {
+            food.nutrition_value = (food.nutrition_value - effect.food_decay * delta_time).max(0.0);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::components::{
+    Ant, Chamber, DiseaseConfig, Dying, Health, Infected, Position, SpatialGrid,
+};
+use bevy::prelude::*;
+use rand::prelude::*;
+use std::collections::HashMap;
+
+/// System spreading adult disease through crowded spots
+///
+/// A spatial grid cell holding at least `crowding_threshold` ants for `crowding_time`
+/// seconds can start an outbreak, and infected ants pass the disease to their cellmates
+/// wherever they are. Infected ants slowly lose HP; grooming inside a chamber clears the
+/// disease. Crowding is read from the shared [`SpatialGrid`] built last frame.
+pub fn adult_disease_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<DiseaseConfig>,
+    spatial_grid: Res<SpatialGrid>,
+    mut crowded_since: Local<HashMap<(i32, i32), f32>>,
+    chamber_query: Query<(&Position, &Chamber), Without<Ant>>,
+    mut ant_query: Query<
+        (Entity, &Position, Option<&mut Health>, Has<Infected>),
+        (With<Ant>, Without<Dying>),
+    >,
+) {
+    let delta_time = time.delta_seconds();
+    let mut rng = thread_rng();
+
+    // Track how long each cell has stayed crowded
+    crowded_since.retain(|cell, _| {
+        spatial_grid
+            .grid
+            .get(cell)
+            .is_some_and(|ants| ants.len() >= config.crowding_threshold)
+    });
+
+    for (cell, ants) in spatial_grid.grid.iter() {
+        let long_crowded = ants.len() >= config.crowding_threshold && {
+            let crowded_for = crowded_since.entry(*cell).or_insert(0.0);
+            *crowded_for += delta_time;
+            *crowded_for >= config.crowding_time
+        };
+
+        let infected = ants
+            .iter()
+            .filter(|ant| ant_query.get(**ant).is_ok_and(|(.., infected)| infected))
+            .count();
+        let mut rate = config.spread_rate * infected as f32;
+        if long_crowded {
+            rate += config.outbreak_rate;
+        }
+        if rate <= 0.0 {
+            continue;
+        }
+
+        let catch_chance = (rate * delta_time).min(1.0);
+        for &ant in ants {
+            let Ok((.., infected)) = ant_query.get(ant) else {
+                continue;
+            };
+            if !infected && rng.gen::<f32>() < catch_chance {
+                commands.entity(ant).insert(Infected);
+            }
+        }
+    }
+
+    // Sickness wears infected ants down until grooming in a chamber clears it
+    let clear_chance = (config.grooming_clear_rate * delta_time).min(1.0);
+    for (entity, position, health, infected) in ant_query.iter_mut() {
+        if !infected {
+            continue;
+        }
+        if let Some(mut health) = health {
+            health.damage(config.hp_loss_rate * delta_time);
+        }
+
+        let in_chamber = chamber_query.iter().any(|(chamber_position, chamber)| {
+            let dx = chamber_position.x - position.x;
+            let dy = chamber_position.y - position.y;
+            dx * dx + dy * dy <= chamber.radius * chamber.radius
+        });
+        if in_chamber && rng.gen::<f32>() < clear_chance {
+            commands.entity(entity).remove::<Infected>();
+        }
+    }
+}
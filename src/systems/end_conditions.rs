@@ -0,0 +1,118 @@
+use crate::components::{
+    Ant, ColonyDevelopmentPhase, Dying, EndConditions, EndReason, EndSummaryPanel, SimulationEnded,
+    SimulationTime, TimeControl,
+};
+use bevy::prelude::*;
+
+/// First of the configured end conditions the run currently meets
+pub fn check_end_conditions(
+    conditions: &EndConditions,
+    simulation_time: &SimulationTime,
+    population: usize,
+    phase: &ColonyDevelopmentPhase,
+) -> Option<EndReason> {
+    if let Some(days) = conditions.after_days {
+        if simulation_time.days_elapsed() >= days {
+            return Some(EndReason::DaysElapsed(days));
+        }
+    }
+    if conditions.on_extinction && population == 0 {
+        return Some(EndReason::ColonyExtinct);
+    }
+    if conditions.on_phase == Some(phase.current_phase) {
+        return Some(EndReason::PhaseReached(phase.current_phase));
+    }
+    None
+}
+
+/// System ending the run, and pausing it, once one of the [`EndConditions`] is met
+///
+/// The run is ended only once; resuming afterwards carries on without checking again
+/// until a new game clears [`SimulationEnded`].
+pub fn end_condition_system(
+    conditions: Res<EndConditions>,
+    simulation_time: Res<SimulationTime>,
+    phase: Res<ColonyDevelopmentPhase>,
+    ant_query: Query<(), (With<Ant>, Without<Dying>)>,
+    mut ended: ResMut<SimulationEnded>,
+    mut time_control: ResMut<TimeControl>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    if ended.is_ended() {
+        return;
+    }
+    let population = ant_query.iter().count();
+    let Some(reason) = check_end_conditions(&conditions, &simulation_time, population, &phase)
+    else {
+        return;
+    };
+
+    *ended = SimulationEnded {
+        reason: Some(reason),
+        day: simulation_time.current_day,
+        population,
+    };
+    // Pause the clock now rather than next frame, so no further time passes
+    time_control.is_paused = true;
+    virtual_time.pause();
+    info!(
+        "Simulation ended on day {}: {}",
+        simulation_time.current_day,
+        reason.description()
+    );
+}
+
+/// System showing the end-of-run summary while the run is over
+pub fn end_summary_panel_system(
+    mut commands: Commands,
+    ended: Res<SimulationEnded>,
+    panel_query: Query<Entity, With<EndSummaryPanel>>,
+) {
+    if !ended.is_changed() {
+        return;
+    }
+    for entity in panel_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    let Some(reason) = ended.reason else {
+        return;
+    };
+
+    let summary = format!(
+        "Simulation ended\n{}\nDay {} - {} ants alive\nPress Space to keep watching or N for a new game",
+        reason.description(),
+        ended.day,
+        ended.population
+    );
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(40.0),
+                left: Val::Percent(50.0),
+                margin: UiRect::left(Val::Px(-200.0)),
+                width: Val::Px(400.0),
+                justify_content: JustifyContent::Center,
+                padding: UiRect::all(Val::Px(14.0)),
+                ..default()
+            },
+            background_color: Color::srgba(0.0, 0.0, 0.0, 0.85).into(),
+            border_radius: BorderRadius::all(Val::Px(5.0)),
+            z_index: ZIndex::Global(100),
+            ..default()
+        })
+        .insert(EndSummaryPanel)
+        .with_children(|parent| {
+            parent.spawn(
+                TextBundle::from_section(
+                    summary,
+                    TextStyle {
+                        font_size: 16.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                )
+                .with_text_justify(JustifyText::Center),
+            );
+        });
+}
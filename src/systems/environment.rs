@@ -1,22 +1,163 @@
-use crate::components::SoilCell;
+use crate::components::{
+    Chamber, ChamberType, Corpse, DisasterState, DisasterType, EnvironmentNoise, NutritionModel,
+    Position, SoilCell, SoilDiffusionConfig,
+};
+use crate::systems::disasters::{MAX_SOIL_TEMPERATURE, MIN_SOIL_TEMPERATURE};
 use bevy::prelude::*;
 use rand::prelude::*;
+use std::collections::HashMap;
+
+/// Grid offsets of the four orthogonal neighbors of a soil cell
+const NEIGHBOR_OFFSETS: [(i32, i32); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+
+/// Advance an Ornstein-Uhlenbeck process by `dt` seconds, given a standard normal `shock`
+///
+/// Uses the exact solution over the step rather than an Euler step, so splitting the same
+/// duration into many short ticks or a few long ones gives the same distribution.
+pub fn ornstein_uhlenbeck_step(
+    value: f32,
+    mean: f32,
+    volatility: f32,
+    reversion: f32,
+    dt: f32,
+    shock: f32,
+) -> f32 {
+    if reversion <= f32::EPSILON {
+        return value + volatility * dt.sqrt() * shock; // Plain random walk
+    }
+    let decay = (-reversion * dt).exp();
+    let spread = volatility * ((1.0 - decay * decay) / (2.0 * reversion)).sqrt();
+    mean + (value - mean) * decay + spread * shock
+}
+
+/// Standard normal sample by the Box-Muller transform
+fn standard_normal(rng: &mut impl Rng) -> f32 {
+    let u1: f32 = rng.gen_range(f32::EPSILON..1.0);
+    let u2: f32 = rng.gen();
+    (-2.0 * u1.ln()).sqrt() * (std::f32::consts::TAU * u2).cos()
+}
 
 /// System for environmental simulation
-pub fn environmental_update_system(time: Res<Time>, mut soil_query: Query<&mut SoilCell>) {
+///
+/// Soil moisture and temperature drift as set by the [`EnvironmentNoise`], with the same
+/// statistics at any frame rate. Soil nutrition regenerates as set by the
+/// [`NutritionModel`]: fastest in moderately moist soil, boosted near corpses and waste
+/// dumps, suppressed during a drought.
+pub fn environmental_update_system(
+    time: Res<Time>,
+    noise: Option<Res<EnvironmentNoise>>,
+    nutrition_model: Option<Res<NutritionModel>>,
+    disaster_state: Option<Res<DisasterState>>,
+    corpse_query: Query<&Position, With<Corpse>>,
+    chamber_query: Query<(&Position, &Chamber)>,
+    mut soil_query: Query<(&Position, &mut SoilCell)>,
+) {
     let mut rng = thread_rng();
     let delta_time = time.delta_seconds();
+    let noise = noise.map(|noise| noise.clone()).unwrap_or_default();
+    let nutrition_model = nutrition_model
+        .map(|model| model.clone())
+        .unwrap_or_default();
+    let drought = disaster_state.is_some_and(|state| state.is_active(DisasterType::Drought));
+
+    // Decaying matter fertilizes the soil around it; a dump's own extent counts as contact
+    let waste: Vec<(Vec2, f32)> = corpse_query
+        .iter()
+        .map(|position| (Vec2::new(position.x, position.y), 0.0))
+        .chain(
+            chamber_query
+                .iter()
+                .filter(|(_, chamber)| chamber.chamber_type == ChamberType::Waste)
+                .map(|(position, chamber)| (Vec2::new(position.x, position.y), chamber.radius)),
+        )
+        .collect();
 
-    for mut soil in soil_query.iter_mut() {
-        // Simple environmental changes over time
-        soil.moisture += rng.gen_range(-0.05..0.05) * delta_time;
-        soil.moisture = soil.moisture.clamp(0.0, 1.0);
+    for (position, mut soil) in soil_query.iter_mut() {
+        soil.moisture = ornstein_uhlenbeck_step(
+            soil.moisture,
+            noise.moisture_mean,
+            noise.moisture_volatility,
+            noise.reversion,
+            delta_time,
+            standard_normal(&mut rng),
+        )
+        .clamp(0.0, 1.0);
 
-        soil.temperature += rng.gen_range(-0.2..0.2) * delta_time;
-        soil.temperature = soil.temperature.clamp(10.0, 35.0);
+        soil.temperature = ornstein_uhlenbeck_step(
+            soil.temperature,
+            noise.temperature_mean,
+            noise.temperature_volatility,
+            noise.reversion,
+            delta_time,
+            standard_normal(&mut rng),
+        )
+        .clamp(MIN_SOIL_TEMPERATURE, MAX_SOIL_TEMPERATURE);
 
-        // Nutrition slowly regenerates
-        soil.nutrition += 0.01 * delta_time;
+        let cell = Vec2::new(position.x, position.y);
+        let waste_distance = waste
+            .iter()
+            .map(|(center, radius)| (cell.distance(*center) - radius).max(0.0))
+            .min_by(f32::total_cmp);
+        soil.nutrition +=
+            nutrition_model.regeneration_rate(soil.moisture, waste_distance, drought) * delta_time;
         soil.nutrition = soil.nutrition.clamp(0.0, 1.0);
     }
 }
+
+/// Per-tick exchange factor, capped so a cell never gives away more than it has
+/// relative to its neighbors (keeps explicit diffusion stable and non-oscillating)
+fn exchange_factor(rate: f32, delta_time: f32) -> f32 {
+    (rate * delta_time).clamp(0.0, 1.0 / (NEIGHBOR_OFFSETS.len() as f32 + 1.0))
+}
+
+/// System smoothing moisture and temperature between adjacent soil cells
+pub fn soil_diffusion_system(
+    time: Res<Time>,
+    config: Res<SoilDiffusionConfig>,
+    mut soil_query: Query<(&Position, &mut SoilCell)>,
+) {
+    let delta_time = time.delta_seconds();
+    let moisture_factor = exchange_factor(config.moisture_rate, delta_time);
+    let temperature_factor = exchange_factor(config.temperature_rate, delta_time);
+    if moisture_factor == 0.0 && temperature_factor == 0.0 {
+        return;
+    }
+
+    // Snapshot current values by grid coordinate so every cell diffuses from the same state
+    let snapshot: HashMap<(i32, i32), (f32, f32)> = soil_query
+        .iter()
+        .map(|(position, soil)| {
+            (
+                grid_coords(position, config.cell_spacing),
+                (soil.moisture, soil.temperature),
+            )
+        })
+        .collect();
+
+    for (position, mut soil) in soil_query.iter_mut() {
+        let (gx, gy) = grid_coords(position, config.cell_spacing);
+        let (moisture, temperature) = snapshot[&(gx, gy)];
+
+        let mut moisture_flow = 0.0;
+        let mut temperature_flow = 0.0;
+        for (dx, dy) in NEIGHBOR_OFFSETS {
+            if let Some(&(neighbor_moisture, neighbor_temperature)) =
+                snapshot.get(&(gx + dx, gy + dy))
+            {
+                moisture_flow += neighbor_moisture - moisture;
+                temperature_flow += neighbor_temperature - temperature;
+            }
+        }
+
+        // Pairwise exchanges are symmetric, so totals are conserved
+        soil.moisture = moisture + moisture_factor * moisture_flow;
+        soil.temperature = temperature + temperature_factor * temperature_flow;
+    }
+}
+
+fn grid_coords(position: &Position, cell_spacing: f32) -> (i32, i32) {
+    (
+        (position.x / cell_spacing).round() as i32,
+        (position.y / cell_spacing).round() as i32,
+    )
+}
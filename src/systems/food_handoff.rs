@@ -0,0 +1,114 @@
+use crate::components::{
+    Ant, AntBehavior, AntState, Chamber, ChamberType, ColonyFoodStore, ColonyId, DaughterColonies,
+    FoodHandoffConfig, Position, SpatialGrid, SpecializedRole, TunnelNode, TunnelNodeType,
+};
+use crate::systems::chambers::nearest_chamber_of_type;
+use crate::systems::foraging::store_food;
+use bevy::prelude::*;
+
+/// System relaying food from returning foragers to storage workers at chamber entrances
+///
+/// A loaded forager and an empty-handed storage worker both within `radius` of a
+/// `ChamberEntrance` node swap the load, and the forager heads straight back out to
+/// forage. Storage workers put what they carry into the colony store once inside a food
+/// storage chamber, or at once while the colony has none. Neighbours come from the shared
+/// [`SpatialGrid`] built last frame.
+pub fn food_handoff_system(
+    config: Option<Res<FoodHandoffConfig>>,
+    spatial_grid: Res<SpatialGrid>,
+    mut food_store: ResMut<ColonyFoodStore>,
+    mut daughter_colonies: Option<ResMut<DaughterColonies>>,
+    node_query: Query<(&Position, &TunnelNode), Without<Ant>>,
+    chamber_query: Query<(&Position, &Chamber), Without<Ant>>,
+    mut ant_query: Query<
+        (
+            &Position,
+            &mut AntBehavior,
+            Option<&SpecializedRole>,
+            Option<&ColonyId>,
+        ),
+        With<Ant>,
+    >,
+) {
+    let config = config.map(|c| c.clone()).unwrap_or_default();
+
+    // Storage workers drop off what they were handed
+    for (position, mut behavior, role, colony) in ant_query.iter_mut() {
+        if role != Some(&SpecializedRole::StorageWorker) || behavior.carried_food <= 0.0 {
+            continue;
+        }
+        let in_storage =
+            nearest_chamber_of_type(ChamberType::FoodStorage, position, chamber_query.iter())
+                .is_none_or(|(chamber_position, chamber)| {
+                    distance(chamber_position, position) <= chamber.radius
+                });
+        if in_storage {
+            store_food(
+                &mut food_store,
+                daughter_colonies.as_deref_mut(),
+                colony,
+                &behavior,
+            );
+            behavior.carried_food = 0.0;
+        }
+    }
+
+    // Pair loaded foragers with free storage workers at each entrance
+    let mut handoffs = Vec::new();
+    let mut busy = Vec::new();
+    for (entrance, node) in node_query.iter() {
+        if node.node_type != TunnelNodeType::ChamberEntrance {
+            continue;
+        }
+        let mut foragers = Vec::new();
+        let mut workers = Vec::new();
+        for ant in spatial_grid.get_nearby_entities(entrance, config.radius) {
+            let Ok((position, behavior, role, colony)) = ant_query.get(ant) else {
+                continue;
+            };
+            if busy.contains(&ant) || distance(position, entrance) > config.radius {
+                continue;
+            }
+            if role == Some(&SpecializedRole::StorageWorker) {
+                if behavior.carried_food == 0.0 {
+                    workers.push((ant, colony.copied()));
+                }
+            } else if behavior.state == AntState::Returning && behavior.carried_food > 0.0 {
+                foragers.push((ant, colony.copied()));
+            }
+        }
+        // Food only changes hands within a colony
+        for (forager, colony) in foragers {
+            let Some(index) = workers.iter().position(|(_, other)| *other == colony) else {
+                continue;
+            };
+            let (worker, _) = workers.remove(index);
+            handoffs.push((forager, worker));
+            busy.push(forager);
+            busy.push(worker);
+        }
+    }
+
+    for (forager, worker) in handoffs {
+        let Ok((_, mut behavior, _, _)) = ant_query.get_mut(forager) else {
+            continue;
+        };
+        let load = (behavior.carried_kind, behavior.carried_food);
+        behavior.carried_food = 0.0;
+        behavior.state = AntState::Foraging;
+        behavior.target_position = None;
+        if let Ok((_, mut behavior, _, _)) = ant_query.get_mut(worker) {
+            (behavior.carried_kind, behavior.carried_food) = load;
+        }
+        debug!(
+            "Ant {:?} handed {:.1} food to {:?} at a chamber entrance",
+            forager, load.1, worker
+        );
+    }
+}
+
+fn distance(a: &Position, b: &Position) -> f32 {
+    let dx = a.x - b.x;
+    let dy = a.y - b.y;
+    (dx * dx + dy * dy).sqrt()
+}
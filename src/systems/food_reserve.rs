@@ -0,0 +1,111 @@
+use crate::components::{
+    ColonyFoodStore, FoodReserveBanner, FoodReserveConfig, FoodReserveDismissButton,
+    FoodReserveWarning,
+};
+use bevy::prelude::*;
+
+/// System showing a warning banner while the colony's food store is critically low
+///
+/// The banner is spawned when stored food falls below the configured threshold and
+/// despawned once it recovers. A dismissed banner stays away until the next shortage.
+pub fn food_reserve_monitor_system(
+    mut commands: Commands,
+    food_store: Res<ColonyFoodStore>,
+    config: Res<FoodReserveConfig>,
+    mut warning: ResMut<FoodReserveWarning>,
+    banner_query: Query<Entity, With<FoodReserveBanner>>,
+) {
+    if food_store.food >= config.critical_threshold {
+        warning.dismissed = false;
+        for entity in banner_query.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+
+    if warning.dismissed || !banner_query.is_empty() {
+        return;
+    }
+    warn!(
+        "Colony food reserves critical: {:.1} stored (threshold {:.1})",
+        food_store.food, config.critical_threshold
+    );
+    spawn_food_reserve_banner(&mut commands);
+}
+
+/// System closing the warning banner when its dismiss button is pressed
+pub fn food_reserve_dismiss_system(
+    mut commands: Commands,
+    mut warning: ResMut<FoodReserveWarning>,
+    button_query: Query<&Interaction, (Changed<Interaction>, With<FoodReserveDismissButton>)>,
+    banner_query: Query<Entity, With<FoodReserveBanner>>,
+) {
+    if !button_query
+        .iter()
+        .any(|interaction| *interaction == Interaction::Pressed)
+    {
+        return;
+    }
+    warning.dismissed = true;
+    for entity in banner_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Red banner across the top of the screen with a close button
+fn spawn_food_reserve_banner(commands: &mut Commands) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(10.0),
+                left: Val::Percent(50.0),
+                margin: UiRect::left(Val::Px(-180.0)),
+                width: Val::Px(360.0),
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            background_color: Color::srgba(0.7, 0.1, 0.1, 0.9).into(),
+            border_radius: BorderRadius::all(Val::Px(5.0)),
+            z_index: ZIndex::Global(100),
+            ..default()
+        })
+        .insert(FoodReserveBanner)
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Food reserves critical - the colony is starving",
+                TextStyle {
+                    font_size: 16.0,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            ));
+            parent
+                .spawn(ButtonBundle {
+                    style: Style {
+                        width: Val::Px(24.0),
+                        height: Val::Px(24.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    background_color: Color::srgba(0.0, 0.0, 0.0, 0.3).into(),
+                    border_radius: BorderRadius::all(Val::Px(12.0)),
+                    ..default()
+                })
+                .insert(FoodReserveDismissButton)
+                .with_children(|button| {
+                    button.spawn(TextBundle::from_section(
+                        "x",
+                        TextStyle {
+                            font_size: 16.0,
+                            color: Color::WHITE,
+                            ..default()
+                        },
+                    ));
+                });
+        });
+}
@@ -0,0 +1,77 @@
+use crate::components::{
+    DepthLayer, FoodKind, FoodSource, FoodSpawningConfig, Position, Soil, SoilCell,
+};
+use crate::systems::foraging::spawn_food_source;
+use bevy::prelude::*;
+use rand::prelude::*;
+
+/// How readily a soil cell grows food, from 0.0 (barren) to 1.0
+///
+/// Cells below the nutrition or moisture threshold grow nothing; above both, richer and
+/// wetter soil is more fertile.
+pub fn soil_fertility(config: &FoodSpawningConfig, soil: &SoilCell) -> f32 {
+    if soil.nutrition < config.min_nutrition || soil.moisture < config.min_moisture {
+        return 0.0;
+    }
+    (soil.nutrition * soil.moisture.min(1.0)).clamp(0.0, 1.0)
+}
+
+/// System letting food sprout above fertile topsoil and wither above depleted soil
+///
+/// Every `check_interval` seconds a random sample of topsoil cells is surveyed. Fertile
+/// samples may sprout a food source on the surface above them; food sources standing over
+/// a sampled cell whose nutrition has run out lose food until the cleanup removes them.
+/// Rain and drought change soil moisture, so they shift where food grows.
+pub fn food_spawning_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut config: ResMut<FoodSpawningConfig>,
+    soil_query: Query<(&Position, &SoilCell), With<Soil>>,
+    mut food_query: Query<(&Position, &mut FoodSource)>,
+) {
+    let config = config.bypass_change_detection();
+    config.check_timer += time.delta_seconds();
+    if config.check_timer < config.check_interval {
+        return;
+    }
+    let elapsed = config.check_timer;
+    config.check_timer = 0.0;
+
+    let mut rng = thread_rng();
+    let samples = soil_query
+        .iter()
+        .filter(|(position, _)| DepthLayer::from_y(position.y) == DepthLayer::Topsoil)
+        .choose_multiple(&mut rng, config.samples);
+
+    // Wither food above depleted soil
+    for (food_position, mut food) in food_query.iter_mut() {
+        let depleted = samples.iter().any(|(position, soil)| {
+            (position.x - food_position.x).abs() <= config.column_radius
+                && soil.nutrition < config.depleted_nutrition
+        });
+        if depleted {
+            food.nutrition_value -= config.wither_rate * elapsed;
+        }
+    }
+
+    // Sprout food above fertile soil
+    let mut food_count = food_query.iter().count();
+    for (position, soil) in samples {
+        if food_count >= config.max_food_sources {
+            break;
+        }
+        if rng.gen::<f32>() < config.spawn_chance * soil_fertility(config, soil) {
+            let surface = Position {
+                x: position.x,
+                y: 2.0, // Just above the soil surface
+            };
+            spawn_food_source(
+                &mut commands,
+                surface,
+                rng.gen_range(20.0..50.0),
+                FoodKind::Carbohydrate,
+            );
+            food_count += 1;
+        }
+    }
+}
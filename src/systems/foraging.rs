@@ -1,131 +1,822 @@
 use crate::components::{
-    Ant, AntBehavior, AntState, Food, FoodSource, InvasiveSpecies, Inventory, Lifecycle, Position,
-    SpatialGrid,
+    ActivitySchedule, Ant, AntAiConfig, AntBehavior, AntState, AntennationCooldown,
+    BehaviorModifiers, Breadcrumbs, Caste, Chamber, ColonyFoodStore, ColonyId, DaughterColonies,
+    Dying, FoodKind, FoodMemory, FoodSource, Lifecycle, NightShelterConfig, PheromoneGrid,
+    Position, Recruited, RecruitmentWaves, Scout, Sheltering, SimulationRng, SimulationTime,
+    SpatialGrid, SpecializedRole, Stamina, WanderMode, WorldConfig,
 };
+use crate::strategy::{AntContext, BoxedStrategy};
+use crate::systems::chambers::ant_destination;
+use crate::systems::rendering::{spawner_rng, z_for, RenderLayer};
+use crate::systems::spatial_grid::reset_entity_grid;
 use bevy::prelude::*;
+use rand::prelude::*;
+use std::collections::HashSet;
 
-/// System for handling food consumption and energy recovery
-pub fn food_consumption_system(
-    spatial_grid: Res<SpatialGrid>,
+/// Distance at which an ant counts as having reached its target or home
+const ARRIVAL_RADIUS: f32 = 1.5;
+
+/// Spawn food sources scattered along the surface
+pub fn spawn_food_sources(mut commands: Commands, simulation_rng: Option<ResMut<SimulationRng>>) {
+    let mut rng = spawner_rng(simulation_rng);
+
+    for i in 0..6 {
+        let position = Position {
+            x: rng.gen_range(-90.0..90.0),
+            y: 2.0, // Just above the soil surface
+        };
+        // Honeydew is plentiful; one source in three is insect prey
+        let kind = if i % 3 == 0 {
+            FoodKind::Protein
+        } else {
+            FoodKind::Carbohydrate
+        };
+        spawn_food_source(&mut commands, position, rng.gen_range(30.0..80.0), kind);
+    }
+
+    info!("Spawned 6 surface food sources");
+}
+
+/// Spawn a single food source with its sprite
+pub fn spawn_food_source(
+    commands: &mut Commands,
+    position: Position,
+    nutrition: f32,
+    kind: FoodKind,
+) -> Entity {
+    let translation = Vec3::new(position.x, position.y, z_for(RenderLayer::Food));
+    let color = match kind {
+        FoodKind::Protein => Color::srgb(0.7, 0.3, 0.2), // Reddish prey
+        FoodKind::Carbohydrate => Color::srgb(0.3, 0.7, 0.2), // Green honeydew
+    };
+
+    commands
+        .spawn((
+            position,
+            FoodSource {
+                nutrition_value: nutrition,
+                max_nutrition: nutrition,
+                kind,
+            },
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::new(3.0, 3.0)),
+                    ..default()
+                },
+                transform: Transform::from_translation(translation),
+                ..default()
+            },
+        ))
+        .id()
+}
+
+/// System keeping the share of scouts among foragers at the configured ratio
+pub fn scout_allocation_system(
+    mut commands: Commands,
+    ai_config: Res<AntAiConfig>,
+    forager_query: Query<(Entity, Has<Scout>), With<AntBehavior>>,
+) {
+    let forager_count = forager_query.iter().count();
+    let desired_scouts = (forager_count as f32 * ai_config.scout_fraction).round() as usize;
+    let current_scouts = forager_query
+        .iter()
+        .filter(|(_, is_scout)| *is_scout)
+        .count();
+
+    if current_scouts < desired_scouts {
+        for (entity, _) in forager_query
+            .iter()
+            .filter(|(_, is_scout)| !is_scout)
+            .take(desired_scouts - current_scouts)
+        {
+            commands.entity(entity).insert(Scout);
+        }
+    } else if current_scouts > desired_scouts {
+        for (entity, _) in forager_query
+            .iter()
+            .filter(|(_, is_scout)| *is_scout)
+            .take(current_scouts - desired_scouts)
+        {
+            commands.entity(entity).remove::<Scout>();
+        }
+    }
+}
+
+/// System driving the forage → return → deliver cycle of worker ants
+///
+/// Taking food from a source richer than the recruitment threshold announces a
+/// recruitment wave toward it. Walking drains [`Stamina`], which slows a tiring ant
+/// and finally forces it to rest where it stands until it has recovered. Fed ants in a
+/// well-stocked colony idle near home instead of ranging out, until food runs low.
+/// Switching between foraging and idling has hysteresis: an ant idles once its energy
+/// passes `idle_energy_fraction` but only forages again below `forage_energy_fraction`,
+/// and keeps either task for at least `task_min_dwell` seconds unless the colony store
+/// runs short.
+/// Food is carried to the nearest storage chamber and scheduled rest is taken in the
+/// worker quarters, falling back to home while the nest lacks those chambers.
+/// Ants with [`Breadcrumbs`] drop a waypoint every `breadcrumb_spacing` on the way out
+/// and retrace them in reverse on the way back, heading straight in once near home.
+/// Food within `detection_radius` is spotted mid-walk: non-scouts break off a wander to
+/// head for the closest source, preferring the kind the colony is short of.
+pub fn foraging_behavior_system(
+    time: Res<Time>,
+    ai_config: Res<AntAiConfig>,
+    strategy: Res<BoxedStrategy>,
+    mut pheromone_grid: ResMut<PheromoneGrid>,
+    world_config: Option<Res<WorldConfig>>,
+    mut food_store: ResMut<ColonyFoodStore>,
+    mut daughter_colonies: Option<ResMut<DaughterColonies>>,
+    mut recruitment: Option<ResMut<RecruitmentWaves>>,
     mut ant_query: Query<
         (
-            Entity,
-            &Position,
+            &mut Position,
             &mut AntBehavior,
-            &mut Lifecycle,
-            &mut Inventory,
+            Option<&mut FoodMemory>,
+            Option<&mut Breadcrumbs>,
+            Option<&Lifecycle>,
+            Option<&SpecializedRole>,
+            Option<&Caste>,
+            Option<&BehaviorModifiers>,
+            Option<&mut Stamina>,
+            Option<&ColonyId>,
+            Has<Scout>,
         ),
-        With<Ant>,
+        (With<Ant>, Without<Dying>),
     >,
-    mut food_query: Query<(Entity, &Position, &mut FoodSource), With<Food>>,
+    mut food_query: Query<(Entity, &Position, &mut FoodSource), Without<Ant>>,
+    chamber_query: Query<(&Position, &Chamber), Without<Ant>>,
+    mut food_grid: Local<SpatialGrid>,
 ) {
-    for (_ant_entity, ant_pos, mut ant_behavior, mut ant_lifecycle, mut inventory) in
-        ant_query.iter_mut()
+    let delta_time = time.delta_seconds();
+    let world_config = world_config.as_deref().cloned().unwrap_or_default();
+
+    reset_entity_grid(
+        &mut food_grid,
+        &world_config,
+        ai_config.detection_radius,
+        food_query.iter().len(),
+    );
+    for (entity, food_position, food) in food_query.iter() {
+        if food.nutrition_value > 0.0 {
+            food_grid.insert_entity(entity, food_position);
+        }
+    }
+
+    for (
+        mut position,
+        mut behavior,
+        mut memory,
+        mut breadcrumbs,
+        lifecycle,
+        role,
+        caste,
+        modifiers,
+        mut stamina,
+        colony,
+        is_scout,
+    ) in ant_query.iter_mut()
     {
-        // Only process ants that are foraging
-        if ant_behavior.state != AntState::Foraging {
+        behavior.task_dwell = (behavior.task_dwell - delta_time).max(0.0);
+
+        // Nursery workers and maintainers stay inside the nest
+        if role.is_some_and(|role| role.works_inside_nest()) {
             continue;
         }
 
-        // Check for food sources within consumption range (wider range for debugging) using spatial indexing
-        let consumption_radius = 10.0;
-        let nearby_food_entities = spatial_grid.get_entities_in_radius(ant_pos, consumption_radius);
+        match behavior.state {
+            AntState::Foraging => {
+                if let Some(breadcrumbs) = breadcrumbs.as_mut() {
+                    breadcrumbs.record(
+                        &position,
+                        ai_config.breadcrumb_spacing,
+                        ai_config.breadcrumb_capacity,
+                    );
+                }
+
+                // Take food if standing next to a source
+                let found_food = food_query.iter_mut().find(|(_, food_position, food)| {
+                    food.nutrition_value > 0.0
+                        && distance(food_position, &position) <= ai_config.pickup_radius
+                });
 
-        for food_entity in nearby_food_entities {
-            if let Ok((_entity, food_pos, mut food_source)) = food_query.get_mut(food_entity) {
-                if !food_source.is_available {
+                if let Some((food_entity, food_position, mut food)) = found_food {
+                    if food.nutrition_value >= ai_config.recruitment_threshold {
+                        if let Some(recruitment) = recruitment.as_mut() {
+                            recruitment.announce(
+                                food_entity,
+                                food_position.clone(),
+                                behavior.home_position.clone(),
+                            );
+                        }
+                    }
+                    if let Some(memory) = memory.as_mut() {
+                        memory.location = Some(food_position.clone());
+                    }
+                    let carry_capacity = ai_config.carry_capacity
+                        * caste.map_or(1.0, |caste| caste.size_class.carry_multiplier())
+                        * modifiers.map_or(1.0, |modifiers| modifiers.foraging_efficiency);
+                    let taken = food.nutrition_value.min(carry_capacity);
+                    food.nutrition_value -= taken;
+                    behavior.carried_food = taken;
+                    behavior.carried_kind = food.kind;
+                    behavior.state = AntState::Returning;
+                    behavior.target_position = Some(ant_destination(
+                        role,
+                        AntState::Returning,
+                        &position,
+                        &behavior.home_position,
+                        chamber_query.iter(),
+                    ));
                     continue;
                 }
 
-                let dx = food_pos.x - ant_pos.x;
-                let dy = food_pos.y - ant_pos.y;
-                let distance = (dx * dx + dy * dy).sqrt();
+                // Detected food of the scarce kind wins over the rest
+                let scarce_kind = food_store.scarce_kind();
+                let mut nearby_food: Vec<(Position, FoodKind)> = food_grid
+                    .get_nearby_entities(&position, ai_config.detection_radius)
+                    .into_iter()
+                    .filter_map(|entity| food_query.get(entity).ok())
+                    .filter(|(_, food_position, food)| {
+                        food.nutrition_value > 0.0
+                            && distance(food_position, &position) <= ai_config.detection_radius
+                    })
+                    .map(|(_, food_position, food)| (food_position.clone(), food.kind))
+                    .collect();
+                if nearby_food.iter().any(|(_, kind)| *kind == scarce_kind) {
+                    nearby_food.retain(|(_, kind)| *kind == scarce_kind);
+                }
+                let nearby_food: Vec<Position> = nearby_food
+                    .into_iter()
+                    .map(|(food_position, _)| food_position)
+                    .collect();
 
-                // If ant is close enough to consume food (same as detection radius)
-                if distance <= consumption_radius {
-                    // Consume the food
-                    food_source.is_available = false;
-                    food_source.regeneration_timer = food_source.regeneration_time;
+                // Spotting food breaks off a wander; scouts keep exploring
+                if !is_scout {
+                    let heading_for_food =
+                        behavior.target_position.as_ref().is_some_and(|target| {
+                            nearby_food.iter().any(|food_position| {
+                                distance(food_position, target) <= ai_config.pickup_radius
+                            })
+                        });
+                    let closest = nearby_food
+                        .iter()
+                        .min_by(|a, b| distance(a, &position).total_cmp(&distance(b, &position)));
+                    if let Some(closest) = closest.filter(|_| !heading_for_food) {
+                        behavior.heading = (closest.y - position.y).atan2(closest.x - position.x);
+                        behavior.target_position = Some(closest.clone());
+                    }
+                }
 
-                    // Recover energy
-                    ant_lifecycle.energy += food_source.nutrition_value;
-                    ant_lifecycle.energy = ant_lifecycle.energy.min(ant_lifecycle.max_energy); // Cap at max energy
+                // Smooth wandering keeps a heading that drifts a little every frame
+                if ai_config.wander_mode == WanderMode::Smooth {
+                    let turn = ai_config.wander_turn_rate * delta_time;
+                    behavior.heading += thread_rng().gen_range(-turn..=turn);
+                }
 
-                    // Set inventory to carry food value back to colony
-                    inventory.carried_food_value = food_source.nutrition_value;
+                let needs_target = behavior
+                    .target_position
+                    .as_ref()
+                    .is_none_or(|target| distance(target, &position) <= ARRIVAL_RADIUS);
+                if needs_target {
+                    // Reaching a remembered location without finding food means it ran out
+                    if let Some(memory) = memory.as_mut() {
+                        if memory.location.as_ref().is_some_and(|location| {
+                            distance(location, &position) <= ai_config.pickup_radius
+                        }) {
+                            memory.location = None;
+                        }
+                    }
 
-                    // Change state to carrying food back to colony
-                    ant_behavior.state = AntState::CarryingFood;
-                    ant_behavior.target_position = Some(inventory.home_position.clone());
+                    // Nothing to search for: a fed ant in a well-stocked colony heads home to idle
+                    if behavior.task_dwell <= 0.0 && is_satiated(lifecycle, &food_store, &ai_config)
+                    {
+                        behavior.state = AntState::Idle;
+                        behavior.task_dwell = ai_config.task_min_dwell;
+                        behavior.target_position =
+                            Some(idle_spot(&behavior.home_position, ai_config.idle_radius));
+                        continue;
+                    }
 
-                    info!(
-                        "Ant consumed food! Energy: {:.1}/{:.1}, Carrying: {:.1}",
-                        ant_lifecycle.energy,
-                        ant_lifecycle.max_energy,
-                        inventory.carried_food_value
+                    let nearby_pheromone: Vec<(Position, f32)> = pheromone_grid
+                        .neighbors(&position)
+                        .into_iter()
+                        .map(|(cell, strength)| (pheromone_grid.cell_center(cell), strength))
+                        .collect();
+
+                    let context = AntContext {
+                        position: &position,
+                        home: &behavior.home_position,
+                        energy: lifecycle.map(|lifecycle| lifecycle.energy),
+                        is_scout,
+                        heading: behavior.heading,
+                        remembered_food: memory
+                            .as_ref()
+                            .and_then(|memory| memory.location.as_ref()),
+                        nearby_food: &nearby_food,
+                        nearby_pheromone: &nearby_pheromone,
+                        config: &ai_config,
+                        world: &world_config,
+                    };
+                    let target = strategy.0.choose_target(&context);
+                    if let Some(target) = target.as_ref() {
+                        if distance(target, &position) > 0.0 {
+                            behavior.heading = (target.y - position.y).atan2(target.x - position.x);
+                        }
+                    }
+                    behavior.target_position = target;
+                }
+            }
+            AntState::Returning => {
+                // Lay a trail back home; scouts recruit with a stronger trail
+                let deposit = if is_scout {
+                    ai_config.scout_trail_deposit
+                } else {
+                    ai_config.trail_deposit
+                };
+                pheromone_grid.deposit(&position, deposit * delta_time);
+
+                let destination = ant_destination(
+                    role,
+                    AntState::Returning,
+                    &position,
+                    &behavior.home_position,
+                    chamber_query.iter(),
+                );
+                if distance(&position, &destination) <= ARRIVAL_RADIUS {
+                    store_food(
+                        &mut food_store,
+                        daughter_colonies.as_deref_mut(),
+                        colony,
+                        &behavior,
                     );
+                    behavior.carried_food = 0.0;
+                    behavior.state = AntState::Foraging;
+                    behavior.target_position = None;
+                    if let Some(breadcrumbs) = breadcrumbs.as_mut() {
+                        breadcrumbs.clear();
+                    }
+                    continue;
+                }
+
+                behavior.target_position = Some(destination.clone());
+                if let Some(breadcrumbs) = breadcrumbs.as_mut() {
+                    // Retrace the outbound trip until close enough to head straight in
+                    if distance(&position, &destination) <= ai_config.breadcrumb_home_radius {
+                        breadcrumbs.clear();
+                    }
+                    while breadcrumbs
+                        .points
+                        .back()
+                        .is_some_and(|crumb| distance(crumb, &position) <= ARRIVAL_RADIUS)
+                    {
+                        breadcrumbs.points.pop_back();
+                    }
+                    if let Some(crumb) = breadcrumbs.points.back() {
+                        behavior.target_position = Some(crumb.clone());
+                    }
+                }
+            }
+            AntState::Idle => {
+                let colony_short = food_store.food < ai_config.idle_food_reserve;
+                if colony_short || (behavior.task_dwell <= 0.0 && is_hungry(lifecycle, &ai_config))
+                {
+                    if let Some(breadcrumbs) = breadcrumbs.as_mut() {
+                        breadcrumbs.clear();
+                    }
+                    behavior.state = AntState::Foraging;
+                    behavior.task_dwell = ai_config.task_min_dwell;
+                    behavior.target_position = None;
+                    continue;
+                }
 
-                    // Only consume one food source per frame per ant
-                    break;
+                let needs_spot = behavior
+                    .target_position
+                    .as_ref()
+                    .is_none_or(|target| distance(target, &position) <= ARRIVAL_RADIUS);
+                if needs_spot {
+                    behavior.target_position =
+                        Some(idle_spot(&behavior.home_position, ai_config.idle_radius));
+                }
+            }
+            // Resting ants recover; exhausted ones rest where they stand and resume their
+            // trip once rested, the others retire to the worker quarters
+            AntState::Resting => {
+                if let Some(stamina) = stamina.as_mut() {
+                    stamina.recover(ai_config.stamina_recovery_rate * delta_time);
+                    if stamina.exhausted {
+                        if stamina.fraction() >= ai_config.stamina_resume_fraction {
+                            stamina.exhausted = false;
+                            behavior.state = if behavior.carried_food > 0.0 {
+                                AntState::Returning
+                            } else {
+                                AntState::Foraging
+                            };
+                        }
+                        continue;
+                    }
+                }
+
+                let quarters = ant_destination(
+                    role,
+                    AntState::Resting,
+                    &position,
+                    &behavior.home_position,
+                    chamber_query.iter(),
+                );
+                if distance(&position, &quarters) <= ARRIVAL_RADIUS {
+                    continue;
+                }
+                behavior.target_position = Some(quarters);
+            }
+            // Defenders are steered by the alarm response
+            AntState::Defending => continue,
+        }
+
+        if let Some(target) = behavior.target_position.clone() {
+            let mut speed_factor = stamina.as_ref().map_or(1.0, |stamina| {
+                ai_config.stamina_speed_factor(stamina.fraction())
+            });
+            if behavior.state == AntState::Idle {
+                speed_factor *= ai_config.idle_speed_factor;
+            }
+            step_toward(
+                &mut position,
+                &target,
+                behavior.speed * speed_factor * delta_time,
+            );
+
+            if let Some(stamina) = stamina.as_mut() {
+                stamina.spend(ai_config.stamina_drain_rate * delta_time);
+                if stamina.current <= 0.0 {
+                    stamina.exhausted = true;
+                    behavior.state = AntState::Resting;
                 }
             }
         }
     }
 }
 
-/// System for handling food regeneration over time
-pub fn food_regeneration_system(
+/// System running recruitment waves toward rich food finds
+///
+/// While a source holds more food than the recruitment threshold, a strong pheromone
+/// column is laid between it and the nest and idle foragers are pulled toward it, both
+/// in proportion to the food left. As the source depletes the column fades and fewer
+/// recruits are kept; once it drops below the threshold the wave ends and its recruits
+/// return to normal foraging.
+pub fn recruitment_system(
+    mut commands: Commands,
     time: Res<Time>,
-    mut food_query: Query<&mut FoodSource, With<Food>>,
+    ai_config: Res<AntAiConfig>,
+    mut recruitment: ResMut<RecruitmentWaves>,
+    mut pheromone_grid: ResMut<PheromoneGrid>,
+    food_query: Query<&FoodSource>,
+    mut ant_query: Query<
+        (
+            Entity,
+            &Position,
+            &mut AntBehavior,
+            Option<&mut FoodMemory>,
+            Option<&Recruited>,
+            Option<&SpecializedRole>,
+        ),
+        (With<Ant>, Without<Scout>, Without<Dying>),
+    >,
 ) {
     let delta_time = time.delta_seconds();
+    let threshold = ai_config.recruitment_threshold.max(f32::EPSILON);
+    let mut enlisted_now: HashSet<Entity> = HashSet::new();
+
+    let mut ended = Vec::new();
+    recruitment.waves.retain(|wave| {
+        let richness = food_query
+            .get(wave.source)
+            .map_or(0.0, |food| food.nutrition_value / threshold);
+        if richness < 1.0 {
+            ended.push(wave.source);
+        }
+        richness >= 1.0
+    });
+
+    for wave in recruitment.waves.iter() {
+        let Ok(food) = food_query.get(wave.source) else {
+            continue;
+        };
+        let richness = food.nutrition_value / threshold;
+
+        // Strong trail along the straight column between the find and the nest
+        let length = distance(&wave.location, &wave.home);
+        let steps = (length / pheromone_grid.cell_size).ceil().max(1.0) as usize;
+        let deposit = ai_config.recruitment_deposit * richness * delta_time;
+        for step in 0..=steps {
+            let t = step as f32 / steps as f32;
+            let point = Position {
+                x: wave.location.x + (wave.home.x - wave.location.x) * t,
+                y: wave.location.y + (wave.home.y - wave.location.y) * t,
+            };
+            pheromone_grid.deposit(&point, deposit);
+        }
+
+        let wanted = ((richness * ai_config.recruits_per_threshold).round() as usize)
+            .min(ai_config.max_recruits);
+        let enlisted = ant_query
+            .iter()
+            .filter(|(_, _, _, _, recruited, _)| {
+                recruited.is_some_and(|recruited| recruited.source == wave.source)
+            })
+            .count();
+        if enlisted >= wanted {
+            continue;
+        }
+
+        let mut idle: Vec<_> = ant_query
+            .iter_mut()
+            .filter(|(entity, _, behavior, _, recruited, role)| {
+                recruited.is_none()
+                    && !enlisted_now.contains(entity)
+                    && behavior.state == AntState::Foraging
+                    && behavior.carried_food == 0.0
+                    && !role.is_some_and(|role| role.works_inside_nest())
+            })
+            .collect();
+        // Foragers still near the nest answer first
+        idle.sort_by(|a, b| distance(a.1, &wave.home).total_cmp(&distance(b.1, &wave.home)));
+        for (entity, _, mut behavior, memory, _, _) in idle.into_iter().take(wanted - enlisted) {
+            behavior.target_position = Some(wave.location.clone());
+            if let Some(mut memory) = memory {
+                memory.location = Some(wave.location.clone());
+            }
+            commands.entity(entity).insert(Recruited {
+                source: wave.source,
+            });
+            enlisted_now.insert(entity);
+        }
+    }
 
-    for mut food_source in food_query.iter_mut() {
-        if !food_source.is_available && food_source.regeneration_timer > 0.0 {
-            food_source.regeneration_timer -= delta_time;
+    if !ended.is_empty() {
+        for (entity, _, _, _, recruited, _) in ant_query.iter() {
+            if recruited.is_some_and(|recruited| ended.contains(&recruited.source)) {
+                commands.entity(entity).remove::<Recruited>();
+            }
+        }
+    }
+}
+
+/// System moving idle workers between foraging and resting with the time of day
+///
+/// Foragers without a load rest at a rate of `1 - multiplier` and resting ants resume at
+/// a rate of `multiplier`, so the foraging share settles at the scheduled multiplier.
+/// Ants [`Sheltering`] for the night are left to [`night_shelter_system`].
+pub fn activity_schedule_system(
+    time: Res<Time>,
+    simulation_time: Res<SimulationTime>,
+    schedule: Res<ActivitySchedule>,
+    mut ant_query: Query<
+        (&mut AntBehavior, Option<&Stamina>),
+        (With<Ant>, Without<Sheltering>, Without<Dying>),
+    >,
+) {
+    let mut rng = thread_rng();
+    let hour = simulation_time.get_time_of_day_fraction() * 24.0;
+    let multiplier = schedule.multiplier_at(hour);
+    let step = (schedule.transition_rate * time.delta_seconds()).clamp(0.0, 1.0);
 
-            // Regenerate food source when timer reaches zero
-            if food_source.regeneration_timer <= 0.0 {
-                food_source.is_available = true;
-                food_source.regeneration_timer = 0.0;
-                info!("Food source regenerated!");
+    for (mut behavior, stamina) in ant_query.iter_mut() {
+        // Exhausted ants wake on their own once recovered
+        if stamina.is_some_and(|stamina| stamina.exhausted) {
+            continue;
+        }
+
+        match behavior.state {
+            AntState::Foraging
+                if behavior.carried_food == 0.0
+                    && rng.gen_bool(((1.0 - multiplier) * step) as f64) =>
+            {
+                behavior.state = AntState::Resting;
+                behavior.target_position = None;
+            }
+            AntState::Resting if rng.gen_bool((multiplier * step) as f64) => {
+                behavior.state = AntState::Foraging;
             }
+            _ => {}
         }
     }
 }
 
-/// System for invasive species food consumption (more aggressive than ants)
-pub fn invasive_species_food_consumption_system(
-    invasive_query: Query<(&Position, &InvasiveSpecies)>,
-    mut food_query: Query<(Entity, &Position, &mut FoodSource), With<Food>>,
+/// System sending surface workers into the nest at dusk and back out at dawn
+///
+/// After dusk, unladen foragers and idlers head for the worker quarters (home while the
+/// nest has none) until `shelter_share` of the surface workers are [`Sheltering`];
+/// ants still carrying food deliver it first. After dawn they trickle back out to forage.
+pub fn night_shelter_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    simulation_time: Res<SimulationTime>,
+    config: Option<Res<NightShelterConfig>>,
+    mut ant_query: Query<
+        (
+            Entity,
+            &mut AntBehavior,
+            Option<&SpecializedRole>,
+            Option<&Stamina>,
+            Has<Sheltering>,
+        ),
+        (With<Ant>, Without<Dying>),
+    >,
 ) {
-    for (invasive_pos, invasive) in invasive_query.iter() {
-        // Check for food sources within consumption range
-        for (_food_entity, food_pos, mut food_source) in food_query.iter_mut() {
-            if !food_source.is_available {
+    let config = config.map(|config| config.clone()).unwrap_or_default();
+    let mut rng = thread_rng();
+    let hour = simulation_time.get_time_of_day_fraction() * 24.0;
+    let delta_time = time.delta_seconds();
+
+    if config.is_night(hour) {
+        let mut surface_workers = 0;
+        let mut sheltering = 0;
+        for (_, _, role, _, is_sheltering) in ant_query.iter() {
+            if !role.is_some_and(|role| role.works_inside_nest()) {
+                surface_workers += 1;
+                sheltering += usize::from(is_sheltering);
+            }
+        }
+        let wanted = (surface_workers as f32 * config.shelter_share).round() as usize;
+        let step = (config.retreat_rate * delta_time).clamp(0.0, 1.0);
+
+        for (entity, mut behavior, role, _, is_sheltering) in ant_query.iter_mut() {
+            if sheltering >= wanted {
+                break;
+            }
+            let heads_in = !is_sheltering
+                && !role.is_some_and(|role| role.works_inside_nest())
+                && matches!(behavior.state, AntState::Foraging | AntState::Idle)
+                && behavior.carried_food == 0.0;
+            if heads_in && rng.gen_bool(step as f64) {
+                behavior.state = AntState::Resting;
+                behavior.target_position = None;
+                commands.entity(entity).insert(Sheltering);
+                sheltering += 1;
+            }
+        }
+    } else {
+        let step = (config.emerge_rate * delta_time).clamp(0.0, 1.0);
+        for (entity, mut behavior, _, stamina, is_sheltering) in ant_query.iter_mut() {
+            if !is_sheltering || !rng.gen_bool(step as f64) {
                 continue;
             }
+            commands.entity(entity).remove::<Sheltering>();
+            // Exhausted ants wake on their own once recovered
+            if behavior.state == AntState::Resting
+                && !stamina.is_some_and(|stamina| stamina.exhausted)
+            {
+                behavior.state = AntState::Foraging;
+            }
+        }
+    }
+}
 
-            let dx = food_pos.x - invasive_pos.x;
-            let dy = food_pos.y - invasive_pos.y;
-            let distance = (dx * dx + dy * dy).sqrt();
+/// System evaporating pheromone trails over time
+pub fn pheromone_decay_system(time: Res<Time>, mut pheromone_grid: ResMut<PheromoneGrid>) {
+    pheromone_grid.decay(time.delta_seconds());
+}
 
-            // Invasive species have a slightly larger consumption range
-            if distance <= 3.0 {
-                // Consume food more aggressively based on consumption rate
-                food_source.is_available = false;
-                // Invasive species damage food sources - longer regeneration time
-                food_source.regeneration_timer =
-                    food_source.regeneration_time * invasive.food_consumption_rate * 1.5;
+/// System removing exhausted food sources
+pub fn depleted_food_cleanup_system(
+    mut commands: Commands,
+    food_query: Query<(Entity, &FoodSource)>,
+) {
+    for (entity, food) in food_query.iter() {
+        if food.nutrition_value <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
 
-                info!(
-                    "Invasive species consumed food! Regeneration extended to: {:.1}s",
-                    food_source.regeneration_timer
-                );
+/// System letting ants that touch antennae share what they know about food
+///
+/// An ant that remembers a food location passes it on to a naive ant it meets. Candidates
+/// come from the shared [`SpatialGrid`] built last frame, and a per-ant cooldown stops the
+/// same pair from exchanging every frame while they stay in contact.
+pub fn antennation_system(
+    time: Res<Time>,
+    ai_config: Res<AntAiConfig>,
+    spatial_grid: Res<SpatialGrid>,
+    mut ant_query: Query<
+        (Entity, &Position, &mut FoodMemory, &mut AntennationCooldown),
+        (With<Ant>, Without<Dying>),
+    >,
+) {
+    let delta_time = time.delta_seconds();
+
+    for (_, _, _, mut cooldown) in ant_query.iter_mut() {
+        cooldown.remaining = (cooldown.remaining - delta_time).max(0.0);
+    }
+
+    // Collect exchanges first so each contact reads the memory from before this tick
+    let mut exchanges = Vec::new();
+    for (entity, position, memory, cooldown) in ant_query.iter() {
+        let Some(location) = memory.location.as_ref() else {
+            continue;
+        };
+        if cooldown.remaining > 0.0 {
+            continue;
+        }
+
+        for other in spatial_grid.get_nearby_entities(position, ai_config.antennation_radius) {
+            if other == entity {
+                continue;
+            }
+            let Ok((_, other_position, other_memory, other_cooldown)) = ant_query.get(other) else {
+                continue;
+            };
+            if other_memory.location.is_none()
+                && other_cooldown.remaining <= 0.0
+                && distance(position, other_position) <= ai_config.antennation_radius
+            {
+                exchanges.push((entity, other, location.clone()));
+            }
+        }
+    }
 
-                // Invasive species can consume multiple food sources per frame
-                // This makes them more damaging to the ecosystem
+    for (informer, receiver, location) in exchanges {
+        if let Ok((_, _, mut memory, mut cooldown)) = ant_query.get_mut(receiver) {
+            if memory.location.is_some() {
+                continue; // Already learned from another ant this tick
             }
+            memory.location = Some(location);
+            cooldown.remaining = ai_config.antennation_cooldown;
         }
+        if let Ok((_, _, _, mut cooldown)) = ant_query.get_mut(informer) {
+            cooldown.remaining = ai_config.antennation_cooldown;
+        }
+    }
+}
+
+/// Put the food an ant brought home into its colony's store
+///
+/// Ants of a daughter colony fill that colony's store in [`DaughterColonies`]; everyone
+/// else, and every ant while no statistics are kept, fills the home [`ColonyFoodStore`].
+pub(crate) fn store_food(
+    food_store: &mut ColonyFoodStore,
+    daughter_colonies: Option<&mut DaughterColonies>,
+    colony: Option<&ColonyId>,
+    behavior: &AntBehavior,
+) {
+    match (colony, daughter_colonies) {
+        (Some(colony), Some(statistics)) if *colony != ColonyId::default() => {
+            statistics.deposit(*colony, behavior.carried_food);
+        }
+        _ => food_store.add(behavior.carried_kind, behavior.carried_food),
+    }
+}
+
+pub(crate) fn distance(a: &Position, b: &Position) -> f32 {
+    let dx = a.x - b.x;
+    let dy = a.y - b.y;
+    (dx * dx + dy * dy).sqrt()
+}
+
+/// Whether an ant is fed enough, in a colony stocked enough, to stop searching for food
+///
+/// Ants without a lifecycle never count as satiated.
+fn is_satiated(
+    lifecycle: Option<&Lifecycle>,
+    food_store: &ColonyFoodStore,
+    ai_config: &AntAiConfig,
+) -> bool {
+    food_store.food >= ai_config.idle_food_reserve
+        && lifecycle.is_some_and(|lifecycle| {
+            lifecycle.energy >= lifecycle.max_energy * ai_config.idle_energy_fraction
+        })
+}
+
+/// Whether an idle ant's energy has fallen low enough to send it foraging again
+fn is_hungry(lifecycle: Option<&Lifecycle>, ai_config: &AntAiConfig) -> bool {
+    lifecycle.is_none_or(|lifecycle| {
+        lifecycle.energy < lifecycle.max_energy * ai_config.forage_energy_fraction
+    })
+}
+
+/// Random spot within `radius` of home for an idle ant to mill toward
+fn idle_spot(home: &Position, radius: f32) -> Position {
+    let mut rng = thread_rng();
+    let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+    let reach = rng.gen_range(0.0..=radius.max(0.0));
+    Position {
+        x: home.x + angle.cos() * reach,
+        y: home.y + angle.sin() * reach,
+    }
+}
+
+/// Move a position toward a target by at most `step` units
+pub(crate) fn step_toward(position: &mut Position, target: &Position, step: f32) {
+    let dx = target.x - position.x;
+    let dy = target.y - position.y;
+    let length = (dx * dx + dy * dy).sqrt();
+
+    if length <= step || length == 0.0 {
+        position.x = target.x;
+        position.y = target.y;
+    } else {
+        position.x += dx / length * step;
+        position.y += dy / length * step;
     }
 }
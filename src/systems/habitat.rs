@@ -0,0 +1,71 @@
+use crate::components::{Ant, AntAiConfig, Position, Queen, SoilCell, SpatialGrid, WorldConfig};
+use crate::systems::foraging::{distance, step_toward};
+use crate::systems::spatial_grid::reset_entity_grid;
+use bevy::prelude::*;
+
+/// System drifting underground ants away from soil outside their humidity comfort band
+///
+/// Each ant compares the soil around it and, while its own spot is too wet or too dry,
+/// walks toward the most comfortable cell in range. The drift speed grows with the
+/// discomfort, so floods and droughts redistribute the colony gradually.
+pub fn humidity_comfort_system(
+    time: Res<Time>,
+    ai_config: Res<AntAiConfig>,
+    world_config: Option<Res<WorldConfig>>,
+    mut soil_grid: Local<SpatialGrid>,
+    soil_query: Query<(Entity, &Position, &SoilCell), Without<Ant>>,
+    mut ant_query: Query<&mut Position, (With<Ant>, Without<Queen>)>,
+) {
+    let delta_time = time.delta_seconds();
+    let radius = ai_config.humidity_sense_radius;
+
+    let world_config = world_config.as_deref().cloned().unwrap_or_default();
+    reset_entity_grid(
+        &mut soil_grid,
+        &world_config,
+        radius,
+        soil_query.iter().len(),
+    );
+    for (entity, position, _) in soil_query.iter() {
+        soil_grid.insert_entity(entity, position);
+    }
+
+    for mut position in ant_query.iter_mut() {
+        // Only ants inside the nest live with the soil around them
+        if position.y >= 0.0 {
+            continue;
+        }
+
+        let nearby: Vec<(&Position, f32)> = soil_grid
+            .get_nearby_entities(&position, radius)
+            .into_iter()
+            .filter_map(|entity| soil_query.get(entity).ok())
+            .filter(|(_, soil_position, _)| distance(soil_position, &position) <= radius)
+            .map(|(_, soil_position, soil)| {
+                (soil_position, ai_config.moisture_discomfort(soil.moisture))
+            })
+            .collect();
+        // The closest cell stands for the ant's own spot
+        let Some(&(_, current_discomfort)) = nearby
+            .iter()
+            .min_by(|a, b| distance(a.0, &position).total_cmp(&distance(b.0, &position)))
+        else {
+            continue;
+        };
+        if current_discomfort <= 0.0 {
+            continue;
+        }
+
+        let Some(&(best, best_discomfort)) = nearby.iter().min_by(|a, b| a.1.total_cmp(&b.1))
+        else {
+            continue;
+        };
+        if best_discomfort >= current_discomfort {
+            continue;
+        }
+
+        let target = best.clone();
+        let step = ai_config.humidity_drift_speed * current_discomfort * delta_time;
+        step_toward(&mut position, &target, step);
+    }
+}
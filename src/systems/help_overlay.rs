@@ -0,0 +1,127 @@
+use crate::components::{HelpOverlay, HelpOverlayText, InputAction, KeyBindings};
+use crate::localization::Localization;
+use bevy::prelude::*;
+
+/// One line of the help overlay: an action and the key it is bound to
+#[derive(Clone, Debug, PartialEq)]
+pub struct HelpEntry {
+    pub action: InputAction,
+    pub category: String,
+    pub label: String,
+    pub key: String,
+}
+
+/// Short on-screen name of a key, e.g. `S` for `KeyS` and `Left` for `ArrowLeft`
+pub fn key_label(key: KeyCode) -> String {
+    match key {
+        KeyCode::Equal => "=".to_string(),
+        KeyCode::Minus => "-".to_string(),
+        KeyCode::Escape => "Esc".to_string(),
+        _ => {
+            let name = format!("{:?}", key);
+            ["Key", "Digit", "Arrow"]
+                .iter()
+                .find_map(|prefix| name.strip_prefix(prefix))
+                .filter(|rest| !rest.is_empty())
+                .map_or(name.clone(), str::to_string)
+        }
+    }
+}
+
+/// Every action with its current key, in [`InputAction::ALL`] order
+///
+/// Categories and labels come from the active [`Localization`].
+pub fn help_entries(key_bindings: &KeyBindings, localization: &Localization) -> Vec<HelpEntry> {
+    InputAction::ALL
+        .iter()
+        .map(|action| {
+            let label = localization.t(action.label_key());
+            HelpEntry {
+                action: *action,
+                category: localization.t(action.category_key()).to_string(),
+                label: match action {
+                    InputAction::SetSpeed(speed) => format!("{} {}x", label, speed),
+                    _ => label.to_string(),
+                },
+                key: key_label(key_bindings.key(*action)),
+            }
+        })
+        .collect()
+}
+
+/// Help overlay text: the entries under a heading per category
+pub fn help_text(key_bindings: &KeyBindings, localization: &Localization) -> String {
+    let mut lines = vec![localization.t("help.title").to_string()];
+    let mut category = String::new();
+    for entry in help_entries(key_bindings, localization) {
+        if entry.category != category {
+            category = entry.category.clone();
+            lines.push(String::new());
+            lines.push(format!("{}:", category));
+        }
+        lines.push(format!("  {:<8} {}", entry.key, entry.label));
+    }
+    lines.join("\n")
+}
+
+/// System to set up the full-screen help overlay (hidden until toggled)
+pub fn setup_help_overlay(mut commands: Commands) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            background_color: Color::srgba(0.0, 0.0, 0.0, 0.85).into(),
+            visibility: Visibility::Hidden,
+            z_index: ZIndex::Global(200),
+            ..default()
+        })
+        .insert(HelpOverlay)
+        .with_children(|parent| {
+            parent
+                .spawn(TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 14.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                ))
+                .insert(HelpOverlayText);
+        });
+}
+
+/// System showing and hiding the help overlay with F1
+pub fn help_overlay_toggle_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    mut overlay_query: Query<&mut Visibility, With<HelpOverlay>>,
+) {
+    if key_bindings.just_pressed(&keyboard_input, InputAction::ToggleHelp) {
+        for mut visibility in overlay_query.iter_mut() {
+            *visibility = match *visibility {
+                Visibility::Hidden => Visibility::Visible,
+                _ => Visibility::Hidden,
+            };
+        }
+    }
+}
+
+/// System rewriting the help overlay when it is built and whenever the key bindings or
+/// the locale change
+pub fn update_help_overlay_system(
+    key_bindings: Res<KeyBindings>,
+    localization: Res<Localization>,
+    mut text_query: Query<(&mut Text, Ref<HelpOverlayText>)>,
+) {
+    for (mut text, marker) in text_query.iter_mut() {
+        if key_bindings.is_changed() || localization.is_changed() || marker.is_added() {
+            text.sections[0].value = help_text(&key_bindings, &localization);
+        }
+    }
+}
@@ -1,109 +1,243 @@
 use crate::components::{
-    Ant, AntBehavior, AntState, DisasterState, DisasterType, FoodSource, InvasiveSpecies,
-    Lifecycle, Position,
+    Ant, Caste, DisasterState, DisasterType, Dying, FoodSource, Health, InvasionConfig,
+    InvasionMode, InvasiveNest, InvasiveSpecies, InvasiveSpeciesConfig, NestInvader, Position,
+    SpatialGrid,
 };
+use crate::systems::rendering::{z_for, RenderLayer};
 use bevy::prelude::*;
 use rand::Rng;
 
+/// Distance at which an ant and an invader fight
+pub const COMBAT_CONTACT_RADIUS: f32 = 2.0;
+/// HP an ant loses when it loses a fight
+const DEFEAT_DAMAGE: f32 = 30.0;
+
 /// System to spawn invasive species entities during invasive species disasters
+///
+/// In [`InvasionMode::Nest`] a single invasive nest is founded instead of lone
+/// invaders, and the nest produces invaders itself. A nest destroyed by the colony is
+/// not replaced until the next invasion. Invader stats come from [`InvasiveSpeciesConfig`].
 pub fn invasive_species_spawning_system(
     mut commands: Commands,
     disaster_state: Res<DisasterState>,
     time: Res<Time>,
+    config: Option<Res<InvasionConfig>>,
+    species_config: Option<Res<InvasiveSpeciesConfig>>,
+    mut nest_founded: Local<bool>,
     query: Query<&InvasiveSpecies>, // Check existing invasive species
 ) {
     // Only spawn if invasive species disaster is active and we don't have too many
     if !disaster_state.is_active(DisasterType::InvasiveSpecies) {
+        *nest_founded = false;
         return;
     }
 
-    let current_count = query.iter().count();
-    let max_invasive_species = 15; // Limit to prevent overwhelming the simulation
+    let config = config.map(|config| config.clone()).unwrap_or_default();
+    if config.mode == InvasionMode::Nest {
+        if !*nest_founded {
+            spawn_invasive_nest(&mut commands, &config);
+            *nest_founded = true;
+        }
+        return;
+    }
 
-    if current_count >= max_invasive_species {
+    let species_config = species_config
+        .map(|config| config.clone())
+        .unwrap_or_default();
+    let current_count = query.iter().count();
+    if current_count >= species_config.spawn_count {
         return;
     }
 
     let delta_time = time.delta_seconds();
 
-    // Spawn rate: attempt to spawn every 2-3 seconds (adjusted for time acceleration)
+    // Spawn rate: attempt to spawn every 2-3 seconds
     let spawn_probability = delta_time * 0.4; // ~40% chance per second
 
     if rand::thread_rng().gen::<f32>() < spawn_probability {
-        spawn_invasive_species_entity(&mut commands);
+        let mut rng = rand::thread_rng();
+        let x = rng.gen_range(-100.0..100.0);
+        let y = rng.gen_range(0.0..8.0); // Invaders arrive over the surface
+        spawn_invasive_species_entity(&mut commands, x, y, &species_config);
     }
 }
 
-/// Spawn a single invasive species entity at a random location
-fn spawn_invasive_species_entity(commands: &mut Commands) {
-    let mut rng = rand::thread_rng();
-
-    // Spawn at random location within the simulation area
-    let x = rng.gen_range(-400.0..400.0);
-    let y = rng.gen_range(-300.0..300.0);
-
-    let lifetime = rng.gen_range(15.0..25.0); // Live for 15-25 seconds
-    let food_consumption_rate = rng.gen_range(2.0..4.0); // Consume 2-4 food per second
+/// Found an invasive nest at a random spot on the surface
+fn spawn_invasive_nest(commands: &mut Commands, config: &InvasionConfig) {
+    let x = rand::thread_rng().gen_range(-90.0..90.0);
+    let y = 2.0;
 
     commands.spawn((
         SpriteBundle {
             sprite: Sprite {
-                color: Color::srgb(1.0, 0.2, 0.2), // Bright red to distinguish from ants
-                ..default()
-            },
-            transform: Transform {
-                translation: Vec3::new(x, y, 0.0),
-                scale: Vec3::splat(4.0), // Slightly larger than ants for visibility
+                color: Color::srgb(0.5, 0.1, 0.1), // Dark red mound
+                custom_size: Some(Vec2::splat(config.nest_radius * 2.0)),
                 ..default()
             },
+            transform: Transform::from_translation(Vec3::new(x, y, z_for(RenderLayer::Invader))),
             ..default()
         },
         Position { x, y },
-        InvasiveSpecies {
-            lifetime,
-            food_consumption_rate,
+        InvasiveNest {
+            spawn_timer: 0.0,
+            spawn_interval: config.nest_spawn_interval,
+            health: config.nest_health,
         },
     ));
 
+    info!("Invasive species founded a nest at ({:.1}, {:.1})", x, y);
+}
+
+/// System letting invasive nests release invaders at a quickening pace
+///
+/// Every invader shortens the wait for the next one, down to the configured minimum,
+/// so pressure on the colony escalates the longer the nest survives. A nest whose health
+/// runs out is destroyed; the invaders it already released stay until they leave or lose.
+pub fn invasive_nest_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Option<Res<InvasionConfig>>,
+    species_config: Option<Res<InvasiveSpeciesConfig>>,
+    mut nest_query: Query<(Entity, &Position, &mut InvasiveNest)>,
+    invader_query: Query<&InvasiveSpecies>,
+) {
+    let config = config.map(|config| config.clone()).unwrap_or_default();
+    let species_config = species_config
+        .map(|config| config.clone())
+        .unwrap_or_default();
+    let mut invader_count = invader_query.iter().count();
+
+    for (nest_entity, position, mut nest) in nest_query.iter_mut() {
+        if nest.health <= 0.0 {
+            info!("The colony destroyed the invasive nest");
+            commands.entity(nest_entity).despawn();
+            continue;
+        }
+
+        nest.spawn_timer += time.delta_seconds();
+        if nest.spawn_timer < nest.spawn_interval || invader_count >= species_config.spawn_count {
+            continue;
+        }
+
+        let mut rng = rand::thread_rng();
+        let x = position.x + rng.gen_range(-2.0..2.0);
+        let y = position.y + rng.gen_range(-2.0..2.0);
+        let invader = spawn_invasive_species_entity(&mut commands, x, y, &species_config);
+        commands
+            .entity(invader)
+            .insert(NestInvader { nest: nest_entity });
+        invader_count += 1;
+
+        nest.spawn_timer = 0.0;
+        nest.spawn_interval = (nest.spawn_interval * config.nest_spawn_acceleration)
+            .max(config.min_nest_spawn_interval);
+    }
+}
+
+/// Spawn a single invasive species entity at the given location
+fn spawn_invasive_species_entity(
+    commands: &mut Commands,
+    x: f32,
+    y: f32,
+    species_config: &InvasiveSpeciesConfig,
+) -> Entity {
+    let mut rng = rand::thread_rng();
+
+    let (min_lifetime, max_lifetime) = species_config.lifetime_range;
+    let lifetime = rng.gen_range(min_lifetime..=max_lifetime);
+    let (min_consumption, max_consumption) = species_config.consumption_range;
+    let food_consumption_rate = rng.gen_range(min_consumption..=max_consumption);
+
+    let entity = commands
+        .spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgb(1.0, 0.2, 0.2), // Bright red to distinguish from ants
+                    custom_size: Some(Vec2::new(4.0, 4.0)), // Larger than ants for visibility
+                    ..default()
+                },
+                transform: Transform::from_translation(Vec3::new(
+                    x,
+                    y,
+                    z_for(RenderLayer::Invader),
+                )),
+                ..default()
+            },
+            Position { x, y },
+            InvasiveSpecies {
+                lifetime,
+                food_consumption_rate,
+                strength: 2.0,
+            },
+        ))
+        .id();
+
     info!(
         "Spawned invasive species at ({:.1}, {:.1}) with {:.1}s lifetime",
         x, y, lifetime
     );
+    entity
 }
 
 /// System to manage invasive species behavior and lifecycle
+///
+/// Invaders from a nest head for the nearest food source, where they compete with the
+/// colony's foragers; lone invaders wander erratically.
 pub fn invasive_species_behavior_system(
     mut commands: Commands,
     time: Res<Time>,
-    mut invasive_query: Query<(Entity, &mut Position, &mut InvasiveSpecies, &mut Transform)>,
+    species_config: Option<Res<InvasiveSpeciesConfig>>,
+    mut invasive_query: Query<(
+        Entity,
+        &mut Position,
+        &mut InvasiveSpecies,
+        &mut Transform,
+        Has<NestInvader>,
+    )>,
     mut food_query: Query<(&Position, &mut FoodSource), Without<InvasiveSpecies>>,
 ) {
     let delta_time = time.delta_seconds();
-    let mut entities_to_despawn = Vec::new();
+    let mut rng = rand::thread_rng();
+    let movement_speed = species_config
+        .map_or(InvasiveSpeciesConfig::default().movement_speed, |config| {
+            config.movement_speed
+        });
 
-    for (entity, mut position, mut invasive_species, mut transform) in invasive_query.iter_mut() {
+    for (entity, mut position, mut invasive_species, mut transform, from_nest) in
+        invasive_query.iter_mut()
+    {
         // Update lifetime
         invasive_species.lifetime -= delta_time;
 
         // Despawn if lifetime expired
         if invasive_species.lifetime <= 0.0 {
-            entities_to_despawn.push(entity);
+            commands.entity(entity).despawn();
             continue;
         }
 
-        // Random movement behavior - invasive species move in random patterns
-        let mut rng = rand::thread_rng();
-        let movement_speed = 50.0; // pixels per second
-
-        let direction_x = rng.gen_range(-1.0..1.0);
-        let direction_y = rng.gen_range(-1.0..1.0);
+        // Random movement behavior - invasive species wander erratically
+        position.x += rng.gen_range(-1.0..1.0) * movement_speed * delta_time;
+        position.y += rng.gen_range(-1.0..1.0) * movement_speed * delta_time;
 
-        position.x += direction_x * movement_speed * delta_time;
-        position.y += direction_y * movement_speed * delta_time;
+        // Nest invaders forage: drift toward the nearest food that is left
+        if from_nest {
+            let nearest_food = food_query
+                .iter()
+                .filter(|(_, food)| food.nutrition_value > 0.0)
+                .map(|(food_position, _)| {
+                    Vec2::new(food_position.x - position.x, food_position.y - position.y)
+                })
+                .min_by(|a, b| a.length_squared().total_cmp(&b.length_squared()));
+            if let Some(offset) = nearest_food {
+                let step = offset.clamp_length_max(movement_speed * 0.5 * delta_time);
+                position.x += step.x;
+                position.y += step.y;
+            }
+        }
 
         // Keep within simulation bounds
-        position.x = position.x.clamp(-400.0, 400.0);
-        position.y = position.y.clamp(-300.0, 300.0);
+        position.x = position.x.clamp(-100.0, 100.0);
+        position.y = position.y.clamp(-80.0, 10.0);
 
         // Update visual transform
         transform.translation.x = position.x;
@@ -115,95 +249,121 @@ pub fn invasive_species_behavior_system(
                 + (position.y - food_position.y).powi(2))
             .sqrt();
 
-            // If close enough to food source (within 30 pixels)
-            if distance < 30.0 && food_source.is_available {
+            if distance < 10.0 {
                 let consumption = invasive_species.food_consumption_rate * delta_time;
-                food_source.nutrition_value -= consumption;
-
-                // Deplete food source if consumed too much
-                if food_source.nutrition_value <= 0.0 {
-                    food_source.is_available = false;
-                    food_source.regeneration_timer = food_source.regeneration_time * 2.0; // Longer regen time
-                    info!(
-                        "Invasive species depleted food source at ({:.1}, {:.1})",
-                        food_position.x, food_position.y
-                    );
-                }
+                food_source.nutrition_value = (food_source.nutrition_value - consumption).max(0.0);
             }
         }
     }
+}
 
-    // Despawn expired invasive species
-    for entity in entities_to_despawn {
-        commands.entity(entity).despawn();
+/// Chance that an ant of the given strength beats an invader
+pub fn fight_win_probability(ant_strength: f32, invader_strength: f32) -> f32 {
+    let total = ant_strength + invader_strength;
+    if total <= 0.0 {
+        return 0.5;
     }
+    ant_strength / total
 }
 
-/// System to influence ant behavior when invasive species are present
-pub fn ant_defensive_behavior_system(
-    invasive_query: Query<&Position, (With<InvasiveSpecies>, Without<Ant>)>,
-    mut ant_query: Query<(&Position, &mut AntBehavior, &mut Lifecycle), With<Ant>>,
+/// Resolve a single fight; returns true if the ant wins
+pub fn resolve_fight(ant_strength: f32, invader_strength: f32, rng: &mut impl Rng) -> bool {
+    rng.gen::<f32>() < fight_win_probability(ant_strength, invader_strength)
+}
+
+/// System letting ants fight invaders they run into
+///
+/// Winning ants drive the invader off; losing ants are hurt. Caste sets fighting strength,
+/// and the invaders' aggression how often a contact turns into a fight.
+/// Ants in contact with an invasive nest wear down its health. With a [`SpatialGrid`]
+/// only ants in nearby cells are considered, which keeps crowded food sources cheap.
+pub fn invader_combat_system(
+    mut commands: Commands,
     time: Res<Time>,
+    config: Option<Res<InvasionConfig>>,
+    species_config: Option<Res<InvasiveSpeciesConfig>>,
+    spatial_grid: Option<Res<SpatialGrid>>,
+    invasive_query: Query<(Entity, &Position, &InvasiveSpecies)>,
+    mut nest_query: Query<(&Position, &mut InvasiveNest)>,
+    mut ant_query: Query<
+        (Entity, &Position, Option<&Caste>, Option<&mut Health>),
+        (With<Ant>, Without<Dying>),
+    >,
 ) {
+    let mut rng = rand::thread_rng();
     let delta_time = time.delta_seconds();
+    let aggression = species_config.map_or(InvasiveSpeciesConfig::default().aggression, |config| {
+        config.aggression
+    });
+    let fight_chance = (aggression * delta_time).min(1.0);
 
-    // Only apply defensive behavior if invasive species are present
-    if invasive_query.is_empty() {
-        return;
-    }
+    // Without a grid every ant is a candidate
+    let all_ants: Vec<Entity> = if spatial_grid.is_none() {
+        ant_query.iter().map(|(entity, ..)| entity).collect()
+    } else {
+        Vec::new()
+    };
+    let nearby_ants = |position: &Position, radius: f32| -> Vec<Entity> {
+        spatial_grid.as_ref().map_or_else(
+            || all_ants.clone(),
+            |grid| grid.get_nearby_entities(position, radius),
+        )
+    };
 
-    for (ant_position, mut ant_behavior, mut lifecycle) in ant_query.iter_mut() {
-        let mut nearest_invasive_distance = f32::INFINITY;
+    for (invader, invader_position, invasive_species) in invasive_query.iter() {
+        for candidate in nearby_ants(invader_position, COMBAT_CONTACT_RADIUS) {
+            let Ok((_, ant_position, caste, health)) = ant_query.get_mut(candidate) else {
+                continue;
+            };
+            let dx = ant_position.x - invader_position.x;
+            let dy = ant_position.y - invader_position.y;
+            if dx * dx + dy * dy > COMBAT_CONTACT_RADIUS * COMBAT_CONTACT_RADIUS
+                || rng.gen::<f32>() >= fight_chance
+            {
+                continue;
+            }
 
-        // Find the nearest invasive species
-        for invasive_position in invasive_query.iter() {
-            let distance = ((ant_position.x - invasive_position.x).powi(2)
-                + (ant_position.y - invasive_position.y).powi(2))
-            .sqrt();
-            nearest_invasive_distance = nearest_invasive_distance.min(distance);
+            let ant_strength = caste.map_or(1.0, |caste| caste.size_class.combat_strength());
+            if resolve_fight(ant_strength, invasive_species.strength, &mut rng) {
+                commands.entity(invader).despawn();
+                break;
+            } else if let Some(mut health) = health {
+                health.damage(DEFEAT_DAMAGE);
+            }
         }
+    }
 
-        // If invasive species is close (within 80 pixels), trigger defensive behavior
-        if nearest_invasive_distance < 80.0 {
-            // Increase energy consumption due to stress
-            lifecycle.energy -= 1.5 * delta_time;
-
-            // Modify behavior to be more clustered/defensive
-            match ant_behavior.state {
-                AntState::Foraging => {
-                    // Chance to switch to resting (defensive clustering)
-                    if rand::thread_rng().gen::<f32>() < 0.3 * delta_time {
-                        ant_behavior.state = AntState::Resting;
-                        ant_behavior.target_position = None;
-                    }
-                }
-                AntState::Returning => {
-                    // Speed up return to nest when invasive species nearby
-                    ant_behavior.speed *= 1.3;
-                }
-                _ => {}
+    let config = config.map(|config| config.clone()).unwrap_or_default();
+    for (nest_position, mut nest) in nest_query.iter_mut() {
+        for candidate in nearby_ants(nest_position, config.nest_radius) {
+            let Ok((_, ant_position, caste, _)) = ant_query.get(candidate) else {
+                continue;
+            };
+            let dx = ant_position.x - nest_position.x;
+            let dy = ant_position.y - nest_position.y;
+            if dx * dx + dy * dy <= config.nest_radius * config.nest_radius {
+                let ant_strength = caste.map_or(1.0, |caste| caste.size_class.combat_strength());
+                nest.health -= config.nest_damage_rate * ant_strength * delta_time;
             }
         }
     }
 }
 
-/// System to clean up invasive species when disaster ends
+/// System to clean up invasive species and their nests when disaster ends
 pub fn invasive_species_cleanup_system(
     mut commands: Commands,
     disaster_state: Res<DisasterState>,
-    invasive_query: Query<Entity, With<InvasiveSpecies>>,
+    invasive_query: Query<Entity, Or<(With<InvasiveSpecies>, With<InvasiveNest>)>>,
 ) {
     // If invasive species disaster is not active, despawn all invasive species
-    if !disaster_state.is_active(DisasterType::InvasiveSpecies) {
+    if !disaster_state.is_active(DisasterType::InvasiveSpecies) && !invasive_query.is_empty() {
+        info!(
+            "Cleaned up {} invasive species entities after disaster ended",
+            invasive_query.iter().count()
+        );
+
         for entity in invasive_query.iter() {
             commands.entity(entity).despawn();
         }
-
-        if !invasive_query.is_empty() {
-            info!(
-                "Cleaned up {} invasive species entities after disaster ended",
-                invasive_query.iter().count()
-            );
-        }
     }
 }
@@ -1,28 +1,235 @@
-use crate::components::{AntBehavior, Lifecycle};
+use crate::components::{
+    AntAgeGroup, AntBehavior, AntState, CarryingCapacity, Corpse, DeathBehavior, Dying, EventLog,
+    Health, Lifecycle, PendingDespawn, PhaseSpecificBehavior, Position, Queen, SoilCell,
+    SpecializedRole,
+};
+use crate::systems::rendering::{z_for, RenderLayer};
 use bevy::prelude::*;
+use rand::prelude::*;
 
-/// System for ant aging and energy management
+/// Energy every ant burns per second under normal conditions
+const BASE_ENERGY_DRAIN: f32 = 0.05;
+
+/// HP a resting ant recovers per second
+const RESTING_HP_REGEN: f32 = 2.0;
+
+/// HP an active ant recovers per second by grooming
+const GROOMING_HP_REGEN: f32 = 0.2;
+
+/// Seconds a corpse takes to decay
+const CORPSE_DECAY_TIME: f32 = 60.0;
+
+/// Soil nutrition a fully decayed corpse returns
+const CORPSE_NUTRIENTS: f32 = 0.1;
+
+/// System for ant aging, hunger, recovery and death
+///
+/// An ant dies of old age, when its energy runs out (starvation) or when its HP runs out
+/// (injury). Injured ants slowly heal, much faster while resting. Dead ants go to the
+/// `PendingDespawn` queue when the app has one, and are despawned immediately otherwise.
+/// Under [`DeathBehavior::Corpse`] a corpse is left where the ant died.
 pub fn ant_lifecycle_system(
     time: Res<Time>,
     mut commands: Commands,
-    mut ant_query: Query<(Entity, &mut Lifecycle), With<AntBehavior>>,
+    carrying_capacity: Res<CarryingCapacity>,
+    death_behavior: Option<Res<DeathBehavior>>,
+    mut pending_despawn: Option<ResMut<PendingDespawn>>,
+    mut ant_query: Query<(
+        Entity,
+        &mut Lifecycle,
+        Option<&mut Health>,
+        Option<&AntBehavior>,
+        Option<&Position>,
+    )>,
 ) {
     let delta_time = time.delta_seconds();
+    let death_behavior = death_behavior.map_or(DeathBehavior::default(), |behavior| *behavior);
+
+    for (entity, mut lifecycle, mut health, behavior, position) in ant_query.iter_mut() {
+        // Already dead and waiting for cleanup
+        if pending_despawn
+            .as_ref()
+            .is_some_and(|pending| pending.contains(entity))
+        {
+            continue;
+        }
 
-    for (entity, mut lifecycle) in ant_query.iter_mut() {
         // Age the ant
         lifecycle.age += delta_time;
 
-        // Decrease energy over time (much slower for debugging)
-        lifecycle.energy -= 0.2 * delta_time;
+        // Burn energy, faster while the colony is over its carrying capacity
+        lifecycle.energy -=
+            BASE_ENERGY_DRAIN * carrying_capacity.energy_drain_multiplier * delta_time;
+
+        // Wounds heal unless the ant has already succumbed to them
+        let injured_to_death = health.as_ref().is_some_and(|health| health.is_dead());
+        if let Some(health) = health.as_mut().filter(|_| !injured_to_death) {
+            let resting = behavior.is_some_and(|behavior| behavior.state == AntState::Resting);
+            let regen = if resting {
+                RESTING_HP_REGEN
+            } else {
+                GROOMING_HP_REGEN
+            };
+            if health.hp < health.max_hp {
+                health.heal(regen * delta_time);
+            }
+        }
 
         // Check if ant should die
-        if lifecycle.age >= lifecycle.max_age || lifecycle.energy <= 0.0 {
-            commands.entity(entity).despawn();
+        if lifecycle.age >= lifecycle.max_age || lifecycle.energy <= 0.0 || injured_to_death {
+            kill_ant(
+                &mut commands,
+                pending_despawn.as_deref_mut(),
+                death_behavior,
+                entity,
+                position,
+            );
             info!(
-                "Ant died at age {:.1}s with {:.1} energy",
-                lifecycle.age, lifecycle.energy
+                "Ant died at age {:.1}s with {:.1} energy and {:.1} HP",
+                lifecycle.age,
+                lifecycle.energy,
+                health.map_or(0.0, |health| health.hp)
             );
         }
     }
 }
+
+/// System healing wounded queens and killing those whose HP runs out
+///
+/// Queens have no [`Lifecycle`], so [`ant_lifecycle_system`] never sees them; here the
+/// wounds invaders deal a queen heal by grooming, or kill her once her HP is gone.
+pub fn queen_health_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut pending_despawn: Option<ResMut<PendingDespawn>>,
+    mut event_log: Option<ResMut<EventLog>>,
+    mut queen_query: Query<(Entity, &mut Health), (With<Queen>, Without<Lifecycle>)>,
+) {
+    let delta_time = time.delta_seconds();
+
+    for (queen, mut health) in queen_query.iter_mut() {
+        if pending_despawn
+            .as_ref()
+            .is_some_and(|pending| pending.contains(queen))
+        {
+            continue;
+        }
+
+        if !health.is_dead() {
+            if health.hp < health.max_hp {
+                health.heal(GROOMING_HP_REGEN * delta_time);
+            }
+            continue;
+        }
+
+        match pending_despawn.as_mut() {
+            Some(pending) => {
+                pending.enqueue(queen);
+                commands.entity(queen).insert(Dying);
+            }
+            None => commands.entity(queen).despawn_recursive(),
+        }
+        info!("A queen died of her wounds");
+        if let Some(event_log) = event_log.as_mut() {
+            event_log.push("The queen died of her wounds");
+        }
+    }
+}
+
+/// Remove a dead ant, leaving a corpse under [`DeathBehavior::Corpse`]
+///
+/// The ant goes to the `PendingDespawn` queue, marked [`Dying`], when there is one, and is
+/// despawned immediately otherwise.
+pub(crate) fn kill_ant(
+    commands: &mut Commands,
+    pending_despawn: Option<&mut PendingDespawn>,
+    death_behavior: DeathBehavior,
+    entity: Entity,
+    position: Option<&Position>,
+) {
+    match pending_despawn {
+        Some(pending) => {
+            pending.enqueue(entity);
+            commands.entity(entity).insert(Dying);
+        }
+        None => commands.entity(entity).despawn_recursive(),
+    }
+    if let (DeathBehavior::Corpse, Some(position)) = (death_behavior, position) {
+        spawn_corpse(commands, position);
+    }
+}
+
+/// Leave a corpse at the spot where an ant died
+fn spawn_corpse(commands: &mut Commands, position: &Position) {
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgb(0.3, 0.25, 0.2), // Dull brown, like a dried husk
+                custom_size: Some(Vec2::splat(1.5)),
+                ..default()
+            },
+            transform: Transform::from_translation(Vec3::new(
+                position.x,
+                position.y,
+                z_for(RenderLayer::Food),
+            )),
+            ..default()
+        },
+        position.clone(),
+        Corpse {
+            decay_remaining: CORPSE_DECAY_TIME,
+            nutrients: CORPSE_NUTRIENTS,
+        },
+    ));
+}
+
+/// System decaying corpses, returning their nutrients to the nearest soil cell
+pub fn corpse_decay_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut corpse_query: Query<(Entity, &Position, &mut Corpse)>,
+    mut soil_query: Query<(&Position, &mut SoilCell)>,
+) {
+    for (entity, position, mut corpse) in corpse_query.iter_mut() {
+        corpse.decay_remaining -= time.delta_seconds();
+        if corpse.decay_remaining > 0.0 {
+            continue;
+        }
+
+        let nearest_soil = soil_query.iter_mut().min_by(|(a, _), (b, _)| {
+            let distance_a = (a.x - position.x).powi(2) + (a.y - position.y).powi(2);
+            let distance_b = (b.x - position.x).powi(2) + (b.y - position.y).powi(2);
+            distance_a.total_cmp(&distance_b)
+        });
+        if let Some((_, mut soil_cell)) = nearest_soil {
+            soil_cell.nutrition = (soil_cell.nutrition + corpse.nutrients).min(1.0);
+        }
+        commands.entity(entity).despawn();
+    }
+}
+
+/// System to update ant age groups based on their lifecycle
+pub fn update_ant_age_groups_system(
+    mut ant_query: Query<(
+        &mut PhaseSpecificBehavior,
+        &Lifecycle,
+        Option<&mut SpecializedRole>,
+    )>,
+) {
+    let mut rng = thread_rng();
+
+    for (mut behavior, lifecycle, role) in ant_query.iter_mut() {
+        let age_ratio = lifecycle.age / lifecycle.max_age;
+        let age_group = AntAgeGroup::from_age_ratio(age_ratio);
+
+        // Avoid triggering change detection when the group is unchanged
+        if behavior.age_group != age_group {
+            behavior.age_group = age_group;
+
+            // Workers take on tasks suited to their new age
+            if let Some(mut role) = role {
+                *role = SpecializedRole::random_for_age(age_group, &mut rng);
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::components::{Ant, AntLodConfig, DensityBlob, Dying, SpatialGrid};
+use crate::systems::rendering::{z_for, RenderLayer};
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+/// System switching between per-ant sprites and one density blob per occupied grid cell
+///
+/// Blobs are used while the population is above the configured threshold or the camera
+/// is zoomed far out. Individual ant sprites are hidden meanwhile, so the number of ant
+/// sprites drawn is bounded by the occupied cells of the [`SpatialGrid`].
+pub fn ant_lod_system(
+    mut commands: Commands,
+    mut config: ResMut<AntLodConfig>,
+    spatial_grid: Res<SpatialGrid>,
+    camera_query: Query<&OrthographicProjection, With<Camera>>,
+    mut ant_query: Query<&mut Visibility, (With<Ant>, Without<DensityBlob>, Without<Dying>)>,
+    mut blob_query: Query<(Entity, &DensityBlob, &mut Sprite)>,
+) {
+    let population = ant_query.iter().count();
+    let camera_scale = camera_query
+        .get_single()
+        .map_or(1.0, |projection| projection.scale);
+    let aggregated = config.should_aggregate(population, camera_scale);
+    if aggregated != config.aggregated {
+        config.aggregated = aggregated;
+        info!(
+            "Drawing {} ants {}",
+            population,
+            if aggregated {
+                "as density blobs"
+            } else {
+                "individually"
+            }
+        );
+    }
+
+    let ant_visibility = if aggregated {
+        Visibility::Hidden
+    } else {
+        Visibility::Inherited
+    };
+    for mut visibility in ant_query.iter_mut() {
+        visibility.set_if_neq(ant_visibility);
+    }
+
+    let mut drawn_cells = HashSet::new();
+    for (entity, blob, mut sprite) in blob_query.iter_mut() {
+        let count = spatial_grid.grid.get(&blob.cell).map_or(0, Vec::len);
+        if !aggregated || count == 0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let size = config.blob_size(count, spatial_grid.cell_size);
+        sprite.custom_size = Some(Vec2::splat(size));
+        drawn_cells.insert(blob.cell);
+    }
+    if !aggregated {
+        return;
+    }
+
+    for (&cell, ants) in spatial_grid.grid.iter() {
+        if ants.is_empty() || drawn_cells.contains(&cell) {
+            continue;
+        }
+        let center = spatial_grid.cell_center(cell);
+        let size = config.blob_size(ants.len(), spatial_grid.cell_size);
+        commands.spawn((
+            DensityBlob { cell },
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(0.0, 0.0, 0.0, 0.7),
+                    custom_size: Some(Vec2::splat(size)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(center.x, center.y, z_for(RenderLayer::Ant)),
+                ..default()
+            },
+        ));
+    }
+}
@@ -1,15 +1,171 @@
 //! MVP Systems Module
 //!
 //! Simplified systems for core ant nest simulation:
-//! - Environment: Basic soil environmental simulation
-//! - Movement: Simple ant movement with gravity and digging
+//! - Age visuals: Subtle age-group tinting of worker ants
+//! - Alarm: Alarm pheromone released at invaders, recruiting defenders
+//! - Camera: Keyboard pan and zoom kept within the world, with a reset key
+//! - Carry visuals: Tint and dot showing the kind of food an ant carries
+//! - Chambers: Nest chambers, their occupancy and microclimate, and role-based destinations
+//! - Cleanup: Budgeted despawning of dead entities
+//! - Color overlay: Disaster and day/night tints composed into one screen overlay, and the themed world background
+//! - Colony comparison: Side-by-side population, phase, food and age of each colony, sortable (F11/F12)
+//! - Colony development: Four-phase colony development progression and time-to-next-phase estimates
+//! - Colony founding: Queen spawning, co-founding queens, founding success or failure and trait inheritance between colonies
+//! - Colony statistics: Per-frame colony-wide figures
+//! - Disasters: Triggered and forecast automatic disasters, their environmental effects and combinations
+//! - Disaster UI: Disaster shortcuts and the active disasters panel with its forecast line
+//! - Disease: Adult disease breaking out in crowded spots, cleared by grooming in chambers
+//! - End conditions: Automatic end of a run on a set day, extinction or phase, with a summary panel
+//! - Environment: Soil environmental simulation and neighbor diffusion
+//! - Food handoff: Returning foragers passing their load to storage workers at chamber entrances
+//! - Food reserve: Dismissible warning banner while the colony's food store is critically low
+//! - Food spawning: Food sprouting above fertile soil and withering above depleted soil
+//! - Foraging: Food sources, scouts and trail followers, pheromones, antennation, activity schedule, night shelter
+//! - Help overlay: Full-screen keyboard shortcut list built from the key bindings (F1)
+//! - Habitat: Humidity comfort drift of ants inside the nest
+//! - Invasive species: Invaders and their nests during the invasive species disaster, and fights with ants
+//! - Lifecycle: Ant aging, age groups, natural death and corpse decay
+//! - LOD: Density blobs instead of ant sprites for large or distant colonies
+//! - Movement: Simple ant movement with gravity and digging, and the queen kept to her chamber
+//! - Naming: Ids and names for workers, renaming, and camera follow of a selected ant (F)
+//! - Nest planning: New chamber sites when chambers fill up, and their excavation
+//! - Nest render: Chambers as filled circles and tunnels as segments between their nodes
+//! - Particle: Disaster weather particles with configurable gravity
+//! - Performance monitoring: FPS/entity-count panel and compact HUD
+//! - Persistence: Auto-save and manual save slot management
+//! - Pheromone debug: Gizmo overlay of trail pheromone strength per grid cell (F6)
+//! - Population: Prioritized feeding of queen, brood and workers from the colony store, and carrying-capacity starvation
+//! - Profiling: Per-section frame timings and their bar overlay (F7)
+//! - Reproduction: Queen egg laying and temperature-dependent incubation
 //! - Rendering: Basic world setup and entity spawning
+//! - Reset: New game in place, optionally with a fixed seed
+//! - Role transitions: Role change events, their log entries and a brief flash on the ant
+//! - Selection: Drag selection of ants with a summary panel, cleared with Esc
+//! - Settings UI: Settings panel with its toggle button and S shortcut
+//! - Simulation time: Day clock used by circadian behavior
+//! - Soil color: Realistic soil tint from moisture and nutrition, with a heatmap override
+//! - Spatial grid: Shared ant lookup grid rebuilt after movement, re-bucketed when its cell size changes
+//! - Stress spawn: Bulk ant and food spawning for stress tests and benches (F9/F10)
+//! - Statistics UI: Colony statistics panel with collapsible sections (F4)
+//! - Theme: UI theme import and export, and live recoloring when the theme changes
+//! - Territory: Colony ownership of spatial grid cells and its color-coded overlay (F8)
+//! - Time control: Pause, speed shortcuts, the uncapped turbo mode and the FPS auto-throttle
+//! - Tooltip: Hover tooltips for UI elements and world entities
+//! - Trophallaxis: Well-fed ants sharing energy with starving nestmates on contact
+//! - Tunnel drawing: Click-drag tunnel sketching and double-click chambers (B)
+//! - UI assets: UI font loading with fallbacks, deferring panel setup until ready
+//! - UI layout: Window-size-aware anchoring of UI panels and dragging them by their title bars
+//! - Visual effects toggle: Accessibility switch for optional visual effects
 
+pub mod age_visuals;
+pub mod alarm;
+pub mod camera;
+pub mod carry_visuals;
+pub mod chambers;
+pub mod cleanup;
+pub mod colony_comparison;
+pub mod colony_development;
+pub mod colony_founding;
+pub mod colony_statistics;
+pub mod color_overlay;
+pub mod disaster_ui;
+pub mod disasters;
+pub mod disease;
+pub mod end_conditions;
 pub mod environment;
+pub mod food_handoff;
+pub mod food_reserve;
+pub mod food_spawning;
+pub mod foraging;
+pub mod habitat;
+pub mod help_overlay;
+pub mod invasive_species;
+pub mod lifecycle;
+pub mod lod;
 pub mod movement;
+pub mod naming;
+pub mod nest_planning;
+pub mod nest_render;
+pub mod particle;
+pub mod performance_monitoring;
+pub mod persistence;
+pub mod pheromone_debug;
+pub mod population;
+pub mod profiling;
 pub mod rendering;
+pub mod reproduction;
+pub mod reset;
+pub mod role_transitions;
+pub mod selection;
+pub mod settings_ui;
+pub mod simulation_time;
+pub mod soil_color;
+pub mod spatial_grid;
+pub mod statistics_ui;
+pub mod stress_spawn;
+pub mod territory;
+pub mod theme;
+pub mod time_control;
+pub mod tooltip;
+pub mod trophallaxis;
+pub mod tunnel_drawing;
+pub mod ui_assets;
+pub mod ui_layout;
+pub mod visual_effects_toggle;
 
 // Re-export only MVP system functions
+pub use age_visuals::*;
+pub use alarm::*;
+pub use camera::*;
+pub use carry_visuals::*;
+pub use chambers::*;
+pub use cleanup::*;
+pub use colony_comparison::*;
+pub use colony_development::*;
+pub use colony_founding::*;
+pub use colony_statistics::*;
+pub use color_overlay::*;
+pub use disaster_ui::*;
+pub use disasters::*;
+pub use disease::*;
+pub use end_conditions::*;
 pub use environment::*;
+pub use food_handoff::*;
+pub use food_reserve::*;
+pub use food_spawning::*;
+pub use foraging::*;
+pub use habitat::*;
+pub use help_overlay::*;
+pub use invasive_species::*;
+pub use lifecycle::*;
+pub use lod::*;
 pub use movement::*;
+pub use naming::*;
+pub use nest_planning::*;
+pub use nest_render::*;
+pub use particle::*;
+pub use performance_monitoring::*;
+pub use persistence::*;
+pub use pheromone_debug::*;
+pub use population::*;
+pub use profiling::*;
 pub use rendering::*;
+pub use reproduction::*;
+pub use reset::*;
+pub use role_transitions::*;
+pub use selection::*;
+pub use settings_ui::*;
+pub use simulation_time::*;
+pub use soil_color::*;
+pub use spatial_grid::*;
+pub use statistics_ui::*;
+pub use stress_spawn::*;
+pub use territory::*;
+pub use theme::*;
+pub use time_control::*;
+pub use tooltip::*;
+pub use trophallaxis::*;
+pub use tunnel_drawing::*;
+pub use ui_assets::*;
+pub use ui_layout::*;
+pub use visual_effects_toggle::*;
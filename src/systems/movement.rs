@@ -1,25 +1,104 @@
-use crate::components::{Ant, FoundingState, Position, Queen, Soil, SoilCell};
+use crate::components::{
+    Ant, AntAiConfig, AntBehavior, AntState, BoundaryMode, ChamberType, CoFoundingQueen,
+    ColonyDevelopmentPhase, DepthLayer, DevelopmentPhase, Dying, FoundingState, NestSpace,
+    Position, Queen, RoyalChamber, Soil, SoilCell, SpatialGrid, WorldConfig,
+};
+use crate::systems::chambers::{spawn_chamber, spawn_chamber_outline};
 use bevy::prelude::*;
 use rand::prelude::*;
 
+/// Top speed of an established queen shuffling around her chamber, per second
+const QUEEN_PACE: f32 = 1.0;
+
+/// Share of the royal chamber's radius the queen wanders within
+const QUEEN_ROAM_FRACTION: f32 = 0.5;
+
+/// Soil moisture that digs most easily: damp enough to hold together, not yet mud
+pub const IDEAL_DIG_MOISTURE: f32 = 0.4;
+
+/// Share of dig speed lost in bone-dry or waterlogged soil
+const MOISTURE_DIG_PENALTY: f32 = 0.6;
+
+/// Share of dig speed lost in the hardest soil
+const HARDNESS_DIG_PENALTY: f32 = 0.7;
+
+/// Relative digging speed (0.0 - 1.0) in soil of the given hardness and moisture
+///
+/// Slightly damp soil digs fastest; speed falls off toward both dry and saturated soil,
+/// and harder layers slow digging further.
+pub fn dig_speed_factor(hardness: f32, moisture: f32) -> f32 {
+    let moisture = moisture.clamp(0.0, 1.0);
+    let deviation = if moisture < IDEAL_DIG_MOISTURE {
+        (IDEAL_DIG_MOISTURE - moisture) / IDEAL_DIG_MOISTURE
+    } else {
+        (moisture - IDEAL_DIG_MOISTURE) / (1.0 - IDEAL_DIG_MOISTURE)
+    };
+    let moisture_factor = 1.0 - MOISTURE_DIG_PENALTY * deviation * deviation;
+    let hardness_factor = 1.0 - HARDNESS_DIG_PENALTY * hardness.clamp(0.0, 1.0);
+    (moisture_factor * hardness_factor).clamp(0.0, 1.0)
+}
+
 /// MVP: Simple ant movement with gravity and basic digging
+///
+/// How readily a soil cell gives way follows [`dig_speed_factor`] for its layer and moisture.
+/// Ants are then kept inside the [`WorldConfig`] bounds according to the
+/// [`BoundaryMode`], with defaults used when either resource is missing. Foraging
+/// targets farther from home than the [`AntAiConfig::foraging_radius`] for the current
+/// [`DevelopmentPhase`] are pulled back onto that radius. Queens move on their own, see
+/// [`queen_founding_system`] and [`queen_movement_system`].
+#[allow(clippy::too_many_arguments)]
 pub fn ant_movement_system(
     mut commands: Commands,
     time: Res<Time>,
-    mut ant_query: Query<(&mut Position, &mut Transform), With<Ant>>,
-    soil_query: Query<(Entity, &Position), (With<Soil>, Without<Ant>)>,
+    mut nest_space: ResMut<NestSpace>,
+    world_config: Option<Res<WorldConfig>>,
+    boundary_mode: Option<Res<BoundaryMode>>,
+    ai_config: Option<Res<AntAiConfig>>,
+    colony_phase: Option<Res<ColonyDevelopmentPhase>>,
+    mut spatial_grid: Option<ResMut<SpatialGrid>>,
+    mut ant_query: Query<
+        (
+            Entity,
+            &mut Position,
+            &mut Transform,
+            Option<&mut AntBehavior>,
+        ),
+        (With<Ant>, Without<Queen>, Without<Dying>),
+    >,
+    soil_query: Query<(Entity, &Position, Option<&SoilCell>), (With<Soil>, Without<Ant>)>,
 ) {
     let mut rng = thread_rng();
     let delta_time = time.delta_seconds();
+    let world_config = world_config
+        .map(|config| config.clone())
+        .unwrap_or_default();
+    let boundary_mode = boundary_mode.map_or(BoundaryMode::default(), |mode| *mode);
+    let phase = colony_phase.map_or(DevelopmentPhase::MatureColony, |phase| phase.current_phase);
+    let foraging_radius = ai_config
+        .map(|config| config.clone())
+        .unwrap_or_default()
+        .foraging_radius(phase);
+
+    for (entity, mut position, mut transform, mut behavior) in ant_query.iter_mut() {
+        // Keep foragers within reach of home
+        if let Some(behavior) = behavior.as_mut() {
+            let pulled_back = behavior
+                .target_position
+                .as_ref()
+                .filter(|_| behavior.state == AntState::Foraging)
+                .and_then(|target| within_range(target, &behavior.home_position, foraging_radius));
+            if pulled_back.is_some() {
+                behavior.target_position = pulled_back;
+            }
+        }
 
-    for (mut position, mut transform) in ant_query.iter_mut() {
         // MVP: Apply gravity - ants fall down unless supported by soil
         let gravity_force = -20.0 * delta_time; // Downward force
         let new_y = position.y + gravity_force;
 
         // Check if there's soil at the new position to stop falling
         let mut can_fall = true;
-        for (_soil_entity, soil_position) in soil_query.iter() {
+        for (_soil_entity, soil_position, _) in soil_query.iter() {
             let dx = soil_position.x - position.x;
             let dy = soil_position.y - new_y;
             let distance = (dx * dx + dy * dy).sqrt();
@@ -32,7 +111,7 @@ pub fn ant_movement_system(
         }
 
         // Apply gravity if ant can fall
-        if can_fall && new_y > -100.0 {
+        if can_fall && new_y > world_config.min_y {
             // Don't fall below a certain depth
             position.y = new_y;
         }
@@ -45,23 +124,38 @@ pub fn ant_movement_system(
 
             position.x += move_x;
             position.y += move_y;
+        }
 
-            // Keep ants within reasonable bounds
-            position.x = position.x.clamp(-100.0, 100.0);
-            position.y = position.y.clamp(-80.0, 20.0);
+        let previous = position.clone();
+        let outside = !(world_config.min_x..=world_config.max_x).contains(&position.x);
+        let target = behavior
+            .filter(|_| outside)
+            .and_then(|behavior| behavior.into_inner().target_position.as_mut());
+        let crossed_edge = boundary_mode.apply(&world_config, &mut position, target);
+        if crossed_edge && boundary_mode == BoundaryMode::Wrap {
+            // Other systems this frame must find the ant on its new side
+            if let Some(spatial_grid) = spatial_grid.as_mut() {
+                spatial_grid.remove_entity(entity, &previous);
+                spatial_grid.insert_entity(entity, &position);
+            }
         }
 
         // MVP: Basic digging - remove soil that ants walk through
         if rng.gen_bool(0.05) {
             // 5% chance to dig each frame
-            for (soil_entity, soil_position) in soil_query.iter() {
+            for (soil_entity, soil_position, soil) in soil_query.iter() {
                 let dx = soil_position.x - position.x;
                 let dy = soil_position.y - position.y;
                 let distance = (dx * dx + dy * dy).sqrt();
 
-                // If ant is very close to soil, dig it out
+                // If ant is very close to soil, try to dig it out
                 if distance < 3.0 {
-                    commands.entity(soil_entity).despawn();
+                    let hardness = DepthLayer::from_y(soil_position.y).hardness();
+                    let moisture = soil.map_or(IDEAL_DIG_MOISTURE, |soil| soil.moisture);
+                    if rng.gen_bool(dig_speed_factor(hardness, moisture) as f64) {
+                        commands.entity(soil_entity).despawn();
+                        nest_space.excavated_cells += 1;
+                    }
                     break; // Only dig one soil cell at a time
                 }
             }
@@ -73,15 +167,34 @@ pub fn ant_movement_system(
     }
 }
 
+/// Point on the circle of `radius` around `home` toward `target`, if `target` lies beyond it
+fn within_range(target: &Position, home: &Position, radius: f32) -> Option<Position> {
+    let offset = Vec2::new(target.x - home.x, target.y - home.y);
+    if offset.length() <= radius {
+        return None;
+    }
+    let offset = offset.clamp_length_max(radius);
+    Some(Position {
+        x: home.x + offset.x,
+        y: home.y + offset.y,
+    })
+}
+
 /// Queen ant founding behavior system
+///
+/// A [`CoFoundingQueen`] does not found a nest of her own; she waits to join her founder.
 pub fn queen_founding_system(
     mut commands: Commands,
-    mut queen_query: Query<(&mut Queen, &mut Position, &mut Transform), With<Ant>>,
+    mut nest_space: ResMut<NestSpace>,
+    mut queen_query: Query<
+        (Entity, &mut Queen, &mut Position, &mut Transform),
+        (With<Ant>, Without<CoFoundingQueen>),
+    >,
     soil_query: Query<(Entity, &Position, &SoilCell), (With<Soil>, Without<Ant>)>,
 ) {
     let mut rng = thread_rng();
 
-    for (mut queen, mut position, mut transform) in queen_query.iter_mut() {
+    for (queen_entity, mut queen, mut position, mut transform) in queen_query.iter_mut() {
         match queen.founding_state {
             FoundingState::Seeking => {
                 // Queen seeks optimal founding location
@@ -126,6 +239,8 @@ pub fn queen_founding_system(
                     }
                 }
 
+                nest_space.excavated_cells += dug_count;
+
                 if dug_count > 0 {
                     info!("Queen dug {} soil cells for founding chamber", dug_count);
                 }
@@ -135,18 +250,38 @@ pub fn queen_founding_system(
 
                 // Move queen slightly underground into the chamber
                 position.y -= 8.0; // Move down into the dug chamber
+
+                // Record the royal chamber so it can be inspected, and confine her to it
+                let radius = 6.0;
+                let chamber = spawn_chamber(
+                    &mut commands,
+                    position.clone(),
+                    ChamberType::Queen,
+                    radius,
+                    4,
+                );
+                spawn_chamber_outline(&mut commands, chamber, ChamberType::Queen, radius);
+                commands.entity(queen_entity).insert(RoyalChamber {
+                    center: position.clone(),
+                    radius,
+                });
+
+                // Dig a nursery beside it for the brood
+                let nursery_position = Position {
+                    x: position.x + 12.0,
+                    y: position.y,
+                };
+                spawn_chamber(
+                    &mut commands,
+                    nursery_position,
+                    ChamberType::Nursery,
+                    6.0,
+                    8,
+                );
             }
 
             FoundingState::Established => {
-                // Queen stays in chamber - minimal movement
-                if rng.gen_bool(0.02) {
-                    // Very small movements within chamber
-                    let move_x = rng.gen_range(-2.0..2.0);
-                    let move_y = rng.gen_range(-2.0..2.0);
-
-                    position.x += move_x;
-                    position.y += move_y;
-                }
+                // Settled in; queen_movement_system takes over
             }
         }
 
@@ -156,6 +291,35 @@ pub fn queen_founding_system(
     }
 }
 
+/// System letting an established queen shuffle slowly around her royal chamber
+///
+/// She never strays beyond part of the chamber's radius, so she stays where the
+/// workers expect her and keeps laying eggs there.
+pub fn queen_movement_system(
+    time: Res<Time>,
+    mut queen_query: Query<(&Queen, &RoyalChamber, &mut Position, &mut Transform), Without<Dying>>,
+) {
+    let mut rng = thread_rng();
+    let delta_time = time.delta_seconds();
+
+    for (queen, chamber, mut position, mut transform) in queen_query.iter_mut() {
+        if queen.founding_state != FoundingState::Established {
+            continue;
+        }
+
+        let center = Vec2::new(chamber.center.x, chamber.center.y);
+        let step =
+            Vec2::new(rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0)) * QUEEN_PACE * delta_time;
+        let offset = (Vec2::new(position.x, position.y) + step - center)
+            .clamp_length_max(chamber.radius * QUEEN_ROAM_FRACTION);
+        position.x = center.x + offset.x;
+        position.y = center.y + offset.y;
+
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+    }
+}
+
 /// Evaluate the quality of a founding site based on soil conditions
 fn evaluate_founding_site(
     queen_position: &Position,
@@ -0,0 +1,252 @@
+use crate::components::{
+    Ant, AntId, AntName, AntRegistry, AntRename, CameraFollow, InputAction, KeyBindings, Queen,
+    RenameButton, RenameButtonText, Selection, Tooltip, TooltipPosition, TooltipTrigger,
+};
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+
+/// Longest name that can be typed in the selection panel
+const MAX_NAME_LENGTH: usize = 24;
+
+/// Names handed out to new workers; ids keep ants with the same name apart
+pub const ANT_NAMES: [&str; 24] = [
+    "Ada", "Basil", "Clover", "Dot", "Ember", "Fern", "Ginger", "Hazel", "Iris", "Juniper", "Kit",
+    "Linden", "Maple", "Nettle", "Olive", "Pip", "Quill", "Rowan", "Sorrel", "Thistle", "Umber",
+    "Violet", "Wren", "Yarrow",
+];
+
+/// Request to give an ant a new name
+#[derive(Event, Clone, Debug)]
+pub struct RenameAntRequest {
+    pub ant: Entity,
+    pub name: String,
+}
+
+/// Pool name for the ant with the given id
+///
+/// Consecutive ids are spread across the pool so siblings hatched together differ.
+pub fn pool_name(id: AntId) -> &'static str {
+    ANT_NAMES[(id.0.wrapping_mul(7) % ANT_NAMES.len() as u64) as usize]
+}
+
+/// How an ant is labeled in its tooltip and the selection panel, e.g. "Fern #12"
+pub fn ant_label(id: AntId, name: &AntName) -> String {
+    format!("{} #{}", name.0, id.0)
+}
+
+/// System giving every new worker an id, a name and a hover tooltip
+///
+/// Hatchlings and other freshly spawned workers get the next id and a name from
+/// [`ANT_NAMES`]; workers restored from a save keep theirs, and the registry skips past
+/// them so later ids stay unique.
+pub fn ant_naming_system(
+    mut commands: Commands,
+    mut registry: ResMut<AntRegistry>,
+    ant_query: Query<
+        (Entity, Option<&AntId>, Option<&AntName>),
+        (With<Ant>, Without<Queen>, Without<Tooltip>),
+    >,
+) {
+    // Saved ids first, so new ones are handed out after them
+    for (_, id, _) in ant_query.iter() {
+        if let Some(id) = id {
+            registry.next_id = registry.next_id.max(id.0 + 1);
+        }
+    }
+
+    for (entity, id, name) in ant_query.iter() {
+        let id = id.copied().unwrap_or_else(|| {
+            let id = AntId(registry.next_id);
+            registry.next_id += 1;
+            id
+        });
+        let name = name
+            .cloned()
+            .unwrap_or_else(|| AntName(pool_name(id).to_string()));
+        commands.entity(entity).insert((
+            Tooltip {
+                text: ant_label(id, &name),
+                shortcut: None,
+                position: TooltipPosition::Above,
+            },
+            TooltipTrigger::default(),
+            id,
+            name,
+        ));
+    }
+}
+
+/// System carrying out rename requests, updating the ant's tooltip to match
+pub fn rename_ant_system(
+    mut requests: EventReader<RenameAntRequest>,
+    mut ant_query: Query<(&AntId, &mut AntName, Option<&mut Tooltip>)>,
+) {
+    for request in requests.read() {
+        let name = request.name.trim();
+        if name.is_empty() {
+            continue;
+        }
+        let Ok((id, mut ant_name, tooltip)) = ant_query.get_mut(request.ant) else {
+            continue;
+        };
+        info!("Ant #{} renamed from {} to {}", id.0, ant_name.0, name);
+        ant_name.0 = name.to_string();
+        if let Some(mut tooltip) = tooltip {
+            tooltip.text = ant_label(*id, &ant_name);
+        }
+    }
+}
+
+/// The one living, named ant in the selection, if exactly one is selected
+fn single_selected_ant(
+    selection: &Selection,
+    ant_query: &Query<&AntName, (With<Ant>, Without<Queen>)>,
+) -> Option<Entity> {
+    let mut selected = selection
+        .0
+        .iter()
+        .copied()
+        .filter(|ant| ant_query.contains(*ant));
+    match (selected.next(), selected.next()) {
+        (Some(ant), None) => Some(ant),
+        _ => None,
+    }
+}
+
+/// System starting to rename the selected ant when the [`RenameButton`] is pressed
+///
+/// Typing starts from the ant's current name.
+pub fn rename_button_system(
+    selection: Res<Selection>,
+    button_query: Query<&Interaction, (Changed<Interaction>, With<RenameButton>)>,
+    ant_query: Query<&AntName, (With<Ant>, Without<Queen>)>,
+    mut rename: ResMut<AntRename>,
+) {
+    if rename.ant.is_some()
+        || !button_query
+            .iter()
+            .any(|interaction| *interaction == Interaction::Pressed)
+    {
+        return;
+    }
+    let Some(ant) = single_selected_ant(&selection, &ant_query) else {
+        return;
+    };
+    if let Ok(name) = ant_query.get(ant) {
+        rename.ant = Some(ant);
+        rename.text.clone_from(&name.0);
+    }
+}
+
+/// System typing the new name while a rename is in progress
+///
+/// Enter sends a [`RenameAntRequest`] and Escape cancels; the rename is also dropped
+/// when the ant is no longer the only one selected. Runs right after input is read and
+/// swallows the keyboard state, so typed letters do not trigger shortcuts.
+pub fn rename_text_input_system(
+    selection: Res<Selection>,
+    ant_query: Query<&AntName, (With<Ant>, Without<Queen>)>,
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut keyboard_input: ResMut<ButtonInput<KeyCode>>,
+    mut rename: ResMut<AntRename>,
+    mut requests: EventWriter<RenameAntRequest>,
+) {
+    let Some(ant) = rename.ant else {
+        keyboard_events.clear();
+        return;
+    };
+    if single_selected_ant(&selection, &ant_query) != Some(ant) {
+        *rename = AntRename::default();
+        return;
+    }
+
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Enter => {
+                requests.send(RenameAntRequest {
+                    ant,
+                    name: rename.text.clone(),
+                });
+                *rename = AntRename::default();
+                break;
+            }
+            Key::Escape => {
+                *rename = AntRename::default();
+                break;
+            }
+            Key::Backspace => {
+                rename.text.pop();
+            }
+            Key::Space => push_name_char(&mut rename.text, ' '),
+            Key::Character(characters) => {
+                for character in characters.chars() {
+                    push_name_char(&mut rename.text, character);
+                }
+            }
+            _ => {}
+        }
+    }
+    keyboard_input.reset_all();
+}
+
+/// Append a typed character to a name, ignoring control characters and overlong names
+fn push_name_char(name: &mut String, character: char) {
+    if !character.is_control() && name.chars().count() < MAX_NAME_LENGTH {
+        name.push(character);
+    }
+}
+
+/// System showing the [`RenameButton`] for a single selected ant, and the name being typed
+pub fn update_rename_button_system(
+    selection: Res<Selection>,
+    rename: Res<AntRename>,
+    ant_query: Query<&AntName, (With<Ant>, Without<Queen>)>,
+    mut button_query: Query<&mut Style, With<RenameButton>>,
+    mut text_query: Query<&mut Text, With<RenameButtonText>>,
+) {
+    let display = if single_selected_ant(&selection, &ant_query).is_some() {
+        Display::Flex
+    } else {
+        Display::None
+    };
+    for mut style in button_query.iter_mut() {
+        if style.display != display {
+            style.display = display;
+        }
+    }
+
+    if !rename.is_changed() {
+        return;
+    }
+    let label = match rename.ant {
+        Some(_) => format!("Name: {}_", rename.text),
+        None => "Rename".to_string(),
+    };
+    for mut text in text_query.iter_mut() {
+        if let Some(section) = text.sections.first_mut() {
+            section.value.clone_from(&label);
+        }
+    }
+}
+
+/// System toggling camera follow on the first selected ant with F
+///
+/// Following stops when pressed again, when the ant dies or when the camera is panned.
+pub fn follow_selection_input_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    selection: Res<Selection>,
+    mut follow: ResMut<CameraFollow>,
+) {
+    if !key_bindings.just_pressed(&keyboard_input, InputAction::FollowSelection) {
+        return;
+    }
+    follow.target = match follow.target {
+        Some(_) => None,
+        None => selection.0.first().copied(),
+    };
+}
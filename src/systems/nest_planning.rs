@@ -0,0 +1,372 @@
+use crate::components::{
+    Ant, AntBehavior, Chamber, ChamberConstruction, ChamberType, ColonyFoodStore, ColonyTraits,
+    DepthLayer, ExcavationTask, NestPlanningConfig, NestSpace, Position, Queen, Soil, SoilCell,
+    SpecializedRole,
+};
+use crate::systems::chambers::spawn_chamber;
+use crate::systems::foraging::{distance, step_toward};
+use crate::systems::movement::{dig_speed_factor, IDEAL_DIG_MOISTURE};
+use crate::systems::rendering::{z_for, RenderLayer};
+use bevy::prelude::*;
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+use std::f32::consts::{PI, TAU};
+
+/// Chamber types the colony expands on its own once they fill up
+const PLANNED_CHAMBER_TYPES: [ChamberType; 2] = [ChamberType::Nursery, ChamberType::FoodStorage];
+
+/// System designating a new chamber site when every chamber of a type is full
+///
+/// The colony only expands when it has enough workers and stored food to spare, and
+/// plans one site per chamber type at a time. The site branches off an existing chamber
+/// of the same type within the limits of [`choose_branch_site`], and nest maintainers
+/// are assigned to dig it out. The queen's architectural skill scales the capacity of
+/// the planned chamber and the branching limits.
+pub fn nest_planning_system(
+    mut commands: Commands,
+    config: Res<NestPlanningConfig>,
+    food_store: Res<ColonyFoodStore>,
+    chamber_query: Query<(&Position, &Chamber)>,
+    site_query: Query<(&Position, &ChamberConstruction)>,
+    queen_query: Query<&ColonyTraits, With<Queen>>,
+    worker_query: Query<
+        (Entity, &SpecializedRole, Has<ExcavationTask>),
+        (With<Ant>, Without<Queen>),
+    >,
+) {
+    if worker_query.iter().count() < config.min_workers || food_store.food < config.min_food_reserve
+    {
+        return;
+    }
+
+    let skill = architectural_skill(&queen_query);
+    let mut assigned = HashSet::new();
+    let mut rng = rand::thread_rng();
+
+    for chamber_type in PLANNED_CHAMBER_TYPES {
+        let anchors = chamber_query
+            .iter()
+            .filter(|(_, chamber)| chamber.chamber_type == chamber_type)
+            .collect::<Vec<_>>();
+        if anchors.is_empty() {
+            continue; // Nothing to outgrow yet
+        }
+        let saturated = anchors
+            .iter()
+            .all(|(_, chamber)| chamber.capacity_usage() >= config.saturation_threshold);
+        let already_planned = site_query
+            .iter()
+            .any(|(_, site)| site.chamber_type == chamber_type);
+        if !saturated || already_planned {
+            continue;
+        }
+
+        let radius = config.chamber_radius * skill.sqrt();
+        let occupied = chamber_query
+            .iter()
+            .map(|(position, chamber)| (position, chamber.radius))
+            .chain(
+                site_query
+                    .iter()
+                    .map(|(position, site)| (position, site.radius)),
+            )
+            .collect::<Vec<_>>();
+        let Some(site_position) = anchors.iter().find_map(|(anchor_position, anchor)| {
+            choose_branch_site(
+                anchor_position,
+                anchor.radius,
+                radius,
+                &occupied,
+                &config,
+                skill,
+                rng.gen(),
+            )
+        }) else {
+            continue;
+        };
+
+        let site = ChamberConstruction {
+            chamber_type,
+            radius,
+            capacity: (config.chamber_capacity as f32 * skill).round().max(1.0) as usize,
+            progress: 0.0,
+        };
+        info!(
+            "Colony plans a new {:?} chamber at ({:.0}, {:.0})",
+            chamber_type, site_position.x, site_position.y
+        );
+        let site_entity = spawn_chamber_site(&mut commands, site_position, site);
+
+        let diggers = worker_query
+            .iter()
+            .filter(|(entity, role, busy)| {
+                **role == SpecializedRole::NestMaintainer && !busy && !assigned.contains(entity)
+            })
+            .take(config.diggers_per_site)
+            .map(|(entity, _, _)| entity)
+            .collect::<Vec<_>>();
+        for digger in diggers {
+            commands
+                .entity(digger)
+                .insert(ExcavationTask { site: site_entity });
+            assigned.insert(digger);
+        }
+    }
+}
+
+/// System moving assigned maintainers to their site and digging the chamber out
+///
+/// Each digger on site advances the work by `dig_rate`, scaled by architectural skill
+/// and by [`dig_speed_factor`] for the site's soil layer and moisture. Soil is cleared
+/// outward from the site's center as work progresses, and the finished site becomes a
+/// regular chamber.
+pub fn chamber_excavation_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<NestPlanningConfig>,
+    mut nest_space: ResMut<NestSpace>,
+    mut site_query: Query<(Entity, &Position, &mut ChamberConstruction), Without<Ant>>,
+    mut digger_query: Query<(Entity, &mut Position, &AntBehavior, &ExcavationTask), With<Ant>>,
+    soil_query: Query<
+        (Entity, &Position, Option<&SoilCell>),
+        (With<Soil>, Without<Ant>, Without<ChamberConstruction>),
+    >,
+    queen_query: Query<&ColonyTraits, With<Queen>>,
+) {
+    let delta_time = time.delta_seconds();
+    let mut diggers_on_site: HashMap<Entity, usize> = HashMap::new();
+
+    for (entity, mut position, behavior, task) in digger_query.iter_mut() {
+        let Ok((_, site_position, site)) = site_query.get(task.site) else {
+            commands.entity(entity).remove::<ExcavationTask>();
+            continue;
+        };
+        if distance(&position, site_position) <= site.radius {
+            *diggers_on_site.entry(task.site).or_default() += 1;
+        } else {
+            step_toward(&mut position, site_position, behavior.speed * delta_time);
+        }
+    }
+
+    let skill = architectural_skill(&queen_query);
+    let mut finished = HashSet::new();
+    // Overlapping sites share soil; each cell is cleared and counted once
+    let mut dug_cells = HashSet::new();
+
+    for (site_entity, site_position, mut site) in site_query.iter_mut() {
+        let diggers = diggers_on_site.get(&site_entity).copied().unwrap_or(0);
+        if diggers == 0 {
+            continue;
+        }
+        let soil_factor = dig_speed_factor(
+            DepthLayer::from_y(site_position.y).hardness(),
+            site_moisture(site_position, site.radius, &soil_query),
+        );
+        site.progress = (site.progress
+            + diggers as f32 * config.dig_rate * skill * soil_factor * delta_time)
+            .min(1.0);
+
+        let dug_radius = site.radius * site.progress;
+        for (soil_entity, soil_position, _) in soil_query.iter() {
+            if distance(soil_position, site_position) <= dug_radius && dug_cells.insert(soil_entity)
+            {
+                commands.entity(soil_entity).despawn();
+                nest_space.excavated_cells += 1;
+            }
+        }
+
+        if site.progress >= 1.0 {
+            info!("A new {:?} chamber is complete", site.chamber_type);
+            spawn_chamber(
+                &mut commands,
+                site_position.clone(),
+                site.chamber_type,
+                site.radius,
+                site.capacity,
+            );
+            commands.entity(site_entity).despawn();
+            finished.insert(site_entity);
+        }
+    }
+
+    for (entity, _, _, task) in digger_query.iter() {
+        if finished.contains(&task.site) {
+            commands.entity(entity).remove::<ExcavationTask>();
+        }
+    }
+}
+
+/// Spawn a marked chamber site, drawn as a faint circle of the future chamber
+pub fn spawn_chamber_site(
+    commands: &mut Commands,
+    position: Position,
+    site: ChamberConstruction,
+) -> Entity {
+    let translation = Vec3::new(position.x, position.y, z_for(RenderLayer::Chamber));
+
+    commands
+        .spawn((
+            position,
+            site,
+            SpatialBundle::from_transform(Transform::from_translation(translation)),
+        ))
+        .id()
+}
+
+/// Directions to try for a new branch: level with the anchor first, then ever deeper
+const BRANCH_DIRECTIONS: [f32; 9] = [
+    0.0,
+    PI,
+    -PI / 8.0,
+    -PI * 7.0 / 8.0,
+    -PI / 4.0,
+    -PI * 3.0 / 4.0,
+    -PI * 3.0 / 8.0,
+    -PI * 5.0 / 8.0,
+    -PI / 2.0,
+];
+
+/// Directions, in radians, of the tunnels leaving a chamber at `node`
+///
+/// Chambers count as linked when no more than the site spacing, with some slack,
+/// separates their edges, which is how far apart [`choose_branch_site`] places them.
+pub fn branch_angles(
+    node: &Position,
+    radius: f32,
+    spacing: f32,
+    occupied: &[(&Position, f32)],
+) -> Vec<f32> {
+    occupied
+        .iter()
+        .filter(|(other, other_radius)| {
+            let gap = distance(node, other);
+            gap > 0.0 && gap <= radius + other_radius + spacing * 1.5
+        })
+        .map(|(other, _)| (other.y - node.y).atan2(other.x - node.x))
+        .collect()
+}
+
+/// Smallest angle between two directions, in radians
+pub fn angle_between(a: f32, b: f32) -> f32 {
+    let difference = (a - b).rem_euclid(TAU);
+    difference.min(TAU - difference)
+}
+
+/// First free spot branching off the anchor chamber without breaking the branching limits
+///
+/// No chamber ends up with more than the skill-scaled `max_branches` tunnels, and tunnels
+/// leaving a chamber stay at least the skill-scaled `min_branch_angle` apart. Extending a
+/// dead end is always allowed, but forking a chamber that already carries a tunnel through
+/// only happens when `roll` falls under the depth-dependent [`NestPlanningConfig::fork_chance`].
+pub fn choose_branch_site(
+    anchor: &Position,
+    anchor_radius: f32,
+    radius: f32,
+    occupied: &[(&Position, f32)],
+    config: &NestPlanningConfig,
+    skill: f32,
+    roll: f32,
+) -> Option<Position> {
+    let spacing = config.site_spacing;
+    let max_branches = config.max_branches_for(skill);
+    let min_angle = config.min_branch_angle_for(skill);
+
+    let anchor_branches = branch_angles(anchor, anchor_radius, spacing, occupied);
+    if anchor_branches.len() >= max_branches {
+        return None;
+    }
+    if anchor_branches.len() >= 2 && roll >= config.fork_chance(-anchor.y, skill) {
+        return None; // Not forking here this time
+    }
+
+    let offset = anchor_radius + radius + spacing;
+    BRANCH_DIRECTIONS
+        .into_iter()
+        .filter(|angle| {
+            anchor_branches
+                .iter()
+                .all(|branch| angle_between(*angle, *branch) >= min_angle)
+        })
+        .map(|angle| Position {
+            x: anchor.x + offset * angle.cos(),
+            y: anchor.y + offset * angle.sin(),
+        })
+        .filter(|candidate| (-100.0..=100.0).contains(&candidate.x) && candidate.y > -100.0)
+        .find(|candidate| {
+            let clear = occupied.iter().all(|(position, other_radius)| {
+                distance(position, candidate) >= other_radius + radius
+            });
+            clear
+                && keeps_branching_limits(
+                    candidate,
+                    radius,
+                    occupied,
+                    spacing,
+                    max_branches,
+                    min_angle,
+                )
+        })
+}
+
+/// Whether a chamber at `candidate` leaves itself and every chamber it links to within the limits
+fn keeps_branching_limits(
+    candidate: &Position,
+    radius: f32,
+    occupied: &[(&Position, f32)],
+    spacing: f32,
+    max_branches: usize,
+    min_angle: f32,
+) -> bool {
+    let own_branches = branch_angles(candidate, radius, spacing, occupied);
+    if own_branches.len() > max_branches {
+        return false;
+    }
+    let spread = own_branches.iter().enumerate().all(|(i, a)| {
+        own_branches[i + 1..]
+            .iter()
+            .all(|b| angle_between(*a, *b) >= min_angle)
+    });
+    if !spread {
+        return false;
+    }
+
+    let with_candidate = [(candidate, radius)];
+    occupied.iter().all(|(neighbor, neighbor_radius)| {
+        let new_link = branch_angles(neighbor, *neighbor_radius, spacing, &with_candidate);
+        let Some(&new_angle) = new_link.first() else {
+            return true; // Not linked to the candidate
+        };
+        let branches = branch_angles(neighbor, *neighbor_radius, spacing, occupied);
+        branches.len() < max_branches
+            && branches
+                .iter()
+                .all(|branch| angle_between(new_angle, *branch) >= min_angle)
+    })
+}
+
+/// Average moisture of the soil still standing within a site
+fn site_moisture(
+    site_position: &Position,
+    radius: f32,
+    soil_query: &Query<
+        (Entity, &Position, Option<&SoilCell>),
+        (With<Soil>, Without<Ant>, Without<ChamberConstruction>),
+    >,
+) -> f32 {
+    let moistures: Vec<f32> = soil_query
+        .iter()
+        .filter(|(_, soil_position, _)| distance(soil_position, site_position) <= radius)
+        .filter_map(|(_, _, soil)| soil.map(|soil| soil.moisture))
+        .collect();
+    if moistures.is_empty() {
+        return IDEAL_DIG_MOISTURE;
+    }
+    moistures.iter().sum::<f32>() / moistures.len() as f32
+}
+
+fn architectural_skill(queen_query: &Query<&ColonyTraits, With<Queen>>) -> f32 {
+    queen_query
+        .iter()
+        .next()
+        .map_or(1.0, |traits| traits.architectural_skill)
+}
@@ -0,0 +1,102 @@
+use crate::components::{
+    Chamber, ChamberConstruction, ChamberType, DugTunnel, NestShape, Position, Soil, Tunnel,
+};
+use bevy::prelude::*;
+use bevy::sprite::Mesh2dHandle;
+
+/// Fill opacity of a finished chamber and of a site still being excavated
+const CHAMBER_ALPHA: f32 = 0.6;
+const CONSTRUCTION_ALPHA: f32 = 0.25;
+/// Opacity of a tunnel once the soil at both of its ends is dug out
+const TUNNEL_ALPHA: f32 = 1.0;
+
+/// Transform and sprite size stretching a tunnel segment of `width` from `a` to `b`
+///
+/// The segment keeps the z of `transform`, so it stays on the tunnel layer.
+pub fn tunnel_segment_shape(
+    a: Vec2,
+    b: Vec2,
+    width: f32,
+    transform: &Transform,
+) -> (Transform, Vec2) {
+    let offset = b - a;
+    let midpoint = (a + b) * 0.5;
+    let shape = Transform::from_translation(midpoint.extend(transform.translation.z))
+        .with_rotation(Quat::from_rotation_z(offset.y.atan2(offset.x)));
+    (shape, Vec2::new(offset.length(), width))
+}
+
+/// System drawing the nest cross-section: chambers as filled circles and tunnels as
+/// thick segments between their nodes, all beneath the ants
+///
+/// Chambers take their type's color; sites still being excavated are drawn faintly.
+/// Shapes are only rebuilt when a chamber's radius changes. Tunnels are drawn faintly
+/// until no soil is left at their nodes, and are retried until both nodes exist.
+pub fn nest_render_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    chamber_query: Query<(Entity, &Chamber, Option<&NestShape>)>,
+    site_query: Query<(Entity, &ChamberConstruction, Option<&NestShape>), Without<Chamber>>,
+    mut tunnel_query: Query<(Entity, &Tunnel, &mut Transform, &mut Sprite), Without<DugTunnel>>,
+    node_query: Query<&Position>,
+    soil_query: Query<&Position, With<Soil>>,
+) {
+    let shapes = chamber_query
+        .iter()
+        .map(|(entity, chamber, shape)| {
+            (
+                entity,
+                chamber.chamber_type,
+                chamber.radius,
+                CHAMBER_ALPHA,
+                shape,
+            )
+        })
+        .chain(site_query.iter().map(|(entity, site, shape)| {
+            (
+                entity,
+                site.chamber_type,
+                site.radius,
+                CONSTRUCTION_ALPHA,
+                shape,
+            )
+        }));
+
+    for (entity, chamber_type, radius, alpha, shape) in shapes {
+        if shape.is_some_and(|shape| shape.radius == radius) {
+            continue;
+        }
+        commands.entity(entity).insert((
+            Mesh2dHandle(meshes.add(Circle::new(radius))),
+            materials.add(ColorMaterial::from(chamber_fill(chamber_type, alpha))),
+            NestShape { radius },
+        ));
+    }
+
+    for (entity, tunnel, mut transform, mut sprite) in tunnel_query.iter_mut() {
+        let (Ok(from), Ok(to)) = (node_query.get(tunnel.from), node_query.get(tunnel.to)) else {
+            continue;
+        };
+        let (from, to) = (Vec2::new(from.x, from.y), Vec2::new(to.x, to.y));
+        let (shape, size) = tunnel_segment_shape(from, to, tunnel.width, &transform);
+        *transform = shape;
+        sprite.custom_size = Some(size);
+
+        let reach = tunnel.width * 0.5;
+        let dug = !soil_query.iter().any(|soil| {
+            let soil = Vec2::new(soil.x, soil.y);
+            soil.distance(from) < reach || soil.distance(to) < reach
+        });
+        if dug {
+            sprite.color.set_alpha(TUNNEL_ALPHA);
+            commands.entity(entity).insert(DugTunnel);
+        } else {
+            sprite.color.set_alpha(CONSTRUCTION_ALPHA);
+        }
+    }
+}
+
+fn chamber_fill(chamber_type: ChamberType, alpha: f32) -> Color {
+    chamber_type.color().with_alpha(alpha)
+}
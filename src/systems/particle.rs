@@ -1,6 +1,8 @@
 use crate::components::{
-    DisasterState, DisasterType, Particle, ParticleData, ParticleType, VisualEffectsSettings,
+    DisasterState, DisasterType, Particle, ParticleData, ParticleFade, ParticleType,
+    VisualEffectsSettings,
 };
+use crate::systems::rendering::{z_for, RenderLayer};
 use bevy::prelude::*;
 use rand::Rng;
 
@@ -16,6 +18,17 @@ pub struct ParticleConfig {
     /// Window dimensions for particle boundary calculations
     pub window_width: f32,
     pub window_height: f32,
+    /// Downward acceleration in pixels per second squared, scaled by each type's weight
+    pub gravity: f32,
+    /// Sideways jitter of snowflakes in pixels per second squared
+    pub snow_drift: f32,
+    /// Speed multipliers per particle type
+    pub rain_velocity_scale: f32,
+    pub dust_velocity_scale: f32,
+    pub snow_velocity_scale: f32,
+    pub disturbance_velocity_scale: f32,
+    /// Fade-in and fade-out over each particle's lifetime
+    pub fade: ParticleFade,
 }
 
 impl Default for ParticleConfig {
@@ -26,11 +39,51 @@ impl Default for ParticleConfig {
             base_spawn_rate: 30.0, // 30 particles per second base rate
             window_width: 800.0,   // Will be updated by window system
             window_height: 600.0,  // Will be updated by window system
+            gravity: 60.0,
+            snow_drift: 10.0,
+            rain_velocity_scale: 1.0,
+            dust_velocity_scale: 1.0,
+            snow_velocity_scale: 1.0,
+            disturbance_velocity_scale: 1.0,
+            fade: ParticleFade::default(),
         }
     }
 }
 
+/// Gravity range covered by the weather intensity setting
+pub const MIN_PARTICLE_GRAVITY: f32 = 10.0;
+pub const MAX_PARTICLE_GRAVITY: f32 = 300.0;
+
+impl ParticleConfig {
+    /// Speed multiplier for a particle type
+    pub fn velocity_scale(&self, particle_type: ParticleType) -> f32 {
+        match particle_type {
+            ParticleType::RainDrop => self.rain_velocity_scale,
+            ParticleType::DustMote => self.dust_velocity_scale,
+            ParticleType::Snowflake => self.snow_velocity_scale,
+            ParticleType::EnvironmentalDisturbance => self.disturbance_velocity_scale,
+        }
+    }
+
+    /// Map a 0.0 - 1.0 intensity (e.g. from a settings slider) onto gravity
+    pub fn set_intensity(&mut self, intensity: f32) {
+        self.gravity = MIN_PARTICLE_GRAVITY
+            + (MAX_PARTICLE_GRAVITY - MIN_PARTICLE_GRAVITY) * intensity.clamp(0.0, 1.0);
+    }
+}
+
+/// Share of the configured gravity acting on a particle type
+pub fn gravity_factor(particle_type: ParticleType) -> f32 {
+    match particle_type {
+        ParticleType::RainDrop => 1.0,
+        ParticleType::Snowflake => 0.1, // Snow floats down gently
+        ParticleType::DustMote | ParticleType::EnvironmentalDisturbance => 0.0, // Airborne
+    }
+}
+
 /// System for spawning particles based on active disasters
+///
+/// Spawn rates scale with the disasters' `visual_intensity`, leaving their effects alone.
 pub fn particle_spawner_system(
     mut commands: Commands,
     disaster_state: Res<DisasterState>,
@@ -58,25 +111,50 @@ pub fn particle_spawner_system(
     }
 
     let mut rng = rand::thread_rng();
+    let intensity = disaster_state.visual_intensity.max(0.0);
 
     // Spawn rain particles
     if disaster_state.is_active(DisasterType::Rain) {
-        spawn_rain_particles(&mut commands, &mut particle_config, &mut rng, delta_time);
+        spawn_rain_particles(
+            &mut commands,
+            &mut particle_config,
+            &mut rng,
+            delta_time,
+            intensity,
+        );
     }
 
     // Spawn drought particles
     if disaster_state.is_active(DisasterType::Drought) {
-        spawn_drought_particles(&mut commands, &mut particle_config, &mut rng, delta_time);
+        spawn_drought_particles(
+            &mut commands,
+            &mut particle_config,
+            &mut rng,
+            delta_time,
+            intensity,
+        );
     }
 
     // Spawn cold snap particles
     if disaster_state.is_active(DisasterType::ColdSnap) {
-        spawn_cold_snap_particles(&mut commands, &mut particle_config, &mut rng, delta_time);
+        spawn_cold_snap_particles(
+            &mut commands,
+            &mut particle_config,
+            &mut rng,
+            delta_time,
+            intensity,
+        );
     }
 
     // Spawn invasive species particles
     if disaster_state.is_active(DisasterType::InvasiveSpecies) {
-        spawn_invasive_particles(&mut commands, &mut particle_config, &mut rng, delta_time);
+        spawn_invasive_particles(
+            &mut commands,
+            &mut particle_config,
+            &mut rng,
+            delta_time,
+            intensity,
+        );
     }
 }
 
@@ -103,12 +181,15 @@ pub fn particle_update_system(
             continue;
         }
 
-        // Update position based on velocity
-        transform.translation.x += particle_data.velocity.x * delta_time;
-        transform.translation.y += particle_data.velocity.y * delta_time;
+        // Gravity first, then move with the type's speed multiplier
+        particle_data.velocity.y -=
+            particle_config.gravity * gravity_factor(particle_data.particle_type) * delta_time;
+        let scale = particle_config.velocity_scale(particle_data.particle_type);
+        transform.translation.x += particle_data.velocity.x * scale * delta_time;
+        transform.translation.y += particle_data.velocity.y * scale * delta_time;
 
         // Update color with fade effect
-        sprite.color = particle_data.get_current_color();
+        sprite.color = particle_data.get_current_color(&particle_config.fade);
 
         // Check bounds and mark for removal if out of screen
         if is_particle_out_of_bounds(
@@ -121,7 +202,7 @@ pub fn particle_update_system(
         }
 
         // Apply particle-type specific behavior updates
-        update_particle_behavior(&mut particle_data, delta_time);
+        update_particle_behavior(&mut particle_data, &particle_config, delta_time);
     }
 
     // Remove expired or out-of-bounds particles
@@ -151,8 +232,13 @@ fn spawn_rain_particles(
     particle_config: &mut ParticleConfig,
     rng: &mut impl Rng,
     delta_time: f32,
+    intensity: f32,
 ) {
-    let spawn_count = calculate_spawn_count(particle_config.base_spawn_rate * 1.2, delta_time, rng);
+    let spawn_count = calculate_spawn_count(
+        particle_config.base_spawn_rate * intensity * 1.2,
+        delta_time,
+        rng,
+    );
 
     for _ in 0..spawn_count {
         if particle_config.active_particles >= particle_config.max_particles {
@@ -171,7 +257,7 @@ fn spawn_rain_particles(
             commands,
             particle_config,
             ParticleData::new_rain_drop(lifetime, velocity),
-            Vec3::new(x, y, 100.0), // Z=100 for particle layer
+            Vec3::new(x, y, z_for(RenderLayer::Particle)),
         );
     }
 }
@@ -182,8 +268,13 @@ fn spawn_drought_particles(
     particle_config: &mut ParticleConfig,
     rng: &mut impl Rng,
     delta_time: f32,
+    intensity: f32,
 ) {
-    let spawn_count = calculate_spawn_count(particle_config.base_spawn_rate * 0.8, delta_time, rng);
+    let spawn_count = calculate_spawn_count(
+        particle_config.base_spawn_rate * intensity * 0.8,
+        delta_time,
+        rng,
+    );
 
     for _ in 0..spawn_count {
         if particle_config.active_particles >= particle_config.max_particles {
@@ -202,7 +293,7 @@ fn spawn_drought_particles(
             commands,
             particle_config,
             ParticleData::new_dust_mote(lifetime, velocity),
-            Vec3::new(x, y, 100.0),
+            Vec3::new(x, y, z_for(RenderLayer::Particle)),
         );
     }
 }
@@ -213,8 +304,13 @@ fn spawn_cold_snap_particles(
     particle_config: &mut ParticleConfig,
     rng: &mut impl Rng,
     delta_time: f32,
+    intensity: f32,
 ) {
-    let spawn_count = calculate_spawn_count(particle_config.base_spawn_rate * 1.0, delta_time, rng);
+    let spawn_count = calculate_spawn_count(
+        particle_config.base_spawn_rate * intensity * 1.0,
+        delta_time,
+        rng,
+    );
 
     for _ in 0..spawn_count {
         if particle_config.active_particles >= particle_config.max_particles {
@@ -233,7 +329,7 @@ fn spawn_cold_snap_particles(
             commands,
             particle_config,
             ParticleData::new_snowflake(lifetime, velocity),
-            Vec3::new(x, y, 100.0),
+            Vec3::new(x, y, z_for(RenderLayer::Particle)),
         );
     }
 }
@@ -244,8 +340,13 @@ fn spawn_invasive_particles(
     particle_config: &mut ParticleConfig,
     rng: &mut impl Rng,
     delta_time: f32,
+    intensity: f32,
 ) {
-    let spawn_count = calculate_spawn_count(particle_config.base_spawn_rate * 0.6, delta_time, rng);
+    let spawn_count = calculate_spawn_count(
+        particle_config.base_spawn_rate * intensity * 0.6,
+        delta_time,
+        rng,
+    );
 
     for _ in 0..spawn_count {
         if particle_config.active_particles >= particle_config.max_particles {
@@ -264,7 +365,7 @@ fn spawn_invasive_particles(
             commands,
             particle_config,
             ParticleData::new_environmental_disturbance(lifetime, velocity),
-            Vec3::new(x, y, 100.0),
+            Vec3::new(x, y, z_for(RenderLayer::Particle)),
         );
     }
 }
@@ -280,7 +381,7 @@ fn spawn_particle(
         Particle,
         SpriteBundle {
             sprite: Sprite {
-                color: particle_data.get_current_color(),
+                color: particle_data.get_current_color(&particle_config.fade),
                 custom_size: Some(particle_data.size),
                 ..default()
             },
@@ -313,19 +414,23 @@ fn is_particle_out_of_bounds(x: f32, y: f32, window_width: f32, window_height: f
 }
 
 /// Update particle behavior based on type
-fn update_particle_behavior(particle_data: &mut ParticleData, delta_time: f32) {
+fn update_particle_behavior(
+    particle_data: &mut ParticleData,
+    particle_config: &ParticleConfig,
+    delta_time: f32,
+) {
     match particle_data.particle_type {
         ParticleType::RainDrop => {
-            // Rain drops might accelerate slightly due to gravity
-            particle_data.velocity.y -= 20.0 * delta_time;
+            // Rain only feels gravity, applied in the update system
         }
         ParticleType::DustMote => {
             // Dust particles slow down over time
             particle_data.velocity *= 0.98;
         }
         ParticleType::Snowflake => {
-            // Snowflakes might drift more horizontally
-            particle_data.velocity.x += rand::thread_rng().gen_range(-10.0..10.0) * delta_time;
+            // Snowflakes drift from side to side as they fall
+            let drift = particle_config.snow_drift;
+            particle_data.velocity.x += rand::thread_rng().gen_range(-drift..=drift) * delta_time;
         }
         ParticleType::EnvironmentalDisturbance => {
             // Environmental disturbance changes direction randomly
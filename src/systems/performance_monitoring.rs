@@ -1,7 +1,10 @@
 use crate::components::{
-    Ant, EntityCountText, Food, FpsText, FrameTimeText, PerformanceMetrics, PerformancePanel, Soil,
-    SpatialGrid, SpatialStatsText,
+    Ant, DisplayedText, DraggablePanel, EntityCountText, FpsText, FrameTimeText, InputAction,
+    KeyBindings, MiniHud, MiniHudEntityText, MiniHudFpsText, MiniHudSpeedText, PanelAnchor,
+    PanelTitleBar, PerformanceMetrics, PerformancePanel, ResponsivePanel, Soil, TimeControl,
 };
+use crate::localization::Localization;
+use crate::systems::time_control::speed_label;
 use bevy::prelude::*;
 
 /// System to set up the performance monitoring UI panel
@@ -13,6 +16,7 @@ pub fn setup_performance_monitoring_ui(mut commands: Commands) {
                 position_type: PositionType::Absolute,
                 left: Val::Px(10.0),
                 top: Val::Px(10.0),
+                width: Val::Px(220.0),
                 flex_direction: FlexDirection::Column,
                 padding: UiRect::all(Val::Px(10.0)),
                 ..default()
@@ -23,9 +27,15 @@ pub fn setup_performance_monitoring_ui(mut commands: Commands) {
             ..default()
         })
         .insert(PerformancePanel)
+        .insert(ResponsivePanel {
+            anchor: PanelAnchor::TopLeft,
+            width: 220.0,
+            margin: 10.0,
+        })
+        .insert(DraggablePanel { id: "performance" })
         .id();
 
-    // Title text
+    // Title text, which also drags the panel
     let title_entity = commands
         .spawn(TextBundle::from_section(
             "Performance Metrics",
@@ -35,6 +45,12 @@ pub fn setup_performance_monitoring_ui(mut commands: Commands) {
                 ..default()
             },
         ))
+        .insert((
+            Interaction::default(),
+            PanelTitleBar {
+                panel: panel_entity,
+            },
+        ))
         .id();
 
     // FPS text
@@ -66,7 +82,7 @@ pub fn setup_performance_monitoring_ui(mut commands: Commands) {
     // Entity count text
     let entity_count_entity = commands
         .spawn(TextBundle::from_section(
-            "Entities: Ants: 0, Food: 0, Soil: 0",
+            "Entities: Ants: 0, Soil: 0",
             TextStyle {
                 font_size: 14.0,
                 color: Color::srgb(0.0, 0.8, 1.0), // Cyan
@@ -76,38 +92,25 @@ pub fn setup_performance_monitoring_ui(mut commands: Commands) {
         .insert(EntityCountText)
         .id();
 
-    // Spatial grid stats text
-    let spatial_stats_entity = commands
-        .spawn(TextBundle::from_section(
-            "Spatial Grid: 0 cells, 0.0 avg/cell",
-            TextStyle {
-                font_size: 14.0,
-                color: Color::srgb(1.0, 0.5, 0.0), // Orange
-                ..default()
-            },
-        ))
-        .insert(SpatialStatsText)
-        .id();
-
     // Add all text entities as children of the panel
     commands.entity(panel_entity).push_children(&[
         title_entity,
         fps_entity,
         frame_time_entity,
         entity_count_entity,
-        spatial_stats_entity,
     ]);
 
     info!("Performance monitoring UI panel created");
 }
 
 /// System to collect performance metrics and update the resource
+///
+/// Frame times are wall-clock, so the FPS readout holds at any simulation speed.
 pub fn collect_performance_metrics(
-    time: Res<Time>,
+    time: Res<Time<Real>>,
     mut performance_metrics: ResMut<PerformanceMetrics>,
-    spatial_grid: Res<SpatialGrid>,
+    entity_query: Query<Entity>,
     ant_query: Query<Entity, With<Ant>>,
-    food_query: Query<Entity, With<Food>>,
     soil_query: Query<Entity, With<Soil>>,
 ) {
     // Add current frame time to metrics
@@ -121,16 +124,10 @@ pub fn collect_performance_metrics(
     {
         // Count entities
         let ant_count = ant_query.iter().count();
-        let food_count = food_query.iter().count();
         let soil_count = soil_query.iter().count();
+        let total_entity_count = entity_query.iter().count();
 
-        performance_metrics.update_entity_counts(ant_count, food_count, soil_count);
-
-        // Update spatial grid statistics
-        let occupied_cells = spatial_grid.grid.len();
-        let total_entities_in_grid = spatial_grid.grid.values().map(|v| v.len()).sum::<usize>();
-
-        performance_metrics.update_spatial_stats(occupied_cells, total_entities_in_grid);
+        performance_metrics.update_entity_counts(ant_count, soil_count, total_entity_count);
     }
 }
 
@@ -143,7 +140,6 @@ pub fn update_performance_monitoring_ui(
             With<FpsText>,
             Without<FrameTimeText>,
             Without<EntityCountText>,
-            Without<SpatialStatsText>,
         ),
     >,
     mut frame_time_query: Query<
@@ -152,7 +148,6 @@ pub fn update_performance_monitoring_ui(
             With<FrameTimeText>,
             Without<FpsText>,
             Without<EntityCountText>,
-            Without<SpatialStatsText>,
         ),
     >,
     mut entity_count_query: Query<
@@ -161,31 +156,13 @@ pub fn update_performance_monitoring_ui(
             With<EntityCountText>,
             Without<FpsText>,
             Without<FrameTimeText>,
-            Without<SpatialStatsText>,
-        ),
-    >,
-    mut spatial_stats_query: Query<
-        &mut Text,
-        (
-            With<SpatialStatsText>,
-            Without<FpsText>,
-            Without<FrameTimeText>,
-            Without<EntityCountText>,
         ),
     >,
 ) {
     // Update FPS text
     for mut text in fps_query.iter_mut() {
         text.sections[0].value = format!("FPS: {:.0}", performance_metrics.fps);
-
-        // Color code based on FPS (Green >= 60, Yellow >= 30, Red < 30)
-        text.sections[0].style.color = if performance_metrics.fps >= 60.0 {
-            Color::srgb(0.0, 1.0, 0.0) // Green
-        } else if performance_metrics.fps >= 30.0 {
-            Color::srgb(1.0, 1.0, 0.0) // Yellow
-        } else {
-            Color::srgb(1.0, 0.0, 0.0) // Red
-        };
+        text.sections[0].style.color = fps_color(performance_metrics.fps);
     }
 
     // Update frame time text
@@ -205,18 +182,8 @@ pub fn update_performance_monitoring_ui(
     // Update entity count text
     for mut text in entity_count_query.iter_mut() {
         text.sections[0].value = format!(
-            "Entities: Ants: {}, Food: {}, Soil: {}",
-            performance_metrics.ant_count,
-            performance_metrics.food_count,
-            performance_metrics.soil_count
-        );
-    }
-
-    // Update spatial grid stats text
-    for mut text in spatial_stats_query.iter_mut() {
-        text.sections[0].value = format!(
-            "Spatial Grid: {} cells, {:.1} avg/cell",
-            performance_metrics.spatial_grid_cells, performance_metrics.avg_entities_per_cell
+            "Entities: Ants: {}, Soil: {}",
+            performance_metrics.ant_count, performance_metrics.soil_count
         );
     }
 }
@@ -224,10 +191,11 @@ pub fn update_performance_monitoring_ui(
 /// System to toggle performance monitoring panel visibility with F3 key
 pub fn toggle_performance_monitoring_system(
     keyboard_input: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
     performance_panel_query: Query<Entity, With<PerformancePanel>>,
     mut visibility_query: Query<&mut Visibility, With<PerformancePanel>>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::F3) {
+    if key_bindings.just_pressed(&keyboard_input, InputAction::TogglePerformancePanel) {
         // Toggle visibility of existing panel
         for mut visibility in visibility_query.iter_mut() {
             *visibility = match *visibility {
@@ -251,3 +219,146 @@ pub fn toggle_performance_monitoring_system(
         }
     }
 }
+
+/// Color code for an FPS readout (Green >= 60, Yellow >= 30, Red < 30)
+pub fn fps_color(fps: f32) -> Color {
+    if fps >= 60.0 {
+        Color::srgb(0.0, 1.0, 0.0) // Green
+    } else if fps >= 30.0 {
+        Color::srgb(1.0, 1.0, 0.0) // Yellow
+    } else {
+        Color::srgb(1.0, 0.0, 0.0) // Red
+    }
+}
+
+/// System to set up the compact always-on HUD (FPS, total entity count and simulation speed)
+pub fn setup_mini_hud(mut commands: Commands) {
+    // Top-center strip, kept narrow and close to the top edge so it stays clear of
+    // the corner-anchored panels
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(4.0),
+                width: Val::Px(260.0),
+                justify_content: JustifyContent::SpaceBetween,
+                padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+                ..default()
+            },
+            background_color: Color::srgba(0.0, 0.0, 0.0, 0.5).into(),
+            border_radius: BorderRadius::all(Val::Px(3.0)),
+            ..default()
+        })
+        .insert(MiniHud)
+        .insert(ResponsivePanel {
+            anchor: PanelAnchor::TopCenter,
+            width: 260.0,
+            margin: 4.0,
+        })
+        .with_children(|parent| {
+            parent
+                .spawn(TextBundle::from_section(
+                    "FPS: 0",
+                    TextStyle {
+                        font_size: 12.0,
+                        color: Color::srgb(0.0, 1.0, 0.0), // Green
+                        ..default()
+                    },
+                ))
+                .insert((MiniHudFpsText, DisplayedText("FPS: 0".to_string())));
+
+            parent
+                .spawn(TextBundle::from_section(
+                    "Entities: 0",
+                    TextStyle {
+                        font_size: 12.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                ))
+                .insert((MiniHudEntityText, DisplayedText("Entities: 0".to_string())));
+
+            parent
+                .spawn(TextBundle::from_section(
+                    "Speed 1x",
+                    TextStyle {
+                        font_size: 12.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                ))
+                .insert((MiniHudSpeedText, DisplayedText("Speed 1x".to_string())));
+        });
+
+    info!("Mini HUD created");
+}
+
+/// System to update the compact HUD from the already collected `PerformanceMetrics`
+///
+/// The speed shown is the achieved one under turbo, which may differ from the request.
+pub fn update_mini_hud(
+    performance_metrics: Res<PerformanceMetrics>,
+    time_control: Option<Res<TimeControl>>,
+    localization: Res<Localization>,
+    mut fps_query: Query<
+        (&mut Text, &mut DisplayedText),
+        (
+            With<MiniHudFpsText>,
+            Without<MiniHudEntityText>,
+            Without<MiniHudSpeedText>,
+        ),
+    >,
+    mut entity_query: Query<
+        (&mut Text, &mut DisplayedText),
+        (
+            With<MiniHudEntityText>,
+            Without<MiniHudFpsText>,
+            Without<MiniHudSpeedText>,
+        ),
+    >,
+    mut speed_query: Query<
+        (&mut Text, &mut DisplayedText),
+        (
+            With<MiniHudSpeedText>,
+            Without<MiniHudFpsText>,
+            Without<MiniHudEntityText>,
+        ),
+    >,
+) {
+    for (mut text, mut displayed) in fps_query.iter_mut() {
+        displayed.set(&mut text, format!("FPS: {:.0}", performance_metrics.fps));
+        let color = fps_color(performance_metrics.fps);
+        if text.sections[0].style.color != color {
+            text.sections[0].style.color = color;
+        }
+    }
+
+    for (mut text, mut displayed) in entity_query.iter_mut() {
+        displayed.set(
+            &mut text,
+            format!("Entities: {}", performance_metrics.total_entity_count),
+        );
+    }
+
+    if let Some(time_control) = time_control {
+        for (mut text, mut displayed) in speed_query.iter_mut() {
+            displayed.set(&mut text, speed_label(&time_control, &localization));
+        }
+    }
+}
+
+/// System to toggle the compact HUD with F2, independently of the F3 performance panel
+pub fn toggle_mini_hud_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    mut visibility_query: Query<&mut Visibility, With<MiniHud>>,
+) {
+    if key_bindings.just_pressed(&keyboard_input, InputAction::ToggleMiniHud) {
+        for mut visibility in visibility_query.iter_mut() {
+            *visibility = match *visibility {
+                Visibility::Hidden => Visibility::Inherited,
+                _ => Visibility::Hidden,
+            };
+        }
+    }
+}
@@ -1,22 +1,69 @@
 use bevy::prelude::*;
+use bincode::Options;
+use chrono::{DateTime, Utc};
 use directories::ProjectDirs;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
 use std::fs;
-use std::path::PathBuf;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
 
 use crate::components::*;
+use crate::localization::Localization;
+use crate::systems::chambers::spawn_chamber;
+use crate::systems::colony_founding::spawn_founding_queen;
+use crate::systems::foraging::spawn_food_source;
+use crate::systems::rendering::{spawn_soil_cell, spawn_worker_ant};
+use crate::systems::reproduction::spawn_egg;
+use crate::systems::reset::{despawn_simulated_entities, reset_run_state};
+
+/// Number of manual save slots offered in the slot picker
+pub const SAVE_SLOT_COUNT: usize = 3;
+
+/// Current save format version, bumped whenever saved fields change shape
+pub const SAVE_FORMAT_VERSION: u32 = 6;
+
+/// Oldest save format version that can still be migrated
+pub const MIN_SAVE_FORMAT_VERSION: u32 = 1;
+
+/// Marker opening save files that carry their format version and a [`SaveSummary`]
+/// ahead of the world
+const SAVE_HEADER_MAGIC: &[u8; 8] = b"ANTSAVE2";
+
+/// Marker of older save files with a [`SaveSummary`] but no format version
+const LEGACY_SUMMARY_MAGIC: &[u8; 8] = b"ANTSAVE1";
+
+/// First bytes of every gzip stream, telling compressed saves from plain ones
+const GZIP_MAGIC: &[u8; 2] = &[0x1f, 0x8b];
+
+/// Size of the colony picture stored with each save, in pixels
+pub const THUMBNAIL_WIDTH: u32 = 48;
+pub const THUMBNAIL_HEIGHT: u32 = 32;
 
 /// Saveable game state structure
 #[derive(Serialize, Deserialize)]
 pub struct SaveData {
-    /// All ant entities with their components
+    /// All worker ant entities with their components
     pub ants: Vec<AntData>,
     /// All soil entities with their components
     pub soil_cells: Vec<SoilData>,
-    /// All food entities with their components
-    pub food_sources: Vec<FoodSourceData>,
     /// Queen ant data
     pub queen: Option<QueenData>,
+    /// Further queens of a polygynous colony
+    pub extra_queens: Vec<QueenData>,
+    /// Excavated nest chambers
+    pub chambers: Vec<ChamberData>,
+    /// Food left on the surface
+    pub food_sources: Vec<FoodSourceData>,
+    /// Incubating eggs
+    pub eggs: Vec<EggData>,
     /// Game settings and state
     pub game_state: GameStateData,
     /// Save metadata
@@ -27,9 +74,14 @@ pub struct SaveData {
 #[derive(Serialize, Deserialize)]
 pub struct AntData {
     pub position: Position,
-    pub behavior: AntBehavior,
     pub lifecycle: Lifecycle,
-    pub inventory: Option<Inventory>,
+    pub caste: CasteClass,
+    pub health: Option<Health>,
+    pub id: Option<AntId>,
+    pub name: Option<AntName>,
+    pub behavior: Option<AntBehavior>, // Task, carried food and home; missing before v6
+    pub modifiers: Option<BehaviorModifiers>, // Missing before v6
+    pub colony: Option<ColonyId>,      // None for the home colony
 }
 
 /// Soil cell data for serialization
@@ -39,27 +91,57 @@ pub struct SoilData {
     pub soil_cell: SoilCell,
 }
 
-/// Food source data for serialization
+/// Queen ant data for serialization
+#[derive(Serialize, Deserialize)]
+pub struct QueenData {
+    pub position: Position,
+    pub founding_state: FoundingState,
+    pub traits: ColonyTraits,
+    // Missing before v6; the queen then starts from her spawn defaults
+    pub lifecycle: Option<Lifecycle>,
+    pub reproduction_state: Option<ReproductionState>,
+    pub health: Option<Health>,
+    pub royal_chamber: Option<RoyalChamber>,
+    pub founding_reserve: Option<FoundingReserve>, // None once her first workers emerged
+    pub colony: Option<ColonyId>,                  // None for the home colony
+    // Index, in `queen` followed by `extra_queens`, of the queen she founds alongside
+    pub co_founder_of: Option<usize>,
+}
+
+/// Nest chamber data for serialization
+#[derive(Serialize, Deserialize)]
+pub struct ChamberData {
+    pub position: Position,
+    pub chamber_type: ChamberType,
+    pub radius: f32,
+    pub capacity: usize,
+    pub temperature: f32,
+}
+
+/// Surface food data for serialization
 #[derive(Serialize, Deserialize)]
 pub struct FoodSourceData {
     pub position: Position,
-    pub food_source: FoodSource,
+    pub nutrition_value: f32,
+    pub max_nutrition: f32,
+    pub kind: FoodKind,
 }
 
-/// Queen ant data for serialization
+/// Egg data for serialization
 #[derive(Serialize, Deserialize)]
-pub struct QueenData {
+pub struct EggData {
     pub position: Position,
-    pub lifecycle: Lifecycle,
-    pub reproduction_state: ReproductionState,
+    pub incubation_time: f32,
+    pub colony: Option<ColonyId>, // None for the home colony
 }
 
 /// Game state data for serialization
 #[derive(Serialize, Deserialize)]
 pub struct GameStateData {
     pub simulation_time: f32,
-    pub is_paused: bool,
-    pub speed_multiplier: f32,
+    pub colony_food: f32,
+    pub colony_protein: f32,
+    pub excavated_cells: usize,
 }
 
 /// Save file metadata
@@ -70,14 +152,94 @@ pub struct SaveMetadata {
     pub colony_age: f32,
     pub ant_population: usize,
     pub version: String,
+    pub format_version: u32,
+}
+
+/// Small picture of the colony stored with a save for the slot preview
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SaveThumbnail {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<u8>, // RGBA, row by row from the top
+}
+
+/// What the slot list shows about a save, written ahead of the world so it can be read
+/// without decoding any entities
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SaveSummary {
+    pub simulated_day: u32,
+    pub population: usize,
+    pub colony_phase: String,
+    pub saved_at: String, // Real-world time, RFC 3339
+    pub thumbnail: Option<SaveThumbnail>,
+}
+
+/// Errors raised while reading or writing save files
+#[derive(Debug)]
+pub enum PersistenceError {
+    NoSaveDirectory,
+    InvalidSlot(usize),
+    EmptySlot(usize),
+    Io(std::io::Error),
+    Encoding(bincode::Error),
+    Json(serde_json::Error),
+    Migration(MigrationError),
+}
+
+impl fmt::Display for PersistenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PersistenceError::NoSaveDirectory => write!(f, "no save directory available"),
+            PersistenceError::InvalidSlot(slot) => write!(f, "slot {} does not exist", slot),
+            PersistenceError::EmptySlot(slot) => write!(f, "slot {} is empty", slot),
+            PersistenceError::Io(e) => write!(f, "file error: {}", e),
+            PersistenceError::Encoding(e) => write!(f, "corrupt save data: {}", e),
+            PersistenceError::Json(e) => write!(f, "corrupt save data: {}", e),
+            PersistenceError::Migration(e) => write!(f, "cannot migrate save: {}", e),
+        }
+    }
 }
 
+impl std::error::Error for PersistenceError {}
+
+/// Errors raised while upgrading an older save to the current format
+#[derive(Debug, PartialEq)]
+pub enum MigrationError {
+    /// Save is older than the oldest supported format
+    TooOld(u32),
+    /// Save was written by a newer build than this one
+    UnknownVersion(u32),
+    /// Save is missing data a migration step depends on
+    Malformed(String),
+}
+
+impl fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MigrationError::TooOld(version) => write!(
+                f,
+                "save format v{} is too old (oldest supported is v{})",
+                version, MIN_SAVE_FORMAT_VERSION
+            ),
+            MigrationError::UnknownVersion(version) => write!(
+                f,
+                "save format v{} is unknown (newest supported is v{})",
+                version, SAVE_FORMAT_VERSION
+            ),
+            MigrationError::Malformed(reason) => write!(f, "malformed save: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for MigrationError {}
+
 /// Resource for managing save/load operations
 #[derive(Resource, Default)]
 pub struct PersistenceState {
     pub save_directory: Option<PathBuf>,
     pub last_save_time: f32,
     pub auto_save_interval: f32, // in seconds
+    pub compress_saves: bool,    // Gzip save files; plain and compressed saves both load
     pub is_saving: bool,
     pub is_loading: bool,
 }
@@ -102,261 +264,1375 @@ impl PersistenceState {
             save_directory,
             last_save_time: 0.0,
             auto_save_interval: 300.0, // Auto-save every 5 minutes
+            compress_saves: false,
             is_saving: false,
             is_loading: false,
         }
     }
 }
 
-/// Save the current game state to a file
-pub fn save_game_system(
-    mut persistence_state: ResMut<PersistenceState>,
-    time: Res<Time>,
-    ant_query: Query<
-        (&Position, &AntBehavior, &Lifecycle, Option<&Inventory>),
-        (With<Ant>, Without<Queen>),
-    >,
-    soil_query: Query<(&Position, &SoilCell), With<Soil>>,
-    food_query: Query<(&Position, &FoodSource), With<Food>>,
-    queen_query: Query<(&Position, &Lifecycle, &ReproductionState), With<Queen>>,
-    input: Res<ButtonInput<KeyCode>>,
-) {
-    // Manual save with Ctrl+S
-    let should_manual_save =
-        input.pressed(KeyCode::ControlLeft) && input.just_pressed(KeyCode::KeyS);
+/// Summary of one manual save slot
+#[derive(Clone, Debug)]
+pub struct SaveSlotInfo {
+    pub slot: usize, // 1-based slot number
+    pub saved_at: Option<DateTime<Utc>>,
+    pub summary: Option<SaveSummary>, // Missing for empty slots and older saves
+}
 
-    // Auto-save check
-    let time_since_last_save = time.elapsed_seconds() - persistence_state.last_save_time;
-    let should_auto_save = time_since_last_save >= persistence_state.auto_save_interval;
+/// Resource listing the manual save slots and the last slot operation result
+#[derive(Resource, Clone, Debug)]
+pub struct SaveSlots {
+    pub slots: Vec<SaveSlotInfo>,
+    pub status_message: Option<String>,
+}
 
-    if !should_manual_save && !should_auto_save {
-        return;
+impl SaveSlots {
+    pub fn new(slot_count: usize) -> Self {
+        Self {
+            slots: (1..=slot_count)
+                .map(|slot| SaveSlotInfo {
+                    slot,
+                    saved_at: None,
+                    summary: None,
+                })
+                .collect(),
+            status_message: None,
+        }
     }
 
-    if persistence_state.is_saving {
-        return; // Already saving
+    /// Re-read slot timestamps and summaries from the save directory
+    ///
+    /// Only each file's summary header is decoded, so this stays fast for large worlds.
+    pub fn refresh(&mut self, save_dir: &Path) {
+        for info in self.slots.iter_mut() {
+            let path = slot_path(save_dir, info.slot);
+            info.saved_at = fs::metadata(&path)
+                .and_then(|metadata| metadata.modified())
+                .ok()
+                .map(DateTime::<Utc>::from);
+            info.summary = read_save_summary(&path).ok().flatten();
+        }
     }
 
-    persistence_state.is_saving = true;
+    pub fn get(&self, slot: usize) -> Option<&SaveSlotInfo> {
+        self.slots.iter().find(|info| info.slot == slot)
+    }
+}
+
+impl Default for SaveSlots {
+    fn default() -> Self {
+        Self::new(SAVE_SLOT_COUNT)
+    }
+}
+
+/// Operations offered for each save slot
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SlotAction {
+    Save,
+    Load,
+    Delete,
+}
+
+/// Request to save, load or delete a manual save slot
+#[derive(Event, Clone, Copy, Debug)]
+pub struct SaveSlotRequest {
+    pub slot: usize,
+    pub action: SlotAction,
+}
+
+/// File path of a manual save slot
+pub fn slot_path(save_dir: &Path, slot: usize) -> PathBuf {
+    save_dir.join(format!("slot_{}.dat", slot))
+}
+
+/// Serialize a save to a file: the format version and summary header first, then the world
+///
+/// With `compress` the whole file is gzipped; [`read_save`] tells the two apart.
+pub fn write_save(
+    path: &Path,
+    summary: &SaveSummary,
+    save_data: &SaveData,
+    compress: bool,
+) -> Result<(), PersistenceError> {
+    let mut encoded = SAVE_HEADER_MAGIC.to_vec();
+    bincode::serialize_into(&mut encoded, &SAVE_FORMAT_VERSION)
+        .map_err(PersistenceError::Encoding)?;
+    bincode::serialize_into(&mut encoded, summary).map_err(PersistenceError::Encoding)?;
+    bincode::serialize_into(&mut encoded, save_data).map_err(PersistenceError::Encoding)?;
+    let mut file = fs::File::create(path).map_err(PersistenceError::Io)?;
+    if compress {
+        let mut encoder = GzEncoder::new(file, Compression::default());
+        encoder
+            .write_all(&encoded)
+            .and_then(|()| encoder.finish().map(|_| ()))
+            .map_err(PersistenceError::Io)
+    } else {
+        file.write_all(&encoded).map_err(PersistenceError::Io)
+    }
+}
+
+/// Open a save file for reading, decompressing it on the fly if it is gzipped
+fn open_save(path: &Path) -> Result<Box<dyn Read>, PersistenceError> {
+    let mut reader = BufReader::new(fs::File::open(path).map_err(PersistenceError::Io)?);
+    let compressed = reader
+        .fill_buf()
+        .map_err(PersistenceError::Io)?
+        .starts_with(GZIP_MAGIC);
+    Ok(if compressed {
+        Box::new(GzDecoder::new(reader))
+    } else {
+        Box::new(reader)
+    })
+}
+
+/// Deserialize save data from a file, with or without a header or compression, migrating
+/// older format versions first
+///
+/// Files written before the header carried a version are decoded with the newest
+/// format layout their contents fit.
+pub fn read_save(path: &Path) -> Result<SaveData, PersistenceError> {
+    let mut data = Vec::new();
+    open_save(path)?
+        .read_to_end(&mut data)
+        .map_err(PersistenceError::Io)?;
+
+    if let Some(mut rest) = data.strip_prefix(SAVE_HEADER_MAGIC.as_slice()) {
+        let version: u32 =
+            bincode::deserialize_from(&mut rest).map_err(PersistenceError::Encoding)?;
+        let _summary: SaveSummary =
+            bincode::deserialize_from(&mut rest).map_err(PersistenceError::Encoding)?;
+        return decode_save_body(rest, version);
+    }
+
+    let body = match data.strip_prefix(LEGACY_SUMMARY_MAGIC.as_slice()) {
+        Some(mut rest) => {
+            let _summary: SaveSummary =
+                bincode::deserialize_from(&mut rest).map_err(PersistenceError::Encoding)?;
+            rest
+        }
+        None => &data,
+    };
+    let current = decode_save_body(body, SAVE_FORMAT_VERSION);
+    if current.is_ok() {
+        return current;
+    }
+    (MIN_SAVE_FORMAT_VERSION..SAVE_FORMAT_VERSION)
+        .rev()
+        .find_map(|version| decode_save_body(body, version).ok())
+        .map_or(current, Ok)
+}
+
+/// Read only the summary header of a save; `None` for saves written before it existed
+pub fn read_save_summary(path: &Path) -> Result<Option<SaveSummary>, PersistenceError> {
+    let mut reader = open_save(path)?;
+    let mut magic = [0u8; SAVE_HEADER_MAGIC.len()];
+    if reader.read_exact(&mut magic).is_err() {
+        return Ok(None);
+    }
+    if &magic == SAVE_HEADER_MAGIC {
+        let _version: u32 =
+            bincode::deserialize_from(&mut reader).map_err(PersistenceError::Encoding)?;
+    } else if &magic != LEGACY_SUMMARY_MAGIC {
+        return Ok(None);
+    }
+    bincode::deserialize_from(reader)
+        .map(Some)
+        .map_err(PersistenceError::Encoding)
+}
+
+/// Bincode settings of [`bincode::serialize`] that also refuse leftover bytes, so a body
+/// read with the wrong format layout fails instead of decoding part of the file
+fn exact_bincode() -> impl Options {
+    bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .reject_trailing_bytes()
+}
 
-    // Collect ant data
+/// Decode a save body written in format `version`, migrating it to the current format
+fn decode_save_body(body: &[u8], version: u32) -> Result<SaveData, PersistenceError> {
+    if version == SAVE_FORMAT_VERSION {
+        return exact_bincode()
+            .deserialize(body)
+            .map_err(PersistenceError::Encoding);
+    }
+    let raw = match version {
+        1 => legacy_json::<legacy::SaveDataV1>(body)?,
+        2 => legacy_json::<legacy::SaveDataV2>(body)?,
+        3 => legacy_json::<legacy::SaveDataV3>(body)?,
+        4 => legacy_json::<legacy::SaveDataV4>(body)?,
+        5 => legacy_json::<legacy::SaveDataV5>(body)?,
+        _ if version < MIN_SAVE_FORMAT_VERSION => {
+            return Err(PersistenceError::Migration(MigrationError::TooOld(version)))
+        }
+        _ => {
+            return Err(PersistenceError::Migration(MigrationError::UnknownVersion(
+                version,
+            )))
+        }
+    };
+    let migrated =
+        migrate_save(raw, version, SAVE_FORMAT_VERSION).map_err(PersistenceError::Migration)?;
+    serde_json::from_value(migrated).map_err(PersistenceError::Json)
+}
+
+/// Decode a body in an older bincode layout into JSON for [`migrate_save`]
+fn legacy_json<T: Serialize + DeserializeOwned>(
+    body: &[u8],
+) -> Result<serde_json::Value, PersistenceError> {
+    let legacy: T = exact_bincode()
+        .deserialize(body)
+        .map_err(PersistenceError::Encoding)?;
+    serde_json::to_value(legacy).map_err(PersistenceError::Json)
+}
+
+/// Bincode layouts of earlier save formats, decoded only to be migrated
+///
+/// Bincode is not self-describing, so a body can only be read back in the exact shape
+/// it was written in. Types that have not changed since are shared with the current
+/// format.
+mod legacy {
+    use super::{ChamberData, FoodSourceData, GameStateData, SaveMetadata, SoilData};
+    use crate::components::{
+        AntId, AntName, CasteClass, ColonyTraits, FoundingState, Health, Lifecycle, Position,
+    };
+    use serde::{Deserialize, Serialize};
+
+    /// v1: soil cells without compaction, metadata without a format version
+    #[derive(Serialize, Deserialize)]
+    pub struct SaveDataV1 {
+        pub ants: Vec<AntDataV1>,
+        pub soil_cells: Vec<SoilDataV1>,
+        pub queen: Option<QueenDataV1>,
+        pub game_state: GameStateData,
+        pub metadata: SaveMetadataV1,
+    }
+
+    /// v2: ants without an id or name
+    #[derive(Serialize, Deserialize)]
+    pub struct SaveDataV2 {
+        pub ants: Vec<AntDataV1>,
+        pub soil_cells: Vec<SoilData>,
+        pub queen: Option<QueenDataV1>,
+        pub game_state: GameStateData,
+        pub metadata: SaveMetadata,
+    }
+
+    /// v3: a single queen, without `extra_queens`
+    #[derive(Serialize, Deserialize)]
+    pub struct SaveDataV3 {
+        pub ants: Vec<AntDataV3>,
+        pub soil_cells: Vec<SoilData>,
+        pub queen: Option<QueenDataV1>,
+        pub game_state: GameStateData,
+        pub metadata: SaveMetadata,
+    }
+
+    /// v4: no chambers, surface food or eggs
+    #[derive(Serialize, Deserialize)]
+    pub struct SaveDataV4 {
+        pub ants: Vec<AntDataV3>,
+        pub soil_cells: Vec<SoilData>,
+        pub queen: Option<QueenDataV1>,
+        pub extra_queens: Vec<QueenDataV1>,
+        pub game_state: GameStateData,
+        pub metadata: SaveMetadata,
+    }
+
+    /// v5: ants and queens without their behavior or condition, nothing kept a colony
+    #[derive(Serialize, Deserialize)]
+    pub struct SaveDataV5 {
+        pub ants: Vec<AntDataV3>,
+        pub soil_cells: Vec<SoilData>,
+        pub queen: Option<QueenDataV1>,
+        pub extra_queens: Vec<QueenDataV1>,
+        pub chambers: Vec<ChamberData>,
+        pub food_sources: Vec<FoodSourceData>,
+        pub eggs: Vec<EggDataV5>,
+        pub game_state: GameStateData,
+        pub metadata: SaveMetadata,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct AntDataV1 {
+        pub position: Position,
+        pub lifecycle: Lifecycle,
+        pub caste: CasteClass,
+        pub health: Option<Health>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct AntDataV3 {
+        pub position: Position,
+        pub lifecycle: Lifecycle,
+        pub caste: CasteClass,
+        pub health: Option<Health>,
+        pub id: Option<AntId>,
+        pub name: Option<AntName>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct EggDataV5 {
+        pub position: Position,
+        pub incubation_time: f32,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct QueenDataV1 {
+        pub position: Position,
+        pub founding_state: FoundingState,
+        pub traits: ColonyTraits,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct SoilDataV1 {
+        pub position: Position,
+        pub soil_cell: SoilCellV1,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct SoilCellV1 {
+        pub moisture: f32,
+        pub temperature: f32,
+        pub nutrition: f32,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct SaveMetadataV1 {
+        pub save_name: String,
+        pub creation_time: String,
+        pub colony_age: f32,
+        pub ant_population: usize,
+        pub version: String,
+    }
+}
+
+/// Upgrade raw save JSON from format version `from` to `to`, one step at a time
+pub fn migrate_save(
+    mut raw: serde_json::Value,
+    from: u32,
+    to: u32,
+) -> Result<serde_json::Value, MigrationError> {
+    if from < MIN_SAVE_FORMAT_VERSION {
+        return Err(MigrationError::TooOld(from));
+    }
+    if from > SAVE_FORMAT_VERSION {
+        return Err(MigrationError::UnknownVersion(from));
+    }
+    if to > SAVE_FORMAT_VERSION || to < from {
+        return Err(MigrationError::UnknownVersion(to));
+    }
+
+    for version in from..to {
+        raw = match version {
+            1 => migrate_v1_to_v2(raw)?,
+            2 => migrate_v2_to_v3(raw)?,
+            3 => migrate_v3_to_v4(raw)?,
+            4 => migrate_v4_to_v5(raw)?,
+            5 => migrate_v5_to_v6(raw)?,
+            _ => return Err(MigrationError::UnknownVersion(version)),
+        };
+    }
+
+    if let Some(metadata) = raw.get_mut("metadata").and_then(|m| m.as_object_mut()) {
+        metadata.insert("format_version".to_string(), to.into());
+    }
+    Ok(raw)
+}
+
+/// v1 → v2: soil cells gain `compaction`, defaulted from each cell's depth
+fn migrate_v1_to_v2(mut raw: serde_json::Value) -> Result<serde_json::Value, MigrationError> {
+    let soil_cells = raw
+        .get_mut("soil_cells")
+        .and_then(|cells| cells.as_array_mut())
+        .ok_or_else(|| MigrationError::Malformed("missing soil_cells".to_string()))?;
+
+    for soil_data in soil_cells.iter_mut() {
+        let y = soil_data
+            .pointer("/position/y")
+            .and_then(|y| y.as_f64())
+            .ok_or_else(|| MigrationError::Malformed("soil cell without position".to_string()))?;
+        let soil_cell = soil_data
+            .get_mut("soil_cell")
+            .and_then(|cell| cell.as_object_mut())
+            .ok_or_else(|| MigrationError::Malformed("soil cell without data".to_string()))?;
+        soil_cell
+            .entry("compaction")
+            .or_insert_with(|| SoilCell::compaction_at_depth(y as f32).into());
+    }
+
+    Ok(raw)
+}
+
+/// v2 → v3: ants gain an optional `id` and `name`, absent for ants saved before them
+fn migrate_v2_to_v3(mut raw: serde_json::Value) -> Result<serde_json::Value, MigrationError> {
+    let ants = raw
+        .get_mut("ants")
+        .and_then(|ants| ants.as_array_mut())
+        .ok_or_else(|| MigrationError::Malformed("missing ants".to_string()))?;
+
+    for ant_data in ants.iter_mut() {
+        let ant_data = ant_data
+            .as_object_mut()
+            .ok_or_else(|| MigrationError::Malformed("ant without data".to_string()))?;
+        ant_data.entry("id").or_insert(serde_json::Value::Null);
+        ant_data.entry("name").or_insert(serde_json::Value::Null);
+    }
+
+    Ok(raw)
+}
+
+/// v3 → v4: saves gain `extra_queens`, empty for colonies saved with a single queen
+fn migrate_v3_to_v4(mut raw: serde_json::Value) -> Result<serde_json::Value, MigrationError> {
+    let save = raw
+        .as_object_mut()
+        .ok_or_else(|| MigrationError::Malformed("save is not an object".to_string()))?;
+    save.entry("extra_queens")
+        .or_insert_with(|| serde_json::Value::Array(Vec::new()));
+
+    Ok(raw)
+}
+
+/// v4 → v5: saves gain chambers, surface food and eggs, none of which older saves kept
+fn migrate_v4_to_v5(mut raw: serde_json::Value) -> Result<serde_json::Value, MigrationError> {
+    let save = raw
+        .as_object_mut()
+        .ok_or_else(|| MigrationError::Malformed("save is not an object".to_string()))?;
+    for field in ["chambers", "food_sources", "eggs"] {
+        save.entry(field)
+            .or_insert_with(|| serde_json::Value::Array(Vec::new()));
+    }
+
+    Ok(raw)
+}
+
+/// v5 → v6: ants gain their `behavior`, `modifiers` and `colony`, queens their lifecycle,
+/// laying state, health, royal chamber, founding reserve and `colony`, eggs their
+/// `colony`; older saves kept none of them, and all their ants belong to the home colony
+fn migrate_v5_to_v6(mut raw: serde_json::Value) -> Result<serde_json::Value, MigrationError> {
+    let ants = raw
+        .get_mut("ants")
+        .and_then(|ants| ants.as_array_mut())
+        .ok_or_else(|| MigrationError::Malformed("missing ants".to_string()))?;
+    for ant_data in ants.iter_mut() {
+        let ant_data = ant_data
+            .as_object_mut()
+            .ok_or_else(|| MigrationError::Malformed("ant without data".to_string()))?;
+        for field in ["behavior", "modifiers", "colony"] {
+            ant_data.entry(field).or_insert(serde_json::Value::Null);
+        }
+    }
+    let eggs = raw
+        .get_mut("eggs")
+        .and_then(|eggs| eggs.as_array_mut())
+        .ok_or_else(|| MigrationError::Malformed("missing eggs".to_string()))?;
+    for egg_data in eggs.iter_mut() {
+        let egg_data = egg_data
+            .as_object_mut()
+            .ok_or_else(|| MigrationError::Malformed("egg without data".to_string()))?;
+        egg_data.entry("colony").or_insert(serde_json::Value::Null);
+    }
+
+    let save = raw
+        .as_object_mut()
+        .ok_or_else(|| MigrationError::Malformed("save is not an object".to_string()))?;
+    let extra_queens = save
+        .get_mut("extra_queens")
+        .and_then(|queens| queens.as_array_mut())
+        .ok_or_else(|| MigrationError::Malformed("missing extra_queens".to_string()))?;
+    for queen_data in extra_queens.iter_mut() {
+        add_v6_queen_fields(queen_data)?;
+    }
+    if let Some(queen_data) = save.get_mut("queen").filter(|queen| !queen.is_null()) {
+        add_v6_queen_fields(queen_data)?;
+    }
+
+    Ok(raw)
+}
+
+/// Queen fields new in v6, absent for queens saved before them
+fn add_v6_queen_fields(queen_data: &mut serde_json::Value) -> Result<(), MigrationError> {
+    let queen_data = queen_data
+        .as_object_mut()
+        .ok_or_else(|| MigrationError::Malformed("queen without data".to_string()))?;
+    for field in [
+        "lifecycle",
+        "reproduction_state",
+        "health",
+        "royal_chamber",
+        "founding_reserve",
+        "colony",
+        "co_founder_of",
+    ] {
+        queen_data.entry(field).or_insert(serde_json::Value::Null);
+    }
+    Ok(())
+}
+
+/// Parse save data from JSON, migrating older format versions first
+///
+/// Saves written before `format_version` existed are treated as v1.
+pub fn load_from_json(json: &str) -> Result<SaveData, PersistenceError> {
+    let raw: serde_json::Value = serde_json::from_str(json).map_err(PersistenceError::Json)?;
+    let from = raw
+        .pointer("/metadata/format_version")
+        .and_then(|version| version.as_u64())
+        .map_or(MIN_SAVE_FORMAT_VERSION, |version| version as u32);
+
+    let migrated =
+        migrate_save(raw, from, SAVE_FORMAT_VERSION).map_err(PersistenceError::Migration)?;
+    serde_json::from_value(migrated).map_err(PersistenceError::Json)
+}
+
+/// Build save data from the current colony state
+fn build_save_data(
+    save_name: String,
+    simulation_time: f32,
+    ant_query: &SavedAnts,
+    soil_query: &Query<(&Position, &SoilCell), With<Soil>>,
+    queen_query: &SavedQueens,
+    nest_contents: &NestContents,
+    food_store: &ColonyFoodStore,
+    nest_space: &NestSpace,
+) -> SaveData {
     let ants: Vec<AntData> = ant_query
         .iter()
-        .map(|(pos, behavior, lifecycle, inventory)| AntData {
-            position: pos.clone(),
-            behavior: behavior.clone(),
-            lifecycle: lifecycle.clone(),
-            inventory: inventory.cloned(),
-        })
+        .map(
+            |(position, lifecycle, caste, health, id, name, behavior, modifiers, colony)| AntData {
+                position: position.clone(),
+                lifecycle: lifecycle.clone(),
+                caste: caste.map_or(CasteClass::Media, |caste| caste.size_class),
+                health: health.cloned(),
+                id: id.copied(),
+                name: name.cloned(),
+                behavior: behavior.cloned(),
+                modifiers: modifiers.copied(),
+                colony: colony.copied(),
+            },
+        )
         .collect();
 
-    // Collect soil data
-    let soil_cells: Vec<SoilData> = soil_query
+    let soil_cells = soil_query
         .iter()
-        .map(|(pos, soil_cell)| SoilData {
-            position: pos.clone(),
+        .map(|(position, soil_cell)| SoilData {
+            position: position.clone(),
             soil_cell: soil_cell.clone(),
         })
         .collect();
 
-    // Collect food source data
-    let food_sources: Vec<FoodSourceData> = food_query
+    // Co-founders point at their founder by her place in the saved queens
+    let queen_indices: HashMap<Entity, usize> = queen_query
         .iter()
-        .map(|(pos, food_source)| FoodSourceData {
-            position: pos.clone(),
-            food_source: food_source.clone(),
-        })
+        .enumerate()
+        .map(|(index, (queen, ..))| (queen, index))
         .collect();
+    let mut queens = queen_query.iter().map(
+        |(
+            _,
+            position,
+            queen,
+            traits,
+            lifecycle,
+            reproduction_state,
+            health,
+            royal_chamber,
+            founding_reserve,
+            colony,
+            co_founding,
+        )| QueenData {
+            position: position.clone(),
+            founding_state: queen.founding_state.clone(),
+            traits: traits.clone(),
+            lifecycle: lifecycle.cloned(),
+            reproduction_state: reproduction_state.cloned(),
+            health: health.cloned(),
+            royal_chamber: royal_chamber.cloned(),
+            founding_reserve: founding_reserve.cloned(),
+            colony: colony.copied(),
+            co_founder_of: co_founding
+                .and_then(|co_founding| queen_indices.get(&co_founding.founder).copied()),
+        },
+    );
+    let queen = queens.next();
+    let extra_queens = queens.collect();
 
-    // Collect queen data
-    let queen = queen_query
-        .get_single()
-        .ok()
-        .map(|(pos, lifecycle, reproduction)| QueenData {
-            position: pos.clone(),
-            lifecycle: lifecycle.clone(),
-            reproduction_state: reproduction.clone(),
-        });
-
-    // Create game state
-    let game_state = GameStateData {
-        simulation_time: time.elapsed_seconds(),
-        is_paused: false,      // Pure observation mode - never paused
-        speed_multiplier: 1.0, // Pure observation mode - normal speed
-    };
+    let (chamber_query, food_query, egg_query) = nest_contents;
+    let chambers = chamber_query
+        .iter()
+        .map(|(position, chamber)| ChamberData {
+            position: position.clone(),
+            chamber_type: chamber.chamber_type,
+            radius: chamber.radius,
+            capacity: chamber.capacity,
+            temperature: chamber.temperature,
+        })
+        .collect();
+    let food_sources = food_query
+        .iter()
+        .map(|(position, food)| FoodSourceData {
+            position: position.clone(),
+            nutrition_value: food.nutrition_value,
+            max_nutrition: food.max_nutrition,
+            kind: food.kind,
+        })
+        .collect();
+    let eggs = egg_query
+        .iter()
+        .map(|(position, egg, colony)| EggData {
+            position: position.clone(),
+            incubation_time: egg.incubation_time,
+            colony: colony.copied(),
+        })
+        .collect();
 
-    // Create metadata
     let metadata = SaveMetadata {
-        save_name: format!("colony_{}", chrono::Utc::now().format("%Y%m%d_%H%M%S")),
-        creation_time: chrono::Utc::now().to_rfc3339(),
-        colony_age: time.elapsed_seconds(),
+        save_name,
+        creation_time: Utc::now().to_rfc3339(),
+        colony_age: simulation_time,
         ant_population: ants.len(),
         version: env!("CARGO_PKG_VERSION").to_string(),
+        format_version: SAVE_FORMAT_VERSION,
     };
 
-    // Create save data
-    let save_data = SaveData {
+    SaveData {
         ants,
         soil_cells,
-        food_sources,
         queen,
-        game_state,
+        extra_queens,
+        chambers,
+        food_sources,
+        eggs,
+        game_state: GameStateData {
+            simulation_time,
+            colony_food: food_store.food,
+            colony_protein: food_store.protein,
+            excavated_cells: nest_space.excavated_cells,
+        },
         metadata,
+    }
+}
+
+/// Workers and the components saved with them
+type SavedAnts<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Position,
+        &'static Lifecycle,
+        Option<&'static Caste>,
+        Option<&'static Health>,
+        Option<&'static AntId>,
+        Option<&'static AntName>,
+        Option<&'static AntBehavior>,
+        Option<&'static BehaviorModifiers>,
+        Option<&'static ColonyId>,
+    ),
+    (With<Ant>, Without<Queen>),
+>;
+
+/// Queens and the components saved with them
+type SavedQueens<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Position,
+        &'static Queen,
+        &'static ColonyTraits,
+        Option<&'static Lifecycle>,
+        Option<&'static ReproductionState>,
+        Option<&'static Health>,
+        Option<&'static RoyalChamber>,
+        Option<&'static FoundingReserve>,
+        Option<&'static ColonyId>,
+        Option<&'static CoFoundingQueen>,
+    ),
+>;
+
+/// Saved entities besides ants, soil and queens: chambers, surface food and eggs
+type NestContents<'w, 's> = (
+    Query<'w, 's, (&'static Position, &'static Chamber)>,
+    Query<'w, 's, (&'static Position, &'static FoodSource)>,
+    Query<'w, 's, (&'static Position, &'static Egg, Option<&'static ColonyId>)>,
+);
+
+/// Resources a save summary is taken from; each falls back to its default when absent
+type SummarySources<'w> = (
+    Option<Res<'w, SimulationTime>>,
+    Option<Res<'w, ColonyDevelopmentPhase>>,
+    Option<Res<'w, WorldConfig>>,
+);
+
+/// Summary of a save for the slot list, with a thumbnail drawn from its positions
+fn build_save_summary(save_data: &SaveData, sources: &SummarySources) -> SaveSummary {
+    let (simulation_time, phase, world_config) = sources;
+    let world_config = world_config.as_deref().cloned().unwrap_or_default();
+    SaveSummary {
+        simulated_day: simulation_time.as_ref().map_or(1, |time| time.current_day),
+        population: save_data.ants.len(),
+        colony_phase: phase
+            .as_ref()
+            .map_or(DevelopmentPhase::QueenFounding, |phase| phase.current_phase)
+            .display_name()
+            .to_string(),
+        saved_at: save_data.metadata.creation_time.clone(),
+        thumbnail: Some(colony_thumbnail(save_data, &world_config)),
+    }
+}
+
+/// Downscaled picture of the colony: soil, dug-out space, workers and the queen
+pub fn colony_thumbnail(save_data: &SaveData, world_config: &WorldConfig) -> SaveThumbnail {
+    const SKY: [u8; 4] = [150, 190, 230, 255];
+    const TUNNEL: [u8; 4] = [45, 30, 20, 255];
+    const SOIL: [u8; 4] = [120, 85, 55, 255];
+    const ANT: [u8; 4] = [15, 15, 15, 255];
+    const QUEEN: [u8; 4] = [200, 40, 40, 255];
+
+    let (width, height) = (THUMBNAIL_WIDTH, THUMBNAIL_HEIGHT);
+    let pixel_of = |position: &Position| {
+        let u = (position.x - world_config.min_x) / (world_config.max_x - world_config.min_x);
+        let v = (world_config.max_y - position.y) / (world_config.max_y - world_config.min_y);
+        let x = (u * width as f32).clamp(0.0, width as f32 - 1.0) as usize;
+        let y = (v * height as f32).clamp(0.0, height as f32 - 1.0) as usize;
+        y * width as usize + x
     };
 
-    // Save to file
-    if let Some(save_dir) = &persistence_state.save_directory {
-        let filename = if should_manual_save {
-            format!(
-                "manual_save_{}.dat",
-                chrono::Utc::now().format("%Y%m%d_%H%M%S")
-            )
-        } else {
-            "auto_save.dat".to_string()
-        };
+    // Above ground is sky; below it, anything not covered by soil has been dug out
+    let mut colors: Vec<[u8; 4]> = (0..height)
+        .flat_map(|row| {
+            let depth = world_config.max_y
+                - (row as f32 + 0.5) / height as f32 * (world_config.max_y - world_config.min_y);
+            std::iter::repeat_n(if depth > 0.0 { SKY } else { TUNNEL }, width as usize)
+        })
+        .collect();
+    for soil in &save_data.soil_cells {
+        colors[pixel_of(&soil.position)] = SOIL;
+    }
+    for ant in &save_data.ants {
+        colors[pixel_of(&ant.position)] = ANT;
+    }
+    if let Some(queen) = &save_data.queen {
+        colors[pixel_of(&queen.position)] = QUEEN;
+    }
 
-        let save_path = save_dir.join(filename);
+    SaveThumbnail {
+        width,
+        height,
+        pixels: colors.concat(),
+    }
+}
 
-        match bincode::serialize(&save_data) {
-            Ok(encoded) => match fs::write(&save_path, encoded) {
-                Ok(_) => {
-                    info!("Game saved successfully to {:?}", save_path);
-                    persistence_state.last_save_time = time.elapsed_seconds();
-                }
-                Err(e) => {
-                    error!("Failed to write save file: {}", e);
-                }
-            },
-            Err(e) => {
-                error!("Failed to serialize save data: {}", e);
-            }
+/// Periodically auto-save the current game state
+pub fn save_game_system(
+    mut persistence_state: ResMut<PersistenceState>,
+    time: Res<Time>,
+    ant_query: SavedAnts,
+    soil_query: Query<(&Position, &SoilCell), With<Soil>>,
+    queen_query: SavedQueens,
+    nest_contents: NestContents,
+    food_store: Res<ColonyFoodStore>,
+    nest_space: Res<NestSpace>,
+    summary_sources: SummarySources,
+) {
+    let time_since_last_save = time.elapsed_seconds() - persistence_state.last_save_time;
+    if time_since_last_save < persistence_state.auto_save_interval || persistence_state.is_saving {
+        return;
+    }
+
+    let Some(save_dir) = persistence_state.save_directory.clone() else {
+        return;
+    };
+
+    persistence_state.is_saving = true;
+
+    let save_data = build_save_data(
+        "auto_save".to_string(),
+        simulated_seconds(&summary_sources),
+        &ant_query,
+        &soil_query,
+        &queen_query,
+        &nest_contents,
+        &food_store,
+        &nest_space,
+    );
+
+    let summary = build_save_summary(&save_data, &summary_sources);
+    let save_path = save_dir.join("auto_save.dat");
+    match write_save(
+        &save_path,
+        &summary,
+        &save_data,
+        persistence_state.compress_saves,
+    ) {
+        Ok(()) => {
+            info!("Game auto-saved to {:?}", save_path);
+            persistence_state.last_save_time = time.elapsed_seconds();
         }
+        Err(e) => error!("Failed to auto-save: {}", e),
     }
 
     persistence_state.is_saving = false;
 }
 
-/// Load game state from a file
-pub fn load_game_system(
+/// System to populate the slot list from disk at startup
+pub fn refresh_save_slots_system(
+    persistence_state: Res<PersistenceState>,
+    mut save_slots: ResMut<SaveSlots>,
+) {
+    if let Some(save_dir) = &persistence_state.save_directory {
+        save_slots.refresh(save_dir);
+    }
+}
+
+/// System to carry out save/load/delete requests for manual save slots
+pub fn save_slot_request_system(
     mut commands: Commands,
+    mut requests: EventReader<SaveSlotRequest>,
     mut persistence_state: ResMut<PersistenceState>,
-    input: Res<ButtonInput<KeyCode>>,
-    existing_ants: Query<Entity, With<Ant>>,
-    existing_soil: Query<Entity, With<Soil>>,
-    existing_food: Query<Entity, With<Food>>,
-    existing_queen: Query<Entity, With<Queen>>,
+    mut save_slots: ResMut<SaveSlots>,
+    mut food_store: ResMut<ColonyFoodStore>,
+    mut nest_space: ResMut<NestSpace>,
+    hatch_config: Option<Res<HatchTraitConfig>>,
+    ai_config: Option<Res<AntAiConfig>>,
+    mut simulation_rng: Option<ResMut<SimulationRng>>,
+    ant_query: SavedAnts,
+    soil_query: Query<(&Position, &SoilCell), With<Soil>>,
+    queen_query: SavedQueens,
+    nest_contents: NestContents,
+    summary_sources: SummarySources,
+    localization: Res<Localization>,
 ) {
-    // Load with Ctrl+L
-    if !(input.pressed(KeyCode::ControlLeft) && input.just_pressed(KeyCode::KeyL)) {
-        return;
+    for request in requests.read() {
+        let result = match persistence_state.save_directory.clone() {
+            None => Err(PersistenceError::NoSaveDirectory),
+            Some(_) if save_slots.get(request.slot).is_none() => {
+                Err(PersistenceError::InvalidSlot(request.slot))
+            }
+            Some(save_dir) => {
+                let path = slot_path(&save_dir, request.slot);
+                match request.action {
+                    SlotAction::Save => {
+                        persistence_state.is_saving = true;
+                        let save_data = build_save_data(
+                            format!("slot_{}", request.slot),
+                            simulated_seconds(&summary_sources),
+                            &ant_query,
+                            &soil_query,
+                            &queen_query,
+                            &nest_contents,
+                            &food_store,
+                            &nest_space,
+                        );
+                        let summary = build_save_summary(&save_data, &summary_sources);
+                        let result = write_save(
+                            &path,
+                            &summary,
+                            &save_data,
+                            persistence_state.compress_saves,
+                        );
+                        persistence_state.is_saving = false;
+                        result
+                    }
+                    SlotAction::Load => {
+                        if !path.exists() {
+                            Err(PersistenceError::EmptySlot(request.slot))
+                        } else {
+                            persistence_state.is_loading = true;
+                            let result = read_save(&path).map(|save_data| {
+                                // Queued ahead of the restored entities, so only the old
+                                // world and its run state go
+                                commands.add(|world: &mut World| {
+                                    despawn_simulated_entities(world);
+                                    reset_run_state(world);
+                                });
+                                let simulation_time =
+                                    summary_sources.0.as_deref().cloned().unwrap_or_default();
+                                let hatch_config =
+                                    hatch_config.as_deref().cloned().unwrap_or_default();
+                                let ai_config = ai_config.as_deref().cloned().unwrap_or_default();
+                                // Ants saved without their behavior roll a fresh heading
+                                let mut rng = simulation_rng
+                                    .as_deref_mut()
+                                    .map_or_else(StdRng::from_entropy, SimulationRng::fork);
+                                restore_save_data(
+                                    &mut commands,
+                                    save_data,
+                                    simulation_time,
+                                    &hatch_config,
+                                    &ai_config,
+                                    &mut food_store,
+                                    &mut nest_space,
+                                    &mut rng,
+                                );
+                            });
+                            persistence_state.is_loading = false;
+                            result
+                        }
+                    }
+                    SlotAction::Delete => {
+                        if path.exists() {
+                            fs::remove_file(&path).map_err(PersistenceError::Io)
+                        } else {
+                            Err(PersistenceError::EmptySlot(request.slot))
+                        }
+                    }
+                }
+                .inspect(|_| save_slots.refresh(&save_dir))
+            }
+        };
+
+        save_slots.status_message = Some(match result {
+            Ok(()) => {
+                let verb = match request.action {
+                    SlotAction::Save => "slots.saved_to",
+                    SlotAction::Load => "slots.loaded",
+                    SlotAction::Delete => "slots.deleted",
+                };
+                info!("Slot {} request done: {:?}", request.slot, request.action);
+                format!("{} {}", localization.t(verb), request.slot)
+            }
+            Err(e) => {
+                warn!("Save slot {} failed: {}", request.slot, e);
+                format!("{}: {}", localization.t("slots.error"), e)
+            }
+        });
     }
+}
 
-    if persistence_state.is_loading {
-        return; // Already loading
+/// Simulated seconds since the colony started, or the default clock's when there is none
+fn simulated_seconds(sources: &SummarySources) -> f32 {
+    sources
+        .0
+        .as_deref()
+        .cloned()
+        .unwrap_or_default()
+        .elapsed_seconds
+}
+
+/// Spawn entities and restore resources from loaded save data
+///
+/// The simulated clock is wound to the saved time, keeping the current day length.
+/// Workers keep their saved [`BehaviorModifiers`]; saves made before those were kept
+/// derive them from the saved queen's traits, as on hatching.
+/// Saved behavior, laying state, health and chambers replace the spawn defaults where
+/// the save has them.
+fn restore_save_data(
+    commands: &mut Commands,
+    save_data: SaveData,
+    mut simulation_time: SimulationTime,
+    hatch_config: &HatchTraitConfig,
+    ai_config: &AntAiConfig,
+    food_store: &mut ColonyFoodStore,
+    nest_space: &mut NestSpace,
+    rng: &mut impl Rng,
+) {
+    let queen_modifiers = save_data
+        .queen
+        .as_ref()
+        .map_or_else(BehaviorModifiers::default, |queen| {
+            BehaviorModifiers::from_traits(&queen.traits, hatch_config)
+        });
+    for ant_data in save_data.ants {
+        let ant = spawn_worker_ant(
+            commands,
+            ant_data.position,
+            ant_data.lifecycle,
+            ant_data.caste,
+            ant_data.modifiers.unwrap_or(queen_modifiers),
+            ai_config,
+            rng,
+        );
+        if let Some(health) = ant_data.health {
+            commands.entity(ant).insert(health);
+        }
+        if let Some(id) = ant_data.id {
+            commands.entity(ant).insert(id);
+        }
+        if let Some(name) = ant_data.name {
+            commands.entity(ant).insert(name);
+        }
+        if let Some(behavior) = ant_data.behavior {
+            commands.entity(ant).insert(behavior);
+        }
+        if let Some(colony) = ant_data.colony {
+            commands.entity(ant).insert(colony);
+        }
     }
 
-    persistence_state.is_loading = true;
+    for soil_data in save_data.soil_cells {
+        spawn_soil_cell(commands, soil_data.position, soil_data.soil_cell);
+    }
 
-    if let Some(save_dir) = &persistence_state.save_directory {
-        let save_path = save_dir.join("auto_save.dat");
+    let mut queens = Vec::new();
+    let mut co_founders = Vec::new();
+    for queen_data in save_data.queen.into_iter().chain(save_data.extra_queens) {
+        let queen_entity = spawn_founding_queen(commands, queen_data.position, queen_data.traits);
+        queens.push(queen_entity);
+        if let Some(founder) = queen_data.co_founder_of {
+            co_founders.push((queen_entity, founder));
+        }
+        let mut queen = commands.entity(queen_entity);
+        queen.insert(Queen {
+            founding_state: queen_data.founding_state,
+        });
+        if let Some(lifecycle) = queen_data.lifecycle {
+            queen.insert(lifecycle);
+        }
+        if let Some(reproduction_state) = queen_data.reproduction_state {
+            queen.insert(reproduction_state);
+        }
+        if let Some(health) = queen_data.health {
+            queen.insert(health);
+        }
+        if let Some(royal_chamber) = queen_data.royal_chamber {
+            queen.insert(royal_chamber);
+        }
+        if let Some(colony) = queen_data.colony {
+            queen.insert(colony);
+        }
+        match queen_data.founding_reserve {
+            Some(founding_reserve) => queen.insert(founding_reserve),
+            None => queen.remove::<FoundingReserve>(),
+        };
+    }
+    // Founders may come after their co-founders, so link them once all are spawned
+    for (queen, founder) in co_founders {
+        if let Some(&founder) = queens.get(founder) {
+            commands.entity(queen).insert(CoFoundingQueen { founder });
+        }
+    }
+
+    for chamber_data in save_data.chambers {
+        let chamber = spawn_chamber(
+            commands,
+            chamber_data.position,
+            chamber_data.chamber_type,
+            chamber_data.radius,
+            chamber_data.capacity,
+        );
+        commands.entity(chamber).insert(Chamber {
+            chamber_type: chamber_data.chamber_type,
+            radius: chamber_data.radius,
+            capacity: chamber_data.capacity,
+            occupants: 0,
+            temperature: chamber_data.temperature,
+        });
+    }
 
-        if !save_path.exists() {
-            warn!("No save file found at {:?}", save_path);
-            persistence_state.is_loading = false;
-            return;
+    for food_data in save_data.food_sources {
+        let food = spawn_food_source(
+            commands,
+            food_data.position,
+            food_data.max_nutrition,
+            food_data.kind,
+        );
+        commands.entity(food).insert(FoodSource {
+            nutrition_value: food_data.nutrition_value,
+            max_nutrition: food_data.max_nutrition,
+            kind: food_data.kind,
+        });
+    }
+
+    for egg_data in save_data.eggs {
+        let egg = spawn_egg(commands, egg_data.position, egg_data.incubation_time);
+        if let Some(colony) = egg_data.colony {
+            commands.entity(egg).insert(colony);
         }
+    }
 
-        match fs::read(&save_path) {
-            Ok(data) => {
-                match bincode::deserialize::<SaveData>(&data) {
-                    Ok(save_data) => {
-                        info!("Loading game from {:?}", save_path);
+    simulation_time.elapsed_seconds = 0.0;
+    simulation_time.advance(save_data.game_state.simulation_time);
+    commands.insert_resource(simulation_time);
 
-                        // Clear existing entities
-                        for entity in existing_ants.iter() {
-                            commands.entity(entity).despawn();
-                        }
-                        for entity in existing_soil.iter() {
-                            commands.entity(entity).despawn();
-                        }
-                        for entity in existing_food.iter() {
-                            commands.entity(entity).despawn();
-                        }
-                        for entity in existing_queen.iter() {
-                            commands.entity(entity).despawn();
-                        }
+    food_store.food = save_data.game_state.colony_food;
+    food_store.protein = save_data.game_state.colony_protein;
+    nest_space.excavated_cells = save_data.game_state.excavated_cells;
 
-                        // Restore ants
-                        for ant_data in save_data.ants {
-                            let mut entity = commands.spawn((
-                                ant_data.position,
-                                ant_data.behavior,
-                                ant_data.lifecycle,
-                                Ant,
-                            ));
-
-                            if let Some(inventory) = ant_data.inventory {
-                                entity.insert(inventory);
-                            }
-                        }
+    info!(
+        "Game loaded successfully! Colony age: {:.1}s, Population: {}",
+        save_data.metadata.colony_age, save_data.metadata.ant_population
+    );
+}
 
-                        // Restore soil
-                        for soil_data in save_data.soil_cells {
-                            commands.spawn((soil_data.position, soil_data.soil_cell, Soil));
-                        }
+/// Marker component for the save slot panel
+#[derive(Component)]
+pub struct SaveSlotPanel;
 
-                        // Restore food sources
-                        for food_data in save_data.food_sources {
-                            commands.spawn((food_data.position, food_data.food_source, Food));
-                        }
+/// Text showing a slot's number and save time
+#[derive(Component)]
+pub struct SaveSlotLabel {
+    pub slot: usize,
+}
 
-                        // Restore queen
-                        if let Some(queen_data) = save_data.queen {
-                            commands.spawn((
-                                queen_data.position,
-                                queen_data.lifecycle,
-                                queen_data.reproduction_state,
-                                Queen,
-                            ));
-                        }
+/// Preview image of the save in a slot, hidden while the slot has no thumbnail
+#[derive(Component)]
+pub struct SaveSlotThumbnail {
+    pub slot: usize,
+}
 
-                        // Restore game state
-                        // Pure observation mode - ignoring saved time control settings
+/// Text showing the result of the last slot operation
+#[derive(Component)]
+pub struct SaveSlotStatusText;
 
-                        info!(
-                            "Game loaded successfully! Colony age: {:.1}s, Population: {}",
-                            save_data.metadata.colony_age, save_data.metadata.ant_population
-                        );
-                    }
-                    Err(e) => {
-                        error!("Failed to deserialize save data: {}", e);
-                    }
-                }
-            }
-            Err(e) => {
-                error!("Failed to read save file: {}", e);
+/// Button triggering an action on a save slot
+#[derive(Component)]
+pub struct SaveSlotButton {
+    pub slot: usize,
+    pub action: SlotAction,
+}
+
+/// Label text for a slot row, with the colony's day, population and phase when known
+///
+/// Words come from the active [`Localization`]; the phase is shown as it was saved.
+pub fn save_slot_label(info: &SaveSlotInfo, localization: &Localization) -> String {
+    let slot = localization.t("slots.slot");
+    let Some(saved_at) = info.saved_at else {
+        return format!("{} {}: {}", slot, info.slot, localization.t("slots.empty"));
+    };
+    let mut label = format!(
+        "{} {}: {}",
+        slot,
+        info.slot,
+        saved_at
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M")
+    );
+    if let Some(summary) = &info.summary {
+        label.push_str(&format!(
+            "\n{} {}, {} {}, {}",
+            localization.t("slots.day"),
+            summary.simulated_day,
+            summary.population,
+            localization.t("slots.ants"),
+            summary.colony_phase
+        ));
+    }
+    label
+}
+
+/// Texture showing a save's thumbnail in the slot list
+pub fn thumbnail_image(thumbnail: &SaveThumbnail) -> Image {
+    Image::new(
+        bevy::render::render_resource::Extent3d {
+            width: thumbnail.width,
+            height: thumbnail.height,
+            depth_or_array_layers: 1,
+        },
+        bevy::render::render_resource::TextureDimension::D2,
+        thumbnail.pixels.clone(),
+        bevy::render::render_resource::TextureFormat::Rgba8UnormSrgb,
+        bevy::render::render_asset::RenderAssetUsages::default(),
+    )
+}
+
+/// Setup the save slot management panel (hidden until toggled with
+/// [`InputAction::ToggleSaveSlots`])
+///
+/// Labels come from the active [`Localization`].
+pub fn setup_save_slot_panel(
+    mut commands: Commands,
+    ui_theme: Res<UITheme>,
+    save_slots: Res<SaveSlots>,
+    localization: Res<Localization>,
+) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                right: Val::Px(10.0),
+                top: Val::Px(10.0),
+                width: Val::Px(260.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(ui_theme.spacing.md)),
+                row_gap: Val::Px(ui_theme.spacing.sm),
+                ..default()
+            },
+            background_color: ui_theme.colors.surface_primary.into(),
+            border_color: ui_theme.colors.border_primary.into(),
+            visibility: Visibility::Hidden,
+            ..default()
+        })
+        .insert(SaveSlotPanel)
+        .insert(ResponsivePanel {
+            anchor: PanelAnchor::TopRight,
+            width: 260.0,
+            margin: 10.0,
+        })
+        .insert(DraggablePanel { id: "save_slots" })
+        .with_children(|parent| {
+            let panel = parent.parent_entity();
+            parent
+                .spawn(TextBundle::from_section(
+                    localization.t("slots.title"),
+                    TextStyle {
+                        font_size: ui_theme.typography.heading_small,
+                        color: ui_theme.colors.text_primary,
+                        ..default()
+                    },
+                ))
+                .insert((Interaction::default(), PanelTitleBar { panel }));
+
+            for info in save_slots.slots.iter() {
+                parent
+                    .spawn(TextBundle::from_section(
+                        save_slot_label(info, &localization),
+                        TextStyle {
+                            font_size: ui_theme.typography.body_small,
+                            color: ui_theme.colors.text_secondary,
+                            ..default()
+                        },
+                    ))
+                    .insert(SaveSlotLabel { slot: info.slot });
+
+                parent
+                    .spawn(ImageBundle {
+                        style: Style {
+                            width: Val::Px(THUMBNAIL_WIDTH as f32 * 2.0),
+                            height: Val::Px(THUMBNAIL_HEIGHT as f32 * 2.0),
+                            ..default()
+                        },
+                        visibility: Visibility::Hidden,
+                        ..default()
+                    })
+                    .insert(SaveSlotThumbnail { slot: info.slot });
+
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::Row,
+                            column_gap: Val::Px(ui_theme.spacing.sm),
+                            ..default()
+                        },
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        for (action, label) in [
+                            (SlotAction::Save, "slots.save"),
+                            (SlotAction::Load, "slots.load"),
+                            (SlotAction::Delete, "slots.delete"),
+                        ] {
+                            let label = localization.t(label);
+                            create_slot_button(row, info.slot, action, label, &ui_theme);
+                        }
+                    });
             }
+
+            parent
+                .spawn(TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: ui_theme.typography.body_small,
+                        color: ui_theme.colors.text_muted,
+                        ..default()
+                    },
+                ))
+                .insert(SaveSlotStatusText);
+        });
+}
+
+fn create_slot_button(
+    parent: &mut ChildBuilder,
+    slot: usize,
+    action: SlotAction,
+    label: &str,
+    ui_theme: &UITheme,
+) {
+    let background = match action {
+        SlotAction::Delete => ui_theme.colors.action_danger,
+        _ => ui_theme.colors.action_primary,
+    };
+
+    parent
+        .spawn(ButtonBundle {
+            style: Style {
+                padding: UiRect::axes(Val::Px(ui_theme.spacing.md), Val::Px(ui_theme.spacing.xs)),
+                ..default()
+            },
+            background_color: background.into(),
+            border_radius: BorderRadius::all(Val::Px(ui_theme.borders.radius_small)),
+            ..default()
+        })
+        .insert(SaveSlotButton { slot, action })
+        .with_children(|button| {
+            button.spawn(TextBundle::from_section(
+                label,
+                TextStyle {
+                    font_size: ui_theme.typography.body_small,
+                    color: ui_theme.colors.text_primary,
+                    ..default()
+                },
+            ));
+        });
+}
+
+/// System turning slot button presses into slot requests
+pub fn save_slot_button_system(
+    interaction_query: Query<(&Interaction, &SaveSlotButton), Changed<Interaction>>,
+    mut requests: EventWriter<SaveSlotRequest>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            requests.send(SaveSlotRequest {
+                slot: button.slot,
+                action: button.action,
+            });
         }
     }
-
-    persistence_state.is_loading = false;
 }
 
-/// System to display save/load status
-pub fn persistence_status_system(persistence_state: Res<PersistenceState>, time: Res<Time>) {
-    if persistence_state.is_saving {
-        info!("Saving game...");
+/// System to refresh slot labels, previews and the status line when slots change
+///
+/// Slot labels are also rebuilt when the [`Localization`] changes.
+pub fn update_save_slot_panel_system(
+    save_slots: Res<SaveSlots>,
+    localization: Res<Localization>,
+    images: Option<ResMut<Assets<Image>>>,
+    mut label_query: Query<(&SaveSlotLabel, &mut Text), Without<SaveSlotStatusText>>,
+    mut status_query: Query<&mut Text, With<SaveSlotStatusText>>,
+    mut thumbnail_query: Query<(&SaveSlotThumbnail, &mut UiImage, &mut Visibility)>,
+) {
+    if !save_slots.is_changed() && !localization.is_changed() {
+        return;
+    }
+
+    if let Some(mut images) = images {
+        for (preview, mut image, mut visibility) in thumbnail_query.iter_mut() {
+            let thumbnail = save_slots
+                .get(preview.slot)
+                .and_then(|info| info.summary.as_ref())
+                .and_then(|summary| summary.thumbnail.as_ref());
+            match thumbnail {
+                Some(thumbnail) => {
+                    image.texture = images.add(thumbnail_image(thumbnail));
+                    *visibility = Visibility::Inherited;
+                }
+                None => *visibility = Visibility::Hidden,
+            }
+        }
     }
 
-    if persistence_state.is_loading {
-        info!("Loading game...");
+    for (label, mut text) in label_query.iter_mut() {
+        if let Some(info) = save_slots.get(label.slot) {
+            text.sections[0].value = save_slot_label(info, &localization);
+        }
     }
 
-    // Show auto-save countdown (optional debug info)
-    let time_until_autosave = persistence_state.auto_save_interval
-        - (time.elapsed_seconds() - persistence_state.last_save_time);
+    for mut text in status_query.iter_mut() {
+        text.sections[0].value = save_slots.status_message.clone().unwrap_or_default();
+    }
+}
 
-    if time_until_autosave <= 30.0 && time_until_autosave > 0.0 {
-        debug!("Auto-save in {:.1} seconds", time_until_autosave);
+/// System to toggle the save slot panel with [`InputAction::ToggleSaveSlots`]
+pub fn toggle_save_slot_panel_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    mut panel_query: Query<&mut Visibility, With<SaveSlotPanel>>,
+) {
+    if key_bindings.just_pressed(&keyboard_input, InputAction::ToggleSaveSlots) {
+        for mut visibility in panel_query.iter_mut() {
+            *visibility = match *visibility {
+                Visibility::Hidden => Visibility::Inherited,
+                _ => Visibility::Hidden,
+            };
+        }
     }
 }
@@ -0,0 +1,81 @@
+use crate::components::{InputAction, KeyBindings, PheromoneGrid, PheromoneOverlay};
+use bevy::prelude::*;
+
+/// Opacity share of a cell with the given pheromone strength, from 0.0 to 1.0
+pub fn pheromone_intensity(strength: f32, saturation: f32) -> f32 {
+    if saturation <= 0.0 {
+        return 0.0;
+    }
+    (strength / saturation).clamp(0.0, 1.0)
+}
+
+/// Centers and intensities of the pheromone cells inside `view`, skipping empty ones
+pub fn visible_pheromone_cells(
+    grid: &PheromoneGrid,
+    view: Rect,
+    saturation: f32,
+) -> Vec<(Vec2, f32)> {
+    grid.cells
+        .iter()
+        .filter_map(|(cell, strength)| {
+            let center = grid.cell_center(*cell);
+            let center = Vec2::new(center.x, center.y);
+            let intensity = pheromone_intensity(*strength, saturation);
+            (intensity > 0.0 && view.contains(center)).then_some((center, intensity))
+        })
+        .collect()
+}
+
+/// System toggling the pheromone debug overlay with F6
+pub fn pheromone_debug_toggle_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    mut overlay: ResMut<PheromoneOverlay>,
+) {
+    if key_bindings.just_pressed(&keyboard_input, InputAction::TogglePheromoneOverlay) {
+        overlay.enabled = !overlay.enabled;
+        overlay.update_timer = overlay.update_interval; // Rebuild right away
+        info!(
+            "Pheromone overlay {}",
+            if overlay.enabled { "shown" } else { "hidden" }
+        );
+    }
+}
+
+/// System drawing a translucent square per pheromone cell, more opaque where the trail
+/// is stronger
+///
+/// Only cells in the camera's view are drawn, and that set is rebuilt every
+/// `update_interval` seconds; gizmos are redrawn each frame from the cached set.
+pub fn pheromone_debug_system(
+    time: Res<Time>,
+    grid: Res<PheromoneGrid>,
+    mut overlay: ResMut<PheromoneOverlay>,
+    camera_query: Query<(&Transform, &OrthographicProjection), With<Camera>>,
+    mut gizmos: Gizmos,
+) {
+    if !overlay.enabled {
+        return;
+    }
+
+    let overlay = overlay.bypass_change_detection();
+    overlay.update_timer += time.delta_seconds();
+    if overlay.update_timer >= overlay.update_interval {
+        overlay.update_timer = 0.0;
+        let Ok((camera_transform, projection)) = camera_query.get_single() else {
+            return;
+        };
+        let camera_center = camera_transform.translation.truncate();
+        let view = Rect::from_corners(
+            projection.area.min + camera_center,
+            projection.area.max + camera_center,
+        );
+        overlay.visible_cells = visible_pheromone_cells(&grid, view, overlay.saturation);
+    }
+
+    let size = Vec2::splat(grid.cell_size);
+    for (center, intensity) in &overlay.visible_cells {
+        let color = Color::srgba(0.2, 0.9, 0.3, intensity * overlay.max_alpha);
+        gizmos.rect_2d(*center, 0.0, size, color);
+    }
+}
@@ -0,0 +1,178 @@
+use crate::components::{
+    CarryingCapacity, ColonyFoodStore, DeathBehavior, Egg, FeedingGroup, FeedingPriorityConfig,
+    FoundingReserve, FoundingState, Lifecycle, NestSpace, Nourishment, PendingDespawn, Position,
+    Queen,
+};
+use crate::systems::lifecycle::kill_ant;
+use bevy::prelude::*;
+use rand::prelude::*;
+
+/// System sharing the colony's carbohydrates out in the [`FeedingPriorityConfig`] order
+///
+/// Every tick the queen and each egg need their upkeep: whoever gets all of it grows
+/// better nourished, whoever goes short slides toward starving (see [`Nourishment`]).
+/// A founding queen lives on her [`FoundingReserve`] and takes no upkeep until she is
+/// established and her reserve is gone. Hungry workers are then topped up, hungriest
+/// first. With the default order the queen and brood keep going on a short store while
+/// surplus workers go without.
+pub fn feeding_priority_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Option<Res<FeedingPriorityConfig>>,
+    mut food_store: ResMut<ColonyFoodStore>,
+    mut queen_query: Query<
+        (
+            Entity,
+            &Queen,
+            Option<&mut Nourishment>,
+            Has<FoundingReserve>,
+        ),
+        Without<Egg>,
+    >,
+    mut brood_query: Query<(Entity, Option<&mut Nourishment>), (With<Egg>, Without<Queen>)>,
+    mut worker_query: Query<&mut Lifecycle, Without<Queen>>,
+) {
+    let delta_time = time.delta_seconds();
+    let config = config.map(|config| config.clone()).unwrap_or_default();
+
+    for group in &config.order {
+        match group {
+            FeedingGroup::Queen => {
+                for (entity, queen, nourishment, founding) in queen_query.iter_mut() {
+                    if founding || queen.founding_state != FoundingState::Established {
+                        continue;
+                    }
+                    let need = config.queen_upkeep * delta_time;
+                    let change = ration(&mut food_store.food, need, delta_time, &config);
+                    nourish(&mut commands, entity, nourishment, change);
+                }
+            }
+            FeedingGroup::Brood => {
+                for (entity, nourishment) in brood_query.iter_mut() {
+                    let need = config.brood_upkeep * delta_time;
+                    let change = ration(&mut food_store.food, need, delta_time, &config);
+                    nourish(&mut commands, entity, nourishment, change);
+                }
+            }
+            FeedingGroup::Workers => {
+                let mut hungry: Vec<Mut<Lifecycle>> = worker_query
+                    .iter_mut()
+                    .filter(|lifecycle| {
+                        lifecycle.energy < lifecycle.max_energy * config.worker_hunger
+                    })
+                    .collect();
+                hungry.sort_by(|a, b| {
+                    (a.energy / a.max_energy).total_cmp(&(b.energy / b.max_energy))
+                });
+                for mut lifecycle in hungry {
+                    if food_store.food <= 0.0 {
+                        break;
+                    }
+                    let meal = (lifecycle.max_energy - lifecycle.energy).min(food_store.food);
+                    lifecycle.energy += meal;
+                    food_store.food -= meal;
+                }
+            }
+        }
+    }
+}
+
+/// Take up to `need` from `store`; returns the resulting change in nourishment
+///
+/// A full ration restores nourishment over `reserve_seconds`, none drains it as fast,
+/// and a partial one lands in between.
+fn ration(store: &mut f32, need: f32, delta_time: f32, config: &FeedingPriorityConfig) -> f32 {
+    let eaten = need.min(store.max(0.0));
+    *store -= eaten;
+    let fed = if need > 0.0 { eaten / need } else { 1.0 };
+    (2.0 * fed - 1.0) * delta_time / config.reserve_seconds.max(f32::EPSILON)
+}
+
+/// Apply a nourishment change, starting from fully nourished the first time
+fn nourish(
+    commands: &mut Commands,
+    entity: Entity,
+    nourishment: Option<Mut<Nourishment>>,
+    change: f32,
+) {
+    match nourishment {
+        Some(mut nourishment) => nourishment.0 = (nourishment.0 + change).clamp(0.0, 1.0),
+        None => {
+            let level = (Nourishment::default().0 + change).clamp(0.0, 1.0);
+            commands.entity(entity).insert(Nourishment(level));
+        }
+    }
+}
+
+/// System that limits population to the colony's carrying capacity
+///
+/// While the population exceeds what food and nest space can sustain, energy drain
+/// rises colony-wide and extra deaths occur in proportion to the overshoot. Both
+/// effects fade as the population falls back under capacity, giving boom/bust
+/// cycles instead of unbounded growth or an instant collapse. Starved ants die like any
+/// other, leaving a corpse under [`DeathBehavior::Corpse`].
+pub fn starvation_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    food_store: Res<ColonyFoodStore>,
+    nest_space: Res<NestSpace>,
+    mut carrying_capacity: ResMut<CarryingCapacity>,
+    death_behavior: Option<Res<DeathBehavior>>,
+    mut pending_despawn: Option<ResMut<PendingDespawn>>,
+    ant_query: Query<(Entity, Option<&Position>), With<Lifecycle>>,
+) {
+    let mut rng = thread_rng();
+    let delta_time = time.delta_seconds();
+
+    // Ants already queued for cleanup no longer count
+    let living: Vec<(Entity, Option<&Position>)> = ant_query
+        .iter()
+        .filter(|(entity, _)| {
+            pending_despawn
+                .as_ref()
+                .is_none_or(|pending| !pending.contains(*entity))
+        })
+        .collect();
+
+    let capacity = carrying_capacity.compute(food_store.food, nest_space.excavated_cells);
+    let population = living.len() as f32;
+
+    // Relative overshoot: 0.0 at or under capacity, 1.0 at double capacity
+    let overshoot = if population > capacity {
+        (population - capacity) / capacity.max(1.0)
+    } else {
+        0.0
+    };
+
+    carrying_capacity.capacity = capacity;
+    carrying_capacity.energy_drain_multiplier =
+        1.0 + overshoot * carrying_capacity.overshoot_drain_factor;
+
+    if overshoot <= 0.0 {
+        return;
+    }
+
+    let death_chance =
+        (overshoot * carrying_capacity.overshoot_death_rate * delta_time).clamp(0.0, 1.0);
+    let death_behavior = death_behavior.map_or(DeathBehavior::default(), |behavior| *behavior);
+    let mut deaths = 0;
+    for (entity, position) in living {
+        if rng.gen_bool(death_chance as f64) {
+            kill_ant(
+                &mut commands,
+                pending_despawn.as_deref_mut(),
+                death_behavior,
+                entity,
+                position,
+            );
+            deaths += 1;
+        }
+    }
+
+    if deaths > 0 {
+        info!(
+            "{} ants starved (population {:.0} over capacity {:.1})",
+            deaths, population, capacity
+        );
+    }
+}
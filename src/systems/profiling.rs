@@ -0,0 +1,135 @@
+use crate::components::{
+    InputAction, KeyBindings, PanelAnchor, ProfilerOverlay, ProfilerText, ResponsivePanel,
+    SystemProfiler,
+};
+use bevy::prelude::*;
+
+/// Sections of the frame timed by the profiler
+pub const PROFILED_SECTIONS: [&str; 5] = [
+    "Environment",
+    "Movement",
+    "Lifecycle",
+    "Spatial grid",
+    "Stats",
+];
+
+/// Width of the bar drawn for the most expensive section, in characters
+const MAX_BAR_LENGTH: usize = 20;
+
+/// System factory stamping the start of a profiled section
+pub fn profile_section_start(section: &'static str) -> impl FnMut(Option<ResMut<SystemProfiler>>) {
+    move |profiler| {
+        if let Some(mut profiler) = profiler.filter(|profiler| profiler.enabled) {
+            profiler.start(section);
+        }
+    }
+}
+
+/// System factory closing a profiled section opened by [`profile_section_start`]
+pub fn profile_section_stop(section: &'static str) -> impl FnMut(Option<ResMut<SystemProfiler>>) {
+    move |profiler| {
+        if let Some(mut profiler) = profiler.filter(|profiler| profiler.enabled) {
+            profiler.stop(section);
+        }
+    }
+}
+
+/// Bar list of section timings, most expensive first
+pub fn profiler_bars(profiler: &SystemProfiler) -> String {
+    let sorted = profiler.sorted();
+    let longest = sorted.first().map_or(0.0, |(_, average)| *average);
+    let mut lines = vec!["System Profile (avg ms)".to_string()];
+    for (section, average) in sorted {
+        let length = if longest > 0.0 {
+            ((average / longest) * MAX_BAR_LENGTH as f32).round() as usize
+        } else {
+            0
+        };
+        lines.push(format!(
+            "{:<12} {:<width$} {:.2}",
+            section,
+            "#".repeat(length.max(1)),
+            average,
+            width = MAX_BAR_LENGTH
+        ));
+    }
+    lines.join("\n")
+}
+
+/// System to set up the profiling overlay (hidden until toggled)
+pub fn setup_profiler_overlay(mut commands: Commands) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                right: Val::Px(10.0),
+                bottom: Val::Px(10.0),
+                width: Val::Px(320.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            background_color: Color::srgba(0.0, 0.0, 0.0, 0.8).into(),
+            border_radius: BorderRadius::all(Val::Px(5.0)),
+            visibility: Visibility::Hidden,
+            ..default()
+        })
+        .insert(ProfilerOverlay)
+        .insert(ResponsivePanel {
+            anchor: PanelAnchor::BottomRight,
+            width: 320.0,
+            margin: 10.0,
+        })
+        .with_children(|parent| {
+            parent
+                .spawn(TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 13.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                ))
+                .insert(ProfilerText);
+        });
+}
+
+/// System to toggle profiling and its overlay with the F7 key
+///
+/// Timings are only taken while the overlay is shown, so profiling costs nothing when off.
+pub fn toggle_profiler_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    mut profiler: ResMut<SystemProfiler>,
+    mut overlay_query: Query<&mut Visibility, With<ProfilerOverlay>>,
+) {
+    if !key_bindings.just_pressed(&keyboard_input, InputAction::ToggleProfiler) {
+        return;
+    }
+    profiler.enabled = !profiler.enabled;
+    profiler.started.clear();
+    for mut visibility in overlay_query.iter_mut() {
+        *visibility = if profiler.enabled {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+    }
+    info!(
+        "System profiler {}",
+        if profiler.enabled { "on" } else { "off" }
+    );
+}
+
+/// System refreshing the profiling overlay's bar list
+pub fn update_profiler_overlay(
+    profiler: Res<SystemProfiler>,
+    mut text_query: Query<&mut Text, With<ProfilerText>>,
+) {
+    if !profiler.enabled {
+        return;
+    }
+    for mut text in text_query.iter_mut() {
+        text.sections[0].value = profiler_bars(&profiler);
+    }
+}
@@ -1,14 +1,79 @@
-use crate::components::{Ant, FoundingState, Position, Queen, Soil, SoilCell};
+use crate::components::{
+    Ant, AntAgeGroup, AntAiConfig, AntBehavior, AntennationCooldown, BaseColor, BehaviorModifiers,
+    Breadcrumbs, CameraSettings, Caste, CasteClass, CoFoundingQueen, ColonyTraits, DepthLayer,
+    FoodMemory, Health, InitialSpawnConfig, Lifecycle, LifespanModel, PhaseSpecificBehavior,
+    Position, SimulationRng, Soil, SoilCell, SpecializedRole, Stamina,
+};
+use crate::systems::colony_founding::spawn_founding_queen;
 use bevy::prelude::*;
 use rand::prelude::*;
 
+/// Draw layers of world sprites, listed back to front
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RenderLayer {
+    Soil,
+    Tunnel,
+    Chamber,
+    Brood,
+    Food,
+    Ant,
+    Queen,
+    Invader,
+    Particle,
+    Overlay,
+    UI,
+}
+
+impl RenderLayer {
+    /// Every layer in visual stacking order, from the back
+    pub const ALL: [RenderLayer; 11] = [
+        RenderLayer::Soil,
+        RenderLayer::Tunnel,
+        RenderLayer::Chamber,
+        RenderLayer::Brood,
+        RenderLayer::Food,
+        RenderLayer::Ant,
+        RenderLayer::Queen,
+        RenderLayer::Invader,
+        RenderLayer::Particle,
+        RenderLayer::Overlay,
+        RenderLayer::UI,
+    ];
+}
+
+/// Sprite z for a render layer; every spawner places its sprites with this
+pub fn z_for(layer: RenderLayer) -> f32 {
+    match layer {
+        RenderLayer::Soil => 0.0,
+        RenderLayer::Tunnel => 1.0,
+        RenderLayer::Chamber => 2.0,
+        RenderLayer::Brood => 3.0,
+        RenderLayer::Food => 5.0,
+        RenderLayer::Ant => 10.0,
+        RenderLayer::Queen => 11.0,
+        RenderLayer::Invader => 12.0,
+        RenderLayer::Particle => 100.0,
+        RenderLayer::Overlay => 200.0,
+        RenderLayer::UI => 500.0, // Still in front of the camera at z = 1000
+    }
+}
+
+/// Random source for a spawner: forked from `SimulationRng` when the run is seeded
+pub fn spawner_rng(simulation_rng: Option<ResMut<SimulationRng>>) -> StdRng {
+    match simulation_rng {
+        Some(mut simulation_rng) => simulation_rng.fork(),
+        None => StdRng::from_entropy(),
+    }
+}
+
 /// Basic camera setup for 2D pixel art view optimized for ant visibility
-pub fn setup_world(mut commands: Commands) {
-    // Spawn 2D camera for side-view ant farm observation with optimal zoom for ant visibility
+pub fn setup_world(mut commands: Commands, camera_settings: Res<CameraSettings>) {
+    // Spawn 2D camera for side-view ant farm observation with optimal zoom for ant visibility,
+    // centered for a colony overview (zoom adjusted for ant visibility in Issue #102)
     commands.spawn(Camera2dBundle {
-        transform: Transform::from_translation(Vec3::new(0.0, -20.0, 1000.0)), // Better positioning for colony overview
+        transform: Transform::from_translation(camera_settings.default_center.extend(1000.0)),
         projection: OrthographicProjection {
-            scale: 0.6, // Adjusted zoom for better ant visibility (Issue #102)
+            scale: camera_settings.default_zoom,
             ..default()
         },
         ..default()
@@ -18,8 +83,8 @@ pub fn setup_world(mut commands: Commands) {
 }
 
 /// Create soil grid with depth layers for cross-section view
-pub fn spawn_soil_grid(mut commands: Commands) {
-    let mut rng = thread_rng();
+pub fn spawn_soil_grid(mut commands: Commands, simulation_rng: Option<ResMut<SimulationRng>>) {
+    let mut rng = spawner_rng(simulation_rng);
 
     // Create a grid of soil cells below ground (y <= 0)
     for x in -25i32..25i32 {
@@ -32,7 +97,8 @@ pub fn spawn_soil_grid(mut commands: Commands) {
                 continue;
             }
 
-            commands.spawn((
+            spawn_soil_cell(
+                &mut commands,
                 Position {
                     x: world_x,
                     y: world_y,
@@ -41,84 +107,178 @@ pub fn spawn_soil_grid(mut commands: Commands) {
                     moisture: rng.gen_range(0.3..0.8),
                     temperature: rng.gen_range(18.0..22.0),
                     nutrition: rng.gen_range(0.2..0.8),
+                    compaction: SoilCell::compaction_at_depth(world_y),
                 },
-                Soil,
-                SpriteBundle {
-                    sprite: Sprite {
-                        color: Color::srgb(0.6, 0.4, 0.2),      // Brown soil color
-                        custom_size: Some(Vec2::new(4.0, 4.0)), // 4x4 pixel soil cells
-                        ..default()
-                    },
-                    transform: Transform::from_translation(Vec3::new(world_x, world_y, 0.0)), // Background layer
-                    ..default()
-                },
-            ));
+            );
         }
     }
 
     info!("Spawned underground soil grid: {} cells", 50 * 20);
 }
 
-/// Spawn simple ants for MVP
-pub fn spawn_initial_ants(mut commands: Commands) {
-    // MVP: Just spawn a few simple ants with basic components
-    let ant_positions = [
-        Position { x: 0.0, y: 0.0 },   // Surface ant
-        Position { x: 4.0, y: 0.0 },   // Surface ant
-        Position { x: -4.0, y: 0.0 },  // Surface ant
-        Position { x: 0.0, y: -8.0 },  // Underground ant
-        Position { x: 0.0, y: -16.0 }, // Underground ant
-    ];
+/// Spawn a single soil cell with its sprite
+pub fn spawn_soil_cell(commands: &mut Commands, position: Position, soil_cell: SoilCell) -> Entity {
+    let translation = Vec3::new(position.x, position.y, z_for(RenderLayer::Soil));
+    let depth_layer = DepthLayer::from_y(position.y);
 
-    for position in ant_positions.iter() {
-        commands.spawn((
-            position.clone(),
+    commands
+        .spawn((
+            position,
+            soil_cell,
+            Soil,
+            depth_layer,
+            SpriteBundle {
+                sprite: Sprite {
+                    color: depth_layer.base_color(), // Tinted later by soil conditions
+                    custom_size: Some(Vec2::new(4.0, 4.0)), // 4x4 pixel soil cells
+                    ..default()
+                },
+                transform: Transform::from_translation(translation),
+                ..default()
+            },
+        ))
+        .id()
+}
+
+/// Spawn a single worker ant of the given caste with its sprite
+///
+/// The worker's speed is scaled by its caste and by its [`BehaviorModifiers`], and it
+/// starts with the full stamina of the [`AntAiConfig`]. Its role and wander heading are
+/// rolled from `rng`.
+pub fn spawn_worker_ant(
+    commands: &mut Commands,
+    position: Position,
+    lifecycle: Lifecycle,
+    caste: CasteClass,
+    modifiers: BehaviorModifiers,
+    ai_config: &AntAiConfig,
+    rng: &mut impl Rng,
+) -> Entity {
+    let translation = Vec3::new(position.x, position.y, z_for(RenderLayer::Ant));
+    let age_group = AntAgeGroup::from_age_ratio(lifecycle.age / lifecycle.max_age);
+    let role = SpecializedRole::random_for_age(age_group, rng);
+
+    // Home is the nest entrance; bigger castes walk slower; wanderers set off every which way
+    let heading = rng.gen_range(0.0..std::f32::consts::TAU);
+    let mut behavior = AntBehavior::new(Position { x: 0.0, y: 0.0 }, heading);
+    behavior.speed *= caste.speed_multiplier() * modifiers.speed;
+
+    commands
+        .spawn((
+            position,
             Ant, // Simple marker component
+            lifecycle,
+            behavior,
+            Caste { size_class: caste },
+            modifiers,
+            Health::new(caste.max_hp()),
+            Stamina::new(ai_config.max_stamina),
+            FoodMemory::default(),
+            Breadcrumbs::default(),
+            AntennationCooldown::default(),
+            PhaseSpecificBehavior { age_group },
+            role,
+            BaseColor(Color::BLACK),
             SpriteBundle {
                 sprite: Sprite {
-                    color: Color::BLACK,                    // Black ants as specified in MVP
-                    custom_size: Some(Vec2::new(2.0, 2.0)), // 2-pixel dots as specified
+                    color: Color::BLACK, // Black ants as specified in MVP
+                    custom_size: Some(Vec2::splat(caste.sprite_size())), // Dot size by caste
                     ..default()
                 },
-                transform: Transform::from_translation(Vec3::new(position.x, position.y, 10.0)),
+                transform: Transform::from_translation(translation),
                 ..default()
             },
-        ));
+        ))
+        .id()
+}
+
+/// Spawn the founding workers around the colony home
+///
+/// They start as a tight cluster or scattered, as set by [`InitialSpawnConfig`].
+pub fn spawn_initial_ants(
+    mut commands: Commands,
+    simulation_rng: Option<ResMut<SimulationRng>>,
+    lifespan_model: Option<Res<LifespanModel>>,
+    spawn_config: Option<Res<InitialSpawnConfig>>,
+    ai_config: Option<Res<AntAiConfig>>,
+) {
+    let mut rng = spawner_rng(simulation_rng);
+    let ai_config = ai_config.as_deref().cloned().unwrap_or_default();
+    let lifespan_model = lifespan_model
+        .map(|model| model.clone())
+        .unwrap_or_default();
+    let spawn_config = spawn_config
+        .map(|config| config.clone())
+        .unwrap_or_default();
+
+    for _ in 0..spawn_config.ant_count {
+        let position = spawn_config.sample_position(&mut rng);
+        let caste = CasteClass::Minor; // Founding workers are small nanitics
+        let max_age = lifespan_model.sample(caste, &mut rng);
+        let age = rng.gen_range(0.0..max_age * 0.5); // Founding workers of mixed ages
+
+        spawn_worker_ant(
+            &mut commands,
+            position,
+            Lifecycle {
+                age,
+                max_age,
+                energy: 100.0,
+                max_energy: 100.0,
+            },
+            caste,
+            BehaviorModifiers::default(),
+            &ai_config,
+            &mut rng,
+        );
     }
 
-    info!("Spawned {} simple ants for MVP", ant_positions.len());
+    info!(
+        "Spawned {} founding ants ({:?}) around ({:.1}, {:.1})",
+        spawn_config.ant_count, spawn_config.distribution, spawn_config.home.x, spawn_config.home.y
+    );
 }
 
-/// Spawn a single queen ant on the surface for nest founding
-pub fn spawn_queen(mut commands: Commands) {
-    let mut rng = thread_rng();
+/// Spawn the colony's queens on the surface for nest founding
+///
+/// The first queen seeks out a site and founds the nest; with `queens_per_colony` above
+/// one, the others join her as [`CoFoundingQueen`]s sharing her traits.
+pub fn spawn_queen(
+    mut commands: Commands,
+    simulation_rng: Option<ResMut<SimulationRng>>,
+    spawn_config: Option<Res<InitialSpawnConfig>>,
+) {
+    let mut rng = spawner_rng(simulation_rng);
+    let queen_count = spawn_config.map_or(1, |config| config.queens_per_colony.max(1));
 
     // Random surface position for queen spawning
     let spawn_x = rng.gen_range(-80.0..80.0); // Within the soil grid range
     let spawn_y = 0.0; // Surface level
 
-    commands.spawn((
+    // The first queen has no mother colony, so her traits are rolled from scratch
+    let traits = ColonyTraits::generate_random(&mut rng);
+    let founder = spawn_founding_queen(
+        &mut commands,
         Position {
             x: spawn_x,
             y: spawn_y,
         },
-        Queen {
-            founding_state: FoundingState::Seeking,
-        },
-        Ant, // Queens are also ants, so they can use existing movement systems
-        SpriteBundle {
-            sprite: Sprite {
-                color: Color::srgb(0.2, 0.1, 0.0), // Dark brown for queen (distinct from black workers)
-                custom_size: Some(Vec2::new(3.0, 3.0)), // 3x3 pixels (larger than 2x2 workers)
-                ..default()
+        traits.clone(),
+    );
+    for i in 1..queen_count {
+        let queen = spawn_founding_queen(
+            &mut commands,
+            Position {
+                x: spawn_x + i as f32 * 2.0,
+                y: spawn_y,
             },
-            transform: Transform::from_translation(Vec3::new(spawn_x, spawn_y, 10.0)),
-            ..default()
-        },
-    ));
+            traits.clone(),
+        );
+        commands.entity(queen).insert(CoFoundingQueen { founder });
+    }
 
     info!(
-        "Spawned queen ant at position ({}, {}) for nest founding",
-        spawn_x, spawn_y
+        "Spawned {} queen ant(s) at position ({}, {}) for nest founding",
+        queen_count, spawn_x, spawn_y
     );
 }
@@ -1,69 +1,103 @@
 use crate::components::{
-    Ant, AntBehavior, AntState, Egg, Lifecycle, Position, Queen, ReproductionState, SoilCell,
+    Ant, AntAiConfig, BehaviorModifiers, BroodBatchConfig, BroodCareConfig, BroodNutritionConfig,
+    CasteConfig, Chamber, ChamberClimateConfig, ChamberType, ColonyFoodStore, ColonyId,
+    ColonyTraits, DisasterReproductionConfig, DisasterState, Dying, Egg, EggLayingPulse, EventLog,
+    FoundingState, HatchTraitConfig, LaidBy, Lifecycle, LifespanModel, Nourishment, Position,
+    Queen, ReproductionState, SimulationRng, SimulationTime, SoilCell, SpecializedRole,
+    VisualEffectsSettings,
 };
+use crate::systems::chambers::chamber_temperature_at;
+use crate::systems::rendering::{spawn_worker_ant, z_for, RenderLayer};
 use bevy::prelude::*;
 use rand::prelude::*;
 
-/// Spawn initial queen ant at the center of the nest
-pub fn spawn_queen_ant(mut commands: Commands) {
-    commands.spawn((
-        Position { x: 0.0, y: 0.0 },
-        Queen,
-        AntBehavior {
-            state: AntState::Resting,
-            target_position: None,
-            speed: 5.0, // Queens move slower than workers
-        },
-        Lifecycle {
-            age: 0.0,
-            max_age: 300.0, // Queens live much longer
-            energy: 200.0,  // Queens have more energy
-            max_energy: 200.0,
-        },
-        ReproductionState {
-            time_since_last_egg: 0.0,
-            egg_laying_interval: 10.0, // Lay egg every 10 seconds
-            reproductive_capacity: 1.0,
-        },
-        Ant,
-        SpriteBundle {
-            sprite: Sprite {
-                color: Color::srgb(0.8, 0.0, 0.8),      // Purple color for queen
-                custom_size: Some(Vec2::new(3.0, 3.0)), // Slightly larger than workers
-                ..default()
-            },
-            transform: Transform::from_translation(Vec3::new(0.0, 0.0, 1.0)),
-            ..default()
-        },
-    ));
+/// How long the egg-laying pulse stays visible, in seconds
+pub const EGG_PULSE_DURATION: f32 = 0.6;
+
+/// Pulse size when it appears and when it has fully expanded
+const EGG_PULSE_START_SIZE: f32 = 4.0;
+const EGG_PULSE_END_SIZE: f32 = 14.0;
 
-    info!("Queen ant spawned at the center of the nest");
+/// A queen laid an egg, or a whole brood batch at once
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub struct EggLaidEvent {
+    pub queen: Entity,
+    pub egg: Entity,     // First egg of the batch
+    pub batch_size: u32, // Eggs laid in this event
+    pub queen_position: Vec2,
 }
 
 /// System for queen ant egg laying behavior
-#[allow(clippy::type_complexity)]
+///
+/// Every egg costs protein from the colony store; without it the queen stops laying.
+/// A vigorous, well-fed queen sometimes lays a brood batch of several eggs instead of one,
+/// giving a cohort that hatches together. With a [`BroodCareConfig`], a queen whose
+/// brood is unattended (no nursery with room, or too few nurses) lays far more slowly.
+/// With a [`DisasterReproductionConfig`], active disasters change the laying pace too,
+/// and a queen left starving by the feeding priorities does not lay at all.
 pub fn queen_reproduction_system(
     time: Res<Time>,
     mut commands: Commands,
+    nutrition_config: Res<BroodNutritionConfig>,
+    batch_config: Option<Res<BroodBatchConfig>>,
+    care_config: Option<Res<BroodCareConfig>>,
+    disaster_config: Option<Res<DisasterReproductionConfig>>,
+    disaster_state: Option<Res<DisasterState>>,
+    mut food_store: ResMut<ColonyFoodStore>,
+    mut eggs_laid: EventWriter<EggLaidEvent>,
     mut queen_query: Query<
-        (&Position, &mut ReproductionState, &Lifecycle),
-        (With<Queen>, With<Ant>),
+        (
+            Entity,
+            &Position,
+            &Queen,
+            &mut ReproductionState,
+            Option<&ColonyTraits>,
+            Option<&Nourishment>,
+            Option<&ColonyId>,
+        ),
+        Without<Dying>,
     >,
-    ant_count: Query<&Ant>,
+    ant_count: Query<Option<&SpecializedRole>, (With<Ant>, Without<Dying>)>,
     soil_query: Query<&SoilCell>,
+    chamber_query: Query<(&Position, &Chamber)>,
 ) {
-    let delta_time = time.delta_seconds();
+    let mut delta_time = time.delta_seconds();
     let current_ant_population = ant_count.iter().count();
+    if let Some(care_config) = care_config.as_deref() {
+        if !brood_attended(
+            care_config,
+            ant_count.iter().flatten(),
+            chamber_query.iter(),
+        ) {
+            delta_time *= care_config.unattended_laying_factor;
+        }
+    }
+    if let (Some(disaster_config), Some(disaster_state)) = (&disaster_config, &disaster_state) {
+        delta_time *= disaster_config.laying_factor(disaster_state);
+    }
 
     // Calculate average soil nutrition for reproductive capacity
-    let total_nutrition: f32 = soil_query.iter().map(|soil| soil.nutrition).sum();
-    let avg_nutrition = if soil_query.iter().count() > 0 {
-        total_nutrition / soil_query.iter().count() as f32
+    let soil_count = soil_query.iter().count();
+    let avg_nutrition = if soil_count > 0 {
+        soil_query.iter().map(|soil| soil.nutrition).sum::<f32>() / soil_count as f32
     } else {
         0.5
     };
 
-    for (position, mut reproduction_state, lifecycle) in queen_query.iter_mut() {
+    let mut rng = thread_rng();
+
+    for (queen_entity, position, queen, mut reproduction_state, traits, nourishment, colony) in
+        queen_query.iter_mut()
+    {
+        // Only an established queen lays eggs
+        if queen.founding_state != FoundingState::Established {
+            continue;
+        }
+        // A starving queen has nothing to lay with
+        if nourishment.is_some_and(Nourishment::is_starving) {
+            continue;
+        }
+
         reproduction_state.time_since_last_egg += delta_time;
 
         // Update reproductive capacity based on nutrition and population
@@ -76,100 +110,376 @@ pub fn queen_reproduction_system(
 
         // Check if it's time to lay an egg and conditions are favorable
         if reproduction_state.time_since_last_egg >= reproduction_state.egg_laying_interval
-            && lifecycle.energy > 50.0  // Queen needs enough energy
             && reproduction_state.reproductive_capacity > 0.3
-            && current_ant_population < 50
-        // Population cap
+            && current_ant_population < 50 // Population cap
+            && food_store.protein >= nutrition_config.egg_protein_cost
         {
-            lay_egg(&mut commands, position);
+            // Brood goes to the nearest nursery, or next to the queen without one
+            let nursery = chamber_query
+                .iter()
+                .filter(|(_, chamber)| chamber.chamber_type == ChamberType::Nursery)
+                .min_by(|a, b| distance(a.0, position).total_cmp(&distance(b.0, position)));
+            let (site, spread) = match nursery {
+                Some((nursery_position, chamber)) => (nursery_position, chamber.radius * 0.7),
+                None => (position, 5.0),
+            };
+
+            let queen_vigor = traits.map_or(1.0, |traits| traits.queen_vigor);
+            let mut batch_size = match batch_config.as_deref() {
+                Some(batch_config) if rng.gen::<f32>() < batch_config.batch_chance => {
+                    let reserve = food_store.protein - nutrition_config.egg_protein_cost;
+                    batch_config.batch_size(queen_vigor, reserve)
+                }
+                _ => 1,
+            };
+            // Never lay more eggs than the store can pay for
+            let affordable =
+                (food_store.protein / nutrition_config.egg_protein_cost.max(f32::EPSILON)) as u32;
+            batch_size = batch_size.min(affordable).max(1);
+
+            // A batch shares one incubation time so the cohort hatches together
+            let incubation_time = rng.gen_range(8.0..15.0); // 8-15 seconds to hatch
+            let laid_by = LaidBy {
+                queen: queen_entity,
+            };
+            let colony = colony.copied();
+            let first_egg = lay_egg(
+                &mut commands,
+                laid_by,
+                colony,
+                site,
+                spread,
+                incubation_time,
+            );
+            for _ in 1..batch_size {
+                lay_egg(
+                    &mut commands,
+                    laid_by,
+                    colony,
+                    site,
+                    spread,
+                    incubation_time,
+                );
+            }
+            eggs_laid.send(EggLaidEvent {
+                queen: queen_entity,
+                egg: first_egg,
+                batch_size,
+                queen_position: Vec2::new(position.x, position.y),
+            });
+            food_store.protein -= nutrition_config.egg_protein_cost * batch_size as f32;
             reproduction_state.time_since_last_egg = 0.0;
         }
     }
 }
 
-/// Helper function to spawn an egg near the queen
-fn lay_egg(commands: &mut Commands, queen_position: &Position) {
+/// Helper function to spawn an egg around a site, marked with its queen and colony
+fn lay_egg(
+    commands: &mut Commands,
+    laid_by: LaidBy,
+    colony: Option<ColonyId>,
+    site: &Position,
+    spread: f32,
+    incubation_time: f32,
+) -> Entity {
     let mut rng = thread_rng();
 
-    // Place egg near queen with small random offset
-    let egg_x = queen_position.x + rng.gen_range(-5.0..5.0);
-    let egg_y = queen_position.y + rng.gen_range(-5.0..5.0);
+    // Place egg with small random offset
+    let egg_x = site.x + rng.gen_range(-spread..spread);
+    let egg_y = site.y + rng.gen_range(-spread..spread);
 
-    commands.spawn((
-        Position { x: egg_x, y: egg_y },
-        Egg {
-            incubation_time: rng.gen_range(8.0..15.0), // 8-15 seconds to hatch
-        },
-        SpriteBundle {
-            sprite: Sprite {
-                color: Color::srgb(1.0, 1.0, 0.8),      // Pale yellow for eggs
-                custom_size: Some(Vec2::new(1.5, 1.5)), // Small eggs
+    let egg = spawn_egg(commands, Position { x: egg_x, y: egg_y }, incubation_time);
+    commands.entity(egg).insert(laid_by);
+    if let Some(colony) = colony {
+        commands.entity(egg).insert(colony);
+    }
+
+    debug!("Queen laid an egg at ({:.1}, {:.1})", egg_x, egg_y);
+    egg
+}
+
+/// Spawn an egg with its sprite at an exact position
+pub fn spawn_egg(commands: &mut Commands, position: Position, incubation_time: f32) -> Entity {
+    let translation = Vec3::new(position.x, position.y, z_for(RenderLayer::Brood));
+
+    commands
+        .spawn((
+            position,
+            Egg { incubation_time },
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgb(1.0, 1.0, 0.8),      // Pale yellow for eggs
+                    custom_size: Some(Vec2::new(1.5, 1.5)), // Small eggs
+                    ..default()
+                },
+                transform: Transform::from_translation(translation),
                 ..default()
             },
-            transform: Transform::from_translation(Vec3::new(egg_x, egg_y, 0.5)), // Between soil and ants
-            ..default()
-        },
-    ));
+        ))
+        .id()
+}
 
-    info!("Queen laid an egg at ({:.1}, {:.1})", egg_x, egg_y);
+/// System recording each laid egg in the colony event log
+pub fn egg_laid_log_system(
+    mut eggs_laid: EventReader<EggLaidEvent>,
+    simulation_time: Option<Res<SimulationTime>>,
+    mut event_log: ResMut<EventLog>,
+) {
+    for event in eggs_laid.read() {
+        let day = simulation_time.as_ref().map_or(1, |time| time.current_day);
+        if event.batch_size > 1 {
+            event_log.push(format!(
+                "Day {}: queen {} laid a brood batch of {} eggs",
+                day, event.queen, event.batch_size
+            ));
+        } else {
+            event_log.push(format!("Day {}: queen {} laid an egg", day, event.queen));
+        }
+    }
+}
+
+/// System drawing a brief pulse at the queen for every laid egg
+///
+/// The pulse expands and fades, or only fades with `reduced_motion`; no pulse is
+/// spawned while particles are disabled.
+pub fn egg_laying_pulse_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    visual_effects_settings: Res<VisualEffectsSettings>,
+    mut eggs_laid: EventReader<EggLaidEvent>,
+    mut pulse_query: Query<(Entity, &mut EggLayingPulse, &mut Sprite)>,
+) {
+    for event in eggs_laid.read() {
+        if !visual_effects_settings.particles_enabled {
+            continue;
+        }
+        commands.spawn((
+            EggLayingPulse {
+                remaining: EGG_PULSE_DURATION,
+            },
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(1.0, 1.0, 0.8, 0.6),
+                    custom_size: Some(Vec2::splat(EGG_PULSE_START_SIZE)),
+                    ..default()
+                },
+                transform: Transform::from_translation(
+                    event.queen_position.extend(z_for(RenderLayer::Particle)),
+                ),
+                ..default()
+            },
+        ));
+    }
+
+    for (entity, mut pulse, mut sprite) in pulse_query.iter_mut() {
+        pulse.remaining -= time.delta_seconds();
+        if pulse.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let progress = 1.0 - pulse.remaining / EGG_PULSE_DURATION;
+        if !visual_effects_settings.reduced_motion {
+            let size =
+                EGG_PULSE_START_SIZE + (EGG_PULSE_END_SIZE - EGG_PULSE_START_SIZE) * progress;
+            sprite.custom_size = Some(Vec2::splat(size));
+        }
+        sprite.color.set_alpha(0.6 * (1.0 - progress));
+    }
 }
 
 /// System for egg incubation and hatching
+///
+/// Eggs inside a chamber incubate at a speed set by the chamber's temperature.
+/// Developing brood is fed protein from the colony store and stalls when it runs out,
+/// or while it is starving for want of carbohydrates.
+/// With a [`BroodCareConfig`], unattended eggs may die before they hatch, and with a
+/// [`DisasterReproductionConfig`] a cold snap slows development. Hatchlings inherit
+/// speed, foraging efficiency and caste odds from the [`ColonyTraits`] of the queen that
+/// laid them, or of a living queen of their colony once she is gone, weighted by the
+/// [`HatchTraitConfig`].
 pub fn egg_hatching_system(
     time: Res<Time>,
     mut commands: Commands,
-    mut egg_query: Query<(Entity, &Position, &mut Egg)>,
+    care_config: Option<Res<BroodCareConfig>>,
+    role_query: Query<&SpecializedRole, (With<Ant>, Without<Dying>)>,
+    climate_config: Res<ChamberClimateConfig>,
+    nutrition_config: Res<BroodNutritionConfig>,
+    mut food_store: ResMut<ColonyFoodStore>,
+    caste_config: Res<CasteConfig>,
+    hatch_config: Option<Res<HatchTraitConfig>>,
+    disasters: (
+        Option<Res<DisasterReproductionConfig>>,
+        Option<Res<DisasterState>>,
+    ),
+    lifespan_model: Option<Res<LifespanModel>>,
+    ai_config: Option<Res<AntAiConfig>>,
+    mut simulation_rng: Option<ResMut<SimulationRng>>,
+    queen_query: Query<(&ColonyTraits, Option<&ColonyId>), (With<Queen>, Without<Dying>)>,
+    mut egg_query: Query<(
+        Entity,
+        &Position,
+        &mut Egg,
+        Option<&Nourishment>,
+        Option<&LaidBy>,
+        Option<&ColonyId>,
+    )>,
+    chamber_query: Query<(&Position, &Chamber)>,
 ) {
     let delta_time = time.delta_seconds();
+    let lifespan_model = lifespan_model
+        .map(|model| model.clone())
+        .unwrap_or_default();
+    let ai_config = ai_config.as_deref().cloned().unwrap_or_default();
+
+    let hatch_config = hatch_config
+        .map(|config| config.clone())
+        .unwrap_or_default();
+    let loss_chance = care_config
+        .as_deref()
+        .filter(|config| !brood_attended(config, role_query.iter(), chamber_query.iter()))
+        .map_or(0.0, |config| {
+            (config.unattended_loss_rate * delta_time).min(1.0)
+        });
+    let (disaster_config, disaster_state) = disasters;
+    let disaster_hatching = match (&disaster_config, &disaster_state) {
+        (Some(disaster_config), Some(disaster_state)) => {
+            disaster_config.hatching_factor(disaster_state)
+        }
+        _ => 1.0,
+    };
+    let mut fallback_rng = thread_rng();
+    let rng: &mut dyn RngCore = match simulation_rng.as_mut() {
+        Some(simulation_rng) => &mut simulation_rng.0,
+        None => &mut fallback_rng,
+    };
+
+    for (egg_entity, position, mut egg, nourishment, laid_by, colony) in egg_query.iter_mut() {
+        if loss_chance > 0.0 && rng.gen::<f32>() < loss_chance {
+            commands.entity(egg_entity).despawn();
+            debug!(
+                "Unattended egg died at ({:.1}, {:.1})",
+                position.x, position.y
+            );
+            continue;
+        }
+
+        // Starving brood waits for the colony to feed it
+        if nourishment.is_some_and(Nourishment::is_starving) {
+            continue;
+        }
+
+        let speed = chamber_temperature_at(position, chamber_query.iter())
+            .map_or(1.0, |temperature| {
+                climate_config.incubation_speed(temperature)
+            });
+        let development = delta_time * speed * disaster_hatching;
 
-    for (egg_entity, position, mut egg) in egg_query.iter_mut() {
-        egg.incubation_time -= delta_time;
+        let protein_needed = development * nutrition_config.larva_protein_rate;
+        if food_store.protein < protein_needed {
+            continue;
+        }
+        food_store.protein -= protein_needed;
+        egg.incubation_time -= development;
 
         // Check if egg is ready to hatch
         if egg.incubation_time <= 0.0 {
-            hatch_egg(&mut commands, egg_entity, position);
+            // Hatchlings take after their mother, else a queen of their colony; without
+            // one they are average workers
+            let traits = laid_by
+                .and_then(|laid_by| queen_query.get(laid_by.queen).ok())
+                .or_else(|| {
+                    let colony = colony.copied().unwrap_or_default();
+                    queen_query.iter().find(|(_, queen_colony)| {
+                        queen_colony.copied().unwrap_or_default() == colony
+                    })
+                })
+                .map(|(traits, _)| traits);
+            let (caste_config, modifiers) = match traits {
+                Some(traits) => (
+                    caste_config.for_traits(traits, hatch_config.caste_influence),
+                    BehaviorModifiers::from_traits(traits, &hatch_config),
+                ),
+                None => (caste_config.clone(), BehaviorModifiers::default()),
+            };
+            hatch_egg(
+                &mut commands,
+                egg_entity,
+                position,
+                laid_by.copied(),
+                colony.copied(),
+                &caste_config,
+                &lifespan_model,
+                modifiers,
+                &ai_config,
+                rng,
+            );
         }
     }
 }
 
 /// Helper function to hatch an egg into a new worker ant
-fn hatch_egg(commands: &mut Commands, egg_entity: Entity, position: &Position) {
-    let mut rng = thread_rng();
+fn hatch_egg(
+    commands: &mut Commands,
+    egg_entity: Entity,
+    position: &Position,
+    laid_by: Option<LaidBy>,
+    colony: Option<ColonyId>,
+    caste_config: &CasteConfig,
+    lifespan_model: &LifespanModel,
+    modifiers: BehaviorModifiers,
+    ai_config: &AntAiConfig,
+    mut rng: &mut dyn RngCore,
+) {
+    let caste = caste_config.roll(&mut rng);
 
     // Remove the egg
     commands.entity(egg_entity).despawn();
 
     // Spawn a new worker ant
-    commands.spawn((
-        Position {
-            x: position.x,
-            y: position.y,
-        },
-        AntBehavior {
-            state: AntState::Foraging,
-            target_position: None,
-            speed: rng.gen_range(10.0..20.0),
-        },
+    let ant = spawn_worker_ant(
+        commands,
+        position.clone(),
         Lifecycle {
             age: 0.0,
-            max_age: rng.gen_range(30.0..60.0),
+            max_age: lifespan_model.sample(caste, &mut rng),
             energy: 100.0,
             max_energy: 100.0,
         },
-        Ant,
-        SpriteBundle {
-            sprite: Sprite {
-                color: Color::BLACK, // Standard black for worker ants
-                custom_size: Some(Vec2::new(2.0, 2.0)),
-                ..default()
-            },
-            transform: Transform::from_translation(Vec3::new(position.x, position.y, 1.0)),
-            ..default()
-        },
-    ));
+        caste,
+        modifiers,
+        ai_config,
+        &mut rng,
+    );
+    if let Some(laid_by) = laid_by {
+        commands.entity(ant).insert(laid_by);
+    }
+    if let Some(colony) = colony {
+        commands.entity(ant).insert(colony);
+    }
 
-    info!(
+    debug!(
         "Egg hatched into new worker ant at ({:.1}, {:.1})",
         position.x, position.y
     );
 }
+
+/// Whether the brood is cared for: a nursery with room and at least `min_nurses` nurses
+fn brood_attended<'a>(
+    config: &BroodCareConfig,
+    roles: impl Iterator<Item = &'a SpecializedRole>,
+    mut chambers: impl Iterator<Item = (&'a Position, &'a Chamber)>,
+) -> bool {
+    let nurses = roles
+        .filter(|role| **role == SpecializedRole::NurseryWorker)
+        .count();
+    nurses >= config.min_nurses
+        && chambers.any(|(_, chamber)| {
+            chamber.chamber_type == ChamberType::Nursery && chamber.capacity_usage() < 1.0
+        })
+}
+
+fn distance(a: &Position, b: &Position) -> f32 {
+    let dx = a.x - b.x;
+    let dy = a.y - b.y;
+    (dx * dx + dy * dy).sqrt()
+}
@@ -0,0 +1,170 @@
+use crate::components::{
+    AlarmPheromoneGrid, Ant, AntRegistry, CameraFollow, Chamber, ChamberConstruction,
+    ColonyComparison, ColonyDevelopmentPhase, ColonyFoodStore, ColonyStatistics, Corpse,
+    DaughterColonies, DisasterState, Egg, EventLog, FoodSource, Forecast, InputAction,
+    InvasiveNest, InvasiveSpecies, KeyBindings, NestSpace, Particle, PendingDespawn, PheromoneGrid,
+    RecruitmentWaves, Selection, SimulationEnded, SimulationRng, SimulationTime, Soil, SpatialGrid,
+    TimeControl, Tunnel, TunnelDrawTool, TunnelNode,
+};
+use crate::systems::colony_development::PhaseProgressTracking;
+use crate::systems::foraging::spawn_food_sources;
+use crate::systems::particle::ParticleConfig;
+use crate::systems::rendering::{spawn_initial_ants, spawn_queen, spawn_soil_grid};
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+
+/// Request to throw away the current colony and start a new game in place
+#[derive(Event, Clone, Copy, Debug, Default)]
+pub struct ResetSimulationRequest {
+    pub seed: Option<u64>, // Seed for the new world; None keeps the current random source
+}
+
+/// System to request a new game with the N key
+pub fn reset_keyboard_input_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    mut requests: EventWriter<ResetSimulationRequest>,
+) {
+    if key_bindings.just_pressed(&keyboard_input, InputAction::NewGame) {
+        requests.send(ResetSimulationRequest::default());
+    }
+}
+
+/// Exclusive system carrying out pending reset requests
+pub fn reset_simulation_system(world: &mut World) {
+    let Some(mut events) = world.get_resource_mut::<Events<ResetSimulationRequest>>() else {
+        return;
+    };
+    let Some(request) = events.drain().last() else {
+        return;
+    };
+
+    reset_simulation(world, request.seed);
+}
+
+/// Despawn the simulated world, reset the run state and re-run the startup spawners
+pub fn reset_simulation(world: &mut World, seed: Option<u64>) {
+    let despawned = despawn_simulated_entities(world);
+
+    // Run state goes back to its defaults; tuning resources such as configs are kept
+    if let Some(mut simulation_time) = world.get_resource_mut::<SimulationTime>() {
+        *simulation_time = SimulationTime {
+            day_length_seconds: simulation_time.day_length_seconds,
+            ..default()
+        };
+    }
+    reset_resource::<ColonyFoodStore>(world);
+    reset_resource::<NestSpace>(world);
+    reset_resource::<AntRegistry>(world);
+    reset_run_state(world);
+
+    if let Some(seed) = seed {
+        world.insert_resource(SimulationRng::from_seed(seed));
+    }
+
+    world.run_system_once(spawn_soil_grid);
+    world.run_system_once(spawn_initial_ants);
+    world.run_system_once(spawn_queen);
+    world.run_system_once(spawn_food_sources);
+
+    info!(
+        "Simulation reset: despawned {} entities, seed {:?}",
+        despawned, seed
+    );
+}
+
+/// Despawn every entity of the running game, returning how many went
+///
+/// Shared by New Game and slot loading. The despawn queue is emptied too, since the
+/// entities it holds are gone.
+pub(crate) fn despawn_simulated_entities(world: &mut World) -> usize {
+    // Dug tunnels are missing soil cells, so despawning the soil grid clears them as well;
+    // drawn tunnels are entities of their own
+    let doomed: Vec<Entity> = world
+        .iter_entities()
+        .filter(|entity| {
+            entity.contains::<Ant>()
+                || entity.contains::<Corpse>()
+                || entity.contains::<Egg>()
+                || entity.contains::<FoodSource>()
+                || entity.contains::<InvasiveSpecies>()
+                || entity.contains::<InvasiveNest>()
+                || entity.contains::<Chamber>()
+                || entity.contains::<ChamberConstruction>()
+                || entity.contains::<Soil>()
+                || entity.contains::<Particle>()
+                || entity.contains::<TunnelNode>()
+                || entity.contains::<Tunnel>()
+        })
+        .map(|entity| entity.id())
+        .collect();
+    let despawned = doomed.len();
+    for entity in doomed {
+        if let Some(entity) = world.get_entity_mut(entity) {
+            entity.despawn_recursive(); // Doomed children may already have gone with a parent
+        }
+    }
+
+    if let Some(mut pending_despawn) = world.get_resource_mut::<PendingDespawn>() {
+        pending_despawn.queue.clear();
+        pending_despawn.queued.clear();
+    }
+    despawned
+}
+
+/// Put the run state shared by New Game and slot loading back to its defaults
+///
+/// The clock, the colony stores and the ant ids are left alone, since a loaded save
+/// brings its own. A run that ended and paused itself is resumed.
+pub(crate) fn reset_run_state(world: &mut World) {
+    if let Some(mut disaster_state) = world.get_resource_mut::<DisasterState>() {
+        disaster_state.active_disasters.clear();
+        disaster_state.cooldown_timers.clear();
+    }
+    if let Some(mut forecast) = world.get_resource_mut::<Forecast>() {
+        *forecast = Forecast::default();
+    }
+    if let Some(mut pheromone_grid) = world.get_resource_mut::<PheromoneGrid>() {
+        pheromone_grid.cells.clear();
+    }
+    if let Some(mut alarm) = world.get_resource_mut::<AlarmPheromoneGrid>() {
+        alarm.grid.cells.clear();
+    }
+    if let Some(mut spatial_grid) = world.get_resource_mut::<SpatialGrid>() {
+        spatial_grid.clear();
+    }
+    if let Some(mut comparison) = world.get_resource_mut::<ColonyComparison>() {
+        comparison.rows.clear();
+    }
+    reset_resource::<ColonyDevelopmentPhase>(world);
+    reset_resource::<PhaseProgressTracking>(world);
+    reset_resource::<ColonyStatistics>(world);
+    reset_resource::<DaughterColonies>(world);
+    reset_resource::<RecruitmentWaves>(world);
+    reset_resource::<EventLog>(world);
+    reset_resource::<Selection>(world);
+    reset_resource::<CameraFollow>(world);
+    let ended = world
+        .get_resource::<SimulationEnded>()
+        .is_some_and(SimulationEnded::is_ended);
+    reset_resource::<SimulationEnded>(world);
+    if ended {
+        if let Some(mut time_control) = world.get_resource_mut::<TimeControl>() {
+            time_control.is_paused = false;
+        }
+    }
+    if let Some(mut particle_config) = world.get_resource_mut::<ParticleConfig>() {
+        particle_config.active_particles = 0;
+    }
+    if let Some(mut tunnel_draw_tool) = world.get_resource_mut::<TunnelDrawTool>() {
+        tunnel_draw_tool.last_node = None;
+        tunnel_draw_tool.last_point = None;
+    }
+}
+
+/// Put a resource back to its default value if the app uses it
+fn reset_resource<R: Resource + Default>(world: &mut World) {
+    if world.contains_resource::<R>() {
+        world.insert_resource(R::default());
+    }
+}
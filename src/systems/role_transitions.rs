@@ -0,0 +1,96 @@
+use crate::components::{
+    EventLog, RoleFlash, SimulationTime, SpecializedRole, VisualEffectsSettings,
+};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// How long an ant pulses after switching roles, in seconds
+pub const ROLE_FLASH_DURATION: f32 = 0.5;
+
+/// Extra sprite scale at the start of the pulse
+const ROLE_FLASH_SCALE: f32 = 0.8;
+
+/// An ant switched from one specialized role to another
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RoleTransitionEvent {
+    pub entity: Entity,
+    pub from: SpecializedRole,
+    pub to: SpecializedRole,
+}
+
+/// System emitting a [`RoleTransitionEvent`] whenever an ant's role actually changes
+///
+/// Only ants whose role was written this frame are looked at, and rewriting the same
+/// role emits nothing. A newly spawned ant's first role is recorded silently.
+pub fn role_transition_system(
+    mut known_roles: Local<HashMap<Entity, SpecializedRole>>,
+    mut transitions: EventWriter<RoleTransitionEvent>,
+    role_query: Query<(Entity, &SpecializedRole), Changed<SpecializedRole>>,
+    mut removed_roles: RemovedComponents<SpecializedRole>,
+) {
+    for entity in removed_roles.read() {
+        known_roles.remove(&entity);
+    }
+
+    for (entity, &role) in role_query.iter() {
+        match known_roles.insert(entity, role) {
+            Some(previous) if previous != role => {
+                transitions.send(RoleTransitionEvent {
+                    entity,
+                    from: previous,
+                    to: role,
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+/// System recording role transitions in the colony event log
+pub fn role_transition_log_system(
+    mut transitions: EventReader<RoleTransitionEvent>,
+    simulation_time: Option<Res<SimulationTime>>,
+    mut event_log: ResMut<EventLog>,
+) {
+    for transition in transitions.read() {
+        let day = simulation_time.as_ref().map_or(1, |time| time.current_day);
+        event_log.push(format!(
+            "Day {}: ant {} switched from {:?} to {:?}",
+            day, transition.entity, transition.from, transition.to
+        ));
+    }
+}
+
+/// System pulsing the sprite of ants that just switched roles
+///
+/// No pulse is started with `reduced_motion`, and one in progress when it is turned on
+/// ends at once.
+pub fn role_flash_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    visual_effects_settings: Res<VisualEffectsSettings>,
+    mut transitions: EventReader<RoleTransitionEvent>,
+    mut flash_query: Query<(Entity, &mut RoleFlash, &mut Transform)>,
+) {
+    for transition in transitions.read() {
+        if visual_effects_settings.reduced_motion {
+            continue;
+        }
+        if let Some(mut entity) = commands.get_entity(transition.entity) {
+            entity.insert(RoleFlash {
+                remaining: ROLE_FLASH_DURATION,
+            });
+        }
+    }
+
+    for (entity, mut flash, mut transform) in flash_query.iter_mut() {
+        flash.remaining -= time.delta_seconds();
+        if flash.remaining <= 0.0 || visual_effects_settings.reduced_motion {
+            transform.scale = Vec3::ONE;
+            commands.entity(entity).remove::<RoleFlash>();
+            continue;
+        }
+        let pulse = 1.0 + ROLE_FLASH_SCALE * flash.remaining / ROLE_FLASH_DURATION;
+        transform.scale = Vec3::new(pulse, pulse, 1.0);
+    }
+}
@@ -0,0 +1,258 @@
+use crate::components::{
+    Ant, AntId, AntName, InputAction, KeyBindings, Lifecycle, PanelAnchor, RenameButton,
+    RenameButtonText, ResponsivePanel, Selection, SelectionDrag, SelectionPanel, SelectionText,
+    SpatialGrid, SpecializedRole, TunnelDrawTool,
+};
+use crate::systems::naming::ant_label;
+use crate::systems::tooltip::screen_to_world;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+/// Shortest drag (world units) treated as a selection rather than a click
+const MIN_DRAG_DISTANCE: f32 = 2.0;
+
+/// Roles listed in the selection panel, in display order
+const LISTED_ROLES: [(SpecializedRole, &str); 5] = [
+    (SpecializedRole::GeneralWorker, "General workers"),
+    (SpecializedRole::Forager, "Foragers"),
+    (SpecializedRole::NurseryWorker, "Nursery workers"),
+    (SpecializedRole::NestMaintainer, "Nest maintainers"),
+    (SpecializedRole::StorageWorker, "Storage workers"),
+];
+
+/// A change to the selection, sent by the mouse and keyboard or replayed from a recording
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub enum SelectionCommand {
+    Select { min: Vec2, max: Vec2 }, // Select the ants inside this world rectangle
+    Clear,                           // Drop the selection
+}
+
+/// System for drag selection: left-drag a rectangle to select ants, Esc to clear
+///
+/// Drags starting over UI elements or while the tunnel drawing tool is on are ignored.
+pub fn selection_input_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Transform, &OrthographicProjection), With<Camera>>,
+    interaction_query: Query<&Interaction>,
+    tunnel_tool: Option<Res<TunnelDrawTool>>,
+    mut drag: ResMut<SelectionDrag>,
+    mut selection_commands: EventWriter<SelectionCommand>,
+) {
+    if key_bindings.just_pressed(&keyboard_input, InputAction::ClearSelection) {
+        drag.start = None;
+        selection_commands.send(SelectionCommand::Clear);
+    }
+    if tunnel_tool.is_some_and(|tool| tool.enabled) {
+        drag.start = None;
+        return;
+    }
+
+    let cursor = window_query
+        .get_single()
+        .ok()
+        .zip(camera_query.get_single().ok())
+        .and_then(|(window, (camera_transform, projection))| {
+            window.cursor_position().map(|cursor| {
+                screen_to_world(
+                    cursor,
+                    camera_transform.translation.truncate(),
+                    projection.scale,
+                    Vec2::new(window.width(), window.height()),
+                )
+            })
+        });
+    if let Some(point) = cursor {
+        let over_ui = interaction_query
+            .iter()
+            .any(|interaction| *interaction != Interaction::None);
+        if mouse_input.just_pressed(MouseButton::Left) && !over_ui {
+            drag.start = Some(point);
+            drag.end = point;
+        } else if drag.start.is_some() && mouse_input.pressed(MouseButton::Left) {
+            drag.end = point;
+        }
+    }
+
+    if mouse_input.just_released(MouseButton::Left) {
+        if let Some(start) = drag.start.take() {
+            if start.distance(drag.end) >= MIN_DRAG_DISTANCE {
+                selection_commands.send(SelectionCommand::Select {
+                    min: start.min(drag.end),
+                    max: start.max(drag.end),
+                });
+            }
+        }
+    }
+}
+
+/// System applying selection commands, collecting ants from the shared [`SpatialGrid`]
+pub fn selection_system(
+    spatial_grid: Res<SpatialGrid>,
+    mut selection: ResMut<Selection>,
+    mut selection_commands: EventReader<SelectionCommand>,
+) {
+    for selection_command in selection_commands.read() {
+        match *selection_command {
+            SelectionCommand::Select { min, max } => {
+                selection.0 = spatial_grid.entities_in_rect(min, max);
+                info!("Selected {} ants", selection.0.len());
+            }
+            SelectionCommand::Clear => selection.0.clear(),
+        }
+    }
+}
+
+/// System drawing the rubber band while a selection is being dragged
+pub fn selection_rubber_band_system(drag: Res<SelectionDrag>, mut gizmos: Gizmos) {
+    let Some(start) = drag.start else {
+        return;
+    };
+    let center = (start + drag.end) * 0.5;
+    let size = (drag.end - start).abs();
+    gizmos.rect_2d(center, 0.0, size, Color::srgba(0.9, 0.9, 0.3, 0.8));
+}
+
+/// Count, average energy and role breakdown of the selected ants still alive
+pub fn selection_summary(
+    selection: &Selection,
+    ant_query: &Query<
+        (
+            &Lifecycle,
+            Option<&SpecializedRole>,
+            Option<&AntId>,
+            Option<&AntName>,
+        ),
+        With<Ant>,
+    >,
+) -> String {
+    let ants: Vec<_> = selection
+        .0
+        .iter()
+        .filter_map(|entity| ant_query.get(*entity).ok())
+        .collect();
+    if ants.is_empty() {
+        return "Selected: 0 ants".to_string();
+    }
+
+    let average_energy = ants
+        .iter()
+        .map(|(lifecycle, _, _, _)| lifecycle.energy)
+        .sum::<f32>()
+        / ants.len() as f32;
+    let mut lines = vec![format!("Selected: {} ants", ants.len())];
+    // A single ant is introduced by name
+    if let [(_, _, Some(id), Some(name))] = ants.as_slice() {
+        lines[0] = format!("Selected: {}", ant_label(**id, name));
+    }
+    lines.push(format!("Avg energy: {:.1}", average_energy));
+    for (role, label) in LISTED_ROLES {
+        let count = ants
+            .iter()
+            .filter(|(_, ant_role, _, _)| *ant_role == Some(&role))
+            .count();
+        if count > 0 {
+            lines.push(format!("{}: {}", label, count));
+        }
+    }
+    lines.join("\n")
+}
+
+/// System to set up the selection panel (hidden until ants are selected)
+///
+/// Below the figures sits a [`RenameButton`], shown while a single ant is selected.
+pub fn setup_selection_panel(mut commands: Commands) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                right: Val::Px(10.0),
+                bottom: Val::Px(10.0),
+                width: Val::Px(220.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            background_color: Color::srgba(0.0, 0.0, 0.0, 0.8).into(),
+            border_radius: BorderRadius::all(Val::Px(5.0)),
+            visibility: Visibility::Hidden,
+            ..default()
+        })
+        .insert(SelectionPanel)
+        .insert(ResponsivePanel {
+            anchor: PanelAnchor::BottomRight,
+            width: 220.0,
+            margin: 10.0,
+        })
+        .with_children(|parent| {
+            parent
+                .spawn(TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 14.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                ))
+                .insert(SelectionText);
+            parent
+                .spawn(ButtonBundle {
+                    style: Style {
+                        display: Display::None,
+                        margin: UiRect::top(Val::Px(8.0)),
+                        padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                        ..default()
+                    },
+                    background_color: Color::srgb(0.25, 0.25, 0.3).into(),
+                    border_radius: BorderRadius::all(Val::Px(3.0)),
+                    ..default()
+                })
+                .insert(RenameButton)
+                .with_children(|button| {
+                    button
+                        .spawn(TextBundle::from_section(
+                            "Rename",
+                            TextStyle {
+                                font_size: 13.0,
+                                color: Color::WHITE,
+                                ..default()
+                            },
+                        ))
+                        .insert(RenameButtonText);
+                });
+        });
+}
+
+/// System refreshing the selection panel, shown only while ants are selected
+pub fn update_selection_panel_system(
+    selection: Res<Selection>,
+    ant_query: Query<
+        (
+            &Lifecycle,
+            Option<&SpecializedRole>,
+            Option<&AntId>,
+            Option<&AntName>,
+        ),
+        With<Ant>,
+    >,
+    mut panel_query: Query<&mut Visibility, With<SelectionPanel>>,
+    mut text_query: Query<&mut Text, With<SelectionText>>,
+) {
+    let visibility = if selection.0.is_empty() {
+        Visibility::Hidden
+    } else {
+        Visibility::Visible
+    };
+    for mut panel_visibility in panel_query.iter_mut() {
+        panel_visibility.set_if_neq(visibility);
+    }
+    if selection.0.is_empty() {
+        return;
+    }
+    let summary = selection_summary(&selection, &ant_query);
+    for mut text in text_query.iter_mut() {
+        text.sections[0].value.clone_from(&summary);
+    }
+}
@@ -1,19 +1,104 @@
 use crate::components::{
-    SettingItem, SettingType, SettingsAction, SettingsButton, SettingsCategory,
-    SettingsCategoryType, SettingsPanel, SettingsToggle, UITheme, UserSettings,
+    DisasterPanelMode, DraggablePanel, InputAction, KeyBindings, PanelAnchor, PanelTitleBar,
+    ResponsivePanel, SettingItem, SettingType, SettingsAction, SettingsButton, SettingsCategory,
+    SettingsCategoryType, SettingsPanel, SettingsToggle, UITheme, UserSettings, WorldTheme,
 };
+use crate::localization::Localization;
+use crate::systems::theme::{export_theme, load_theme_file, theme_file_path};
 use bevy::prelude::*;
+use directories::ProjectDirs;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Name of the file the settings panel saves [`UserSettings`] to
+pub const SETTINGS_FILE_NAME: &str = "settings.json";
+
+/// Where [`UserSettings`] are saved and loaded; `None` keeps them in memory only
+#[derive(Resource, Clone, Debug, Default)]
+pub struct SettingsFile {
+    pub path: Option<PathBuf>,
+}
+
+impl SettingsFile {
+    /// The settings file in the user's config directory
+    pub fn new() -> Self {
+        Self {
+            path: ProjectDirs::from("com", "traponion", "ant-nest-simulator")
+                .map(|dirs| dirs.config_dir().join(SETTINGS_FILE_NAME)),
+        }
+    }
+}
+
+/// Errors raised while reading or writing the settings file
+#[derive(Debug)]
+pub enum SettingsFileError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for SettingsFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SettingsFileError::Io(e) => write!(f, "file error: {}", e),
+            SettingsFileError::Json(e) => write!(f, "malformed settings: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for SettingsFileError {}
+
+/// Write settings as pretty-printed JSON, creating the config directory if needed
+pub fn write_settings_file(path: &Path, settings: &UserSettings) -> Result<(), SettingsFileError> {
+    let json = serde_json::to_string_pretty(settings).map_err(SettingsFileError::Json)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(SettingsFileError::Io)?;
+    }
+    fs::write(path, json).map_err(SettingsFileError::Io)
+}
+
+/// Read settings written by [`write_settings_file`]
+pub fn read_settings_file(path: &Path) -> Result<UserSettings, SettingsFileError> {
+    let contents = fs::read_to_string(path).map_err(SettingsFileError::Io)?;
+    serde_json::from_str(&contents).map_err(SettingsFileError::Json)
+}
+
+/// System loading the saved settings at startup; without a settings file the defaults stay
+pub fn load_settings_system(
+    settings_file: Option<Res<SettingsFile>>,
+    mut user_settings: ResMut<UserSettings>,
+) {
+    let Some(path) = settings_file.and_then(|file| file.path.clone()) else {
+        return;
+    };
+    if path.exists() {
+        load_settings(&path, &mut user_settings);
+    }
+}
+
+fn load_settings(path: &Path, user_settings: &mut UserSettings) {
+    match read_settings_file(path) {
+        Ok(settings) => {
+            *user_settings = settings;
+            info!("Settings loaded from {}", path.display());
+        }
+        Err(e) => warn!("Failed to load settings from {}: {}", path.display(), e),
+    }
+}
 
 /// Setup settings panel UI with tabbed interface
-pub fn setup_settings_panel(mut commands: Commands, ui_theme: Res<UITheme>) {
+///
+/// Labels come from the active [`Localization`].
+pub fn setup_settings_panel(
+    mut commands: Commands,
+    ui_theme: Res<UITheme>,
+    localization: Res<Localization>,
+) {
     // Main settings panel container - initially hidden
     commands
         .spawn(NodeBundle {
             style: Style {
                 position_type: PositionType::Absolute,
-                right: Val::Px(10.0),
-                top: Val::Px(10.0),
-                width: Val::Px(400.0),
                 max_height: Val::Px(600.0),
                 flex_direction: FlexDirection::Column,
                 padding: UiRect::all(Val::Px(ui_theme.spacing.lg)),
@@ -27,8 +112,15 @@ pub fn setup_settings_panel(mut commands: Commands, ui_theme: Res<UITheme>) {
             visibility: Visibility::Hidden, // Start hidden
             ..default()
         })
+        .insert(ResponsivePanel {
+            anchor: PanelAnchor::TopRight,
+            width: 400.0,
+            margin: 10.0,
+        })
+        .insert(DraggablePanel { id: "settings" })
         .with_children(|parent| {
-            // Panel title with close button
+            // Panel title with close button; the header drags the panel
+            let panel = parent.parent_entity();
             parent
                 .spawn(NodeBundle {
                     style: Style {
@@ -41,10 +133,11 @@ pub fn setup_settings_panel(mut commands: Commands, ui_theme: Res<UITheme>) {
                     },
                     ..default()
                 })
+                .insert((Interaction::default(), PanelTitleBar { panel }))
                 .with_children(|header_parent| {
                     // Title
                     header_parent.spawn(TextBundle::from_section(
-                        "Settings",
+                        localization.t("settings.title"),
                         TextStyle {
                             font_size: ui_theme.typography.heading_small,
                             color: ui_theme.colors.text_primary,
@@ -97,9 +190,17 @@ pub fn setup_settings_panel(mut commands: Commands, ui_theme: Res<UITheme>) {
                 })
                 .with_children(|tabs_parent| {
                     let categories = [
-                        (SettingsCategoryType::Visual, "Visual", true),
-                        (SettingsCategoryType::Accessibility, "Accessibility", false),
-                        (SettingsCategoryType::Application, "Application", false),
+                        (SettingsCategoryType::Visual, "settings.tab.visual", true),
+                        (
+                            SettingsCategoryType::Accessibility,
+                            "settings.tab.accessibility",
+                            false,
+                        ),
+                        (
+                            SettingsCategoryType::Application,
+                            "settings.tab.application",
+                            false,
+                        ),
                     ];
 
                     for (category_type, label, is_active) in categories {
@@ -126,7 +227,7 @@ pub fn setup_settings_panel(mut commands: Commands, ui_theme: Res<UITheme>) {
                             })
                             .with_children(|tab_parent| {
                                 tab_parent.spawn(TextBundle::from_section(
-                                    label,
+                                    localization.t(label),
                                     TextStyle {
                                         font_size: ui_theme.typography.body_small,
                                         color: ui_theme.colors.text_primary,
@@ -158,7 +259,7 @@ pub fn setup_settings_panel(mut commands: Commands, ui_theme: Res<UITheme>) {
                     ..default()
                 })
                 .with_children(|content_parent| {
-                    setup_visual_settings_content(content_parent, &ui_theme);
+                    setup_visual_settings_content(content_parent, &ui_theme, &localization);
                 });
 
             // Action buttons at bottom
@@ -178,19 +279,24 @@ pub fn setup_settings_panel(mut commands: Commands, ui_theme: Res<UITheme>) {
                     let buttons = [
                         (
                             SettingsAction::ResetToDefaults,
-                            "Reset to Defaults",
+                            "settings.reset_defaults",
                             ui_theme.colors.action_danger,
                         ),
                         (
                             SettingsAction::SaveSettings,
-                            "Save Settings",
+                            "settings.save",
                             ui_theme.colors.action_success,
                         ),
                         (
                             SettingsAction::ApplySettings,
-                            "Apply",
+                            "settings.apply",
                             ui_theme.colors.action_primary,
                         ),
+                        (
+                            SettingsAction::LoadTheme,
+                            "settings.load_theme",
+                            ui_theme.colors.surface_secondary,
+                        ),
                     ];
 
                     for (action, label, color) in buttons {
@@ -211,7 +317,7 @@ pub fn setup_settings_panel(mut commands: Commands, ui_theme: Res<UITheme>) {
                             })
                             .with_children(|button_parent| {
                                 button_parent.spawn(TextBundle::from_section(
-                                    label,
+                                    localization.t(label),
                                     TextStyle {
                                         font_size: ui_theme.typography.body_small,
                                         color: ui_theme.colors.text_primary,
@@ -227,52 +333,78 @@ pub fn setup_settings_panel(mut commands: Commands, ui_theme: Res<UITheme>) {
 }
 
 /// Setup visual settings content (default tab)
-fn setup_visual_settings_content(parent: &mut ChildBuilder, ui_theme: &UITheme) {
+fn setup_visual_settings_content(
+    parent: &mut ChildBuilder,
+    ui_theme: &UITheme,
+    localization: &Localization,
+) {
     // Visual Effects Toggle
     setup_setting_item(
         parent,
-        "Visual Effects",
-        "Enable particle effects and visual overlays",
+        "settings.visual_effects",
         SettingType::VisualEffectsToggle,
         ui_theme,
+        localization,
     );
 
     // UI Scale Slider
     setup_setting_item(
         parent,
-        "UI Scale",
-        "Adjust the size of interface elements",
+        "settings.ui_scale",
         SettingType::UIScale,
         ui_theme,
+        localization,
     );
 
     // Color Theme Selection
     setup_setting_item(
         parent,
-        "Color Theme",
-        "Choose interface color scheme",
+        "settings.color_theme",
         SettingType::ColorTheme,
         ui_theme,
+        localization,
+    );
+
+    // World Background Selection
+    setup_setting_item(
+        parent,
+        "settings.world_theme",
+        SettingType::WorldTheme,
+        ui_theme,
+        localization,
     );
 
     // Performance Mode Toggle
     setup_setting_item(
         parent,
-        "Performance Mode",
-        "Reduce visual effects for better performance",
+        "settings.performance_mode",
         SettingType::PerformanceMode,
         ui_theme,
+        localization,
+    );
+
+    // Active Disasters Panel Mode Selection
+    setup_setting_item(
+        parent,
+        "settings.disaster_panel",
+        SettingType::DisasterPanelMode,
+        ui_theme,
+        localization,
     );
 }
 
 /// Helper function to create a setting item with label and description
+///
+/// The description is looked up under `<label_key>.description`.
 fn setup_setting_item(
     parent: &mut ChildBuilder,
-    label: &str,
-    description: &str,
+    label_key: &str,
     setting_type: SettingType,
     ui_theme: &UITheme,
+    localization: &Localization,
 ) {
+    let description_key = format!("{}.description", label_key);
+
     parent
         .spawn(NodeBundle {
             style: Style {
@@ -291,7 +423,7 @@ fn setup_setting_item(
         .with_children(|item_parent| {
             // Setting label
             item_parent.spawn(TextBundle::from_section(
-                label,
+                localization.t(label_key),
                 TextStyle {
                     font_size: ui_theme.typography.body_medium,
                     color: ui_theme.colors.text_primary,
@@ -301,7 +433,7 @@ fn setup_setting_item(
 
             // Setting description
             item_parent.spawn(TextBundle::from_section(
-                description,
+                localization.t(&description_key),
                 TextStyle {
                     font_size: ui_theme.typography.body_small,
                     color: ui_theme.colors.text_secondary,
@@ -312,17 +444,15 @@ fn setup_setting_item(
             // Setting control (will be customized based on setting type)
             match setting_type {
                 SettingType::VisualEffectsToggle | SettingType::PerformanceMode => {
-                    setup_toggle_control(item_parent, setting_type.clone(), ui_theme);
+                    setup_toggle_control(item_parent, setting_type, ui_theme, localization);
                 }
                 SettingType::UIScale => {
-                    setup_slider_control(item_parent, setting_type.clone(), ui_theme);
+                    setup_slider_control(item_parent, setting_type, ui_theme);
                 }
-                SettingType::ColorTheme => {
-                    setup_dropdown_control(item_parent, setting_type.clone(), ui_theme);
-                }
-                _ => {
-                    // Default control for other types
-                    setup_toggle_control(item_parent, setting_type.clone(), ui_theme);
+                SettingType::ColorTheme
+                | SettingType::WorldTheme
+                | SettingType::DisasterPanelMode => {
+                    setup_dropdown_control(item_parent, setting_type, ui_theme, localization);
                 }
             }
         })
@@ -330,7 +460,12 @@ fn setup_setting_item(
 }
 
 /// Setup toggle control for boolean settings
-fn setup_toggle_control(parent: &mut ChildBuilder, setting_type: SettingType, ui_theme: &UITheme) {
+fn setup_toggle_control(
+    parent: &mut ChildBuilder,
+    setting_type: SettingType,
+    ui_theme: &UITheme,
+    localization: &Localization,
+) {
     parent
         .spawn(ButtonBundle {
             style: Style {
@@ -349,7 +484,7 @@ fn setup_toggle_control(parent: &mut ChildBuilder, setting_type: SettingType, ui
         })
         .with_children(|toggle_parent| {
             toggle_parent.spawn(TextBundle::from_section(
-                "ON",
+                localization.t("settings.on"),
                 TextStyle {
                     font_size: ui_theme.typography.body_small,
                     color: ui_theme.colors.text_primary,
@@ -422,6 +557,7 @@ fn setup_dropdown_control(
     parent: &mut ChildBuilder,
     setting_type: SettingType,
     ui_theme: &UITheme,
+    localization: &Localization,
 ) {
     parent
         .spawn(ButtonBundle {
@@ -441,8 +577,13 @@ fn setup_dropdown_control(
             ..default()
         })
         .with_children(|dropdown_parent| {
+            let label_key = match setting_type {
+                SettingType::WorldTheme => WorldTheme::default().label_key(),
+                SettingType::DisasterPanelMode => DisasterPanelMode::default().label_key(),
+                _ => "settings.color_theme.default",
+            };
             dropdown_parent.spawn(TextBundle::from_section(
-                "Default Theme",
+                localization.t(label_key),
                 TextStyle {
                     font_size: ui_theme.typography.body_small,
                     color: ui_theme.colors.text_primary,
@@ -462,15 +603,95 @@ fn setup_dropdown_control(
         .insert(SettingItem { setting_type });
 }
 
+/// System cycling the world background preset when its dropdown is clicked
+///
+/// The dropdown's label follows the setting, including after a reset to defaults.
+pub fn world_theme_dropdown_system(
+    interaction_query: Query<(&Interaction, &SettingItem), (Changed<Interaction>, With<Button>)>,
+    dropdown_query: Query<(&SettingItem, &Children), With<Button>>,
+    mut text_query: Query<&mut Text>,
+    mut user_settings: ResMut<UserSettings>,
+    localization: Res<Localization>,
+) {
+    for (interaction, item) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed && item.setting_type == SettingType::WorldTheme {
+            user_settings.world_theme = user_settings.world_theme.next();
+            info!("World theme set to {:?}", user_settings.world_theme);
+        }
+    }
+
+    if !user_settings.is_changed() {
+        return;
+    }
+    let label = localization.t(user_settings.world_theme.label_key());
+    set_dropdown_label(
+        &dropdown_query,
+        &mut text_query,
+        SettingType::WorldTheme,
+        label,
+    );
+}
+
+/// System switching the active disasters panel between detailed and compact mode
+pub fn disaster_panel_mode_dropdown_system(
+    interaction_query: Query<(&Interaction, &SettingItem), (Changed<Interaction>, With<Button>)>,
+    dropdown_query: Query<(&SettingItem, &Children), With<Button>>,
+    mut text_query: Query<&mut Text>,
+    mut user_settings: ResMut<UserSettings>,
+    localization: Res<Localization>,
+) {
+    for (interaction, item) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed
+            && item.setting_type == SettingType::DisasterPanelMode
+        {
+            user_settings.disaster_panel_mode = user_settings.disaster_panel_mode.next();
+            info!(
+                "Disaster panel mode set to {:?}",
+                user_settings.disaster_panel_mode
+            );
+        }
+    }
+
+    if !user_settings.is_changed() {
+        return;
+    }
+    let label = localization.t(user_settings.disaster_panel_mode.label_key());
+    set_dropdown_label(
+        &dropdown_query,
+        &mut text_query,
+        SettingType::DisasterPanelMode,
+        label,
+    );
+}
+
+/// Show `label` on every dropdown editing `setting_type`
+fn set_dropdown_label(
+    dropdown_query: &Query<(&SettingItem, &Children), With<Button>>,
+    text_query: &mut Query<&mut Text>,
+    setting_type: SettingType,
+    label: &str,
+) {
+    for (item, children) in dropdown_query.iter() {
+        if item.setting_type != setting_type {
+            continue;
+        }
+        if let Some(mut text) = children
+            .first()
+            .and_then(|child| text_query.get_mut(*child).ok())
+        {
+            if text.sections[0].value != label {
+                text.sections[0].value = label.to_string();
+            }
+        }
+    }
+}
+
 /// Setup settings toggle button in main UI
 pub fn setup_settings_toggle_button(mut commands: Commands, ui_theme: Res<UITheme>) {
     commands
         .spawn(ButtonBundle {
             style: Style {
                 position_type: PositionType::Absolute,
-                top: Val::Px(10.0),
-                right: Val::Px(10.0),
-                width: Val::Px(40.0),
                 height: Val::Px(40.0),
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
@@ -492,16 +713,22 @@ pub fn setup_settings_toggle_button(mut commands: Commands, ui_theme: Res<UIThem
                 },
             ));
         })
+        .insert(ResponsivePanel {
+            anchor: PanelAnchor::TopRight,
+            width: 40.0,
+            margin: 10.0,
+        })
         .insert(SettingsToggle { is_visible: false });
 }
 
 /// System for handling settings toggle input (S key)
 pub fn settings_toggle_input_system(
     input: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
     mut settings_toggle_query: Query<&mut SettingsToggle>,
     mut panel_query: Query<&mut Visibility, With<SettingsPanel>>,
 ) {
-    if input.just_pressed(KeyCode::KeyS) {
+    if key_bindings.just_pressed(&input, InputAction::ToggleSettings) {
         if let Ok(mut settings_toggle) = settings_toggle_query.get_single_mut() {
             settings_toggle.is_visible = !settings_toggle.is_visible;
 
@@ -516,6 +743,38 @@ pub fn settings_toggle_input_system(
     }
 }
 
+/// Replace the theme with the user's theme file
+///
+/// Without a theme file yet, the current theme is written there as a starting point
+/// to edit and share.
+fn load_theme(ui_theme: &mut UITheme) {
+    let Some(path) = theme_file_path() else {
+        warn!("No config directory to load a theme from");
+        return;
+    };
+    if !path.exists() {
+        let written = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| std::fs::write(&path, export_theme(ui_theme)));
+        match written {
+            Ok(()) => info!(
+                "Wrote the current theme to {}; edit it and load again",
+                path.display()
+            ),
+            Err(e) => warn!("Failed to write theme file {}: {}", path.display(), e),
+        }
+        return;
+    }
+    match load_theme_file(&path) {
+        Ok(theme) => {
+            *ui_theme = theme;
+            info!("Loaded theme from {}", path.display());
+        }
+        Err(e) => warn!("Failed to load theme from {}: {}", path.display(), e),
+    }
+}
+
 /// System for handling settings button interactions
 pub fn handle_settings_interactions_system(
     mut interaction_query: Query<
@@ -523,14 +782,17 @@ pub fn handle_settings_interactions_system(
             &Interaction,
             &mut BackgroundColor,
             Option<&SettingsButton>,
-            Option<&SettingsToggle>,
+            Has<SettingsToggle>,
         ),
         (Changed<Interaction>, With<Button>),
     >,
     mut panel_query: Query<&mut Visibility, With<SettingsPanel>>,
-    mut settings_toggle_query: Query<&mut SettingsToggle, Without<Button>>,
+    mut settings_toggle_query: Query<&mut SettingsToggle>,
     mut user_settings: ResMut<UserSettings>,
+    mut ui_theme: ResMut<UITheme>,
+    settings_file: Option<Res<SettingsFile>>,
 ) {
+    let settings_path = settings_file.and_then(|file| file.path.clone());
     for (interaction, mut background_color, settings_button, settings_toggle_button) in
         &mut interaction_query
     {
@@ -551,23 +813,29 @@ pub fn handle_settings_interactions_system(
                             *user_settings = UserSettings::default();
                             info!("Settings reset to defaults");
                         }
-                        SettingsAction::SaveSettings => {
-                            // TODO: Implement save to file
-                            info!("Settings saved");
-                        }
+                        SettingsAction::SaveSettings => match &settings_path {
+                            Some(path) => match write_settings_file(path, &user_settings) {
+                                Ok(()) => info!("Settings saved to {}", path.display()),
+                                Err(e) => {
+                                    warn!("Failed to save settings to {}: {}", path.display(), e)
+                                }
+                            },
+                            None => warn!("No config directory to save settings to"),
+                        },
                         SettingsAction::ApplySettings => {
                             // TODO: Apply settings to systems
                             info!("Settings applied");
                         }
-                        SettingsAction::LoadSettings => {
-                            // TODO: Implement load from file
-                            info!("Settings loaded");
-                        }
+                        SettingsAction::LoadSettings => match &settings_path {
+                            Some(path) => load_settings(path, &mut user_settings),
+                            None => warn!("No config directory to load settings from"),
+                        },
+                        SettingsAction::LoadTheme => load_theme(&mut ui_theme),
                     }
                 }
 
                 // Handle settings toggle button
-                if settings_toggle_button.is_some() {
+                if settings_toggle_button {
                     if let Ok(mut settings_toggle) = settings_toggle_query.get_single_mut() {
                         settings_toggle.is_visible = !settings_toggle.is_visible;
 
@@ -583,7 +851,7 @@ pub fn handle_settings_interactions_system(
             }
             Interaction::Hovered => {
                 // Visual feedback for hover state
-                if settings_button.is_some() || settings_toggle_button.is_some() {
+                if settings_button.is_some() || settings_toggle_button {
                     *background_color = Color::srgb(0.3, 0.3, 0.3).into();
                 }
             }
@@ -597,7 +865,7 @@ pub fn handle_settings_interactions_system(
                         SettingsAction::ClosePanel => Color::srgb(0.6, 0.2, 0.2).into(),
                         _ => Color::srgb(0.2, 0.2, 0.2).into(),
                     };
-                } else if settings_toggle_button.is_some() {
+                } else if settings_toggle_button {
                     *background_color = Color::srgba(0.2, 0.2, 0.2, 0.8).into();
                 }
             }
@@ -0,0 +1,19 @@
+use crate::components::{DayLengthMode, SimulationTime};
+use bevy::prelude::*;
+
+/// System advancing the simulated clock and day counter
+///
+/// Days follow the [`DayLengthMode`], counted in seconds when the resource is missing.
+pub fn simulation_time_system(
+    time: Res<Time>,
+    day_length_mode: Option<Res<DayLengthMode>>,
+    mut simulation_time: ResMut<SimulationTime>,
+) {
+    let mode = day_length_mode.map_or(DayLengthMode::default(), |mode| *mode);
+    let seconds = mode.step_seconds(time.delta_seconds(), simulation_time.day_length_seconds);
+    let previous_day = simulation_time.current_day;
+    simulation_time.advance(seconds);
+    if simulation_time.current_day != previous_day {
+        info!("Day {} begins", simulation_time.current_day);
+    }
+}
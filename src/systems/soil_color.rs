@@ -0,0 +1,66 @@
+use crate::components::{DepthLayer, Soil, SoilCell, SoilColorConfig, SoilColorMode, SoilHeatmap};
+use crate::systems::disasters::{MAX_SOIL_TEMPERATURE, MIN_SOIL_TEMPERATURE};
+use bevy::prelude::*;
+
+/// Everyday soil color: the layer's base color, greener where rich and darker where wet
+pub fn realistic_soil_color(config: &SoilColorConfig, layer: DepthLayer, soil: &SoilCell) -> Color {
+    let [r, g, b, _] = layer.base_color().to_srgba().to_f32_array();
+    let nutrition = soil.nutrition.clamp(0.0, 1.0);
+    let moisture = soil.moisture.clamp(0.0, 1.0);
+
+    let green_shift = config.nutrient_greening * nutrition;
+    let brightness = 1.0 - config.wet_darkening * moisture;
+
+    Color::srgb(
+        (r - green_shift * 0.5) * brightness,
+        (g + green_shift) * brightness,
+        b * brightness,
+    )
+}
+
+/// Debug color ramp for a single soil variable
+pub fn heatmap_soil_color(heatmap: SoilHeatmap, soil: &SoilCell) -> Color {
+    match heatmap {
+        SoilHeatmap::Moisture => {
+            let t = soil.moisture.clamp(0.0, 1.0);
+            Color::srgb(0.9 - 0.8 * t, 0.8 - 0.5 * t, 0.4 + 0.6 * t) // Sand to blue
+        }
+        SoilHeatmap::Temperature => {
+            let t = ((soil.temperature - MIN_SOIL_TEMPERATURE)
+                / (MAX_SOIL_TEMPERATURE - MIN_SOIL_TEMPERATURE))
+                .clamp(0.0, 1.0);
+            Color::srgb(t, 0.2, 1.0 - t) // Blue to red
+        }
+        SoilHeatmap::Nutrition => {
+            let t = soil.nutrition.clamp(0.0, 1.0);
+            Color::srgb(0.5 - 0.4 * t, 0.5 + 0.4 * t, 0.5 - 0.4 * t) // Gray to green
+        }
+    }
+}
+
+/// System recoloring soil cells from their current environmental state
+///
+/// Recolors every `update_interval` seconds rather than every frame, or right away when
+/// the color mode is switched. The realistic look is the default; a heatmap mode
+/// overrides it.
+pub fn realistic_soil_color_system(
+    time: Res<Time>,
+    mut config: ResMut<SoilColorConfig>,
+    mut soil_query: Query<(&SoilCell, &DepthLayer, &mut Sprite), With<Soil>>,
+) {
+    // The timer is bookkeeping; only outside edits such as a mode switch count as changes
+    let mode_changed = config.is_changed();
+    let config = config.bypass_change_detection();
+    config.update_timer += time.delta_seconds();
+    if config.update_timer < config.update_interval && !mode_changed {
+        return;
+    }
+    config.update_timer = 0.0;
+
+    for (soil, layer, mut sprite) in soil_query.iter_mut() {
+        sprite.color = match config.mode {
+            SoilColorMode::Realistic => realistic_soil_color(config, *layer, soil),
+            SoilColorMode::Heatmap(heatmap) => heatmap_soil_color(heatmap, soil),
+        };
+    }
+}
@@ -1,8 +1,23 @@
-use crate::components::{ColonyStatistics, StatisticsPanel, StatisticsToggle, UITheme};
+use crate::components::{
+    ChamberType, ColonyDevelopmentPhase, ColonyStatistics, DisasterState, DisasterType,
+    DisplayedText, DraggablePanel, InputAction, KeyBindings, PanelAnchor, PanelTitleBar,
+    ResponsivePanel, SpecializedRole, StatSection, StatisticsPanel, StatisticsSectionContent,
+    StatisticsSectionHeader, StatisticsSectionLabel, StatisticsToggle, UITheme, UserSettings,
+};
+use crate::localization::Localization;
+use crate::systems::colony_development::{estimate_phase_completion, PhaseProgressTracking};
 use bevy::prelude::*;
 
 /// Setup the statistics display panel UI
-pub fn setup_statistics_panel(mut commands: Commands, ui_theme: Res<UITheme>) {
+///
+/// Each section starts expanded or collapsed as recorded in [`UserSettings`]. Labels
+/// come from the active [`Localization`].
+pub fn setup_statistics_panel(
+    mut commands: Commands,
+    ui_theme: Res<UITheme>,
+    user_settings: Res<UserSettings>,
+    localization: Res<Localization>,
+) {
     // Main statistics panel container (initially hidden)
     commands
         .spawn(NodeBundle {
@@ -24,123 +39,51 @@ pub fn setup_statistics_panel(mut commands: Commands, ui_theme: Res<UITheme>) {
         })
         .insert(StatisticsPanel)
         .insert(StatisticsToggle::default())
+        .insert(ResponsivePanel {
+            anchor: PanelAnchor::BottomLeft,
+            width: 350.0,
+            margin: 20.0,
+        })
+        .insert(DraggablePanel { id: "statistics" })
         .with_children(|parent| {
-            // Panel Title
-            parent.spawn(TextBundle::from_section(
-                "Colony Statistics",
-                TextStyle {
-                    font_size: ui_theme.typography.heading_small,
-                    color: ui_theme.colors.text_primary,
-                    ..default()
-                },
-            ));
-
-            // Population Section
-            create_statistics_section(parent, "Population", &ui_theme);
-            parent.spawn(create_stat_text(
-                "Total Ants: 0",
-                "population_total",
-                &ui_theme,
-            ));
-            parent.spawn(create_stat_text("Queen: 0", "population_queen", &ui_theme));
-            parent.spawn(create_stat_text("Eggs: 0", "population_eggs", &ui_theme));
-            parent.spawn(create_stat_text(
-                "Age Distribution: No ants",
-                "population_age",
-                &ui_theme,
-            ));
-
-            // Resource Section
-            create_statistics_section(parent, "Resources", &ui_theme);
-            parent.spawn(create_stat_text(
-                "Food Sources: 0",
-                "resource_food",
-                &ui_theme,
-            ));
-            parent.spawn(create_stat_text(
-                "Avg Energy: 0%",
-                "resource_energy",
-                &ui_theme,
-            ));
-            parent.spawn(create_stat_text(
-                "Foraging Efficiency: 0%",
-                "resource_efficiency",
-                &ui_theme,
-            ));
-            parent.spawn(create_stat_text(
-                "Carrying Food: 0",
-                "resource_carrying",
-                &ui_theme,
-            ));
-
-            // Environment Section
-            create_statistics_section(parent, "Environment", &ui_theme);
-            parent.spawn(create_stat_text(
-                "Soil Moisture: 0%",
-                "environment_moisture",
-                &ui_theme,
-            ));
-            parent.spawn(create_stat_text(
-                "Soil Temperature: 0°C",
-                "environment_temperature",
-                &ui_theme,
-            ));
-            parent.spawn(create_stat_text(
-                "Soil Nutrition: 0%",
-                "environment_nutrition",
-                &ui_theme,
-            ));
-            parent.spawn(create_stat_text(
-                "Active Disasters: 0",
-                "environment_disasters",
-                &ui_theme,
-            ));
-
-            // Behavior Section
-            create_statistics_section(parent, "Behavior", &ui_theme);
-            parent.spawn(create_stat_text(
-                "Activity: No ants",
-                "behavior_activity",
-                &ui_theme,
-            ));
+            // Panel Title, which also drags the panel
+            let panel = parent.parent_entity();
+            parent
+                .spawn(TextBundle::from_section(
+                    localization.t("stats.title"),
+                    TextStyle {
+                        font_size: ui_theme.typography.heading_small,
+                        color: ui_theme.colors.text_primary,
+                        ..default()
+                    },
+                ))
+                .insert((Interaction::default(), PanelTitleBar { panel }));
 
-            // Role Distribution Section
-            create_statistics_section(parent, "Role Distribution", &ui_theme);
-            parent.spawn(create_stat_text(
-                "General Workers: 0",
-                "role_general_workers",
-                &ui_theme,
-            ));
-            parent.spawn(create_stat_text("Foragers: 0", "role_foragers", &ui_theme));
-            parent.spawn(create_stat_text(
-                "Nest Maintainers: 0",
-                "role_nest_maintainers",
-                &ui_theme,
-            ));
-            parent.spawn(create_stat_text(
-                "Nursery Workers: 0",
-                "role_nursery_workers",
-                &ui_theme,
-            ));
-            parent.spawn(create_stat_text(
-                "Waste Managers: 0",
-                "role_waste_managers",
-                &ui_theme,
-            ));
-            parent.spawn(create_stat_text(
-                "Storage Workers: 0",
-                "role_storage_workers",
-                &ui_theme,
-            ));
-            parent.spawn(create_stat_text(
-                "Specialization Rate: 0%",
-                "role_specialization",
-                &ui_theme,
-            ));
+            for section in StatSection::ALL {
+                let expanded = user_settings.is_section_expanded(section);
+                create_statistics_section(parent, section, expanded, &ui_theme, &localization);
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(ui_theme.spacing.xs),
+                            display: section_display(expanded),
+                            ..default()
+                        },
+                        ..default()
+                    })
+                    .insert(StatisticsSectionContent { section })
+                    .with_children(|content| {
+                        for (identifier, initial_value) in section_stats(section) {
+                            let initial_text = stat_text(&localization, identifier, initial_value);
+                            content.spawn(create_stat_text(&initial_text, identifier, &ui_theme));
+                        }
+                    });
+            }
 
             // Controls hint
             parent.spawn(TextBundle::from_section(
-                "Press S to toggle",
+                localization.t("stats.toggle_hint"),
                 TextStyle {
                     font_size: ui_theme.typography.body_small,
                     color: ui_theme.colors.text_secondary,
@@ -150,16 +93,104 @@ pub fn setup_statistics_panel(mut commands: Commands, ui_theme: Res<UITheme>) {
         });
 }
 
-/// Create a section header for the statistics panel
-fn create_statistics_section(parent: &mut ChildBuilder, title: &str, ui_theme: &UITheme) {
-    parent.spawn(TextBundle::from_section(
-        title,
-        TextStyle {
-            font_size: ui_theme.typography.body_medium,
-            color: ui_theme.colors.text_secondary,
+/// Identifier and initial value of every figure in a section
+///
+/// A figure is labeled by the `stats.<identifier>` key of the [`Localization`].
+fn section_stats(section: StatSection) -> &'static [(&'static str, &'static str)] {
+    match section {
+        StatSection::Population => &[
+            ("population_total", "0"),
+            ("population_queen", "0"),
+            ("population_castes", "-"),
+            ("population_eggs", "0"),
+            ("population_phase", "-"),
+        ],
+        StatSection::Resources => &[
+            ("resource_food", "0"),
+            ("resource_energy", "0"),
+            ("resource_carrying", "0"),
+        ],
+        StatSection::Environment => &[
+            ("environment_depth", "0"),
+            ("environment_location", "0 / 0"),
+            ("environment_disasters", "0"),
+        ],
+        StatSection::Nest => &[
+            ("nest_depth", "0.0"),
+            ("nest_tunnels", "0"),
+            ("nest_chambers", "-"),
+        ],
+        StatSection::Behavior => &[("behavior_activity", "-")],
+        StatSection::Roles => &[
+            ("role_general_workers", "0"),
+            ("role_foragers", "0"),
+            ("role_nursery_workers", "0"),
+            ("role_nest_maintainers", "0"),
+            ("role_storage_workers", "0"),
+        ],
+    }
+}
+
+/// A figure's text: its localized label followed by its value
+fn stat_text(localization: &Localization, identifier: &str, value: &str) -> String {
+    format!(
+        "{}: {}",
+        localization.t(&format!("stats.{}", identifier)),
+        value
+    )
+}
+
+fn section_display(expanded: bool) -> Display {
+    if expanded {
+        Display::Flex
+    } else {
+        Display::None
+    }
+}
+
+/// Header label showing a section's title and whether it is open
+pub fn section_header_text(
+    section: StatSection,
+    expanded: bool,
+    localization: &Localization,
+) -> String {
+    format!(
+        "{} {}",
+        if expanded { "[-]" } else { "[+]" },
+        localization.t(section.title_key())
+    )
+}
+
+/// Create a clickable section header for the statistics panel
+fn create_statistics_section(
+    parent: &mut ChildBuilder,
+    section: StatSection,
+    expanded: bool,
+    ui_theme: &UITheme,
+    localization: &Localization,
+) {
+    parent
+        .spawn(ButtonBundle {
+            style: Style {
+                padding: UiRect::axes(Val::Px(ui_theme.spacing.xs), Val::Px(2.0)),
+                ..default()
+            },
+            background_color: Color::NONE.into(),
             ..default()
-        },
-    ));
+        })
+        .insert(StatisticsSectionHeader { section })
+        .with_children(|header| {
+            header
+                .spawn(TextBundle::from_section(
+                    section_header_text(section, expanded, localization),
+                    TextStyle {
+                        font_size: ui_theme.typography.body_medium,
+                        color: ui_theme.colors.text_secondary,
+                        ..default()
+                    },
+                ))
+                .insert(StatisticsSectionLabel { section });
+        });
 }
 
 /// Create a statistics text element with identifier for updates
@@ -167,7 +198,7 @@ fn create_stat_text(
     initial_text: &str,
     identifier: &str,
     ui_theme: &UITheme,
-) -> (TextBundle, Name) {
+) -> (TextBundle, Name, DisplayedText) {
     (
         TextBundle::from_section(
             initial_text,
@@ -178,13 +209,22 @@ fn create_stat_text(
             },
         ),
         Name::new(identifier.to_string()),
+        DisplayedText(initial_text.to_string()),
     )
 }
 
 /// System for updating statistics display in real-time
+///
+/// Labels come from the active [`Localization`], so a locale switch shows on the next
+/// update.
 pub fn update_statistics_display(
     colony_stats: Res<ColonyStatistics>,
-    mut text_query: Query<(&mut Text, &Name)>,
+    disaster_state: Res<DisasterState>,
+    localization: Res<Localization>,
+    colony_phase: Option<Res<ColonyDevelopmentPhase>>,
+    progress_tracking: Option<Res<PhaseProgressTracking>>,
+    role_query: Query<&SpecializedRole>,
+    mut text_query: Query<(&mut Text, &Name, &mut DisplayedText)>,
     toggle_query: Query<&StatisticsToggle, With<StatisticsPanel>>,
 ) {
     // Only update if panel is visible
@@ -194,95 +234,149 @@ pub fn update_statistics_display(
         }
     }
 
-    for (mut text, name) in text_query.iter_mut() {
-        let new_text = match name.as_str() {
-            "population_total" => format!("Total Ants: {}", colony_stats.total_ant_count),
-            "population_queen" => format!("Queen: {}", colony_stats.queen_count),
-            "population_eggs" => format!(
-                "Eggs: {} (Avg hatch: {:.1}s)",
-                colony_stats.egg_count, colony_stats.average_incubation_time
+    let role_count = |role: SpecializedRole| role_query.iter().filter(|r| **r == role).count();
+    let t = |key: &'static str| localization.t(key);
+
+    for (mut text, name, mut displayed) in text_query.iter_mut() {
+        let value = match name.as_str() {
+            "population_total" => colony_stats.total_ant_count.to_string(),
+            "population_queen" => colony_stats.queen_count.to_string(),
+            "population_castes" => format!(
+                "{} {} / {} {} / {} {}",
+                t("caste.minor"),
+                colony_stats.minor_count,
+                t("caste.media"),
+                colony_stats.media_count,
+                t("caste.major"),
+                colony_stats.major_count
             ),
-            "population_age" => format!("Age: {}", colony_stats.age_distribution_text()),
+            "population_eggs" => colony_stats.egg_count.to_string(),
+            "population_phase" => match (colony_phase.as_deref(), progress_tracking.as_deref()) {
+                (Some(colony_phase), Some(progress_tracking)) => format!(
+                    "{} ({:.0}%, {})",
+                    colony_phase.current_phase.display_name(),
+                    colony_phase.phase_progress * 100.0,
+                    estimate_phase_completion(colony_phase, progress_tracking).label()
+                ),
+                _ => continue,
+            },
 
             "resource_food" => format!(
-                "Food Sources: {} (Total: {:.0})",
-                colony_stats.available_food_sources, colony_stats.total_food_nutrition
+                "{:.0} {}, {:.0} {}",
+                colony_stats.colony_food,
+                t("stats.carbs"),
+                colony_stats.colony_protein,
+                t("stats.protein")
             ),
             "resource_energy" => format!(
-                "Avg Energy: {:.0}% ({:.0}-{:.0})",
-                colony_stats.average_energy_percentage(),
-                colony_stats.min_ant_energy,
-                colony_stats.max_ant_energy
-            ),
-            "resource_efficiency" => format!(
-                "Foraging Efficiency: {:.1}%",
-                colony_stats.foraging_efficiency()
-            ),
-            "resource_carrying" => format!(
-                "Carrying Food: {} (Value: {:.0})",
-                colony_stats.ants_carrying_food, colony_stats.total_carried_food_value
+                "{:.0} ({} {:.0})",
+                colony_stats.average_ant_energy,
+                t("stats.hp"),
+                colony_stats.average_ant_hp
             ),
+            "resource_carrying" => colony_stats.ants_carrying_food.to_string(),
 
-            "environment_moisture" => format!(
-                "Soil Moisture: {:.1}% ({:.1}-{:.1})",
-                colony_stats.average_soil_moisture * 100.0,
-                colony_stats.min_soil_moisture * 100.0,
-                colony_stats.max_soil_moisture * 100.0
-            ),
-            "environment_temperature" => format!(
-                "Soil Temperature: {:.1}°C ({:.1}-{:.1})",
-                colony_stats.average_soil_temperature,
-                colony_stats.min_soil_temperature,
-                colony_stats.max_soil_temperature
+            "environment_depth" => format!("{:.1}", colony_stats.average_ant_depth),
+            "environment_location" => format!(
+                "{} / {}",
+                colony_stats.ants_on_surface, colony_stats.ants_underground
             ),
-            "environment_nutrition" => format!(
-                "Soil Nutrition: {:.1}% ({:.1}-{:.1})",
-                colony_stats.average_soil_nutrition * 100.0,
-                colony_stats.min_soil_nutrition * 100.0,
-                colony_stats.max_soil_nutrition * 100.0
-            ),
-            "environment_disasters" => {
-                format!("Active Disasters: {}", colony_stats.active_disasters_count)
-            }
+            "environment_disasters" => DisasterType::ALL
+                .iter()
+                .filter(|disaster_type| disaster_state.is_active(**disaster_type))
+                .count()
+                .to_string(),
 
-            "behavior_activity" => colony_stats.behavior_distribution_text(),
+            "nest_depth" => match colony_stats.deepest_layer {
+                Some(layer) => format!("{:.1} ({:?})", colony_stats.max_nest_depth, layer),
+                None => "0.0".to_string(),
+            },
+            "nest_tunnels" => format!("{:.0}", colony_stats.total_tunnel_length),
+            "nest_chambers" => ChamberType::ALL
+                .iter()
+                .map(|chamber_type| {
+                    format!(
+                        "{:?} {}",
+                        chamber_type,
+                        colony_stats.chamber_count(*chamber_type)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(" / "),
 
-            "role_general_workers" => {
-                format!("General Workers: {}", colony_stats.role_general_workers)
-            }
-            "role_foragers" => format!("Foragers: {}", colony_stats.role_foragers),
-            "role_nest_maintainers" => {
-                format!("Nest Maintainers: {}", colony_stats.role_nest_maintainers)
-            }
-            "role_nursery_workers" => {
-                format!("Nursery Workers: {}", colony_stats.role_nursery_workers)
-            }
-            "role_waste_managers" => {
-                format!("Waste Managers: {}", colony_stats.role_waste_managers)
-            }
-            "role_storage_workers" => {
-                format!("Storage Workers: {}", colony_stats.role_storage_workers)
-            }
-            "role_specialization" => format!(
-                "Specialization Rate: {:.1}%",
-                colony_stats.specialization_rate()
+            "behavior_activity" => format!(
+                "{} {} / {} {} / {} {} / {} {} / {} {}",
+                t("state.foraging"),
+                colony_stats.ants_foraging,
+                t("state.idle"),
+                colony_stats.ants_idle,
+                t("state.returning"),
+                colony_stats.ants_returning,
+                t("state.resting"),
+                colony_stats.ants_resting,
+                t("state.defending"),
+                colony_stats.ants_defending
             ),
 
+            "role_general_workers" => role_count(SpecializedRole::GeneralWorker).to_string(),
+            "role_foragers" => role_count(SpecializedRole::Forager).to_string(),
+            "role_nursery_workers" => role_count(SpecializedRole::NurseryWorker).to_string(),
+            "role_nest_maintainers" => role_count(SpecializedRole::NestMaintainer).to_string(),
+            "role_storage_workers" => role_count(SpecializedRole::StorageWorker).to_string(),
+
             _ => continue,
         };
 
-        if !text.sections.is_empty() {
-            text.sections[0].value = new_text;
+        displayed.set(&mut text, stat_text(&localization, name.as_str(), &value));
+    }
+}
+
+/// System flipping a section's expansion state in [`UserSettings`] when its header is clicked
+pub fn statistics_section_header_system(
+    header_query: Query<(&Interaction, &StatisticsSectionHeader), Changed<Interaction>>,
+    mut user_settings: ResMut<UserSettings>,
+) {
+    for (interaction, header) in header_query.iter() {
+        if *interaction == Interaction::Pressed {
+            user_settings.toggle_section(header.section);
+        }
+    }
+}
+
+/// System showing or hiding section contents to match [`UserSettings`]
+///
+/// Runs whenever the settings or the locale change, so a click, a reset or loaded
+/// settings all apply.
+pub fn apply_statistics_sections_system(
+    user_settings: Res<UserSettings>,
+    localization: Res<Localization>,
+    mut content_query: Query<(&StatisticsSectionContent, &mut Style)>,
+    mut label_query: Query<(&StatisticsSectionLabel, &mut Text)>,
+) {
+    if !user_settings.is_changed() && !localization.is_changed() {
+        return;
+    }
+
+    for (content, mut style) in content_query.iter_mut() {
+        let display = section_display(user_settings.is_section_expanded(content.section));
+        if style.display != display {
+            style.display = display;
         }
     }
+
+    for (label, mut text) in label_query.iter_mut() {
+        let expanded = user_settings.is_section_expanded(label.section);
+        text.sections[0].value = section_header_text(label.section, expanded, &localization);
+    }
 }
 
-/// System for handling statistics panel toggle input (S key)
+/// System for handling statistics panel toggle input (F4 key)
 pub fn statistics_toggle_input_system(
     keyboard_input: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
     mut panel_query: Query<(&mut Style, &mut StatisticsToggle), With<StatisticsPanel>>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::KeyS) {
+    if key_bindings.just_pressed(&keyboard_input, InputAction::ToggleStatistics) {
         if let Ok((mut style, mut toggle)) = panel_query.get_single_mut() {
             toggle.is_visible = !toggle.is_visible;
 
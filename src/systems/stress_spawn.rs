@@ -0,0 +1,148 @@
+use crate::components::{
+    AntAiConfig, BehaviorModifiers, CasteConfig, FoodKind, InitialSpawnConfig, InputAction,
+    KeyBindings, Lifecycle, LifespanModel, Position, SimulationRng, SpatialGrid, SpeciesConfig,
+    StressSpawnConfig,
+};
+use crate::systems::foraging::spawn_food_source;
+use crate::systems::rendering::{spawn_worker_ant, spawner_rng};
+use bevy::prelude::*;
+use rand::prelude::*;
+
+/// Spawn `count` workers of the species and add them to `grid`
+///
+/// Each worker rolls its position, caste and lifespan from `config` exactly as the
+/// founding workers do, so it carries every component a hatched worker has.
+pub fn spawn_ants(
+    commands: &mut Commands,
+    grid: &mut SpatialGrid,
+    count: usize,
+    config: &SpeciesConfig,
+    ai_config: &AntAiConfig,
+    rng: &mut impl Rng,
+) -> Vec<Entity> {
+    (0..count)
+        .map(|_| {
+            let position = config.placement.sample_position(rng);
+            let caste = config.castes.roll(rng);
+            let max_age = config.lifespan.sample(caste, rng);
+            let age = rng.gen_range(0.0..max_age * 0.5); // Mixed ages, like the founders
+            let entity = spawn_worker_ant(
+                commands,
+                position.clone(),
+                Lifecycle {
+                    age,
+                    max_age,
+                    energy: 100.0,
+                    max_energy: 100.0,
+                },
+                caste,
+                BehaviorModifiers::default(),
+                ai_config,
+                rng,
+            );
+            grid.insert_entity(entity, &position);
+            entity
+        })
+        .collect()
+}
+
+/// Spawn `count` surface food sources and add them to `grid`
+pub fn spawn_food(
+    commands: &mut Commands,
+    grid: &mut SpatialGrid,
+    count: usize,
+    config: &StressSpawnConfig,
+    rng: &mut impl Rng,
+) -> Vec<Entity> {
+    let (min_nutrition, max_nutrition) = config.food_nutrition;
+    (0..count)
+        .map(|_| {
+            let position = Position {
+                x: rng.gen_range(-config.food_half_width..=config.food_half_width),
+                y: 2.0, // Just above the soil surface
+            };
+            let kind = if rng.gen_bool(config.protein_share.clamp(0.0, 1.0) as f64) {
+                FoodKind::Protein
+            } else {
+                FoodKind::Carbohydrate
+            };
+            let nutrition = rng.gen_range(min_nutrition..=max_nutrition.max(min_nutrition));
+            let entity = spawn_food_source(commands, position.clone(), nutrition, kind);
+            grid.insert_entity(entity, &position);
+            entity
+        })
+        .collect()
+}
+
+/// System bulk-spawning ants (F9) or food (F10) to reproduce performance reports
+///
+/// Workers follow the colony's spawn, caste and lifespan settings. The shared grid is
+/// rebuilt from ants every frame, so bulk food only stays in it until the next rebuild.
+#[allow(clippy::too_many_arguments)]
+pub fn stress_spawn_input_system(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    stress_config: Option<Res<StressSpawnConfig>>,
+    spawn_config: Option<Res<InitialSpawnConfig>>,
+    caste_config: Option<Res<CasteConfig>>,
+    lifespan_model: Option<Res<LifespanModel>>,
+    ai_config: Option<Res<AntAiConfig>>,
+    spatial_grid: Option<ResMut<SpatialGrid>>,
+    simulation_rng: Option<ResMut<SimulationRng>>,
+) {
+    let spawn_ants_pressed =
+        key_bindings.just_pressed(&keyboard_input, InputAction::StressSpawnAnts);
+    let spawn_food_pressed =
+        key_bindings.just_pressed(&keyboard_input, InputAction::StressSpawnFood);
+    if !spawn_ants_pressed && !spawn_food_pressed {
+        return;
+    }
+
+    let stress_config = stress_config
+        .map(|config| config.clone())
+        .unwrap_or_default();
+    let mut scratch_grid = SpatialGrid::default();
+    let mut spatial_grid = spatial_grid;
+    let grid = match spatial_grid.as_deref_mut() {
+        Some(grid) => grid,
+        None => &mut scratch_grid,
+    };
+    let mut rng = spawner_rng(simulation_rng);
+
+    if spawn_ants_pressed {
+        let species = SpeciesConfig {
+            placement: spawn_config
+                .map(|config| config.clone())
+                .unwrap_or_default(),
+            castes: caste_config
+                .map(|config| config.clone())
+                .unwrap_or_default(),
+            lifespan: lifespan_model
+                .map(|model| model.clone())
+                .unwrap_or_default(),
+        };
+        spawn_ants(
+            &mut commands,
+            grid,
+            stress_config.ant_batch,
+            &species,
+            &ai_config.as_deref().cloned().unwrap_or_default(),
+            &mut rng,
+        );
+        info!("Stress test: spawned {} ants", stress_config.ant_batch);
+    }
+    if spawn_food_pressed {
+        spawn_food(
+            &mut commands,
+            grid,
+            stress_config.food_batch,
+            &stress_config,
+            &mut rng,
+        );
+        info!(
+            "Stress test: spawned {} food sources",
+            stress_config.food_batch
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use crate::components::{
+    Ant, ColonyId, InputAction, KeyBindings, SpatialGrid, TerritoryCell, TerritoryMap,
+};
+use crate::systems::rendering::{z_for, RenderLayer};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Colony with the most ants among `colonies`, and its share of them
+///
+/// Ties go to the lowest colony id so ownership does not flicker between rebuilds.
+pub fn dominant_colony(colonies: impl IntoIterator<Item = ColonyId>) -> Option<TerritoryCell> {
+    let mut counts: HashMap<ColonyId, usize> = HashMap::new();
+    let mut total = 0;
+    for colony in colonies {
+        *counts.entry(colony).or_default() += 1;
+        total += 1;
+    }
+    counts
+        .into_iter()
+        .max_by(|(a, a_count), (b, b_count)| a_count.cmp(b_count).then(b.cmp(a)))
+        .map(|(owner, count)| TerritoryCell {
+            owner,
+            share: count as f32 / total as f32,
+        })
+}
+
+/// System attributing each occupied [`SpatialGrid`] cell to the colony with most ants in it
+///
+/// Rebuilt every `update_interval` seconds rather than every frame.
+pub fn territory_system(
+    time: Res<Time>,
+    spatial_grid: Res<SpatialGrid>,
+    ant_query: Query<Option<&ColonyId>, With<Ant>>,
+    mut territory: ResMut<TerritoryMap>,
+) {
+    // Only a rebuild counts as a change, so the overlay redraws when the map does
+    let unchanged = territory.bypass_change_detection();
+    unchanged.update_timer += time.delta_seconds();
+    if unchanged.update_timer < unchanged.update_interval {
+        return;
+    }
+    unchanged.update_timer = 0.0;
+
+    territory.cell_size = spatial_grid.cell_size;
+    territory.cells = spatial_grid
+        .grid
+        .iter()
+        .filter_map(|(cell, entities)| {
+            let colonies = entities
+                .iter()
+                .filter_map(|entity| ant_query.get(*entity).ok())
+                .map(|colony| colony.copied().unwrap_or_default());
+            dominant_colony(colonies).map(|owner| (*cell, owner))
+        })
+        .collect();
+}
+
+/// System toggling the territory overlay with F8
+pub fn territory_overlay_toggle_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    mut territory: ResMut<TerritoryMap>,
+) {
+    if key_bindings.just_pressed(&keyboard_input, InputAction::ToggleTerritoryOverlay) {
+        territory.overlay_enabled = !territory.overlay_enabled;
+        info!(
+            "Territory overlay {}",
+            if territory.overlay_enabled {
+                "shown"
+            } else {
+                "hidden"
+            }
+        );
+    }
+}
+
+/// Filled square tinting one held cell on the territory overlay
+#[derive(Component)]
+pub struct TerritoryTile;
+
+/// System filling each held cell with its owner's color, fainter where it is contested
+///
+/// Tiles are rebuilt only when the map is rebuilt or the overlay is toggled.
+pub fn territory_overlay_system(
+    mut commands: Commands,
+    territory: Res<TerritoryMap>,
+    tile_query: Query<Entity, With<TerritoryTile>>,
+) {
+    if !territory.is_changed() {
+        return;
+    }
+    for tile in tile_query.iter() {
+        commands.entity(tile).despawn();
+    }
+    if !territory.overlay_enabled {
+        return;
+    }
+
+    let size = Vec2::splat(territory.cell_size);
+    for (&(x, y), cell) in &territory.cells {
+        let center = (Vec2::new(x as f32, y as f32) + 0.5) * territory.cell_size;
+        let color = cell
+            .owner
+            .color()
+            .with_alpha(cell.share * territory.max_alpha);
+        commands.spawn((
+            TerritoryTile,
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(size),
+                    ..default()
+                },
+                transform: Transform::from_translation(center.extend(z_for(RenderLayer::Overlay))),
+                ..default()
+            },
+        ));
+    }
+}
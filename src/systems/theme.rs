@@ -0,0 +1,159 @@
+use crate::components::{ColorPalette, UITheme};
+use bevy::prelude::*;
+use directories::ProjectDirs;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Name of the theme file the settings panel loads
+pub const THEME_FILE_NAME: &str = "theme.json";
+
+/// Errors raised while reading a shared theme
+#[derive(Debug)]
+pub enum ThemeError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    /// Theme parsed, but a size in it is unusable
+    Invalid(String),
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeError::Io(e) => write!(f, "file error: {}", e),
+            ThemeError::Json(e) => write!(f, "malformed theme: {}", e),
+            ThemeError::Invalid(reason) => write!(f, "invalid theme: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {}
+
+/// Theme as pretty-printed JSON, ready to be shared
+pub fn export_theme(theme: &UITheme) -> String {
+    serde_json::to_string_pretty(theme).expect("a theme always serializes")
+}
+
+/// Theme from JSON written by [`export_theme`]
+///
+/// Font sizes must be positive, and spacing and border sizes must not be negative.
+pub fn import_theme(s: &str) -> Result<UITheme, ThemeError> {
+    let theme: UITheme = serde_json::from_str(s).map_err(ThemeError::Json)?;
+
+    let typography = &theme.typography;
+    let font_sizes = [
+        typography.heading_medium,
+        typography.heading_small,
+        typography.body_large,
+        typography.body_medium,
+        typography.body_small,
+    ];
+    if font_sizes
+        .iter()
+        .any(|size| !size.is_finite() || *size <= 0.0)
+    {
+        return Err(ThemeError::Invalid("font sizes must be positive".into()));
+    }
+    let spacing = &theme.spacing;
+    let borders = &theme.borders;
+    let lengths = [
+        spacing.xs,
+        spacing.sm,
+        spacing.md,
+        spacing.lg,
+        borders.width_thin,
+        borders.width_medium,
+        borders.radius_small,
+        borders.radius_medium,
+    ];
+    if lengths
+        .iter()
+        .any(|length| !length.is_finite() || *length < 0.0)
+    {
+        return Err(ThemeError::Invalid(
+            "spacing and borders must not be negative".into(),
+        ));
+    }
+    Ok(theme)
+}
+
+/// Read and import the theme file at `path`
+pub fn load_theme_file(path: &Path) -> Result<UITheme, ThemeError> {
+    let contents = fs::read_to_string(path).map_err(ThemeError::Io)?;
+    import_theme(&contents)
+}
+
+/// Where the settings panel looks for a theme file, in the user's config directory
+pub fn theme_file_path() -> Option<PathBuf> {
+    ProjectDirs::from("com", "traponion", "ant-nest-simulator")
+        .map(|dirs| dirs.config_dir().join(THEME_FILE_NAME))
+}
+
+/// Every palette color, in field order, so two palettes can be matched role by role
+fn palette_colors(palette: &ColorPalette) -> [Color; 13] {
+    [
+        palette.surface_primary,
+        palette.surface_secondary,
+        palette.surface_elevated,
+        palette.text_primary,
+        palette.text_secondary,
+        palette.text_muted,
+        palette.border_primary,
+        palette.border_secondary,
+        palette.border_focus,
+        palette.action_primary,
+        palette.action_success,
+        palette.action_danger,
+        palette.accent_green,
+    ]
+}
+
+/// The new palette's color for the role `color` played in the old palette, if any
+fn recolor(color: Color, old: &[Color; 13], new: &[Color; 13]) -> Option<Color> {
+    old.iter()
+        .position(|old_color| *old_color == color)
+        .map(|role| new[role])
+        .filter(|new_color| *new_color != color)
+}
+
+/// System recoloring UI already on screen when the [`UITheme`] palette changes
+///
+/// Panels bake their colors in when they are built, so every background, border and
+/// text color matching a role of the previous palette is swapped for that role's color
+/// in the new one. Sizes apply to panels built after the change.
+pub fn theme_recolor_system(
+    theme: Res<UITheme>,
+    mut previous: Local<Option<ColorPalette>>,
+    mut background_query: Query<&mut BackgroundColor>,
+    mut border_query: Query<&mut BorderColor>,
+    mut text_query: Query<&mut Text>,
+) {
+    let Some(old_palette) = previous.as_ref() else {
+        *previous = Some(theme.colors.clone());
+        return;
+    };
+    if !theme.is_changed() || *old_palette == theme.colors {
+        return;
+    }
+
+    let old = palette_colors(old_palette);
+    let new = palette_colors(&theme.colors);
+    for mut background in background_query.iter_mut() {
+        if let Some(color) = recolor(background.0, &old, &new) {
+            background.0 = color;
+        }
+    }
+    for mut border in border_query.iter_mut() {
+        if let Some(color) = recolor(border.0, &old, &new) {
+            border.0 = color;
+        }
+    }
+    for mut text in text_query.iter_mut() {
+        for section in text.sections.iter_mut() {
+            if let Some(color) = recolor(section.style.color, &old, &new) {
+                section.style.color = color;
+            }
+        }
+    }
+    *previous = Some(theme.colors.clone());
+}
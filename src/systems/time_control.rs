@@ -0,0 +1,171 @@
+use crate::components::{
+    AutoThrottleConfig, InputAction, KeyBindings, PerformanceMetrics, TimeControl, UI_MAX_SPEED,
+};
+use crate::localization::Localization;
+use crate::schedule::SimStep;
+use bevy::prelude::*;
+use std::time::{Duration, Instant};
+
+/// Label for the current speed: the requested speed normally, the achieved one in turbo
+///
+/// Words come from the active [`Localization`].
+pub fn speed_label(time_control: &TimeControl, localization: &Localization) -> String {
+    if time_control.is_paused {
+        localization.t("time.paused").to_string()
+    } else if time_control.turbo {
+        format!(
+            "{} {:.0}x",
+            localization.t("time.turbo"),
+            time_control.achieved_multiplier
+        )
+    } else if time_control.is_throttled() {
+        format!(
+            "{} {:.0}x ({} {:.0}x)",
+            localization.t("time.speed"),
+            time_control.speed_multiplier,
+            localization.t("time.throttled"),
+            time_control.effective_speed()
+        )
+    } else {
+        format!(
+            "{} {:.0}x",
+            localization.t("time.speed"),
+            time_control.speed_multiplier
+        )
+    }
+}
+
+/// System handling the time shortcuts: Space pauses, 1-9 set the speed, 0 sets the
+/// maximum UI speed and T toggles turbo (keys as bound in [`KeyBindings`])
+pub fn time_control_input_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    mut time_control: ResMut<TimeControl>,
+) {
+    if key_bindings.just_pressed(&keyboard_input, InputAction::TogglePause) {
+        time_control.is_paused = !time_control.is_paused;
+    }
+    for speed in 1..=9 {
+        if key_bindings.just_pressed(&keyboard_input, InputAction::SetSpeed(speed)) {
+            time_control.set_speed(speed as f32);
+        }
+    }
+    if key_bindings.just_pressed(&keyboard_input, InputAction::MaxSpeed) {
+        time_control.set_speed(UI_MAX_SPEED);
+    }
+    if key_bindings.just_pressed(&keyboard_input, InputAction::ToggleTurbo) {
+        time_control.turbo = !time_control.turbo;
+        info!(
+            "Turbo mode {}",
+            if time_control.turbo { "on" } else { "off" }
+        );
+    }
+}
+
+/// System applying the requested speed and pause state to virtual time
+///
+/// Only acts when [`TimeControl`] changes, so embedders driving virtual time themselves
+/// are left alone.
+pub fn apply_time_control_system(
+    time_control: Res<TimeControl>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    if !time_control.is_changed() {
+        return;
+    }
+
+    if time_control.is_paused {
+        virtual_time.pause();
+    } else {
+        virtual_time.unpause();
+    }
+    virtual_time.set_relative_speed(time_control.effective_speed());
+}
+
+/// System lowering the simulation speed while the frame rate is below target
+///
+/// Every adjustment interval the throttle shrinks while FPS is under `target_fps` and
+/// grows back once it is above `recovery_fps`, so speed is restored when frames recover.
+/// Disabling the throttle lifts it at once.
+pub fn auto_throttle_system(
+    time: Res<Time<Real>>,
+    config: Option<ResMut<AutoThrottleConfig>>,
+    performance_metrics: Res<PerformanceMetrics>,
+    mut time_control: ResMut<TimeControl>,
+) {
+    let Some(mut config) = config else {
+        return;
+    };
+    if !config.enabled {
+        if time_control.is_throttled() {
+            time_control.throttle = 1.0;
+        }
+        return;
+    }
+    let adjust = config
+        .bypass_change_detection()
+        .adjust_timer
+        .tick(time.delta())
+        .just_finished();
+    if !adjust || performance_metrics.fps <= 0.0 || time_control.is_paused {
+        return;
+    }
+
+    let fps = performance_metrics.fps;
+    let throttle = if fps < config.target_fps {
+        (time_control.throttle * config.reduce_factor).max(config.min_throttle)
+    } else if fps >= config.recovery_fps {
+        (time_control.throttle * config.restore_factor).min(1.0)
+    } else {
+        time_control.throttle
+    };
+    if throttle != time_control.throttle {
+        time_control.throttle = throttle;
+        if throttle < 1.0 {
+            debug!(
+                "Auto-throttle at {:.2} of requested speed ({:.0} FPS)",
+                throttle, fps
+            );
+        }
+    }
+}
+
+/// Exclusive system running extra fixed-size simulation steps while turbo is on
+///
+/// Runs after the frame's regular update, repeating the [`SimStep`] schedule with a
+/// `turbo_step` delta until the step count or the wall-clock frame budget runs out.
+/// Input and presentation stay in `Update`, so they still run once per frame.
+/// The auto-throttle scales the step count down while frames are slow.
+/// Every frame it records the achieved speed in [`TimeControl::achieved_multiplier`].
+pub fn turbo_substep_system(world: &mut World) {
+    let Some(time_control) = world.get_resource::<TimeControl>().cloned() else {
+        return;
+    };
+    let real_delta = world.resource::<Time<Real>>().delta_seconds();
+    let mut simulated = world.resource::<Time<Virtual>>().delta_seconds();
+
+    if time_control.turbo && !time_control.is_paused && time_control.turbo_step > 0.0 {
+        let step = Duration::from_secs_f32(time_control.turbo_step);
+        let started = Instant::now();
+        let mut steps = 0;
+        while steps < time_control.effective_turbo_steps()
+            && started.elapsed().as_secs_f32() < time_control.turbo_frame_budget
+        {
+            let mut virtual_time = world.resource_mut::<Time<Virtual>>();
+            virtual_time.advance_by(step);
+            let current = virtual_time.as_generic();
+            *world.resource_mut::<Time>() = current;
+            world.run_schedule(SimStep);
+            steps += 1;
+        }
+        simulated += steps as f32 * time_control.turbo_step;
+    }
+
+    if real_delta > 0.0 {
+        // Reporting the speed is not a request, so it must not re-trigger the apply system
+        world
+            .resource_mut::<TimeControl>()
+            .bypass_change_detection()
+            .achieved_multiplier = simulated / real_delta;
+    }
+}
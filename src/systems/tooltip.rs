@@ -1,5 +1,42 @@
-use crate::components::{Tooltip, TooltipDisplay, TooltipPosition, TooltipTrigger, UITheme};
+use crate::components::{
+    Position, Tooltip, TooltipDisplay, TooltipPosition, TooltipTrigger, UITheme,
+};
 use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+/// Screen-space distance (pixels) within which the cursor hovers a world entity
+const WORLD_HOVER_RADIUS: f32 = 8.0;
+
+/// Project a world position to window coordinates (origin top-left, y down)
+///
+/// Accounts for the 2D camera's pan (`camera_position`) and zoom (`camera_scale`,
+/// the orthographic projection scale).
+pub fn world_to_screen(
+    world_position: Vec2,
+    camera_position: Vec2,
+    camera_scale: f32,
+    viewport_size: Vec2,
+) -> Vec2 {
+    let relative = (world_position - camera_position) / camera_scale;
+    Vec2::new(
+        viewport_size.x / 2.0 + relative.x,
+        viewport_size.y / 2.0 - relative.y,
+    )
+}
+
+/// Map window coordinates back to a world position, the inverse of [`world_to_screen`]
+pub fn screen_to_world(
+    screen_position: Vec2,
+    camera_position: Vec2,
+    camera_scale: f32,
+    viewport_size: Vec2,
+) -> Vec2 {
+    let relative = Vec2::new(
+        screen_position.x - viewport_size.x / 2.0,
+        viewport_size.y / 2.0 - screen_position.y,
+    );
+    camera_position + relative * camera_scale
+}
 
 /// System to manage tooltip triggers and display timing
 pub fn tooltip_trigger_system(
@@ -39,11 +76,54 @@ pub fn tooltip_trigger_system(
     }
 }
 
+/// System to track cursor hover over world entities (ants, food, chambers) with tooltips
+pub fn world_tooltip_hover_system(
+    time: Res<Time>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Transform, &OrthographicProjection), With<Camera>>,
+    mut trigger_query: Query<(&Position, &mut TooltipTrigger), With<Tooltip>>,
+) {
+    let Ok(window) = window_query.get_single() else {
+        return;
+    };
+    let Ok((camera_transform, projection)) = camera_query.get_single() else {
+        return;
+    };
+    let cursor = window.cursor_position();
+    let viewport_size = Vec2::new(window.width(), window.height());
+
+    for (position, mut trigger) in trigger_query.iter_mut() {
+        let screen_position = world_to_screen(
+            Vec2::new(position.x, position.y),
+            camera_transform.translation.truncate(),
+            projection.scale,
+            viewport_size,
+        );
+        let hovered = cursor
+            .map(|cursor| cursor.distance(screen_position) <= WORLD_HOVER_RADIUS)
+            .unwrap_or(false);
+
+        if hovered {
+            if !trigger.is_hovered {
+                trigger.is_hovered = true;
+                trigger.hover_timer = 0.0;
+            }
+            trigger.hover_timer += time.delta_seconds();
+        } else if trigger.is_hovered {
+            trigger.is_hovered = false;
+            trigger.hover_timer = 0.0;
+        }
+    }
+}
+
 /// System to display and position tooltips
 pub fn tooltip_display_system(
     mut commands: Commands,
     theme: Res<UITheme>,
-    trigger_query: Query<(&TooltipTrigger, &Tooltip, &GlobalTransform), With<Button>>,
+    ui_trigger_query: Query<(&TooltipTrigger, &Tooltip, &GlobalTransform), With<Button>>,
+    world_trigger_query: Query<(&TooltipTrigger, &Tooltip, &Position), Without<Node>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Transform, &OrthographicProjection), With<Camera>>,
     existing_tooltips: Query<Entity, With<TooltipDisplay>>,
 ) {
     // First, remove existing tooltips
@@ -51,21 +131,41 @@ pub fn tooltip_display_system(
         commands.entity(entity).despawn_recursive();
     }
 
-    // Then, create new tooltips for elements that should show them
-    for (trigger, tooltip, transform) in &trigger_query {
+    // Then, create new tooltips for UI elements that should show them
+    for (trigger, tooltip, transform) in &ui_trigger_query {
+        if trigger.is_hovered && trigger.hover_timer >= trigger.show_delay {
+            spawn_tooltip(
+                &mut commands,
+                &theme,
+                tooltip,
+                transform.translation().truncate(),
+            );
+        }
+    }
+
+    // World entities are anchored at their projected screen position
+    let (Ok(window), Ok((camera_transform, projection))) =
+        (window_query.get_single(), camera_query.get_single())
+    else {
+        return;
+    };
+    let viewport_size = Vec2::new(window.width(), window.height());
+
+    for (trigger, tooltip, position) in &world_trigger_query {
         if trigger.is_hovered && trigger.hover_timer >= trigger.show_delay {
-            spawn_tooltip(&mut commands, &theme, tooltip, transform);
+            let anchor = world_to_screen(
+                Vec2::new(position.x, position.y),
+                camera_transform.translation.truncate(),
+                projection.scale,
+                viewport_size,
+            );
+            spawn_tooltip(&mut commands, &theme, tooltip, anchor);
         }
     }
 }
 
-/// Helper function to spawn a tooltip entity
-fn spawn_tooltip(
-    commands: &mut Commands,
-    theme: &UITheme,
-    tooltip: &Tooltip,
-    target_transform: &GlobalTransform,
-) {
+/// Helper function to spawn a tooltip entity next to a screen-space anchor point
+fn spawn_tooltip(commands: &mut Commands, theme: &UITheme, tooltip: &Tooltip, anchor: Vec2) {
     let tooltip_text = if let Some(shortcut) = &tooltip.shortcut {
         format!("{}\n\nKeyboard shortcut: {}", tooltip.text, shortcut)
     } else {
@@ -73,21 +173,20 @@ fn spawn_tooltip(
     };
 
     // Calculate position based on target transform and tooltip position preference
+    // UI coordinates grow downward, so "below" is a positive top offset
     let (x_offset, y_offset) = match tooltip.position {
-        TooltipPosition::Below => (0.0, -60.0),
-        TooltipPosition::Above => (0.0, 60.0),
+        TooltipPosition::Below => (0.0, 60.0),
+        TooltipPosition::Above => (0.0, -60.0),
         TooltipPosition::Left => (-200.0, 0.0),
         TooltipPosition::Right => (200.0, 0.0),
     };
 
-    let target_translation = target_transform.translation();
-
     commands
         .spawn(NodeBundle {
             style: Style {
                 position_type: PositionType::Absolute,
-                left: Val::Px(target_translation.x + x_offset),
-                top: Val::Px(target_translation.y + y_offset),
+                left: Val::Px(anchor.x + x_offset),
+                top: Val::Px(anchor.y + y_offset),
                 max_width: Val::Px(280.0),
                 padding: UiRect::all(Val::Px(theme.spacing.md)),
                 border: UiRect::all(Val::Px(theme.borders.width_thin)),
@@ -0,0 +1,83 @@
+use crate::components::{Ant, Dying, Lifecycle, Position, SpatialGrid, TrophallaxisConfig};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// System passing energy from well-fed ants to starving nestmates they touch
+///
+/// A donor above `donor_min_energy_fraction` of its max energy feeds the hungriest
+/// neighbour below `recipient_max_energy_fraction` within `radius`, handing over
+/// `share_fraction` of the energy gap. Energy is only moved, never created, and both
+/// ants wait out the cooldown before sharing again. Neighbours come from the shared
+/// [`SpatialGrid`] built last frame.
+pub fn trophallaxis_system(
+    time: Res<Time>,
+    config: Option<Res<TrophallaxisConfig>>,
+    spatial_grid: Res<SpatialGrid>,
+    mut cooldowns: Local<HashMap<Entity, f32>>,
+    mut ant_query: Query<(Entity, &Position, &mut Lifecycle), (With<Ant>, Without<Dying>)>,
+) {
+    let config = config.map(|c| c.clone()).unwrap_or_default();
+    let delta_time = time.delta_seconds();
+
+    cooldowns.retain(|ant, remaining| {
+        *remaining -= delta_time;
+        *remaining > 0.0 && ant_query.contains(*ant)
+    });
+
+    // Pair donors with recipients first, then move the energy
+    let mut exchanges = Vec::new();
+    let mut busy = Vec::new();
+    for (donor, position, lifecycle) in ant_query.iter() {
+        if cooldowns.contains_key(&donor)
+            || busy.contains(&donor)
+            || lifecycle.energy < lifecycle.max_energy * config.donor_min_energy_fraction
+        {
+            continue;
+        }
+
+        let recipient = spatial_grid
+            .get_nearby_entities(position, config.radius)
+            .into_iter()
+            .filter(|other| {
+                *other != donor && !cooldowns.contains_key(other) && !busy.contains(other)
+            })
+            .filter_map(|other| {
+                let (_, other_position, other_lifecycle) = ant_query.get(other).ok()?;
+                let dx = other_position.x - position.x;
+                let dy = other_position.y - position.y;
+                let hungry = other_lifecycle.energy
+                    < other_lifecycle.max_energy * config.recipient_max_energy_fraction;
+                (hungry && dx * dx + dy * dy <= config.radius * config.radius)
+                    .then_some((other, other_lifecycle))
+            })
+            .min_by(|a, b| a.1.energy.total_cmp(&b.1.energy));
+        let Some((recipient, recipient_lifecycle)) = recipient else {
+            continue;
+        };
+
+        let headroom = recipient_lifecycle.max_energy - recipient_lifecycle.energy;
+        let amount = ((lifecycle.energy - recipient_lifecycle.energy) * config.share_fraction)
+            .min(headroom)
+            .max(0.0);
+        if amount > 0.0 {
+            exchanges.push((donor, recipient, amount));
+            busy.push(donor);
+            busy.push(recipient);
+        }
+    }
+
+    for (donor, recipient, amount) in exchanges {
+        if let Ok((_, _, mut lifecycle)) = ant_query.get_mut(donor) {
+            lifecycle.energy -= amount;
+        }
+        if let Ok((_, _, mut lifecycle)) = ant_query.get_mut(recipient) {
+            lifecycle.energy += amount;
+        }
+        cooldowns.insert(donor, config.cooldown);
+        cooldowns.insert(recipient, config.cooldown);
+        debug!(
+            "Ant {:?} shared {:.1} energy with {:?}",
+            donor, amount, recipient
+        );
+    }
+}
@@ -0,0 +1,298 @@
+use crate::components::{
+    InputAction, KeyBindings, NestPlanningConfig, NestSpace, Position, Soil, Tunnel,
+    TunnelDrawTool, TunnelNode, TunnelNodeType,
+};
+use crate::systems::chambers::{spawn_chamber, spawn_chamber_outline};
+use crate::systems::rendering::{z_for, RenderLayer};
+use crate::systems::tooltip::screen_to_world;
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+use bevy::window::PrimaryWindow;
+
+/// A step of a tunnel sketch, sent by the mouse input or replayed from a recording
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub enum TunnelDrawCommand {
+    Start(Vec2),        // Button pressed: begin a tunnel here
+    DragTo(Vec2),       // Cursor dragged: extend the tunnel towards this point
+    End,                // Button released: finish the tunnel
+    PlaceChamber(Vec2), // Double-click: dig a chamber here and link it to the tunnels
+}
+
+/// System for the tunnel drawing tool's keys and mouse (B toggles, Tab picks the chamber type)
+///
+/// Clicks over UI elements are ignored so panels stay usable while drawing.
+pub fn tunnel_draw_input_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    time: Res<Time<Real>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Transform, &OrthographicProjection), With<Camera>>,
+    interaction_query: Query<&Interaction>,
+    mut tool: ResMut<TunnelDrawTool>,
+    mut draw_commands: EventWriter<TunnelDrawCommand>,
+) {
+    if key_bindings.just_pressed(&keyboard_input, InputAction::ToggleTunnelTool) {
+        tool.enabled = !tool.enabled;
+        if tool.enabled {
+            info!("Tunnel drawing on ({:?} chambers)", tool.chamber_type);
+        } else {
+            info!("Tunnel drawing off");
+            draw_commands.send(TunnelDrawCommand::End);
+        }
+    }
+    if !tool.enabled {
+        return;
+    }
+    if key_bindings.just_pressed(&keyboard_input, InputAction::CycleChamberType) {
+        tool.chamber_type = tool.chamber_type.next_drawable();
+        info!("Tunnel drawing places {:?} chambers", tool.chamber_type);
+    }
+
+    if mouse_input.just_released(MouseButton::Left) {
+        draw_commands.send(TunnelDrawCommand::End);
+        return;
+    }
+    let over_ui = interaction_query
+        .iter()
+        .any(|interaction| *interaction != Interaction::None);
+    if over_ui || !mouse_input.pressed(MouseButton::Left) {
+        return;
+    }
+
+    let Ok(window) = window_query.get_single() else {
+        return;
+    };
+    let Ok((camera_transform, projection)) = camera_query.get_single() else {
+        return;
+    };
+    let Some(cursor) = window.cursor_position() else {
+        return;
+    };
+    let point = screen_to_world(
+        cursor,
+        camera_transform.translation.truncate(),
+        projection.scale,
+        Vec2::new(window.width(), window.height()),
+    );
+
+    if mouse_input.just_pressed(MouseButton::Left) {
+        let now = time.elapsed_seconds();
+        if now - tool.last_click_time <= tool.double_click_window {
+            draw_commands.send(TunnelDrawCommand::PlaceChamber(point));
+            tool.last_click_time = f32::NEG_INFINITY;
+        } else {
+            draw_commands.send(TunnelDrawCommand::Start(point));
+            tool.last_click_time = now;
+        }
+    } else {
+        draw_commands.send(TunnelDrawCommand::DragTo(point));
+    }
+}
+
+/// System laying down tunnel nodes, segments and chambers from draw commands
+///
+/// Nodes are spaced evenly along the drag and linked both ways, soil under them is
+/// dug out, and a drag starting on an existing node continues from it.
+pub fn tunnel_draw_system(
+    mut commands: Commands,
+    mut tool: ResMut<TunnelDrawTool>,
+    planning_config: Option<Res<NestPlanningConfig>>,
+    mut nest_space: Option<ResMut<NestSpace>>,
+    mut draw_commands: EventReader<TunnelDrawCommand>,
+    mut node_query: Query<(Entity, &Position, &mut TunnelNode)>,
+    soil_query: Query<(Entity, &Position), With<Soil>>,
+) {
+    let planning_config = planning_config.map(|c| c.clone()).unwrap_or_default();
+    let segment_width = tool.tunnel_width * 0.5; // Drawn narrower than the dug-out soil
+    let mut sketch = TunnelSketch::default();
+
+    for draw_command in draw_commands.read() {
+        match *draw_command {
+            TunnelDrawCommand::Start(point) => {
+                let snap_distance = tool.node_spacing * 0.5;
+                let node = sketch
+                    .nearest_node(point, snap_distance, &node_query)
+                    .map(|(node, _)| node)
+                    .unwrap_or_else(|| sketch.add_node(&mut commands, point));
+                tool.last_node = Some(node);
+                tool.last_point = Some(point);
+            }
+            TunnelDrawCommand::DragTo(point) => {
+                let (Some(mut from_node), Some(mut from_point)) = (tool.last_node, tool.last_point)
+                else {
+                    continue;
+                };
+                while from_point.distance(point) >= tool.node_spacing {
+                    let next_point =
+                        from_point + (point - from_point).normalize() * tool.node_spacing;
+                    let next_node = sketch.add_node(&mut commands, next_point);
+                    sketch.link(
+                        &mut commands,
+                        &mut node_query,
+                        from_node,
+                        next_node,
+                        segment_width,
+                    );
+                    from_node = next_node;
+                    from_point = next_point;
+                }
+                tool.last_node = Some(from_node);
+                tool.last_point = Some(from_point);
+            }
+            TunnelDrawCommand::End => {
+                tool.last_node = None;
+                tool.last_point = None;
+            }
+            TunnelDrawCommand::PlaceChamber(point) => {
+                let radius = planning_config.chamber_radius;
+                let chamber_type = tool.chamber_type;
+                let chamber = spawn_chamber(
+                    &mut commands,
+                    Position {
+                        x: point.x,
+                        y: point.y,
+                    },
+                    chamber_type,
+                    radius,
+                    planning_config.chamber_capacity,
+                );
+                spawn_chamber_outline(&mut commands, chamber, chamber_type, radius);
+
+                let reach = radius + tool.node_spacing;
+                let nearest = sketch.nearest_node(point, reach, &node_query);
+                sketch.nodes.push((chamber, point, TunnelNode::default()));
+                sketch.dig(point, radius);
+                if let Some((node, _)) = nearest {
+                    sketch.link(&mut commands, &mut node_query, chamber, node, segment_width);
+                    sketch.mark_entrance(&mut node_query, node);
+                }
+                tool.last_node = None;
+                tool.last_point = None;
+                info!(
+                    "Drew a {:?} chamber at ({:.1}, {:.1})",
+                    chamber_type, point.x, point.y
+                );
+            }
+        }
+    }
+
+    // Dig out the soil under the new tunnels
+    let half_width = tool.tunnel_width * 0.5;
+    let mut dug = HashSet::new();
+    for (soil_entity, soil_position) in soil_query.iter() {
+        let soil_point = Vec2::new(soil_position.x, soil_position.y);
+        let under_tunnel = sketch
+            .dig_sites
+            .iter()
+            .any(|(point, radius)| soil_point.distance(*point) <= radius.max(half_width));
+        if under_tunnel && dug.insert(soil_entity) {
+            commands.entity(soil_entity).despawn();
+        }
+    }
+    if let Some(nest_space) = nest_space.as_mut() {
+        if !dug.is_empty() {
+            nest_space.excavated_cells += dug.len();
+        }
+    }
+
+    for (entity, point, node) in sketch.nodes {
+        commands.entity(entity).insert((
+            Position {
+                x: point.x,
+                y: point.y,
+            },
+            node,
+        ));
+    }
+}
+
+/// Nodes and dig sites created by the current batch of draw commands
+///
+/// New nodes only exist as commands until the end of the frame, so their links are
+/// collected here and inserted together.
+#[derive(Default)]
+struct TunnelSketch {
+    nodes: Vec<(Entity, Vec2, TunnelNode)>,
+    dig_sites: Vec<(Vec2, f32)>,
+}
+
+impl TunnelSketch {
+    fn add_node(&mut self, commands: &mut Commands, point: Vec2) -> Entity {
+        let node = commands.spawn_empty().id();
+        self.nodes.push((node, point, TunnelNode::default()));
+        self.dig(point, 0.0);
+        node
+    }
+
+    fn dig(&mut self, point: Vec2, radius: f32) {
+        self.dig_sites.push((point, radius));
+    }
+
+    /// Closest node within `max_distance`, new or already in the world
+    fn nearest_node(
+        &self,
+        point: Vec2,
+        max_distance: f32,
+        node_query: &Query<(Entity, &Position, &mut TunnelNode)>,
+    ) -> Option<(Entity, Vec2)> {
+        let existing = node_query
+            .iter()
+            .map(|(entity, position, _)| (entity, Vec2::new(position.x, position.y)));
+        let pending = self
+            .nodes
+            .iter()
+            .map(|(entity, point, _)| (*entity, *point));
+        existing
+            .chain(pending)
+            .filter(|(_, node_point)| node_point.distance(point) <= max_distance)
+            .min_by(|a, b| a.1.distance(point).total_cmp(&b.1.distance(point)))
+    }
+
+    /// Mark the node where a tunnel opens into a chamber
+    fn mark_entrance(
+        &mut self,
+        node_query: &mut Query<(Entity, &Position, &mut TunnelNode)>,
+        node: Entity,
+    ) {
+        if let Some((_, _, pending)) = self.nodes.iter_mut().find(|(e, _, _)| *e == node) {
+            pending.node_type = TunnelNodeType::ChamberEntrance;
+        } else if let Ok((_, _, mut existing)) = node_query.get_mut(node) {
+            existing.node_type = TunnelNodeType::ChamberEntrance;
+        }
+    }
+
+    /// Link two nodes both ways and lay the tunnel segment between them
+    fn link(
+        &mut self,
+        commands: &mut Commands,
+        node_query: &mut Query<(Entity, &Position, &mut TunnelNode)>,
+        a: Entity,
+        b: Entity,
+        width: f32,
+    ) {
+        for (node, other) in [(a, b), (b, a)] {
+            if let Some((_, _, pending)) = self.nodes.iter_mut().find(|(e, _, _)| *e == node) {
+                pending.link(other);
+            } else if let Ok((_, _, mut existing)) = node_query.get_mut(node) {
+                existing.link(other);
+            }
+        }
+        spawn_tunnel_segment(commands, a, b, width);
+    }
+}
+
+/// Spawn a tunnel segment between two nodes; `nest_render_system` stretches it between them
+fn spawn_tunnel_segment(commands: &mut Commands, from: Entity, to: Entity, width: f32) {
+    commands.spawn((
+        Tunnel { from, to, width },
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgba(0.25, 0.15, 0.08, 0.6), // Dark hollow in the soil
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, 0.0, z_for(RenderLayer::Tunnel)),
+            ..default()
+        },
+    ));
+}
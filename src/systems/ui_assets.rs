@@ -0,0 +1,75 @@
+use crate::components::UiAssets;
+use bevy::asset::LoadState;
+use bevy::prelude::*;
+
+/// System loading the UI font and marking [`UiAssets`] ready
+///
+/// Tries the custom font, then the fallback path, then settles on the built-in font.
+/// Without an asset server (headless apps) the built-in font is used straight away.
+pub fn ui_assets_loading_system(
+    asset_server: Option<Res<AssetServer>>,
+    mut ui_assets: ResMut<UiAssets>,
+    mut tried_fallback: Local<bool>,
+) {
+    if ui_assets.ready {
+        return;
+    }
+    let Some(asset_server) = asset_server else {
+        ui_assets.font = Handle::default();
+        ui_assets.ready = true;
+        return;
+    };
+
+    if ui_assets.font == Handle::default() {
+        match ui_assets.font_path.clone() {
+            Some(path) => ui_assets.font = asset_server.load(path),
+            None => ui_assets.ready = true,
+        }
+        return;
+    }
+
+    match asset_server.load_state(&ui_assets.font) {
+        LoadState::Loaded => ui_assets.ready = true,
+        LoadState::Failed(error) => {
+            let fallback = ui_assets.fallback_font_path.clone();
+            match fallback.filter(|_| !*tried_fallback) {
+                Some(path) => {
+                    warn!("UI font failed to load ({}), trying {}", error, path);
+                    *tried_fallback = true;
+                    ui_assets.font = asset_server.load(path);
+                }
+                None => {
+                    warn!("UI font failed to load ({}), using built-in font", error);
+                    ui_assets.font = Handle::default();
+                    ui_assets.ready = true;
+                }
+            }
+        }
+        LoadState::NotLoaded | LoadState::Loading => {}
+    }
+}
+
+/// Run condition for the deferred panel setup: assets are ready and panels not yet built
+pub fn ui_panels_pending(ui_assets: Option<Res<UiAssets>>) -> bool {
+    ui_assets.is_some_and(|ui_assets| ui_assets.ready && !ui_assets.panels_built)
+}
+
+/// System recording that the deferred panel setup has run, so it runs only once
+pub fn mark_ui_panels_built_system(mut ui_assets: ResMut<UiAssets>) {
+    ui_assets.panels_built = true;
+}
+
+/// System giving newly spawned UI text the loaded custom font
+pub fn apply_ui_font_system(
+    ui_assets: Res<UiAssets>,
+    mut text_query: Query<&mut Text, Added<Text>>,
+) {
+    if !ui_assets.ready || ui_assets.font == Handle::default() {
+        return;
+    }
+    for mut text in text_query.iter_mut() {
+        for section in text.sections.iter_mut() {
+            section.style.font = ui_assets.font.clone();
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use crate::components::{
+    DraggablePanel, PanelAnchor, PanelDrag, PanelTitleBar, ResponsivePanel, UserSettings,
+};
+use crate::systems::settings_ui::{write_settings_file, SettingsFile};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+/// Compute a panel's absolute left offset and width for the given window width
+///
+/// Corner panels are capped at half the window so left and right columns never
+/// overlap; every panel is kept fully inside the window margins.
+pub fn compute_panel_layout(panel: &ResponsivePanel, window_width: f32) -> (f32, f32) {
+    let max_width = match panel.anchor {
+        PanelAnchor::TopCenter | PanelAnchor::BottomCenter => window_width - 2.0 * panel.margin,
+        _ => (window_width - 3.0 * panel.margin) / 2.0,
+    };
+    let width = panel.width.min(max_width).max(0.0);
+
+    let left = match panel.anchor {
+        PanelAnchor::TopLeft | PanelAnchor::BottomLeft => panel.margin,
+        PanelAnchor::TopRight | PanelAnchor::BottomRight => window_width - panel.margin - width,
+        PanelAnchor::TopCenter | PanelAnchor::BottomCenter => (window_width - width) / 2.0,
+    };
+
+    (left.max(0.0), width)
+}
+
+/// Reposition anchored panels whenever the window, a panel's size or its visibility changes
+///
+/// Visible panels along the same window edge are stacked: each is pushed inward past
+/// the panels laid out before it that it overlaps horizontally, corner panels first and
+/// centered ones after them. Panels the user has dragged keep their stored place and
+/// only get their width.
+pub fn responsive_layout_system(
+    window_query: Query<Ref<Window>, With<PrimaryWindow>>,
+    user_settings: Option<Res<UserSettings>>,
+    mut panel_query: Query<(
+        Entity,
+        Ref<ResponsivePanel>,
+        &mut Style,
+        Ref<Node>,
+        Option<Ref<Visibility>>,
+        Option<&DraggablePanel>,
+    )>,
+) {
+    let Ok(window) = window_query.get_single() else {
+        return;
+    };
+
+    let mut relayout = window.is_changed();
+    let mut order = Vec::new();
+    for (entity, panel, style, node, visibility, _) in panel_query.iter_mut() {
+        relayout |= panel.is_added()
+            || style.is_changed()
+            || node.is_changed()
+            || visibility.is_some_and(|visibility| visibility.is_changed());
+        let centered = matches!(
+            panel.anchor,
+            PanelAnchor::TopCenter | PanelAnchor::BottomCenter
+        );
+        order.push((centered, entity));
+    }
+    if !relayout {
+        return;
+    }
+    order.sort();
+
+    // Edge (top or bottom), horizontal span and reach from that edge of each stacked panel
+    let mut stacked: Vec<(bool, f32, f32, f32)> = Vec::new();
+    for (_, entity) in order {
+        let Ok((_, panel, mut style, node, visibility, draggable)) = panel_query.get_mut(entity)
+        else {
+            continue;
+        };
+        let (left, width) = compute_panel_layout(&panel, window.width());
+        let mut laid_out = style.clone();
+        laid_out.width = Val::Px(width);
+
+        let dragged = draggable
+            .zip(user_settings.as_deref())
+            .is_some_and(|(draggable, settings)| settings.panel_position(draggable.id).is_some());
+        if dragged {
+            style.set_if_neq(laid_out);
+            continue;
+        }
+
+        let top = matches!(
+            panel.anchor,
+            PanelAnchor::TopLeft | PanelAnchor::TopCenter | PanelAnchor::TopRight
+        );
+        let right = left + width;
+        let offset = stacked
+            .iter()
+            .filter(|(edge, other_left, other_right, _)| {
+                *edge == top && *other_left < right && left < *other_right
+            })
+            .map(|(_, _, _, reach)| reach + panel.margin)
+            .fold(panel.margin, f32::max);
+
+        laid_out.left = Val::Px(left);
+        laid_out.right = Val::Auto;
+        if top {
+            laid_out.top = Val::Px(offset);
+            laid_out.bottom = Val::Auto;
+        } else {
+            laid_out.bottom = Val::Px(offset);
+            laid_out.top = Val::Auto;
+        }
+
+        let shown = laid_out.display != Display::None
+            && visibility.is_none_or(|visibility| *visibility != Visibility::Hidden);
+        if shown {
+            stacked.push((top, left, right, offset + node.size().y));
+        }
+        style.set_if_neq(laid_out);
+    }
+}
+
+/// Keep a panel's top-left corner inside the window, given the panel's size
+pub fn clamp_panel_position(position: Vec2, panel_size: Vec2, window_size: Vec2) -> Vec2 {
+    let max = (window_size - panel_size).max(Vec2::ZERO);
+    position.clamp(Vec2::ZERO, max)
+}
+
+/// Move draggable panels by their title bars and keep them where they were put
+///
+/// Pressing a [`PanelTitleBar`] picks its panel up; while the button is held the panel
+/// follows the cursor, clamped to the window, and its position is stored in
+/// [`UserSettings`], which are written to the [`SettingsFile`] when the panel is let go.
+/// Panels with a stored position are put back there when they are spawned, laid out or
+/// the window is resized, overriding their responsive anchor. A new panel has no size
+/// until its first layout, so it is clamped again once it has one.
+pub fn panel_drag_system(
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    window_query: Query<Ref<Window>, With<PrimaryWindow>>,
+    title_query: Query<(&Interaction, &PanelTitleBar)>,
+    mut panel_query: Query<(Ref<DraggablePanel>, &mut Style, Ref<Node>, &GlobalTransform)>,
+    mut drag: ResMut<PanelDrag>,
+    mut user_settings: ResMut<UserSettings>,
+    settings_file: Option<Res<SettingsFile>>,
+) {
+    let Ok(window) = window_query.get_single() else {
+        return;
+    };
+    let window_size = Vec2::new(window.width(), window.height());
+
+    // Put moved panels back after spawning or a resize re-anchored them
+    for (panel, mut style, node, _) in panel_query.iter_mut() {
+        if !window.is_changed() && !panel.is_added() && !node.is_changed() {
+            continue;
+        }
+        if let Some(position) = user_settings.panel_position(panel.id) {
+            place_panel(
+                &mut style,
+                clamp_panel_position(position, node.size(), window_size),
+            );
+        }
+    }
+
+    if mouse_input.just_released(MouseButton::Left) {
+        if drag.panel.take().is_some() {
+            save_panel_positions(settings_file.as_deref(), &user_settings);
+        }
+        return;
+    }
+    let Some(cursor) = window.cursor_position() else {
+        return;
+    };
+
+    if mouse_input.just_pressed(MouseButton::Left) {
+        let grabbed = title_query
+            .iter()
+            .find(|(interaction, _)| **interaction == Interaction::Pressed);
+        if let Some((_, title_bar)) = grabbed {
+            if let Ok((_, style, node, transform)) = panel_query.get(title_bar.panel) {
+                drag.panel = Some(title_bar.panel);
+                drag.grab_offset = cursor - panel_corner(style, &node, transform);
+            }
+        }
+    }
+
+    let Some(entity) = drag.panel else {
+        return;
+    };
+    if !mouse_input.pressed(MouseButton::Left) {
+        drag.panel = None;
+        save_panel_positions(settings_file.as_deref(), &user_settings);
+        return;
+    }
+    let Ok((panel, mut style, node, _)) = panel_query.get_mut(entity) else {
+        drag.panel = None;
+        return;
+    };
+    let position = clamp_panel_position(cursor - drag.grab_offset, node.size(), window_size);
+    if user_settings.panel_position(panel.id) != Some(position) {
+        place_panel(&mut style, position);
+        user_settings.set_panel_position(panel.id, position);
+    }
+}
+
+/// Write the settings, with the panel positions just dragged, to the settings file
+fn save_panel_positions(settings_file: Option<&SettingsFile>, user_settings: &UserSettings) {
+    let Some(path) = settings_file.and_then(|file| file.path.as_ref()) else {
+        return;
+    };
+    if let Err(e) = write_settings_file(path, user_settings) {
+        warn!(
+            "Failed to save panel positions to {}: {}",
+            path.display(),
+            e
+        );
+    }
+}
+
+/// Panel's current top-left corner, from its style or else from its laid-out node
+fn panel_corner(style: &Style, node: &Node, transform: &GlobalTransform) -> Vec2 {
+    match (style.left, style.top) {
+        (Val::Px(left), Val::Px(top)) => Vec2::new(left, top),
+        _ => transform.translation().truncate() - node.size() / 2.0,
+    }
+}
+
+fn place_panel(style: &mut Style, position: Vec2) {
+    style.left = Val::Px(position.x);
+    style.top = Val::Px(position.y);
+    style.right = Val::Auto;
+    style.bottom = Val::Auto;
+}
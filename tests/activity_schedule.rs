@@ -0,0 +1,71 @@
+//! Time-of-day activity schedule tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+/// Average share of foraging ants over a sampling window at a fixed hour
+fn sample_foraging_fraction(hour: f32) -> f32 {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    let mut simulation_time = SimulationTime::default();
+    simulation_time.set_hour(hour);
+    app.insert_resource(simulation_time);
+    app.init_resource::<ActivitySchedule>();
+    app.add_systems(Update, systems::activity_schedule_system);
+
+    for _ in 0..100 {
+        let home = Position { x: 0.0, y: 0.0 };
+        app.world_mut().spawn((Ant, AntBehavior::new(home, 0.0)));
+    }
+
+    // Let the population settle, then sample
+    for _ in 0..100 {
+        app.update();
+    }
+
+    let mut foraging = 0;
+    let mut total = 0;
+    for _ in 0..200 {
+        app.update();
+        let world = app.world_mut();
+        for behavior in world.query::<&AntBehavior>().iter(world) {
+            total += 1;
+            if behavior.state == AntState::Foraging {
+                foraging += 1;
+            }
+        }
+    }
+
+    foraging as f32 / total as f32
+}
+
+/// Fewer ants forage at a night hour than at the mid-morning peak
+#[test]
+fn test_fewer_foragers_at_night_than_at_peak() {
+    let night = sample_foraging_fraction(2.0);
+    let peak = sample_foraging_fraction(10.0);
+
+    assert!(
+        night + 0.3 < peak,
+        "Night fraction {:.2} should be well below peak fraction {:.2}",
+        night,
+        peak
+    );
+}
+
+/// Editing the schedule changes the multiplier used at that hour
+#[test]
+fn test_schedule_is_editable() {
+    let mut schedule = ActivitySchedule::default();
+    assert!(schedule.multiplier_at(2.0) < 0.5);
+
+    schedule.set_period(0.0, 4.0, 0.9);
+    assert_eq!(schedule.multiplier_at(2.0), 0.9);
+    assert_eq!(schedule.multiplier_at(10.0), 1.0);
+}
@@ -0,0 +1,96 @@
+//! Crowding disease tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+fn disease_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<SpatialGrid>();
+    app.init_resource::<DiseaseConfig>();
+    app.add_systems(
+        Update,
+        (
+            systems::spatial_grid_update_system,
+            systems::adult_disease_system,
+        )
+            .chain(),
+    );
+    app
+}
+
+fn spawn_ant(app: &mut App, x: f32, y: f32) -> Entity {
+    app.world_mut()
+        .spawn((Ant, Position { x, y }, Health::new(100.0)))
+        .id()
+}
+
+fn infected_count(app: &mut App) -> usize {
+    app.world_mut()
+        .query_filtered::<(), (With<Ant>, With<Infected>)>()
+        .iter(app.world())
+        .count()
+}
+
+/// Ants packed into one cell fall ill far more often than the same number spread out
+#[test]
+fn test_crowding_raises_infection_incidence() {
+    let mut packed = disease_app();
+    let mut spread = disease_app();
+    for i in 0..30 {
+        spawn_ant(&mut packed, 1.0 + (i % 5) as f32, 1.0 + (i / 5) as f32);
+        spawn_ant(&mut spread, i as f32 * 20.0, 1.0);
+    }
+
+    for _ in 0..600 {
+        packed.update();
+        spread.update();
+    }
+
+    let packed_infected = infected_count(&mut packed);
+    let spread_infected = infected_count(&mut spread);
+    assert!(
+        packed_infected > spread_infected,
+        "packed {} vs spread {}",
+        packed_infected,
+        spread_infected
+    );
+}
+
+/// An infected ant loses HP, and grooming in a chamber clears the disease
+#[test]
+fn test_infection_hurts_and_grooming_in_chamber_clears_it() {
+    let mut app = disease_app();
+    app.insert_resource(DiseaseConfig {
+        grooming_clear_rate: 0.5,
+        ..default()
+    });
+    app.world_mut().spawn((
+        Position { x: 0.0, y: -20.0 },
+        Chamber {
+            chamber_type: ChamberType::Worker,
+            radius: 6.0,
+            capacity: 8,
+            occupants: 0,
+            temperature: 25.0,
+        },
+    ));
+    let outside = spawn_ant(&mut app, 100.0, 0.0);
+    let groomed = spawn_ant(&mut app, 0.0, -20.0);
+    app.world_mut().entity_mut(outside).insert(Infected);
+    app.world_mut().entity_mut(groomed).insert(Infected);
+
+    for _ in 0..600 {
+        app.update();
+    }
+
+    assert!(app.world().get::<Infected>(outside).is_some());
+    assert!(app.world().get::<Health>(outside).unwrap().hp < 100.0);
+    assert!(app.world().get::<Infected>(groomed).is_none());
+}
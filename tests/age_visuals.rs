@@ -0,0 +1,52 @@
+//! Ant age visual indicator tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+
+fn spawn_worker(app: &mut App, age_group: AntAgeGroup) -> Entity {
+    app.world_mut()
+        .spawn((
+            Ant,
+            PhaseSpecificBehavior { age_group },
+            BaseColor(Color::BLACK),
+            Sprite {
+                color: Color::BLACK,
+                ..default()
+            },
+        ))
+        .id()
+}
+
+/// Senior and young workers must be visually distinguishable
+#[test]
+fn test_senior_ant_renders_differently_from_young_ant() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<VisualEffectsSettings>();
+    app.add_systems(Update, systems::age_visual_system);
+
+    let young = spawn_worker(&mut app, AntAgeGroup::Young);
+    let senior = spawn_worker(&mut app, AntAgeGroup::Senior);
+    app.update();
+
+    let young_color = app.world().get::<Sprite>(young).unwrap().color;
+    let senior_color = app.world().get::<Sprite>(senior).unwrap().color;
+    assert_ne!(young_color, senior_color);
+    assert_ne!(young_color, Color::BLACK, "Young ants should be tinted");
+
+    // Disabling the effect restores the untinted base color
+    app.world_mut()
+        .resource_mut::<VisualEffectsSettings>()
+        .age_indicators_enabled = false;
+    app.update();
+
+    assert_eq!(
+        app.world().get::<Sprite>(young).unwrap().color,
+        Color::BLACK
+    );
+    assert_eq!(
+        app.world().get::<Sprite>(senior).unwrap().color,
+        Color::BLACK
+    );
+}
@@ -0,0 +1,118 @@
+//! Alarm pheromone tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+const THREAT: Position = Position { x: 40.0, y: 2.0 };
+
+fn alarm_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<AntAiConfig>();
+    app.init_resource::<AlarmPheromoneGrid>();
+    app.add_systems(
+        Update,
+        (
+            systems::alarm_emission_system,
+            systems::alarm_spread_system,
+            systems::alarm_response_system,
+        )
+            .chain(),
+    );
+    app
+}
+
+fn spawn_ant(world: &mut World, x: f32, size_class: CasteClass) -> Entity {
+    world
+        .spawn((
+            Position { x, y: 2.0 },
+            Ant,
+            AntBehavior::new(Position { x: 0.0, y: 0.0 }, 0.0),
+            Caste { size_class },
+        ))
+        .id()
+}
+
+/// Run three seconds; returns (alarm at the threat, majors within 5 units of it, minor's state)
+fn run_scenario(with_invader: bool) -> (f32, usize, AntState) {
+    let mut app = alarm_test_app();
+    let majors: Vec<Entity> = [-20.0, -14.0, -6.0, 6.0, 14.0, 20.0]
+        .iter()
+        .map(|offset| spawn_ant(app.world_mut(), THREAT.x + offset, CasteClass::Major))
+        .collect();
+    let minor = spawn_ant(app.world_mut(), THREAT.x + 3.0, CasteClass::Minor);
+    if with_invader {
+        app.world_mut().spawn((
+            THREAT,
+            InvasiveSpecies {
+                lifetime: 100.0,
+                food_consumption_rate: 0.0,
+                strength: 1.0,
+            },
+        ));
+    }
+
+    for _ in 0..30 {
+        app.update();
+    }
+
+    let world = app.world();
+    let alarm = world
+        .resource::<AlarmPheromoneGrid>()
+        .grid
+        .concentration(&THREAT);
+    let gathered = majors
+        .iter()
+        .filter(|ant| {
+            let position = world.get::<Position>(**ant).unwrap();
+            (position.x - THREAT.x).abs() <= 5.0
+        })
+        .count();
+    let minor_state = world.get::<AntBehavior>(minor).unwrap().state;
+    (alarm, gathered, minor_state)
+}
+
+/// An invader raises alarm around itself and draws defenders in while minors flee
+#[test]
+fn test_invader_raises_alarm_and_recruits_defenders() {
+    let (baseline_alarm, baseline_gathered, baseline_minor) = run_scenario(false);
+    let (alarm, gathered, minor) = run_scenario(true);
+
+    assert_eq!(baseline_alarm, 0.0);
+    assert_eq!(baseline_minor, AntState::Foraging);
+    assert!(alarm > 0.0, "Alarm should build up near the invader");
+    assert!(
+        gathered > baseline_gathered + 1,
+        "{} majors gathered at the threat vs {} without one",
+        gathered,
+        baseline_gathered
+    );
+    assert_eq!(minor, AntState::Returning, "Minors should retreat");
+}
+
+/// Without a threat to renew it, alarm fades within a few seconds
+#[test]
+fn test_alarm_fades_once_threat_is_gone() {
+    let mut app = alarm_test_app();
+    app.world_mut()
+        .resource_mut::<AlarmPheromoneGrid>()
+        .grid
+        .deposit(&THREAT, 5.0);
+
+    for _ in 0..50 {
+        app.update();
+    }
+
+    assert!(app
+        .world()
+        .resource::<AlarmPheromoneGrid>()
+        .grid
+        .cells
+        .is_empty());
+}
@@ -0,0 +1,100 @@
+//! Ant level-of-detail rendering tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+
+/// Crowd of `count` ants spread over a 10x10 patch of ground
+fn lod_app(count: usize, population_threshold: usize) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<SpatialGrid>();
+    app.insert_resource(AntLodConfig {
+        population_threshold,
+        ..default()
+    });
+    app.add_systems(
+        Update,
+        (systems::spatial_grid_update_system, systems::ant_lod_system).chain(),
+    );
+
+    let world = app.world_mut();
+    for i in 0..count {
+        systems::spawn_worker_ant(
+            &mut world.commands(),
+            Position {
+                x: (i % 10) as f32 * 8.0,
+                y: ((i / 10) % 10) as f32 * -8.0,
+            },
+            Lifecycle {
+                age: 0.0,
+                max_age: 1000.0,
+                energy: 100.0,
+                max_energy: 100.0,
+            },
+            CasteClass::Media,
+            BehaviorModifiers::default(),
+            &AntAiConfig::default(),
+            &mut SimulationRng::from_seed(1).0,
+        );
+    }
+    world.flush();
+    app
+}
+
+fn visible_sprites(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world
+        .query_filtered::<&Visibility, With<Sprite>>()
+        .iter(world)
+        .filter(|visibility| **visibility != Visibility::Hidden)
+        .count()
+}
+
+/// Above the threshold, sprites drawn are bounded by occupied cells, not ants
+#[test]
+fn test_large_colony_draws_one_sprite_per_cell() {
+    let mut app = lod_app(500, 100);
+    app.update();
+    app.update();
+
+    let occupied_cells = app.world().resource::<SpatialGrid>().grid.len();
+    assert!(app.world().resource::<AntLodConfig>().aggregated);
+    assert!(occupied_cells < 500);
+    assert!(
+        visible_sprites(&mut app) <= occupied_cells,
+        "{} sprites drawn for {} occupied cells",
+        visible_sprites(&mut app),
+        occupied_cells
+    );
+}
+
+/// Below the threshold every ant is drawn individually and no blobs exist
+#[test]
+fn test_small_colony_draws_every_ant() {
+    let mut app = lod_app(50, 100);
+    app.update();
+    app.update();
+
+    let world = app.world_mut();
+    assert_eq!(world.query::<&DensityBlob>().iter(world).count(), 0);
+    assert!(!app.world().resource::<AntLodConfig>().aggregated);
+    assert_eq!(visible_sprites(&mut app), 50);
+}
+
+/// Zooming the camera all the way out switches even a small colony to blobs
+#[test]
+fn test_max_zoom_reaches_aggregation() {
+    let mut app = lod_app(50, 100);
+    app.world_mut().spawn((
+        Camera::default(),
+        OrthographicProjection {
+            scale: CameraSettings::default().max_zoom,
+            ..default()
+        },
+    ));
+    app.update();
+    app.update();
+
+    assert!(app.world().resource::<AntLodConfig>().aggregated);
+}
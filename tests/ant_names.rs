@@ -0,0 +1,220 @@
+//! Ant id and naming tests
+
+mod common;
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::localization::Localization;
+use ant_nest_simulator::systems::{self, PersistenceState, SaveSlotRequest, SaveSlots, SlotAction};
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use common::temp_save_dir;
+use std::collections::HashSet;
+use std::time::Duration;
+
+/// Hatching a clutch of eggs gives every hatchling its own id and a name
+#[test]
+fn test_hatched_ants_receive_unique_ids() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(ColonyFoodStore {
+        food: 1000.0,
+        protein: 1000.0,
+    });
+    app.init_resource::<BroodNutritionConfig>();
+    app.init_resource::<ChamberClimateConfig>();
+    app.init_resource::<CasteConfig>();
+    app.init_resource::<AntRegistry>();
+    app.add_systems(
+        Update,
+        (systems::egg_hatching_system, systems::ant_naming_system).chain(),
+    );
+
+    for i in 0..30 {
+        app.world_mut().spawn((
+            Position {
+                x: i as f32,
+                y: -20.0,
+            },
+            Egg {
+                incubation_time: 0.01,
+            },
+        ));
+    }
+    for _ in 0..5 {
+        app.update();
+    }
+
+    let world = app.world_mut();
+    let named: Vec<(AntId, AntName)> = world
+        .query::<(&AntId, &AntName)>()
+        .iter(world)
+        .map(|(id, name)| (*id, name.clone()))
+        .collect();
+    assert_eq!(named.len(), 30, "Every hatchling should be named");
+    let ids: HashSet<AntId> = named.iter().map(|(id, _)| *id).collect();
+    assert_eq!(ids.len(), 30, "Ids should be unique");
+    assert!(named.iter().all(|(_, name)| !name.0.is_empty()));
+}
+
+/// A renamed ant keeps its new name and id through saving and loading
+#[test]
+fn test_renamed_ant_survives_save_and_load() {
+    let save_dir = temp_save_dir("ant-names");
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(PersistenceState {
+        save_directory: Some(save_dir.clone()),
+        ..default()
+    });
+    app.init_resource::<SaveSlots>();
+    app.init_resource::<Localization>();
+    app.init_resource::<ColonyFoodStore>();
+    app.init_resource::<NestSpace>();
+    app.init_resource::<AntRegistry>();
+    app.add_event::<SaveSlotRequest>();
+    app.add_event::<systems::RenameAntRequest>();
+    app.add_systems(Startup, systems::spawn_initial_ants);
+    app.add_systems(
+        Update,
+        (
+            systems::save_slot_request_system,
+            systems::ant_naming_system,
+            systems::rename_ant_system,
+        )
+            .chain(),
+    );
+    app.update();
+    app.update();
+
+    let world = app.world_mut();
+    let (ant, id) = world
+        .query::<(Entity, &AntId)>()
+        .iter(world)
+        .next()
+        .map(|(entity, id)| (entity, *id))
+        .unwrap();
+    app.world_mut().send_event(systems::RenameAntRequest {
+        ant,
+        name: "Antoinette".to_string(),
+    });
+    app.update();
+    assert_eq!(
+        app.world().get::<Tooltip>(ant).unwrap().text,
+        format!("Antoinette #{}", id.0)
+    );
+
+    app.world_mut().send_event(SaveSlotRequest {
+        slot: 1,
+        action: SlotAction::Save,
+    });
+    app.update();
+    app.world_mut().send_event(SaveSlotRequest {
+        slot: 1,
+        action: SlotAction::Load,
+    });
+    app.update();
+    app.update();
+
+    let world = app.world_mut();
+    assert!(world.get_entity(ant).is_none(), "The old ant was replaced");
+    let restored: Vec<(AntId, AntName)> = world
+        .query::<(&AntId, &AntName)>()
+        .iter(world)
+        .map(|(id, name)| (*id, name.clone()))
+        .collect();
+    assert_eq!(restored.len(), 5);
+    assert!(restored.contains(&(id, AntName("Antoinette".to_string()))));
+
+    // New ants are numbered after the restored ones
+    let highest = restored.iter().map(|(id, _)| id.0).max().unwrap();
+    assert!(app.world().resource::<AntRegistry>().next_id > highest);
+
+    let _ = std::fs::remove_dir_all(save_dir);
+}
+
+fn key_press(key_code: KeyCode, logical_key: Key) -> KeyboardInput {
+    KeyboardInput {
+        key_code,
+        logical_key,
+        state: ButtonState::Pressed,
+        window: Entity::PLACEHOLDER,
+    }
+}
+
+/// The selection panel's rename button lets a single selected ant be renamed by typing
+#[test]
+fn test_rename_button_renames_single_selected_ant() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<Selection>();
+    app.init_resource::<AntRename>();
+    app.init_resource::<ButtonInput<KeyCode>>();
+    app.add_event::<KeyboardInput>();
+    app.add_event::<systems::RenameAntRequest>();
+    app.add_systems(Startup, systems::setup_selection_panel);
+    app.add_systems(PreUpdate, systems::rename_text_input_system);
+    app.add_systems(
+        Update,
+        (
+            systems::rename_button_system,
+            systems::rename_ant_system,
+            systems::update_rename_button_system,
+        )
+            .chain(),
+    );
+    let ant = app
+        .world_mut()
+        .spawn((Ant, AntId(5), AntName("Fern".to_string())))
+        .id();
+    let other = app
+        .world_mut()
+        .spawn((Ant, AntId(6), AntName("Wren".to_string())))
+        .id();
+    app.world_mut().resource_mut::<Selection>().0 = vec![ant, other];
+    app.update();
+
+    let world = app.world_mut();
+    let button = world
+        .query_filtered::<Entity, With<RenameButton>>()
+        .single(world);
+    assert_eq!(
+        world.get::<Style>(button).unwrap().display,
+        Display::None,
+        "Several selected ants cannot be renamed at once"
+    );
+
+    app.world_mut().resource_mut::<Selection>().0 = vec![ant];
+    app.update();
+    assert_eq!(
+        app.world().get::<Style>(button).unwrap().display,
+        Display::Flex
+    );
+    *app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::Pressed;
+    app.update();
+    assert_eq!(app.world().resource::<AntRename>().text, "Fern");
+
+    let mut typed = vec![key_press(KeyCode::Backspace, Key::Backspace); 4];
+    typed.extend(
+        ["Z", "o", "e"].map(|letter| key_press(KeyCode::KeyZ, Key::Character(letter.into()))),
+    );
+    typed.push(key_press(KeyCode::Enter, Key::Enter));
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::KeyZ);
+    app.world_mut().send_event_batch(typed);
+    app.update();
+
+    assert_eq!(app.world().get::<AntName>(ant).unwrap().0, "Zoe");
+    assert!(app.world().resource::<AntRename>().ant.is_none());
+    assert!(
+        !app.world()
+            .resource::<ButtonInput<KeyCode>>()
+            .pressed(KeyCode::KeyZ),
+        "Typed keys should not reach the shortcuts"
+    );
+}
@@ -0,0 +1,89 @@
+//! Pluggable ant strategy tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::strategy::{AntContext, AntStrategy, BoxedStrategy, DefaultStrategy};
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+/// Trivial strategy that always heads for the origin
+struct OriginStrategy;
+
+impl AntStrategy for OriginStrategy {
+    fn choose_target(&self, _ctx: &AntContext) -> Option<Position> {
+        Some(Position { x: 0.0, y: 0.0 })
+    }
+}
+
+/// A custom strategy inserted as a resource drives the ant
+#[test]
+fn test_custom_strategy_drives_ant_toward_origin() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<AntAiConfig>();
+    app.init_resource::<PheromoneGrid>();
+    app.init_resource::<ColonyFoodStore>();
+    app.insert_resource(BoxedStrategy::new(OriginStrategy));
+    app.add_systems(Update, systems::foraging_behavior_system);
+
+    let ant = app
+        .world_mut()
+        .spawn((
+            Position { x: 40.0, y: -20.0 },
+            Ant,
+            AntBehavior::new(Position { x: 60.0, y: 0.0 }, 0.0),
+        ))
+        .id();
+
+    let start_distance = 40.0f32.hypot(20.0);
+    let mut previous_distance = start_distance;
+    for _ in 0..30 {
+        app.update();
+        let position = app.world().get::<Position>(ant).unwrap();
+        let distance = position.x.hypot(position.y);
+        assert!(distance <= previous_distance);
+        previous_distance = distance;
+    }
+    assert!(previous_distance < start_distance);
+
+    for _ in 0..100 {
+        app.update();
+    }
+    let position = app.world().get::<Position>(ant).unwrap();
+    assert!(position.x.hypot(position.y) < 0.01);
+}
+
+/// A zero wander range keeps the default strategy's ant in place instead of panicking
+#[test]
+fn test_default_strategy_stays_put_without_range() {
+    let position = Position { x: 12.0, y: 2.0 };
+    let home = Position { x: 0.0, y: 0.0 };
+    let world = WorldConfig::default();
+
+    for wander_mode in [WanderMode::FarTarget, WanderMode::Smooth] {
+        let config = AntAiConfig {
+            forage_range: 0.0,
+            wander_mode,
+            ..default()
+        };
+        let ctx = AntContext {
+            position: &position,
+            home: &home,
+            energy: None,
+            is_scout: false,
+            heading: 0.0,
+            remembered_food: None,
+            nearby_food: &[],
+            nearby_pheromone: &[],
+            config: &config,
+            world: &world,
+        };
+
+        let target = DefaultStrategy.choose_target(&ctx).unwrap();
+        assert_eq!((target.x, target.y), (12.0, 2.0));
+    }
+}
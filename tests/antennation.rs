@@ -0,0 +1,96 @@
+//! Antennation (contact information exchange) tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+fn antennation_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<AntAiConfig>();
+    app.init_resource::<SpatialGrid>();
+    app.add_systems(
+        Update,
+        (
+            systems::spatial_grid_update_system,
+            systems::antennation_system,
+        )
+            .chain(),
+    );
+    app
+}
+
+fn spawn_ant(app: &mut App, x: f32, memory: Option<Position>) -> Entity {
+    app.world_mut()
+        .spawn((
+            Position { x, y: 0.0 },
+            Ant,
+            FoodMemory { location: memory },
+            AntennationCooldown::default(),
+        ))
+        .id()
+}
+
+/// A food-knowing ant meeting a naive ant passes on the food location
+#[test]
+fn test_food_location_transfers_on_contact() {
+    let mut app = antennation_app();
+    let informed = spawn_ant(&mut app, 0.0, Some(Position { x: 40.0, y: 2.0 }));
+    let naive = spawn_ant(&mut app, 1.0, None);
+    let distant = spawn_ant(&mut app, 20.0, None);
+
+    app.update();
+
+    let learned = app.world().get::<FoodMemory>(naive).unwrap();
+    let location = learned.location.as_ref().expect("Naive ant should learn");
+    assert_eq!((location.x, location.y), (40.0, 2.0));
+
+    // Ants out of contact learn nothing
+    assert!(app
+        .world()
+        .get::<FoodMemory>(distant)
+        .unwrap()
+        .location
+        .is_none());
+
+    // Both participants wait before exchanging again
+    assert!(
+        app.world()
+            .get::<AntennationCooldown>(informed)
+            .unwrap()
+            .remaining
+            > 0.0
+    );
+    assert!(
+        app.world()
+            .get::<AntennationCooldown>(naive)
+            .unwrap()
+            .remaining
+            > 0.0
+    );
+}
+
+/// An ant on cooldown does not share until the cooldown elapses
+#[test]
+fn test_cooldown_delays_exchange() {
+    let mut app = antennation_app();
+    let informed = spawn_ant(&mut app, 0.0, Some(Position { x: 40.0, y: 2.0 }));
+    app.world_mut()
+        .get_mut::<AntennationCooldown>(informed)
+        .unwrap()
+        .remaining = 10.0;
+    let naive = spawn_ant(&mut app, 1.0, None);
+
+    app.update();
+    assert!(app
+        .world()
+        .get::<FoodMemory>(naive)
+        .unwrap()
+        .location
+        .is_none());
+}
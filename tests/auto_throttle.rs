@@ -0,0 +1,85 @@
+//! Speed auto-throttle tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::localization::Localization;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+fn throttle_app(speed: f32, config: AutoThrottleConfig) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    let mut time_control = TimeControl::default();
+    time_control.set_speed(speed);
+    app.insert_resource(time_control);
+    app.insert_resource(AutoThrottleConfig {
+        adjust_timer: Timer::from_seconds(0.1, TimerMode::Repeating),
+        ..config
+    });
+    app.init_resource::<PerformanceMetrics>();
+    app.add_systems(
+        Update,
+        (
+            systems::auto_throttle_system,
+            systems::apply_time_control_system,
+        )
+            .chain(),
+    );
+    app
+}
+
+/// Feed a steady FPS reading for a number of frames
+fn run_at_fps(app: &mut App, fps: f32, frames: usize) {
+    for _ in 0..frames {
+        app.world_mut().resource_mut::<PerformanceMetrics>().fps = fps;
+        app.update();
+    }
+}
+
+fn relative_speed(app: &App) -> f32 {
+    app.world().resource::<Time<Virtual>>().relative_speed()
+}
+
+/// Low FPS slows the simulation step rate down and recovered FPS brings it back
+#[test]
+fn test_low_fps_throttles_and_recovery_restores() {
+    let mut app = throttle_app(8.0, AutoThrottleConfig::default());
+
+    run_at_fps(&mut app, 10.0, 6);
+    let throttled = relative_speed(&app);
+    assert!(throttled < 8.0, "Still running at {:.2}x", throttled);
+    let time_control = app.world().resource::<TimeControl>();
+    assert!(time_control.is_throttled());
+    assert!(systems::speed_label(time_control, &Localization::default()).contains("throttled"));
+
+    run_at_fps(&mut app, 60.0, 20);
+    assert_eq!(relative_speed(&app), 8.0);
+    let time_control = app.world().resource::<TimeControl>();
+    assert!(!time_control.is_throttled());
+    assert_eq!(
+        systems::speed_label(time_control, &Localization::default()),
+        "Speed 8x"
+    );
+}
+
+/// A disabled throttle leaves the speed alone, and throttling never drops below real time
+#[test]
+fn test_disabled_or_real_time_is_not_slowed() {
+    let mut disabled = throttle_app(
+        8.0,
+        AutoThrottleConfig {
+            enabled: false,
+            ..default()
+        },
+    );
+    run_at_fps(&mut disabled, 5.0, 10);
+    assert_eq!(relative_speed(&disabled), 8.0);
+
+    let mut real_time = throttle_app(1.0, AutoThrottleConfig::default());
+    run_at_fps(&mut real_time, 5.0, 10);
+    assert_eq!(relative_speed(&real_time), 1.0);
+}
@@ -0,0 +1,120 @@
+//! Batched despawn tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::snapshot::colony_snapshot;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+
+fn population(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world.query::<&Lifecycle>().iter(world).count()
+}
+
+/// A mass die-off is despawned over several frames, never more than the budget per frame
+#[test]
+fn test_mass_deaths_are_despawned_within_budget() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<CarryingCapacity>();
+    app.insert_resource(PendingDespawn::with_budget(10));
+    app.init_resource::<SpatialGrid>();
+    app.add_systems(
+        Update,
+        (
+            systems::ant_lifecycle_system,
+            systems::batched_cleanup_system,
+        )
+            .chain(),
+    );
+
+    for i in 0..35 {
+        let position = Position {
+            x: i as f32,
+            y: 0.0,
+        };
+        let ant = app
+            .world_mut()
+            .spawn((
+                position.clone(),
+                Lifecycle {
+                    age: 0.0,
+                    max_age: 1000.0,
+                    energy: 0.0, // Starved
+                    max_energy: 100.0,
+                },
+            ))
+            .id();
+        app.world_mut()
+            .resource_mut::<SpatialGrid>()
+            .insert_entity(ant, &position);
+    }
+
+    let mut remaining = Vec::new();
+    for _ in 0..4 {
+        app.update();
+        remaining.push(population(&mut app));
+    }
+
+    assert_eq!(remaining, vec![25, 15, 5, 0]);
+    assert!(app.world().resource::<PendingDespawn>().is_empty());
+    assert!(app.world().resource::<SpatialGrid>().grid.is_empty());
+}
+
+/// An entity is queued once however many times it is reported dead
+#[test]
+fn test_entities_are_queued_once() {
+    let mut world = World::new();
+    let entity = world.spawn_empty().id();
+    let mut pending = PendingDespawn::with_budget(5);
+
+    assert!(pending.enqueue(entity));
+    assert!(!pending.enqueue(entity));
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending.next_batch(), vec![entity]);
+    assert!(!pending.contains(entity));
+}
+
+/// An ant waiting in the despawn queue no longer counts as alive
+#[test]
+fn test_queued_ants_do_not_count_toward_population() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<CarryingCapacity>();
+    app.init_resource::<PendingDespawn>();
+    app.init_resource::<SimulationTime>();
+    app.init_resource::<ColonyDevelopmentPhase>();
+    app.init_resource::<SimulationEnded>();
+    app.init_resource::<TimeControl>();
+    app.insert_resource(EndConditions {
+        on_extinction: true,
+        ..default()
+    });
+    // No cleanup system runs, so the dead ant stays queued
+    app.add_systems(
+        Update,
+        (systems::ant_lifecycle_system, systems::end_condition_system).chain(),
+    );
+
+    let ant = app
+        .world_mut()
+        .spawn((
+            Ant,
+            Position { x: 0.0, y: 0.0 },
+            Lifecycle {
+                age: 0.0,
+                max_age: 1000.0,
+                energy: 0.0, // Starved
+                max_energy: 100.0,
+            },
+        ))
+        .id();
+    app.update();
+
+    assert!(app.world().resource::<PendingDespawn>().contains(ant));
+    assert!(app.world().get::<Dying>(ant).is_some());
+    assert_eq!(colony_snapshot(app.world()).population, 0);
+    assert_eq!(
+        app.world().resource::<SimulationEnded>().reason,
+        Some(EndReason::ColonyExtinct)
+    );
+}
@@ -0,0 +1,142 @@
+//! Breadcrumb return path tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::strategy::BoxedStrategy;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+fn breadcrumb_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<AntAiConfig>();
+    app.init_resource::<BoxedStrategy>();
+    app.init_resource::<PheromoneGrid>();
+    app.init_resource::<ColonyFoodStore>();
+    app.add_systems(Update, systems::foraging_behavior_system);
+    app
+}
+
+/// Steer a foraging ant toward `target` until it is within 2 units of it
+fn walk_out_to(app: &mut App, ant: Entity, target: Position) {
+    for _ in 0..1000 {
+        let position = app.world().get::<Position>(ant).unwrap().clone();
+        let (dx, dy) = (target.x - position.x, target.y - position.y);
+        if (dx * dx + dy * dy).sqrt() <= 2.0 {
+            return;
+        }
+        app.world_mut()
+            .get_mut::<AntBehavior>(ant)
+            .unwrap()
+            .target_position = Some(target.clone());
+        app.update();
+    }
+    panic!("Ant never reached ({}, {})", target.x, target.y);
+}
+
+/// Distance from `point` to the segment between `a` and `b`
+fn distance_to_segment(point: &Position, a: &Position, b: &Position) -> f32 {
+    let (abx, aby) = (b.x - a.x, b.y - a.y);
+    let length_squared = abx * abx + aby * aby;
+    let t = if length_squared > 0.0 {
+        (((point.x - a.x) * abx + (point.y - a.y) * aby) / length_squared).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let (x, y) = (a.x + abx * t, a.y + aby * t);
+    ((point.x - x).powi(2) + (point.y - y).powi(2)).sqrt()
+}
+
+/// A returning ant walks back along its recorded outbound waypoints instead of cutting across
+#[test]
+fn test_returning_ant_retraces_breadcrumbs() {
+    let mut app = breadcrumb_app();
+    let home = Position { x: 0.0, y: 0.0 };
+    let ant = app
+        .world_mut()
+        .spawn((
+            home.clone(),
+            Ant,
+            AntBehavior::new(home.clone(), 0.0),
+            Breadcrumbs::default(),
+        ))
+        .id();
+
+    // Out along an L: east, then north
+    walk_out_to(&mut app, ant, Position { x: 40.0, y: 0.0 });
+    walk_out_to(&mut app, ant, Position { x: 40.0, y: 40.0 });
+
+    let outbound: Vec<Position> = app
+        .world()
+        .get::<Breadcrumbs>(ant)
+        .unwrap()
+        .points
+        .iter()
+        .cloned()
+        .collect();
+    assert!(outbound.len() >= 10, "Only {} waypoints", outbound.len());
+
+    let mut behavior = app.world_mut().get_mut::<AntBehavior>(ant).unwrap();
+    behavior.state = AntState::Returning;
+    behavior.carried_food = 5.0;
+
+    // The way back, ending with delivery at home
+    let mut path = Vec::new();
+    for _ in 0..1000 {
+        app.update();
+        path.push(app.world().get::<Position>(ant).unwrap().clone());
+        if app.world().get::<AntBehavior>(ant).unwrap().state == AntState::Foraging {
+            break;
+        }
+    }
+    assert_eq!(
+        app.world().get::<AntBehavior>(ant).unwrap().state,
+        AntState::Foraging,
+        "Ant should have delivered its food"
+    );
+
+    // Every step of the way back lies on the reversed outbound route
+    let mut route = outbound.clone();
+    route.reverse();
+    route.push(home.clone());
+    for position in &path {
+        let deviation = route
+            .windows(2)
+            .map(|leg| distance_to_segment(position, &leg[0], &leg[1]))
+            .fold(f32::INFINITY, f32::min);
+        assert!(
+            deviation < 2.0,
+            "({:.1}, {:.1}) is {:.1} off the outbound route",
+            position.x,
+            position.y,
+            deviation
+        );
+    }
+    // It passed the corner rather than beelining home
+    assert!(path
+        .iter()
+        .any(|position| position.x > 35.0 && position.y < 5.0));
+}
+
+/// Waypoints are spaced out and capped, dropping the oldest first
+#[test]
+fn test_breadcrumbs_are_sparse_and_bounded() {
+    let mut breadcrumbs = Breadcrumbs::default();
+    for x in 0..100 {
+        breadcrumbs.record(
+            &Position {
+                x: x as f32,
+                y: 0.0,
+            },
+            5.0,
+            8,
+        );
+    }
+    assert_eq!(breadcrumbs.points.len(), 8);
+    let xs: Vec<f32> = breadcrumbs.points.iter().map(|point| point.x).collect();
+    assert_eq!(xs, vec![60.0, 65.0, 70.0, 75.0, 80.0, 85.0, 90.0, 95.0]);
+}
@@ -0,0 +1,100 @@
+//! Brood batch egg-laying tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+fn brood_batch_app(queen_vigor: f32) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(ColonyFoodStore {
+        food: 100.0,
+        protein: 200.0,
+    });
+    app.init_resource::<BroodNutritionConfig>();
+    app.insert_resource(BroodBatchConfig {
+        batch_chance: 1.0, // Every laying event is a batch candidate
+        ..default()
+    });
+    app.add_event::<systems::EggLaidEvent>();
+    app.add_systems(Update, systems::queen_reproduction_system);
+
+    let traits = ColonyTraits {
+        queen_vigor,
+        worker_efficiency: 1.0,
+        architectural_skill: 1.0,
+        environmental_adaptation: 1.0,
+    };
+    app.world_mut().spawn((
+        Position { x: 0.0, y: -20.0 },
+        Ant,
+        Queen {
+            founding_state: FoundingState::Established,
+        },
+        ReproductionState {
+            time_since_last_egg: 9.95, // Lays on the first update, then not for 10s
+            ..default()
+        },
+        traits,
+    ));
+    app
+}
+
+fn laying_events(app: &App) -> Vec<systems::EggLaidEvent> {
+    let events = app.world().resource::<Events<systems::EggLaidEvent>>();
+    events.get_reader().read(events).copied().collect()
+}
+
+fn incubation_times(app: &mut App) -> Vec<f32> {
+    app.world_mut()
+        .query::<&Egg>()
+        .iter(app.world())
+        .map(|egg| egg.incubation_time)
+        .collect()
+}
+
+/// A peak-vigor, well-fed queen lays a multi-egg cohort in one laying event
+#[test]
+fn test_vigorous_well_fed_queen_lays_batch() {
+    let mut app = brood_batch_app(ColonyTraits::MAX);
+
+    app.update();
+    app.update();
+
+    let events = laying_events(&app);
+    assert_eq!(events.len(), 1, "The whole batch is a single laying event");
+    let times = incubation_times(&mut app);
+    assert!(
+        times.len() > 1,
+        "Expected a batch, got {} eggs",
+        times.len()
+    );
+    assert_eq!(events[0].batch_size as usize, times.len());
+    assert!(
+        times.iter().all(|time| *time == times[0]),
+        "A batch should hatch together"
+    );
+
+    let protein = app.world().resource::<ColonyFoodStore>().protein;
+    let cost = BroodNutritionConfig::default().egg_protein_cost;
+    assert_eq!(protein, 200.0 - cost * times.len() as f32);
+}
+
+/// A low-vigor queen keeps laying one egg at a time
+#[test]
+fn test_low_vigor_queen_lays_singly() {
+    let mut app = brood_batch_app(0.7);
+
+    app.update();
+    app.update();
+
+    let events = laying_events(&app);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].batch_size, 1);
+    assert_eq!(incubation_times(&mut app).len(), 1);
+}
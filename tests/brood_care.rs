@@ -0,0 +1,107 @@
+//! Nursery and nurse-gated reproduction tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+fn brood_care_app(worker_role: SpecializedRole) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(ColonyFoodStore {
+        food: 100.0,
+        protein: 1000.0,
+    });
+    app.init_resource::<BroodNutritionConfig>();
+    app.init_resource::<BroodCareConfig>();
+    app.init_resource::<ChamberClimateConfig>();
+    app.init_resource::<CasteConfig>();
+    app.add_event::<systems::EggLaidEvent>();
+    app.add_systems(
+        Update,
+        (
+            systems::queen_reproduction_system,
+            systems::egg_hatching_system,
+        )
+            .chain(),
+    );
+
+    app.world_mut().spawn((
+        Position { x: 0.0, y: -20.0 },
+        Ant,
+        Queen {
+            founding_state: FoundingState::Established,
+        },
+        ReproductionState::default(),
+    ));
+    app.world_mut().spawn((
+        Position { x: 12.0, y: -20.0 },
+        Chamber {
+            chamber_type: ChamberType::Nursery,
+            radius: 6.0,
+            capacity: 8,
+            occupants: 0,
+            temperature: 25.0,
+        },
+    ));
+    for _ in 0..2 {
+        app.world_mut()
+            .spawn((Position { x: 12.0, y: -20.0 }, Ant, worker_role));
+    }
+    app
+}
+
+/// Eggs still developing plus workers that hatched from them
+fn surviving_brood(app: &mut App) -> usize {
+    let eggs = app.world_mut().query::<&Egg>().iter(app.world()).count();
+    let hatched = app
+        .world_mut()
+        .query_filtered::<(), (With<Ant>, With<Lifecycle>)>()
+        .iter(app.world())
+        .count();
+    eggs + hatched
+}
+
+/// A colony without nursery workers raises far less brood than a staffed one
+#[test]
+fn test_unstaffed_nursery_raises_far_fewer_eggs() {
+    let mut staffed = brood_care_app(SpecializedRole::NurseryWorker);
+    let mut unstaffed = brood_care_app(SpecializedRole::Forager);
+
+    // Short enough that no hatchling can join the unstaffed nursery as a nurse
+    for _ in 0..600 {
+        staffed.update();
+        unstaffed.update();
+    }
+
+    let staffed_brood = surviving_brood(&mut staffed);
+    let unstaffed_brood = surviving_brood(&mut unstaffed);
+    assert!(
+        unstaffed_brood * 2 < staffed_brood,
+        "staffed {} vs unstaffed {}",
+        staffed_brood,
+        unstaffed_brood
+    );
+}
+
+/// A full nursery leaves the brood unattended even with nurses around
+#[test]
+fn test_full_nursery_leaves_brood_unattended() {
+    let mut roomy = brood_care_app(SpecializedRole::NurseryWorker);
+    let mut full = brood_care_app(SpecializedRole::NurseryWorker);
+    full.world_mut()
+        .query::<&mut Chamber>()
+        .single_mut(full.world_mut())
+        .occupants = 8;
+
+    for _ in 0..400 {
+        roomy.update();
+        full.update();
+    }
+
+    assert!(surviving_brood(&mut full) < surviving_brood(&mut roomy));
+}
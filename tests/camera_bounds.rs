@@ -0,0 +1,90 @@
+//! Camera pan bounds and zoom limit tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+fn camera_app() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<ButtonInput<KeyCode>>();
+    app.init_resource::<CameraSettings>();
+    app.init_resource::<KeyBindings>();
+    app.init_resource::<WorldConfig>();
+    app.add_systems(Update, systems::camera_control_system);
+
+    let settings = CameraSettings::default();
+    let camera = app
+        .world_mut()
+        .spawn((
+            Camera::default(),
+            Transform::from_translation(settings.default_center.extend(1000.0)),
+            OrthographicProjection {
+                scale: settings.default_zoom,
+                ..default()
+            },
+        ))
+        .id();
+    (app, camera)
+}
+
+fn press(app: &mut App, key: KeyCode) {
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(key);
+}
+
+/// Panning past the world edge stops the camera at the edge of the bounding box
+#[test]
+fn test_pan_beyond_bounds_clamps_camera() {
+    let (mut app, camera) = camera_app();
+    press(&mut app, KeyCode::ArrowRight);
+    press(&mut app, KeyCode::ArrowUp);
+
+    for _ in 0..200 {
+        app.update();
+    }
+
+    let bounds = CameraSettings::default().pan_bounds(&WorldConfig::default());
+    let translation = app.world().get::<Transform>(camera).unwrap().translation;
+    assert_eq!(translation.x, bounds.max.x);
+    assert_eq!(translation.y, bounds.max.y);
+    assert_eq!(translation.z, 1000.0);
+}
+
+/// Zoom stays within the configured limits, and the reset key restores the default view
+#[test]
+fn test_zoom_limits_and_reset() {
+    let (mut app, camera) = camera_app();
+    press(&mut app, KeyCode::Minus);
+    press(&mut app, KeyCode::ArrowLeft);
+    for _ in 0..100 {
+        app.update();
+    }
+    let settings = CameraSettings::default();
+    let scale = app
+        .world()
+        .get::<OrthographicProjection>(camera)
+        .unwrap()
+        .scale;
+    assert_eq!(scale, settings.max_zoom);
+
+    let mut input = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    input.reset_all();
+    input.press(KeyCode::Home);
+    app.update();
+
+    let scale = app
+        .world()
+        .get::<OrthographicProjection>(camera)
+        .unwrap()
+        .scale;
+    let translation = app.world().get::<Transform>(camera).unwrap().translation;
+    assert_eq!(scale, settings.default_zoom);
+    assert_eq!(translation.truncate(), settings.default_center);
+}
@@ -0,0 +1,92 @@
+//! Food carrying visual indicator tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+
+fn spawn_worker(app: &mut App, load: Option<FoodKind>) -> Entity {
+    let mut behavior = AntBehavior::new(Position { x: 0.0, y: 0.0 }, 0.0);
+    if let Some(kind) = load {
+        behavior.carried_food = 5.0;
+        behavior.carried_kind = kind;
+    }
+    app.world_mut()
+        .spawn((
+            Ant,
+            behavior,
+            PhaseSpecificBehavior {
+                age_group: AntAgeGroup::Adult,
+            },
+            BaseColor(Color::BLACK),
+            Sprite {
+                color: Color::BLACK,
+                ..default()
+            },
+        ))
+        .id()
+}
+
+fn color(app: &App, entity: Entity) -> Srgba {
+    app.world().get::<Sprite>(entity).unwrap().color.to_srgba()
+}
+
+/// Color of the dot riding on `ant`, if it has one
+fn dot_color(app: &mut App, ant: Entity) -> Option<Color> {
+    let world = app.world_mut();
+    world
+        .query_filtered::<(&Parent, &Sprite), With<CarryIndicator>>()
+        .iter(world)
+        .find(|(parent, _)| parent.get() == ant)
+        .map(|(_, sprite)| sprite.color)
+}
+
+/// Protein and carbohydrate carriers are tinted apart from each other and from empty ants
+#[test]
+fn test_carried_food_kind_tints_the_ant() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<VisualEffectsSettings>();
+    app.add_systems(
+        Update,
+        (systems::age_visual_system, systems::carry_indicator_system),
+    );
+
+    let protein = spawn_worker(&mut app, Some(FoodKind::Protein));
+    let carbohydrate = spawn_worker(&mut app, Some(FoodKind::Carbohydrate));
+    let empty = spawn_worker(&mut app, None);
+    app.update();
+
+    assert_ne!(color(&app, protein), color(&app, carbohydrate));
+    assert_ne!(color(&app, protein), color(&app, empty));
+    assert_ne!(color(&app, carbohydrate), color(&app, empty));
+    assert_eq!(color(&app, empty), Color::BLACK.to_srgba());
+
+    assert_eq!(
+        dot_color(&mut app, protein),
+        Some(FoodKind::Protein.carry_color())
+    );
+    assert_eq!(
+        dot_color(&mut app, carbohydrate),
+        Some(FoodKind::Carbohydrate.carry_color())
+    );
+    assert_eq!(dot_color(&mut app, empty), None);
+
+    // Dropping the load clears both the tint and the dot
+    app.world_mut()
+        .get_mut::<AntBehavior>(protein)
+        .unwrap()
+        .carried_food = 0.0;
+    app.update();
+    app.update();
+    assert_eq!(color(&app, protein), Color::BLACK.to_srgba());
+    assert_eq!(dot_color(&mut app, protein), None);
+
+    // Switching the indicators off restores the base color
+    app.world_mut()
+        .resource_mut::<VisualEffectsSettings>()
+        .carry_indicators_enabled = false;
+    app.update();
+    app.update();
+    assert_eq!(color(&app, carbohydrate), Color::BLACK.to_srgba());
+    assert_eq!(dot_color(&mut app, carbohydrate), None);
+}
@@ -0,0 +1,109 @@
+//! Carrying capacity and starvation tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+fn population(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world.query::<&Lifecycle>().iter(world).count()
+}
+
+/// Overshooting capacity raises drain and deaths until the population recovers
+#[test]
+fn test_overshoot_brings_population_back_toward_capacity() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        500,
+    )));
+    app.insert_resource(ColonyFoodStore {
+        food: 50.0,
+        ..default()
+    }); // Enough for 10 ants
+    app.init_resource::<NestSpace>();
+    app.init_resource::<CarryingCapacity>();
+    app.add_systems(
+        Update,
+        (systems::starvation_system, systems::ant_lifecycle_system).chain(),
+    );
+
+    for _ in 0..60 {
+        app.world_mut().spawn(Lifecycle {
+            age: 0.0,
+            max_age: 10_000.0,
+            energy: 100.0,
+            max_energy: 100.0,
+        });
+    }
+
+    // First updates only establish the time delta
+    app.update();
+    app.update();
+
+    let capacity = app.world().resource::<CarryingCapacity>().capacity;
+    assert!((capacity - 10.0).abs() < 0.01, "Capacity was {}", capacity);
+    assert!(
+        app.world()
+            .resource::<CarryingCapacity>()
+            .energy_drain_multiplier
+            > 1.0
+    );
+
+    // Simulate a few minutes
+    for _ in 0..400 {
+        app.update();
+    }
+
+    let remaining = population(&mut app);
+    assert!(
+        remaining < 30,
+        "Overshoot should cause elevated deaths, {} ants remain",
+        remaining
+    );
+    assert!(
+        remaining >= 8,
+        "Population should settle near capacity rather than collapse, {} remain",
+        remaining
+    );
+}
+
+/// A colony under capacity suffers no starvation pressure
+#[test]
+fn test_population_under_capacity_is_not_culled() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        500,
+    )));
+    app.insert_resource(ColonyFoodStore {
+        food: 500.0,
+        ..default()
+    });
+    app.init_resource::<NestSpace>();
+    app.init_resource::<CarryingCapacity>();
+    app.add_systems(Update, systems::starvation_system);
+
+    for _ in 0..5 {
+        app.world_mut().spawn(Lifecycle {
+            age: 0.0,
+            max_age: 10_000.0,
+            energy: 100.0,
+            max_energy: 100.0,
+        });
+    }
+
+    for _ in 0..200 {
+        app.update();
+    }
+
+    assert_eq!(population(&mut app), 5);
+    assert_eq!(
+        app.world()
+            .resource::<CarryingCapacity>()
+            .energy_drain_multiplier,
+        1.0
+    );
+}
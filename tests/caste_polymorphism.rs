@@ -0,0 +1,166 @@
+//! Worker caste (minor/media/major) tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::localization::Localization;
+use ant_nest_simulator::strategy::BoxedStrategy;
+use ant_nest_simulator::systems;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::time::Duration;
+
+fn spawn_with_caste(world: &mut World, caste: CasteClass) -> Entity {
+    let entity = systems::spawn_worker_ant(
+        &mut world.commands(),
+        Position { x: 10.0, y: 2.0 },
+        Lifecycle {
+            age: 500.0, // Adult, whatever role it rolls
+            max_age: 1000.0,
+            energy: 100.0,
+            max_energy: 100.0,
+        },
+        caste,
+        BehaviorModifiers::default(),
+        &AntAiConfig::default(),
+        &mut SimulationRng::from_seed(1).0,
+    );
+    world.flush();
+    entity
+}
+
+/// Food picked up by a lone forager of the given caste standing on a food source
+fn food_carried_by(caste: CasteClass) -> f32 {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<AntAiConfig>();
+    app.init_resource::<PheromoneGrid>();
+    app.init_resource::<ColonyFoodStore>();
+    app.init_resource::<BoxedStrategy>();
+    app.add_systems(Update, systems::foraging_behavior_system);
+
+    let ant = spawn_with_caste(app.world_mut(), caste);
+    app.world_mut()
+        .entity_mut(ant)
+        .insert(SpecializedRole::Forager);
+    app.world_mut().spawn((
+        Position { x: 10.0, y: 2.0 },
+        FoodSource {
+            nutrition_value: 100.0,
+            max_nutrition: 100.0,
+            kind: FoodKind::Carbohydrate,
+        },
+    ));
+
+    app.update();
+    app.world().get::<AntBehavior>(ant).unwrap().carried_food
+}
+
+/// Majors render larger than minors
+#[test]
+fn test_majors_render_larger() {
+    let mut world = World::new();
+    let minor = spawn_with_caste(&mut world, CasteClass::Minor);
+    let major = spawn_with_caste(&mut world, CasteClass::Major);
+
+    let size = |entity| world.get::<Sprite>(entity).unwrap().custom_size.unwrap().x;
+    assert!(size(major) > size(minor));
+
+    // ...and walk slower
+    let speed = |entity| world.get::<AntBehavior>(entity).unwrap().speed;
+    assert!(speed(major) < speed(minor));
+}
+
+/// Majors carry more food per trip than minors
+#[test]
+fn test_majors_carry_more() {
+    let minor = food_carried_by(CasteClass::Minor);
+    let major = food_carried_by(CasteClass::Major);
+
+    assert!(minor > 0.0);
+    assert!(major > minor, "Major carried {}, minor {}", major, minor);
+}
+
+/// Majors beat invaders more reliably than minors under identical conditions
+#[test]
+fn test_majors_win_invader_fights_more_reliably() {
+    let invader_strength = 2.0;
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let mut wins = |caste: CasteClass| {
+        (0..1000)
+            .filter(|_| systems::resolve_fight(caste.combat_strength(), invader_strength, &mut rng))
+            .count()
+    };
+    let minor_wins = wins(CasteClass::Minor);
+    let major_wins = wins(CasteClass::Major);
+
+    assert!(
+        major_wins > minor_wins + 200,
+        "Majors won {} of 1000, minors {}",
+        major_wins,
+        minor_wins
+    );
+}
+
+/// Hatching castes follow the configured proportions
+#[test]
+fn test_caste_proportions_are_configurable() {
+    let config = CasteConfig {
+        minor_weight: 0.0,
+        media_weight: 0.0,
+        major_weight: 1.0,
+    };
+    let mut rng = StdRng::seed_from_u64(7);
+    assert!((0..100).all(|_| config.roll(&mut rng) == CasteClass::Major));
+}
+
+/// Colony statistics count each caste, and the statistics panel shows the counts
+#[test]
+fn test_statistics_count_castes() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<ColonyFoodStore>();
+    app.init_resource::<ColonyStatistics>();
+    app.init_resource::<UITheme>();
+    app.init_resource::<UserSettings>();
+    app.init_resource::<DisasterState>();
+    app.init_resource::<Localization>();
+    app.add_systems(
+        Update,
+        (
+            systems::colony_statistics_calculation_system,
+            systems::update_statistics_display,
+        )
+            .chain(),
+    );
+    app.world_mut()
+        .run_system_once(systems::setup_statistics_panel);
+    {
+        let world = app.world_mut();
+        for mut toggle in world.query::<&mut StatisticsToggle>().iter_mut(world) {
+            toggle.is_visible = true;
+        }
+    }
+
+    let world = app.world_mut();
+    for caste in [CasteClass::Minor, CasteClass::Minor, CasteClass::Major] {
+        spawn_with_caste(world, caste);
+    }
+    app.update();
+
+    let stats = app.world().resource::<ColonyStatistics>();
+    assert_eq!(
+        (stats.minor_count, stats.media_count, stats.major_count),
+        (2, 0, 1)
+    );
+    let world = app.world_mut();
+    assert!(world
+        .query::<&Text>()
+        .iter(world)
+        .any(|text| text.sections[0].value == "Castes: Minor 2 / Media 0 / Major 1"));
+}
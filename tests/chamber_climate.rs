@@ -0,0 +1,101 @@
+//! Chamber microclimate regulation tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+/// Nursery temperature after a cold snap with the given number of maintainers inside
+fn nursery_temperature_after_cold_snap(maintainers: usize) -> f32 {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<ChamberClimateConfig>();
+    let mut disaster_state = DisasterState::default();
+    disaster_state.start_disaster(DisasterType::ColdSnap, 1000.0);
+    app.insert_resource(disaster_state);
+    app.add_systems(
+        Update,
+        (
+            systems::disaster_effect_system,
+            systems::chamber_climate_system,
+        )
+            .chain(),
+    );
+
+    let nursery_position = Position { x: 0.0, y: -20.0 };
+    let nursery = app
+        .world_mut()
+        .spawn((
+            nursery_position.clone(),
+            Chamber {
+                chamber_type: ChamberType::Nursery,
+                radius: 6.0,
+                capacity: 8,
+                occupants: 0,
+                temperature: 25.0,
+            },
+        ))
+        .id();
+
+    // Soil surrounding the nursery
+    for x in -3..=3 {
+        for y in -8..=-2 {
+            app.world_mut().spawn((
+                Position {
+                    x: x as f32 * 4.0,
+                    y: y as f32 * 4.0,
+                },
+                SoilCell {
+                    moisture: 0.5,
+                    temperature: 20.0,
+                    nutrition: 0.5,
+                    compaction: 0.5,
+                },
+            ));
+        }
+    }
+
+    for _ in 0..maintainers {
+        app.world_mut().spawn((
+            nursery_position.clone(),
+            Ant,
+            SpecializedRole::NestMaintainer,
+        ));
+    }
+
+    for _ in 0..300 {
+        app.update();
+    }
+
+    app.world().get::<Chamber>(nursery).unwrap().temperature
+}
+
+/// Maintainers huddling in the nursery slow its cooling during a cold snap
+#[test]
+fn test_maintained_nursery_cools_more_slowly() {
+    let unattended = nursery_temperature_after_cold_snap(0);
+    let maintained = nursery_temperature_after_cold_snap(4);
+
+    assert!(unattended < 25.0, "Unattended nursery should cool");
+    assert!(
+        maintained > unattended + 1.0,
+        "Maintained nursery at {:.1}°C should stay warmer than unattended at {:.1}°C",
+        maintained,
+        unattended
+    );
+}
+
+/// Brood incubates fastest inside the optimal band
+#[test]
+fn test_incubation_speed_peaks_in_optimal_band() {
+    let config = ChamberClimateConfig::default();
+
+    assert_eq!(config.incubation_speed(25.0), 1.0);
+    assert!(config.incubation_speed(15.0) < 1.0);
+    assert!(config.incubation_speed(5.0) < config.incubation_speed(15.0));
+    assert!(config.incubation_speed(35.0) < 1.0);
+}
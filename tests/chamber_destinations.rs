@@ -0,0 +1,92 @@
+//! Chamber-type-aware destination tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::strategy::BoxedStrategy;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+fn destination_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<AntAiConfig>();
+    app.init_resource::<PheromoneGrid>();
+    app.init_resource::<ColonyFoodStore>();
+    app.init_resource::<BoxedStrategy>();
+    app.add_systems(
+        Update,
+        (
+            systems::nest_worker_positioning_system,
+            systems::foraging_behavior_system,
+        ),
+    );
+    app
+}
+
+fn spawn_chamber(app: &mut App, chamber_type: ChamberType, x: f32, y: f32) {
+    app.world_mut().spawn((
+        Position { x, y },
+        Chamber {
+            chamber_type,
+            radius: 6.0,
+            capacity: 8,
+            occupants: 0,
+            temperature: 25.0,
+        },
+    ));
+}
+
+fn spawn_ant(app: &mut App, role: SpecializedRole, state: AntState) -> Entity {
+    let mut behavior = AntBehavior::new(Position { x: 0.0, y: -10.0 }, 0.0);
+    behavior.state = state;
+    behavior.carried_food = 1.0;
+    app.world_mut()
+        .spawn((Position { x: 20.0, y: -10.0 }, Ant, behavior, role))
+        .id()
+}
+
+fn target(app: &App, ant: Entity) -> (f32, f32) {
+    let target = app
+        .world()
+        .get::<AntBehavior>(ant)
+        .unwrap()
+        .target_position
+        .clone()
+        .expect("Ant should have a destination");
+    (target.x, target.y)
+}
+
+/// A nursery worker heads for the nursery even when another chamber is closer
+#[test]
+fn test_nursery_worker_targets_nursery() {
+    let mut app = destination_app();
+    spawn_chamber(&mut app, ChamberType::Worker, 5.0, -10.0);
+    spawn_chamber(&mut app, ChamberType::Nursery, 40.0, -30.0);
+    spawn_chamber(&mut app, ChamberType::Nursery, -80.0, -30.0);
+    let nurse = spawn_ant(
+        &mut app,
+        SpecializedRole::NurseryWorker,
+        AntState::Returning,
+    );
+
+    app.update();
+    assert_eq!(target(&app, nurse), (40.0, -30.0));
+}
+
+/// Returning foragers deliver to food storage, or home while there is none
+#[test]
+fn test_forager_returns_to_storage_or_home() {
+    let mut app = destination_app();
+    let forager = spawn_ant(&mut app, SpecializedRole::Forager, AntState::Returning);
+    app.update();
+    assert_eq!(target(&app, forager), (0.0, -10.0));
+
+    spawn_chamber(&mut app, ChamberType::Worker, 5.0, -10.0);
+    spawn_chamber(&mut app, ChamberType::FoodStorage, 30.0, -20.0);
+    app.update();
+    assert_eq!(target(&app, forager), (30.0, -20.0));
+}
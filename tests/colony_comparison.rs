@@ -0,0 +1,182 @@
+//! Multi-colony comparison panel tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::localization::Localization;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+
+fn comparison_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<ColonyComparison>();
+    app.init_resource::<SimulationTime>();
+    app.init_resource::<DaughterColonies>();
+    app.add_systems(
+        Update,
+        (
+            systems::daughter_colony_system,
+            systems::colony_comparison_system,
+        )
+            .chain(),
+    );
+    app
+}
+
+fn spawn_colony(app: &mut App, colony: ColonyId, workers: usize) -> Vec<Entity> {
+    (0..workers)
+        .map(|index| {
+            app.world_mut()
+                .spawn((
+                    Position {
+                        x: index as f32,
+                        y: 0.0,
+                    },
+                    Ant,
+                    colony,
+                ))
+                .id()
+        })
+        .collect()
+}
+
+fn order(app: &App) -> Vec<ColonyId> {
+    app.world()
+        .resource::<ColonyComparison>()
+        .rows
+        .iter()
+        .map(|row| row.colony)
+        .collect()
+}
+
+/// Each colony reports its own population, and rows follow the chosen metric
+#[test]
+fn test_rows_report_each_colony_and_sort_by_metric() {
+    let mut app = comparison_app();
+    spawn_colony(&mut app, ColonyId(1), 3);
+    app.world_mut()
+        .resource_mut::<SimulationTime>()
+        .elapsed_seconds = 480.0;
+    spawn_colony(&mut app, ColonyId(2), 25);
+    // Colony 1 was around two days before colony 2 appeared
+    app.world_mut().resource_mut::<ColonyComparison>().rows = vec![ColonyComparisonRow {
+        colony: ColonyId(1),
+        population: 3,
+        phase: DevelopmentPhase::QueenFounding,
+        food: 0.0,
+        founded_at: 0.0,
+        age_days: 2.0,
+        extinct: false,
+    }];
+    app.update();
+
+    let comparison = app.world().resource::<ColonyComparison>();
+    let small = comparison.row(ColonyId(1)).unwrap();
+    let large = comparison.row(ColonyId(2)).unwrap();
+    assert_eq!(small.population, 3);
+    assert_eq!(large.population, 25);
+    assert_eq!(small.phase, DevelopmentPhase::QueenFounding);
+    assert_eq!(large.phase, DevelopmentPhase::ColonyExpansion);
+    assert_eq!(order(&app), vec![ColonyId(2), ColonyId(1)]);
+
+    let mut comparison = app.world_mut().resource_mut::<ColonyComparison>();
+    comparison.sort_by = ComparisonMetric::Age;
+    comparison.sort();
+    assert_eq!(order(&app), vec![ColonyId(1), ColonyId(2)]);
+}
+
+/// A colony that dies out keeps a greyed-out row, with its last figures, below the living ones
+#[test]
+fn test_extinct_colony_sinks_to_bottom() {
+    let mut app = comparison_app();
+    let large = spawn_colony(&mut app, ColonyId(1), 10);
+    spawn_colony(&mut app, ColonyId(2), 4);
+    app.update();
+    assert_eq!(order(&app), vec![ColonyId(1), ColonyId(2)]);
+
+    for ant in large {
+        app.world_mut().despawn(ant);
+    }
+    app.update();
+
+    let comparison = app.world().resource::<ColonyComparison>();
+    let extinct = comparison.row(ColonyId(1)).unwrap();
+    assert!(extinct.extinct);
+    assert_eq!(extinct.population, 10);
+    assert!(!comparison.row(ColonyId(2)).unwrap().extinct);
+    assert_eq!(order(&app), vec![ColonyId(2), ColonyId(1)]);
+    assert!(
+        systems::comparison_row_label(extinct, &Localization::english()).ends_with("(extinct)")
+    );
+}
+
+/// Daughter colonies report their own store and a phase that does not fall back
+#[test]
+fn test_daughter_colony_reports_its_store_and_phase() {
+    let mut app = comparison_app();
+    let workers = spawn_colony(&mut app, ColonyId(2), 25);
+    app.world_mut()
+        .resource_mut::<DaughterColonies>()
+        .deposit(ColonyId(2), 30.0);
+    app.update();
+
+    let row = app
+        .world()
+        .resource::<ColonyComparison>()
+        .row(ColonyId(2))
+        .unwrap()
+        .clone();
+    assert_eq!(row.food, 30.0);
+    assert_eq!(row.phase, DevelopmentPhase::ColonyExpansion);
+
+    for ant in workers.into_iter().skip(3) {
+        app.world_mut().despawn(ant);
+    }
+    app.update();
+
+    let comparison = app.world().resource::<ColonyComparison>();
+    let row = comparison.row(ColonyId(2)).unwrap();
+    assert_eq!(row.population, 3);
+    assert_eq!(row.phase, DevelopmentPhase::ColonyExpansion);
+}
+
+/// Ages creeping on do not rebuild the panel until a shown figure changes
+#[test]
+fn test_panel_text_only_rebuilt_when_labels_change() {
+    let mut app = comparison_app();
+    app.init_resource::<Localization>();
+    app.add_systems(
+        Update,
+        systems::update_colony_comparison_panel.after(systems::colony_comparison_system),
+    );
+    let text = app
+        .world_mut()
+        .spawn((
+            TextBundle::from_section("", TextStyle::default()),
+            ColonyComparisonText,
+            DisplayedText::default(),
+        ))
+        .id();
+    app.world_mut().resource_mut::<ColonyComparison>().visible = true;
+    spawn_colony(&mut app, ColonyId(1), 3);
+    app.update();
+
+    let sections = |app: &App| app.world().get::<Text>(text).unwrap().sections.len();
+    assert_eq!(sections(&app), 2);
+
+    // Mark the text so a rebuild would show
+    app.world_mut().get_mut::<Text>(text).unwrap().sections[0].value = "marker".into();
+    app.world_mut()
+        .resource_mut::<SimulationTime>()
+        .elapsed_seconds += 1.0;
+    app.update();
+    let shown = |app: &App| {
+        app.world().get::<Text>(text).unwrap().sections[0]
+            .value
+            .clone()
+    };
+    assert_eq!(shown(&app), "marker");
+
+    spawn_colony(&mut app, ColonyId(1), 1);
+    app.update();
+    assert_ne!(shown(&app), "marker");
+}
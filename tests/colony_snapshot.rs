@@ -0,0 +1,100 @@
+//! Colony snapshot metrics API tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::snapshot::colony_snapshot;
+use bevy::prelude::*;
+
+fn spawn_worker(world: &mut World, state: AntState, energy: f32) -> Entity {
+    let home = Position { x: 0.0, y: 0.0 };
+    let mut behavior = AntBehavior::new(home.clone(), 0.0);
+    behavior.state = state;
+    world
+        .spawn((
+            home,
+            Ant,
+            behavior,
+            Lifecycle {
+                age: 0.0,
+                max_age: 1000.0,
+                energy,
+                max_energy: 100.0,
+            },
+        ))
+        .id()
+}
+
+/// A known small world is reported with exact counts and totals
+#[test]
+fn test_snapshot_of_small_world_reports_exact_counts() {
+    let mut world = World::new();
+    world.insert_resource(ColonyFoodStore {
+        food: 42.0,
+        protein: 8.0,
+    });
+    let mut disaster_state = DisasterState::default();
+    disaster_state.start_disaster(DisasterType::Rain, 10.0);
+    world.insert_resource(disaster_state);
+    world.insert_resource(ColonyDevelopmentPhase::default());
+    world.insert_resource(SimulationTime {
+        elapsed_seconds: 500.0,
+        day_length_seconds: 240.0,
+        current_day: 2,
+    });
+
+    spawn_worker(&mut world, AntState::Foraging, 80.0);
+    spawn_worker(&mut world, AntState::Foraging, 60.0);
+    spawn_worker(&mut world, AntState::Returning, 40.0);
+    let scout = spawn_worker(&mut world, AntState::Resting, 20.0);
+    world.entity_mut(scout).insert(Scout);
+    world.spawn((
+        Position { x: 0.0, y: 0.0 },
+        Ant,
+        Queen {
+            founding_state: FoundingState::Established,
+        },
+    ));
+    for nutrition in [10.0, 15.0] {
+        world.spawn((
+            Position { x: 5.0, y: 2.0 },
+            FoodSource {
+                nutrition_value: nutrition,
+                max_nutrition: 20.0,
+                kind: FoodKind::Carbohydrate,
+            },
+        ));
+    }
+
+    let snapshot = colony_snapshot(&world);
+
+    assert_eq!(snapshot.population, 5);
+    assert_eq!(snapshot.worker_count, 4);
+    assert_eq!(snapshot.queen_count, 1);
+    assert_eq!(snapshot.scout_count, 1);
+    assert_eq!(snapshot.foraging_count, 2);
+    assert_eq!(snapshot.returning_count, 1);
+    assert_eq!(snapshot.resting_count, 1);
+    assert_eq!(snapshot.average_energy, 50.0);
+    assert_eq!(snapshot.colony_food, 42.0);
+    assert_eq!(snapshot.colony_protein, 8.0);
+    assert_eq!(snapshot.food_source_count, 2);
+    assert_eq!(snapshot.food_source_nutrition, 25.0);
+    assert_eq!(snapshot.active_disasters, vec!["Rain".to_string()]);
+    assert_eq!(
+        snapshot.current_phase,
+        Some(DevelopmentPhase::QueenFounding)
+    );
+    assert_eq!(snapshot.simulated_day, 2);
+    assert_eq!(snapshot.simulated_seconds, 500.0);
+}
+
+/// An empty world yields an empty snapshot rather than failing
+#[test]
+fn test_snapshot_of_empty_world() {
+    let world = World::new();
+    let snapshot = colony_snapshot(&world);
+
+    assert_eq!(snapshot.population, 0);
+    assert_eq!(snapshot.average_energy, 0.0);
+    assert!(snapshot.active_disasters.is_empty());
+    assert_eq!(snapshot.current_phase, None);
+}
@@ -0,0 +1,77 @@
+//! Overlay compositing tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+
+fn disaster_overlay(disaster_type: DisasterType, color: Color) -> OverlayDescriptor {
+    OverlayDescriptor {
+        source: OverlaySource::Disaster(disaster_type),
+        color,
+    }
+}
+
+/// Two overlays average their colors by alpha and stack their opacity
+#[test]
+fn test_two_overlays_compose_to_blended_color() {
+    let config = ColorOverlayConfig {
+        active_overlays: vec![
+            disaster_overlay(DisasterType::Rain, Color::srgba(1.0, 0.0, 0.0, 0.2)),
+            OverlayDescriptor {
+                source: OverlaySource::DayNight,
+                color: Color::srgba(0.0, 0.0, 1.0, 0.1),
+            },
+        ],
+        ..default()
+    };
+
+    let [r, g, b, a] = config.compose().unwrap().to_srgba().to_f32_array();
+    assert!((r - 2.0 / 3.0).abs() < 1e-4);
+    assert_eq!(g, 0.0);
+    assert!((b - 1.0 / 3.0).abs() < 1e-4);
+    assert!(
+        (a - 0.28).abs() < 1e-4,
+        "0.2 over 0.1 should give 0.28, got {}",
+        a
+    );
+}
+
+/// However many overlays are active, the composed alpha stays under the cap
+#[test]
+fn test_composed_alpha_never_exceeds_cap() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(ColorOverlayConfig {
+        max_alpha: 0.35,
+        ..default()
+    });
+    app.insert_resource(SimulationTime {
+        elapsed_seconds: 0.0, // Midnight, darkest night tint
+        ..default()
+    });
+    let mut disaster_state = DisasterState::default();
+    for disaster_type in DisasterType::ALL {
+        disaster_state.start_disaster(disaster_type, 100.0);
+    }
+    app.insert_resource(disaster_state);
+    app.add_systems(Update, systems::overlay_compositor_system);
+
+    app.update();
+
+    let config = app.world().resource::<ColorOverlayConfig>();
+    assert_eq!(config.active_overlays.len(), DisasterType::ALL.len() + 1);
+    let alpha = config.composed_color.unwrap().alpha();
+    assert!(
+        alpha <= 0.35 + f32::EPSILON,
+        "Composed alpha {} over cap",
+        alpha
+    );
+}
+
+/// At noon with nothing going on there is no overlay at all
+#[test]
+fn test_no_overlay_on_a_quiet_afternoon() {
+    let config = ColorOverlayConfig::default();
+    assert!(config.night_overlay(0.5).is_none());
+    assert!(config.compose().is_none());
+}
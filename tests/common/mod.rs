@@ -0,0 +1,15 @@
+//! Helpers shared by the integration tests
+
+use std::path::PathBuf;
+
+/// A fresh, empty save directory under the system temp dir, unique to this test process
+pub fn temp_save_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "ant-nest-simulator-{}-{}",
+        name,
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
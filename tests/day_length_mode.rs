@@ -0,0 +1,51 @@
+//! Tick-based vs seconds-based day length tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::{step, systems};
+use bevy::prelude::*;
+
+fn clock_app(mode: DayLengthMode) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<SimulationTime>();
+    app.insert_resource(mode);
+    app.add_systems(Update, systems::simulation_time_system);
+    app
+}
+
+fn days_elapsed(app: &App) -> f32 {
+    app.world().resource::<SimulationTime>().days_elapsed()
+}
+
+/// Each step counts as one tick however long it lasts, so N steps advance N / ticks-per-day days
+#[test]
+fn test_ticks_advance_exact_days() {
+    let mut app = clock_app(DayLengthMode::Ticks(50));
+    let start = days_elapsed(&app);
+
+    // Uneven frame lengths, as a wall clock would give
+    for i in 0..150 {
+        step(&mut app, [0.01, 0.5, 3.0][i % 3]);
+    }
+
+    assert!((days_elapsed(&app) - start - 3.0).abs() < 1e-4);
+    assert_eq!(app.world().resource::<SimulationTime>().current_day, 4);
+
+    // A paused frame is not a tick
+    step(&mut app, 0.0);
+    assert!((days_elapsed(&app) - start - 3.0).abs() < 1e-4);
+}
+
+/// In the default mode the same steps advance by the seconds that passed instead
+#[test]
+fn test_seconds_mode_follows_frame_length() {
+    let mut app = clock_app(DayLengthMode::default());
+    let start = days_elapsed(&app);
+    let day_length = app.world().resource::<SimulationTime>().day_length_seconds;
+
+    for _ in 0..150 {
+        step(&mut app, 0.5);
+    }
+
+    assert!((days_elapsed(&app) - start - 75.0 / day_length).abs() < 1e-4);
+}
@@ -0,0 +1,105 @@
+//! Death behavior tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+fn death_app(death_behavior: DeathBehavior) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<CarryingCapacity>();
+    app.insert_resource(death_behavior);
+    app.add_systems(Update, systems::ant_lifecycle_system);
+
+    app.world_mut().spawn((
+        Ant,
+        Position { x: 3.0, y: -5.0 },
+        Lifecycle {
+            age: 100.0,
+            max_age: 10.0, // Dies of old age on the first update
+            energy: 100.0,
+            max_energy: 100.0,
+        },
+    ));
+    app
+}
+
+fn corpse_count(app: &mut App) -> usize {
+    app.world_mut().query::<&Corpse>().iter(app.world()).count()
+}
+
+/// Under `Despawn` the dead ant vanishes without leaving a corpse
+#[test]
+fn test_despawn_leaves_no_corpse() {
+    let mut app = death_app(DeathBehavior::Despawn);
+    app.update();
+    app.update();
+
+    assert_eq!(app.world_mut().query::<&Ant>().iter(app.world()).count(), 0);
+    assert_eq!(corpse_count(&mut app), 0);
+}
+
+/// Under `Corpse` exactly one corpse appears where the ant died
+#[test]
+fn test_corpse_left_on_death() {
+    let mut app = death_app(DeathBehavior::Corpse);
+    app.update();
+    app.update();
+
+    assert_eq!(app.world_mut().query::<&Ant>().iter(app.world()).count(), 0);
+    assert_eq!(corpse_count(&mut app), 1);
+    let position = app
+        .world_mut()
+        .query_filtered::<&Position, With<Corpse>>()
+        .single(app.world())
+        .clone();
+    assert_eq!((position.x, position.y), (3.0, -5.0));
+}
+
+/// Ants culled by overshooting the carrying capacity leave corpses too
+#[test]
+fn test_overshoot_deaths_leave_corpses() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(ColonyFoodStore {
+        food: 0.0,
+        protein: 0.0,
+    });
+    app.init_resource::<NestSpace>();
+    app.insert_resource(CarryingCapacity {
+        overshoot_death_rate: 1000.0, // Every ant over capacity dies at once
+        ..default()
+    });
+    app.insert_resource(DeathBehavior::Corpse);
+    app.add_systems(Update, systems::starvation_system);
+    for index in 0..50 {
+        app.world_mut().spawn((
+            Ant,
+            Position {
+                x: index as f32,
+                y: -5.0,
+            },
+            Lifecycle {
+                age: 0.0,
+                max_age: 10_000.0,
+                energy: 100.0,
+                max_energy: 100.0,
+            },
+        ));
+    }
+
+    app.update();
+    app.update();
+
+    let survivors = app.world_mut().query::<&Ant>().iter(app.world()).count();
+    assert!(survivors < 50);
+    assert_eq!(corpse_count(&mut app), 50 - survivors);
+}
@@ -0,0 +1,42 @@
+//! Moisture-dependent digging speed tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems::dig_speed_factor;
+
+/// Damp soil digs faster than both bone-dry and waterlogged soil, in every layer
+#[test]
+fn test_moist_soil_digs_fastest() {
+    for layer in [DepthLayer::Topsoil, DepthLayer::Subsoil, DepthLayer::Clay] {
+        let hardness = layer.hardness();
+        let dry = dig_speed_factor(hardness, 0.0);
+        let moist = dig_speed_factor(hardness, 0.4);
+        let saturated = dig_speed_factor(hardness, 1.0);
+
+        assert!(moist > dry, "{:?}: moist {} vs dry {}", layer, moist, dry);
+        assert!(
+            moist > saturated,
+            "{:?}: moist {} vs saturated {}",
+            layer,
+            moist,
+            saturated
+        );
+        for moisture in [0.1, 0.3, 0.5, 0.7, 0.9] {
+            assert!(dig_speed_factor(hardness, moisture) <= moist);
+        }
+    }
+}
+
+/// Harder layers dig slower at the same moisture, and the factor stays within 0-1
+#[test]
+fn test_hardness_slows_digging() {
+    let topsoil = dig_speed_factor(DepthLayer::Topsoil.hardness(), 0.4);
+    let clay = dig_speed_factor(DepthLayer::Clay.hardness(), 0.4);
+    assert!(topsoil > clay);
+
+    for hardness in [0.0, 0.5, 1.0] {
+        for moisture in [0.0, 0.4, 1.0] {
+            let factor = dig_speed_factor(hardness, moisture);
+            assert!((0.0..=1.0).contains(&factor), "{}", factor);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Automatic disaster forecast tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::localization::Localization;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+
+fn forecast_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(AutoDisasterConfig {
+        enabled: true,
+        min_interval: 100.0,
+        max_interval: 200.0,
+        forecast_lead: 60.0,
+    });
+    app.init_resource::<SimulationTime>();
+    app.init_resource::<Forecast>();
+    app.init_resource::<DisasterState>();
+    app.add_event::<systems::AutoDisasterEvent>();
+    app.add_systems(Update, systems::auto_disaster_system);
+    app
+}
+
+fn advance(app: &mut App, seconds: f32) {
+    app.world_mut()
+        .resource_mut::<SimulationTime>()
+        .elapsed_seconds += seconds;
+    app.update();
+}
+
+/// The disaster that strikes is the forecast one, at the forecast time
+#[test]
+fn test_forecast_matches_triggered_disaster() {
+    let mut app = forecast_app();
+    let mut forecasts = Vec::new();
+    let mut fired = Vec::new();
+
+    for _ in 0..2000 {
+        advance(&mut app, 1.0);
+        if let Some(upcoming) = app.world().resource::<Forecast>().upcoming {
+            if forecasts.last() != Some(&upcoming) {
+                forecasts.push(upcoming);
+            }
+        }
+        fired.extend(
+            app.world_mut()
+                .resource_mut::<Events<systems::AutoDisasterEvent>>()
+                .drain(),
+        );
+    }
+
+    assert!(fired.len() >= 3, "only {} disasters struck", fired.len());
+    for (forecast, event) in forecasts.iter().zip(&fired) {
+        assert_eq!(forecast.disaster_type, event.disaster_type);
+        assert_eq!(forecast.strikes_at, event.strikes_at);
+        assert!(event.fired_at >= event.strikes_at && event.fired_at < event.strikes_at + 1.0);
+    }
+}
+
+/// The forecast is only announced within the lead time and reads in days
+#[test]
+fn test_forecast_announced_within_lead_time() {
+    let mut app = forecast_app();
+    advance(&mut app, 0.0);
+    let scheduled_at = app.world().resource::<Forecast>().scheduled_at.unwrap();
+    let start = app.world().resource::<SimulationTime>().elapsed_seconds;
+    assert!(app.world().resource::<Forecast>().upcoming.is_none());
+
+    advance(&mut app, scheduled_at - 60.0 - start);
+    let forecast = app.world().resource::<Forecast>().clone();
+    assert!(forecast.upcoming.is_some());
+
+    let text = systems::forecast_text(
+        &forecast,
+        app.world().resource::<SimulationTime>(),
+        &Localization::english(),
+    )
+    .unwrap();
+    assert!(text.ends_with("incoming in ~1 day"), "{}", text);
+}
@@ -0,0 +1,94 @@
+//! Disaster visual vs effect intensity tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use ant_nest_simulator::systems::particle::ParticleConfig;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+/// Particles spawned and soil moisture left after 5 seconds of rain at the given intensities
+fn rain_for_five_seconds(visual_intensity: f32, effect_intensity: f32) -> (usize, f32) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(ParticleConfig {
+        max_particles: 100_000,
+        ..default()
+    });
+    app.init_resource::<VisualEffectsSettings>();
+    let mut disaster_state = DisasterState {
+        visual_intensity,
+        effect_intensity,
+        ..default()
+    };
+    disaster_state.start_disaster(DisasterType::Rain, 1000.0);
+    app.insert_resource(disaster_state);
+    app.add_systems(
+        Update,
+        (
+            systems::particle_spawner_system,
+            systems::disaster_effect_system,
+        ),
+    );
+
+    let soil = app
+        .world_mut()
+        .spawn(SoilCell {
+            moisture: 0.2,
+            temperature: 20.0,
+            nutrition: 0.5,
+            compaction: 0.0,
+        })
+        .id();
+
+    for _ in 0..50 {
+        app.update();
+    }
+
+    let world = app.world_mut();
+    let particles = world.query::<&Particle>().iter(world).count();
+    (particles, world.get::<SoilCell>(soil).unwrap().moisture)
+}
+
+/// Dimming only the visuals spawns fewer particles but rains on the soil just as hard
+#[test]
+fn test_visual_intensity_leaves_effects_alone() {
+    let (full_particles, full_moisture) = rain_for_five_seconds(1.0, 1.0);
+    let (dim_particles, dim_moisture) = rain_for_five_seconds(0.25, 1.0);
+
+    assert!(full_particles > 0);
+    assert!(
+        (dim_particles as f32) < full_particles as f32 * 0.5,
+        "dimmed {} vs full {}",
+        dim_particles,
+        full_particles
+    );
+    assert!(full_moisture > 0.2, "Rain should wet the soil");
+    assert!((dim_moisture - full_moisture).abs() < 1e-5);
+}
+
+/// Softening only the effect keeps the particles but wets the soil less
+#[test]
+fn test_effect_intensity_leaves_visuals_alone() {
+    let (full_particles, full_moisture) = rain_for_five_seconds(1.0, 1.0);
+    let (soft_particles, soft_moisture) = rain_for_five_seconds(1.0, 0.5);
+
+    assert!(soft_particles as f32 > full_particles as f32 * 0.7);
+    assert!(soft_moisture < full_moisture);
+    assert!((soft_moisture - 0.2 - (full_moisture - 0.2) * 0.5).abs() < 1e-3);
+
+    // The defaults keep both intensities linked at 1.0
+    let mut linked = DisasterState::default();
+    assert_eq!(
+        (linked.visual_intensity, linked.effect_intensity),
+        (1.0, 1.0)
+    );
+    linked.set_intensity(0.5);
+    assert_eq!(
+        (linked.visual_intensity, linked.effect_intensity),
+        (0.5, 0.5)
+    );
+}
@@ -0,0 +1,143 @@
+//! Disaster combination effect tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::localization::Localization;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+/// Soil state after running the given disasters for a fixed number of ticks
+fn soil_after(disasters: &[DisasterType]) -> SoilCell {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    let mut disaster_state = DisasterState::default();
+    for disaster_type in disasters {
+        disaster_state.start_disaster(*disaster_type, 1000.0);
+    }
+    app.insert_resource(disaster_state);
+    app.add_systems(Update, systems::disaster_effect_system);
+
+    let soil = app
+        .world_mut()
+        .spawn(SoilCell {
+            moisture: 0.5,
+            temperature: 20.0,
+            nutrition: 0.5,
+            compaction: 0.5,
+        })
+        .id();
+
+    for _ in 0..50 {
+        app.update();
+    }
+
+    app.world().get::<SoilCell>(soil).unwrap().clone()
+}
+
+/// Rain and a cold snap together cool the soil more than either alone
+#[test]
+fn test_rain_and_cold_snap_drive_temperature_lower() {
+    let rain = soil_after(&[DisasterType::Rain]).temperature;
+    let cold = soil_after(&[DisasterType::ColdSnap]).temperature;
+    let combined = soil_after(&[DisasterType::Rain, DisasterType::ColdSnap]).temperature;
+
+    assert!(combined < rain, "{} should be below {}", combined, rain);
+    assert!(combined < cold, "{} should be below {}", combined, cold);
+}
+
+/// Rain and drought partly cancel, leaving a smaller moisture change than rain alone
+#[test]
+fn test_rain_and_drought_partially_cancel_moisture() {
+    let rain_change = soil_after(&[DisasterType::Rain]).moisture - 0.5;
+    let combined_change = soil_after(&[DisasterType::Rain, DisasterType::Drought]).moisture - 0.5;
+
+    assert!(rain_change > 0.0);
+    assert!(combined_change.abs() < rain_change.abs());
+}
+
+/// A combination rule leaves the effects of other active disasters unscaled
+#[test]
+fn test_interaction_scales_only_its_own_disasters() {
+    let mut pair = DisasterState::default();
+    pair.start_disaster(DisasterType::Heatwave, 30.0);
+    pair.start_disaster(DisasterType::Drought, 30.0);
+    let mut invasion = DisasterState::default();
+    invasion.start_disaster(DisasterType::InvasiveSpecies, 30.0);
+    let mut all = pair.clone();
+    all.start_disaster(DisasterType::InvasiveSpecies, 30.0);
+
+    let expected = systems::combined_disaster_effect(&pair).food_decay
+        + systems::combined_disaster_effect(&invasion).food_decay;
+    let combined = systems::combined_disaster_effect(&all).food_decay;
+    assert!(
+        (combined - expected).abs() < 1e-5,
+        "{} should be {}",
+        combined,
+        expected
+    );
+}
+
+/// The active disasters panel text calls out combined effects
+#[test]
+fn test_panel_text_notes_combined_effect() {
+    let mut disaster_state = DisasterState::default();
+    disaster_state.start_disaster(DisasterType::Heatwave, 30.0);
+    let localization = Localization::default();
+    assert!(
+        !systems::active_disasters_text(&disaster_state, &localization).contains("Combined effect")
+    );
+
+    disaster_state.start_disaster(DisasterType::Drought, 30.0);
+    let text = systems::active_disasters_text(&disaster_state, &localization);
+    assert!(text.contains("Heatwave"));
+    assert!(text.contains("Drought"));
+    assert!(text.contains("Combined effect"));
+}
+
+/// Pressing a disaster control button starts its disaster and lights its indicator
+#[test]
+fn test_control_button_triggers_disaster() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<DisasterState>();
+    app.init_resource::<UITheme>();
+    app.add_systems(
+        Update,
+        (
+            systems::handle_disaster_control_interactions,
+            systems::update_disaster_status_indicators,
+        )
+            .chain(),
+    );
+    app.world_mut().spawn((
+        Button,
+        Interaction::Pressed,
+        BackgroundColor::default(),
+        DisasterControlButton {
+            disaster_type: DisasterType::Drought,
+        },
+    ));
+    let indicator = app
+        .world_mut()
+        .spawn((
+            BackgroundColor::default(),
+            DisasterStatusIndicator {
+                disaster_type: DisasterType::Drought,
+            },
+        ))
+        .id();
+    app.update();
+
+    let disaster_state = app.world().resource::<DisasterState>();
+    assert!(disaster_state.is_active(DisasterType::Drought));
+    assert!(!disaster_state.is_active(DisasterType::Rain));
+    let ui_theme = app.world().resource::<UITheme>();
+    assert_eq!(
+        app.world().get::<BackgroundColor>(indicator).unwrap().0,
+        ui_theme.colors.action_danger
+    );
+}
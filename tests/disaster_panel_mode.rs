@@ -0,0 +1,102 @@
+//! Compact vs detailed active disasters panel tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::localization::Localization;
+use ant_nest_simulator::systems;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+
+fn disaster_panel_app(mode: DisasterPanelMode) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<UITheme>();
+    app.init_resource::<Localization>();
+    app.insert_resource(UserSettings {
+        disaster_panel_mode: mode,
+        ..default()
+    });
+    let mut disaster_state = DisasterState::default();
+    disaster_state.start_disaster(DisasterType::Rain, 30.0);
+    disaster_state.start_disaster(DisasterType::ColdSnap, 25.0);
+    app.insert_resource(disaster_state);
+    app.add_systems(Update, systems::update_active_disasters_panel);
+    app.world_mut()
+        .run_system_once(systems::setup_active_disasters_panel);
+    app
+}
+
+/// Active disaster icons in display order, and whether each has child nodes
+fn icons(app: &mut App) -> Vec<(DisasterType, bool)> {
+    let world = app.world_mut();
+    let mut icons: Vec<_> = world
+        .query::<(&DisasterIcon, Option<&Children>)>()
+        .iter(world)
+        .map(|(icon, children)| (icon.disaster_type, children.is_some()))
+        .collect();
+    icons.sort_by_key(|(disaster_type, _)| *disaster_type as u8);
+    icons
+}
+
+fn panel_text(app: &mut App) -> (String, Display) {
+    let world = app.world_mut();
+    let (text, style) = world
+        .query_filtered::<(&Text, &Style), With<ActiveDisastersText>>()
+        .single(world);
+    (text.sections[0].value.clone(), style.display)
+}
+
+/// Compact mode shows one bare icon per active disaster and hides the labeled list
+#[test]
+fn test_compact_mode_renders_one_icon_per_disaster() {
+    let mut app = disaster_panel_app(DisasterPanelMode::Compact);
+    app.update();
+    app.update();
+
+    let icon_types: Vec<DisasterType> = icons(&mut app)
+        .into_iter()
+        .map(|(disaster_type, has_children)| {
+            assert!(!has_children, "Icons carry no label text");
+            disaster_type
+        })
+        .collect();
+    assert_eq!(icon_types, vec![DisasterType::Rain, DisasterType::ColdSnap]);
+
+    let (text, display) = panel_text(&mut app);
+    assert!(text.is_empty());
+    assert_eq!(display, Display::None);
+
+    // An ended disaster loses its icon
+    app.world_mut()
+        .resource_mut::<DisasterState>()
+        .active_disasters
+        .remove(&DisasterType::Rain);
+    app.update();
+    assert_eq!(icons(&mut app).len(), 1);
+}
+
+/// Detailed mode, the default, keeps the labeled list and draws no icons
+#[test]
+fn test_detailed_mode_is_default_and_labeled() {
+    assert_eq!(
+        UserSettings::default().disaster_panel_mode,
+        DisasterPanelMode::Detailed
+    );
+
+    let mut app = disaster_panel_app(DisasterPanelMode::Detailed);
+    app.update();
+    assert!(icons(&mut app).is_empty());
+    let (text, display) = panel_text(&mut app);
+    assert!(
+        text.contains("Rain") && text.contains("Cold Snap"),
+        "{}",
+        text
+    );
+    assert_eq!(display, Display::Flex);
+
+    // Switching modes in the settings re-renders the panel
+    app.world_mut()
+        .resource_mut::<UserSettings>()
+        .disaster_panel_mode = DisasterPanelMode::Compact;
+    app.update();
+    assert_eq!(icons(&mut app).len(), 2);
+}
@@ -0,0 +1,86 @@
+//! Disaster effects on egg laying and hatching
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+#[derive(Resource, Default)]
+struct EggsLaid(usize);
+
+fn count_eggs_laid(mut events: EventReader<systems::EggLaidEvent>, mut eggs: ResMut<EggsLaid>) {
+    eggs.0 += events.read().count();
+}
+
+/// Eggs the queen lays over 40 seconds with `disaster` active the whole time
+fn eggs_laid_over_forty_seconds(disaster: Option<DisasterType>) -> usize {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(ColonyFoodStore {
+        food: 1000.0,
+        protein: 1000.0,
+    });
+    app.init_resource::<BroodNutritionConfig>();
+    app.init_resource::<ChamberClimateConfig>();
+    app.init_resource::<CasteConfig>();
+    app.init_resource::<DisasterReproductionConfig>();
+    app.init_resource::<EggsLaid>();
+    app.add_event::<systems::EggLaidEvent>();
+
+    let mut disaster_state = DisasterState::default();
+    if let Some(disaster) = disaster {
+        disaster_state.start_disaster(disaster, 1000.0);
+    }
+    app.insert_resource(disaster_state);
+    app.add_systems(
+        Update,
+        (systems::queen_reproduction_system, count_eggs_laid).chain(),
+    );
+
+    app.world_mut().spawn((
+        Position { x: 0.0, y: -20.0 },
+        Ant,
+        Queen {
+            founding_state: FoundingState::Established,
+        },
+        ReproductionState::default(),
+    ));
+
+    for _ in 0..400 {
+        app.update();
+    }
+    app.world().resource::<EggsLaid>().0
+}
+
+/// The queen lays fewer eggs during a cold snap than in calm weather
+#[test]
+fn test_cold_snap_reduces_egg_laying() {
+    let calm = eggs_laid_over_forty_seconds(None);
+    let cold = eggs_laid_over_forty_seconds(Some(DisasterType::ColdSnap));
+    assert!(calm > 0, "Queen should lay in calm conditions");
+    assert!(cold < calm, "cold snap {} vs calm {}", cold, calm);
+}
+
+/// Factors combine across active disasters and fall back to 1.0 in calm weather
+#[test]
+fn test_disaster_factors() {
+    let config = DisasterReproductionConfig::default();
+    let mut state = DisasterState::default();
+    assert_eq!(config.laying_factor(&state), 1.0);
+    assert_eq!(config.hatching_factor(&state), 1.0);
+
+    state.start_disaster(DisasterType::Drought, 10.0);
+    assert!(config.laying_factor(&state) < 1.0);
+    state.start_disaster(DisasterType::Rain, 10.0);
+    assert_eq!(
+        config.laying_factor(&state),
+        config.drought_laying * config.rain_laying
+    );
+
+    state.start_disaster(DisasterType::ColdSnap, 10.0);
+    assert!(config.hatching_factor(&state) < 1.0);
+}
@@ -0,0 +1,92 @@
+//! Egg-laying feedback tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+fn egg_laying_app(visual_effects_settings: VisualEffectsSettings) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(visual_effects_settings);
+    app.init_resource::<ColonyFoodStore>();
+    app.init_resource::<BroodNutritionConfig>();
+    app.init_resource::<EventLog>();
+    app.add_event::<systems::EggLaidEvent>();
+    app.add_systems(
+        Update,
+        (
+            systems::queen_reproduction_system,
+            systems::egg_laid_log_system,
+            systems::egg_laying_pulse_system,
+        )
+            .chain(),
+    );
+
+    app.world_mut().spawn((
+        Position { x: 0.0, y: -20.0 },
+        Ant,
+        Queen {
+            founding_state: FoundingState::Established,
+        },
+        ReproductionState {
+            time_since_last_egg: 9.95, // Lays on the first update, then not for 10s
+            ..default()
+        },
+    ));
+    app
+}
+
+fn pulse_count(app: &mut App) -> usize {
+    app.world_mut()
+        .query::<&EggLayingPulse>()
+        .iter(app.world())
+        .count()
+}
+
+/// One laid egg spawns exactly one pulse and one log entry; the pulse despawns after its lifetime
+#[test]
+fn test_one_egg_spawns_one_pulse_that_expires() {
+    let mut app = egg_laying_app(VisualEffectsSettings::default());
+
+    app.update();
+    app.update();
+    let eggs = app.world_mut().query::<&Egg>().iter(app.world()).count();
+    assert_eq!(eggs, 1);
+    assert_eq!(pulse_count(&mut app), 1);
+
+    let event_log = app.world().resource::<EventLog>();
+    assert_eq!(event_log.entries.len(), 1);
+    assert!(event_log.entries[0].contains("laid an egg"));
+
+    app.update();
+    assert_eq!(
+        pulse_count(&mut app),
+        1,
+        "Pulse should not repeat per frame"
+    );
+
+    for _ in 0..10 {
+        app.update();
+    }
+    assert_eq!(pulse_count(&mut app), 0);
+}
+
+/// With particles disabled the egg is still laid and logged but no pulse is drawn
+#[test]
+fn test_no_pulse_when_particles_disabled() {
+    let mut app = egg_laying_app(VisualEffectsSettings {
+        particles_enabled: false,
+        ..default()
+    });
+
+    app.update();
+    app.update();
+
+    assert_eq!(pulse_count(&mut app), 0);
+    assert_eq!(app.world().resource::<EventLog>().entries.len(), 1);
+}
@@ -0,0 +1,66 @@
+//! Simulation end condition tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::{run_until_ended, step, AntNestSimulationPlugin};
+use bevy::prelude::*;
+
+/// Headless app with 10-second days and the given end conditions
+fn end_condition_app(conditions: EndConditions) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(SimulationTime {
+        elapsed_seconds: 0.0,
+        day_length_seconds: 10.0,
+        current_day: 1,
+    });
+    app.insert_resource(conditions);
+    app.add_plugins(AntNestSimulationPlugin);
+    app
+}
+
+/// "End at day 5" ends the run once five days have passed and not a step before
+#[test]
+fn test_run_ends_once_day_five_is_crossed() {
+    let mut app = end_condition_app(EndConditions {
+        after_days: Some(5.0),
+        ..default()
+    });
+
+    loop {
+        step(&mut app, 1.0);
+        let days = app.world().resource::<SimulationTime>().days_elapsed();
+        let ended = app.world().resource::<SimulationEnded>().clone();
+        if days < 5.0 {
+            assert!(!ended.is_ended(), "Ended early on day {}", days);
+            continue;
+        }
+        assert_eq!(ended.reason, Some(EndReason::DaysElapsed(5.0)));
+        break;
+    }
+    assert!(app.world().resource::<TimeControl>().is_paused);
+
+    // The ended run stays paused
+    let elapsed = app.world().resource::<SimulationTime>().elapsed_seconds;
+    step(&mut app, 1.0);
+    step(&mut app, 1.0);
+    assert_eq!(
+        app.world().resource::<SimulationTime>().elapsed_seconds,
+        elapsed
+    );
+}
+
+/// A headless runner can step until the run ends, and a run without conditions never does
+#[test]
+fn test_headless_runner_stops_at_end_condition() {
+    let mut app = end_condition_app(EndConditions {
+        after_days: Some(2.0),
+        ..default()
+    });
+    assert_eq!(
+        run_until_ended(&mut app, 1.0, 1000),
+        Some(EndReason::DaysElapsed(2.0))
+    );
+
+    let mut app = end_condition_app(EndConditions::default());
+    assert_eq!(run_until_ended(&mut app, 1.0, 30), None);
+}
@@ -0,0 +1,107 @@
+//! Frame-rate independence of the soil moisture and temperature drift
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+const CELLS: usize = 400;
+const SIMULATED_SECONDS: f32 = 60.0;
+
+/// Mean and standard deviation of `values`
+fn mean_and_spread(values: &[f32]) -> (f32, f32) {
+    let mean = values.iter().sum::<f32>() / values.len() as f32;
+    let variance = values
+        .iter()
+        .map(|value| (value - mean).powi(2))
+        .sum::<f32>()
+        / values.len() as f32;
+    (mean, variance.sqrt())
+}
+
+/// Soil moisture and temperature of every cell after a minute updated at `hz`
+fn soil_after_a_minute(hz: u32) -> (Vec<f32>, Vec<f32>) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        1.0 / hz as f32,
+    )));
+    app.init_resource::<EnvironmentNoise>();
+    app.add_systems(Update, systems::environmental_update_system);
+
+    for i in 0..CELLS {
+        app.world_mut().spawn((
+            Position {
+                x: (i % 20) as f32 * 4.0,
+                y: -((i / 20) as f32) * 4.0,
+            },
+            SoilCell {
+                moisture: 0.55,
+                temperature: 20.0,
+                nutrition: 0.5,
+                compaction: 0.0,
+            },
+        ));
+    }
+
+    // The first update has no elapsed time yet
+    for _ in 0..=(SIMULATED_SECONDS * hz as f32).round() as usize {
+        app.update();
+    }
+
+    let world = app.world_mut();
+    world
+        .query::<&SoilCell>()
+        .iter(world)
+        .map(|soil| (soil.moisture, soil.temperature))
+        .unzip()
+}
+
+/// 30 Hz and 144 Hz runs of the same duration spread the soil out alike
+#[test]
+fn test_soil_drift_matches_across_frame_rates() {
+    let (slow_moisture, slow_temperature) = soil_after_a_minute(30);
+    let (fast_moisture, fast_temperature) = soil_after_a_minute(144);
+
+    for (name, slow, fast) in [
+        ("moisture", slow_moisture, fast_moisture),
+        ("temperature", slow_temperature, fast_temperature),
+    ] {
+        let (slow_mean, slow_spread) = mean_and_spread(&slow);
+        let (fast_mean, fast_spread) = mean_and_spread(&fast);
+        assert!(slow_spread > 0.0, "{} should drift", name);
+        assert!(
+            (slow_mean - fast_mean).abs() < slow_spread * 0.3,
+            "{} means {} vs {}",
+            name,
+            slow_mean,
+            fast_mean
+        );
+        let ratio = slow_spread / fast_spread;
+        assert!(
+            (0.8..1.25).contains(&ratio),
+            "{} spreads {} vs {}",
+            name,
+            slow_spread,
+            fast_spread
+        );
+    }
+}
+
+/// One long step lands on the same distribution as many short ones, and drifts back to the mean
+#[test]
+fn test_ornstein_uhlenbeck_step_is_exact() {
+    // Without a shock, the gap to the mean decays exponentially however the time is split
+    let mut value = 1.0;
+    for _ in 0..10 {
+        value = systems::ornstein_uhlenbeck_step(value, 0.0, 0.5, 0.2, 0.1, 0.0);
+    }
+    let once = systems::ornstein_uhlenbeck_step(1.0, 0.0, 0.5, 0.2, 1.0, 0.0);
+    assert!((value - once).abs() < 1e-5, "{} vs {}", value, once);
+    assert!((once - (-0.2f32).exp()).abs() < 1e-5);
+
+    // Long steps settle to the stationary spread volatility / sqrt(2 * reversion)
+    let settled = systems::ornstein_uhlenbeck_step(5.0, 0.0, 0.5, 0.2, 1000.0, 1.0);
+    assert!((settled - 0.5 / 0.4f32.sqrt()).abs() < 1e-4, "{}", settled);
+}
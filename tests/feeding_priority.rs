@@ -0,0 +1,181 @@
+//! Colony feeding priority tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+/// Food delivered to the store every tick: enough for the queen and brood, little more
+const DELIVERY_PER_TICK: f32 = 0.1;
+
+/// Colony with a queen, four eggs and ten hungry workers on a trickle of food
+///
+/// Returns the app, the queen, the eggs and the workers.
+fn hungry_colony(config: FeedingPriorityConfig) -> (App, Entity, Vec<Entity>, Vec<Entity>) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(ColonyFoodStore {
+        food: 0.0,
+        protein: 1000.0,
+    });
+    app.insert_resource(config);
+    app.init_resource::<BroodNutritionConfig>();
+    app.init_resource::<ChamberClimateConfig>();
+    app.init_resource::<CasteConfig>();
+    app.add_event::<systems::EggLaidEvent>();
+    app.add_systems(
+        Update,
+        (
+            deliver_food,
+            systems::feeding_priority_system,
+            systems::egg_hatching_system,
+        )
+            .chain(),
+    );
+
+    let world = app.world_mut();
+    let queen = world
+        .spawn((
+            Position { x: 0.0, y: -20.0 },
+            Ant,
+            Queen {
+                founding_state: FoundingState::Established,
+            },
+            ReproductionState::default(),
+        ))
+        .id();
+    let eggs = (0..4)
+        .map(|i| {
+            world
+                .spawn((
+                    Position {
+                        x: i as f32,
+                        y: -20.0,
+                    },
+                    Egg {
+                        incubation_time: 1000.0,
+                    },
+                ))
+                .id()
+        })
+        .collect();
+    let workers = (0..10)
+        .map(|i| {
+            world
+                .spawn((
+                    Position {
+                        x: i as f32,
+                        y: -10.0,
+                    },
+                    Ant,
+                    Lifecycle {
+                        age: 0.0,
+                        max_age: 10_000.0,
+                        energy: 40.0,
+                        max_energy: 100.0,
+                    },
+                ))
+                .id()
+        })
+        .collect();
+    (app, queen, eggs, workers)
+}
+
+fn deliver_food(mut food_store: ResMut<ColonyFoodStore>) {
+    food_store.food += DELIVERY_PER_TICK;
+}
+
+fn nourishment(app: &App, entity: Entity) -> f32 {
+    app.world().get::<Nourishment>(entity).map_or(1.0, |n| n.0)
+}
+
+fn total_worker_energy(app: &App, workers: &[Entity]) -> f32 {
+    workers
+        .iter()
+        .map(|worker| app.world().get::<Lifecycle>(*worker).unwrap().energy)
+        .sum()
+}
+
+/// On a short store the queen and larvae stay fed and keep developing; workers go without
+#[test]
+fn test_queen_and_brood_fed_before_workers() {
+    let (mut app, queen, eggs, workers) = hungry_colony(FeedingPriorityConfig::default());
+    let worker_energy = total_worker_energy(&app, &workers);
+
+    for _ in 0..300 {
+        app.update();
+    }
+
+    assert!(
+        nourishment(&app, queen) > 0.9,
+        "{}",
+        nourishment(&app, queen)
+    );
+    for egg in &eggs {
+        assert!(nourishment(&app, *egg) > 0.9);
+        let incubation = app.world().get::<Egg>(*egg).unwrap().incubation_time;
+        assert!(incubation < 1000.0, "Fed brood should keep developing");
+    }
+    assert!(
+        total_worker_energy(&app, &workers) < worker_energy * 1.5,
+        "Workers only get the leftovers"
+    );
+}
+
+/// Putting workers first lets them eat the store bare, starving the queen and stalling the brood
+#[test]
+fn test_priority_order_is_configurable() {
+    let (mut app, queen, eggs, _) = hungry_colony(FeedingPriorityConfig {
+        order: vec![
+            FeedingGroup::Workers,
+            FeedingGroup::Queen,
+            FeedingGroup::Brood,
+        ],
+        ..default()
+    });
+
+    for _ in 0..300 {
+        app.update();
+    }
+
+    assert!(app.world().get::<Nourishment>(queen).unwrap().is_starving());
+    let stalled = app.world().get::<Egg>(eggs[0]).unwrap().incubation_time;
+    for _ in 0..20 {
+        app.update();
+    }
+    assert!(app
+        .world()
+        .get::<Nourishment>(eggs[0])
+        .unwrap()
+        .is_starving());
+    assert_eq!(
+        app.world().get::<Egg>(eggs[0]).unwrap().incubation_time,
+        stalled,
+        "Starving brood should not develop"
+    );
+}
+
+/// A queen still living on her founding reserve takes no upkeep from the store
+#[test]
+fn test_founding_queen_takes_no_upkeep() {
+    let (mut app, queen, eggs, workers) = hungry_colony(FeedingPriorityConfig::default());
+    let world = app.world_mut();
+    world.entity_mut(queen).insert(FoundingReserve::default());
+    for entity in eggs.into_iter().chain(workers) {
+        world.despawn(entity);
+    }
+
+    app.update();
+    let food = app.world().resource::<ColonyFoodStore>().food;
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<ColonyFoodStore>().food,
+        food + DELIVERY_PER_TICK
+    );
+    assert!(app.world().get::<Nourishment>(queen).is_none());
+}
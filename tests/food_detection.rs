@@ -0,0 +1,120 @@
+//! Forager food detection radius tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::strategy::{AntContext, AntStrategy, BoxedStrategy};
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+/// Strategy that always wanders off west, away from any food placed east of the ant
+struct WestWanderStrategy;
+
+impl AntStrategy for WestWanderStrategy {
+    fn choose_target(&self, ctx: &AntContext) -> Option<Position> {
+        Some(Position {
+            x: ctx.position.x - 20.0,
+            y: ctx.position.y,
+        })
+    }
+}
+
+/// Run a lone forager with food `food_offset` east of it, returning the ant's final
+/// x position and whether it took any of the food
+fn forage_with_food_at(food_offset: f32, detection_radius: f32) -> (f32, bool) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(AntAiConfig {
+        detection_radius,
+        ..default()
+    });
+    app.init_resource::<PheromoneGrid>();
+    app.init_resource::<ColonyFoodStore>();
+    app.init_resource::<NestSpace>();
+    app.insert_resource(WorldConfig {
+        min_x: -1000.0,
+        max_x: 1000.0,
+        ..default()
+    });
+    app.insert_resource(BoxedStrategy::new(WestWanderStrategy));
+    app.add_systems(
+        Update,
+        (
+            systems::foraging_behavior_system,
+            systems::ant_movement_system,
+        )
+            .chain(),
+    );
+
+    // Soil floor keeping the ant on the surface
+    for x in -40..=40 {
+        app.world_mut().spawn((
+            Position {
+                x: x as f32 * 2.0,
+                y: -2.0,
+            },
+            Soil,
+        ));
+    }
+    let food = app
+        .world_mut()
+        .spawn((
+            Position {
+                x: food_offset,
+                y: 0.0,
+            },
+            FoodSource {
+                nutrition_value: 50.0,
+                max_nutrition: 50.0,
+                kind: FoodKind::Carbohydrate,
+            },
+        ))
+        .id();
+    let home = Position { x: 0.0, y: 0.0 };
+    let ant = app
+        .world_mut()
+        .spawn((
+            home.clone(),
+            Transform::default(),
+            Ant,
+            AntBehavior::new(home, 0.0),
+        ))
+        .id();
+
+    for _ in 0..200 {
+        app.update();
+    }
+    let world = app.world();
+    let x = world.get::<Position>(ant).unwrap().x;
+    let taken = world.get::<FoodSource>(food).unwrap().nutrition_value < 50.0;
+    (x, taken)
+}
+
+/// Food within the detection radius draws the forager in; food beyond it goes unnoticed
+#[test]
+fn test_forager_heads_for_food_within_detection_radius() {
+    let radius = AntAiConfig::default().detection_radius;
+
+    let (_, found) = forage_with_food_at(radius * 0.8, radius);
+    assert!(found, "forager should spot food within {}", radius);
+
+    let (x, found) = forage_with_food_at(radius * 2.0, radius);
+    assert!(!found);
+    assert!(
+        x < 0.0,
+        "forager beyond range should keep wandering, at {}",
+        x
+    );
+}
+
+/// A wider radius lets the same forager find food it would otherwise walk away from
+#[test]
+fn test_larger_detection_radius_finds_more_food() {
+    let radius = AntAiConfig::default().detection_radius;
+
+    let (_, found) = forage_with_food_at(radius * 2.0, radius * 3.0);
+    assert!(found);
+}
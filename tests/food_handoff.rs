@@ -0,0 +1,130 @@
+//! Food handoff (relay foraging) tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+
+fn food_handoff_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<SpatialGrid>();
+    app.init_resource::<ColonyFoodStore>();
+    app.init_resource::<FoodHandoffConfig>();
+    app.init_resource::<DaughterColonies>();
+    app.add_systems(
+        Update,
+        (
+            systems::spatial_grid_update_system,
+            systems::food_handoff_system,
+        )
+            .chain(),
+    );
+    app
+}
+
+fn spawn_node(app: &mut App, x: f32, node_type: TunnelNodeType) {
+    app.world_mut().spawn((
+        Position { x, y: 0.0 },
+        TunnelNode {
+            node_type,
+            ..default()
+        },
+    ));
+}
+
+fn spawn_ant(app: &mut App, x: f32, role: SpecializedRole, carried_food: f32) -> Entity {
+    let mut behavior = AntBehavior::new(Position { x: 0.0, y: 0.0 }, 0.0);
+    if carried_food > 0.0 {
+        behavior.state = AntState::Returning;
+        behavior.carried_food = carried_food;
+        behavior.carried_kind = FoodKind::Protein;
+    }
+    app.world_mut()
+        .spawn((Ant, Position { x, y: 0.0 }, behavior, role))
+        .id()
+}
+
+fn behavior(app: &App, ant: Entity) -> &AntBehavior {
+    app.world().get::<AntBehavior>(ant).unwrap()
+}
+
+/// A loaded forager meeting a storage worker at a chamber entrance hands over its load
+/// and goes back to foraging
+#[test]
+fn test_forager_hands_load_to_storage_worker_at_entrance() {
+    let mut app = food_handoff_app();
+    // A storage chamber away from the entrance, so the worker keeps the load
+    app.world_mut().spawn((
+        Position { x: 50.0, y: 0.0 },
+        Chamber {
+            chamber_type: ChamberType::FoodStorage,
+            radius: 5.0,
+            capacity: 10,
+            occupants: 0,
+            temperature: 22.0,
+        },
+    ));
+    spawn_node(&mut app, 0.0, TunnelNodeType::ChamberEntrance);
+    let forager = spawn_ant(&mut app, 1.0, SpecializedRole::Forager, 5.0);
+    let worker = spawn_ant(&mut app, -1.0, SpecializedRole::StorageWorker, 0.0);
+
+    app.update();
+
+    let forager_behavior = behavior(&app, forager);
+    assert_eq!(forager_behavior.carried_food, 0.0);
+    assert_eq!(forager_behavior.state, AntState::Foraging);
+    let worker_behavior = behavior(&app, worker);
+    assert_eq!(worker_behavior.carried_food, 5.0);
+    assert_eq!(worker_behavior.carried_kind, FoodKind::Protein);
+}
+
+/// Away from a chamber entrance, or with no storage worker around, the forager keeps its load
+#[test]
+fn test_no_handoff_at_plain_junction_or_without_worker() {
+    let mut app = food_handoff_app();
+    spawn_node(&mut app, 0.0, TunnelNodeType::Junction);
+    spawn_node(&mut app, 100.0, TunnelNodeType::ChamberEntrance);
+    let at_junction = spawn_ant(&mut app, 1.0, SpecializedRole::Forager, 5.0);
+    let worker = spawn_ant(&mut app, -1.0, SpecializedRole::StorageWorker, 0.0);
+    let alone = spawn_ant(&mut app, 101.0, SpecializedRole::Forager, 5.0);
+
+    app.update();
+
+    for forager in [at_junction, alone] {
+        assert_eq!(behavior(&app, forager).carried_food, 5.0);
+        assert_eq!(behavior(&app, forager).state, AntState::Returning);
+    }
+    assert_eq!(behavior(&app, worker).carried_food, 0.0);
+}
+
+/// Food stays within a colony: foragers only hand over to their own colony's storage
+/// workers, and a daughter colony's storage worker fills that colony's store
+#[test]
+fn test_daughter_colony_food_stays_in_its_store() {
+    let mut app = food_handoff_app();
+    spawn_node(&mut app, 0.0, TunnelNodeType::ChamberEntrance);
+    let daughter_forager = spawn_ant(&mut app, 1.0, SpecializedRole::Forager, 5.0);
+    app.world_mut()
+        .entity_mut(daughter_forager)
+        .insert(ColonyId(2));
+    let home_worker = spawn_ant(&mut app, -1.0, SpecializedRole::StorageWorker, 0.0);
+    let home_food = app.world().resource::<ColonyFoodStore>().protein;
+
+    app.update();
+
+    assert_eq!(behavior(&app, daughter_forager).carried_food, 5.0);
+    assert_eq!(behavior(&app, home_worker).carried_food, 0.0);
+
+    // Without a storage chamber the daughter's storage worker drops its load at once
+    let daughter_worker = spawn_ant(&mut app, 200.0, SpecializedRole::StorageWorker, 0.0);
+    let mut worker = app.world_mut().entity_mut(daughter_worker);
+    worker.insert(ColonyId(2));
+    let mut worker_behavior = worker.get_mut::<AntBehavior>().unwrap();
+    worker_behavior.carried_food = 4.0;
+    app.update();
+
+    assert_eq!(behavior(&app, daughter_worker).carried_food, 0.0);
+    let daughters = app.world().resource::<DaughterColonies>();
+    assert_eq!(daughters.colonies[&ColonyId(2)].food, 4.0);
+    assert_eq!(app.world().resource::<ColonyFoodStore>().protein, home_food);
+}
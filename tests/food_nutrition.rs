@@ -0,0 +1,165 @@
+//! Protein vs carbohydrate nutrition tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::strategy::BoxedStrategy;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+fn nutrition_test_app(food_store: ColonyFoodStore) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(food_store);
+    app.init_resource::<BroodNutritionConfig>();
+    app.init_resource::<ChamberClimateConfig>();
+    app.init_resource::<CasteConfig>();
+    app.add_event::<systems::EggLaidEvent>();
+    app
+}
+
+/// Run the brood systems for 5 seconds; returns (eggs alive, incubation left on the first egg)
+fn brood_after_five_seconds(food_store: ColonyFoodStore) -> (usize, f32) {
+    let mut app = nutrition_test_app(food_store);
+    app.add_systems(
+        Update,
+        (
+            systems::queen_reproduction_system,
+            systems::egg_hatching_system,
+        )
+            .chain(),
+    );
+
+    app.world_mut().spawn((
+        Position { x: 0.0, y: -20.0 },
+        Ant,
+        Queen {
+            founding_state: FoundingState::Established,
+        },
+        ReproductionState {
+            time_since_last_egg: 100.0, // Overdue to lay
+            ..default()
+        },
+    ));
+    let egg = app
+        .world_mut()
+        .spawn((
+            Position { x: 0.0, y: -20.0 },
+            Egg {
+                incubation_time: 100.0,
+            },
+        ))
+        .id();
+
+    for _ in 0..50 {
+        app.update();
+    }
+
+    let eggs = app.world_mut().query::<&Egg>().iter(app.world()).count();
+    (eggs, app.world().get::<Egg>(egg).unwrap().incubation_time)
+}
+
+/// Energy of a hungry worker after one feeding pass
+fn worker_energy_after_feeding(food_store: ColonyFoodStore) -> (f32, ColonyFoodStore) {
+    let mut app = nutrition_test_app(food_store);
+    app.add_systems(Update, systems::feeding_priority_system);
+
+    let ant = app
+        .world_mut()
+        .spawn(Lifecycle {
+            age: 0.0,
+            max_age: 1000.0,
+            energy: 20.0,
+            max_energy: 100.0,
+        })
+        .id();
+    app.update();
+
+    (
+        app.world().get::<Lifecycle>(ant).unwrap().energy,
+        app.world().resource::<ColonyFoodStore>().clone(),
+    )
+}
+
+/// Without protein the brood stops developing and no eggs are laid, however much sugar is stored
+#[test]
+fn test_protein_starved_colony_halts_brood() {
+    let (eggs, incubation) = brood_after_five_seconds(ColonyFoodStore {
+        food: 1000.0,
+        protein: 0.0,
+    });
+    assert_eq!(eggs, 1, "Queen should not lay without protein");
+    assert_eq!(
+        incubation, 100.0,
+        "Brood should not develop without protein"
+    );
+
+    let (eggs, incubation) = brood_after_five_seconds(ColonyFoodStore {
+        food: 0.0,
+        protein: 1000.0,
+    });
+    assert_eq!(eggs, 2, "Queen should lay once with protein available");
+    assert!(incubation < 100.0, "Brood should develop on protein");
+}
+
+/// Without carbohydrates workers cannot refuel, however much protein is stored
+#[test]
+fn test_carbohydrate_starved_colony_cannot_refuel_workers() {
+    let (energy, store) = worker_energy_after_feeding(ColonyFoodStore {
+        food: 0.0,
+        protein: 1000.0,
+    });
+    assert_eq!(energy, 20.0);
+    assert_eq!(store.protein, 1000.0, "Protein is kept for the brood");
+
+    let (energy, _) = worker_energy_after_feeding(ColonyFoodStore {
+        food: 1000.0,
+        protein: 0.0,
+    });
+    assert_eq!(energy, 100.0);
+}
+
+/// A forager sensing both kinds heads for the one the colony is short of
+#[test]
+fn test_forager_prefers_scarce_food_kind() {
+    let mut app = nutrition_test_app(ColonyFoodStore {
+        food: 500.0,
+        protein: 5.0,
+    });
+    app.init_resource::<AntAiConfig>();
+    app.init_resource::<PheromoneGrid>();
+    app.init_resource::<BoxedStrategy>();
+    app.add_systems(Update, systems::foraging_behavior_system);
+
+    let home = Position { x: 0.0, y: 0.0 };
+    let ant = app
+        .world_mut()
+        .spawn((home.clone(), Ant, AntBehavior::new(home, 0.0)))
+        .id();
+    let spawn_food = |world: &mut World, x: f32, kind: FoodKind| {
+        world.spawn((
+            Position { x, y: 0.0 },
+            FoodSource {
+                nutrition_value: 50.0,
+                max_nutrition: 50.0,
+                kind,
+            },
+        ));
+    };
+    spawn_food(app.world_mut(), 5.0, FoodKind::Carbohydrate);
+    spawn_food(app.world_mut(), -8.0, FoodKind::Protein);
+
+    app.update();
+
+    let target = app
+        .world()
+        .get::<AntBehavior>(ant)
+        .unwrap()
+        .target_position
+        .clone()
+        .expect("Forager should pick a target");
+    assert_eq!(target.x, -8.0);
+}
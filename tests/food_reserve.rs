@@ -0,0 +1,82 @@
+//! Low food reserve warning tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+
+fn food_reserve_app(food: f32) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(ColonyFoodStore {
+        food,
+        protein: 20.0,
+    });
+    app.insert_resource(FoodReserveConfig {
+        critical_threshold: 20.0,
+    });
+    app.init_resource::<FoodReserveWarning>();
+    app.add_systems(
+        Update,
+        (
+            systems::food_reserve_dismiss_system,
+            systems::food_reserve_monitor_system,
+        )
+            .chain(),
+    );
+    app
+}
+
+fn banner_count(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world
+        .query_filtered::<Entity, With<FoodReserveBanner>>()
+        .iter(world)
+        .count()
+}
+
+fn set_food(app: &mut App, food: f32) {
+    app.world_mut().resource_mut::<ColonyFoodStore>().food = food;
+    app.update();
+}
+
+/// The banner appears once below the threshold and goes away when food is restocked
+#[test]
+fn test_banner_follows_food_reserves() {
+    let mut app = food_reserve_app(100.0);
+    app.update();
+    assert_eq!(banner_count(&mut app), 0);
+
+    set_food(&mut app, 5.0);
+    assert_eq!(banner_count(&mut app), 1);
+    app.update();
+    assert_eq!(banner_count(&mut app), 1, "banner spawned only once");
+
+    set_food(&mut app, 50.0);
+    assert_eq!(banner_count(&mut app), 0);
+}
+
+/// A dismissed banner stays closed until reserves recover and drop again
+#[test]
+fn test_dismissed_banner_returns_on_next_shortage() {
+    let mut app = food_reserve_app(5.0);
+    app.update();
+    assert_eq!(banner_count(&mut app), 1);
+
+    let world = app.world_mut();
+    let button = world
+        .query_filtered::<Entity, With<FoodReserveDismissButton>>()
+        .single(world);
+    world.entity_mut(button).insert(Interaction::Pressed);
+    app.update();
+    assert_eq!(banner_count(&mut app), 0);
+    app.update();
+    assert_eq!(
+        banner_count(&mut app),
+        0,
+        "dismissal holds while still short"
+    );
+
+    set_food(&mut app, 50.0);
+    set_food(&mut app, 5.0);
+    assert_eq!(banner_count(&mut app), 1);
+}
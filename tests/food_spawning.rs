@@ -0,0 +1,94 @@
+//! Nutrient-driven food spawning tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+fn food_spawning_app(config: FoodSpawningConfig) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(config);
+    app.add_systems(
+        Update,
+        (
+            systems::food_spawning_system,
+            systems::depleted_food_cleanup_system,
+        )
+            .chain(),
+    );
+    app
+}
+
+fn spawn_soil(app: &mut App, x: f32, nutrition: f32, moisture: f32) {
+    app.world_mut().spawn((
+        Soil,
+        Position { x, y: -4.0 },
+        SoilCell {
+            moisture,
+            temperature: 20.0,
+            nutrition,
+            compaction: 0.1,
+        },
+    ));
+}
+
+/// Over a long run food sprouts above rich, wet soil and not above poor, dry soil
+#[test]
+fn test_food_prefers_rich_wet_soil() {
+    let mut app = food_spawning_app(FoodSpawningConfig {
+        check_interval: 1.0,
+        spawn_chance: 0.2,
+        max_food_sources: 1000,
+        ..default()
+    });
+    for i in 0..20 {
+        let x = i as f32 * 4.0 + 4.0;
+        spawn_soil(&mut app, -x, 0.9, 0.8); // Rich and wet in the west
+        spawn_soil(&mut app, x, 0.3, 0.1); // Poor and dry in the east
+    }
+
+    for _ in 0..1000 {
+        app.update();
+    }
+
+    let positions: Vec<f32> = app
+        .world_mut()
+        .query_filtered::<&Position, With<FoodSource>>()
+        .iter(app.world())
+        .map(|position| position.x)
+        .collect();
+    let rich = positions.iter().filter(|x| **x < 0.0).count();
+    let poor = positions.len() - rich;
+    assert!(rich > 5, "Rich soil should grow food, got {}", rich);
+    assert!(rich > poor * 3, "rich {} vs poor {}", rich, poor);
+}
+
+/// Food standing over depleted soil withers and is removed
+#[test]
+fn test_food_withers_on_depleted_soil() {
+    let mut app = food_spawning_app(FoodSpawningConfig {
+        check_interval: 1.0,
+        wither_rate: 5.0,
+        ..default()
+    });
+    spawn_soil(&mut app, 0.0, 0.05, 0.5);
+    let food = app.world_mut().spawn((
+        Position { x: 1.0, y: 2.0 },
+        FoodSource {
+            nutrition_value: 20.0,
+            max_nutrition: 20.0,
+            kind: FoodKind::Carbohydrate,
+        },
+    ));
+    let food = food.id();
+
+    for _ in 0..60 {
+        app.update();
+    }
+    assert!(app.world().get_entity(food).is_none());
+}
@@ -0,0 +1,97 @@
+//! Foraging range cap tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::strategy::{AntContext, AntStrategy, BoxedStrategy};
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+/// Strategy that always heads far out east, as a bold scout would
+struct FarEastStrategy;
+
+impl AntStrategy for FarEastStrategy {
+    fn choose_target(&self, ctx: &AntContext) -> Option<Position> {
+        Some(Position {
+            x: ctx.position.x + 500.0,
+            y: ctx.position.y,
+        })
+    }
+}
+
+/// Farthest from home any target of a forager got while the colony was in `phase`
+fn farthest_target(phase: DevelopmentPhase) -> f32 {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<AntAiConfig>();
+    app.init_resource::<PheromoneGrid>();
+    app.init_resource::<ColonyFoodStore>();
+    app.init_resource::<NestSpace>();
+    app.insert_resource(WorldConfig {
+        min_x: -1000.0,
+        max_x: 1000.0,
+        ..default()
+    });
+    app.insert_resource(ColonyDevelopmentPhase {
+        current_phase: phase,
+        ..default()
+    });
+    app.insert_resource(BoxedStrategy::new(FarEastStrategy));
+    app.add_systems(
+        Update,
+        (
+            systems::foraging_behavior_system,
+            systems::ant_movement_system,
+        )
+            .chain(),
+    );
+
+    let home = Position { x: 0.0, y: 0.0 };
+    let ant = app
+        .world_mut()
+        .spawn((
+            home.clone(),
+            Transform::default(),
+            Ant,
+            AntBehavior::new(home.clone(), 0.0),
+        ))
+        .id();
+
+    let mut farthest: f32 = 0.0;
+    for _ in 0..300 {
+        app.update();
+        let behavior = app.world().get::<AntBehavior>(ant).unwrap();
+        if let Some(target) = behavior.target_position.as_ref() {
+            farthest = farthest.max((target.x - home.x).hypot(target.y - home.y));
+        }
+    }
+    farthest
+}
+
+/// A founding colony's forager stays close to home; a mature colony's ranges farther
+#[test]
+fn test_foraging_range_grows_with_colony_phase() {
+    let config = AntAiConfig::default();
+    let founding_radius = config.foraging_radius(DevelopmentPhase::QueenFounding);
+    let mature_radius = config.foraging_radius(DevelopmentPhase::MatureColony);
+    assert!(founding_radius < mature_radius);
+
+    let founding = farthest_target(DevelopmentPhase::QueenFounding);
+    let mature = farthest_target(DevelopmentPhase::MatureColony);
+
+    assert!(
+        founding <= founding_radius + 0.01,
+        "founding forager targeted {} beyond {}",
+        founding,
+        founding_radius
+    );
+    assert!(mature <= mature_radius + 0.01);
+    assert!(
+        mature > founding_radius * 2.0,
+        "mature forager only reached {}",
+        mature
+    );
+}
@@ -0,0 +1,149 @@
+//! Founding success and early queen attrition tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use ant_nest_simulator::AntNestSimulationPlugin;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+const RUNS: u64 = 20;
+
+/// Seconds a queen waits for her first worker
+const FIRST_WORKERS_AFTER: usize = 60;
+
+/// Whether a settled queen with the given traits lives to see her first worker
+fn queen_survives_founding(
+    seed: u64,
+    traits: ColonyTraits,
+    temperature: f32,
+    moisture: f32,
+) -> bool {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+    app.insert_resource(SimulationRng::from_seed(seed));
+    app.init_resource::<FoundingSuccessConfig>();
+    app.add_systems(Update, systems::founding_success_system);
+
+    let queen = app
+        .world_mut()
+        .spawn((
+            Position { x: 0.0, y: -10.0 },
+            Ant,
+            Queen {
+                founding_state: FoundingState::Established,
+            },
+            traits,
+            FoundingReserve::default(),
+        ))
+        .id();
+    for x in -2..=2 {
+        app.world_mut().spawn((
+            Position {
+                x: x as f32 * 4.0,
+                y: -14.0,
+            },
+            Soil,
+            SoilCell {
+                moisture,
+                temperature,
+                nutrition: 0.5,
+                compaction: 0.0,
+            },
+        ));
+    }
+
+    // The first update has no elapsed time yet
+    for _ in 0..=FIRST_WORKERS_AFTER {
+        app.update();
+    }
+    app.world_mut()
+        .spawn((Position { x: 0.0, y: -10.0 }, Ant, LaidBy { queen }));
+    for _ in 0..FIRST_WORKERS_AFTER {
+        app.update();
+    }
+
+    let world = app.world();
+    let alive = world.get_entity(queen).is_some();
+    if alive {
+        assert!(
+            world.get::<FoundingReserve>(queen).is_none(),
+            "Founding should end with the first worker"
+        );
+    }
+    alive
+}
+
+fn traits(queen_vigor: f32, environmental_adaptation: f32) -> ColonyTraits {
+    ColonyTraits {
+        queen_vigor,
+        worker_efficiency: 1.0,
+        architectural_skill: 1.0,
+        environmental_adaptation,
+    }
+}
+
+/// A weak queen in hot, dry soil fails far more often than a vigorous one in good soil
+#[test]
+fn test_weak_queen_in_harsh_soil_fails_more_often() {
+    let failures = |traits: &ColonyTraits, temperature: f32, moisture: f32| {
+        (0..RUNS)
+            .filter(|seed| !queen_survives_founding(*seed, traits.clone(), temperature, moisture))
+            .count()
+    };
+
+    let harsh = failures(&traits(0.6, 0.6), 30.0, 0.1);
+    let good = failures(&traits(1.4, 1.2), 20.0, 0.55);
+
+    assert!(
+        harsh > good + RUNS as usize / 2,
+        "{} harsh vs {} good failures out of {}",
+        harsh,
+        good,
+        RUNS
+    );
+    assert!(good < RUNS as usize / 4);
+}
+
+/// Seeded runs decide the same way every time
+#[test]
+fn test_founding_outcome_is_reproducible() {
+    for seed in 0..5 {
+        let first = queen_survives_founding(seed, traits(1.0, 1.0), 26.0, 0.3);
+        let second = queen_survives_founding(seed, traits(1.0, 1.0), 26.0, 0.3);
+        assert_eq!(first, second);
+    }
+}
+
+/// The starting workers of the default colony do not end the queen's founding
+#[test]
+fn test_starting_workers_leave_founding_reserve_in_place() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(SimulationRng::from_seed(3));
+    app.add_plugins(AntNestSimulationPlugin);
+    for _ in 0..5 {
+        app.update();
+    }
+
+    let world = app.world_mut();
+    let workers = world
+        .query_filtered::<(), (With<Ant>, Without<Queen>)>()
+        .iter(world)
+        .count();
+    assert_eq!(workers, InitialSpawnConfig::default().ant_count);
+    let queen = world.query_filtered::<Entity, With<Queen>>().single(world);
+    assert!(
+        world.get::<FoundingReserve>(queen).is_some(),
+        "Workers the queen did not raise ended her founding"
+    );
+
+    // Her own first worker does end it
+    world.spawn((Position { x: 0.0, y: -10.0 }, Ant, LaidBy { queen }));
+    app.update();
+    assert!(app.world().get::<FoundingReserve>(queen).is_none());
+}
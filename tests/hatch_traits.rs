@@ -0,0 +1,238 @@
+//! Hatchling attributes inherited from colony traits tests
+
+mod common;
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::localization::Localization;
+use ant_nest_simulator::systems::{self, PersistenceState, SaveSlotRequest, SaveSlots, SlotAction};
+use bevy::prelude::*;
+use common::temp_save_dir;
+
+/// Hatch a brood of eggs under a queen with the given traits;
+/// returns the average (speed, foraging efficiency) of the new workers
+fn hatch_brood(traits: ColonyTraits) -> (f32, f32) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(ColonyFoodStore {
+        food: 100.0,
+        protein: 1000.0,
+    });
+    app.init_resource::<BroodNutritionConfig>();
+    app.init_resource::<ChamberClimateConfig>();
+    app.init_resource::<CasteConfig>();
+    app.init_resource::<HatchTraitConfig>();
+    app.add_systems(Update, systems::egg_hatching_system);
+
+    app.world_mut().spawn((
+        Position { x: 0.0, y: -20.0 },
+        Queen {
+            founding_state: FoundingState::Established,
+        },
+        traits,
+    ));
+    for _ in 0..300 {
+        app.world_mut().spawn((
+            Position { x: 0.0, y: -20.0 },
+            Egg {
+                incubation_time: 0.0, // Ready to hatch
+            },
+        ));
+    }
+    app.update();
+
+    let world = app.world_mut();
+    let workers: Vec<(f32, f32)> = world
+        .query::<(&AntBehavior, &BehaviorModifiers)>()
+        .iter(world)
+        .map(|(behavior, modifiers)| (behavior.speed, modifiers.foraging_efficiency))
+        .collect();
+    assert_eq!(workers.len(), 300, "every egg hatched");
+    let count = workers.len() as f32;
+    (
+        workers.iter().map(|(speed, _)| speed).sum::<f32>() / count,
+        workers
+            .iter()
+            .map(|(_, efficiency)| efficiency)
+            .sum::<f32>()
+            / count,
+    )
+}
+
+fn traits_with(worker_efficiency: f32, queen_vigor: f32) -> ColonyTraits {
+    ColonyTraits {
+        queen_vigor,
+        worker_efficiency,
+        architectural_skill: 1.0,
+        environmental_adaptation: 1.0,
+    }
+}
+
+/// An efficient colony hatches faster workers that carry more per trip
+#[test]
+fn test_efficient_colony_hatches_faster_workers() {
+    let (fast_speed, fast_efficiency) = hatch_brood(traits_with(1.4, 1.0));
+    let (slow_speed, slow_efficiency) = hatch_brood(traits_with(0.6, 1.0));
+
+    assert!(
+        fast_speed > slow_speed,
+        "speed {} vs {}",
+        fast_speed,
+        slow_speed
+    );
+    assert!(
+        fast_efficiency > slow_efficiency,
+        "efficiency {} vs {}",
+        fast_efficiency,
+        slow_efficiency
+    );
+}
+
+/// A vigorous queen shifts the caste odds toward larger workers
+#[test]
+fn test_queen_vigor_favours_larger_castes() {
+    let base = CasteConfig::default();
+    let vigorous = base.for_traits(&traits_with(1.0, 1.4), 1.0);
+    let weak = base.for_traits(&traits_with(1.0, 0.6), 1.0);
+    let major_share = |config: &CasteConfig| {
+        config.major_weight / (config.minor_weight + config.media_weight + config.major_weight)
+    };
+
+    assert!(major_share(&vigorous) > major_share(&base));
+    assert!(major_share(&weak) < major_share(&base));
+
+    // No influence leaves the configured shares untouched
+    let neutral = base.for_traits(&traits_with(1.0, 1.4), 0.0);
+    assert_eq!(neutral.major_weight, base.major_weight);
+    assert_eq!(neutral.media_weight, base.media_weight);
+}
+
+/// Each egg takes after the queen that laid it, or a queen of its colony once she is gone
+#[test]
+fn test_hatchlings_take_after_their_own_queen() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(ColonyFoodStore {
+        food: 100.0,
+        protein: 1000.0,
+    });
+    app.init_resource::<BroodNutritionConfig>();
+    app.init_resource::<ChamberClimateConfig>();
+    app.init_resource::<CasteConfig>();
+    app.init_resource::<HatchTraitConfig>();
+    app.add_systems(Update, systems::egg_hatching_system);
+
+    let world = app.world_mut();
+    let mut spawn_queen = |traits: ColonyTraits, colony: ColonyId| {
+        world
+            .spawn((
+                Position { x: 0.0, y: -20.0 },
+                Queen {
+                    founding_state: FoundingState::Established,
+                },
+                traits,
+                colony,
+            ))
+            .id()
+    };
+    let efficient = spawn_queen(traits_with(1.4, 1.0), ColonyId(0));
+    let sluggish = spawn_queen(traits_with(0.6, 1.0), ColonyId(1));
+    let gone = world.spawn_empty().id();
+    world.despawn(gone);
+    for (queen, colony) in [(efficient, 0), (sluggish, 1), (gone, 1)] {
+        world.spawn((
+            Position { x: 0.0, y: -20.0 },
+            Egg {
+                incubation_time: 0.0, // Ready to hatch
+            },
+            LaidBy { queen },
+            ColonyId(colony),
+        ));
+    }
+    app.update();
+
+    let world = app.world_mut();
+    let efficiency_of = |world: &mut World, queen: Entity| {
+        world
+            .query::<(&LaidBy, &BehaviorModifiers)>()
+            .iter(world)
+            .find(|(laid_by, _)| laid_by.queen == queen)
+            .map(|(_, modifiers)| modifiers.foraging_efficiency)
+            .unwrap()
+    };
+    let efficient_brood = efficiency_of(world, efficient);
+    let sluggish_brood = efficiency_of(world, sluggish);
+    let orphan_brood = efficiency_of(world, gone);
+    assert!(
+        efficient_brood > sluggish_brood,
+        "efficiency {} vs {}",
+        efficient_brood,
+        sluggish_brood
+    );
+    assert_eq!(
+        orphan_brood, sluggish_brood,
+        "Orphans take after their colony"
+    );
+}
+
+/// Workers loaded from a save keep their own saved modifiers, not the queen's
+#[test]
+fn test_loaded_workers_keep_trait_modifiers() {
+    let save_dir = temp_save_dir("hatch-traits-load");
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(PersistenceState {
+        save_directory: Some(save_dir.clone()),
+        ..default()
+    });
+    app.init_resource::<SaveSlots>();
+    app.init_resource::<Localization>();
+    app.init_resource::<ColonyFoodStore>();
+    app.init_resource::<NestSpace>();
+    app.init_resource::<HatchTraitConfig>();
+    app.add_event::<SaveSlotRequest>();
+    app.add_systems(Startup, systems::spawn_initial_ants);
+    app.add_systems(Update, systems::save_slot_request_system);
+
+    let traits = traits_with(1.4, 1.0);
+    app.world_mut().spawn((
+        Position { x: 0.0, y: -20.0 },
+        Queen {
+            founding_state: FoundingState::Established,
+        },
+        traits,
+    ));
+    app.update();
+
+    // Workers hatched under earlier queens differ from what these traits would give
+    let world = app.world_mut();
+    let mut saved = Vec::new();
+    for (i, mut modifiers) in world
+        .query_filtered::<&mut BehaviorModifiers, Without<Queen>>()
+        .iter_mut(world)
+        .enumerate()
+    {
+        modifiers.speed = 0.8 + i as f32 * 0.1;
+        modifiers.foraging_efficiency = 1.2 - i as f32 * 0.05;
+        saved.push(*modifiers);
+    }
+
+    for action in [SlotAction::Save, SlotAction::Load] {
+        app.world_mut()
+            .send_event(SaveSlotRequest { slot: 1, action });
+        app.update();
+    }
+
+    let world = app.world_mut();
+    let mut loaded: Vec<BehaviorModifiers> = world
+        .query_filtered::<&BehaviorModifiers, Without<Queen>>()
+        .iter(world)
+        .copied()
+        .collect();
+    let by_speed = |a: &BehaviorModifiers, b: &BehaviorModifiers| a.speed.total_cmp(&b.speed);
+    loaded.sort_by(by_speed);
+    saved.sort_by(by_speed);
+    assert_eq!(loaded.len(), 5);
+    assert_eq!(loaded, saved);
+
+    let _ = std::fs::remove_dir_all(save_dir);
+}
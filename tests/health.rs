@@ -0,0 +1,162 @@
+//! Ant health tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+fn health_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<CarryingCapacity>();
+    app.add_systems(
+        Update,
+        (
+            systems::invader_combat_system,
+            systems::ant_lifecycle_system,
+            systems::queen_health_system,
+        )
+            .chain(),
+    );
+    app
+}
+
+fn spawn_ant(app: &mut App, hp: f32, state: AntState) -> Entity {
+    let world = app.world_mut();
+    let mut behavior = AntBehavior::new(Position { x: 0.0, y: 0.0 }, 0.0);
+    behavior.state = state;
+    world
+        .spawn((
+            Ant,
+            Position { x: 0.0, y: 0.0 },
+            Lifecycle {
+                age: 0.0,
+                max_age: 10_000.0,
+                energy: 100.0,
+                max_energy: 100.0,
+            },
+            Health { hp, max_hp: 100.0 },
+            behavior,
+        ))
+        .id()
+}
+
+fn spawn_queen(app: &mut App, hp: f32) -> Entity {
+    app.world_mut()
+        .spawn((
+            Ant,
+            Queen {
+                founding_state: FoundingState::Established,
+            },
+            Position { x: 0.0, y: 0.0 },
+            Health { hp, max_hp: 100.0 },
+        ))
+        .id()
+}
+
+/// Losing fights kills an ant through injury even though it is well fed
+#[test]
+fn test_hp_damage_kills_well_fed_ant() {
+    let mut app = health_app();
+    let ant = spawn_ant(&mut app, 100.0, AntState::Defending);
+    app.world_mut().spawn((
+        Position { x: 0.5, y: 0.0 },
+        InvasiveSpecies {
+            lifetime: 1000.0,
+            food_consumption_rate: 0.0,
+            strength: 1_000_000.0, // The ant never wins
+        },
+    ));
+
+    let mut lowest_energy = 100.0_f32;
+    for _ in 0..600 {
+        app.update();
+        match app.world().get::<Lifecycle>(ant) {
+            Some(lifecycle) => lowest_energy = lowest_energy.min(lifecycle.energy),
+            None => break,
+        }
+    }
+
+    assert!(
+        app.world().get_entity(ant).is_none(),
+        "Repeated defeats should kill the ant"
+    );
+    assert!(
+        lowest_energy > 90.0,
+        "The ant should die of wounds, not hunger"
+    );
+}
+
+/// Resting heals wounds much faster than grooming on the move
+#[test]
+fn test_resting_regenerates_hp() {
+    let mut app = health_app();
+    let resting = spawn_ant(&mut app, 50.0, AntState::Resting);
+    let active = spawn_ant(&mut app, 50.0, AntState::Foraging);
+
+    for _ in 0..51 {
+        app.update();
+    }
+
+    let hp = |entity| app.world().get::<Health>(entity).unwrap().hp;
+    assert!(
+        (hp(resting) - 60.0).abs() < 0.5,
+        "Resting ant has {} HP",
+        hp(resting)
+    );
+    assert!(hp(active) > 50.0);
+    assert!(hp(active) < hp(resting));
+}
+
+/// Invaders can kill a queen, who has no lifecycle of her own
+#[test]
+fn test_hp_damage_kills_queen() {
+    let mut app = health_app();
+    app.init_resource::<EventLog>();
+    let queen = spawn_queen(&mut app, 100.0);
+    app.world_mut().spawn((
+        Position { x: 0.5, y: 0.0 },
+        InvasiveSpecies {
+            lifetime: 1000.0,
+            food_consumption_rate: 0.0,
+            strength: 1_000_000.0, // The queen never wins
+        },
+    ));
+
+    for _ in 0..600 {
+        if app.world().get_entity(queen).is_none() {
+            break;
+        }
+        app.update();
+    }
+
+    assert!(
+        app.world().get_entity(queen).is_none(),
+        "Repeated defeats should kill the queen"
+    );
+    assert!(app
+        .world()
+        .resource::<EventLog>()
+        .entries
+        .iter()
+        .any(|entry| entry.contains("queen died")));
+}
+
+/// A wounded queen slowly heals by grooming
+#[test]
+fn test_wounded_queen_heals() {
+    let mut app = health_app();
+    let queen = spawn_queen(&mut app, 50.0);
+
+    for _ in 0..51 {
+        app.update();
+    }
+
+    let hp = app.world().get::<Health>(queen).unwrap().hp;
+    assert!(hp > 50.0, "Queen has {} HP", hp);
+    assert!(hp < 100.0);
+}
@@ -0,0 +1,119 @@
+//! Keyboard shortcut help overlay tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::localization::Localization;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+
+fn help_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<ButtonInput<KeyCode>>();
+    app.init_resource::<KeyBindings>();
+    app.init_resource::<Localization>();
+    app.add_systems(Startup, systems::setup_help_overlay);
+    app.add_systems(
+        Update,
+        (
+            systems::help_overlay_toggle_system,
+            systems::update_help_overlay_system,
+        ),
+    );
+    app
+}
+
+fn overlay_text(app: &mut App) -> String {
+    let world = app.world_mut();
+    world
+        .query_filtered::<&Text, With<HelpOverlayText>>()
+        .single(world)
+        .sections[0]
+        .value
+        .clone()
+}
+
+/// Every action is listed once, and no two actions share a default key
+#[test]
+fn test_entries_cover_every_action() {
+    let bindings = KeyBindings::default();
+    let entries = systems::help_entries(&bindings, &Localization::default());
+
+    assert_eq!(entries.len(), InputAction::ALL.len());
+    for action in InputAction::ALL {
+        assert_eq!(
+            entries
+                .iter()
+                .filter(|entry| entry.action == action)
+                .count(),
+            1,
+            "{:?} listed once",
+            action
+        );
+    }
+    let mut keys: Vec<KeyCode> = InputAction::ALL
+        .iter()
+        .map(|action| bindings.key(*action))
+        .collect();
+    keys.sort_by_key(|key| format!("{:?}", key));
+    keys.dedup();
+    assert_eq!(keys.len(), InputAction::ALL.len(), "default keys clash");
+
+    let text = systems::help_text(&bindings, &Localization::default());
+    for category in ["Time", "Camera", "Disasters", "Panels", "Tools"] {
+        assert!(text.contains(&format!("{}:", category)), "{}", text);
+    }
+}
+
+/// Remapping a key shows up in the entries and in the overlay text
+#[test]
+fn test_overlay_reflects_remapped_key() {
+    let mut app = help_app();
+    app.update();
+    assert!(overlay_text(&mut app).contains("T        Turbo mode"));
+
+    app.world_mut()
+        .resource_mut::<KeyBindings>()
+        .keys
+        .insert(InputAction::ToggleTurbo, KeyCode::KeyY);
+    app.update();
+
+    let entries = systems::help_entries(
+        app.world().resource::<KeyBindings>(),
+        app.world().resource::<Localization>(),
+    );
+    let turbo = entries
+        .iter()
+        .find(|entry| entry.action == InputAction::ToggleTurbo)
+        .unwrap();
+    assert_eq!(turbo.key, "Y");
+    let text = overlay_text(&mut app);
+    assert!(text.contains("Y        Turbo mode"), "{}", text);
+    assert!(!text.contains("T        Turbo mode"));
+}
+
+/// The help key opens and closes the overlay
+#[test]
+fn test_help_key_toggles_overlay() {
+    let mut app = help_app();
+    app.update();
+
+    let visibility = |app: &mut App| {
+        let world = app.world_mut();
+        *world
+            .query_filtered::<&Visibility, With<HelpOverlay>>()
+            .single(world)
+    };
+    assert_eq!(visibility(&mut app), Visibility::Hidden);
+
+    let mut input = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    input.press(KeyCode::F1);
+    app.update();
+    assert_eq!(visibility(&mut app), Visibility::Visible);
+
+    let mut input = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    input.release(KeyCode::F1);
+    input.clear();
+    input.press(KeyCode::F1);
+    app.update();
+    assert_eq!(visibility(&mut app), Visibility::Hidden);
+}
@@ -0,0 +1,69 @@
+//! Humidity comfort drift tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+/// Ants in a flooded region drift toward the drier soil next to it
+#[test]
+fn test_ants_drift_out_of_flooded_soil() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<AntAiConfig>();
+    app.add_systems(Update, systems::humidity_comfort_system);
+
+    // Flooded soil left of x = 0, comfortable soil from there on
+    for x in -6..6 {
+        for y in -8..0 {
+            app.world_mut().spawn((
+                Position {
+                    x: x as f32 * 4.0,
+                    y: y as f32 * 4.0,
+                },
+                SoilCell {
+                    moisture: if x < 0 { 1.0 } else { 0.5 },
+                    temperature: 20.0,
+                    nutrition: 0.5,
+                    compaction: 0.5,
+                },
+            ));
+        }
+    }
+
+    let ants: Vec<Entity> = [-12.0, -8.0, -4.0]
+        .iter()
+        .map(|x| {
+            app.world_mut()
+                .spawn((Position { x: *x, y: -16.0 }, Ant))
+                .id()
+        })
+        .collect();
+    let mean_x = |app: &App| {
+        ants.iter()
+            .map(|ant| app.world().get::<Position>(*ant).unwrap().x)
+            .sum::<f32>()
+            / ants.len() as f32
+    };
+    let start = mean_x(&app);
+
+    for _ in 0..100 {
+        app.update();
+    }
+
+    let end = mean_x(&app);
+    assert!(
+        end > start + 4.0,
+        "Ants should move toward dry soil, mean x went from {:.1} to {:.1}",
+        start,
+        end
+    );
+    for ant in ants.iter() {
+        let x = app.world().get::<Position>(*ant).unwrap().x;
+        assert!(x > -4.0, "Ant at x = {:.1} is still in the flood", x);
+    }
+}
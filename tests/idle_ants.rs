@@ -0,0 +1,97 @@
+//! Idle vs foraging tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::strategy::BoxedStrategy;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+const HOME: Position = Position { x: 0.0, y: 0.0 };
+
+fn idle_app(colony_food: f32) -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<AntAiConfig>();
+    app.init_resource::<PheromoneGrid>();
+    app.insert_resource(ColonyFoodStore {
+        food: colony_food,
+        protein: 50.0,
+    });
+    app.init_resource::<BoxedStrategy>();
+    app.init_resource::<ColonyStatistics>();
+    app.add_systems(
+        Update,
+        (
+            systems::foraging_behavior_system,
+            systems::colony_statistics_calculation_system,
+        )
+            .chain(),
+    );
+
+    let ant = app
+        .world_mut()
+        .spawn((
+            HOME,
+            Ant,
+            AntBehavior::new(HOME, 0.0),
+            Lifecycle {
+                age: 0.0,
+                max_age: 1000.0,
+                energy: 100.0,
+                max_energy: 100.0,
+            },
+        ))
+        .id();
+    (app, ant)
+}
+
+fn distance_from_home(app: &App, ant: Entity) -> f32 {
+    let position = app.world().get::<Position>(ant).unwrap();
+    (position.x * position.x + position.y * position.y).sqrt()
+}
+
+/// A well-fed ant in a food-rich colony idles near home instead of ranging out
+#[test]
+fn test_fed_ant_in_rich_colony_idles_near_home() {
+    let (mut app, ant) = idle_app(500.0);
+    let idle_radius = AntAiConfig::default().idle_radius;
+
+    let mut farthest: f32 = 0.0;
+    for _ in 0..300 {
+        app.update();
+        farthest = farthest.max(distance_from_home(&app, ant));
+    }
+
+    let behavior = app.world().get::<AntBehavior>(ant).unwrap();
+    assert_eq!(behavior.state, AntState::Idle);
+    assert!(
+        farthest <= idle_radius + 0.01,
+        "Idle ant wandered {} from home",
+        farthest
+    );
+    assert_eq!(app.world().resource::<ColonyStatistics>().ants_idle, 1);
+}
+
+/// Idle ants go back to foraging once the colony store runs low
+#[test]
+fn test_idle_ant_forages_when_food_runs_low() {
+    let (mut app, ant) = idle_app(500.0);
+    app.update();
+    app.update();
+    assert_eq!(
+        app.world().get::<AntBehavior>(ant).unwrap().state,
+        AntState::Idle
+    );
+
+    app.world_mut().resource_mut::<ColonyFoodStore>().food = 10.0;
+    app.update();
+
+    assert_eq!(
+        app.world().get::<AntBehavior>(ant).unwrap().state,
+        AntState::Foraging
+    );
+}
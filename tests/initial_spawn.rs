@@ -0,0 +1,64 @@
+//! Initial worker spawn distribution tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+
+/// Average distance of the founding workers from the configured home
+fn average_distance_from_home(spawn_config: InitialSpawnConfig) -> f32 {
+    let home = spawn_config.home.clone();
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(SimulationRng::from_seed(11));
+    app.insert_resource(spawn_config);
+    app.add_systems(Startup, systems::spawn_initial_ants);
+    app.update();
+
+    let distances: Vec<f32> = app
+        .world_mut()
+        .query_filtered::<&Position, With<Ant>>()
+        .iter(app.world())
+        .map(|position| Vec2::new(position.x - home.x, position.y - home.y).length())
+        .collect();
+    assert_eq!(distances.len(), 40);
+    distances.iter().sum::<f32>() / distances.len() as f32
+}
+
+/// A tight cluster keeps workers close to home while a scattered start spreads them out
+#[test]
+fn test_cluster_spawns_nearer_home_than_scattered() {
+    let home = Position { x: 30.0, y: -10.0 };
+    let cluster = average_distance_from_home(InitialSpawnConfig {
+        home: home.clone(),
+        ant_count: 40,
+        distribution: SpawnDistribution::Cluster,
+        ..default()
+    });
+    let scattered = average_distance_from_home(InitialSpawnConfig {
+        home,
+        ant_count: 40,
+        distribution: SpawnDistribution::Scattered,
+        ..default()
+    });
+
+    assert!(cluster < 16.0, "Cluster average distance {}", cluster);
+    assert!(scattered > 20.0, "Scattered average distance {}", scattered);
+}
+
+/// Sampled positions stay within the spawn radius and never above the surface of home
+#[test]
+fn test_spawn_positions_stay_within_radius_below_home() {
+    let config = InitialSpawnConfig {
+        home: Position { x: -5.0, y: 0.0 },
+        cluster_radius: 10.0,
+        ..default()
+    };
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let position = config.sample_position(&mut rng);
+        let distance = Vec2::new(position.x + 5.0, position.y).length();
+        assert!(distance <= 10.0 + 1e-4);
+        assert!(position.y <= 0.0);
+    }
+}
@@ -0,0 +1,97 @@
+//! Invasive nest tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+fn invasion_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(InvasionConfig {
+        mode: InvasionMode::Nest,
+        ..default()
+    });
+    app.init_resource::<DisasterState>();
+    app.add_systems(
+        Update,
+        (
+            systems::invasive_species_spawning_system,
+            systems::invasive_nest_system,
+            systems::invasive_species_behavior_system,
+            systems::invader_combat_system,
+            systems::invasive_species_cleanup_system,
+        )
+            .chain(),
+    );
+    app.world_mut()
+        .resource_mut::<DisasterState>()
+        .start_disaster(DisasterType::InvasiveSpecies, 1000.0);
+    app
+}
+
+fn count<T: Component>(app: &mut App) -> usize {
+    app.world_mut().query::<&T>().iter(app.world()).count()
+}
+
+/// The nest releases more invaders over time and everything is removed when the invasion ends
+#[test]
+fn test_nest_escalates_and_is_cleaned_up() {
+    let mut app = invasion_app();
+
+    for _ in 0..50 {
+        app.update();
+    }
+    assert_eq!(count::<InvasiveNest>(&mut app), 1);
+    let early = count::<InvasiveSpecies>(&mut app);
+
+    for _ in 0..70 {
+        app.update();
+    }
+    let later = count::<InvasiveSpecies>(&mut app);
+    assert!(
+        later > early + 1,
+        "Invader count should grow ({} -> {})",
+        early,
+        later
+    );
+    assert_eq!(count::<NestInvader>(&mut app), later);
+
+    app.world_mut()
+        .resource_mut::<DisasterState>()
+        .active_disasters
+        .clear();
+    app.update();
+    app.update();
+    assert_eq!(count::<InvasiveSpecies>(&mut app), 0);
+    assert_eq!(count::<InvasiveNest>(&mut app), 0);
+}
+
+/// Ants gathered on the nest wear it down until it is destroyed
+#[test]
+fn test_ants_destroy_the_nest() {
+    let mut app = invasion_app();
+    app.update();
+
+    let nest_position = app
+        .world_mut()
+        .query_filtered::<&Position, With<InvasiveNest>>()
+        .single(app.world())
+        .clone();
+    for _ in 0..10 {
+        app.world_mut().spawn((Ant, nest_position.clone()));
+    }
+
+    for _ in 0..100 {
+        app.update();
+    }
+    assert_eq!(count::<InvasiveNest>(&mut app), 0);
+    assert!(app
+        .world()
+        .resource::<DisasterState>()
+        .is_active(DisasterType::InvasiveSpecies));
+}
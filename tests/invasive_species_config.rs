@@ -0,0 +1,118 @@
+//! Invasive species config tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+const ANT_COUNT: usize = 100;
+
+fn invasion_app(species_config: InvasiveSpeciesConfig) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(species_config);
+    app.init_resource::<DisasterState>();
+    app.init_resource::<CarryingCapacity>();
+    app.add_systems(
+        Update,
+        (
+            systems::invasive_species_spawning_system,
+            systems::invasive_species_behavior_system,
+            systems::invader_combat_system,
+            systems::ant_lifecycle_system,
+        )
+            .chain(),
+    );
+    app.world_mut()
+        .resource_mut::<DisasterState>()
+        .start_disaster(DisasterType::InvasiveSpecies, 1000.0);
+    app
+}
+
+/// Cover the surface strip invaders arrive in with fragile ants
+fn spawn_ants(app: &mut App) {
+    for i in 0..ANT_COUNT {
+        let x = -100.0 + (i / 2) as f32 * 4.0;
+        let y = if i % 2 == 0 { 2.0 } else { 6.0 };
+        app.world_mut().spawn((
+            Ant,
+            Position { x, y },
+            Lifecycle {
+                age: 0.0,
+                max_age: 10_000.0,
+                energy: 100.0,
+                max_energy: 100.0,
+            },
+            Health {
+                hp: 10.0, // One lost fight is fatal
+                max_hp: 100.0,
+            },
+        ));
+    }
+}
+
+fn casualties(app: &mut App) -> usize {
+    ANT_COUNT - app.world_mut().query::<&Ant>().iter(app.world()).count()
+}
+
+/// Many aggressive invaders kill more ants than a few docile ones over the same invasion
+#[test]
+fn test_aggressive_invasion_causes_more_casualties() {
+    let mut harsh = invasion_app(InvasiveSpeciesConfig {
+        spawn_count: 50,
+        aggression: 20.0,
+        ..default()
+    });
+    let mut mild = invasion_app(InvasiveSpeciesConfig {
+        spawn_count: 1,
+        aggression: 0.0,
+        ..default()
+    });
+    spawn_ants(&mut harsh);
+    spawn_ants(&mut mild);
+
+    for _ in 0..300 {
+        harsh.update();
+        mild.update();
+    }
+
+    let harsh_casualties = casualties(&mut harsh);
+    let mild_casualties = casualties(&mut mild);
+    assert!(
+        harsh_casualties > mild_casualties,
+        "harsh {} vs mild {}",
+        harsh_casualties,
+        mild_casualties
+    );
+}
+
+/// Spawned invaders roll their lifetime and appetite from the configured ranges
+#[test]
+fn test_invaders_use_configured_stats() {
+    let mut app = invasion_app(InvasiveSpeciesConfig {
+        spawn_count: 5,
+        lifetime_range: (100.0, 100.0),
+        consumption_range: (7.0, 7.0),
+        ..default()
+    });
+
+    for _ in 0..300 {
+        app.update();
+    }
+
+    let invaders: Vec<InvasiveSpecies> = app
+        .world_mut()
+        .query::<&InvasiveSpecies>()
+        .iter(app.world())
+        .cloned()
+        .collect();
+    assert!(!invaders.is_empty() && invaders.len() <= 5);
+    for invader in invaders {
+        assert!(invader.lifetime > 60.0 && invader.lifetime <= 100.0);
+        assert_eq!(invader.food_consumption_rate, 7.0);
+    }
+}
@@ -0,0 +1,71 @@
+//! Lifespan distribution tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// Average `max_age` of the founding workers spawned under a lifespan model
+fn average_spawned_lifespan(lifespan_model: LifespanModel) -> f32 {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(lifespan_model);
+    app.add_systems(Startup, systems::spawn_initial_ants);
+    app.update();
+
+    let lifespans: Vec<f32> = app
+        .world_mut()
+        .query::<&Lifecycle>()
+        .iter(app.world())
+        .map(|lifecycle| lifecycle.max_age)
+        .collect();
+    assert!(!lifespans.is_empty());
+    lifespans.iter().sum::<f32>() / lifespans.len() as f32
+}
+
+/// A long-lived strain spawns workers that live longer on average than a short-lived one
+#[test]
+fn test_long_lifespan_config_spawns_longer_lived_ants() {
+    let short = average_spawned_lifespan(LifespanModel {
+        mean: 300.0,
+        spread: 100.0,
+        ..default()
+    });
+    let long = average_spawned_lifespan(LifespanModel {
+        mean: 1500.0,
+        spread: 500.0,
+        ..default()
+    });
+
+    assert!((200.0..=400.0).contains(&short), "Short average {}", short);
+    assert!((1000.0..=2000.0).contains(&long), "Long average {}", long);
+    assert!(long > short);
+}
+
+/// Caste multipliers let majors outlive minors, and samples stay within mean ± spread
+#[test]
+fn test_caste_multipliers_scale_lifespan() {
+    let model = LifespanModel {
+        major_multiplier: 2.0,
+        ..default()
+    };
+    let mut rng = StdRng::seed_from_u64(7);
+
+    let average = |caste: CasteClass, rng: &mut StdRng| {
+        (0..200).map(|_| model.sample(caste, rng)).sum::<f32>() / 200.0
+    };
+    let minor = average(CasteClass::Minor, &mut rng);
+    let major = average(CasteClass::Major, &mut rng);
+
+    assert!((800.0..=1000.0).contains(&minor), "Minor average {}", minor);
+    assert!(
+        (1600.0..=2000.0).contains(&major),
+        "Major average {}",
+        major
+    );
+    for _ in 0..100 {
+        let lifespan = model.sample(CasteClass::Minor, &mut rng);
+        assert!((600.0..=1200.0).contains(&lifespan));
+    }
+}
@@ -0,0 +1,174 @@
+//! UI label localization tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::localization::Localization;
+use ant_nest_simulator::systems;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+
+const GERMAN_STUB: &str = "
+# Partial translation
+settings.title = Einstellungen
+disasters.title = Aktive Katastrophen
+disaster.rain = Regen
+time.paused = Pausiert
+time.speed = Tempo
+help.title = Tastenkürzel
+help.category.time = Zeit
+action.toggle_turbo = Turbomodus
+stats.title = Kolonie-Statistik
+stats.section.population = Bevölkerung
+stats.population_total = Ameisen gesamt
+slots.slot = Spielstand
+slots.empty = leer
+";
+
+fn settings_panel_texts(localization: Localization) -> Vec<String> {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<UITheme>();
+    app.init_resource::<UserSettings>();
+    app.insert_resource(localization);
+    app.add_systems(Update, systems::handle_settings_interactions_system);
+    app.world_mut()
+        .run_system_once(systems::setup_settings_panel);
+    app.update();
+
+    let world = app.world_mut();
+    world
+        .query::<&Text>()
+        .iter(world)
+        .map(|text| text.sections[0].value.clone())
+        .collect()
+}
+
+/// A rebuilt settings panel shows the translated title, and untranslated keys as-is
+#[test]
+fn test_stub_locale_translates_settings_panel() {
+    let english = settings_panel_texts(Localization::default());
+    assert!(english.iter().any(|text| text == "Settings"));
+    assert!(english.iter().any(|text| text == "Apply"));
+
+    let german = settings_panel_texts(Localization::from_key_values("de", GERMAN_STUB));
+    assert!(german.iter().any(|text| text == "Einstellungen"));
+    assert!(!german.iter().any(|text| text == "Settings"));
+    assert!(german.iter().any(|text| text == "settings.apply"));
+}
+
+/// Switching locale re-renders the active disasters panel
+#[test]
+fn test_switching_locale_updates_disaster_panel() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<UITheme>();
+    app.init_resource::<Localization>();
+    let mut disaster_state = DisasterState::default();
+    disaster_state.start_disaster(DisasterType::Rain, 30.0);
+    app.insert_resource(disaster_state);
+    app.add_systems(Update, systems::update_active_disasters_panel);
+    app.world_mut()
+        .run_system_once(systems::setup_active_disasters_panel);
+
+    let panel_text = |app: &mut App| {
+        let world = app.world_mut();
+        world
+            .query_filtered::<&Text, With<ActiveDisastersText>>()
+            .single(world)
+            .sections[0]
+            .value
+            .clone()
+    };
+
+    app.update();
+    assert!(panel_text(&mut app).starts_with("Active Disasters\nRain"));
+
+    app.insert_resource(Localization::from_key_values("de", GERMAN_STUB));
+    app.update();
+    assert!(panel_text(&mut app).starts_with("Aktive Katastrophen\nRegen"));
+}
+
+/// Locale files are read from disk and named after the file
+#[test]
+fn test_locale_loads_from_key_value_file() {
+    let path = std::env::temp_dir().join(format!("ant_nest_locale_{}", std::process::id()));
+    std::fs::create_dir_all(&path).unwrap();
+    let file = path.join("de.txt");
+    std::fs::write(&file, GERMAN_STUB).unwrap();
+
+    let localization = Localization::load(&file).unwrap();
+    std::fs::remove_dir_all(&path).unwrap();
+
+    assert_eq!(localization.locale, "de");
+    assert_eq!(localization.t("settings.title"), "Einstellungen");
+    assert_eq!(localization.t("no.such.key"), "no.such.key");
+}
+
+/// The speed label, help overlay and statistics panel all follow the active locale
+#[test]
+fn test_stub_locale_translates_time_help_and_statistics() {
+    let german = Localization::from_key_values("de", GERMAN_STUB);
+
+    let mut time_control = TimeControl {
+        speed_multiplier: 4.0,
+        ..default()
+    };
+    assert_eq!(systems::speed_label(&time_control, &german), "Tempo 4x");
+    time_control.is_paused = true;
+    assert_eq!(systems::speed_label(&time_control, &german), "Pausiert");
+
+    let help = systems::help_text(&KeyBindings::default(), &german);
+    assert!(help.starts_with("Tastenkürzel"), "{}", help);
+    assert!(help.contains("Zeit:"));
+    assert!(help.contains("Turbomodus"));
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<UITheme>();
+    app.init_resource::<UserSettings>();
+    app.init_resource::<DisasterState>();
+    app.insert_resource(ColonyStatistics {
+        total_ant_count: 7,
+        ..default()
+    });
+    app.insert_resource(german);
+    app.add_systems(Update, systems::update_statistics_display);
+    app.world_mut()
+        .run_system_once(systems::setup_statistics_panel);
+    {
+        let world = app.world_mut();
+        for mut toggle in world.query::<&mut StatisticsToggle>().iter_mut(world) {
+            toggle.is_visible = true;
+        }
+    }
+    app.update();
+
+    let world = app.world_mut();
+    let texts: Vec<String> = world
+        .query::<&Text>()
+        .iter(world)
+        .map(|text| text.sections[0].value.clone())
+        .collect();
+    assert!(texts.iter().any(|text| text == "Kolonie-Statistik"));
+    assert!(texts.iter().any(|text| text == "[-] Bevölkerung"));
+    assert!(texts.iter().any(|text| text == "Ameisen gesamt: 7"));
+}
+
+/// Empty save slots are labeled in the active locale
+#[test]
+fn test_stub_locale_translates_save_slot_label() {
+    let german = Localization::from_key_values("de", GERMAN_STUB);
+    let empty = systems::SaveSlotInfo {
+        slot: 2,
+        saved_at: None,
+        summary: None,
+    };
+
+    assert_eq!(
+        systems::save_slot_label(&empty, &Localization::default()),
+        "Slot 2: empty"
+    );
+    assert_eq!(
+        systems::save_slot_label(&empty, &german),
+        "Spielstand 2: leer"
+    );
+}
@@ -0,0 +1,53 @@
+//! Compact FPS/entity-count HUD tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::localization::Localization;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+
+/// The HUD text should mirror whatever `PerformanceMetrics` currently reports
+#[test]
+fn test_mini_hud_reflects_performance_metrics() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<PerformanceMetrics>();
+    app.init_resource::<Localization>();
+    app.add_systems(Startup, systems::setup_mini_hud);
+    app.add_systems(Update, systems::update_mini_hud);
+
+    app.update();
+
+    {
+        let mut metrics = app.world_mut().resource_mut::<PerformanceMetrics>();
+        metrics.fps = 24.0;
+        metrics.total_entity_count = 1234;
+    }
+    app.update();
+
+    let world = app.world_mut();
+    let fps_text = world
+        .query_filtered::<&Text, With<MiniHudFpsText>>()
+        .single(world)
+        .clone();
+    assert_eq!(fps_text.sections[0].value, "FPS: 24");
+    assert_eq!(
+        fps_text.sections[0].style.color,
+        Color::srgb(1.0, 0.0, 0.0),
+        "Low FPS should be shown in red"
+    );
+
+    let entity_text = world
+        .query_filtered::<&Text, With<MiniHudEntityText>>()
+        .single(world);
+    assert_eq!(entity_text.sections[0].value, "Entities: 1234");
+
+    app.world_mut().resource_mut::<PerformanceMetrics>().fps = 75.0;
+    app.update();
+
+    let world = app.world_mut();
+    let fps_text = world
+        .query_filtered::<&Text, With<MiniHudFpsText>>()
+        .single(world);
+    assert_eq!(fps_text.sections[0].value, "FPS: 75");
+    assert_eq!(fps_text.sections[0].style.color, Color::srgb(0.0, 1.0, 0.0));
+}
@@ -0,0 +1,97 @@
+//! Nest depth, tunnel length and chamber count statistics
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+
+fn statistics_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<ColonyStatistics>();
+    app.init_resource::<ColonyFoodStore>();
+    app.add_systems(Update, systems::colony_statistics_calculation_system);
+    app
+}
+
+fn spawn_node(app: &mut App, x: f32, y: f32) -> Entity {
+    app.world_mut()
+        .spawn((
+            Position { x, y },
+            TunnelNode {
+                neighbors: Vec::new(),
+                node_type: TunnelNodeType::default(),
+            },
+        ))
+        .id()
+}
+
+/// Dig a straight tunnel between two points, returning the segment
+fn dig(app: &mut App, from: (f32, f32), to: (f32, f32)) -> Entity {
+    let from = spawn_node(app, from.0, from.1);
+    let to = spawn_node(app, to.0, to.1);
+    app.world_mut()
+        .spawn(Tunnel {
+            from,
+            to,
+            width: 2.0,
+        })
+        .id()
+}
+
+fn stats(app: &App) -> &ColonyStatistics {
+    app.world().resource::<ColonyStatistics>()
+}
+
+/// A deeper tunnel raises the max depth, and removing it falls back to the next-deepest
+#[test]
+fn test_max_depth_tracks_deepest_tunnel() {
+    let mut app = statistics_app();
+    app.update();
+    assert_eq!(stats(&app).max_nest_depth, 0.0);
+    assert_eq!(stats(&app).deepest_layer, None);
+
+    dig(&mut app, (0.0, 0.0), (0.0, -30.0));
+    app.update();
+    assert_eq!(stats(&app).max_nest_depth, 30.0);
+    assert_eq!(stats(&app).deepest_layer, Some(DepthLayer::Subsoil));
+    assert_eq!(stats(&app).total_tunnel_length, 30.0);
+
+    let deep = dig(&mut app, (0.0, -30.0), (40.0, -60.0));
+    app.update();
+    assert_eq!(stats(&app).max_nest_depth, 60.0);
+    assert_eq!(stats(&app).deepest_layer, Some(DepthLayer::Clay));
+    assert_eq!(stats(&app).total_tunnel_length, 80.0);
+
+    app.world_mut().despawn(deep);
+    app.update();
+    assert_eq!(stats(&app).max_nest_depth, 30.0);
+    assert_eq!(stats(&app).deepest_layer, Some(DepthLayer::Subsoil));
+    assert_eq!(stats(&app).total_tunnel_length, 30.0);
+}
+
+/// Chambers are counted by type
+#[test]
+fn test_chambers_counted_by_type() {
+    let mut app = statistics_app();
+    for chamber_type in [
+        ChamberType::Queen,
+        ChamberType::Nursery,
+        ChamberType::Nursery,
+        ChamberType::FoodStorage,
+    ] {
+        app.world_mut().spawn(Chamber {
+            chamber_type,
+            radius: 8.0,
+            capacity: 10,
+            occupants: 0,
+            temperature: 22.0,
+        });
+    }
+    app.update();
+
+    let stats = stats(&app);
+    assert_eq!(stats.chamber_count(ChamberType::Queen), 1);
+    assert_eq!(stats.chamber_count(ChamberType::Nursery), 2);
+    assert_eq!(stats.chamber_count(ChamberType::FoodStorage), 1);
+    assert_eq!(stats.chamber_count(ChamberType::Waste), 0);
+}
@@ -0,0 +1,184 @@
+//! Chamber construction planning tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+const NURSERY: Position = Position { x: 0.0, y: -20.0 };
+
+/// Colony with a nursery packed with `workers` ants, four of them nest maintainers
+fn crowded_colony(workers: usize) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<NestPlanningConfig>();
+    app.init_resource::<ColonyFoodStore>();
+    app.init_resource::<NestSpace>();
+    app.add_systems(
+        Update,
+        (
+            systems::chamber_occupancy_system,
+            systems::nest_planning_system,
+            systems::chamber_excavation_system,
+        )
+            .chain(),
+    );
+
+    let world = app.world_mut();
+    systems::spawn_chamber(&mut world.commands(), NURSERY, ChamberType::Nursery, 6.0, 8);
+    for i in 0..workers {
+        let role = if i < 4 {
+            SpecializedRole::NestMaintainer
+        } else {
+            SpecializedRole::NurseryWorker
+        };
+        world.spawn((
+            Ant,
+            Position {
+                x: NURSERY.x + (i % 5) as f32 - 2.0,
+                y: NURSERY.y + (i / 5) as f32 - 1.0,
+            },
+            AntBehavior::new(NURSERY, 0.0),
+            role,
+        ));
+    }
+
+    // Soil around the nursery for the new chamber to be dug out of
+    for x in -10..=10 {
+        for y in -10..=0 {
+            world.spawn((
+                Soil,
+                Position {
+                    x: x as f32 * 4.0,
+                    y: y as f32 * 4.0 - 4.0,
+                },
+            ));
+        }
+    }
+    world.flush();
+    app
+}
+
+fn nursery_count(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world
+        .query::<&Chamber>()
+        .iter(world)
+        .filter(|chamber| chamber.chamber_type == ChamberType::Nursery)
+        .count()
+}
+
+/// A full, well-staffed nursery leads to a second nursery being marked, dug and finished
+#[test]
+fn test_full_nursery_gets_a_second_one_dug() {
+    let mut app = crowded_colony(12);
+
+    for _ in 0..5 {
+        app.update();
+    }
+    let world = app.world_mut();
+    let sites: Vec<ChamberConstruction> = world
+        .query::<&ChamberConstruction>()
+        .iter(world)
+        .cloned()
+        .collect();
+    assert_eq!(sites.len(), 1, "One new chamber site should be planned");
+    assert_eq!(sites[0].chamber_type, ChamberType::Nursery);
+    let diggers = world.query::<&ExcavationTask>().iter(world).count();
+    assert_eq!(diggers, 4, "The maintainers should be sent to dig");
+
+    let soil_before = app.world().resource::<NestSpace>().excavated_cells;
+    for _ in 0..300 {
+        app.update();
+    }
+
+    assert_eq!(nursery_count(&mut app), 2);
+    assert!(app.world().resource::<NestSpace>().excavated_cells > soil_before);
+    let world = app.world_mut();
+    assert_eq!(world.query::<&ChamberConstruction>().iter(world).count(), 0);
+    assert_eq!(world.query::<&ExcavationTask>().iter(world).count(), 0);
+}
+
+/// Too few workers to spare means no expansion, however crowded the nursery
+#[test]
+fn test_understaffed_colony_does_not_expand() {
+    let mut app = crowded_colony(9);
+
+    for _ in 0..50 {
+        app.update();
+    }
+
+    let world = app.world_mut();
+    assert_eq!(world.query::<&ChamberConstruction>().iter(world).count(), 0);
+    assert_eq!(nursery_count(&mut app), 1);
+}
+
+/// Soil shared by two overlapping sites is cleared and counted only once
+#[test]
+fn test_overlapping_sites_count_shared_soil_once() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(NestPlanningConfig {
+        dig_rate: 100.0, // Both sites finish in a single frame
+        ..default()
+    });
+    app.init_resource::<NestSpace>();
+    app.add_systems(Update, systems::chamber_excavation_system);
+
+    let world = app.world_mut();
+    for x in [-1.0, 1.0] {
+        let site = systems::spawn_chamber_site(
+            &mut world.commands(),
+            Position { x, y: -20.0 },
+            ChamberConstruction {
+                chamber_type: ChamberType::FoodStorage,
+                radius: 4.0,
+                capacity: 8,
+                progress: 0.0,
+            },
+        );
+        world.flush();
+        world.spawn((
+            Ant,
+            Position { x, y: -20.0 },
+            AntBehavior::new(NURSERY, 0.0),
+            ExcavationTask { site },
+        ));
+    }
+    let mut soil_cells = 0;
+    for x in -6..=6 {
+        for y in -6..=6 {
+            let position = Position {
+                x: x as f32,
+                y: -20.0 + y as f32,
+            };
+            let near_site = [-1.0_f32, 1.0].iter().any(|site_x| {
+                let dx = position.x - site_x;
+                let dy = position.y + 20.0;
+                dx * dx + dy * dy <= 16.0
+            });
+            if near_site {
+                soil_cells += 1;
+            }
+            world.spawn((Soil, position));
+        }
+    }
+
+    app.update();
+    app.update();
+
+    let world = app.world_mut();
+    let remaining_soil = world.query_filtered::<(), With<Soil>>().iter(world).count();
+    assert_eq!(remaining_soil, 13 * 13 - soil_cells);
+    assert_eq!(
+        app.world().resource::<NestSpace>().excavated_cells,
+        soil_cells
+    );
+}
@@ -0,0 +1,189 @@
+//! Nest cross-section rendering tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::render::mesh::VertexAttributeValues;
+use bevy::sprite::Mesh2dHandle;
+
+fn nest_render_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<Assets<Mesh>>();
+    app.init_resource::<Assets<ColorMaterial>>();
+    app.add_systems(Update, systems::nest_render_system);
+    app
+}
+
+/// Largest distance of a mesh vertex from its center
+fn mesh_extent(app: &App, entity: Entity) -> f32 {
+    let handle = app.world().get::<Mesh2dHandle>(entity).unwrap();
+    let mesh = app
+        .world()
+        .resource::<Assets<Mesh>>()
+        .get(&handle.0)
+        .unwrap();
+    let Some(VertexAttributeValues::Float32x3(positions)) =
+        mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+    else {
+        panic!("Chamber mesh has no positions");
+    };
+    positions
+        .iter()
+        .map(|p| Vec2::new(p[0], p[1]).length())
+        .fold(0.0, f32::max)
+}
+
+/// A chamber is drawn as a circle of its radius in its type's color; sites are fainter
+#[test]
+fn test_chamber_circle_matches_radius() {
+    let mut app = nest_render_app();
+    let chamber = app
+        .world_mut()
+        .spawn((
+            Chamber {
+                chamber_type: ChamberType::Nursery,
+                radius: 7.5,
+                capacity: 8,
+                occupants: 0,
+                temperature: 25.0,
+            },
+            SpatialBundle::default(),
+        ))
+        .id();
+    let site = app
+        .world_mut()
+        .spawn((
+            ChamberConstruction {
+                chamber_type: ChamberType::Nursery,
+                radius: 4.0,
+                capacity: 8,
+                progress: 0.2,
+            },
+            SpatialBundle::default(),
+        ))
+        .id();
+
+    app.update();
+
+    assert!((mesh_extent(&app, chamber) - 7.5).abs() < 1e-3);
+    assert!((mesh_extent(&app, site) - 4.0).abs() < 1e-3);
+
+    let alpha = |entity: Entity| {
+        let handle = app.world().get::<Handle<ColorMaterial>>(entity).unwrap();
+        let material = app.world().resource::<Assets<ColorMaterial>>().get(handle);
+        material.unwrap().color.alpha()
+    };
+    let chamber_alpha = alpha(chamber);
+    assert!(
+        alpha(site) < chamber_alpha,
+        "Sites under construction are fainter"
+    );
+    let handle = app.world().get::<Handle<ColorMaterial>>(chamber).unwrap();
+    let color = app
+        .world()
+        .resource::<Assets<ColorMaterial>>()
+        .get(handle)
+        .unwrap()
+        .color;
+    assert_eq!(
+        color,
+        ChamberType::Nursery.color().with_alpha(chamber_alpha)
+    );
+}
+
+/// A tunnel segment is stretched so its two ends sit on the nodes it connects
+#[test]
+fn test_tunnel_segment_connects_its_nodes() {
+    let mut app = nest_render_app();
+    let from = app
+        .world_mut()
+        .spawn((Position { x: 2.0, y: -4.0 }, TunnelNode::default()))
+        .id();
+    let to = app
+        .world_mut()
+        .spawn((Position { x: 10.0, y: -10.0 }, TunnelNode::default()))
+        .id();
+    let tunnel = app
+        .world_mut()
+        .spawn((
+            Tunnel {
+                from,
+                to,
+                width: 2.0,
+            },
+            SpriteBundle {
+                transform: Transform::from_xyz(0.0, 0.0, 1.0),
+                ..default()
+            },
+        ))
+        .id();
+
+    app.update();
+
+    let transform = *app.world().get::<Transform>(tunnel).unwrap();
+    let size = app
+        .world()
+        .get::<Sprite>(tunnel)
+        .unwrap()
+        .custom_size
+        .unwrap();
+    assert_eq!(size, Vec2::new(10.0, 2.0));
+    assert_eq!(transform.translation.z, 1.0, "Tunnels stay on their layer");
+
+    let start = transform.transform_point(Vec3::new(-size.x / 2.0, 0.0, 0.0));
+    let end = transform.transform_point(Vec3::new(size.x / 2.0, 0.0, 0.0));
+    assert!(start.truncate().distance(Vec2::new(2.0, -4.0)) < 1e-4);
+    assert!(end.truncate().distance(Vec2::new(10.0, -10.0)) < 1e-4);
+}
+
+/// A tunnel is drawn faintly while soil remains at its nodes, is retried until its
+/// nodes exist, and turns opaque once it is dug out
+#[test]
+fn test_tunnel_turns_opaque_once_dug() {
+    let mut app = nest_render_app();
+    let from = app.world_mut().spawn(TunnelNode::default()).id();
+    let to = app
+        .world_mut()
+        .spawn((Position { x: 8.0, y: 0.0 }, TunnelNode::default()))
+        .id();
+    let tunnel = app
+        .world_mut()
+        .spawn((
+            Tunnel {
+                from,
+                to,
+                width: 2.0,
+            },
+            SpriteBundle::default(),
+        ))
+        .id();
+    let soil = app
+        .world_mut()
+        .spawn((Soil, Position { x: 8.5, y: 0.0 }))
+        .id();
+
+    app.update();
+    assert!(
+        app.world()
+            .get::<Sprite>(tunnel)
+            .unwrap()
+            .custom_size
+            .is_none(),
+        "A tunnel waits for both of its nodes"
+    );
+
+    app.world_mut()
+        .entity_mut(from)
+        .insert(Position { x: 0.0, y: 0.0 });
+    app.update();
+    let sprite = app.world().get::<Sprite>(tunnel).unwrap();
+    assert_eq!(sprite.custom_size, Some(Vec2::new(8.0, 2.0)));
+    assert!(sprite.color.alpha() < 1.0, "Tunnels being dug are faint");
+
+    app.world_mut().despawn(soil);
+    app.update();
+    let sprite = app.world().get::<Sprite>(tunnel).unwrap();
+    assert_eq!(sprite.color.alpha(), 1.0, "Dug tunnels are opaque");
+    assert!(app.world().get::<DugTunnel>(tunnel).is_some());
+}
@@ -0,0 +1,125 @@
+//! Night retreat into the nest tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::strategy::BoxedStrategy;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+const WORKERS: usize = 40;
+
+/// Foragers spread over the surface above a worker quarters chamber, held at `hour`
+fn colony_at(hour: f32) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<AntAiConfig>();
+    app.init_resource::<PheromoneGrid>();
+    app.init_resource::<BoxedStrategy>();
+    app.insert_resource(ColonyFoodStore {
+        food: 0.0, // Hungry colony: nobody idles for lack of work
+        protein: 0.0,
+    });
+    app.init_resource::<NightShelterConfig>();
+    let mut simulation_time = SimulationTime::default();
+    simulation_time.set_hour(hour);
+    app.insert_resource(simulation_time);
+    app.add_systems(
+        Update,
+        (
+            systems::night_shelter_system,
+            systems::foraging_behavior_system,
+        )
+            .chain(),
+    );
+
+    app.world_mut().spawn((
+        Position { x: 0.0, y: -20.0 },
+        Chamber {
+            chamber_type: ChamberType::Worker,
+            radius: 6.0,
+            capacity: WORKERS,
+            occupants: 0,
+            temperature: 20.0,
+        },
+    ));
+    for i in 0..WORKERS {
+        app.world_mut().spawn((
+            Position {
+                x: i as f32 * 2.0 - WORKERS as f32,
+                y: 2.0,
+            },
+            Ant,
+            AntBehavior::new(Position { x: 0.0, y: 0.0 }, 0.0),
+            SpecializedRole::Forager,
+        ));
+    }
+    app
+}
+
+/// Ants standing on the surface after a minute at the given hour
+fn surface_ants_after_a_minute(hour: f32) -> usize {
+    let mut app = colony_at(hour);
+    for _ in 0..600 {
+        app.update();
+    }
+    let world = app.world_mut();
+    world
+        .query_filtered::<&Position, With<Ant>>()
+        .iter(world)
+        .filter(|position| position.y >= 0.0)
+        .count()
+}
+
+/// The colony withdraws underground at midnight and stays out at midday
+#[test]
+fn test_fewer_ants_on_surface_at_night() {
+    let midnight = surface_ants_after_a_minute(0.0);
+    let midday = surface_ants_after_a_minute(12.0);
+
+    assert!(
+        midnight * 3 < midday,
+        "{} ants on the surface at midnight vs {} at midday",
+        midnight,
+        midday
+    );
+}
+
+/// Sheltering ants come back out once dawn breaks
+#[test]
+fn test_sheltering_ants_emerge_at_dawn() {
+    let mut app = colony_at(0.0);
+    for _ in 0..600 {
+        app.update();
+    }
+    let world = app.world_mut();
+    let sheltering = world
+        .query_filtered::<(), With<Sheltering>>()
+        .iter(world)
+        .count();
+    assert!(sheltering >= WORKERS * 3 / 4, "{} sheltering", sheltering);
+
+    app.world_mut()
+        .resource_mut::<SimulationTime>()
+        .set_hour(7.0);
+    for _ in 0..200 {
+        app.update();
+    }
+    let world = app.world_mut();
+    assert_eq!(
+        world
+            .query_filtered::<(), With<Sheltering>>()
+            .iter(world)
+            .count(),
+        0
+    );
+    let foraging = world
+        .query::<&AntBehavior>()
+        .iter(world)
+        .filter(|behavior| behavior.state == AntState::Foraging)
+        .count();
+    assert_eq!(foraging, WORKERS);
+}
@@ -0,0 +1,85 @@
+//! Environment-driven soil nutrition regeneration tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+fn soil(nutrition: f32) -> SoilCell {
+    SoilCell {
+        moisture: 0.5,
+        temperature: 20.0,
+        nutrition,
+        compaction: 0.0,
+    }
+}
+
+/// Nutrition of a cell next to a corpse and of an isolated cell after 5 seconds
+fn nutrition_after_five_seconds(disaster_state: DisasterState) -> (f32, f32) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<NutritionModel>();
+    app.insert_resource(disaster_state);
+    app.add_systems(Update, systems::environmental_update_system);
+
+    let near = app
+        .world_mut()
+        .spawn((Position { x: 0.0, y: -20.0 }, soil(0.2)))
+        .id();
+    let isolated = app
+        .world_mut()
+        .spawn((Position { x: 200.0, y: -20.0 }, soil(0.2)))
+        .id();
+    app.world_mut().spawn((
+        Position { x: 2.0, y: -20.0 },
+        Corpse {
+            decay_remaining: 1000.0,
+            nutrients: 0.1,
+        },
+    ));
+
+    for _ in 0..50 {
+        app.update();
+    }
+    let nutrition = |entity| app.world().get::<SoilCell>(entity).unwrap().nutrition - 0.2;
+    (nutrition(near), nutrition(isolated))
+}
+
+/// Soil next to decaying waste regains nutrition faster than soil far from any
+#[test]
+fn test_waste_boosts_nearby_regeneration() {
+    let (near, isolated) = nutrition_after_five_seconds(DisasterState::default());
+    assert!(isolated > 0.0, "Isolated soil should still regenerate");
+    assert!(
+        near > isolated * 2.0,
+        "near waste {} vs isolated {}",
+        near,
+        isolated
+    );
+}
+
+/// A drought holds regeneration back everywhere, waste or not
+#[test]
+fn test_drought_suppresses_regeneration() {
+    let (calm_near, calm_isolated) = nutrition_after_five_seconds(DisasterState::default());
+    let mut drought = DisasterState::default();
+    drought.start_disaster(DisasterType::Drought, 1000.0);
+    let (dry_near, dry_isolated) = nutrition_after_five_seconds(drought);
+
+    assert!(dry_near < calm_near * 0.5);
+    assert!(dry_isolated < calm_isolated * 0.5);
+}
+
+/// Moderately moist soil regenerates fastest
+#[test]
+fn test_moderate_moisture_regenerates_fastest() {
+    let model = NutritionModel::default();
+    let moist = model.regeneration_rate(0.5, None, false);
+    assert!(moist > model.regeneration_rate(0.0, None, false));
+    assert!(moist > model.regeneration_rate(1.0, None, false));
+    assert!(model.regeneration_rate(0.0, None, false) > 0.0);
+}
@@ -0,0 +1,79 @@
+//! Configurable disaster overlay color tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+
+fn overlay_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<ColorOverlayConfig>();
+    app.init_resource::<VisualEffectsSettings>();
+    app.init_resource::<DisasterState>();
+    app.init_resource::<UserSettings>();
+    app.add_systems(
+        Update,
+        (
+            systems::overlay_color_settings_system,
+            systems::overlay_compositor_system,
+            systems::color_overlay_system,
+        )
+            .chain(),
+    );
+    app
+}
+
+fn overlay_color(app: &mut App) -> [f32; 4] {
+    let sprite = app
+        .world_mut()
+        .query_filtered::<&Sprite, With<ColorOverlay>>()
+        .single(app.world());
+    sprite.color.to_srgba().to_f32_array()
+}
+
+fn assert_color_eq(actual: [f32; 4], expected: [f32; 4]) {
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-4, "{:?} != {:?}", actual, expected);
+    }
+}
+
+/// Overriding the Rain tint and triggering rain draws the overlay in the new color
+#[test]
+fn test_rain_overlay_uses_overridden_color() {
+    let mut app = overlay_app();
+    app.update();
+    app.world_mut()
+        .resource_mut::<ColorOverlayConfig>()
+        .set_disaster_color(DisasterType::Rain, Color::srgba(0.0, 1.0, 0.0, 0.3));
+    app.world_mut()
+        .resource_mut::<DisasterState>()
+        .start_disaster(DisasterType::Rain, 60.0);
+
+    app.update();
+    assert_color_eq(overlay_color(&mut app), [0.0, 1.0, 0.0, 0.3]);
+}
+
+/// Tints from the user settings apply live to an overlay already on screen
+#[test]
+fn test_user_color_change_updates_visible_overlay() {
+    let mut app = overlay_app();
+    app.world_mut()
+        .resource_mut::<DisasterState>()
+        .start_disaster(DisasterType::Rain, 60.0);
+    app.update();
+    app.update();
+    let built_in = overlay_color(&mut app);
+
+    app.world_mut()
+        .resource_mut::<UserSettings>()
+        .overlay_colors
+        .push(OverlayColorSetting {
+            disaster_type: DisasterType::Rain,
+            rgba: [1.0, 0.0, 1.0, 0.25],
+        });
+    app.update();
+
+    let updated = overlay_color(&mut app);
+    assert_ne!(built_in, updated);
+    assert_color_eq(updated, [1.0, 0.0, 1.0, 0.25]);
+}
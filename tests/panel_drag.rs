@@ -0,0 +1,183 @@
+//! Draggable panel tests
+
+mod common;
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems::{self, SettingsFile};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use common::temp_save_dir;
+
+/// App with an 800x600 window and one draggable panel; returns (app, window, panel, title bar)
+fn drag_app() -> (App, Entity, Entity, Entity) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<ButtonInput<MouseButton>>();
+    app.init_resource::<PanelDrag>();
+    app.init_resource::<UserSettings>();
+    app.add_systems(Update, systems::panel_drag_system);
+
+    let window = app
+        .world_mut()
+        .spawn((Window::default(), PrimaryWindow))
+        .id();
+    app.world_mut()
+        .get_mut::<Window>(window)
+        .unwrap()
+        .resolution
+        .set(800.0, 600.0);
+
+    let panel = app
+        .world_mut()
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(10.0),
+                    top: Val::Px(10.0),
+                    ..default()
+                },
+                ..default()
+            },
+            DraggablePanel { id: "test" },
+        ))
+        .id();
+    let title_bar = app
+        .world_mut()
+        .spawn((
+            NodeBundle::default(),
+            Interaction::None,
+            PanelTitleBar { panel },
+        ))
+        .id();
+    app.world_mut().entity_mut(panel).add_child(title_bar);
+    app.update();
+    (app, window, panel, title_bar)
+}
+
+fn move_cursor(app: &mut App, window: Entity, position: Vec2) {
+    app.world_mut()
+        .get_mut::<Window>(window)
+        .unwrap()
+        .set_cursor_position(Some(position));
+}
+
+fn panel_corner(app: &App, panel: Entity) -> Vec2 {
+    let style = app.world().get::<Style>(panel).unwrap();
+    match (style.left, style.top) {
+        (Val::Px(left), Val::Px(top)) => Vec2::new(left, top),
+        other => panic!("Panel should be placed in pixels, got {:?}", other),
+    }
+}
+
+/// Press on the title bar at `from`, then drag the cursor to `to`
+fn drag_title_bar(app: &mut App, window: Entity, title_bar: Entity, from: Vec2, to: Vec2) {
+    move_cursor(app, window, from);
+    app.world_mut()
+        .entity_mut(title_bar)
+        .insert(Interaction::Pressed);
+    app.world_mut()
+        .resource_mut::<ButtonInput<MouseButton>>()
+        .press(MouseButton::Left);
+    app.update();
+
+    move_cursor(app, window, to);
+    app.world_mut()
+        .resource_mut::<ButtonInput<MouseButton>>()
+        .clear();
+    app.update();
+}
+
+/// Dragging the title bar moves the panel with the cursor and remembers where it went
+#[test]
+fn test_title_bar_drag_moves_and_stores_panel() {
+    let (mut app, window, panel, title_bar) = drag_app();
+
+    // Grab the title bar 5px into the panel, then move 200px right and 100px down
+    drag_title_bar(
+        &mut app,
+        window,
+        title_bar,
+        Vec2::new(15.0, 15.0),
+        Vec2::new(215.0, 115.0),
+    );
+
+    assert_eq!(panel_corner(&app, panel), Vec2::new(210.0, 110.0));
+    assert_eq!(
+        app.world()
+            .resource::<UserSettings>()
+            .panel_position("test"),
+        Some(Vec2::new(210.0, 110.0))
+    );
+
+    // Letting go ends the drag; later cursor movement leaves the panel alone
+    app.world_mut()
+        .resource_mut::<ButtonInput<MouseButton>>()
+        .release(MouseButton::Left);
+    app.update();
+    move_cursor(&mut app, window, Vec2::new(400.0, 400.0));
+    app.update();
+    assert_eq!(panel_corner(&app, panel), Vec2::new(210.0, 110.0));
+}
+
+/// A panel dragged past the window edge stops at the edge
+#[test]
+fn test_drag_is_clamped_to_window() {
+    let (mut app, window, panel, title_bar) = drag_app();
+
+    drag_title_bar(
+        &mut app,
+        window,
+        title_bar,
+        Vec2::new(15.0, 15.0),
+        Vec2::new(2.0, 2.0),
+    );
+    assert_eq!(panel_corner(&app, panel), Vec2::ZERO);
+
+    move_cursor(&mut app, window, Vec2::new(5000.0, 5000.0));
+    app.update();
+    let corner = panel_corner(&app, panel);
+    assert!(corner.x <= 800.0 && corner.y <= 600.0, "{:?}", corner);
+    assert_eq!(
+        app.world()
+            .resource::<UserSettings>()
+            .panel_position("test"),
+        Some(corner)
+    );
+
+    // The clamp also accounts for the panel's own size
+    assert_eq!(
+        systems::clamp_panel_position(
+            Vec2::new(700.0, 550.0),
+            Vec2::new(220.0, 100.0),
+            Vec2::new(800.0, 600.0)
+        ),
+        Vec2::new(580.0, 500.0)
+    );
+}
+
+/// Letting go of a dragged panel writes its position to the settings file
+#[test]
+fn test_dropped_panel_position_is_saved_to_settings_file() {
+    let path = temp_save_dir("panel-drag-settings").join("settings.json");
+    let (mut app, window, _, title_bar) = drag_app();
+    app.insert_resource(SettingsFile {
+        path: Some(path.clone()),
+    });
+
+    drag_title_bar(
+        &mut app,
+        window,
+        title_bar,
+        Vec2::new(15.0, 15.0),
+        Vec2::new(215.0, 115.0),
+    );
+    assert!(!path.exists(), "nothing is written mid-drag");
+    app.world_mut()
+        .resource_mut::<ButtonInput<MouseButton>>()
+        .release(MouseButton::Left);
+    app.update();
+
+    let saved = systems::read_settings_file(&path).expect("settings file should be written");
+    assert_eq!(saved.panel_position("test"), Some(Vec2::new(210.0, 110.0)));
+}
@@ -0,0 +1,69 @@
+//! Particle fade curve tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems::{self, ParticleConfig};
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+/// A configured 50% fade-out keeps particles opaque until half-life, then fades them
+#[test]
+fn test_half_fade_out_starts_fading_at_half_life() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(ParticleConfig {
+        gravity: 0.0,
+        fade: ParticleFade {
+            fade_out_fraction: 0.5,
+            ..default()
+        },
+        ..default()
+    });
+    app.add_systems(Update, systems::particle_update_system);
+
+    let data = ParticleData::new_dust_mote(10.0, Vec2::ZERO);
+    let base_alpha = data.color.alpha();
+    let mote = app
+        .world_mut()
+        .spawn((
+            Particle,
+            data,
+            Sprite::default(),
+            Transform::from_xyz(400.0, 300.0, 100.0),
+        ))
+        .id();
+    let alpha = |app: &App| app.world().get::<Sprite>(mote).unwrap().color.alpha();
+
+    // Past the quarter-life mark, still at full opacity
+    for _ in 0..35 {
+        app.update();
+    }
+    assert_eq!(alpha(&app), base_alpha);
+
+    // Past half-life the fade has begun
+    for _ in 0..25 {
+        app.update();
+    }
+    assert!(alpha(&app) < base_alpha, "alpha {}", alpha(&app));
+}
+
+/// The default fades linearly over the whole life; a fade-in and easing reshape the curve
+#[test]
+fn test_fade_in_and_easing_shape_alpha() {
+    let linear = ParticleFade::default();
+    assert!((linear.alpha(0.5) - 0.5).abs() < 1e-6);
+    assert_eq!(linear.alpha(1.0), 1.0);
+
+    let soft = ParticleFade {
+        fade_in_fraction: 0.2,
+        fade_out_fraction: 0.2,
+        easing: EasingFunction::EaseIn,
+    };
+    assert_eq!(soft.alpha(1.0), 0.0); // Just spawned
+    assert!((soft.alpha(0.9) - 0.25).abs() < 1e-6); // Halfway through the eased fade-in
+    assert_eq!(soft.alpha(0.5), 1.0);
+    assert!((soft.alpha(0.1) - 0.25).abs() < 1e-6);
+}
@@ -0,0 +1,64 @@
+//! Particle gravity tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems::{self, ParticleConfig};
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+/// Distance a rain drop falls in ten 100ms ticks under the given gravity
+fn rain_fall_distance(gravity: f32) -> f32 {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(ParticleConfig {
+        gravity,
+        ..default()
+    });
+    app.add_systems(Update, systems::particle_update_system);
+
+    let start_y = 500.0;
+    let drop = app
+        .world_mut()
+        .spawn((
+            Particle,
+            ParticleData::new_rain_drop(60.0, Vec2::new(0.0, -50.0)),
+            Sprite::default(),
+            Transform::from_xyz(400.0, start_y, 100.0),
+        ))
+        .id();
+
+    for _ in 0..10 {
+        app.update();
+    }
+
+    start_y - app.world().get::<Transform>(drop).unwrap().translation.y
+}
+
+/// Stronger gravity pulls rain further in the same number of ticks
+#[test]
+fn test_higher_gravity_makes_rain_fall_further() {
+    let light = rain_fall_distance(20.0);
+    let heavy = rain_fall_distance(200.0);
+
+    assert!(light > 0.0);
+    assert!(
+        heavy > light,
+        "Heavy rain fell {:.1}px, light rain {:.1}px",
+        heavy,
+        light
+    );
+}
+
+/// Snow feels only a fraction of the gravity rain does
+#[test]
+fn test_snow_is_lighter_than_rain() {
+    assert!(systems::gravity_factor(ParticleType::Snowflake) < 0.5);
+    assert_eq!(systems::gravity_factor(ParticleType::RainDrop), 1.0);
+
+    let mut config = ParticleConfig::default();
+    config.set_intensity(1.0);
+    assert_eq!(config.gravity, systems::MAX_PARTICLE_GRAVITY);
+}
@@ -0,0 +1,32 @@
+//! Frame-time smoothing window tests
+
+use ant_nest_simulator::components::PerformanceMetrics;
+
+/// The buffer never holds more frames than the configured window
+#[test]
+fn test_frame_buffer_stays_within_window() {
+    let mut metrics = PerformanceMetrics::with_window(5);
+    for _ in 0..20 {
+        metrics.add_frame_time(0.016);
+        assert!(metrics.frame_times.len() <= 5);
+    }
+    assert_eq!(metrics.frame_times.len(), 5);
+
+    // Shrinking the window at runtime trims the buffer on the next frame
+    metrics.frame_time_window = 2;
+    metrics.add_frame_time(0.016);
+    assert_eq!(metrics.frame_times.len(), 2);
+}
+
+/// The average covers only the most recent frames in the window
+#[test]
+fn test_average_frame_time_over_known_sequence() {
+    let mut metrics = PerformanceMetrics::with_window(4);
+    for delta in [1.0, 1.0, 0.01, 0.02, 0.03, 0.04] {
+        metrics.add_frame_time(delta);
+    }
+
+    // Only 0.01..0.04 remain: average 0.025s
+    assert!((metrics.frame_time_ms - 25.0).abs() < 1e-3);
+    assert!((metrics.fps - 40.0).abs() < 1e-2);
+}
@@ -0,0 +1,95 @@
+//! Colony phase time-to-next-phase estimate tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems::{self, PhaseEstimate, PhaseProgressTracking};
+use bevy::prelude::*;
+
+/// App in the First Workers phase (3 days, 20 workers, complexity 10) with the given workers
+fn phase_app(workers: usize) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(SimulationTime {
+        elapsed_seconds: 0.0,
+        ..default()
+    });
+    app.insert_resource(NestSpace {
+        excavated_cells: 10,
+    });
+    app.insert_resource(ColonyDevelopmentPhase {
+        current_phase: DevelopmentPhase::FirstWorkers,
+        phase_conditions: PhaseConditions::for_phase(DevelopmentPhase::FirstWorkers),
+        ..default()
+    });
+    app.init_resource::<PhaseProgressTracking>();
+    app.add_systems(Update, systems::colony_development_management_system);
+
+    app.world_mut().spawn((
+        Ant,
+        Queen {
+            founding_state: FoundingState::Established,
+        },
+    ));
+    for _ in 0..workers {
+        app.world_mut().spawn(Ant);
+    }
+    app
+}
+
+/// Advance the day clock in quarter-day steps up to `days`
+fn run_until_day(app: &mut App, days: f32) {
+    let day_length = app.world().resource::<SimulationTime>().day_length_seconds;
+    let mut day = app.world().resource::<SimulationTime>().days_elapsed();
+    while day < days - 1e-4 {
+        day += systems::PHASE_RATE_SAMPLE_DAYS;
+        app.world_mut()
+            .resource_mut::<SimulationTime>()
+            .elapsed_seconds = day * day_length;
+        app.update();
+    }
+}
+
+fn estimate(app: &App) -> PhaseEstimate {
+    systems::estimate_phase_completion(
+        app.world().resource::<ColonyDevelopmentPhase>(),
+        app.world().resource::<PhaseProgressTracking>(),
+    )
+}
+
+/// With only time holding the colony back, progress is steady and the estimate is linear
+#[test]
+fn test_steady_progress_extrapolates_linearly() {
+    let mut app = phase_app(20);
+
+    run_until_day(&mut app, 1.0);
+
+    let progress = app
+        .world()
+        .resource::<ColonyDevelopmentPhase>()
+        .phase_progress;
+    assert!((progress - 1.0 / 3.0).abs() < 1e-4);
+    match estimate(&app) {
+        PhaseEstimate::Days(days) => assert!((days - 2.0).abs() < 1e-3, "Estimate {}", days),
+        other => panic!("Expected a day estimate, got {:?}", other),
+    }
+
+    run_until_day(&mut app, 2.5);
+    match estimate(&app) {
+        PhaseEstimate::Days(days) => assert!((days - 0.5).abs() < 1e-3, "Estimate {}", days),
+        other => panic!("Expected a day estimate, got {:?}", other),
+    }
+}
+
+/// A bottleneck that does not improve reads as stalled, and a mature colony has no next phase
+#[test]
+fn test_stalled_bottleneck_and_final_phase() {
+    let mut app = phase_app(10); // Half the workers needed, and none are hatching
+
+    run_until_day(&mut app, 2.0);
+    assert_eq!(estimate(&app), PhaseEstimate::Stalled);
+    assert_eq!(estimate(&app).label(), "stalled");
+
+    app.world_mut()
+        .resource_mut::<ColonyDevelopmentPhase>()
+        .current_phase = DevelopmentPhase::MatureColony;
+    assert_eq!(estimate(&app), PhaseEstimate::FinalPhase);
+}
@@ -0,0 +1,36 @@
+//! Pheromone debug overlay tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems::{pheromone_intensity, visible_pheromone_cells};
+use bevy::prelude::*;
+
+/// A heavily reinforced cell is drawn more intensely than an untouched one
+#[test]
+fn test_reinforced_cell_drawn_more_intensely() {
+    let mut grid = PheromoneGrid::default();
+    let saturation = PheromoneOverlay::default().saturation;
+    let trail = Position { x: 10.0, y: 2.0 };
+    let untouched = Position { x: -30.0, y: 2.0 };
+    for _ in 0..50 {
+        grid.deposit(&trail, 0.5);
+    }
+
+    let reinforced = pheromone_intensity(grid.concentration(&trail), saturation);
+    let empty = pheromone_intensity(grid.concentration(&untouched), saturation);
+    assert!(reinforced > empty);
+    assert_eq!(empty, 0.0);
+    assert!(reinforced <= 1.0);
+}
+
+/// Only cells inside the view are drawn
+#[test]
+fn test_only_visible_cells_drawn() {
+    let mut grid = PheromoneGrid::default();
+    grid.deposit(&Position { x: 10.0, y: 2.0 }, 1.0);
+    grid.deposit(&Position { x: 500.0, y: 2.0 }, 1.0);
+
+    let view = Rect::from_corners(Vec2::new(-50.0, -50.0), Vec2::new(50.0, 50.0));
+    let cells = visible_pheromone_cells(&grid, view, 5.0);
+    assert_eq!(cells.len(), 1);
+    assert!(cells[0].0.x < 50.0);
+}
@@ -0,0 +1,146 @@
+//! Polygynous colony tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::snapshot::colony_snapshot;
+use ant_nest_simulator::systems;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+#[derive(Resource, Default)]
+struct EggsLaid(usize);
+
+fn count_eggs_laid(mut events: EventReader<systems::EggLaidEvent>, mut eggs: ResMut<EggsLaid>) {
+    eggs.0 += events.read().count();
+}
+
+/// Well-fed colony with `queens` established queens laying eggs; returns the app and queens
+fn laying_colony(queens: usize) -> (App, Vec<Entity>) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(ColonyFoodStore {
+        food: 1000.0,
+        protein: 1000.0,
+    });
+    app.init_resource::<BroodNutritionConfig>();
+    app.init_resource::<EggsLaid>();
+    app.add_event::<systems::EggLaidEvent>();
+    app.add_systems(
+        Update,
+        (systems::queen_reproduction_system, count_eggs_laid).chain(),
+    );
+
+    let queens = (0..queens)
+        .map(|i| {
+            app.world_mut()
+                .spawn((
+                    Position {
+                        x: i as f32 * 2.0,
+                        y: -20.0,
+                    },
+                    Ant,
+                    Queen {
+                        founding_state: FoundingState::Established,
+                    },
+                    ReproductionState::default(),
+                ))
+                .id()
+        })
+        .collect();
+    (app, queens)
+}
+
+/// Eggs laid over the next `seconds` of simulated time
+fn eggs_laid_over(app: &mut App, seconds: usize) -> usize {
+    app.world_mut().resource_mut::<EggsLaid>().0 = 0;
+    for _ in 0..seconds * 10 {
+        app.update();
+    }
+    app.world().resource::<EggsLaid>().0
+}
+
+/// Two queens lay together, and the colony keeps laying after losing one of them
+#[test]
+fn test_polygynous_colony_survives_losing_a_queen() {
+    let (mut app, queens) = laying_colony(2);
+    let (mut single_app, _) = laying_colony(1);
+    let together = eggs_laid_over(&mut app, 30);
+    let alone = eggs_laid_over(&mut single_app, 30);
+    assert!(together > alone, "two queens {} vs one {}", together, alone);
+
+    app.world_mut().despawn(queens[0]);
+    assert!(
+        eggs_laid_over(&mut app, 30) > 0,
+        "The remaining queen should keep laying"
+    );
+}
+
+/// A monogynous colony stops laying for good once its only queen dies
+#[test]
+fn test_monogynous_colony_halts_without_its_queen() {
+    let (mut app, queens) = laying_colony(1);
+    assert!(eggs_laid_over(&mut app, 30) > 0);
+
+    app.world_mut().despawn(queens[0]);
+    assert_eq!(eggs_laid_over(&mut app, 30), 0);
+}
+
+/// Extra queens spawn as co-founders and move into the founder's royal chamber once dug
+#[test]
+fn test_co_founders_join_the_royal_chamber() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(InitialSpawnConfig {
+        queens_per_colony: 3,
+        ..default()
+    });
+    app.add_systems(Update, systems::co_founding_queen_system);
+    app.world_mut().run_system_once(systems::spawn_queen);
+
+    let world = app.world_mut();
+    let queens = world
+        .query_filtered::<(Entity, Option<&CoFoundingQueen>), With<Queen>>()
+        .iter(world)
+        .map(|(entity, co_founder)| (entity, co_founder.copied()))
+        .collect::<Vec<_>>();
+    assert_eq!(queens.len(), 3);
+    let founders = queens
+        .iter()
+        .filter(|(_, co_founder)| co_founder.is_none())
+        .map(|(entity, _)| *entity)
+        .collect::<Vec<_>>();
+    assert_eq!(founders.len(), 1, "Only one queen founds the nest");
+    assert!(queens
+        .iter()
+        .filter_map(|(_, co_founder)| *co_founder)
+        .all(|co_founder| co_founder.founder == founders[0]));
+
+    // The founder settles into her chamber
+    let center = Position { x: 10.0, y: -8.0 };
+    app.world_mut().entity_mut(founders[0]).insert((
+        center.clone(),
+        Queen {
+            founding_state: FoundingState::Established,
+        },
+        RoyalChamber {
+            center: center.clone(),
+            radius: 6.0,
+        },
+    ));
+    app.update();
+
+    let world = app.world_mut();
+    let mut settled = world.query::<(&Queen, &Position, Has<RoyalChamber>, Has<CoFoundingQueen>)>();
+    assert_eq!(settled.iter(world).count(), 3);
+    for (queen, position, has_chamber, co_founding) in settled.iter(world) {
+        assert!(queen.founding_state == FoundingState::Established);
+        assert!(has_chamber && !co_founding);
+        assert_eq!((position.x, position.y), (center.x, center.y));
+    }
+
+    assert_eq!(colony_snapshot(world).queen_count, 3);
+}
@@ -0,0 +1,83 @@
+//! Queen movement tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+fn queen_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<NestSpace>();
+    app.add_systems(
+        Update,
+        (
+            systems::ant_movement_system,
+            systems::queen_founding_system,
+            systems::queen_movement_system,
+        )
+            .chain(),
+    );
+    app
+}
+
+fn spawn_queen(app: &mut App, founding_state: FoundingState) -> Entity {
+    app.world_mut()
+        .spawn((
+            Ant,
+            Queen { founding_state },
+            Position { x: 10.0, y: -2.0 },
+            Transform::default(),
+        ))
+        .id()
+}
+
+/// Once founded, the queen stays inside her royal chamber across many ticks
+#[test]
+fn test_founded_queen_stays_in_chamber() {
+    let mut app = queen_app();
+    let queen = spawn_queen(&mut app, FoundingState::Digging);
+
+    app.update();
+    let chamber = app
+        .world()
+        .get::<RoyalChamber>(queen)
+        .expect("Digging should give the queen a royal chamber")
+        .clone();
+    let chambers = app
+        .world_mut()
+        .query::<&Chamber>()
+        .iter(app.world())
+        .filter(|chamber| chamber.chamber_type == ChamberType::Queen)
+        .count();
+    assert_eq!(chambers, 1);
+
+    for _ in 0..2000 {
+        app.update();
+        let position = app.world().get::<Position>(queen).unwrap();
+        let dx = position.x - chamber.center.x;
+        let dy = position.y - chamber.center.y;
+        assert!(
+            (dx * dx + dy * dy).sqrt() <= chamber.radius,
+            "Queen left her chamber at ({:.1}, {:.1})",
+            position.x,
+            position.y
+        );
+    }
+}
+
+/// A queen still seeking a site is not confined and gets no chamber
+#[test]
+fn test_seeking_queen_has_no_chamber() {
+    let mut app = queen_app();
+    let queen = spawn_queen(&mut app, FoundingState::Seeking);
+
+    for _ in 0..10 {
+        app.update();
+    }
+    assert!(app.world().get::<RoyalChamber>(queen).is_none());
+}
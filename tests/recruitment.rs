@@ -0,0 +1,140 @@
+//! Recruitment wave tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::strategy::BoxedStrategy;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+const HOME: Position = Position { x: 0.0, y: 0.0 };
+const RICH: Position = Position { x: 30.0, y: 2.0 };
+const POOR: Position = Position { x: -30.0, y: 2.0 };
+
+fn recruitment_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(AntAiConfig {
+        scout_fraction: 0.0,
+        ..default()
+    });
+    app.init_resource::<PheromoneGrid>();
+    app.init_resource::<ColonyFoodStore>();
+    app.init_resource::<BoxedStrategy>();
+    app.init_resource::<RecruitmentWaves>();
+    app.add_systems(
+        Update,
+        (
+            systems::foraging_behavior_system,
+            systems::recruitment_system,
+            systems::pheromone_decay_system,
+        )
+            .chain(),
+    );
+    app
+}
+
+/// Spawn a forager setting off along `heading`
+fn spawn_forager(world: &mut World, position: Position, heading: f32) {
+    world.spawn((
+        position,
+        Ant,
+        AntBehavior::new(HOME, heading),
+        FoodMemory::default(),
+    ));
+}
+
+/// Headings spreading the nest's foragers evenly in every direction
+fn fan_out_heading(index: usize) -> f32 {
+    index as f32 * std::f32::consts::TAU / 30.0
+}
+
+fn ants_near(app: &mut App, target: &Position) -> usize {
+    let world = app.world_mut();
+    world
+        .query_filtered::<&Position, With<Ant>>()
+        .iter(world)
+        .filter(|position| {
+            let dx = position.x - target.x;
+            let dy = position.y - target.y;
+            (dx * dx + dy * dy).sqrt() <= 8.0
+        })
+        .count()
+}
+
+/// A rich find draws a column of foragers while an equally distant poor one does not
+#[test]
+fn test_rich_source_recruits_more_foragers_than_poor_source() {
+    let mut app = recruitment_app();
+    let world = app.world_mut();
+    for (position, nutrition) in [(RICH, 200.0), (POOR, 20.0)] {
+        world.spawn((
+            position.clone(),
+            FoodSource {
+                nutrition_value: nutrition,
+                max_nutrition: nutrition,
+                kind: FoodKind::Carbohydrate,
+            },
+        ));
+        // One forager has just stumbled on each source
+        spawn_forager(world, position, 0.0);
+    }
+    for i in 0..30 {
+        spawn_forager(world, HOME, fan_out_heading(i));
+    }
+
+    for _ in 0..35 {
+        app.update();
+    }
+
+    let rich = ants_near(&mut app, &RICH);
+    let poor = ants_near(&mut app, &POOR);
+    assert!(
+        rich >= poor + 5,
+        "{} foragers at the rich source vs {} at the poor one",
+        rich,
+        poor
+    );
+    let world = app.world_mut();
+    assert!(world.query::<&Recruited>().iter(world).count() > 0);
+}
+
+/// The wave ends and releases its recruits once the source is nearly exhausted
+#[test]
+fn test_wave_ends_when_source_depletes() {
+    let mut app = recruitment_app();
+    let world = app.world_mut();
+    let source = world
+        .spawn((
+            RICH,
+            FoodSource {
+                nutrition_value: 100.0,
+                max_nutrition: 100.0,
+                kind: FoodKind::Carbohydrate,
+            },
+        ))
+        .id();
+    spawn_forager(world, RICH, 0.0);
+    for i in 0..10 {
+        spawn_forager(world, HOME, fan_out_heading(i));
+    }
+
+    app.update();
+    assert!(app
+        .world()
+        .resource::<RecruitmentWaves>()
+        .is_recruiting(source));
+
+    app.world_mut()
+        .get_mut::<FoodSource>(source)
+        .unwrap()
+        .nutrition_value = 10.0;
+    app.update();
+
+    assert!(app.world().resource::<RecruitmentWaves>().waves.is_empty());
+    let world = app.world_mut();
+    assert_eq!(world.query::<&Recruited>().iter(world).count(), 0);
+}
@@ -0,0 +1,51 @@
+//! Render layer z-ordering tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems::{self, z_for, RenderLayer};
+use bevy::prelude::*;
+
+/// Layer z values rise strictly in the intended stacking order
+#[test]
+fn test_render_layers_strictly_increase() {
+    for pair in RenderLayer::ALL.windows(2) {
+        assert!(
+            z_for(pair[0]) < z_for(pair[1]),
+            "{:?} should be drawn behind {:?}",
+            pair[0],
+            pair[1]
+        );
+    }
+}
+
+/// Spawned ants are never hidden behind the chamber they stand in
+#[test]
+fn test_ants_draw_in_front_of_chambers() {
+    let mut world = World::new();
+    let position = Position { x: 0.0, y: -20.0 };
+    let chamber = systems::spawn_chamber(
+        &mut world.commands(),
+        position.clone(),
+        ChamberType::Nursery,
+        6.0,
+        8,
+    );
+    let ant = systems::spawn_worker_ant(
+        &mut world.commands(),
+        position,
+        Lifecycle {
+            age: 0.0,
+            max_age: 1000.0,
+            energy: 100.0,
+            max_energy: 100.0,
+        },
+        CasteClass::Media,
+        BehaviorModifiers::default(),
+        &AntAiConfig::default(),
+        &mut SimulationRng::from_seed(1).0,
+    );
+    world.flush();
+
+    let z = |entity| world.get::<Transform>(entity).unwrap().translation.z;
+    assert_eq!(z(chamber), z_for(RenderLayer::Chamber));
+    assert!(z(ant) > z(chamber));
+}
@@ -0,0 +1,128 @@
+//! New game / reset tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems::{self, ResetSimulationRequest};
+use bevy::prelude::*;
+
+fn reset_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<SimulationTime>();
+    app.init_resource::<DisasterState>();
+    app.init_resource::<ColonyDevelopmentPhase>();
+    app.init_resource::<systems::PhaseProgressTracking>();
+    app.init_resource::<ColonyFoodStore>();
+    app.init_resource::<NestSpace>();
+    app.init_resource::<PheromoneGrid>();
+    app.init_resource::<SpatialGrid>();
+    app.add_event::<ResetSimulationRequest>();
+    app.add_systems(
+        Startup,
+        (
+            systems::spawn_soil_grid,
+            systems::spawn_initial_ants,
+            systems::spawn_queen,
+            systems::spawn_food_sources,
+        ),
+    );
+    app.add_systems(Update, systems::reset_simulation_system);
+    app.update();
+    app
+}
+
+fn count<T: Component>(app: &mut App) -> usize {
+    app.world_mut()
+        .query_filtered::<Entity, With<T>>()
+        .iter(app.world())
+        .count()
+}
+
+fn queen_position(app: &mut App) -> (f32, f32) {
+    let position = app
+        .world_mut()
+        .query_filtered::<&Position, With<Queen>>()
+        .single(app.world());
+    (position.x, position.y)
+}
+
+/// After a reset the world holds exactly the startup spawn and the clock is back on day 1
+#[test]
+fn test_reset_restores_initial_world() {
+    let mut app = reset_test_app();
+    let initial_soil = count::<Soil>(&mut app);
+    let initial_ants = count::<Ant>(&mut app);
+    let initial_food = count::<FoodSource>(&mut app);
+
+    // Play on: time passes, a disaster strikes and the colony changes
+    {
+        let world = app.world_mut();
+        world.resource_mut::<SimulationTime>().elapsed_seconds = 2000.0;
+        world.resource_mut::<SimulationTime>().current_day = 9;
+        world
+            .resource_mut::<DisasterState>()
+            .start_disaster(DisasterType::Rain, 30.0);
+        world.resource_mut::<ColonyDevelopmentPhase>().current_phase =
+            DevelopmentPhase::ColonyExpansion;
+        world.resource_mut::<NestSpace>().excavated_cells = 12;
+        world.spawn((
+            Position { x: 0.0, y: -30.0 },
+            Egg {
+                incubation_time: 5.0,
+            },
+        ));
+        world.spawn((Position { x: 0.0, y: 0.0 }, Ant));
+        world.spawn((
+            Position { x: 5.0, y: -20.0 },
+            Corpse {
+                decay_remaining: 60.0,
+                nutrients: 2.0,
+            },
+        ));
+        let soil = world
+            .query_filtered::<Entity, With<Soil>>()
+            .iter(world)
+            .next()
+            .unwrap();
+        world.despawn(soil); // A dug tunnel cell
+    }
+
+    app.world_mut()
+        .send_event(ResetSimulationRequest::default());
+    app.update();
+
+    assert_eq!(count::<Soil>(&mut app), initial_soil);
+    assert_eq!(count::<Ant>(&mut app), initial_ants);
+    assert_eq!(count::<Queen>(&mut app), 1);
+    assert_eq!(count::<FoodSource>(&mut app), initial_food);
+    assert_eq!(count::<Egg>(&mut app), 0);
+    assert_eq!(count::<Chamber>(&mut app), 0);
+    assert_eq!(count::<Corpse>(&mut app), 0);
+
+    let world = app.world();
+    assert_eq!(world.resource::<SimulationTime>().current_day, 1);
+    assert!(world
+        .resource::<DisasterState>()
+        .active_disasters
+        .is_empty());
+    assert_eq!(
+        world.resource::<ColonyDevelopmentPhase>().current_phase,
+        DevelopmentPhase::QueenFounding
+    );
+    assert_eq!(world.resource::<NestSpace>().excavated_cells, 0);
+}
+
+/// Resetting twice with the same seed reproduces the same world
+#[test]
+fn test_reset_with_seed_is_reproducible() {
+    let mut app = reset_test_app();
+
+    app.world_mut()
+        .send_event(ResetSimulationRequest { seed: Some(7) });
+    app.update();
+    let first = queen_position(&mut app);
+
+    app.world_mut()
+        .send_event(ResetSimulationRequest { seed: Some(7) });
+    app.update();
+    assert_eq!(queen_position(&mut app), first);
+}
@@ -0,0 +1,222 @@
+//! Responsive UI anchoring tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::localization::Localization;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+fn panel_left_and_width(app: &mut App, entity: Entity) -> (f32, f32) {
+    let style = app.world().get::<Style>(entity).unwrap();
+    match (style.left, style.width) {
+        (Val::Px(left), Val::Px(width)) => (left, width),
+        other => panic!("Panel should be laid out in pixels, got {:?}", other),
+    }
+}
+
+/// A right-anchored control panel must stay fully on-screen after the window shrinks
+#[test]
+fn test_right_anchored_panel_stays_within_resized_window() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_systems(Update, systems::responsive_layout_system);
+
+    let window = app
+        .world_mut()
+        .spawn((Window::default(), PrimaryWindow))
+        .id();
+    app.world_mut()
+        .get_mut::<Window>(window)
+        .unwrap()
+        .resolution
+        .set(800.0, 600.0);
+
+    let right_panel = app
+        .world_mut()
+        .spawn((
+            NodeBundle::default(),
+            ResponsivePanel {
+                anchor: PanelAnchor::TopRight,
+                width: 220.0,
+                margin: 20.0,
+            },
+        ))
+        .id();
+    let left_panel = app
+        .world_mut()
+        .spawn((
+            NodeBundle::default(),
+            ResponsivePanel {
+                anchor: PanelAnchor::TopLeft,
+                width: 220.0,
+                margin: 20.0,
+            },
+        ))
+        .id();
+
+    app.update();
+
+    let (left, width) = panel_left_and_width(&mut app, right_panel);
+    assert_eq!(left + width, 780.0, "Panel should hug the right margin");
+
+    // Shrink the window well below the combined panel widths
+    app.world_mut()
+        .get_mut::<Window>(window)
+        .unwrap()
+        .resolution
+        .set(320.0, 240.0);
+    app.update();
+
+    let (left, width) = panel_left_and_width(&mut app, right_panel);
+    assert!(left >= 0.0, "Panel left edge off-screen: {}", left);
+    assert!(
+        left + width <= 320.0,
+        "Panel right edge off-screen: {}",
+        left + width
+    );
+
+    let (other_left, other_width) = panel_left_and_width(&mut app, left_panel);
+    assert!(
+        other_left + other_width <= left,
+        "Left and right panels should not overlap at small window sizes"
+    );
+}
+
+/// The settings panel and its toggle button follow the right edge of a resized window
+#[test]
+fn test_settings_panel_and_toggle_follow_window_edge() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<UITheme>();
+    app.init_resource::<Localization>();
+    app.add_systems(
+        Startup,
+        (
+            systems::setup_settings_panel,
+            systems::setup_settings_toggle_button,
+        ),
+    );
+    app.add_systems(Update, systems::responsive_layout_system);
+
+    let window = app
+        .world_mut()
+        .spawn((Window::default(), PrimaryWindow))
+        .id();
+    app.world_mut()
+        .get_mut::<Window>(window)
+        .unwrap()
+        .resolution
+        .set(1000.0, 600.0);
+    app.update();
+
+    let panel = app
+        .world_mut()
+        .query_filtered::<Entity, With<SettingsPanel>>()
+        .single(app.world());
+    let toggle = app
+        .world_mut()
+        .query_filtered::<Entity, With<SettingsToggle>>()
+        .single(app.world());
+
+    for entity in [panel, toggle] {
+        let (left, width) = panel_left_and_width(&mut app, entity);
+        assert_eq!(left + width, 990.0, "Should hug the right margin");
+    }
+
+    app.world_mut()
+        .get_mut::<Window>(window)
+        .unwrap()
+        .resolution
+        .set(500.0, 400.0);
+    app.update();
+
+    for entity in [panel, toggle] {
+        let (left, width) = panel_left_and_width(&mut app, entity);
+        assert!(left >= 0.0, "Left edge off-screen: {}", left);
+        assert_eq!(left + width, 490.0, "Should follow the right margin");
+    }
+}
+
+fn panel_bottom(app: &App, entity: Entity) -> f32 {
+    match app.world().get::<Style>(entity).unwrap().bottom {
+        Val::Px(bottom) => bottom,
+        other => panic!("Panel should be anchored to the bottom, got {:?}", other),
+    }
+}
+
+/// Panels sharing an edge are stacked instead of drawn over each other
+#[test]
+fn test_panels_on_the_same_edge_are_stacked() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_systems(Update, systems::responsive_layout_system);
+
+    let window = app
+        .world_mut()
+        .spawn((Window::default(), PrimaryWindow))
+        .id();
+    app.world_mut()
+        .get_mut::<Window>(window)
+        .unwrap()
+        .resolution
+        .set(800.0, 600.0);
+
+    // Laid out before the statistics panel, which it must not push aside
+    let hidden = app
+        .world_mut()
+        .spawn((
+            NodeBundle {
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            ResponsivePanel {
+                anchor: PanelAnchor::BottomLeft,
+                width: 260.0,
+                margin: 10.0,
+            },
+        ))
+        .id();
+
+    let mut spawn_panel = |anchor: PanelAnchor, width: f32| {
+        app.world_mut()
+            .spawn((
+                NodeBundle::default(),
+                ResponsivePanel {
+                    anchor,
+                    width,
+                    margin: 10.0,
+                },
+            ))
+            .id()
+    };
+    let selection = spawn_panel(PanelAnchor::BottomRight, 220.0);
+    let profiler = spawn_panel(PanelAnchor::BottomRight, 320.0);
+    let statistics = spawn_panel(PanelAnchor::BottomLeft, 350.0);
+    let comparison = spawn_panel(PanelAnchor::BottomCenter, 460.0);
+
+    app.update();
+
+    assert!(
+        panel_bottom(&app, profiler) > panel_bottom(&app, selection),
+        "The second bottom-right panel sits above the first"
+    );
+    assert!(
+        panel_bottom(&app, comparison) > panel_bottom(&app, statistics),
+        "A centered panel overlapping a corner panel is pushed past it"
+    );
+    assert_eq!(
+        panel_bottom(&app, statistics),
+        10.0,
+        "A hidden panel takes no room in the stack"
+    );
+    assert_eq!(panel_bottom(&app, hidden), 10.0);
+
+    // A wide window leaves room for the centered panel beside the corners
+    app.world_mut()
+        .get_mut::<Window>(window)
+        .unwrap()
+        .resolution
+        .set(1600.0, 900.0);
+    app.update();
+    assert_eq!(panel_bottom(&app, comparison), 10.0);
+}
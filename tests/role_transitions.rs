@@ -0,0 +1,105 @@
+//! Role transition event tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems::{self, RoleTransitionEvent};
+use bevy::prelude::*;
+
+#[derive(Resource, Default)]
+struct Received(Vec<RoleTransitionEvent>);
+
+fn collect_transitions(
+    mut transitions: EventReader<RoleTransitionEvent>,
+    mut received: ResMut<Received>,
+) {
+    received.0.extend(transitions.read().copied());
+}
+
+fn role_app() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_event::<RoleTransitionEvent>();
+    app.init_resource::<EventLog>();
+    app.init_resource::<Received>();
+    app.add_systems(
+        Update,
+        (
+            systems::role_transition_system,
+            systems::role_transition_log_system,
+            collect_transitions,
+        )
+            .chain(),
+    );
+
+    let ant = app
+        .world_mut()
+        .spawn((Ant, SpecializedRole::NurseryWorker))
+        .id();
+    app.update();
+    (app, ant)
+}
+
+/// A real role change emits one event with both roles and logs it
+#[test]
+fn test_role_change_emits_single_event() {
+    let (mut app, ant) = role_app();
+    assert!(app.world().resource::<Received>().0.is_empty());
+
+    *app.world_mut().get_mut::<SpecializedRole>(ant).unwrap() = SpecializedRole::Forager;
+    app.update();
+    app.update();
+
+    let received = &app.world().resource::<Received>().0;
+    assert_eq!(
+        received,
+        &vec![RoleTransitionEvent {
+            entity: ant,
+            from: SpecializedRole::NurseryWorker,
+            to: SpecializedRole::Forager,
+        }]
+    );
+    let event_log = app.world().resource::<EventLog>();
+    assert_eq!(event_log.entries.len(), 1);
+    assert!(event_log.entries[0].contains("NurseryWorker to Forager"));
+}
+
+/// Rewriting the same role does not count as a transition
+#[test]
+fn test_unchanged_role_emits_nothing() {
+    let (mut app, ant) = role_app();
+
+    *app.world_mut().get_mut::<SpecializedRole>(ant).unwrap() = SpecializedRole::NurseryWorker;
+    app.update();
+    app.update();
+
+    assert!(app.world().resource::<Received>().0.is_empty());
+    assert!(app.world().resource::<EventLog>().entries.is_empty());
+}
+
+/// Role changes pulse the ant's sprite, except with reduced motion
+#[test]
+fn test_role_flash_respects_reduced_motion() {
+    for reduced_motion in [false, true] {
+        let (mut app, ant) = role_app();
+        app.insert_resource(VisualEffectsSettings {
+            reduced_motion,
+            ..default()
+        });
+        app.add_systems(
+            Update,
+            systems::role_flash_system.after(systems::role_transition_system),
+        );
+        app.world_mut()
+            .entity_mut(ant)
+            .insert(TransformBundle::default());
+
+        *app.world_mut().get_mut::<SpecializedRole>(ant).unwrap() = SpecializedRole::Forager;
+        app.update();
+        app.update();
+
+        let flashing = app.world().get::<RoleFlash>(ant).is_some();
+        assert_eq!(flashing, !reduced_motion);
+        if reduced_motion {
+            assert_eq!(app.world().get::<Transform>(ant).unwrap().scale, Vec3::ONE);
+        }
+    }
+}
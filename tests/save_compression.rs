@@ -0,0 +1,93 @@
+//! Compressed save file tests
+
+mod common;
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::localization::Localization;
+use ant_nest_simulator::systems::{self, PersistenceState, SaveSlotRequest, SaveSlots, SlotAction};
+use bevy::prelude::*;
+use common::temp_save_dir;
+use std::path::PathBuf;
+
+/// Save a seeded colony of ants over a thousand soil cells to slot 1, plain or compressed
+fn save_populated_world(save_dir: PathBuf, compress_saves: bool) -> PathBuf {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(PersistenceState {
+        save_directory: Some(save_dir.clone()),
+        compress_saves,
+        ..default()
+    });
+    app.init_resource::<SaveSlots>();
+    app.init_resource::<Localization>();
+    app.init_resource::<ColonyFoodStore>();
+    app.init_resource::<NestSpace>();
+    app.insert_resource(SimulationRng::from_seed(7));
+    app.add_event::<SaveSlotRequest>();
+    app.add_systems(Startup, systems::spawn_initial_ants);
+    app.add_systems(Update, systems::save_slot_request_system);
+    for i in 0..1000 {
+        app.world_mut().spawn((
+            Position {
+                x: (i % 50) as f32 * 4.0,
+                y: -((i / 50) as f32) * 4.0,
+            },
+            Soil,
+            SoilCell {
+                moisture: 0.5,
+                temperature: 20.0,
+                nutrition: 0.5,
+                compaction: SoilCell::compaction_at_depth(-((i / 50) as f32) * 4.0),
+            },
+        ));
+    }
+    app.update();
+
+    app.world_mut().send_event(SaveSlotRequest {
+        slot: 1,
+        action: SlotAction::Save,
+    });
+    app.update();
+    systems::slot_path(&save_dir, 1)
+}
+
+/// A loaded save as JSON, minus the clock readings that differ between two runs
+fn world_json(path: &std::path::Path) -> serde_json::Value {
+    let save_data = systems::read_save(path).expect("save should load");
+    let mut json = serde_json::to_value(&save_data).unwrap();
+    json["metadata"]["creation_time"] = serde_json::Value::Null;
+    json["metadata"]["colony_age"] = serde_json::Value::Null;
+    json["game_state"]["simulation_time"] = serde_json::Value::Null;
+    json
+}
+
+/// A compressed save loads back to the same world as a plain one and takes less space
+#[test]
+fn test_compressed_save_round_trips_and_is_smaller() {
+    let plain_dir = temp_save_dir("compression-plain");
+    let compressed_dir = temp_save_dir("compression-gzip");
+    let plain = save_populated_world(plain_dir.clone(), false);
+    let compressed = save_populated_world(compressed_dir.clone(), true);
+
+    assert_eq!(world_json(&plain), world_json(&compressed));
+    assert_eq!(
+        systems::read_save(&compressed).unwrap().soil_cells.len(),
+        1000
+    );
+
+    let plain_size = std::fs::metadata(&plain).unwrap().len();
+    let compressed_size = std::fs::metadata(&compressed).unwrap().len();
+    assert!(
+        compressed_size < plain_size / 2,
+        "compressed {} vs plain {} bytes",
+        compressed_size,
+        plain_size
+    );
+
+    // The slot list still reads the summary out of the compressed file
+    let summary = systems::read_save_summary(&compressed).unwrap().unwrap();
+    assert_eq!(summary.population, 5);
+
+    let _ = std::fs::remove_dir_all(plain_dir);
+    let _ = std::fs::remove_dir_all(compressed_dir);
+}
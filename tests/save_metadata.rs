@@ -0,0 +1,100 @@
+//! Save summary and slot preview tests
+
+mod common;
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::localization::Localization;
+use ant_nest_simulator::systems::{self, PersistenceState, SaveSlotRequest, SaveSlots, SlotAction};
+use bevy::prelude::*;
+use common::temp_save_dir;
+use std::path::PathBuf;
+
+fn save_to_slot(save_dir: PathBuf, slot: usize) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(PersistenceState {
+        save_directory: Some(save_dir),
+        ..default()
+    });
+    app.init_resource::<SaveSlots>();
+    app.init_resource::<Localization>();
+    app.init_resource::<ColonyFoodStore>();
+    app.init_resource::<NestSpace>();
+    app.insert_resource(SimulationTime {
+        current_day: 4,
+        ..default()
+    });
+    app.insert_resource(ColonyDevelopmentPhase {
+        current_phase: DevelopmentPhase::FirstWorkers,
+        ..default()
+    });
+    app.add_event::<SaveSlotRequest>();
+    app.add_systems(Startup, systems::spawn_initial_ants);
+    app.add_systems(Update, systems::save_slot_request_system);
+    app.update();
+
+    app.world_mut().send_event(SaveSlotRequest {
+        slot,
+        action: SlotAction::Save,
+    });
+    app.update();
+    app
+}
+
+/// Saving records the simulated day, population and phase in the slot listing
+#[test]
+fn test_save_records_summary_in_slot_listing() {
+    let save_dir = temp_save_dir("summary-slot");
+    let app = save_to_slot(save_dir.clone(), 1);
+
+    let summary = app
+        .world()
+        .resource::<SaveSlots>()
+        .get(1)
+        .unwrap()
+        .summary
+        .clone();
+    let summary = summary.expect("Saved slot should have a summary");
+    assert_eq!(summary.simulated_day, 4);
+    assert_eq!(summary.population, 5);
+    assert_eq!(summary.colony_phase, "First Workers");
+    let thumbnail = summary.thumbnail.expect("Save should carry a thumbnail");
+    assert_eq!(
+        thumbnail.pixels.len(),
+        (thumbnail.width * thumbnail.height * 4) as usize
+    );
+
+    let label = systems::save_slot_label(
+        app.world().resource::<SaveSlots>().get(1).unwrap(),
+        &Localization::default(),
+    );
+    assert!(label.contains("Day 4, 5 ants"), "{}", label);
+
+    let _ = std::fs::remove_dir_all(save_dir);
+}
+
+/// The summary is read on its own: a save whose world data is damaged still lists
+#[test]
+fn test_summary_reads_without_decoding_world() {
+    let save_dir = temp_save_dir("summary-header");
+    save_to_slot(save_dir.clone(), 2);
+    let path = systems::slot_path(&save_dir, 2);
+
+    // Chop off the tail, where the entities are stored
+    let bytes = std::fs::read(&path).unwrap();
+    std::fs::write(&path, &bytes[..bytes.len() - 16]).unwrap();
+
+    assert!(systems::read_save(&path).is_err());
+    let summary = systems::read_save_summary(&path).unwrap().unwrap();
+    assert_eq!(summary.simulated_day, 4);
+    assert_eq!(summary.population, 5);
+
+    let mut slots = SaveSlots::default();
+    slots.refresh(&save_dir);
+    assert_eq!(
+        slots.get(2).unwrap().summary.as_ref().unwrap().population,
+        5
+    );
+
+    let _ = std::fs::remove_dir_all(save_dir);
+}
@@ -0,0 +1,245 @@
+//! Save format migration tests
+
+mod common;
+
+use ant_nest_simulator::components::{
+    AntId, AntName, CasteClass, ColonyTraits, FoundingState, Health, Lifecycle, Position, SoilCell,
+};
+use ant_nest_simulator::systems::{
+    load_from_json, migrate_save, read_save, MigrationError, PersistenceError, SaveMetadata,
+    SoilData, SAVE_FORMAT_VERSION,
+};
+use common::temp_save_dir;
+use serde::{Deserialize, Serialize};
+
+/// A v1 save as written before soil compaction and `format_version` existed
+const V1_SAVE: &str = r#"{
+    "ants": [
+        {
+            "position": { "x": 4.0, "y": -8.0 },
+            "lifecycle": { "age": 10.0, "max_age": 3600.0, "energy": 80.0, "max_energy": 100.0 },
+            "caste": "Minor",
+            "health": null
+        }
+    ],
+    "soil_cells": [
+        {
+            "position": { "x": 0.0, "y": -40.0 },
+            "soil_cell": { "moisture": 0.5, "temperature": 20.0, "nutrition": 0.4 }
+        },
+        {
+            "position": { "x": 4.0, "y": -80.0 },
+            "soil_cell": { "moisture": 0.6, "temperature": 19.0, "nutrition": 0.3 }
+        }
+    ],
+    "queen": null,
+    "game_state": {
+        "simulation_time": 120.0,
+        "colony_food": 50.0,
+        "colony_protein": 20.0,
+        "excavated_cells": 3
+    },
+    "metadata": {
+        "save_name": "old_save",
+        "creation_time": "2024-01-01T00:00:00+00:00",
+        "colony_age": 120.0,
+        "ant_population": 1,
+        "version": "0.1.0"
+    }
+}"#;
+
+/// A v1 save lacking newer fields migrates and loads with depth-based compaction filled in
+#[test]
+fn test_v1_save_migrates_and_loads_with_defaults() {
+    let save_data = load_from_json(V1_SAVE).expect("v1 save should migrate and load");
+
+    assert_eq!(save_data.metadata.format_version, SAVE_FORMAT_VERSION);
+    assert_eq!(save_data.ants.len(), 1);
+    assert_eq!(save_data.game_state.excavated_cells, 3);
+
+    let shallow = &save_data.soil_cells[0];
+    let deep = &save_data.soil_cells[1];
+    assert_eq!(shallow.soil_cell.moisture, 0.5);
+    assert_eq!(
+        shallow.soil_cell.compaction,
+        SoilCell::compaction_at_depth(-40.0)
+    );
+    assert!(deep.soil_cell.compaction > shallow.soil_cell.compaction);
+}
+
+/// The bincode layout v1 builds wrote to disk, without any header
+#[derive(Serialize, Deserialize)]
+struct V1SaveData {
+    ants: Vec<V1AntData>,
+    soil_cells: Vec<V1SoilData>,
+    queen: Option<V1QueenData>,
+    game_state: V1GameState,
+    metadata: V1Metadata,
+}
+
+#[derive(Serialize, Deserialize)]
+struct V1AntData {
+    position: Position,
+    lifecycle: Lifecycle,
+    caste: CasteClass,
+    health: Option<Health>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct V1QueenData {
+    position: Position,
+    founding_state: FoundingState,
+    traits: ColonyTraits,
+}
+
+#[derive(Serialize, Deserialize)]
+struct V1SoilData {
+    position: Position,
+    soil_cell: V1SoilCell,
+}
+
+#[derive(Serialize, Deserialize)]
+struct V1SoilCell {
+    moisture: f32,
+    temperature: f32,
+    nutrition: f32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct V1GameState {
+    simulation_time: f32,
+    colony_food: f32,
+    colony_protein: f32,
+    excavated_cells: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct V1Metadata {
+    save_name: String,
+    creation_time: String,
+    colony_age: f32,
+    ant_population: usize,
+    version: String,
+}
+
+/// A v1 bincode save file is migrated by `read_save` instead of failing to decode
+#[test]
+fn test_v1_bincode_save_file_migrates_on_read() {
+    let v1: V1SaveData = serde_json::from_str(V1_SAVE).unwrap();
+    let path = temp_save_dir("v1-bincode").join("old_save.save");
+    std::fs::write(&path, bincode::serialize(&v1).unwrap()).unwrap();
+
+    let save_data = read_save(&path).expect("v1 bincode save should migrate and load");
+
+    assert_eq!(save_data.metadata.format_version, SAVE_FORMAT_VERSION);
+    assert_eq!(save_data.metadata.save_name, "old_save");
+    assert_eq!(save_data.ants.len(), 1);
+    assert_eq!(save_data.game_state.excavated_cells, 3);
+    assert_eq!(save_data.soil_cells[1].soil_cell.moisture, 0.6);
+    assert_eq!(
+        save_data.soil_cells[0].soil_cell.compaction,
+        SoilCell::compaction_at_depth(-40.0)
+    );
+}
+
+/// The bincode layout v2 builds wrote, before ants had an id or name
+#[derive(Serialize, Deserialize)]
+struct V2SaveData {
+    ants: Vec<V1AntData>,
+    soil_cells: Vec<SoilData>,
+    queen: Option<V1QueenData>,
+    game_state: V1GameState,
+    metadata: SaveMetadata,
+}
+
+/// Ants of a v2 bincode save load without an id or name
+#[test]
+fn test_v2_bincode_save_file_loads_ants_without_names() {
+    let raw = migrate_save(serde_json::from_str(V1_SAVE).unwrap(), 1, 2).unwrap();
+    let v2: V2SaveData = serde_json::from_value(raw).unwrap();
+    let path = temp_save_dir("v2-bincode").join("old_save.save");
+    std::fs::write(&path, bincode::serialize(&v2).unwrap()).unwrap();
+
+    let save_data = read_save(&path).expect("v2 bincode save should migrate and load");
+
+    assert_eq!(save_data.metadata.format_version, SAVE_FORMAT_VERSION);
+    assert_eq!(save_data.ants.len(), 1);
+    assert!(save_data.ants[0].id.is_none());
+    assert!(save_data.ants[0].name.is_none());
+    assert_eq!(save_data.ants[0].lifecycle.age, 10.0);
+}
+
+/// The bincode layout v3 builds wrote, before colonies could hold several queens
+#[derive(Serialize, Deserialize)]
+struct V3SaveData {
+    ants: Vec<V3AntData>,
+    soil_cells: Vec<SoilData>,
+    queen: Option<V1QueenData>,
+    game_state: V1GameState,
+    metadata: SaveMetadata,
+}
+
+#[derive(Serialize, Deserialize)]
+struct V3AntData {
+    position: Position,
+    lifecycle: Lifecycle,
+    caste: CasteClass,
+    health: Option<Health>,
+    id: Option<AntId>,
+    name: Option<AntName>,
+}
+
+/// A v3 bincode save loads with its single queen and no extra queens
+#[test]
+fn test_v3_bincode_save_file_loads_without_extra_queens() {
+    let mut raw = migrate_save(serde_json::from_str(V1_SAVE).unwrap(), 1, 3).unwrap();
+    raw["queen"] = serde_json::json!({
+        "position": { "x": 0.0, "y": -20.0 },
+        "founding_state": "Established",
+        "traits": {
+            "queen_vigor": 1.0,
+            "worker_efficiency": 1.0,
+            "architectural_skill": 1.0,
+            "environmental_adaptation": 1.0
+        }
+    });
+    let v3: V3SaveData = serde_json::from_value(raw).unwrap();
+    let path = temp_save_dir("v3-bincode").join("old_save.save");
+    std::fs::write(&path, bincode::serialize(&v3).unwrap()).unwrap();
+
+    let save_data = read_save(&path).expect("v3 bincode save should migrate and load");
+
+    assert_eq!(save_data.metadata.format_version, SAVE_FORMAT_VERSION);
+    let queen = save_data.queen.expect("Queen should survive the migration");
+    assert!(queen.lifecycle.is_none() && queen.health.is_none());
+    assert!(save_data.extra_queens.is_empty());
+    assert_eq!(save_data.ants.len(), 1);
+    assert!(save_data.ants[0].behavior.is_none());
+    assert!(save_data.ants[0].modifiers.is_none());
+}
+
+/// Versions outside the supported range are rejected with a clear error
+#[test]
+fn test_unknown_and_too_old_versions_error() {
+    let raw: serde_json::Value = serde_json::from_str(V1_SAVE).unwrap();
+
+    assert_eq!(
+        migrate_save(raw.clone(), 0, SAVE_FORMAT_VERSION),
+        Err(MigrationError::TooOld(0))
+    );
+    assert_eq!(
+        migrate_save(raw, SAVE_FORMAT_VERSION + 1, SAVE_FORMAT_VERSION),
+        Err(MigrationError::UnknownVersion(SAVE_FORMAT_VERSION + 1))
+    );
+
+    let future_save = V1_SAVE.replace(
+        r#""version": "0.1.0""#,
+        r#""version": "9.0.0", "format_version": 99"#,
+    );
+    assert!(matches!(
+        load_from_json(&future_save),
+        Err(PersistenceError::Migration(MigrationError::UnknownVersion(
+            99
+        )))
+    ));
+}
@@ -0,0 +1,385 @@
+//! Manual save slot management tests
+
+mod common;
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::localization::Localization;
+use ant_nest_simulator::systems::{self, PersistenceState, SaveSlotRequest, SaveSlots, SlotAction};
+use bevy::prelude::*;
+use common::temp_save_dir;
+use std::path::PathBuf;
+
+fn slot_test_app(save_dir: PathBuf) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(PersistenceState {
+        save_directory: Some(save_dir),
+        ..default()
+    });
+    app.init_resource::<SaveSlots>();
+    app.init_resource::<Localization>();
+    app.init_resource::<ColonyFoodStore>();
+    app.init_resource::<NestSpace>();
+    app.add_event::<SaveSlotRequest>();
+    app.add_systems(Startup, systems::spawn_initial_ants);
+    app.add_systems(Update, systems::save_slot_request_system);
+    app.update();
+    app
+}
+
+/// Saving to slot 2 populates only slot 2 with a fresh timestamp
+#[test]
+fn test_save_to_slot_two_lists_slot_two_populated() {
+    let save_dir = temp_save_dir("slot-save");
+    let mut app = slot_test_app(save_dir.clone());
+
+    app.world_mut().send_event(SaveSlotRequest {
+        slot: 2,
+        action: SlotAction::Save,
+    });
+    app.update();
+
+    let save_slots = app.world().resource::<SaveSlots>();
+    let slot_two = save_slots.get(2).unwrap();
+    let saved_at = slot_two.saved_at.expect("Slot 2 should be populated");
+    let age = chrono::Utc::now() - saved_at;
+    assert!(age.num_seconds().abs() < 60, "Timestamp should be recent");
+    assert!(save_slots.get(1).unwrap().saved_at.is_none());
+    assert!(save_slots.get(3).unwrap().saved_at.is_none());
+
+    let _ = std::fs::remove_dir_all(save_dir);
+}
+
+/// Loading an empty slot reports an error instead of panicking or clearing the world
+#[test]
+fn test_loading_empty_slot_reports_error() {
+    let save_dir = temp_save_dir("slot-empty");
+    let mut app = slot_test_app(save_dir.clone());
+
+    app.world_mut().send_event(SaveSlotRequest {
+        slot: 1,
+        action: SlotAction::Load,
+    });
+    app.update();
+
+    let status = app
+        .world()
+        .resource::<SaveSlots>()
+        .status_message
+        .clone()
+        .unwrap();
+    assert!(status.starts_with("Error"), "Status was {:?}", status);
+
+    let world = app.world_mut();
+    assert_eq!(world.query::<&Ant>().iter(world).count(), 5);
+
+    let _ = std::fs::remove_dir_all(save_dir);
+}
+
+/// Chambers, surface food, eggs and the simulated clock survive a save and load
+#[test]
+fn test_slot_round_trip_restores_nest_contents_and_clock() {
+    let save_dir = temp_save_dir("slot-round-trip");
+    let mut app = slot_test_app(save_dir.clone());
+    app.insert_resource(SimulationTime {
+        elapsed_seconds: 1000.0,
+        current_day: 5,
+        ..default()
+    });
+    app.world_mut().spawn((
+        Position { x: 10.0, y: -30.0 },
+        Chamber {
+            chamber_type: ChamberType::Nursery,
+            radius: 6.0,
+            capacity: 8,
+            occupants: 3,
+            temperature: 27.5,
+        },
+    ));
+    app.world_mut().spawn((
+        Position { x: 40.0, y: 5.0 },
+        FoodSource {
+            nutrition_value: 12.0,
+            max_nutrition: 30.0,
+            kind: FoodKind::Protein,
+        },
+    ));
+    app.world_mut().spawn((
+        Position { x: 11.0, y: -31.0 },
+        Egg {
+            incubation_time: 42.0,
+        },
+    ));
+
+    app.world_mut().send_event(SaveSlotRequest {
+        slot: 1,
+        action: SlotAction::Save,
+    });
+    app.update();
+    app.insert_resource(SimulationTime::default());
+    app.world_mut().send_event(SaveSlotRequest {
+        slot: 1,
+        action: SlotAction::Load,
+    });
+    app.update();
+
+    let world = app.world_mut();
+    let chambers: Vec<Chamber> = world.query::<&Chamber>().iter(world).cloned().collect();
+    assert_eq!(chambers.len(), 1);
+    assert_eq!(chambers[0].chamber_type, ChamberType::Nursery);
+    assert_eq!(chambers[0].capacity, 8);
+    assert_eq!(chambers[0].temperature, 27.5);
+
+    let food: Vec<FoodSource> = world.query::<&FoodSource>().iter(world).cloned().collect();
+    assert_eq!(food.len(), 1);
+    assert_eq!(food[0].nutrition_value, 12.0);
+    assert_eq!(food[0].max_nutrition, 30.0);
+    assert_eq!(food[0].kind, FoodKind::Protein);
+
+    let eggs: Vec<Egg> = world.query::<&Egg>().iter(world).cloned().collect();
+    assert_eq!(eggs.len(), 1);
+    assert_eq!(eggs[0].incubation_time, 42.0);
+
+    assert_eq!(world.query::<&Ant>().iter(world).count(), 5);
+    let simulation_time = world.resource::<SimulationTime>();
+    assert_eq!(simulation_time.elapsed_seconds, 1000.0);
+    assert_eq!(simulation_time.current_day, 5);
+
+    let _ = std::fs::remove_dir_all(save_dir);
+}
+
+/// Workers keep their task and load, and queens their condition, across a save and load
+#[test]
+fn test_slot_round_trip_restores_worker_behavior_and_queen_state() {
+    let save_dir = temp_save_dir("slot-behavior");
+    let mut app = slot_test_app(save_dir.clone());
+    let world = app.world_mut();
+    for mut behavior in world.query::<&mut AntBehavior>().iter_mut(world) {
+        behavior.state = AntState::Returning;
+        behavior.carried_food = 3.5;
+        behavior.home_position = Position { x: 12.0, y: -4.0 };
+    }
+    world.spawn((
+        Position { x: 0.0, y: -20.0 },
+        Ant,
+        Queen {
+            founding_state: FoundingState::Established,
+        },
+        ColonyTraits {
+            queen_vigor: 1.0,
+            worker_efficiency: 1.0,
+            architectural_skill: 1.0,
+            environmental_adaptation: 1.0,
+        },
+        Lifecycle {
+            age: 500.0,
+            max_age: 10000.0,
+            energy: 40.0,
+            max_energy: 100.0,
+        },
+        ReproductionState {
+            time_since_last_egg: 3.0,
+            egg_laying_interval: 7.0,
+            reproductive_capacity: 0.6,
+        },
+        Health {
+            hp: 25.0,
+            max_hp: 60.0,
+        },
+        RoyalChamber {
+            center: Position { x: 1.0, y: -21.0 },
+            radius: 9.0,
+        },
+        ColonyId(2),
+    ));
+
+    for action in [SlotAction::Save, SlotAction::Load] {
+        app.world_mut()
+            .send_event(SaveSlotRequest { slot: 1, action });
+        app.update();
+    }
+
+    let world = app.world_mut();
+    let behaviors: Vec<AntBehavior> = world
+        .query_filtered::<&AntBehavior, Without<Queen>>()
+        .iter(world)
+        .cloned()
+        .collect();
+    assert_eq!(behaviors.len(), 5);
+    for behavior in &behaviors {
+        assert_eq!(behavior.state, AntState::Returning);
+        assert_eq!(behavior.carried_food, 3.5);
+        assert_eq!(behavior.home_position.x, 12.0);
+    }
+
+    let (lifecycle, reproduction, health, chamber, colony) = world
+        .query_filtered::<(
+            &Lifecycle,
+            &ReproductionState,
+            &Health,
+            &RoyalChamber,
+            &ColonyId,
+        ), With<Queen>>()
+        .single(world);
+    assert_eq!(*colony, ColonyId(2));
+    assert_eq!(lifecycle.age, 500.0);
+    assert_eq!(reproduction.egg_laying_interval, 7.0);
+    assert_eq!(reproduction.reproductive_capacity, 0.6);
+    assert_eq!(health.hp, 25.0);
+    assert_eq!(chamber.radius, 9.0);
+    let founding_queens = world
+        .query_filtered::<(), (With<Queen>, With<FoundingReserve>)>()
+        .iter(world)
+        .count();
+    assert_eq!(founding_queens, 0, "A settled queen should not found again");
+
+    let _ = std::fs::remove_dir_all(save_dir);
+}
+
+/// Co-founding queens keep pointing at their founder after a load
+#[test]
+fn test_slot_round_trip_restores_co_founding_queens() {
+    let save_dir = temp_save_dir("slot-co-founding");
+    let mut app = slot_test_app(save_dir.clone());
+    let traits = ColonyTraits {
+        queen_vigor: 1.0,
+        worker_efficiency: 1.0,
+        architectural_skill: 1.0,
+        environmental_adaptation: 1.0,
+    };
+    let world = app.world_mut();
+    let founder = world
+        .spawn((
+            Position { x: 0.0, y: -20.0 },
+            Ant,
+            Queen {
+                founding_state: FoundingState::Seeking,
+            },
+            traits.clone(),
+            ColonyId(3),
+        ))
+        .id();
+    world.spawn((
+        Position { x: 4.0, y: -20.0 },
+        Ant,
+        Queen {
+            founding_state: FoundingState::Seeking,
+        },
+        traits,
+        CoFoundingQueen { founder },
+    ));
+
+    for action in [SlotAction::Save, SlotAction::Load] {
+        app.world_mut()
+            .send_event(SaveSlotRequest { slot: 1, action });
+        app.update();
+    }
+
+    let world = app.world_mut();
+    let co_founders: Vec<(Position, Entity)> = world
+        .query::<(&Position, &CoFoundingQueen)>()
+        .iter(world)
+        .map(|(position, co_founding)| (position.clone(), co_founding.founder))
+        .collect();
+    assert_eq!(co_founders.len(), 1);
+    let (position, founder) = &co_founders[0];
+    assert_eq!(position.x, 4.0);
+    let loaded_founder = world
+        .query::<(&Queen, &ColonyId)>()
+        .get(world, *founder)
+        .map(|(_, colony)| *colony);
+    assert_eq!(loaded_founder.ok(), Some(ColonyId(3)));
+
+    let _ = std::fs::remove_dir_all(save_dir);
+}
+
+/// Loading a slot clears drawn tunnels and invaders left over from the running game
+#[test]
+fn test_loading_slot_clears_running_world() {
+    let save_dir = temp_save_dir("slot-clear");
+    let mut app = slot_test_app(save_dir.clone());
+
+    app.world_mut().send_event(SaveSlotRequest {
+        slot: 1,
+        action: SlotAction::Save,
+    });
+    app.update();
+
+    let world = app.world_mut();
+    let from = world
+        .spawn((Position { x: 0.0, y: -10.0 }, TunnelNode::default()))
+        .id();
+    let to = world
+        .spawn((Position { x: 0.0, y: -30.0 }, TunnelNode::default()))
+        .id();
+    world.spawn(Tunnel {
+        from,
+        to,
+        width: 3.0,
+    });
+    world.spawn((
+        Position { x: 50.0, y: 5.0 },
+        InvasiveSpecies {
+            lifetime: 60.0,
+            food_consumption_rate: 1.0,
+            strength: 2.0,
+        },
+    ));
+
+    app.world_mut().send_event(SaveSlotRequest {
+        slot: 1,
+        action: SlotAction::Load,
+    });
+    app.update();
+
+    let world = app.world_mut();
+    assert_eq!(world.query::<&Tunnel>().iter(world).count(), 0);
+    assert_eq!(world.query::<&TunnelNode>().iter(world).count(), 0);
+    assert_eq!(world.query::<&InvasiveSpecies>().iter(world).count(), 0);
+    assert_eq!(world.query::<&Ant>().iter(world).count(), 5);
+
+    let _ = std::fs::remove_dir_all(save_dir);
+}
+
+/// Loading a slot after the run ended clears the ending, the pause and active disasters
+#[test]
+fn test_loading_slot_clears_previous_run_state() {
+    let save_dir = temp_save_dir("slot-run-state");
+    let mut app = slot_test_app(save_dir.clone());
+    app.init_resource::<DisasterState>();
+    app.init_resource::<TimeControl>();
+    app.init_resource::<SimulationEnded>();
+
+    app.world_mut().send_event(SaveSlotRequest {
+        slot: 1,
+        action: SlotAction::Save,
+    });
+    app.update();
+
+    let world = app.world_mut();
+    world.insert_resource(SimulationEnded {
+        reason: Some(EndReason::ColonyExtinct),
+        day: 12,
+        population: 0,
+    });
+    world.resource_mut::<TimeControl>().is_paused = true;
+    world
+        .resource_mut::<DisasterState>()
+        .start_disaster(DisasterType::Drought, 30.0);
+
+    app.world_mut().send_event(SaveSlotRequest {
+        slot: 1,
+        action: SlotAction::Load,
+    });
+    app.update();
+
+    let world = app.world();
+    assert!(!world.resource::<SimulationEnded>().is_ended());
+    assert!(!world.resource::<TimeControl>().is_paused);
+    assert!(world
+        .resource::<DisasterState>()
+        .active_disasters
+        .is_empty());
+
+    let _ = std::fs::remove_dir_all(save_dir);
+}
@@ -0,0 +1,106 @@
+//! Scouting vs trail-following forager tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::strategy::BoxedStrategy;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+fn foraging_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<AntAiConfig>();
+    app.init_resource::<PheromoneGrid>();
+    app.init_resource::<ColonyFoodStore>();
+    app.init_resource::<BoxedStrategy>();
+    app.add_systems(
+        Update,
+        (
+            systems::scout_allocation_system,
+            systems::foraging_behavior_system,
+        )
+            .chain(),
+    );
+    app
+}
+
+fn spawn_forager(app: &mut App) {
+    let home = Position { x: 0.0, y: 0.0 };
+    app.world_mut()
+        .spawn((home.clone(), Ant, AntBehavior::new(home, 0.0)));
+}
+
+/// The number of scouts follows the configured fraction of foragers
+#[test]
+fn test_scout_count_matches_configured_ratio() {
+    let mut app = foraging_app();
+    app.insert_resource(AntAiConfig {
+        scout_fraction: 0.3,
+        ..default()
+    });
+
+    for _ in 0..20 {
+        spawn_forager(&mut app);
+    }
+    app.update();
+
+    let world = app.world_mut();
+    let scouts = world.query::<&Scout>().iter(world).count();
+    assert_eq!(scouts, 6);
+
+    // Lowering the ratio demotes scouts back to regular foragers
+    app.insert_resource(AntAiConfig {
+        scout_fraction: 0.1,
+        ..default()
+    });
+    app.update();
+
+    let world = app.world_mut();
+    let scouts = world.query::<&Scout>().iter(world).count();
+    assert_eq!(scouts, 2);
+}
+
+/// Scouts range further from home than trail-following foragers
+#[test]
+fn test_scouts_explore_further_than_foragers() {
+    let mut app = foraging_app();
+    app.insert_resource(AntAiConfig {
+        scout_fraction: 0.5,
+        ..default()
+    });
+
+    for _ in 0..20 {
+        spawn_forager(&mut app);
+    }
+
+    let mut scout_max: f32 = 0.0;
+    let mut forager_max: f32 = 0.0;
+    for _ in 0..600 {
+        app.update();
+
+        let world = app.world_mut();
+        for (position, is_scout) in world
+            .query_filtered::<(&Position, Has<Scout>), With<Ant>>()
+            .iter(world)
+        {
+            let distance = (position.x * position.x + position.y * position.y).sqrt();
+            if is_scout {
+                scout_max = scout_max.max(distance);
+            } else {
+                forager_max = forager_max.max(distance);
+            }
+        }
+    }
+
+    assert!(
+        scout_max > forager_max,
+        "Scouts reached {:.1}, foragers reached {:.1}",
+        scout_max,
+        forager_max
+    );
+    assert!(forager_max <= 30.0 + 4.0, "Foragers stay in forage range");
+}
@@ -0,0 +1,104 @@
+//! Drag selection tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems::{self, SelectionCommand};
+use bevy::prelude::*;
+
+fn selection_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<SpatialGrid>();
+    app.init_resource::<Selection>();
+    app.add_event::<SelectionCommand>();
+    app.add_systems(
+        Update,
+        (
+            systems::spatial_grid_update_system,
+            systems::selection_system,
+            systems::update_selection_panel_system,
+        )
+            .chain(),
+    );
+    app.add_systems(Startup, systems::setup_selection_panel);
+    app
+}
+
+fn spawn_ant(app: &mut App, x: f32, y: f32, role: SpecializedRole, energy: f32) -> Entity {
+    app.world_mut()
+        .spawn((
+            Ant,
+            Position { x, y },
+            role,
+            Lifecycle {
+                age: 0.0,
+                max_age: 1000.0,
+                energy,
+                max_energy: 100.0,
+            },
+        ))
+        .id()
+}
+
+fn select(app: &mut App, min: Vec2, max: Vec2) {
+    app.world_mut()
+        .send_event(SelectionCommand::Select { min, max });
+    app.update();
+}
+
+/// A drag rectangle selects exactly the ants inside it, even across grid cells
+#[test]
+fn test_rectangle_selects_only_ants_inside() {
+    let mut app = selection_app();
+    let inside = [
+        spawn_ant(&mut app, 1.0, 1.0, SpecializedRole::Forager, 50.0),
+        spawn_ant(&mut app, 19.0, -9.0, SpecializedRole::Forager, 50.0),
+        spawn_ant(&mut app, 10.0, 5.0, SpecializedRole::NurseryWorker, 50.0),
+    ];
+    let outside = [
+        spawn_ant(&mut app, 21.0, 0.0, SpecializedRole::Forager, 50.0),
+        spawn_ant(&mut app, 10.0, 11.0, SpecializedRole::Forager, 50.0),
+        spawn_ant(&mut app, -0.5, 0.0, SpecializedRole::Forager, 50.0),
+    ];
+    app.update(); // Fill the spatial grid
+
+    select(&mut app, Vec2::new(0.0, -10.0), Vec2::new(20.0, 10.0));
+
+    let selection = &app.world().resource::<Selection>().0;
+    assert_eq!(selection.len(), inside.len());
+    for ant in inside {
+        assert!(selection.contains(&ant));
+    }
+    for ant in outside {
+        assert!(!selection.contains(&ant));
+    }
+}
+
+/// The panel sums up the selection and hides again once it is cleared
+#[test]
+fn test_panel_summarises_and_clears() {
+    let mut app = selection_app();
+    spawn_ant(&mut app, 1.0, 1.0, SpecializedRole::Forager, 40.0);
+    spawn_ant(&mut app, 2.0, 1.0, SpecializedRole::StorageWorker, 80.0);
+    app.update();
+
+    select(&mut app, Vec2::new(0.0, 0.0), Vec2::new(5.0, 5.0));
+    let world = app.world_mut();
+    let text = world
+        .query_filtered::<&Text, With<SelectionText>>()
+        .single(world)
+        .sections[0]
+        .value
+        .clone();
+    assert!(text.contains("Selected: 2 ants"), "{}", text);
+    assert!(text.contains("Avg energy: 60.0"), "{}", text);
+    assert!(text.contains("Foragers: 1") && text.contains("Storage workers: 1"));
+
+    app.world_mut().send_event(SelectionCommand::Clear);
+    app.update();
+    assert!(app.world().resource::<Selection>().0.is_empty());
+    let world = app.world_mut();
+    let visibility = world
+        .query_filtered::<&Visibility, With<SelectionPanel>>()
+        .single(world);
+    assert_eq!(*visibility, Visibility::Hidden);
+}
@@ -0,0 +1,77 @@
+//! Realistic soil color tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+
+fn spawn_cell(app: &mut App, moisture: f32, nutrition: f32) -> Entity {
+    let layer = DepthLayer::from_y(-40.0);
+    app.world_mut()
+        .spawn((
+            Soil,
+            SoilCell {
+                moisture,
+                temperature: 20.0,
+                nutrition,
+                compaction: 0.5,
+            },
+            layer,
+            Sprite {
+                color: layer.base_color(),
+                ..default()
+            },
+        ))
+        .id()
+}
+
+fn luminance(color: Color) -> f32 {
+    let [r, g, b, _] = color.to_srgba().to_f32_array();
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+/// A wet, rich cell renders darker and greener than a dry, poor cell at the same depth
+#[test]
+fn test_wet_rich_soil_is_darker_and_greener() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<SoilColorConfig>();
+    app.add_systems(Update, systems::realistic_soil_color_system);
+
+    let wet_rich = spawn_cell(&mut app, 1.0, 1.0);
+    let dry_poor = spawn_cell(&mut app, 0.0, 0.0);
+    app.update();
+
+    let wet_rich = app.world().get::<Sprite>(wet_rich).unwrap().color;
+    let dry_poor = app.world().get::<Sprite>(dry_poor).unwrap().color;
+    assert!(luminance(wet_rich) < luminance(dry_poor));
+
+    let [wet_r, wet_g, _, _] = wet_rich.to_srgba().to_f32_array();
+    let [dry_r, dry_g, _, _] = dry_poor.to_srgba().to_f32_array();
+    assert!(
+        wet_g / wet_r > dry_g / dry_r,
+        "Rich soil should lean green: {:?} vs {:?}",
+        wet_rich,
+        dry_poor
+    );
+}
+
+/// Switching to a heatmap takes effect right away, without waiting for the next interval
+#[test]
+fn test_heatmap_mode_overrides_realistic_color() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<SoilColorConfig>();
+    app.add_systems(Update, systems::realistic_soil_color_system);
+    let cell = spawn_cell(&mut app, 1.0, 0.5);
+    app.update();
+
+    app.world_mut().resource_mut::<SoilColorConfig>().mode =
+        SoilColorMode::Heatmap(SoilHeatmap::Moisture);
+    app.update();
+
+    let soil = app.world().get::<SoilCell>(cell).unwrap().clone();
+    assert_eq!(
+        app.world().get::<Sprite>(cell).unwrap().color,
+        systems::heatmap_soil_color(SoilHeatmap::Moisture, &soil)
+    );
+}
@@ -0,0 +1,78 @@
+//! Soil moisture/temperature diffusion tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+fn moisture_at(app: &mut App, x: f32, y: f32) -> f32 {
+    let world = app.world_mut();
+    world
+        .query::<(&Position, &SoilCell)>()
+        .iter(world)
+        .find(|(position, _)| position.x == x && position.y == y)
+        .map(|(_, soil)| soil.moisture)
+        .unwrap()
+}
+
+/// A single wet cell spreads moisture to dry neighbors while the total is conserved
+#[test]
+fn test_wet_cell_spreads_moisture_and_conserves_total() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<SoilDiffusionConfig>();
+    app.add_systems(Update, systems::soil_diffusion_system);
+
+    for x in -3..=3 {
+        for y in -3..=3 {
+            let moisture = if x == 0 && y == 0 { 1.0 } else { 0.0 };
+            app.world_mut().spawn((
+                Position {
+                    x: x as f32 * 4.0,
+                    y: y as f32 * 4.0,
+                },
+                SoilCell {
+                    moisture,
+                    temperature: 20.0,
+                    nutrition: 0.5,
+                    compaction: 0.5,
+                },
+            ));
+        }
+    }
+
+    let mut previous_center = 1.0;
+    for _ in 0..30 {
+        app.update();
+
+        // Smooth, monotone decay at the source: no oscillation
+        let center = moisture_at(&mut app, 0.0, 0.0);
+        assert!(center <= previous_center + 1e-6);
+        assert!(center >= 0.0);
+        previous_center = center;
+    }
+
+    assert!(moisture_at(&mut app, 4.0, 0.0) > 0.0);
+    assert!(moisture_at(&mut app, 0.0, -4.0) > 0.0);
+    assert!(moisture_at(&mut app, 8.0, 0.0) > 0.0);
+    assert!(previous_center < 1.0);
+
+    let world = app.world_mut();
+    let total: f32 = world
+        .query::<&SoilCell>()
+        .iter(world)
+        .map(|s| s.moisture)
+        .sum();
+    assert!((total - 1.0).abs() < 1e-3, "Total moisture was {}", total);
+
+    // Uniform temperature stays uniform
+    let world = app.world_mut();
+    assert!(world
+        .query::<&SoilCell>()
+        .iter(world)
+        .all(|s| (s.temperature - 20.0).abs() < 1e-4));
+}
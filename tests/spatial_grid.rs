@@ -0,0 +1,82 @@
+//! Spatial grid cell size tuning tests
+
+use ant_nest_simulator::components::*;
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+fn nearby(grid: &SpatialGrid, positions: &[(Entity, Position)], radius: f32) -> HashSet<Entity> {
+    let center = Position { x: 3.0, y: -5.0 };
+    grid.get_nearby_entities(&center, radius)
+        .into_iter()
+        .filter(|entity| {
+            let (_, position) = positions.iter().find(|(e, _)| e == entity).unwrap();
+            let (dx, dy) = (position.x - center.x, position.y - center.y);
+            dx * dx + dy * dy <= radius * radius
+        })
+        .collect()
+}
+
+/// Re-bucketing changes the cells but not which entities a radius query finds
+#[test]
+fn test_rebucket_preserves_radius_query_results() {
+    let mut world = World::new();
+    let mut grid = SpatialGrid::new(8.0);
+    let positions: Vec<(Entity, Position)> = (0..60)
+        .map(|i| {
+            let position = Position {
+                x: (i % 10) as f32 * 3.7 - 15.0,
+                y: (i / 10) as f32 * -4.1 + 2.0,
+            };
+            (world.spawn_empty().id(), position)
+        })
+        .collect();
+    for (entity, position) in &positions {
+        grid.insert_entity(*entity, position);
+    }
+    let before = nearby(&grid, &positions, 10.0);
+    let cells_before = grid.grid.len();
+
+    grid.rebucket(SpatialGrid::recommended_cell_size(10.0, 0.5));
+
+    assert_eq!(grid.cell_size, 10.0);
+    assert_ne!(grid.grid.len(), cells_before);
+    assert!(!before.is_empty());
+    assert_eq!(nearby(&grid, &positions, 10.0), before);
+}
+
+/// Changing the configured cell size re-buckets the plugin's grid at runtime
+#[test]
+fn test_world_config_cell_size_reaches_grid() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(WorldConfig {
+        spatial_cell_size: 12.0,
+        ..default()
+    });
+    app.add_plugins(ant_nest_simulator::AntNestSimulationPlugin);
+    assert_eq!(app.world().resource::<SpatialGrid>().cell_size, 12.0);
+
+    app.world_mut()
+        .resource_mut::<WorldConfig>()
+        .spatial_cell_size = 20.0;
+    app.update();
+    assert_eq!(app.world().resource::<SpatialGrid>().cell_size, 20.0);
+}
+
+/// Private entity grids are emptied for each rebuild and sized for their query radius
+#[test]
+fn test_entity_grid_sized_for_query_radius() {
+    let world = WorldConfig::default();
+    let mut grid = SpatialGrid::default();
+    grid.insert_entity(Entity::from_raw(1), &Position { x: 0.0, y: 0.0 });
+
+    ant_nest_simulator::systems::reset_entity_grid(&mut grid, &world, 12.0, 6);
+
+    assert!(grid.positions.is_empty());
+    let density = 6.0 / world.area();
+    assert_eq!(
+        grid.cell_size,
+        SpatialGrid::recommended_cell_size(12.0, density)
+    );
+    assert!(grid.cell_size >= 12.0);
+}
@@ -0,0 +1,139 @@
+//! Forager stamina tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::strategy::BoxedStrategy;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+fn stamina_app() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<AntAiConfig>();
+    app.init_resource::<PheromoneGrid>();
+    app.init_resource::<ColonyFoodStore>();
+    app.init_resource::<BoxedStrategy>();
+    app.add_systems(Update, systems::foraging_behavior_system);
+
+    // A loaded forager with a very long walk home
+    let mut behavior = AntBehavior::new(
+        Position {
+            x: 10_000.0,
+            y: 0.0,
+        },
+        0.0,
+    );
+    behavior.state = AntState::Returning;
+    behavior.carried_food = 1.0;
+    let max_stamina = AntAiConfig::default().max_stamina;
+    let ant = app
+        .world_mut()
+        .spawn((
+            Position { x: 0.0, y: 0.0 },
+            Ant,
+            behavior,
+            Stamina::new(max_stamina),
+        ))
+        .id();
+    app.update();
+    (app, ant)
+}
+
+/// Distance the ant covers in one update
+fn step_length(app: &mut App, ant: Entity) -> f32 {
+    let before = app.world().get::<Position>(ant).unwrap().x;
+    app.update();
+    app.world().get::<Position>(ant).unwrap().x - before
+}
+
+fn state(app: &App, ant: Entity) -> AntState {
+    app.world().get::<AntBehavior>(ant).unwrap().state
+}
+
+/// A forager slows once tired and finally stops to rest, however well fed
+#[test]
+fn test_long_trip_slows_ant_until_it_rests() {
+    let (mut app, ant) = stamina_app();
+    let fresh_step = step_length(&mut app, ant);
+    assert!(fresh_step > 0.0);
+
+    while app.world().get::<Stamina>(ant).unwrap().fraction() > 0.15 {
+        app.update();
+    }
+    let tired_step = step_length(&mut app, ant);
+    assert!(
+        tired_step < fresh_step * 0.9,
+        "Tired step {} should be shorter than fresh step {}",
+        tired_step,
+        fresh_step
+    );
+
+    for _ in 0..100 {
+        if state(&app, ant) == AntState::Resting {
+            break;
+        }
+        app.update();
+    }
+    assert_eq!(state(&app, ant), AntState::Resting);
+    assert!(app.world().get::<Stamina>(ant).unwrap().exhausted);
+    assert_eq!(step_length(&mut app, ant), 0.0);
+}
+
+/// Resting refills stamina, after which the ant resumes its trip at full speed
+#[test]
+fn test_resting_restores_stamina_and_speed() {
+    let (mut app, ant) = stamina_app();
+    let fresh_step = step_length(&mut app, ant);
+
+    for _ in 0..1000 {
+        if state(&app, ant) == AntState::Resting {
+            break;
+        }
+        app.update();
+    }
+    assert_eq!(state(&app, ant), AntState::Resting);
+
+    for _ in 0..1000 {
+        if state(&app, ant) != AntState::Resting {
+            break;
+        }
+        app.update();
+    }
+
+    assert_eq!(state(&app, ant), AntState::Returning);
+    let stamina = app.world().get::<Stamina>(ant).unwrap();
+    assert!(!stamina.exhausted);
+    assert!(stamina.fraction() >= AntAiConfig::default().stamina_resume_fraction);
+    let rested_step = step_length(&mut app, ant);
+    assert!((rested_step - fresh_step).abs() < 1e-3);
+}
+
+/// Founding workers start with the configured stamina, not the default
+#[test]
+fn test_spawned_workers_use_configured_max_stamina() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(SimulationRng::from_seed(3));
+    app.insert_resource(AntAiConfig {
+        max_stamina: 42.0,
+        ..default()
+    });
+    app.add_systems(Startup, systems::spawn_initial_ants);
+    app.update();
+
+    let staminas: Vec<Stamina> = app
+        .world_mut()
+        .query_filtered::<&Stamina, With<Ant>>()
+        .iter(app.world())
+        .cloned()
+        .collect();
+    assert!(!staminas.is_empty());
+    for stamina in staminas {
+        assert_eq!(stamina.max, 42.0);
+        assert_eq!(stamina.current, 42.0);
+    }
+}
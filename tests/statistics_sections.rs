@@ -0,0 +1,149 @@
+//! Collapsible statistics panel section tests
+
+mod common;
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::localization::Localization;
+use ant_nest_simulator::systems::{self, SettingsFile};
+use bevy::prelude::*;
+use common::temp_save_dir;
+
+fn statistics_app(user_settings: UserSettings) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<UITheme>();
+    app.init_resource::<Localization>();
+    app.insert_resource(user_settings);
+    app.add_systems(Startup, systems::setup_statistics_panel);
+    app.add_systems(
+        Update,
+        (
+            systems::statistics_section_header_system,
+            systems::apply_statistics_sections_system,
+        )
+            .chain(),
+    );
+    app.update();
+    app
+}
+
+fn section_display(app: &mut App, section: StatSection) -> Display {
+    let world = app.world_mut();
+    world
+        .query::<(&StatisticsSectionContent, &Style)>()
+        .iter(world)
+        .find(|(content, _)| content.section == section)
+        .map(|(_, style)| style.display)
+        .unwrap()
+}
+
+fn header(app: &mut App, section: StatSection) -> Entity {
+    let world = app.world_mut();
+    world
+        .query::<(Entity, &StatisticsSectionHeader)>()
+        .iter(world)
+        .find(|(_, header)| header.section == section)
+        .map(|(entity, _)| entity)
+        .unwrap()
+}
+
+/// Clicking a header hides its contents, clicking again shows them; the state lands in settings
+#[test]
+fn test_header_click_flips_section_and_updates_settings() {
+    let mut app = statistics_app(UserSettings::default());
+    assert_eq!(
+        section_display(&mut app, StatSection::Resources),
+        Display::Flex
+    );
+
+    let resources = header(&mut app, StatSection::Resources);
+    *app.world_mut().get_mut::<Interaction>(resources).unwrap() = Interaction::Pressed;
+    app.update();
+
+    assert_eq!(
+        section_display(&mut app, StatSection::Resources),
+        Display::None
+    );
+    assert_eq!(
+        section_display(&mut app, StatSection::Population),
+        Display::Flex
+    );
+    let user_settings = app.world().resource::<UserSettings>().clone();
+    assert!(!user_settings.is_section_expanded(StatSection::Resources));
+
+    let serialized = serde_json::to_string(&user_settings).unwrap();
+    let restored: UserSettings = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(restored, user_settings);
+    assert!(!restored.is_section_expanded(StatSection::Resources));
+
+    *app.world_mut().get_mut::<Interaction>(resources).unwrap() = Interaction::None;
+    app.update();
+    *app.world_mut().get_mut::<Interaction>(resources).unwrap() = Interaction::Pressed;
+    app.update();
+    assert_eq!(
+        section_display(&mut app, StatSection::Resources),
+        Display::Flex
+    );
+}
+
+/// A panel built from loaded settings starts with the saved sections collapsed
+#[test]
+fn test_panel_restores_expansion_state_from_settings() {
+    let mut app = statistics_app(UserSettings {
+        expanded_stat_sections: vec![StatSection::Behavior],
+        ..default()
+    });
+
+    for section in StatSection::ALL {
+        let expected = if section == StatSection::Behavior {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        assert_eq!(section_display(&mut app, section), expected);
+    }
+}
+
+/// Sections left open survive saving the settings and loading them at the next startup
+#[test]
+fn test_saved_sections_load_at_startup() {
+    let path = temp_save_dir("settings-sections").join("settings.json");
+    let expanded = vec![StatSection::Nest, StatSection::Roles];
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<UITheme>();
+    app.insert_resource(UserSettings {
+        expanded_stat_sections: expanded.clone(),
+        ..default()
+    });
+    app.insert_resource(SettingsFile {
+        path: Some(path.clone()),
+    });
+    app.add_systems(Update, systems::handle_settings_interactions_system);
+    app.world_mut().spawn((
+        Button,
+        Interaction::Pressed,
+        BackgroundColor::default(),
+        SettingsButton {
+            action: SettingsAction::SaveSettings,
+        },
+    ));
+    app.update();
+    assert!(path.exists(), "settings file should be written");
+
+    let mut restarted = App::new();
+    restarted.add_plugins(MinimalPlugins);
+    restarted.init_resource::<UserSettings>();
+    restarted.insert_resource(SettingsFile { path: Some(path) });
+    restarted.add_systems(Startup, systems::load_settings_system);
+    restarted.update();
+
+    assert_eq!(
+        restarted
+            .world()
+            .resource::<UserSettings>()
+            .expanded_stat_sections,
+        expanded
+    );
+}
@@ -0,0 +1,54 @@
+//! Embedding step API tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::{headless_app, step};
+use bevy::prelude::*;
+
+fn ant_positions(app: &mut App) -> Vec<(Entity, f32, f32)> {
+    let world = app.world_mut();
+    let mut positions: Vec<_> = world
+        .query_filtered::<(Entity, &Position), With<Ant>>()
+        .iter(world)
+        .map(|(entity, position)| (entity, position.x, position.y))
+        .collect();
+    positions.sort_by_key(|(entity, _, _)| *entity);
+    positions
+}
+
+/// Each step advances the simulated clock by exactly its delta and moves the ants
+#[test]
+fn test_step_advances_time_and_ants() {
+    let mut app = headless_app();
+    let start = SimulationTime::default().elapsed_seconds;
+
+    step(&mut app, 0.1);
+    let elapsed = app.world().resource::<SimulationTime>().elapsed_seconds;
+    assert!((elapsed - (start + 0.1)).abs() < 1e-4);
+
+    let before = ant_positions(&mut app);
+    assert!(!before.is_empty(), "Startup should have spawned ants");
+
+    // Deltas above the usual per-frame cap are applied in full
+    for _ in 0..10 {
+        step(&mut app, 0.5);
+    }
+    let elapsed = app.world().resource::<SimulationTime>().elapsed_seconds;
+    assert!(
+        (elapsed - (start + 5.1)).abs() < 1e-3,
+        "Expected {} elapsed, got {}",
+        start + 5.1,
+        elapsed
+    );
+
+    let after = ant_positions(&mut app);
+    let moved = before
+        .iter()
+        .filter(|(entity, x, y)| {
+            after
+                .iter()
+                .find(|(other, _, _)| other == entity)
+                .is_some_and(|(_, new_x, new_y)| new_x != x || new_y != y)
+        })
+        .count();
+    assert!(moved > 0, "Some ants should have moved");
+}
@@ -0,0 +1,86 @@
+//! Bulk stress test spawning tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// Bulk-spawning 500 ants yields 500 complete workers, every one in the spatial grid
+#[test]
+fn test_bulk_spawned_ants_are_in_spatial_grid() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(SpatialGrid::new(8.0));
+
+    app.world_mut()
+        .run_system_once(|mut commands: Commands, mut grid: ResMut<SpatialGrid>| {
+            let mut rng = StdRng::seed_from_u64(7);
+            let spawned = systems::spawn_ants(
+                &mut commands,
+                &mut grid,
+                500,
+                &SpeciesConfig::default(),
+                &AntAiConfig::default(),
+                &mut rng,
+            );
+            assert_eq!(spawned.len(), 500);
+        });
+
+    let ants: Vec<Entity> = app
+        .world_mut()
+        .query_filtered::<Entity, (
+            With<Ant>,
+            With<Position>,
+            With<Lifecycle>,
+            With<AntBehavior>,
+            With<Caste>,
+            With<Health>,
+        )>()
+        .iter(app.world())
+        .collect();
+    assert_eq!(ants.len(), 500);
+
+    let grid = app.world().resource::<SpatialGrid>();
+    assert_eq!(grid.positions.len(), 500);
+    assert!(ants.iter().all(|ant| grid.positions.contains_key(ant)));
+
+    // Rebuilding the grid from the world keeps every spawned ant
+    app.add_systems(Update, systems::spatial_grid_update_system);
+    app.update();
+    let grid = app.world().resource::<SpatialGrid>();
+    assert_eq!(grid.grid.values().map(Vec::len).sum::<usize>(), 500);
+}
+
+/// Bulk food lands on the surface within the configured width and nutrition range
+#[test]
+fn test_bulk_spawned_food_follows_config() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(SpatialGrid::new(8.0));
+
+    app.world_mut()
+        .run_system_once(|mut commands: Commands, mut grid: ResMut<SpatialGrid>| {
+            let mut rng = StdRng::seed_from_u64(7);
+            systems::spawn_food(
+                &mut commands,
+                &mut grid,
+                50,
+                &StressSpawnConfig::default(),
+                &mut rng,
+            );
+        });
+
+    let config = StressSpawnConfig::default();
+    let mut food_query = app.world_mut().query::<(&Position, &FoodSource)>();
+    let food: Vec<_> = food_query.iter(app.world()).collect();
+    assert_eq!(food.len(), 50);
+    for (position, source) in food {
+        assert!(position.x.abs() <= config.food_half_width);
+        assert!(
+            (config.food_nutrition.0..=config.food_nutrition.1).contains(&source.nutrition_value)
+        );
+    }
+    assert_eq!(app.world().resource::<SpatialGrid>().positions.len(), 50);
+}
@@ -0,0 +1,54 @@
+//! Frame ordering tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::schedule::{SimSchedulePlugin, SimSet};
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+/// Statistics read right after an update match that frame's post-movement positions
+#[test]
+fn test_statistics_reflect_current_frame_positions() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, SimSchedulePlugin));
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<NestSpace>();
+    app.init_resource::<ColonyFoodStore>();
+    app.init_resource::<SpatialGrid>();
+    app.init_resource::<ColonyStatistics>();
+
+    // Registered back to front: only the sets decide the order
+    app.add_systems(
+        Update,
+        systems::colony_statistics_calculation_system.in_set(SimSet::Stats),
+    );
+    app.add_systems(
+        Update,
+        systems::spatial_grid_update_system.in_set(SimSet::Spatial),
+    );
+    app.add_systems(
+        Update,
+        systems::ant_movement_system.in_set(SimSet::Movement),
+    );
+
+    // Falling through open air, so every frame moves the ant
+    let ant = app
+        .world_mut()
+        .spawn((Position { x: 0.0, y: 10.0 }, Ant, Transform::default()))
+        .id();
+
+    for _ in 0..10 {
+        app.update();
+
+        let position = app.world().get::<Position>(ant).unwrap().clone();
+        let stats = app.world().resource::<ColonyStatistics>();
+        assert_eq!(stats.average_ant_depth, -position.y);
+        assert_eq!(stats.ants_underground, usize::from(position.y < 0.0));
+
+        let grid = app.world().resource::<SpatialGrid>();
+        assert_eq!(grid.get_nearby_entities(&position, 0.0), vec![ant]);
+    }
+}
@@ -0,0 +1,38 @@
+//! Per-section profiling tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use ant_nest_simulator::{headless_app, step};
+
+/// With profiling on, every instrumented section records a nonzero average
+#[test]
+fn test_profiler_times_each_section() {
+    let mut app = headless_app();
+    app.world_mut().resource_mut::<SystemProfiler>().enabled = true;
+
+    for _ in 0..10 {
+        step(&mut app, 0.1);
+    }
+
+    let profiler = app.world().resource::<SystemProfiler>();
+    for section in systems::PROFILED_SECTIONS {
+        let average = profiler
+            .average_ms(section)
+            .unwrap_or_else(|| panic!("{} was not timed", section));
+        assert!(average > 0.0, "{} averaged {}", section, average);
+    }
+    assert!(systems::profiler_bars(profiler).lines().count() > systems::PROFILED_SECTIONS.len());
+}
+
+/// The profiler is off by default and records nothing
+#[test]
+fn test_profiler_is_off_by_default() {
+    let mut app = headless_app();
+    for _ in 0..3 {
+        step(&mut app, 0.1);
+    }
+
+    let profiler = app.world().resource::<SystemProfiler>();
+    assert!(!profiler.enabled);
+    assert!(profiler.averages.is_empty());
+}
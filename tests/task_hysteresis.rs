@@ -0,0 +1,92 @@
+//! Foraging/idle task switching hysteresis tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::strategy::BoxedStrategy;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+const HOME: Position = Position { x: 0.0, y: 0.0 };
+
+fn hysteresis_app() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<AntAiConfig>();
+    app.init_resource::<PheromoneGrid>();
+    app.insert_resource(ColonyFoodStore {
+        food: 500.0,
+        protein: 50.0,
+    });
+    app.init_resource::<BoxedStrategy>();
+    app.add_systems(Update, systems::foraging_behavior_system);
+
+    let ant = app
+        .world_mut()
+        .spawn((
+            HOME,
+            Ant,
+            AntBehavior::new(HOME, 0.0),
+            Lifecycle {
+                age: 0.0,
+                max_age: 1000.0,
+                energy: 100.0,
+                max_energy: 100.0,
+            },
+        ))
+        .id();
+    (app, ant)
+}
+
+fn set_energy(app: &mut App, ant: Entity, energy: f32) {
+    app.world_mut().get_mut::<Lifecycle>(ant).unwrap().energy = energy;
+}
+
+fn state(app: &App, ant: Entity) -> AntState {
+    app.world().get::<AntBehavior>(ant).unwrap().state
+}
+
+/// An ant whose energy jitters around the satiation threshold switches task at most once
+#[test]
+fn test_energy_jitter_at_threshold_does_not_thrash() {
+    let (mut app, ant) = hysteresis_app();
+    let config = AntAiConfig::default();
+    let threshold = 100.0 * config.idle_energy_fraction;
+    let frames = (config.task_min_dwell / 0.1) as usize;
+
+    let mut previous = state(&app, ant);
+    let mut switches = 0;
+    for frame in 0..frames {
+        let jitter = if frame % 2 == 0 { 1.0 } else { -1.0 };
+        set_energy(&mut app, ant, threshold + jitter);
+        app.update();
+        let current = state(&app, ant);
+        if current != previous {
+            switches += 1;
+        }
+        previous = current;
+    }
+
+    assert!(switches <= 1, "ant switched task {} times", switches);
+}
+
+/// A hungry idle ant keeps idling until the dwell time has passed, then forages
+#[test]
+fn test_dwell_time_delays_return_to_foraging() {
+    let (mut app, ant) = hysteresis_app();
+    app.update();
+    app.update();
+    assert_eq!(state(&app, ant), AntState::Idle);
+
+    set_energy(&mut app, ant, 10.0);
+    app.update();
+    assert_eq!(state(&app, ant), AntState::Idle);
+
+    for _ in 0..25 {
+        app.update();
+    }
+    assert_eq!(state(&app, ant), AntState::Foraging);
+}
@@ -0,0 +1,120 @@
+//! Colony territory tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+
+fn territory_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(SpatialGrid::new(8.0));
+    app.insert_resource(TerritoryMap {
+        update_interval: 0.0, // Rebuild every frame
+        ..default()
+    });
+    app.add_systems(
+        Update,
+        (
+            systems::spatial_grid_update_system,
+            systems::territory_system,
+        )
+            .chain(),
+    );
+    app
+}
+
+fn spawn_ant(app: &mut App, x: f32, y: f32, colony: Option<ColonyId>) {
+    let mut ant = app.world_mut().spawn((Ant, Position { x, y }));
+    if let Some(colony) = colony {
+        ant.insert(colony);
+    }
+}
+
+/// A cell with mostly colony A's ants belongs to colony A; one nearly split is contested
+#[test]
+fn test_cell_goes_to_colony_with_most_ants() {
+    let mut app = territory_app();
+    let colony_a = ColonyId(1);
+    let colony_b = ColonyId(2);
+
+    // Cell (0, 0): three ants of A, one of B
+    for x in [1.0, 2.0, 3.0] {
+        spawn_ant(&mut app, x, 1.0, Some(colony_a));
+    }
+    spawn_ant(&mut app, 4.0, 1.0, Some(colony_b));
+    // Cell (2, 0): two of B, one of A
+    spawn_ant(&mut app, 17.0, 1.0, Some(colony_b));
+    spawn_ant(&mut app, 18.0, 1.0, Some(colony_b));
+    spawn_ant(&mut app, 19.0, 1.0, Some(colony_a));
+    // Cell (5, 0): home colony ants without an id
+    spawn_ant(&mut app, 41.0, 1.0, None);
+
+    app.update();
+
+    let territory = app.world().resource::<TerritoryMap>();
+    let cell = territory.cells[&(0, 0)];
+    assert_eq!(cell.owner, colony_a);
+    assert_eq!(cell.share, 0.75);
+    assert_eq!(territory.cells[&(2, 0)].owner, colony_b);
+    assert_eq!(
+        territory.owner_at(&Position { x: 42.0, y: 2.0 }),
+        Some(ColonyId(0))
+    );
+    assert_eq!(territory.owner_at(&Position { x: 90.0, y: 2.0 }), None);
+}
+
+/// Ties go to the lower colony id, so ownership is stable between rebuilds
+#[test]
+fn test_tie_goes_to_lowest_colony_id() {
+    let cell =
+        systems::dominant_colony([ColonyId(3), ColonyId(1), ColonyId(3), ColonyId(1)]).unwrap();
+    assert_eq!(cell.owner, ColonyId(1));
+    assert_eq!(cell.share, 0.5);
+    assert!(systems::dominant_colony([]).is_none());
+}
+
+/// The overlay fills each held cell with one tile, rebuilt only with the map
+#[test]
+fn test_overlay_fills_held_cells_with_tiles() {
+    let mut app = territory_app();
+    app.insert_resource(TerritoryMap {
+        update_interval: 1000.0,
+        update_timer: 1000.0, // Rebuild on the first update only
+        overlay_enabled: true,
+        ..default()
+    });
+    app.add_systems(
+        Update,
+        systems::territory_overlay_system.after(systems::territory_system),
+    );
+    spawn_ant(&mut app, 1.0, 1.0, Some(ColonyId(1)));
+    spawn_ant(&mut app, 17.0, 1.0, Some(ColonyId(2)));
+
+    let tiles = |app: &mut App| {
+        let world = app.world_mut();
+        world
+            .query_filtered::<(Entity, &Sprite), With<systems::TerritoryTile>>()
+            .iter(world)
+            .map(|(tile, sprite)| (tile, sprite.custom_size))
+            .collect::<Vec<_>>()
+    };
+    app.update();
+    app.update();
+    let drawn = tiles(&mut app);
+    assert_eq!(drawn.len(), 2);
+    assert!(drawn
+        .iter()
+        .all(|(_, size)| *size == Some(Vec2::splat(8.0))));
+    app.update();
+    assert_eq!(
+        tiles(&mut app),
+        drawn,
+        "Tiles are kept until the map changes"
+    );
+
+    app.world_mut()
+        .resource_mut::<TerritoryMap>()
+        .overlay_enabled = false;
+    app.update();
+    assert!(tiles(&mut app).is_empty());
+}
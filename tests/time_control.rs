@@ -0,0 +1,105 @@
+//! Simulation speed and turbo mode tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::schedule::{SimSchedulePlugin, SimSet, SimStep};
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+fn time_control_app(time_control: TimeControl) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, SimSchedulePlugin));
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(time_control);
+    app.init_resource::<SimulationTime>();
+    app.add_systems(
+        Update,
+        systems::apply_time_control_system.in_set(SimSet::Input),
+    );
+    app.add_systems(
+        SimStep,
+        systems::simulation_time_system.in_set(SimSet::Environment),
+    );
+    app.add_systems(Last, systems::turbo_substep_system);
+    app
+}
+
+/// Run `frames` updates and return simulated seconds per real second
+fn measured_speed(app: &mut App, frames: usize) -> f32 {
+    // Real time starts counting from its first update
+    app.update();
+    app.update();
+    let start = app.world().resource::<SimulationTime>().elapsed_seconds;
+    for _ in 0..frames {
+        app.update();
+    }
+    let simulated = app.world().resource::<SimulationTime>().elapsed_seconds - start;
+    simulated / (frames as f32 * 0.1)
+}
+
+/// With a generous step budget turbo runs the simulation faster than the 100x cap
+#[test]
+fn test_turbo_exceeds_speed_cap() {
+    let mut app = time_control_app(TimeControl {
+        speed_multiplier: UI_MAX_SPEED,
+        turbo: true,
+        turbo_step: 0.1,
+        turbo_max_steps: 500,
+        turbo_frame_budget: 10.0,
+        ..default()
+    });
+
+    let speed = measured_speed(&mut app, 10);
+    assert!(speed > UI_MAX_SPEED, "Turbo only reached {:.0}x", speed);
+
+    let achieved = app.world().resource::<TimeControl>().achieved_multiplier;
+    assert!((achieved - speed).abs() < 1.0, "Reported {:.0}x", achieved);
+}
+
+/// Without turbo the requested speed is capped and the achieved speed matches it
+#[test]
+fn test_requested_speed_is_capped() {
+    let mut time_control = TimeControl::default();
+    time_control.set_speed(500.0);
+    assert_eq!(time_control.speed_multiplier, UI_MAX_SPEED);
+    time_control.set_speed(2.0);
+    let mut app = time_control_app(time_control);
+
+    let speed = measured_speed(&mut app, 10);
+    assert!((speed - 2.0).abs() < 0.01, "Ran at {:.2}x", speed);
+    let achieved = app.world().resource::<TimeControl>().achieved_multiplier;
+    assert!((achieved - 2.0).abs() < 0.01);
+}
+
+/// Turbo sub-steps rerun only the simulation, so a key press is handled once
+#[test]
+fn test_turbo_handles_key_press_once() {
+    let mut app = time_control_app(TimeControl {
+        turbo: false,
+        turbo_step: 0.1,
+        turbo_max_steps: 5,
+        turbo_frame_budget: 10.0,
+        ..default()
+    });
+    let key_bindings = KeyBindings::default();
+    let mut keyboard_input = ButtonInput::<KeyCode>::default();
+    keyboard_input.press(key_bindings.key(InputAction::ToggleTurbo));
+    app.insert_resource(keyboard_input);
+    app.insert_resource(key_bindings);
+    app.add_systems(
+        Update,
+        systems::time_control_input_system
+            .in_set(SimSet::Input)
+            .before(systems::apply_time_control_system),
+    );
+
+    app.update();
+
+    let time_control = app.world().resource::<TimeControl>();
+    assert!(time_control.turbo, "Turbo toggled more than once");
+    let elapsed = app.world().resource::<SimulationTime>().elapsed_seconds;
+    assert!(elapsed > 0.4, "Only {:.2}s simulated", elapsed);
+}
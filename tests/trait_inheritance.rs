@@ -0,0 +1,286 @@
+//! Colony trait inheritance tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::time::Duration;
+
+/// Daughter colonies stay close to their mother and never leave the valid range
+#[test]
+fn test_inherited_traits_stay_near_parent_and_within_bounds() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let mutation = 0.05;
+
+    let parents = [
+        ColonyTraits {
+            queen_vigor: 1.0,
+            worker_efficiency: 1.1,
+            architectural_skill: 0.9,
+            environmental_adaptation: 1.2,
+        },
+        // Parent sitting on the bounds must not be pushed past them
+        ColonyTraits {
+            queen_vigor: ColonyTraits::MIN,
+            worker_efficiency: ColonyTraits::MAX,
+            architectural_skill: ColonyTraits::MIN,
+            environmental_adaptation: ColonyTraits::MAX,
+        },
+    ];
+
+    for parent in parents.iter() {
+        for _ in 0..1000 {
+            let child = systems::inherit_traits(parent, mutation, &mut rng);
+
+            let pairs = [
+                (parent.queen_vigor, child.queen_vigor),
+                (parent.worker_efficiency, child.worker_efficiency),
+                (parent.architectural_skill, child.architectural_skill),
+                (
+                    parent.environmental_adaptation,
+                    child.environmental_adaptation,
+                ),
+            ];
+            for (parent_value, child_value) in pairs {
+                assert!(
+                    (child_value - parent_value).abs() <= mutation + f32::EPSILON,
+                    "Trait drifted too far: {} -> {}",
+                    parent_value,
+                    child_value
+                );
+                assert!(
+                    (ColonyTraits::MIN..=ColonyTraits::MAX).contains(&child_value),
+                    "Trait out of bounds: {}",
+                    child_value
+                );
+            }
+        }
+    }
+}
+
+/// A colony big enough for nuptial flights sends off daughters that take after it
+#[test]
+fn test_nuptial_flight_founds_daughter_with_inherited_traits() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(SimulationRng::from_seed(5));
+    app.insert_resource(NuptialFlightConfig {
+        min_workers: 3,
+        interval: 1.0,
+        dispersal_distance: 30.0,
+    });
+    app.add_systems(Update, systems::nuptial_flight_system);
+
+    let mother_traits = ColonyTraits {
+        queen_vigor: 1.3,
+        worker_efficiency: 0.8,
+        architectural_skill: 1.1,
+        environmental_adaptation: 0.9,
+    };
+    let mother = systems::spawn_founding_queen(
+        &mut app.world_mut().commands(),
+        Position { x: 10.0, y: -20.0 },
+        mother_traits.clone(),
+    );
+    app.world_mut().flush();
+    app.world_mut()
+        .get_mut::<Queen>(mother)
+        .unwrap()
+        .founding_state = FoundingState::Established;
+
+    // Too small a colony raises no daughters
+    app.world_mut().spawn((Ant, Position { x: 0.0, y: 0.0 }));
+    for _ in 0..20 {
+        app.update();
+    }
+    let queen_count = |app: &mut App| {
+        app.world_mut()
+            .query_filtered::<(), With<Queen>>()
+            .iter(app.world())
+            .count()
+    };
+    assert_eq!(queen_count(&mut app), 1);
+
+    for _ in 0..2 {
+        app.world_mut().spawn((Ant, Position { x: 0.0, y: 0.0 }));
+    }
+    for _ in 0..15 {
+        app.update();
+    }
+    assert_eq!(queen_count(&mut app), 2, "One flight per interval");
+
+    let world = app.world_mut();
+    let (founding_state, position, traits, colony) = world
+        .query::<(Entity, &Queen, &Position, &ColonyTraits, Option<&ColonyId>)>()
+        .iter(world)
+        .find(|(entity, ..)| *entity != mother)
+        .map(|(_, queen, position, traits, colony)| {
+            (
+                queen.founding_state.clone(),
+                position.clone(),
+                traits.clone(),
+                colony.copied(),
+            )
+        })
+        .unwrap();
+    assert!(founding_state == FoundingState::Seeking);
+    assert_eq!(
+        colony,
+        Some(ColonyId(1)),
+        "A daughter heads a colony of her own"
+    );
+    assert!(world.get::<ColonyId>(mother).is_none());
+    assert_eq!(position.y, 0.0);
+    assert!((position.x - 10.0).abs() <= 30.0);
+    let pairs = [
+        (mother_traits.queen_vigor, traits.queen_vigor),
+        (mother_traits.worker_efficiency, traits.worker_efficiency),
+        (
+            mother_traits.architectural_skill,
+            traits.architectural_skill,
+        ),
+        (
+            mother_traits.environmental_adaptation,
+            traits.environmental_adaptation,
+        ),
+    ];
+    for (mother_value, daughter_value) in pairs {
+        assert!(
+            (daughter_value - mother_value).abs()
+                <= systems::DAUGHTER_TRAIT_MUTATION + f32::EPSILON,
+            "Daughter trait drifted too far: {} -> {}",
+            mother_value,
+            daughter_value
+        );
+    }
+}
+
+/// A colony that died out never hands its id to the next daughter
+#[test]
+fn test_daughter_colony_ids_are_not_reused() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(SimulationRng::from_seed(5));
+    app.insert_resource(NuptialFlightConfig {
+        min_workers: 3,
+        interval: 1.0,
+        dispersal_distance: 30.0,
+    });
+    app.init_resource::<DaughterColonies>();
+    app.add_systems(Update, systems::nuptial_flight_system);
+
+    let mother = systems::spawn_founding_queen(
+        &mut app.world_mut().commands(),
+        Position { x: 10.0, y: -20.0 },
+        ColonyTraits {
+            queen_vigor: 1.0,
+            worker_efficiency: 1.0,
+            architectural_skill: 1.0,
+            environmental_adaptation: 1.0,
+        },
+    );
+    app.world_mut().flush();
+    app.world_mut()
+        .get_mut::<Queen>(mother)
+        .unwrap()
+        .founding_state = FoundingState::Established;
+    for _ in 0..3 {
+        app.world_mut().spawn((Ant, Position { x: 0.0, y: 0.0 }));
+    }
+
+    let daughter = |app: &mut App| {
+        let world = app.world_mut();
+        world
+            .query_filtered::<(Entity, &ColonyId), With<Queen>>()
+            .iter(world)
+            .map(|(entity, colony)| (entity, *colony))
+            .next()
+    };
+    for _ in 0..15 {
+        app.update();
+    }
+    let (first, colony) = daughter(&mut app).expect("A daughter should have flown");
+    assert_eq!(colony, ColonyId(1));
+
+    // The daughter colony dies out before the next flight
+    app.world_mut().despawn(first);
+    for _ in 0..10 {
+        app.update();
+    }
+    let (_, colony) = daughter(&mut app).expect("A second daughter should have flown");
+    assert_eq!(colony, ColonyId(2));
+}
+
+/// A daughter queen's eggs and the workers hatched from them join her colony
+#[test]
+fn test_daughter_brood_joins_her_colony() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )));
+    app.insert_resource(ColonyFoodStore {
+        food: 100.0,
+        protein: 1000.0,
+    });
+    app.init_resource::<BroodNutritionConfig>();
+    app.init_resource::<ChamberClimateConfig>();
+    app.init_resource::<CasteConfig>();
+    app.add_event::<systems::EggLaidEvent>();
+    app.add_systems(
+        Update,
+        (
+            systems::queen_reproduction_system,
+            systems::egg_hatching_system,
+        )
+            .chain(),
+    );
+    let queen = app
+        .world_mut()
+        .spawn((
+            Position { x: 0.0, y: -20.0 },
+            Ant,
+            Queen {
+                founding_state: FoundingState::Established,
+            },
+            ReproductionState {
+                time_since_last_egg: 10.0, // Lays on the first update
+                ..default()
+            },
+            ColonyId(4),
+        ))
+        .id();
+
+    app.update();
+    let world = app.world_mut();
+    let eggs: Vec<Option<ColonyId>> = world
+        .query_filtered::<Option<&ColonyId>, With<Egg>>()
+        .iter(world)
+        .map(|colony| colony.copied())
+        .collect();
+    assert!(!eggs.is_empty());
+    assert!(eggs.iter().all(|colony| *colony == Some(ColonyId(4))));
+
+    for _ in 0..80 {
+        app.update();
+    }
+    let world = app.world_mut();
+    let workers: Vec<(Option<ColonyId>, Option<LaidBy>)> = world
+        .query_filtered::<(Option<&ColonyId>, Option<&LaidBy>), (With<Ant>, Without<Queen>)>()
+        .iter(world)
+        .map(|(colony, laid_by)| (colony.copied(), laid_by.copied()))
+        .collect();
+    assert!(!workers.is_empty(), "The brood should have hatched");
+    for (colony, laid_by) in workers {
+        assert_eq!(colony, Some(ColonyId(4)));
+        assert_eq!(laid_by, Some(LaidBy { queen }));
+    }
+}
@@ -0,0 +1,86 @@
+//! Trophallaxis (energy sharing) tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+fn trophallaxis_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<SpatialGrid>();
+    app.init_resource::<TrophallaxisConfig>();
+    app.add_systems(
+        Update,
+        (
+            systems::spatial_grid_update_system,
+            systems::trophallaxis_system,
+        )
+            .chain(),
+    );
+    app
+}
+
+fn spawn_ant(app: &mut App, x: f32, energy: f32) -> Entity {
+    app.world_mut()
+        .spawn((
+            Ant,
+            Position { x, y: 0.0 },
+            Lifecycle {
+                age: 0.0,
+                max_age: 1000.0,
+                energy,
+                max_energy: 100.0,
+            },
+        ))
+        .id()
+}
+
+fn energy(app: &App, ant: Entity) -> f32 {
+    app.world().get::<Lifecycle>(ant).unwrap().energy
+}
+
+/// A well-fed ant next to a starving one feeds it, and no energy is lost on the way
+#[test]
+fn test_energy_moves_to_starving_neighbour_conserving_total() {
+    let mut app = trophallaxis_app();
+    let donor = spawn_ant(&mut app, 0.0, 90.0);
+    let starving = spawn_ant(&mut app, 1.0, 10.0);
+
+    app.update();
+    app.update();
+
+    let (donor_energy, starving_energy) = (energy(&app, donor), energy(&app, starving));
+    assert!(donor_energy < 90.0, "donor kept {}", donor_energy);
+    assert!(starving_energy > 10.0, "recipient got {}", starving_energy);
+    assert!((donor_energy + starving_energy - 100.0).abs() < 1e-3);
+}
+
+/// Ants out of contact never share, and a pair waits out the cooldown before sharing again
+#[test]
+fn test_distance_and_cooldown_limit_sharing() {
+    let mut app = trophallaxis_app();
+    app.insert_resource(TrophallaxisConfig {
+        donor_min_energy_fraction: 0.5,
+        recipient_max_energy_fraction: 0.5,
+        ..default()
+    });
+    let donor = spawn_ant(&mut app, 0.0, 90.0);
+    let starving = spawn_ant(&mut app, 1.0, 0.0);
+    let distant = spawn_ant(&mut app, 40.0, 10.0);
+
+    app.update();
+    app.update();
+    let after_first = energy(&app, starving);
+    assert!(after_first > 0.0);
+
+    // Still hungry, but the pair is on cooldown
+    app.update();
+    assert_eq!(energy(&app, starving), after_first);
+    assert_eq!(energy(&app, distant), 10.0);
+    assert!(energy(&app, donor) < 90.0);
+}
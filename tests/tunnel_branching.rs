@@ -0,0 +1,115 @@
+//! Nest branching limit tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use rand::prelude::*;
+
+const RADIUS: f32 = 6.0;
+
+/// Grow a nest from one chamber by repeatedly branching off random chambers
+fn grow_nest(config: &NestPlanningConfig, skill: f32, seed: u64) -> Vec<Position> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut chambers = vec![Position { x: 0.0, y: -10.0 }];
+
+    for _ in 0..200 {
+        let anchor = chambers.choose(&mut rng).unwrap().clone();
+        let occupied = chambers
+            .iter()
+            .map(|position| (position, RADIUS))
+            .collect::<Vec<_>>();
+        if let Some(site) = systems::choose_branch_site(
+            &anchor,
+            RADIUS,
+            RADIUS,
+            &occupied,
+            config,
+            skill,
+            rng.gen(),
+        ) {
+            chambers.push(site);
+        }
+    }
+    chambers
+}
+
+/// No grown junction carries more tunnels than allowed, or two tunnels closer than the minimum angle
+#[test]
+fn test_junctions_respect_branch_limits() {
+    let config = NestPlanningConfig::default();
+
+    for skill in [0.7, 1.0, 1.3] {
+        let max_branches = config.max_branches_for(skill);
+        let min_angle = config.min_branch_angle_for(skill);
+
+        for seed in 0..5 {
+            let chambers = grow_nest(&config, skill, seed);
+            assert!(
+                chambers.len() > 5,
+                "The nest should grow: {}",
+                chambers.len()
+            );
+            let occupied = chambers
+                .iter()
+                .map(|position| (position, RADIUS))
+                .collect::<Vec<_>>();
+
+            for chamber in &chambers {
+                let branches =
+                    systems::branch_angles(chamber, RADIUS, config.site_spacing, &occupied);
+                assert!(
+                    branches.len() <= max_branches,
+                    "Skill {}: {} branches at ({}, {})",
+                    skill,
+                    branches.len(),
+                    chamber.x,
+                    chamber.y
+                );
+                for (i, a) in branches.iter().enumerate() {
+                    for b in &branches[i + 1..] {
+                        assert!(
+                            systems::angle_between(*a, *b) >= min_angle - 1e-4,
+                            "Skill {}: branches {} and {} too close",
+                            skill,
+                            a,
+                            b
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Shallow chambers fork more readily than deep ones, and skill scales the limits
+#[test]
+fn test_branching_narrows_with_depth() {
+    let config = NestPlanningConfig::default();
+    assert!(config.fork_chance(0.0, 1.0) > config.fork_chance(50.0, 1.0));
+    assert!(config.fork_chance(50.0, 1.0) > config.fork_chance(200.0, 1.0));
+    assert_eq!(
+        config.fork_chance(200.0, 1.0),
+        config.fork_chance(config.branch_depth, 1.0)
+    );
+    assert!(config.max_branches_for(1.3) >= config.max_branches_for(0.7));
+    assert!(config.min_branch_angle_for(1.3) > config.min_branch_angle_for(0.7));
+
+    // A chamber with a tunnel running through it refuses to fork on an unlucky roll
+    let anchor = Position { x: 0.0, y: -10.0 };
+    let offset = RADIUS * 2.0 + config.site_spacing;
+    let left = Position {
+        x: -offset,
+        y: -10.0,
+    };
+    let right = Position {
+        x: offset,
+        y: -10.0,
+    };
+    let occupied = [(&anchor, RADIUS), (&left, RADIUS), (&right, RADIUS)];
+    assert!(
+        systems::choose_branch_site(&anchor, RADIUS, RADIUS, &occupied, &config, 1.0, 0.99)
+            .is_none()
+    );
+    let fork = systems::choose_branch_site(&anchor, RADIUS, RADIUS, &occupied, &config, 1.0, 0.0)
+        .expect("A lucky roll should fork");
+    assert!(fork.y < anchor.y, "The fork should head downward");
+}
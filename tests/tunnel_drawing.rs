@@ -0,0 +1,115 @@
+//! Tunnel drawing tool tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems::{self, TunnelDrawCommand};
+use bevy::prelude::*;
+
+fn tunnel_drawing_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<TunnelDrawTool>();
+    app.init_resource::<NestSpace>();
+    app.add_event::<TunnelDrawCommand>();
+    app.add_systems(Update, systems::tunnel_draw_system);
+    app
+}
+
+fn send(app: &mut App, draw_commands: &[TunnelDrawCommand]) {
+    for draw_command in draw_commands {
+        app.world_mut().send_event(*draw_command);
+    }
+    app.update();
+}
+
+/// Tunnel nodes ordered by x, with their neighbors
+fn nodes_by_x(app: &mut App) -> Vec<(Entity, f32, Vec<Entity>)> {
+    let mut nodes: Vec<_> = app
+        .world_mut()
+        .query::<(Entity, &Position, &TunnelNode)>()
+        .iter(app.world())
+        .map(|(entity, position, node)| (entity, position.x, node.neighbors.clone()))
+        .collect();
+    nodes.sort_by(|a, b| a.1.total_cmp(&b.1));
+    nodes
+}
+
+/// A recorded drag lays an evenly spaced chain of nodes linked both ways to their neighbors
+#[test]
+fn test_drag_path_lays_symmetric_node_chain() {
+    let mut app = tunnel_drawing_app();
+    app.world_mut().spawn((Position { x: 8.0, y: -10.0 }, Soil));
+
+    // Drag 16 units to the right over two frames: one node every 4 units
+    send(
+        &mut app,
+        &[
+            TunnelDrawCommand::Start(Vec2::new(0.0, -10.0)),
+            TunnelDrawCommand::DragTo(Vec2::new(9.0, -10.0)),
+        ],
+    );
+    send(
+        &mut app,
+        &[
+            TunnelDrawCommand::DragTo(Vec2::new(16.0, -10.0)),
+            TunnelDrawCommand::End,
+        ],
+    );
+
+    let nodes = nodes_by_x(&mut app);
+    let xs: Vec<f32> = nodes.iter().map(|(_, x, _)| *x).collect();
+    assert_eq!(xs, vec![0.0, 4.0, 8.0, 12.0, 16.0]);
+
+    for (index, (entity, _, neighbors)) in nodes.iter().enumerate() {
+        let mut expected = Vec::new();
+        if index > 0 {
+            expected.push(nodes[index - 1].0);
+        }
+        if index + 1 < nodes.len() {
+            expected.push(nodes[index + 1].0);
+        }
+        let mut actual = neighbors.clone();
+        actual.sort();
+        expected.sort();
+        assert_eq!(actual, expected, "Node {:?} links", entity);
+    }
+
+    let tunnels = app.world_mut().query::<&Tunnel>().iter(app.world()).count();
+    assert_eq!(tunnels, 4);
+    let soil = app.world_mut().query::<&Soil>().iter(app.world()).count();
+    assert_eq!(soil, 0, "Soil under the tunnel is dug out");
+    assert_eq!(app.world().resource::<NestSpace>().excavated_cells, 1);
+}
+
+/// A double-click places a chamber of the selected type and links it into the nearby tunnel
+#[test]
+fn test_double_click_chamber_joins_node_graph() {
+    let mut app = tunnel_drawing_app();
+    app.world_mut()
+        .resource_mut::<TunnelDrawTool>()
+        .chamber_type = ChamberType::FoodStorage;
+
+    send(
+        &mut app,
+        &[
+            TunnelDrawCommand::Start(Vec2::new(0.0, -10.0)),
+            TunnelDrawCommand::DragTo(Vec2::new(8.0, -10.0)),
+            TunnelDrawCommand::End,
+        ],
+    );
+    send(
+        &mut app,
+        &[TunnelDrawCommand::PlaceChamber(Vec2::new(12.0, -10.0))],
+    );
+
+    let (chamber_entity, chamber, chamber_node) = app
+        .world_mut()
+        .query::<(Entity, &Chamber, &TunnelNode)>()
+        .single(app.world());
+    assert_eq!(chamber.chamber_type, ChamberType::FoodStorage);
+    let neighbors = chamber_node.neighbors.clone();
+    assert_eq!(neighbors.len(), 1);
+
+    let tunnel_end = app.world().get::<TunnelNode>(neighbors[0]).unwrap();
+    assert!(tunnel_end.neighbors.contains(&chamber_entity));
+    assert_eq!(app.world().get::<Position>(neighbors[0]).unwrap().x, 8.0);
+}
@@ -0,0 +1,69 @@
+//! Deferred UI panel setup tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::localization::Localization;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+
+fn deferred_panel_app(ui_assets: UiAssets) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(ui_assets);
+    app.init_resource::<UITheme>();
+    app.init_resource::<UserSettings>();
+    app.init_resource::<Localization>();
+    app.add_systems(
+        PreUpdate,
+        (
+            systems::setup_statistics_panel.run_if(systems::ui_panels_pending),
+            systems::mark_ui_panels_built_system.run_if(systems::ui_panels_pending),
+        )
+            .chain(),
+    );
+    app
+}
+
+fn panel_count(app: &mut App) -> usize {
+    app.world_mut()
+        .query::<&StatisticsPanel>()
+        .iter(app.world())
+        .count()
+}
+
+/// Panel setup waits while the font is unloaded, then runs exactly once when it is ready
+#[test]
+fn test_panel_setup_deferred_until_font_ready() {
+    let mut app = deferred_panel_app(UiAssets::default());
+
+    app.update();
+    app.update();
+    assert_eq!(panel_count(&mut app), 0, "Panels must wait for the font");
+
+    app.world_mut().resource_mut::<UiAssets>().ready = true;
+    app.update();
+    assert_eq!(panel_count(&mut app), 1);
+
+    app.update();
+    assert_eq!(panel_count(&mut app), 1, "Panels are built only once");
+    assert!(app.world().resource::<UiAssets>().panels_built);
+}
+
+/// Without an asset server a custom font cannot load, so the built-in font is used at once
+#[test]
+fn test_headless_falls_back_to_built_in_font() {
+    let mut app = deferred_panel_app(UiAssets {
+        font_path: Some("fonts/missing.ttf".to_string()),
+        ..default()
+    });
+    app.add_systems(
+        PreUpdate,
+        systems::ui_assets_loading_system.before(systems::setup_statistics_panel),
+    );
+
+    app.update();
+
+    let ui_assets = app.world().resource::<UiAssets>();
+    assert!(ui_assets.ready);
+    assert_eq!(ui_assets.font, Handle::default());
+    assert_eq!(panel_count(&mut app), 1);
+}
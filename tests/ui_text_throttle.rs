@@ -0,0 +1,87 @@
+//! UI text is only rewritten when the value shown actually changes
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::localization::Localization;
+use ant_nest_simulator::systems;
+use bevy::ecs::component::Tick;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+
+/// Tick each text entity was last changed at, keyed by its first section
+fn text_ticks(app: &mut App) -> Vec<(String, Tick)> {
+    let world = app.world_mut();
+    let mut ticks: Vec<_> = world
+        .query::<(Ref<Text>, &DisplayedText)>()
+        .iter(world)
+        .map(|(text, _)| (text.sections[0].value.clone(), text.last_changed()))
+        .collect();
+    ticks.sort_by(|a, b| a.0.cmp(&b.0));
+    ticks
+}
+
+/// Statistics texts stay untouched while the statistics behind them do not move
+#[test]
+fn test_statistics_text_unchanged_for_identical_data() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<UITheme>();
+    app.init_resource::<UserSettings>();
+    app.init_resource::<DisasterState>();
+    app.init_resource::<Localization>();
+    app.insert_resource(ColonyStatistics {
+        total_ant_count: 12,
+        ..default()
+    });
+    app.add_systems(Update, systems::update_statistics_display);
+    app.world_mut()
+        .run_system_once(systems::setup_statistics_panel);
+    {
+        let world = app.world_mut();
+        for mut toggle in world.query::<&mut StatisticsToggle>().iter_mut(world) {
+            toggle.is_visible = true;
+        }
+    }
+
+    app.update();
+    let first = text_ticks(&mut app);
+    assert!(first.iter().any(|(value, _)| value == "Total Ants: 12"));
+
+    app.update();
+    app.update();
+    assert_eq!(text_ticks(&mut app), first);
+
+    app.world_mut()
+        .resource_mut::<ColonyStatistics>()
+        .total_ant_count = 13;
+    app.update();
+    let changed: Vec<_> = text_ticks(&mut app)
+        .into_iter()
+        .filter(|entry| !first.contains(entry))
+        .collect();
+    assert_eq!(changed.len(), 1);
+    assert_eq!(changed[0].0, "Total Ants: 13");
+}
+
+/// The compact HUD's speed, FPS and entity texts hold still at steady metrics
+#[test]
+fn test_mini_hud_text_unchanged_for_identical_data() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(PerformanceMetrics {
+        fps: 60.0,
+        total_entity_count: 500,
+        ..default()
+    });
+    app.init_resource::<TimeControl>();
+    app.init_resource::<Localization>();
+    app.add_systems(Startup, systems::setup_mini_hud);
+    app.add_systems(Update, systems::update_mini_hud);
+
+    app.update();
+    let first = text_ticks(&mut app);
+    assert_eq!(first.len(), 3);
+
+    app.update();
+    app.update();
+    assert_eq!(text_ticks(&mut app), first);
+}
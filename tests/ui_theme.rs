@@ -0,0 +1,113 @@
+//! UI theme import and export tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems::{self, ThemeError};
+use bevy::prelude::*;
+
+/// A theme that differs from the default in every section
+fn custom_theme() -> UITheme {
+    let mut theme = UITheme::default();
+    theme.colors.surface_primary = Color::srgba(0.9, 0.85, 0.7, 0.95);
+    theme.colors.text_primary = Color::srgb(0.1, 0.1, 0.1);
+    theme.typography.body_medium = 18.0;
+    theme.spacing.md = 10.0;
+    theme.borders.radius_medium = 0.0;
+    theme
+}
+
+/// A modified theme comes back from its JSON field for field
+#[test]
+fn test_theme_round_trips_through_export() {
+    let theme = custom_theme();
+    let exported = systems::export_theme(&theme);
+    let imported = systems::import_theme(&exported).expect("exported theme should import");
+
+    assert_eq!(imported, theme);
+    assert_eq!(imported.colors, theme.colors);
+    assert_eq!(imported.typography, theme.typography);
+    assert_eq!(imported.spacing, theme.spacing);
+    assert_eq!(imported.borders, theme.borders);
+    assert_ne!(imported, UITheme::default());
+}
+
+/// Garbage and unusable sizes are rejected instead of breaking the panels
+#[test]
+fn test_bad_themes_are_rejected() {
+    assert!(matches!(
+        systems::import_theme("not a theme"),
+        Err(ThemeError::Json(_))
+    ));
+
+    let mut theme = UITheme::default();
+    theme.typography.body_small = 0.0;
+    assert!(matches!(
+        systems::import_theme(&systems::export_theme(&theme)),
+        Err(ThemeError::Invalid(_))
+    ));
+
+    let mut theme = UITheme::default();
+    theme.spacing.sm = -4.0;
+    assert!(matches!(
+        systems::import_theme(&systems::export_theme(&theme)),
+        Err(ThemeError::Invalid(_))
+    ));
+}
+
+/// Applying a theme recolors panels already on screen, role by role
+#[test]
+fn test_new_theme_recolors_panels_live() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<UITheme>();
+    app.add_systems(Update, systems::theme_recolor_system);
+
+    let default_colors = UITheme::default().colors;
+    let panel = app
+        .world_mut()
+        .spawn(NodeBundle {
+            background_color: default_colors.surface_primary.into(),
+            border_color: default_colors.border_primary.into(),
+            ..default()
+        })
+        .id();
+    let label = app
+        .world_mut()
+        .spawn(TextBundle::from_section(
+            "Colony",
+            TextStyle {
+                color: default_colors.text_primary,
+                ..default()
+            },
+        ))
+        .id();
+    let unthemed = app
+        .world_mut()
+        .spawn(NodeBundle {
+            background_color: Color::srgb(0.123, 0.456, 0.789).into(),
+            ..default()
+        })
+        .id();
+    app.update();
+
+    let theme = custom_theme();
+    *app.world_mut().resource_mut::<UITheme>() = theme.clone();
+    app.update();
+
+    let world = app.world();
+    assert_eq!(
+        world.get::<BackgroundColor>(panel).unwrap().0,
+        theme.colors.surface_primary
+    );
+    assert_eq!(
+        world.get::<BorderColor>(panel).unwrap().0,
+        theme.colors.border_primary
+    );
+    assert_eq!(
+        world.get::<Text>(label).unwrap().sections[0].style.color,
+        theme.colors.text_primary
+    );
+    assert_eq!(
+        world.get::<BackgroundColor>(unthemed).unwrap().0,
+        Color::srgb(0.123, 0.456, 0.789)
+    );
+}
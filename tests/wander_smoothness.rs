@@ -0,0 +1,108 @@
+//! Smooth wander (correlated random walk) tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::strategy::BoxedStrategy;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+/// Positions of a lone forager with nothing to find, one per 100ms tick
+fn wander_path(ai_config: AntAiConfig, ticks: usize) -> Vec<Vec2> {
+    wander_path_in(WorldConfig::default(), ai_config, ticks)
+}
+
+/// Like [`wander_path`], in a world with the given bounds
+fn wander_path_in(world: WorldConfig, ai_config: AntAiConfig, ticks: usize) -> Vec<Vec2> {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(ai_config);
+    app.insert_resource(world);
+    app.init_resource::<PheromoneGrid>();
+    app.init_resource::<ColonyFoodStore>();
+    app.init_resource::<BoxedStrategy>();
+    app.add_systems(Update, systems::foraging_behavior_system);
+
+    let mut behavior = AntBehavior::new(Position { x: 0.0, y: 0.0 }, 0.0);
+    behavior.speed = 3.0;
+    let ant = app
+        .world_mut()
+        .spawn((Position { x: 0.0, y: 2.0 }, Ant, behavior))
+        .id();
+
+    (0..ticks)
+        .map(|_| {
+            app.update();
+            let position = app.world().get::<Position>(ant).unwrap();
+            Vec2::new(position.x, position.y)
+        })
+        .collect()
+}
+
+/// Largest turn between successive steps of a path, in radians
+fn largest_turn(path: &[Vec2]) -> f32 {
+    let steps: Vec<Vec2> = path
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .filter(|step| step.length() > 1e-4)
+        .collect();
+    steps
+        .windows(2)
+        .map(|pair| pair[0].angle_between(pair[1]).abs())
+        .fold(0.0, f32::max)
+}
+
+/// Smooth wandering turns gently between steps; far targets swing the ant around
+#[test]
+fn test_smooth_wander_turns_less_than_far_targets() {
+    // Short enough that the smooth wanderer never reaches the edge of its range
+    let smooth = largest_turn(&wander_path(AntAiConfig::default(), 80));
+    let far = largest_turn(&wander_path(
+        AntAiConfig {
+            wander_mode: WanderMode::FarTarget,
+            forage_range: 10.0,
+            ..default()
+        },
+        200,
+    ));
+    assert!(smooth < 1.0, "smooth wander turned {} rad", smooth);
+    assert!(smooth < far, "smooth {} vs far-target {}", smooth, far);
+}
+
+/// A smooth wanderer keeps moving along the surface instead of stalling or sinking
+#[test]
+fn test_smooth_wander_stays_on_surface() {
+    let path = wander_path(AntAiConfig::default(), 100);
+    let travelled = path.first().unwrap().distance(*path.last().unwrap());
+    assert!(travelled > 5.0, "only travelled {}", travelled);
+    assert!(path.iter().all(|point| (0.0..=4.0).contains(&point.y)));
+}
+
+/// Wanderers with a range wider than the world turn back at its edges, in either mode
+#[test]
+fn test_wander_stays_within_world_bounds() {
+    let world = WorldConfig {
+        min_x: -15.0,
+        max_x: 15.0,
+        ..default()
+    };
+    for wander_mode in [WanderMode::Smooth, WanderMode::FarTarget] {
+        let path = wander_path_in(
+            world.clone(),
+            AntAiConfig {
+                wander_mode,
+                forage_range: 80.0,
+                ..default()
+            },
+            400,
+        );
+        assert!(
+            path.iter().all(|point| (-15.5..=15.5).contains(&point.x)),
+            "{:?} wandered out of the world",
+            wander_mode
+        );
+    }
+}
@@ -0,0 +1,77 @@
+//! World edge handling tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+/// Run one movement frame for an ant that has stepped past the east edge while
+/// heading further east
+fn step_past_east_edge(mode: BoundaryMode) -> (Position, AntBehavior, SpatialGrid, Entity) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<NestSpace>();
+    app.init_resource::<WorldConfig>();
+    app.init_resource::<SpatialGrid>();
+    app.insert_resource(mode);
+    app.add_systems(Update, systems::ant_movement_system);
+
+    let start = Position { x: 103.0, y: 0.0 };
+    let mut behavior = AntBehavior::new(Position { x: 0.0, y: 0.0 }, 0.0);
+    behavior.target_position = Some(Position { x: 150.0, y: 0.0 });
+    let ant = app
+        .world_mut()
+        .spawn((Ant, start.clone(), behavior, Transform::default()))
+        .id();
+    app.world_mut()
+        .resource_mut::<SpatialGrid>()
+        .insert_entity(ant, &start);
+
+    app.update();
+
+    let world = app.world();
+    (
+        world.get::<Position>(ant).unwrap().clone(),
+        world.get::<AntBehavior>(ant).unwrap().clone(),
+        world.resource::<SpatialGrid>().clone(),
+        ant,
+    )
+}
+
+#[test]
+fn test_clamp_keeps_ant_inside_east_edge() {
+    let (position, _, _, _) = step_past_east_edge(BoundaryMode::Clamp);
+    let world = WorldConfig::default();
+
+    assert!(position.x <= world.max_x);
+    assert!(position.x > world.max_x - 1.0);
+}
+
+#[test]
+fn test_wrap_moves_ant_to_west_edge() {
+    let (position, _, spatial_grid, ant) = step_past_east_edge(BoundaryMode::Wrap);
+    let world = WorldConfig::default();
+
+    assert!(position.x >= world.min_x);
+    assert!(position.x < world.min_x + 5.0);
+    // The grid lists the ant at its new side only
+    let near_west = spatial_grid.get_nearby_entities(&position, 1.0);
+    let near_east = spatial_grid.get_nearby_entities(&Position { x: 103.0, y: 0.0 }, 1.0);
+    assert!(near_west.contains(&ant));
+    assert!(!near_east.contains(&ant));
+}
+
+#[test]
+fn test_bounce_reverses_heading() {
+    let (position, behavior, _, _) = step_past_east_edge(BoundaryMode::Bounce);
+    let world = WorldConfig::default();
+
+    assert!(position.x <= world.max_x);
+    assert!(position.x > world.max_x - 5.0);
+    let target = behavior.target_position.unwrap();
+    assert!(target.x < position.x, "ant should now head west");
+}
@@ -0,0 +1,83 @@
+//! World background theme tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::localization::Localization;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+
+fn world_theme_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<UserSettings>();
+    app.init_resource::<Localization>();
+    app.init_resource::<ClearColor>();
+    app.add_systems(
+        Update,
+        (
+            systems::world_theme_dropdown_system,
+            systems::world_background_system,
+        )
+            .chain(),
+    );
+    app
+}
+
+/// Clicking the world background dropdown picks the next preset and repaints `ClearColor`
+#[test]
+fn test_selecting_theme_updates_clear_color() {
+    let mut app = world_theme_app();
+    app.update();
+    assert_eq!(
+        app.world().resource::<ClearColor>().0,
+        WorldTheme::Night.background()
+    );
+
+    let label = app
+        .world_mut()
+        .spawn(TextBundle::from_section("", TextStyle::default()))
+        .id();
+    app.world_mut()
+        .spawn((
+            Button,
+            Interaction::Pressed,
+            SettingItem {
+                setting_type: SettingType::WorldTheme,
+            },
+        ))
+        .add_child(label);
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<UserSettings>().world_theme,
+        WorldTheme::Daylight
+    );
+    assert_eq!(
+        app.world().resource::<ClearColor>().0,
+        WorldTheme::Daylight.background()
+    );
+    assert_eq!(
+        app.world().get::<Text>(label).unwrap().sections[0].value,
+        "Daylight"
+    );
+}
+
+/// The themed background dims toward midnight and is at full brightness at noon
+#[test]
+fn test_background_dims_at_night() {
+    let mut app = world_theme_app();
+    app.world_mut().resource_mut::<UserSettings>().world_theme = WorldTheme::Sepia;
+    let mut simulation_time = SimulationTime::default();
+    simulation_time.set_hour(12.0);
+    app.insert_resource(simulation_time);
+    app.update();
+    let noon = app.world().resource::<ClearColor>().0.to_srgba();
+    assert_eq!(noon, WorldTheme::Sepia.background().to_srgba());
+
+    app.world_mut()
+        .resource_mut::<SimulationTime>()
+        .set_hour(0.0);
+    app.update();
+    let midnight = app.world().resource::<ClearColor>().0.to_srgba();
+    assert!(midnight.red < noon.red && midnight.green < noon.green);
+    assert!(midnight.red > 0.0, "background should dim, not vanish");
+}
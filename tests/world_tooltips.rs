@@ -0,0 +1,60 @@
+//! World-entity tooltip tests
+
+use ant_nest_simulator::components::*;
+use ant_nest_simulator::systems;
+use bevy::prelude::*;
+
+/// Chamber tooltips are anchored at the chamber's projected screen position
+#[test]
+fn test_chamber_world_position_projects_to_screen() {
+    let viewport = Vec2::new(800.0, 600.0);
+
+    // Camera centered on the origin at 1:1 zoom: world origin is the screen center
+    let center = systems::world_to_screen(Vec2::ZERO, Vec2::ZERO, 1.0, viewport);
+    assert_eq!(center, Vec2::new(400.0, 300.0));
+
+    // Default world camera: panned down 20 units and zoomed in (scale 0.6)
+    let chamber = Chamber {
+        chamber_type: ChamberType::Queen,
+        radius: 6.0,
+        capacity: 4,
+        occupants: 0,
+        temperature: 25.0,
+    };
+    let chamber_position = Vec2::new(30.0, -8.0);
+    let screen = systems::world_to_screen(chamber_position, Vec2::new(0.0, -20.0), 0.6, viewport);
+
+    assert!((screen.x - 450.0).abs() < 0.01, "x was {}", screen.x);
+    assert!((screen.y - 280.0).abs() < 0.01, "y was {}", screen.y);
+
+    assert!(systems::chamber_tooltip_text(&chamber).starts_with("Royal Chamber"));
+}
+
+/// Chamber tooltips track how many ants are inside
+#[test]
+fn test_chamber_tooltip_reports_occupancy() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_systems(Startup, |mut commands: Commands| {
+        systems::spawn_chamber(
+            &mut commands,
+            Position { x: 0.0, y: -20.0 },
+            ChamberType::Nursery,
+            6.0,
+            4,
+        );
+    });
+    app.add_systems(Update, systems::chamber_occupancy_system);
+
+    app.world_mut().spawn((Ant, Position { x: 1.0, y: -20.0 }));
+    app.world_mut().spawn((Ant, Position { x: -2.0, y: -18.0 }));
+    app.world_mut().spawn((Ant, Position { x: 50.0, y: 0.0 })); // Outside
+
+    app.update();
+    app.update();
+
+    let world = app.world_mut();
+    let (chamber, tooltip) = world.query::<(&Chamber, &Tooltip)>().single(world);
+    assert_eq!(chamber.occupants, 2);
+    assert_eq!(tooltip.text, "Nursery\n2/4 ants (50% full)");
+}